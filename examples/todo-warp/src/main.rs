@@ -1,23 +1,16 @@
-use std::{net::Ipv4Addr, sync::Arc};
+use std::net::Ipv4Addr;
 
 use utoipa::{
     openapi::security::{ApiKey, ApiKeyValue, SecurityScheme},
     Modify, OpenApi,
 };
-use utoipa_swagger_ui::Config;
-use warp::{
-    http::Uri,
-    hyper::{Response, StatusCode},
-    path::{FullPath, Tail},
-    Filter, Rejection, Reply,
-};
+use utoipa_swagger_ui::SwaggerUi;
+use warp::{filters::BoxedFilter, Filter, Reply};
 
 #[tokio::main]
 async fn main() {
     env_logger::init();
 
-    let config = Arc::new(Config::from("/api-doc.json"));
-
     #[derive(OpenApi)]
     #[openapi(
         paths(todo::list_todos, todo::create_todo, todo::delete_todo),
@@ -43,54 +36,15 @@ async fn main() {
         }
     }
 
-    let api_doc = warp::path("api-doc.json")
-        .and(warp::get())
-        .map(|| warp::reply::json(&ApiDoc::openapi()));
-
-    let swagger_ui = warp::path("swagger-ui")
-        .and(warp::get())
-        .and(warp::path::full())
-        .and(warp::path::tail())
-        .and(warp::any().map(move || config.clone()))
-        .and_then(serve_swagger);
+    let swagger_ui: BoxedFilter<(Box<dyn Reply>,)> = SwaggerUi::new("/swagger-ui")
+        .url("/api-doc.json", ApiDoc::openapi())
+        .into();
 
-    warp::serve(api_doc.or(swagger_ui).or(todo::handlers()))
+    warp::serve(swagger_ui.or(todo::handlers()))
         .run((Ipv4Addr::UNSPECIFIED, 8080))
         .await
 }
 
-async fn serve_swagger(
-    full_path: FullPath,
-    tail: Tail,
-    config: Arc<Config<'static>>,
-) -> Result<Box<dyn Reply + 'static>, Rejection> {
-    if full_path.as_str() == "/swagger-ui" {
-        return Ok(Box::new(warp::redirect::found(Uri::from_static(
-            "/swagger-ui/",
-        ))));
-    }
-
-    let path = tail.as_str();
-    match utoipa_swagger_ui::serve(path, config) {
-        Ok(file) => {
-            if let Some(file) = file {
-                Ok(Box::new(
-                    Response::builder()
-                        .header("Content-Type", file.content_type)
-                        .body(file.bytes),
-                ))
-            } else {
-                Ok(Box::new(StatusCode::NOT_FOUND))
-            }
-        }
-        Err(error) => Ok(Box::new(
-            Response::builder()
-                .status(StatusCode::INTERNAL_SERVER_ERROR)
-                .body(error.to_string()),
-        )),
-    }
-}
-
 mod todo {
     use std::{
         convert::Infallible,
@@ -10,12 +10,18 @@ for crate in $crates; do
     echo "Testing crate: $crate..."
 
     if [[ "$crate" == "utoipa" ]]; then
-        $CARGO test -p utoipa --features openapi_extensions,preserve_order,preserve_path_order,debug
+        $CARGO test -p utoipa --features openapi_extensions,preserve_order,preserve_path_order,debug,yaml
+        $CARGO test -p utoipa --lib --features openapi_3_1,debug openapi_3_1
+        $CARGO test -p utoipa --lib --features large_numbers_as_string,debug large_numbers_as_string
     elif [[ "$crate" == "utoipa-gen" ]]; then
         $CARGO test -p utoipa-gen --features utoipa/actix_extras,chrono,decimal,utoipa/uuid,uuid,utoipa/ulid,ulid,utoipa/url,url,utoipa/time,time,utoipa/repr,utoipa/smallvec,smallvec,rc_schema,utoipa/rc_schema
         $CARGO test -p utoipa-gen --test schema_derive_test --features decimal_float
+        $CARGO test -p utoipa-gen --test schema_derive_validator_test --features validator
+        $CARGO test -p utoipa-gen --test schema_derive_serde_with_test --features serde_with
+        $CARGO test -p utoipa-gen --test openapi_derive_auto_collect_schemas --features auto_collect_schemas,axum_extras
+        $CARGO test -p utoipa-gen --test path_derive_register --features register_paths,utoipa/register_paths,auto_collect_schemas
 
-        $CARGO test -p utoipa-gen --test path_derive_auto_into_responses --features auto_into_responses,utoipa/uuid,uuid
+        $CARGO test -p utoipa-gen --test path_derive_auto_into_responses --features auto_into_responses,utoipa/auto_into_responses,utoipa/uuid,uuid
         $CARGO test -p utoipa-gen --test path_derive_actix --test path_parameter_derive_actix --features actix_extras,utoipa/uuid,uuid,utoipa/chrono,chrono,utoipa/time,time
         $CARGO test -p utoipa-gen --test path_derive_auto_into_responses_actix --features actix_extras,utoipa/auto_into_responses,utoipa/uuid,uuid
 
@@ -23,8 +29,11 @@ for crate in $crates; do
 
         $CARGO test -p utoipa-gen --test path_derive_axum_test --features axum_extras
         $CARGO test -p utoipa-gen --test path_derive_auto_into_responses_axum --features axum_extras,utoipa/auto_into_responses
+
+        $CARGO test -p utoipa-gen --test path_derive_poem_test --features poem_extras
+        $CARGO test -p utoipa-gen --test path_derive_salvo_test --features salvo_extras
     elif [[ "$crate" == "utoipa-swagger-ui" ]]; then
-        $CARGO test -p utoipa-swagger-ui --features actix-web,rocket,axum
+        $CARGO test -p utoipa-swagger-ui --features actix-web,rocket,axum,warp,poem,salvo
     elif [[ "$crate" == "utoipa-redoc" ]]; then
         $CARGO test -p utoipa-redoc --features actix-web,rocket,axum
     elif [[ "$crate" == "utoipa-rapidoc" ]]; then
@@ -2,17 +2,22 @@ use std::borrow::Cow;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Attribute, GenericArgument, Path, PathArguments, PathSegment, Type, TypePath};
+use syn::token::{Brace, Comma};
+use syn::{
+    braced, Attribute, Expr, GenericArgument, Path, PathArguments, PathSegment, Token, Type,
+    TypePath,
+};
 
 use crate::doc_comment::CommentAttributes;
 use crate::schema_type::SchemaFormat;
 use crate::{schema_type::SchemaType, Deprecated};
 use crate::{Diagnostics, OptionExt};
 
-use self::features::{
-    pop_feature, Feature, FeaturesExt, IsInline, Minimum, Nullable, ToTokensExt, Validatable,
-};
+use self::features::{pop_feature, Feature, FeaturesExt, IsInline, Minimum, Nullable, ToTokensExt};
 use self::schema::format_path_ref;
 use self::serde::{RenameRule, SerdeContainer, SerdeValue};
 
@@ -21,6 +26,10 @@ pub mod into_params;
 pub mod features;
 pub mod schema;
 pub mod serde;
+#[cfg(feature = "serde_with")]
+pub mod serde_with;
+#[cfg(feature = "validator")]
+pub mod validator;
 
 /// Check whether either serde `container_rule` or `field_rule` has _`default`_ attribute set.
 #[inline]
@@ -35,6 +44,15 @@ fn is_default(container_rules: &Option<&SerdeContainer>, field_rule: &Option<&Se
             .unwrap_or(false)
 }
 
+/// Check whether `feature` is only valid with `array` types, e.g. _`max_items`_.
+#[inline]
+fn is_array_feature(feature: &Feature) -> bool {
+    matches!(
+        feature,
+        Feature::MaxItems(_) | Feature::MinItems(_) | Feature::UniqueItems(_)
+    )
+}
+
 /// Find `#[deprecated]` attribute from given attributes. Typically derive type attributes
 /// or field attributes of struct.
 fn get_deprecated(attributes: &[Attribute]) -> Option<Deprecated> {
@@ -73,8 +91,8 @@ enum TypeTreeValue<'t> {
     TypePath(&'t TypePath),
     Path(&'t Path),
     /// Slice and array types need to be manually defined, since they cannot be recognized from
-    /// generic arguments.
-    Array(Vec<TypeTreeValue<'t>>, Span),
+    /// generic arguments. Fixed size arrays carry their length expression.
+    Array(Vec<TypeTreeValue<'t>>, Option<&'t Expr>, Span),
     UnitType,
     Tuple(Vec<TypeTreeValue<'t>>, Span),
 }
@@ -84,7 +102,7 @@ impl PartialEq for TypeTreeValue<'_> {
         match self {
             Self::Path(_) => self == other,
             Self::TypePath(_) => self == other,
-            Self::Array(array, _) => matches!(other, Self::Array(other, _) if other == array),
+            Self::Array(array, _, _) => matches!(other, Self::Array(other, _, _) if other == array),
             Self::Tuple(tuple, _) => matches!(other, Self::Tuple(other, _) if other == tuple),
             Self::UnitType => self == other,
         }
@@ -101,6 +119,8 @@ pub struct TypeTree<'t> {
     pub value_type: ValueType,
     pub generic_type: Option<GenericType>,
     pub children: Option<Vec<TypeTree<'t>>>,
+    /// Length expression of fixed size array `[T; N]`.
+    pub array_len: Option<&'t Expr>,
 }
 
 impl<'t> TypeTree<'t> {
@@ -127,8 +147,8 @@ impl<'t> TypeTree<'t> {
                 )]
             },
             Type::Group(group) => Self::get_type_tree_values(group.elem.as_ref())?,
-            Type::Slice(slice) => vec![TypeTreeValue::Array(Self::get_type_tree_values(&slice.elem)?, slice.bracket_token.span.join())],
-            Type::Array(array) => vec![TypeTreeValue::Array(Self::get_type_tree_values(&array.elem)?, array.bracket_token.span.join())],
+            Type::Slice(slice) => vec![TypeTreeValue::Array(Self::get_type_tree_values(&slice.elem)?, None, slice.bracket_token.span.join())],
+            Type::Array(array) => vec![TypeTreeValue::Array(Self::get_type_tree_values(&array.elem)?, Some(&array.len), array.bracket_token.span.join())],
             Type::TraitObject(trait_object) => {
                 trait_object
                     .bounds
@@ -158,7 +178,7 @@ impl<'t> TypeTree<'t> {
                 let path = match value {
                     TypeTreeValue::TypePath(type_path) => &type_path.path,
                     TypeTreeValue::Path(path) => path,
-                    TypeTreeValue::Array(value, span) => {
+                    TypeTreeValue::Array(value, array_len, span) => {
                         let array: Path = Ident::new("Array", span).into();
                         return Ok(TypeTree {
                             path: Some(Cow::Owned(array)),
@@ -169,6 +189,7 @@ impl<'t> TypeTree<'t> {
                                 Ok(converted_values) => converted_values.collect(),
                                 Err(diagnostics) => return Err(diagnostics),
                             }),
+                            array_len,
                         });
                     }
                     TypeTreeValue::Tuple(tuple, span) => {
@@ -181,6 +202,7 @@ impl<'t> TypeTree<'t> {
                             }),
                             generic_type: None,
                             value_type: ValueType::Tuple,
+                            array_len: None,
                         })
                     }
                     TypeTreeValue::UnitType => {
@@ -190,6 +212,7 @@ impl<'t> TypeTree<'t> {
                             value_type: ValueType::Tuple,
                             generic_type: None,
                             children: None,
+                            array_len: None,
                         })
                     }
                 };
@@ -290,6 +313,7 @@ impl<'t> TypeTree<'t> {
             },
             generic_type,
             children: None,
+            array_len: None,
         }
     }
 
@@ -377,10 +401,175 @@ impl<'t> TypeTree<'t> {
         matches!(self.generic_type, Some(GenericType::Option))
     }
 
+    /// Check whether the [`TypeTree`]'s `generic_type` is a smart pointer or [`GenericType::Cow`]
+    /// which serde treats transparently.
+    pub fn is_smart_pointer(&self) -> bool {
+        match self.generic_type {
+            Some(GenericType::Cow | GenericType::Box | GenericType::RefCell) => true,
+            #[cfg(feature = "rc_schema")]
+            Some(GenericType::Arc | GenericType::Rc) => true,
+            _ => false,
+        }
+    }
+
     /// Check whether the [`TypeTree`]'s `generic_type` is [`GenericType::Map`]
     pub fn is_map(&self) -> bool {
         matches!(self.generic_type, Some(GenericType::Map))
     }
+
+    /// Check whether the [`TypeTree`] is a user defined generic type with generic arguments e.g.
+    /// _`Page<Pet>`_ in which case the schema is instantiated with the generic arguments.
+    pub fn is_generic_object(&self) -> bool {
+        self.value_type == ValueType::Object
+            && self.generic_type.is_none()
+            && self.children.is_some()
+            && !self.is_object()
+    }
+
+    /// Get name of the schema the [`TypeTree`] is referenced with. Names of the generic arguments
+    /// of user defined generic types are appended to the name e.g. _`Page<Pet>`_ is referenced
+    /// as _`PagePet`_.
+    pub fn reference_name(&self) -> Option<String> {
+        let path = self.path.as_deref()?;
+        let mut name = format_path_ref(path);
+        if self.is_generic_object() {
+            self.children
+                .iter()
+                .flatten()
+                .for_each(|child| name.push_str(&child.generic_argument_name()));
+        }
+
+        Some(name)
+    }
+
+    fn generic_argument_name(&self) -> String {
+        let name = self
+            .path
+            .as_ref()
+            .and_then(|path| path.segments.last())
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default();
+
+        self.children
+            .iter()
+            .flatten()
+            .fold(name, |name, child| name + &child.generic_argument_name())
+    }
+
+    /// Get tokens of the schemas of the generic arguments of a user defined generic type as
+    /// `Vec` of [`utoipa::openapi::RefOr`] schemas given to [`utoipa::ToSchema::generic_schema`].
+    pub fn generic_arguments_tokens(&self, object_name: &str) -> TokenStream {
+        let generics = self.children.iter().flatten().map(|child| {
+            let features = if child.is_option() {
+                Some(vec![Feature::Nullable(Nullable::new())])
+            } else {
+                None
+            };
+            let schema = ComponentSchema::new(ComponentSchemaProps {
+                type_tree: child,
+                features,
+                description: None,
+                deprecated: None,
+                object_name,
+            });
+
+            quote! { #schema.into() }
+        });
+
+        quote! { Vec::from([#( #generics ),*]) }
+    }
+
+    /// Get tokens collecting schemas of the types referenced by the [`TypeTree`] into
+    /// `schemas` in generated [`utoipa::ToSchema::schemas`] or [`utoipa::Path::schemas`].
+    ///
+    /// Known generic types and tuples are traversed to their children. Schema of inlined type is
+    /// not collected but the schemas it references are. Schemas are collected with the same name
+    /// they are referenced with, thus aliases of generic types are collected as separate schemas.
+    /// User defined generic types are collected with their generic arguments instantiated and
+    /// the generic arguments are collected as well. Types not implementing `ToSchema` are
+    /// silently ignored as well as references to `Self` or to the `object_name` which is being
+    /// collected already.
+    pub fn schema_references_tokens(&self, is_inline: bool, object_name: &str) -> TokenStream {
+        match &self.children {
+            Some(children)
+                if self.generic_type.is_some() || self.value_type == ValueType::Tuple =>
+            {
+                children
+                    .iter()
+                    .map(|child| child.schema_references_tokens(is_inline, object_name))
+                    .collect()
+            }
+            _ => match self.path.as_deref() {
+                Some(path) if self.is_generic_object() => {
+                    let name = self.reference_name().unwrap_or_default();
+                    let generics = self.generic_arguments_tokens(object_name);
+                    let generic_references = self
+                        .children
+                        .iter()
+                        .flatten()
+                        .map(|child| child.schema_references_tokens(false, object_name))
+                        .collect::<TokenStream>();
+
+                    let path = elide_lifetimes(path);
+                    quote! {
+                        (&utoipa::__dev::SchemaCollector::<#path>::new()).collect_generic(schemas, #name, #is_inline, #generics);
+                        #generic_references
+                    }
+                }
+                Some(path) if self.value_type == ValueType::Object && !self.is_object() => {
+                    let name = format_path_ref(path);
+                    if name == "Self" || name == object_name {
+                        return TokenStream::new();
+                    }
+
+                    let path = elide_lifetimes(path);
+                    quote! {
+                        (&utoipa::__dev::SchemaCollector::<#path>::new()).collect(schemas, #name, #is_inline);
+                    }
+                }
+                _ => TokenStream::new(),
+            },
+        }
+    }
+}
+
+/// Replace lifetimes of the `path` with anonymous lifetime `'_` so the path can be used
+/// outside of the scope where the lifetimes are declared.
+fn elide_lifetimes(path: &Path) -> Path {
+    let mut path = path.clone();
+    for segment in &mut path.segments {
+        if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+            for argument in &mut arguments.args {
+                match argument {
+                    GenericArgument::Lifetime(lifetime) => {
+                        *lifetime = syn::Lifetime::new("'_", lifetime.span())
+                    }
+                    GenericArgument::Type(Type::Path(type_path)) => {
+                        type_path.path = elide_lifetimes(&type_path.path)
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    path
+}
+
+/// Wrap the schema collecting `tokens` created with [`TypeTree::schema_references_tokens`] to
+/// body of `schemas` function. Returns empty tokens if there is nothing to collect.
+pub fn schemas_fn_tokens(tokens: TokenStream) -> TokenStream {
+    if tokens.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        fn schemas(schemas: &mut Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) {
+            #[allow(unused_imports)]
+            use utoipa::__dev::{CollectNothing as _, CollectSchema as _};
+            #tokens
+        }
+    }
 }
 
 impl PartialEq for TypeTree<'_> {
@@ -656,6 +845,8 @@ impl<'c> ComponentSchema {
         let additional_properties = pop_feature!(features => Feature::AdditionalProperties(_));
         let nullable = pop_feature!(features => Feature::Nullable(_));
         let default = pop_feature!(features => Feature::Default(_));
+        let max_properties = pop_feature!(features => Feature::MaxProperties(_));
+        let min_properties = pop_feature!(features => Feature::MinProperties(_));
 
         let additional_properties = additional_properties
             .as_ref()
@@ -688,6 +879,8 @@ impl<'c> ComponentSchema {
                 #description_stream
                 #deprecated_stream
                 #default
+                #max_properties
+                #min_properties
         });
 
         example.to_tokens(tokens);
@@ -706,8 +899,11 @@ impl<'c> ComponentSchema {
         let xml = features.extract_vec_xml_feature(type_tree)?;
         let max_items = pop_feature!(features => Feature::MaxItems(_));
         let min_items = pop_feature!(features => Feature::MinItems(_));
+        let unique_items = pop_feature!(features => Feature::UniqueItems(_));
         let nullable = pop_feature!(features => Feature::Nullable(_));
         let default = pop_feature!(features => Feature::Default(_));
+        let read_only = pop_feature!(features => Feature::ReadOnly(_));
+        let write_only = pop_feature!(features => Feature::WriteOnly(_));
 
         let child = type_tree
             .children
@@ -730,15 +926,33 @@ impl<'c> ComponentSchema {
             child
         };
 
-        let unique = matches!(type_tree.generic_type, Some(GenericType::Set));
+        let validate = |feature: &Feature| {
+            let type_path = &**type_tree.path.as_ref().unwrap();
+            let schema_type = SchemaType(type_path);
+            match feature.validate(&schema_type, type_tree) {
+                Some(diagnostics) => Err(diagnostics),
+                None => Ok(()),
+            }
+        };
+
+        [&max_items, &min_items, &unique_items]
+            .into_iter()
+            .flatten()
+            .try_for_each(validate)?;
+
+        let unique = matches!(type_tree.generic_type, Some(GenericType::Set))
+            || matches!(&unique_items, Some(Feature::UniqueItems(unique_items)) if unique_items.is_true());
 
         // is octet-stream
-        let schema = if child
+        let is_binary = child
             .path
             .as_ref()
             .map(|path| SchemaType(path).is_byte())
-            .unwrap_or(false)
-        {
+            .unwrap_or(false);
+        // fixed size arrays have exactly as many items as their length
+        let array_len = type_tree.array_len.filter(|_| !is_binary);
+
+        let schema = if is_binary {
             quote! {
                 utoipa::openapi::ObjectBuilder::new()
                     .schema_type(utoipa::openapi::schema::SchemaType::String)
@@ -767,12 +981,6 @@ impl<'c> ComponentSchema {
             }
         };
 
-        let validate = |feature: &Feature| {
-            let type_path = &**type_tree.path.as_ref().unwrap();
-            let schema_type = SchemaType(type_path);
-            feature.validate(&schema_type, type_tree);
-        };
-
         tokens.extend(quote! {
             #schema
             #deprecated_stream
@@ -780,13 +988,15 @@ impl<'c> ComponentSchema {
         });
 
         if let Some(max_items) = max_items {
-            validate(&max_items);
             tokens.extend(max_items.to_token_stream())
+        } else if let Some(len) = array_len {
+            tokens.extend(quote! { .max_items(Some(#len)) })
         }
 
         if let Some(min_items) = min_items {
-            validate(&min_items);
             tokens.extend(min_items.to_token_stream())
+        } else if let Some(len) = array_len {
+            tokens.extend(quote! { .min_items(Some(#len)) })
         }
 
         if let Some(default) = default {
@@ -796,6 +1006,8 @@ impl<'c> ComponentSchema {
         example.to_tokens(tokens);
         xml.to_tokens(tokens);
         nullable.to_tokens(tokens);
+        read_only.to_tokens(tokens);
+        write_only.to_tokens(tokens);
 
         Ok(())
     }
@@ -808,12 +1020,33 @@ impl<'c> ComponentSchema {
         description_stream: Option<TokenStream>,
         deprecated_stream: Option<TokenStream>,
     ) {
-        let nullable = pop_feature!(features => Feature::Nullable(_));
+        // `nullable = false` is same as not nullable, thus references need not be wrapped in `allOf`
+        let nullable = pop_feature!(features => Feature::Nullable(_))
+            .filter(|feature| !matches!(feature, Feature::Nullable(nullable) if !nullable.value()));
 
         match type_tree.value_type {
             ValueType::Primitive => {
                 let type_path = &**type_tree.path.as_ref().unwrap();
                 let schema_type = SchemaType(type_path);
+                // only `enum_values` and `array` features are enforced, other validatable features
+                // are not validated against the type to keep existing attributes compiling
+                let diagnostics = features
+                    .iter()
+                    .filter(|feature| {
+                        matches!(feature, Feature::EnumValues(_)) || is_array_feature(feature)
+                    })
+                    .filter_map(|feature| feature.validate(&schema_type, type_tree))
+                    .collect::<Option<Diagnostics>>();
+                if let Some(diagnostics) = diagnostics {
+                    tokens.extend(quote! {
+                        {
+                            #diagnostics
+                            utoipa::openapi::ObjectBuilder::new()
+                        }
+                    });
+                    return;
+                }
+
                 if schema_type.is_unsigned_integer() {
                     // add default minimum feature only when there is no explicit minimum
                     // provided
@@ -821,7 +1054,12 @@ impl<'c> ComponentSchema {
                         .iter()
                         .any(|feature| matches!(&feature, Feature::Minimum(_)))
                     {
-                        features.push(Minimum::new(0f64, type_path.span()).into());
+                        let minimum = if schema_type.is_non_zero_integer() {
+                            1f64
+                        } else {
+                            0f64
+                        };
+                        features.push(Minimum::new(minimum, type_path.span()).into());
                     }
                 }
 
@@ -838,9 +1076,6 @@ impl<'c> ComponentSchema {
 
                 tokens.extend(description_stream);
                 tokens.extend(deprecated_stream);
-                for feature in features.iter().filter(|feature| feature.is_validatable()) {
-                    feature.validate(&schema_type, type_tree);
-                }
                 tokens.extend(features.to_token_stream());
                 nullable.to_tokens(tokens);
             }
@@ -854,7 +1089,25 @@ impl<'c> ComponentSchema {
                 }
             }
             ValueType::Object => {
+                let diagnostics = type_tree.path.as_deref().and_then(|type_path| {
+                    features
+                        .iter()
+                        .filter(|feature| is_array_feature(feature))
+                        .filter_map(|feature| feature.validate(&SchemaType(type_path), type_tree))
+                        .collect::<Option<Diagnostics>>()
+                });
+                if let Some(diagnostics) = diagnostics {
+                    tokens.extend(quote! {
+                        {
+                            #diagnostics
+                            utoipa::openapi::ObjectBuilder::new()
+                        }
+                    });
+                    return;
+                }
+
                 let is_inline = features.is_inline();
+                let schema_fn = features.inline_schema_fn();
 
                 if type_tree.is_object() {
                     tokens.extend(quote! {
@@ -863,36 +1116,83 @@ impl<'c> ComponentSchema {
                     })
                 } else {
                     let type_path = &**type_tree.path.as_ref().unwrap();
-                    if is_inline {
+                    let mut name = Cow::Owned(type_tree.reference_name().unwrap_or_default());
+                    if name == "Self" && !object_name.is_empty() {
+                        name = Cow::Borrowed(object_name);
+                    }
+                    // inlining self referencing type would recurse infinitely, thus it is always
+                    // referenced instead
+                    let is_self_reference = !object_name.is_empty() && name == object_name;
+
+                    if is_inline && !is_self_reference {
+                        let inline_schema = if type_tree.is_generic_object() {
+                            let generics = type_tree.generic_arguments_tokens(object_name);
+                            quote_spanned! {type_path.span()=>
+                                <#type_path as utoipa::ToSchema>::generic_schema(#generics)
+                            }
+                        } else {
+                            quote_spanned! {type_path.span()=>
+                                <#type_path as utoipa::ToSchema>::#schema_fn().1
+                            }
+                        };
                         let default = pop_feature!(features => Feature::Default(_));
-                        let schema = if default.is_some() || nullable.is_some() {
+                        let example = pop_feature!(features => Feature::Example(_));
+                        let read_only = pop_feature!(features => Feature::ReadOnly(_));
+                        let write_only = pop_feature!(features => Feature::WriteOnly(_));
+                        let xml = pop_feature!(features => Feature::XmlAttr(_));
+                        let schema = if default.is_some()
+                            || example.is_some()
+                            || nullable.is_some()
+                            || read_only.is_some()
+                            || write_only.is_some()
+                            || xml.is_some()
+                            || description_stream.is_some()
+                            || deprecated_stream.is_some()
+                        {
                             quote_spanned! {type_path.span()=>
                                 utoipa::openapi::schema::AllOfBuilder::new()
                                     #nullable
-                                    .item(<#type_path as utoipa::ToSchema>::schema().1)
+                                    .item(#inline_schema)
+                                    #description_stream
                                     #default
+                                    #example
+                                    #deprecated_stream
+                                    #read_only
+                                    #write_only
+                                    #xml
                             }
                         } else {
-                            quote_spanned! {type_path.span() =>
-                                <#type_path as utoipa::ToSchema>::schema().1
-                            }
+                            inline_schema
                         };
 
                         schema.to_tokens(tokens);
                     } else {
-                        let mut name = Cow::Owned(format_path_ref(type_path));
-                        if name == "Self" && !object_name.is_empty() {
-                            name = Cow::Borrowed(object_name);
-                        }
-
                         let default = pop_feature!(features => Feature::Default(_));
-
-                        let schema = if default.is_some() || nullable.is_some() {
+                        let example = pop_feature!(features => Feature::Example(_));
+                        let read_only = pop_feature!(features => Feature::ReadOnly(_));
+                        let write_only = pop_feature!(features => Feature::WriteOnly(_));
+                        let xml = pop_feature!(features => Feature::XmlAttr(_));
+
+                        let schema = if default.is_some()
+                            || example.is_some()
+                            || nullable.is_some()
+                            || read_only.is_some()
+                            || write_only.is_some()
+                            || xml.is_some()
+                            || description_stream.is_some()
+                            || deprecated_stream.is_some()
+                        {
                             quote! {
                                 utoipa::openapi::schema::AllOfBuilder::new()
                                     #nullable
                                     .item(utoipa::openapi::Ref::from_schema_name(#name))
+                                    #description_stream
                                     #default
+                                    #example
+                                    #deprecated_stream
+                                    #read_only
+                                    #write_only
+                                    #xml
                             }
                         } else {
                             quote! {
@@ -909,30 +1209,49 @@ impl<'c> ComponentSchema {
                     .children
                     .as_ref()
                     .map(|children| {
-                        let all_of = children.iter().fold(
-                            quote! { utoipa::openapi::schema::AllOfBuilder::new() },
-                            |mut all_of, child| {
-                                let features = if child.is_option() {
-                                    Some(vec![Feature::Nullable(Nullable::new())])
-                                } else {
-                                    None
-                                };
-
-                                let item = ComponentSchema::new(ComponentSchemaProps {
-                                    type_tree: child,
-                                    features,
-                                    description: None,
-                                    deprecated: None,
-                                    object_name,
-                                });
-                                all_of.extend(quote!( .item(#item) ));
-
-                                all_of
-                            },
-                        );
+                        let item_schema = |child: &TypeTree| {
+                            let features = if child.is_option() {
+                                Some(vec![Feature::Nullable(Nullable::new())])
+                            } else {
+                                None
+                            };
+
+                            ComponentSchema::new(ComponentSchemaProps {
+                                type_tree: child,
+                                features,
+                                description: None,
+                                deprecated: None,
+                                object_name,
+                            })
+                        };
+
+                        // serde serializes tuples as arrays of fixed length, OpenAPI 3.1 describes
+                        // the items by position and OpenAPI 3.0 with an union of the item types
+                        let mut unique_children = Vec::<&TypeTree>::new();
+                        for child in children {
+                            if !unique_children.contains(&child) {
+                                unique_children.push(child);
+                            }
+                        }
+                        let items = match unique_children.as_slice() {
+                            [child] => item_schema(child).to_token_stream(),
+                            unique_children => {
+                                let items = unique_children.iter().map(|child| item_schema(child));
+                                quote! {
+                                    utoipa::openapi::schema::OneOfBuilder::new()
+                                        #( .item(#items) )*
+                                }
+                            }
+                        };
+                        let prefix_items = children.iter().map(item_schema);
+                        let len = children.len();
+
                         quote! {
                             utoipa::openapi::schema::ArrayBuilder::new()
-                                .items(#all_of)
+                                .items(#items)
+                                #( .prefix_item(#prefix_items) )*
+                                .min_items(Some(#len))
+                                .max_items(Some(#len))
                                 #nullable
                                 #description_stream
                                 #deprecated_stream
@@ -969,6 +1288,83 @@ impl ToTokens for ComponentSchema {
     }
 }
 
+/// Ad-hoc inline object schema e.g. _`{ id: i64, name: String }`_ which does not require
+/// a named type deriving `ToSchema`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct InlineObject {
+    fields: Punctuated<InlineObjectField, Comma>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct InlineObjectField {
+    name: String,
+    value: InlineObjectValue,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum InlineObjectValue {
+    Object(InlineObject),
+    Type(Type),
+}
+
+impl Parse for InlineObject {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        braced!(content in input);
+
+        Ok(Self {
+            fields: Punctuated::parse_terminated(&content)?,
+        })
+    }
+}
+
+impl Parse for InlineObjectField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.call(Ident::parse_any)?.unraw().to_string();
+        input.parse::<Token![:]>()?;
+        let value = if input.peek(Brace) {
+            InlineObjectValue::Object(input.parse()?)
+        } else {
+            InlineObjectValue::Type(input.parse()?)
+        };
+
+        Ok(Self { name, value })
+    }
+}
+
+impl crate::ToTokensDiagnostics for InlineObject {
+    fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
+        let mut object = quote! { utoipa::openapi::ObjectBuilder::new() };
+
+        for InlineObjectField { name, value } in &self.fields {
+            match value {
+                InlineObjectValue::Object(inline_object) => {
+                    let schema = crate::ToTokensDiagnostics::to_token_stream(inline_object);
+                    object.extend(quote! { .property(#name, #schema).required(#name) });
+                }
+                InlineObjectValue::Type(ty) => {
+                    let type_tree = TypeTree::from_type(ty)?;
+                    let schema = ComponentSchema::new(ComponentSchemaProps {
+                        type_tree: &type_tree,
+                        features: None,
+                        description: None,
+                        deprecated: None,
+                        object_name: "",
+                    });
+                    object.extend(quote! { .property(#name, #schema) });
+                    if !type_tree.is_option() {
+                        object.extend(quote! { .required(#name) });
+                    }
+                }
+            }
+        }
+
+        tokens.extend(object);
+
+        Ok(())
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct FlattenedMapSchema {
     tokens: TokenStream,
@@ -2,13 +2,16 @@ use std::{fmt::Display, mem, str::FromStr};
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{parenthesized, parse::ParseStream, LitFloat, LitInt, LitStr, TypePath};
+use syn::{
+    bracketed, parenthesized, parse::ParseStream, punctuated::Punctuated, token::Comma, Lit,
+    LitFloat, LitInt, LitStr, Token, TypePath,
+};
 
 use crate::{
     impl_to_tokens_diagnostics, parse_utils,
     path::parameter::{self, ParameterStyle},
     schema_type::{SchemaFormat, SchemaType},
-    AnyValue, Diagnostics, OptionExt,
+    AnyValue, Array, Diagnostics, OptionExt,
 };
 
 use super::{schema, serde::RenameRule, GenericType, TypeTree};
@@ -62,14 +65,7 @@ macro_rules! name {
     };
 }
 
-/// Define whether [`Feature`] variant is validatable or not
-pub trait Validatable {
-    fn is_validatable(&self) -> bool {
-        false
-    }
-}
-
-pub trait Validate: Validatable {
+pub trait Validate {
     /// Perform validation check against schema type.
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics>;
 }
@@ -84,6 +80,7 @@ pub trait Parse {
 #[derive(Clone)]
 pub enum Feature {
     Example(Example),
+    Examples(Examples),
     Default(Default),
     Inline(Inline),
     XmlAttr(XmlAttr),
@@ -108,8 +105,10 @@ pub enum Feature {
     MaxLength(MaxLength),
     MinLength(MinLength),
     Pattern(Pattern),
+    EnumValues(EnumValues),
     MaxItems(MaxItems),
     MinItems(MinItems),
+    UniqueItems(UniqueItems),
     MaxProperties(MaxProperties),
     MinProperties(MinProperties),
     SchemaWith(SchemaWith),
@@ -118,6 +117,10 @@ pub enum Feature {
     As(As),
     AdditionalProperties(AdditionalProperties),
     Required(Required),
+    ExternalDocs(ExternalDocs),
+    Extensions(Extensions),
+    Form(Form),
+    Flatten(Flatten),
 }
 
 impl Feature {
@@ -141,14 +144,16 @@ impl Feature {
                 ValidatorChain::new(&IsString(schema_type)).next(&AboveZeroUsize(min_length.0)),
             ),
             Feature::Pattern(pattern) => pattern.validate(IsString(schema_type)),
-            Feature::MaxItems(max_items) => max_items.validate(
-                ValidatorChain::new(&AboveZeroUsize(max_items.0)).next(&IsVec(type_tree)),
-            ),
-            Feature::MinItems(min_items) => min_items.validate(
-                ValidatorChain::new(&AboveZeroUsize(min_items.0)).next(&IsVec(type_tree)),
-            ),
+            Feature::EnumValues(enum_values) => match enum_values.kind() {
+                EnumValueKind::String => enum_values.validate(IsString(schema_type)),
+                EnumValueKind::Integer => enum_values.validate(IsNumber(schema_type)),
+                EnumValueKind::Float => enum_values.validate(IsFloat(schema_type)),
+            },
+            Feature::MaxItems(max_items) => max_items.validate(IsVec(type_tree)),
+            Feature::MinItems(min_items) => min_items.validate(IsVec(type_tree)),
+            Feature::UniqueItems(unique_items) => unique_items.validate(IsVec(type_tree)),
             _unsupported_variant => {
-                const SUPPORTED_VARIANTS: [&str; 10] = [
+                const SUPPORTED_VARIANTS: [&str; 12] = [
                     "multiple_of",
                     "maximum",
                     "minimum",
@@ -157,8 +162,10 @@ impl Feature {
                     "max_length",
                     "min_length",
                     "pattern",
+                    "enum_values",
                     "max_items",
                     "min_items",
+                    "unique_items",
                 ];
                 panic!(
                     "Unsupported variant: `{variant}` for Validate::validate, expected one of: {variants}",
@@ -173,6 +180,7 @@ impl Feature {
         let feature = match &self {
                 Feature::Default(default) => quote! { .default(#default) },
                 Feature::Example(example) => quote! { .example(Some(#example)) },
+                Feature::Examples(examples) => quote! { .examples_from_iter(#examples) },
                 Feature::XmlAttr(xml) => quote! { .xml(Some(#xml)) },
                 Feature::Format(format) => quote! { .format(Some(#format)) },
                 Feature::WriteOnly(write_only) => quote! { .write_only(Some(#write_only)) },
@@ -198,8 +206,10 @@ impl Feature {
                 Feature::MaxLength(max_length) => quote! { .max_length(Some(#max_length)) },
                 Feature::MinLength(min_length) => quote! { .min_length(Some(#min_length)) },
                 Feature::Pattern(pattern) => quote! { .pattern(Some(#pattern)) },
+                Feature::EnumValues(enum_values) => quote! { .enum_values(Some(#enum_values)) },
                 Feature::MaxItems(max_items) => quote! { .max_items(Some(#max_items)) },
                 Feature::MinItems(min_items) => quote! { .min_items(Some(#min_items)) },
+                Feature::UniqueItems(unique_items) => quote! { .unique_items(#unique_items) },
                 Feature::MaxProperties(max_properties) => {
                     quote! { .max_properties(Some(#max_properties)) }
                 }
@@ -223,6 +233,14 @@ impl Feature {
                     // inline feature is ignored by `ToTokens`
                     TokenStream::new()
                 }
+                Feature::Form(_) => {
+                    // form feature is ignored by `ToTokens`
+                    TokenStream::new()
+                }
+                Feature::Flatten(_) => {
+                    // flatten feature is ignored by `ToTokens`
+                    TokenStream::new()
+                }
                 Feature::IntoParamsNames(_) => {
                     return Err(Diagnostics::new("Names feature does not support `ToTokens`")
                         .help("Names is only used with IntoParams to artificially give names for unnamed struct type `IntoParams`."))
@@ -234,6 +252,10 @@ impl Feature {
                     let name = <Required as Name>::get_name();
                     quote! { .#name(#required) }
                 }
+                Feature::ExternalDocs(external_docs) => {
+                    quote! { .external_docs(Some(#external_docs)) }
+                }
+                Feature::Extensions(extensions) => quote! { .extensions(Some(#extensions)) },
             };
 
         tokens.extend(feature);
@@ -255,6 +277,7 @@ impl Display for Feature {
         match self {
             Feature::Default(default) => default.fmt(f),
             Feature::Example(example) => example.fmt(f),
+            Feature::Examples(examples) => examples.fmt(f),
             Feature::XmlAttr(xml) => xml.fmt(f),
             Feature::Format(format) => format.fmt(f),
             Feature::WriteOnly(write_only) => write_only.fmt(f),
@@ -278,8 +301,10 @@ impl Display for Feature {
             Feature::MaxLength(max_length) => max_length.fmt(f),
             Feature::MinLength(min_length) => min_length.fmt(f),
             Feature::Pattern(pattern) => pattern.fmt(f),
+            Feature::EnumValues(enum_values) => enum_values.fmt(f),
             Feature::MaxItems(max_items) => max_items.fmt(f),
             Feature::MinItems(min_items) => min_items.fmt(f),
+            Feature::UniqueItems(unique_items) => unique_items.fmt(f),
             Feature::MaxProperties(max_properties) => max_properties.fmt(f),
             Feature::MinProperties(min_properties) => min_properties.fmt(f),
             Feature::SchemaWith(schema_with) => schema_with.fmt(f),
@@ -288,104 +313,14 @@ impl Display for Feature {
             Feature::As(as_feature) => as_feature.fmt(f),
             Feature::AdditionalProperties(additional_properties) => additional_properties.fmt(f),
             Feature::Required(required) => required.fmt(f),
+            Feature::ExternalDocs(external_docs) => external_docs.fmt(f),
+            Feature::Extensions(extensions) => extensions.fmt(f),
+            Feature::Form(form) => form.fmt(f),
+            Feature::Flatten(flatten) => flatten.fmt(f),
         }
     }
 }
 
-impl Validatable for Feature {
-    fn is_validatable(&self) -> bool {
-        match &self {
-            Feature::Default(default) => default.is_validatable(),
-            Feature::Example(example) => example.is_validatable(),
-            Feature::XmlAttr(xml) => xml.is_validatable(),
-            Feature::Format(format) => format.is_validatable(),
-            Feature::WriteOnly(write_only) => write_only.is_validatable(),
-            Feature::ReadOnly(read_only) => read_only.is_validatable(),
-            Feature::Title(title) => title.is_validatable(),
-            Feature::Nullable(nullable) => nullable.is_validatable(),
-            Feature::Rename(rename) => rename.is_validatable(),
-            Feature::Style(style) => style.is_validatable(),
-            Feature::ParameterIn(parameter_in) => parameter_in.is_validatable(),
-            Feature::AllowReserved(allow_reserved) => allow_reserved.is_validatable(),
-            Feature::Explode(explode) => explode.is_validatable(),
-            Feature::RenameAll(rename_all) => rename_all.is_validatable(),
-            Feature::ValueType(value_type) => value_type.is_validatable(),
-            Feature::Inline(inline) => inline.is_validatable(),
-            Feature::IntoParamsNames(names) => names.is_validatable(),
-            Feature::MultipleOf(multiple_of) => multiple_of.is_validatable(),
-            Feature::Maximum(maximum) => maximum.is_validatable(),
-            Feature::Minimum(minimum) => minimum.is_validatable(),
-            Feature::ExclusiveMaximum(exclusive_maximum) => exclusive_maximum.is_validatable(),
-            Feature::ExclusiveMinimum(exclusive_minimum) => exclusive_minimum.is_validatable(),
-            Feature::MaxLength(max_length) => max_length.is_validatable(),
-            Feature::MinLength(min_length) => min_length.is_validatable(),
-            Feature::Pattern(pattern) => pattern.is_validatable(),
-            Feature::MaxItems(max_items) => max_items.is_validatable(),
-            Feature::MinItems(min_items) => min_items.is_validatable(),
-            Feature::MaxProperties(max_properties) => max_properties.is_validatable(),
-            Feature::MinProperties(min_properties) => min_properties.is_validatable(),
-            Feature::SchemaWith(schema_with) => schema_with.is_validatable(),
-            Feature::Description(description) => description.is_validatable(),
-            Feature::Deprecated(deprecated) => deprecated.is_validatable(),
-            Feature::As(as_feature) => as_feature.is_validatable(),
-            Feature::AdditionalProperties(additional_properties) => {
-                additional_properties.is_validatable()
-            }
-            Feature::Required(required) => required.is_validatable(),
-        }
-    }
-}
-
-macro_rules! is_validatable {
-    ( $( $ident:ident => $validatable:literal ),* ) => {
-        $(
-            impl Validatable for $ident {
-                fn is_validatable(&self) -> bool {
-                    $validatable
-                }
-            }
-        )*
-    };
-}
-
-is_validatable! {
-    Default => false,
-    Example => false,
-    XmlAttr => false,
-    Format => false,
-    WriteOnly => false,
-    ReadOnly => false,
-    Title => false,
-    Nullable => false,
-    Rename => false,
-    Style => false,
-    ParameterIn => false,
-    AllowReserved => false,
-    Explode => false,
-    RenameAll => false,
-    ValueType => false,
-    Inline => false,
-    Names => false,
-    MultipleOf => true,
-    Maximum => true,
-    Minimum => true,
-    ExclusiveMaximum => true,
-    ExclusiveMinimum => true,
-    MaxLength => true,
-    MinLength => true,
-    Pattern => true,
-    MaxItems => true,
-    MinItems => true,
-    MaxProperties => false,
-    MinProperties => false,
-    SchemaWith => false,
-    Description => false,
-    Deprecated => false,
-    As => false,
-    AdditionalProperties => false,
-    Required => false
-}
-
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Example(AnyValue);
@@ -410,6 +345,38 @@ impl From<Example> for Feature {
 
 name!(Example = "example");
 
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Examples(Punctuated<crate::path::example::Example, Comma>);
+
+impl Parse for Examples {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        crate::path::parse::examples(input).map(Self)
+    }
+}
+
+impl ToTokens for Examples {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let examples = self
+            .0
+            .iter()
+            .map(|example| {
+                let name = &example.name;
+                quote!((#name, #example))
+            })
+            .collect::<Array<TokenStream>>();
+        examples.to_tokens(tokens)
+    }
+}
+
+impl From<Examples> for Feature {
+    fn from(value: Examples) -> Self {
+        Feature::Examples(value)
+    }
+}
+
+name!(Examples = "examples");
+
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Default(pub(crate) Option<AnyValue>);
@@ -418,6 +385,10 @@ impl Default {
     pub fn new_default_trait(struct_ident: Ident, field_ident: syn::Member) -> Self {
         Self(Some(AnyValue::new_default_trait(struct_ident, field_ident)))
     }
+
+    pub fn new_default_fn(default_fn: proc_macro2::TokenStream) -> Self {
+        Self(Some(AnyValue::new_default_fn(default_fn)))
+    }
 }
 
 impl Parse for Default {
@@ -447,19 +418,54 @@ impl From<self::Default> for Feature {
 
 name!(Default = "default");
 
+/// Schema of the inlined type, either the full schema or the request or response flavor
+/// omitting `read_only` or `write_only` properties.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum InlineFlavor {
+    #[default]
+    Schema,
+    Request,
+    Response,
+}
+
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Inline(bool);
+pub struct Inline(bool, InlineFlavor);
+
+impl Inline {
+    /// Inline the request flavor of the schema when `value` is `true`.
+    pub fn request(value: bool) -> Self {
+        Self(value, InlineFlavor::Request)
+    }
+
+    /// Inline the response flavor of the schema when `value` is `true`.
+    pub fn response(value: bool) -> Self {
+        Self(value, InlineFlavor::Response)
+    }
+
+    /// Name of the [`ToSchema`][to_schema] method returning the inlined schema.
+    ///
+    /// [to_schema]: https://docs.rs/utoipa/latest/utoipa/trait.ToSchema.html
+    pub fn schema_fn(&self) -> Ident {
+        let schema_fn = match self.1 {
+            InlineFlavor::Schema => "schema",
+            InlineFlavor::Request => "request_schema",
+            InlineFlavor::Response => "response_schema",
+        };
+        Ident::new(schema_fn, Span::call_site())
+    }
+}
 
 impl Parse for Inline {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_bool_or_true(input).map(Self)
+        parse_utils::parse_bool_or_true(input).map(Self::from)
     }
 }
 
 impl From<bool> for Inline {
     fn from(value: bool) -> Self {
-        Inline(value)
+        Inline(value, InlineFlavor::default())
     }
 }
 
@@ -477,7 +483,8 @@ pub struct XmlAttr(schema::xml::XmlAttr);
 
 impl XmlAttr {
     /// Split [`XmlAttr`] for [`GenericType::Vec`] returning tuple of [`XmlAttr`]s where first
-    /// one is for a vec and second one is for object field.
+    /// one is for a vec and second one is for object field. Object field [`XmlAttr`] is omitted
+    /// if it does not define any attributes.
     pub fn split_for_vec(
         &mut self,
         type_tree: &TypeTree,
@@ -489,7 +496,10 @@ impl XmlAttr {
                 mem::take(&mut value_xml.0.wrap_name),
             );
 
-            Ok((Some(XmlAttr(vec_xml)), Some(value_xml)))
+            Ok((
+                Some(XmlAttr(vec_xml)),
+                Some(value_xml).filter(|value_xml| !value_xml.0.is_empty()),
+            ))
         } else {
             self.validate_xml(&self.0)?;
 
@@ -537,6 +547,13 @@ name!(XmlAttr = "xml");
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Format(SchemaFormat<'static>);
 
+#[cfg(any(feature = "validator", feature = "serde_with"))]
+impl Format {
+    pub fn new(format: SchemaFormat<'static>) -> Self {
+        Self(format)
+    }
+}
+
 impl Parse for Format {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
         parse_utils::parse_next(input, || input.parse::<SchemaFormat>()).map(Self)
@@ -662,6 +679,10 @@ impl Nullable {
     pub fn new() -> Self {
         Self(true)
     }
+
+    pub fn value(&self) -> bool {
+        self.0
+    }
 }
 
 impl Parse for Nullable {
@@ -746,17 +767,45 @@ name!(RenameAll = "rename_all");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct Style(ParameterStyle);
+pub struct Style(ParameterStyle, Ident);
+
+impl Style {
+    /// Check that the style is allowed for given [`parameter::ParameterIn`] as defined in
+    /// OpenAPI specification, e.g. _`DeepObject`_ is only allowed for query parameters.
+    pub fn validate_parameter_in(&self, parameter_in: &parameter::ParameterIn) -> syn::Result<()> {
+        let allowed = self.0.allowed_parameter_in();
+        if allowed.contains(parameter_in) {
+            Ok(())
+        } else {
+            let allowed = allowed
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(syn::Error::new(
+                self.1.span(),
+                format!(
+                    "style: `{}` is not allowed with parameter in: `{parameter_in}`, expected parameter in one of: {allowed}",
+                    self.0
+                ),
+            ))
+        }
+    }
 
-impl From<ParameterStyle> for Style {
-    fn from(style: ParameterStyle) -> Self {
-        Self(style)
+    /// Get default [`Explode`] for the style. By OpenAPI specification _`Form`_ style is
+    /// exploded by default and all other styles are not.
+    pub fn default_explode(&self) -> Explode {
+        Explode(matches!(self.0, ParameterStyle::Form))
     }
 }
 
 impl Parse for Style {
-    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_next(input, || input.parse::<ParameterStyle>().map(Self))
+    fn parse(input: syn::parse::ParseStream, ident: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || {
+            input
+                .parse::<ParameterStyle>()
+                .map(|style| Self(style, ident))
+        })
     }
 }
 
@@ -776,11 +825,28 @@ name!(Style = "style");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct AllowReserved(bool);
+pub struct AllowReserved(bool, Ident);
+
+impl AllowReserved {
+    /// Check that reserved characters are only allowed for query parameters as defined in
+    /// OpenAPI specification.
+    pub fn validate_parameter_in(&self, parameter_in: &parameter::ParameterIn) -> syn::Result<()> {
+        if !self.0 || *parameter_in == parameter::ParameterIn::Query {
+            Ok(())
+        } else {
+            Err(syn::Error::new(
+                self.1.span(),
+                format!(
+                    "allow_reserved is not allowed with parameter in: `{parameter_in}`, expected parameter in: `Query`"
+                ),
+            ))
+        }
+    }
+}
 
 impl Parse for AllowReserved {
-    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_bool_or_true(input).map(Self)
+    fn parse(input: syn::parse::ParseStream, ident: Ident) -> syn::Result<Self> {
+        Ok(Self(parse_utils::parse_bool_or_true(input)?, ident))
     }
 }
 
@@ -844,6 +910,12 @@ impl From<ParameterIn> for Feature {
     }
 }
 
+impl ParameterIn {
+    pub fn as_parameter_in(&self) -> &parameter::ParameterIn {
+        &self.0
+    }
+}
+
 name!(ParameterIn = "parameter_in");
 
 /// Specify names of unnamed fields with `names(...) attribute for `IntoParams` derive.
@@ -878,12 +950,12 @@ name!(Names = "names");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MultipleOf(f64, Ident);
+pub struct MultipleOf(f64, Span);
 
 impl Validate for MultipleOf {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!( "`multiple_of` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!( "`multiple_of` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-multipleof`")),
             _ => None
         }
@@ -892,7 +964,7 @@ impl Validate for MultipleOf {
 
 impl Parse for MultipleOf {
     fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self> {
-        parse_number(input).map(|multiple_of| Self(multiple_of, ident))
+        parse_number(input).map(|multiple_of| Self(multiple_of, ident.span()))
     }
 }
 
@@ -912,12 +984,19 @@ name!(MultipleOf = "multiple_of");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct Maximum(f64, Ident);
+pub struct Maximum(f64, Span);
+
+#[cfg(feature = "validator")]
+impl Maximum {
+    pub fn new(value: f64, span: Span) -> Self {
+        Self(value, span)
+    }
+}
 
 impl Validate for Maximum {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`maximum` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`maximum` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-maximum`")),
             _ => None,
         }
@@ -929,7 +1008,7 @@ impl Parse for Maximum {
     where
         Self: Sized,
     {
-        parse_number(input).map(|maximum| Self(maximum, ident))
+        parse_number(input).map(|maximum| Self(maximum, ident.span()))
     }
 }
 
@@ -949,11 +1028,11 @@ name!(Maximum = "maximum");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct Minimum(f64, Ident);
+pub struct Minimum(f64, Span);
 
 impl Minimum {
     pub fn new(value: f64, span: Span) -> Self {
-        Self(value, Ident::new("empty", span))
+        Self(value, span)
     }
 }
 
@@ -961,7 +1040,7 @@ impl Validate for Minimum {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
             Err(error) => Some(
-                Diagnostics::with_span(self.1.span(), format!("`minimum` error: {}", error))
+                Diagnostics::with_span(self.1, format!("`minimum` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-minimum`")
             ),
             _ => None,
@@ -974,7 +1053,7 @@ impl Parse for Minimum {
     where
         Self: Sized,
     {
-        parse_number(input).map(|maximum| Self(maximum, ident))
+        parse_number(input).map(|maximum| Self(maximum, ident.span()))
     }
 }
 
@@ -994,12 +1073,19 @@ name!(Minimum = "minimum");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct ExclusiveMaximum(f64, Ident);
+pub struct ExclusiveMaximum(f64, Span);
+
+#[cfg(feature = "validator")]
+impl ExclusiveMaximum {
+    pub fn new(value: f64, span: Span) -> Self {
+        Self(value, span)
+    }
+}
 
 impl Validate for ExclusiveMaximum {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`exclusive_maximum` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`exclusive_maximum` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-exclusivemaximum`")),
             _ => None,
         }
@@ -1011,7 +1097,7 @@ impl Parse for ExclusiveMaximum {
     where
         Self: Sized,
     {
-        parse_number(input).map(|max| Self(max, ident))
+        parse_number(input).map(|max| Self(max, ident.span()))
     }
 }
 
@@ -1031,12 +1117,19 @@ name!(ExclusiveMaximum = "exclusive_maximum");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct ExclusiveMinimum(f64, Ident);
+pub struct ExclusiveMinimum(f64, Span);
+
+#[cfg(feature = "validator")]
+impl ExclusiveMinimum {
+    pub fn new(value: f64, span: Span) -> Self {
+        Self(value, span)
+    }
+}
 
 impl Validate for ExclusiveMinimum {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`exclusive_minimum` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`exclusive_minimum` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-exclusiveminimum`")),
             _ => None,
         }
@@ -1048,7 +1141,7 @@ impl Parse for ExclusiveMinimum {
     where
         Self: Sized,
     {
-        parse_number(input).map(|min| Self(min, ident))
+        parse_number(input).map(|min| Self(min, ident.span()))
     }
 }
 
@@ -1068,12 +1161,19 @@ name!(ExclusiveMinimum = "exclusive_minimum");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MaxLength(usize, Ident);
+pub struct MaxLength(usize, Span);
+
+#[cfg(feature = "validator")]
+impl MaxLength {
+    pub fn new(value: usize, span: Span) -> Self {
+        Self(value, span)
+    }
+}
 
 impl Validate for MaxLength {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`max_length` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`max_length` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-maxlength`")),
             _ => None,
         }
@@ -1085,7 +1185,7 @@ impl Parse for MaxLength {
     where
         Self: Sized,
     {
-        parse_integer(input).map(|max_length| Self(max_length, ident))
+        parse_integer(input).map(|max_length| Self(max_length, ident.span()))
     }
 }
 
@@ -1105,12 +1205,19 @@ name!(MaxLength = "max_length");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MinLength(usize, Ident);
+pub struct MinLength(usize, Span);
+
+#[cfg(feature = "validator")]
+impl MinLength {
+    pub fn new(value: usize, span: Span) -> Self {
+        Self(value, span)
+    }
+}
 
 impl Validate for MinLength {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`min_length` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`min_length` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-minlength`")),
             _ => None,
         }
@@ -1122,7 +1229,7 @@ impl Parse for MinLength {
     where
         Self: Sized,
     {
-        parse_integer(input).map(|max_length| Self(max_length, ident))
+        parse_integer(input).map(|max_length| Self(max_length, ident.span()))
     }
 }
 
@@ -1142,12 +1249,21 @@ name!(MinLength = "min_length");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct Pattern(String, Ident);
+pub struct Pattern(TokenStream, Span);
+
+#[cfg(feature = "validator")]
+impl Pattern {
+    /// Construct new [`Pattern`] from expression resolving to a regex value with `as_str()` method
+    /// e.g. `regex::Regex`.
+    pub fn new_regex(regex: &syn::Expr, span: Span) -> Self {
+        Self(quote! { (#regex).as_str() }, span)
+    }
+}
 
 impl Validate for Pattern {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`pattern` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`pattern` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-pattern`")
             ),
             _ => None,
@@ -1161,7 +1277,7 @@ impl Parse for Pattern {
         Self: Sized,
     {
         parse_utils::parse_next(input, || input.parse::<LitStr>())
-            .map(|pattern| Self(pattern.value(), ident))
+            .map(|pattern| Self(pattern.to_token_stream(), ident.span()))
     }
 }
 
@@ -1179,14 +1295,117 @@ impl From<Pattern> for Feature {
 
 name!(Pattern = "pattern");
 
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EnumValueKind {
+    String,
+    Integer,
+    Float,
+}
+
+impl EnumValueKind {
+    fn from_lit(lit: &Lit) -> Option<Self> {
+        match lit {
+            Lit::Str(_) => Some(Self::String),
+            Lit::Int(_) => Some(Self::Integer),
+            Lit::Float(_) => Some(Self::Float),
+            _ => None,
+        }
+    }
+}
+
+/// Restricts value of a field to given list of literal values, e.g.
+/// `enum_values = ["asc", "desc"]` or `enum_values = [1, 2, 3]`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct EnumValues(Vec<Lit>, EnumValueKind, Span);
+
+impl EnumValues {
+    pub fn kind(&self) -> EnumValueKind {
+        self.1
+    }
+}
+
+impl Validate for EnumValues {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.2, format!("`enum_values` error: {}", error))
+                .help("Use string literals with `String` types and number literals with number types")
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-enum`")
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for EnumValues {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        let values = parse_utils::parse_next(input, || {
+            let content;
+            bracketed!(content in input);
+            Punctuated::<Lit, Token![,]>::parse_terminated(&content)
+        })?;
+
+        let mut kind: Option<EnumValueKind> = None;
+        for value in &values {
+            let value_kind = EnumValueKind::from_lit(value).ok_or_else(|| {
+                syn::Error::new(
+                    value.span(),
+                    "unexpected literal, expected string, integer or float literal",
+                )
+            })?;
+            match kind {
+                Some(kind) if kind != value_kind => {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "mixed literal types in `enum_values`, all values must be of same type",
+                    ))
+                }
+                _ => kind = Some(value_kind),
+            }
+        }
+
+        let kind = kind.ok_or_else(|| {
+            syn::Error::new(ident.span(), "`enum_values` must have at least one value")
+        })?;
+
+        Ok(Self(values.into_iter().collect(), kind, ident.span()))
+    }
+}
+
+impl ToTokens for EnumValues {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let values = &self.0;
+        tokens.extend(quote! { [#( #values ),*] })
+    }
+}
+
+impl From<EnumValues> for Feature {
+    fn from(value: EnumValues) -> Self {
+        Feature::EnumValues(value)
+    }
+}
+
+name!(EnumValues = "enum_values");
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MaxItems(usize, Ident);
+pub struct MaxItems(usize, Span);
+
+#[cfg(feature = "validator")]
+impl MaxItems {
+    pub fn new(value: usize, span: Span) -> Self {
+        Self(value, span)
+    }
+}
 
 impl Validate for MaxItems {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`max_items` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`max_items` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-maxitems")),
             _ => None,
         }
@@ -1198,7 +1417,7 @@ impl Parse for MaxItems {
     where
         Self: Sized,
     {
-        parse_number(input).map(|max_items| Self(max_items, ident))
+        parse_number(input).map(|max_items| Self(max_items, ident.span()))
     }
 }
 
@@ -1218,12 +1437,19 @@ name!(MaxItems = "max_items");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MinItems(usize, Ident);
+pub struct MinItems(usize, Span);
+
+#[cfg(feature = "validator")]
+impl MinItems {
+    pub fn new(value: usize, span: Span) -> Self {
+        Self(value, span)
+    }
+}
 
 impl Validate for MinItems {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`min_items` error: {}", error))
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`min_items` error: {}", error))
                 .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-minitems")),
             _ => None,
         }
@@ -1235,7 +1461,7 @@ impl Parse for MinItems {
     where
         Self: Sized,
     {
-        parse_number(input).map(|max_items| Self(max_items, ident))
+        parse_number(input).map(|max_items| Self(max_items, ident.span()))
     }
 }
 
@@ -1253,10 +1479,60 @@ impl From<MinItems> for Feature {
 
 name!(MinItems = "min_items");
 
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct UniqueItems(bool, Span);
+
+impl UniqueItems {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+}
+
+impl Validate for UniqueItems {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1, format!("`unique_items` error: {}", error))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-uniqueitems")),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for UniqueItems {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(|unique_items| Self(unique_items, ident.span()))
+    }
+}
+
+impl ToTokens for UniqueItems {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<UniqueItems> for Feature {
+    fn from(value: UniqueItems) -> Self {
+        Feature::UniqueItems(value)
+    }
+}
+
+name!(UniqueItems = "unique_items");
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
 pub struct MaxProperties(usize, ());
 
+#[cfg(feature = "validator")]
+impl MaxProperties {
+    pub fn new(value: usize) -> Self {
+        Self(value, ())
+    }
+}
+
 impl Parse for MaxProperties {
     fn parse(input: ParseStream, _ident: Ident) -> syn::Result<Self>
     where
@@ -1284,6 +1560,13 @@ name!(MaxProperties = "max_properties");
 #[derive(Clone)]
 pub struct MinProperties(usize, ());
 
+#[cfg(feature = "validator")]
+impl MinProperties {
+    pub fn new(value: usize) -> Self {
+        Self(value, ())
+    }
+}
+
 impl Parse for MinProperties {
     fn parse(input: ParseStream, _ident: Ident) -> syn::Result<Self>
     where
@@ -1501,6 +1784,112 @@ impl From<Required> for Feature {
 
 name!(Required = "required");
 
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ExternalDocs(crate::ExternalDocs);
+
+impl Parse for ExternalDocs {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        let external_docs;
+        parenthesized!(external_docs in input);
+        external_docs.parse::<crate::ExternalDocs>().map(Self)
+    }
+}
+
+impl ToTokens for ExternalDocs {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<ExternalDocs> for Feature {
+    fn from(value: ExternalDocs) -> Self {
+        Feature::ExternalDocs(value)
+    }
+}
+
+name!(ExternalDocs = "external_docs");
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Extensions(crate::Extensions);
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        let extensions;
+        parenthesized!(extensions in input);
+        extensions.parse::<crate::Extensions>().map(Self)
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<Extensions> for Feature {
+    fn from(value: Extensions) -> Self {
+        Feature::Extensions(value)
+    }
+}
+
+name!(Extensions = "extensions");
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Form(bool);
+
+impl Form {
+    pub fn is_form(&self) -> bool {
+        self.0
+    }
+}
+
+impl Parse for Form {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl From<Form> for Feature {
+    fn from(value: Form) -> Self {
+        Feature::Form(value)
+    }
+}
+
+name!(Form = "form");
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Flatten(bool);
+
+impl Flatten {
+    pub fn is_flatten(&self) -> bool {
+        self.0
+    }
+}
+
+impl Parse for Flatten {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl From<Flatten> for Feature {
+    fn from(value: Flatten) -> Self {
+        Feature::Flatten(value)
+    }
+}
+
+name!(Flatten = "flatten");
+
 pub trait Validator {
     fn is_valid(&self) -> Result<(), &'static str>;
 }
@@ -1529,6 +1918,18 @@ impl Validator for IsString<'_> {
     }
 }
 
+pub struct IsFloat<'a>(&'a SchemaType<'a>);
+
+impl Validator for IsFloat<'_> {
+    fn is_valid(&self) -> Result<(), &'static str> {
+        if self.0.is_number() && !self.0.is_integer() {
+            Ok(())
+        } else {
+            Err("float values can only be used with `f32` or `f64` types")
+        }
+    }
+}
+
 pub struct IsInteger<'a>(&'a SchemaType<'a>);
 
 impl Validator for IsInteger<'_> {
@@ -1545,10 +1946,17 @@ pub struct IsVec<'a>(&'a TypeTree<'a>);
 
 impl Validator for IsVec<'_> {
     fn is_valid(&self) -> Result<(), &'static str> {
-        if self.0.generic_type == Some(GenericType::Vec) {
+        if matches!(
+            self.0.generic_type,
+            Some(GenericType::Vec) | Some(GenericType::Set)
+        ) {
             Ok(())
         } else {
-            Err("can only be used with `Vec`, `array` or `slice` types")
+            #[cfg(feature = "smallvec")]
+            if self.0.generic_type == Some(GenericType::SmallVec) {
+                return Ok(());
+            }
+            Err("can only be used with `Vec`, `array`, `slice` or set types")
         }
     }
 }
@@ -1659,6 +2067,8 @@ pub(crate) use parse_features;
 
 pub trait IsInline {
     fn is_inline(&self) -> bool;
+
+    fn inline_schema_fn(&self) -> Ident;
 }
 
 impl IsInline for Vec<Feature> {
@@ -1670,6 +2080,15 @@ impl IsInline for Vec<Feature> {
             })
             .is_some()
     }
+
+    fn inline_schema_fn(&self) -> Ident {
+        self.iter()
+            .find_map(|feature| match feature {
+                Feature::Inline(inline) => Some(inline.schema_fn()),
+                _ => None,
+            })
+            .unwrap_or_else(|| Inline::from(true).schema_fn())
+    }
 }
 
 pub trait ToTokensExt {
@@ -1738,24 +2157,19 @@ impl FeaturesExt for Vec<Feature> {
         &mut self,
         type_tree: &TypeTree,
     ) -> Result<Option<Feature>, Diagnostics> {
-        self.iter_mut()
-            .find_map(|feature| match feature {
-                Feature::XmlAttr(xml_feature) => {
-                    match xml_feature.split_for_vec(type_tree) {
-                        Ok((vec_xml, value_xml)) => {
-                            // replace the original xml attribute with split value xml
-                            if let Some(mut xml) = value_xml {
-                                mem::swap(xml_feature, &mut xml)
-                            }
+        let Some(Feature::XmlAttr(mut xml_feature)) =
+            self.pop_by(|feature| matches!(feature, Feature::XmlAttr(_)))
+        else {
+            return Ok(None);
+        };
+
+        let (vec_xml, value_xml) = xml_feature.split_for_vec(type_tree)?;
+        // replace the original xml attribute with split value xml
+        if let Some(value_xml) = value_xml {
+            self.push(Feature::XmlAttr(value_xml));
+        }
 
-                            Some(Ok(vec_xml.map(Feature::XmlAttr)))
-                        }
-                        Err(diagnostics) => Some(Err(diagnostics)),
-                    }
-                }
-                _ => None,
-            })
-            .and_then_try(|value| value)
+        Ok(vec_xml.map(Feature::XmlAttr))
     }
 }
 
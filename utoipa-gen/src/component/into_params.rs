@@ -11,15 +11,17 @@ use crate::{
     component::{
         self,
         features::{
-            self, AdditionalProperties, AllowReserved, Example, ExclusiveMaximum, ExclusiveMinimum,
-            Explode, Format, Inline, MaxItems, MaxLength, Maximum, MinItems, MinLength, Minimum,
-            MultipleOf, Names, Nullable, Pattern, ReadOnly, Rename, RenameAll, SchemaWith, Style,
-            WriteOnly, XmlAttr,
+            self, AdditionalProperties, AllowReserved, EnumValues, Example, Examples,
+            ExclusiveMaximum, ExclusiveMinimum, Explode, Flatten, Format, Inline, MaxItems,
+            MaxLength, Maximum, MinItems, MinLength, Minimum, MultipleOf, Names, Nullable, Pattern,
+            ReadOnly, Rename, RenameAll, SchemaWith, Style, UniqueItems, WriteOnly, XmlAttr,
         },
         FieldRename,
     },
     doc_comment::CommentAttributes,
-    impl_to_tokens_diagnostics, Array, Diagnostics, OptionExt, Required, ToTokensDiagnostics,
+    impl_to_tokens_diagnostics,
+    path::PathTypeTree,
+    Array, Diagnostics, OptionExt, Required, ToTokensDiagnostics,
 };
 
 use super::{
@@ -28,7 +30,7 @@ use super::{
         FeaturesExt, IntoInner, Merge, ToTokensExt,
     },
     serde::{self, SerdeContainer, SerdeValue},
-    ComponentSchema, TypeTree,
+    ComponentSchema, GenericType, TypeTree,
 };
 
 impl_merge!(IntoParamsFeatures, FieldFeatures);
@@ -120,6 +122,18 @@ impl ToTokensDiagnostics for IntoParams {
                 }
             })
             .map(|(index, field, field_serde_params)| {
+                if Self::is_flattened_params(field, field_serde_params.as_ref())? {
+                    let ty = &field.ty;
+                    let parameter_in_provider = match &parameter_in {
+                        Some(Feature::ParameterIn(parameter_in)) => quote! { || Some(#parameter_in) },
+                        _ => quote! { &parameter_in_provider },
+                    };
+
+                    return Ok(quote! {
+                        <#ty as utoipa::IntoParams>::into_params(#parameter_in_provider)
+                    });
+                }
+
                 let name = names.as_ref()
                     .map_try(|names| names.get(index).ok_or_else(|| Diagnostics::with_span(
                         ident.span(),
@@ -148,7 +162,7 @@ impl ToTokensDiagnostics for IntoParams {
 
                 let mut param_tokens = TokenStream::new();
                 match ToTokensDiagnostics::to_tokens(&param, &mut param_tokens) {
-                    Ok(_) => Ok(param_tokens),
+                    Ok(_) => Ok(quote! { vec![#param_tokens] }),
                     Err(diagnostics) => Err(diagnostics)
                 }
             })
@@ -157,7 +171,7 @@ impl ToTokensDiagnostics for IntoParams {
         tokens.extend(quote! {
             impl #impl_generics utoipa::IntoParams for #ident #ty_generics #where_clause {
                 fn into_params(parameter_in_provider: impl Fn() -> Option<utoipa::openapi::path::ParameterIn>) -> Vec<utoipa::openapi::path::Parameter> {
-                    #params.to_vec()
+                    Vec::<Vec<utoipa::openapi::path::Parameter>>::from(#params).concat()
                 }
             }
         });
@@ -167,6 +181,36 @@ impl ToTokensDiagnostics for IntoParams {
 }
 
 impl IntoParams {
+    /// Check whether field is `#[serde(flatten)]` or `#[param(flatten)]` of another [`IntoParams`]
+    /// type. Flattened maps are still documented as single parameter.
+    fn is_flattened_params(
+        field: &Field,
+        field_serde_params: Option<&SerdeValue>,
+    ) -> Result<bool, Diagnostics> {
+        let param_flatten = field
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("param"))
+            .map(|attribute| {
+                attribute
+                    .parse_args::<FieldFeatures>()
+                    .map(FieldFeatures::into_inner)
+            })
+            .collect::<Result<Vec<_>, syn::Error>>()?
+            .into_iter()
+            .flatten()
+            .any(|feature| matches!(feature, Feature::Flatten(flatten) if flatten.is_flatten()));
+
+        if param_flatten || matches!(field_serde_params, Some(serde_value) if serde_value.flatten) {
+            Ok(!matches!(
+                TypeTree::from_type(&field.ty)?.generic_type,
+                Some(GenericType::Map)
+            ))
+        } else {
+            Ok(false)
+        }
+    }
+
     fn get_struct_fields(
         &self,
         field_names: &Option<&Vec<String>>,
@@ -261,9 +305,11 @@ impl Parse for FieldFeatures {
             Style,
             AllowReserved,
             Example,
+            Examples,
             Explode,
             SchemaWith,
             component::features::Required,
+            Flatten,
             // param schema features
             Inline,
             Format,
@@ -280,8 +326,10 @@ impl Parse for FieldFeatures {
             MaxLength,
             MinLength,
             Pattern,
+            EnumValues,
             MaxItems,
             MinItems,
+            UniqueItems,
             AdditionalProperties
         )))
     }
@@ -329,6 +377,22 @@ impl Param<'_> {
             };
         }
 
+        // parameter in is only known if defined in container, otherwise it is resolved at runtime
+        if let Some(Feature::ParameterIn(parameter_in)) = self.container_attributes.parameter_in {
+            if let Some(Feature::Style(style)) = field_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::Style(_)))
+            {
+                style.validate_parameter_in(parameter_in.as_parameter_in())?;
+            }
+            if let Some(Feature::AllowReserved(allow_reserved)) = field_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::AllowReserved(_)))
+            {
+                allow_reserved.validate_parameter_in(parameter_in.as_parameter_in())?;
+            }
+        }
+
         Ok(field_features.into_iter().fold(
             (Vec::<Feature>::new(), Vec::<Feature>::new()),
             |(mut schema_features, mut param_features), feature| {
@@ -348,8 +412,10 @@ impl Param<'_> {
                     | Feature::MaxLength(_)
                     | Feature::MinLength(_)
                     | Feature::Pattern(_)
+                    | Feature::EnumValues(_)
                     | Feature::MaxItems(_)
                     | Feature::MinItems(_)
+                    | Feature::UniqueItems(_)
                     | Feature::AdditionalProperties(_) => {
                         schema_features.push(feature);
                     }
@@ -431,6 +497,18 @@ impl Param<'_> {
             }
 
             let value_type = param_features.pop_value_type_feature();
+            #[cfg(feature = "serde_with")]
+            let serde_as = value_type
+                .is_none()
+                .then(|| {
+                    component::serde_with::SerdeAs::resolve(field_serde_params.as_ref(), &field.ty)
+                })
+                .flatten();
+            #[cfg(feature = "serde_with")]
+            let type_tree = match &serde_as {
+                Some(serde_as) => TypeTree::from_type(&serde_as.ty)?,
+                None => type_tree,
+            };
             let component = value_type
                 .as_ref()
                 .map_try(|value_type| value_type.as_type_tree())?
@@ -450,6 +528,45 @@ impl Param<'_> {
             });
             tokens.extend(param_features.to_token_stream());
 
+            if component.is_array() {
+                let array_style = match self.container_attributes.parameter_in {
+                    Some(Feature::ParameterIn(parameter_in)) => (parameter_in.as_parameter_in()
+                        == &crate::path::parameter::ParameterIn::Query)
+                        .then(|| {
+                            crate::path::parameter::array_query_style_tokens(&param_features, None)
+                        })
+                        .flatten(),
+                    // parameter in is resolved at runtime
+                    _ => crate::path::parameter::array_query_style_tokens(
+                        &param_features,
+                        Some(quote! {
+                            matches!(parameter_in_provider(), Some(utoipa::openapi::path::ParameterIn::Query))
+                        }),
+                    ),
+                };
+                tokens.extend(array_style);
+            }
+
+            #[cfg(feature = "serde_with")]
+            let schema_features = {
+                let mut schema_features = schema_features;
+                if let Some(serde_as) = &serde_as {
+                    serde_as.extend_features(&mut schema_features);
+                }
+                schema_features
+            };
+
+            #[cfg(feature = "validator")]
+            let schema_features = {
+                let mut schema_features = schema_features;
+                component::validator::extend_features(
+                    &field.attrs,
+                    &component,
+                    &mut schema_features,
+                )?;
+                schema_features
+            };
+
             let schema = ComponentSchema::new(component::ComponentSchemaProps {
                 type_tree: &component,
                 features: Some(schema_features),
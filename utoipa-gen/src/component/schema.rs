@@ -11,14 +11,13 @@ use syn::{
 use crate::{
     component::features::{Example, Rename},
     doc_comment::CommentAttributes,
-    impl_to_tokens_diagnostics, Array, Deprecated, Diagnostics, OptionExt, ToTokensDiagnostics,
+    impl_to_tokens_diagnostics,
+    schema_type::SchemaType,
+    Array, Deprecated, Diagnostics, OptionExt, ToTokensDiagnostics,
 };
 
 use self::{
-    enum_variant::{
-        AdjacentlyTaggedEnum, CustomEnum, Enum, ObjectVariant, SimpleEnumVariant, TaggedEnum,
-        UntaggedEnum,
-    },
+    enum_variant::{CustomEnum, Enum, ObjectVariant, SimpleEnumVariant, TaggedEnum, UntaggedEnum},
     features::{
         ComplexEnumFeatures, EnumFeatures, EnumNamedFieldVariantFeatures,
         EnumUnnamedFieldVariantFeatures, FromAttributes, NamedFieldFeatures,
@@ -29,7 +28,7 @@ use self::{
 use super::{
     features::{
         parse_features, pop_feature, pop_feature_as_inner, As, Feature, FeaturesExt, IntoInner,
-        RenameAll, ToTokensExt,
+        IsInline, RenameAll, ToTokensExt,
     },
     serde::{self, SerdeContainer, SerdeEnumRepr, SerdeValue},
     ComponentSchema, FieldRename, FlattenedMapSchema, TypeTree, ValueType, VariantRename,
@@ -149,8 +148,12 @@ impl ToTokensDiagnostics for Schema<'_> {
                 .collect::<Result<TokenStream, Diagnostics>>()
         })?;
 
-        let name = if let Some(schema_as) = variant.get_schema_as() {
-            format_path_ref(&schema_as.0.path)
+        let remote =
+            serde::parse_container(self.attributes)?.and_then(|container| container.remote);
+        let name = if let Some(schema_name) = variant.get_schema_name() {
+            schema_name.to_name()
+        } else if let Some(remote) = remote {
+            format_path_ref(&remote)
         } else {
             ident.to_string()
         };
@@ -167,6 +170,36 @@ impl ToTokensDiagnostics for Schema<'_> {
 
         let mut variant_tokens = TokenStream::new();
         variant.to_tokens(&mut variant_tokens)?;
+        let schemas = if cfg!(feature = "auto_collect_schemas") {
+            super::schemas_fn_tokens(schema_references(
+                self.data,
+                self.attributes,
+                &ident.to_string(),
+            )?)
+        } else {
+            TokenStream::new()
+        };
+        let type_params = self
+            .generics
+            .type_params()
+            .map(|type_param| type_param.ident.to_string())
+            .collect::<Vec<_>>();
+        let generic_schema = (!type_params.is_empty()).then(|| {
+            quote! {
+                fn generic_schema(
+                    generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+                ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+                    utoipa::__dev::replace_generics(Self::schema().1, &[#( #type_params ),*], generics)
+                }
+            }
+        });
+        let content_type = variant.is_form().then(|| {
+            quote! {
+                fn content_type() -> Option<& #life str> {
+                    Some("application/x-www-form-urlencoded")
+                }
+            }
+        });
 
         tokens.extend(quote! {
             impl #impl_generics utoipa::ToSchema #schema_generics for #ident #ty_generics #where_clause {
@@ -174,7 +207,17 @@ impl ToTokensDiagnostics for Schema<'_> {
                     (#name, #variant_tokens.into())
                 }
 
+                fn schema_name() -> & #life str {
+                    #name
+                }
+
                 #aliases
+
+                #generic_schema
+
+                #content_type
+
+                #schemas
             }
 
             #type_aliases
@@ -183,6 +226,103 @@ impl ToTokensDiagnostics for Schema<'_> {
     }
 }
 
+/// Create tokens collecting schemas of the types referenced by the fields of the `data`. Skipped
+/// fields and fields with `schema_with` or `value_type` are ignored as well as fields referencing
+/// the `object_name` itself.
+fn schema_references(
+    data: &Data,
+    attributes: &[Attribute],
+    object_name: &str,
+) -> Result<TokenStream, Diagnostics> {
+    let mut tokens = TokenStream::new();
+    match data {
+        Data::Struct(content) => {
+            let features = match &content.fields {
+                Fields::Unnamed(_) => attributes
+                    .parse_features::<UnnamedFieldStructFeatures>()?
+                    .into_inner(),
+                _ => None,
+            };
+            fields_schema_references(&content.fields, features, object_name, &mut tokens)?;
+        }
+        Data::Enum(content) => {
+            for variant in &content.variants {
+                if !is_not_skipped(&serde::parse_value(&variant.attrs)?) {
+                    continue;
+                }
+                let features = match &variant.fields {
+                    Fields::Unnamed(_) => variant
+                        .attrs
+                        .parse_features::<EnumUnnamedFieldVariantFeatures>()?
+                        .into_inner(),
+                    _ => None,
+                };
+                fields_schema_references(&variant.fields, features, object_name, &mut tokens)?;
+            }
+        }
+        Data::Union(_) => (),
+    }
+
+    Ok(tokens)
+}
+
+fn fields_schema_references(
+    fields: &Fields,
+    container_features: Option<Vec<Feature>>,
+    object_name: &str,
+    tokens: &mut TokenStream,
+) -> Result<(), Diagnostics> {
+    let fields = fields
+        .iter()
+        .map(|field| serde::parse_value(&field.attrs).map(|rules| (field, rules)))
+        .collect::<Result<Vec<_>, Diagnostics>>()?
+        .into_iter()
+        .filter(|(_, field_rules)| is_not_skipped(field_rules))
+        .map(|(field, _)| field)
+        .collect::<Vec<_>>();
+
+    for field in &fields {
+        let field_features = if field.ident.is_some() {
+            field
+                .attrs
+                .parse_features::<NamedFieldFeatures>()?
+                .into_inner()
+                .unwrap_or_default()
+        } else if fields.len() == 1 {
+            // container level features take precedence over the features of the single field
+            let mut features = container_features.clone().unwrap_or_default();
+            features.extend(
+                features::parse_schema_features_with(&field.attrs, |input| {
+                    Ok(parse_features!(
+                        input as super::features::ValueType,
+                        super::features::Format,
+                        super::features::Inline
+                    ))
+                })?
+                .unwrap_or_default(),
+            );
+            features
+        } else {
+            Vec::new()
+        };
+
+        // type given with `value_type` is not required to exist thus it cannot be collected
+        if field_features
+            .iter()
+            .any(|feature| matches!(feature, Feature::SchemaWith(_) | Feature::ValueType(_)))
+        {
+            continue;
+        }
+
+        tokens.extend(
+            TypeTree::from_type(&field.ty)?
+                .schema_references_tokens(field_features.is_inline(), object_name),
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum SchemaVariant<'a> {
     Named(NamedStructSchema<'a>),
@@ -199,29 +339,44 @@ impl<'a> SchemaVariant<'a> {
         generics: &'a Generics,
         aliases: Option<I>,
     ) -> Result<SchemaVariant<'a>, Diagnostics> {
+        let aliases = aliases.map(|aliases| aliases.into_iter().collect::<Vec<_>>());
+        let is_transparent = serde::parse_container(attributes)?
+            .map(|container| container.transparent)
+            .unwrap_or(false);
+
         match data {
-            Data::Struct(content) => match &content.fields {
-                Fields::Unnamed(fields) => {
-                    let FieldsUnnamed { unnamed, .. } = fields;
+            Data::Struct(content) => match (&content.fields, is_transparent) {
+                // transparent struct is serialized as its single field thus it is represented
+                // same way as single unnamed field struct
+                (Fields::Unnamed(FieldsUnnamed { unnamed, .. }), _)
+                | (Fields::Named(FieldsNamed { named: unnamed, .. }), true) => {
+                    if unnamed.is_empty() {
+                        return Err(Diagnostics::with_span(
+                            ident.span(),
+                            "unnamed field struct without fields is not supported",
+                        )
+                        .help("Try using a unit struct instead e.g. `struct Foo;`"));
+                    }
                     let mut unnamed_features = attributes
                         .parse_features::<UnnamedFieldStructFeatures>()?
                         .into_inner();
 
-                    let schema_as = pop_feature_as_inner!(unnamed_features => Feature::As(_v));
+                    let schema_name = SchemaName::pop_from(&mut unnamed_features);
                     Ok(Self::Unnamed(UnnamedStructSchema {
                         struct_name: Cow::Owned(ident.to_string()),
                         attributes,
                         features: unnamed_features,
                         fields: unnamed,
-                        schema_as,
+                        schema_name,
+                        aliases,
                     }))
                 }
-                Fields::Named(fields) => {
+                (Fields::Named(fields), false) => {
                     let FieldsNamed { named, .. } = fields;
                     let mut named_features = attributes
                         .parse_features::<NamedFieldStructFeatures>()?
                         .into_inner();
-                    let schema_as = pop_feature_as_inner!(named_features => Feature::As(_v));
+                    let schema_name = SchemaName::pop_from(&mut named_features);
 
                     Ok(Self::Named(NamedStructSchema {
                         struct_name: Cow::Owned(ident.to_string()),
@@ -230,16 +385,17 @@ impl<'a> SchemaVariant<'a> {
                         features: named_features,
                         fields: named,
                         generics: Some(generics),
-                        schema_as,
-                        aliases: aliases.map(|aliases| aliases.into_iter().collect()),
+                        schema_name,
+                        aliases,
                     }))
                 }
-                Fields::Unit => Ok(Self::Unit(UnitStructVariant)),
+                (Fields::Unit, _) => Ok(Self::Unit(UnitStructVariant)),
             },
             Data::Enum(content) => Ok(Self::Enum(EnumSchema::new(
                 Cow::Owned(ident.to_string()),
                 &content.variants,
                 attributes,
+                aliases,
             )?)),
             _ => Err(Diagnostics::with_span(
                 ident.span(),
@@ -248,14 +404,25 @@ impl<'a> SchemaVariant<'a> {
         }
     }
 
-    fn get_schema_as(&self) -> &Option<As> {
+    fn get_schema_name(&self) -> &Option<SchemaName> {
         match self {
-            Self::Enum(schema) => &schema.schema_as,
-            Self::Named(schema) => &schema.schema_as,
-            Self::Unnamed(schema) => &schema.schema_as,
+            Self::Enum(schema) => &schema.schema_name,
+            Self::Named(schema) => &schema.schema_name,
+            Self::Unnamed(schema) => &schema.schema_name,
             _ => &None,
         }
     }
+
+    fn is_form(&self) -> bool {
+        match self {
+            Self::Named(schema) => schema
+                .features
+                .iter()
+                .flatten()
+                .any(|feature| matches!(feature, Feature::Form(form) if form.is_form())),
+            _ => false,
+        }
+    }
 }
 
 impl ToTokensDiagnostics for SchemaVariant<'_> {
@@ -295,7 +462,7 @@ pub struct NamedStructSchema<'a> {
     pub rename_all: Option<RenameAll>,
     pub generics: Option<&'a Generics>,
     pub aliases: Option<Vec<(TypeTree<'a>, &'a TypeTree<'a>)>>,
-    pub schema_as: Option<As>,
+    pub schema_name: Option<SchemaName>,
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -314,19 +481,52 @@ impl NamedStructSchema<'_> {
         container_rules: &Option<SerdeContainer>,
     ) -> Result<NamedStructFieldOptions<'_>, Diagnostics> {
         let type_tree = &mut TypeTree::from_type(&field.ty)?;
-        if let Some(aliases) = &self.aliases {
-            for (new_generic, old_generic_matcher) in aliases.iter() {
-                if let Some(generic_match) = type_tree.find_mut(old_generic_matcher) {
-                    *generic_match = new_generic.clone();
-                }
-            }
-        }
+        // Default value from serde's field level `default` is only known to serialize for
+        // primitive types without aliases applied.
+        let is_primitive = type_tree.generic_type.is_none()
+            && type_tree
+                .path
+                .as_ref()
+                .map(|path| SchemaType(path).is_primitive())
+                .unwrap_or(false);
+        replace_generics_with_aliases(type_tree, &self.aliases);
 
         let mut field_features = field
             .attrs
             .parse_features::<NamedFieldFeatures>()?
             .into_inner();
 
+        // field level `default` without a value is resolved from the `Default` implementation of
+        // the field's type
+        if let Some(ref mut features) = field_features {
+            if pop_feature!(features => Feature::Default(crate::features::Default(None))).is_some()
+            {
+                let ty = &field.ty;
+                features.push(Feature::Default(crate::features::Default::new_default_fn(
+                    quote! { <#ty as ::core::default::Default>::default },
+                )));
+            }
+        }
+
+        if let Some(field_rules) = field_rules.filter(|rules| rules.default && is_primitive) {
+            let features_inner = field_features.get_or_insert(vec![]);
+            if !features_inner
+                .iter()
+                .any(|f| matches!(f, Feature::Default(_)))
+            {
+                let default_fn = match &field_rules.default_path {
+                    Some(default_path) => default_path.to_token_stream(),
+                    None => {
+                        let ty = &field.ty;
+                        quote! { <#ty as ::core::default::Default>::default }
+                    }
+                };
+                features_inner.push(Feature::Default(crate::features::Default::new_default_fn(
+                    default_fn,
+                )));
+            }
+        }
+
         let schema_default = self
             .features
             .as_ref()
@@ -369,15 +569,38 @@ impl NamedStructSchema<'_> {
         let value_type = field_features
             .as_mut()
             .and_then(|features| features.pop_value_type_feature());
+        #[cfg(feature = "serde_with")]
+        let serde_as = value_type
+            .is_none()
+            .then(|| super::serde_with::SerdeAs::resolve(field_rules, &field.ty))
+            .flatten();
+        #[cfg(feature = "serde_with")]
+        if let Some(serde_as) = &serde_as {
+            serde_as.extend_features(field_features.get_or_insert(vec![]));
+        }
         let override_type_tree = value_type
             .as_ref()
             .map_try(|value_type| value_type.as_type_tree())?;
+        #[cfg(feature = "serde_with")]
+        let override_type_tree = match override_type_tree {
+            Some(override_type_tree) => Some(override_type_tree),
+            None => serde_as
+                .as_ref()
+                .map_try(|serde_as| TypeTree::from_type(&serde_as.ty))?,
+        };
         let comments = CommentAttributes::from_attributes(&field.attrs);
         let schema_with = pop_feature!(field_features => Feature::SchemaWith(_));
         let required = pop_feature_as_inner!(field_features => Feature::Required(_v));
         let type_tree = override_type_tree.as_ref().unwrap_or(type_tree);
         let is_option = type_tree.is_option();
 
+        #[cfg(feature = "validator")]
+        super::validator::extend_features(
+            &field.attrs,
+            type_tree,
+            field_features.get_or_insert(vec![]),
+        )?;
+
         Ok(NamedStructFieldOptions {
             property: if let Some(schema_with) = schema_with {
                 Property::SchemaWith(schema_with)
@@ -472,13 +695,17 @@ impl NamedStructSchema<'_> {
                         .property(#name, #property)
                     });
 
-                    if (!is_option
-                        && super::is_required(field_rules.as_ref(), container_rules.as_ref()))
-                        || required
-                            .as_ref()
-                            .map(super::features::Required::is_true)
-                            .unwrap_or(false)
-                    {
+                    let is_required = required
+                        .as_ref()
+                        .map(super::features::Required::is_true)
+                        .unwrap_or_else(|| {
+                            !is_option
+                                && super::is_required(
+                                    field_rules.as_ref(),
+                                    container_rules.as_ref(),
+                                )
+                        });
+                    if is_required {
                         object_tokens.extend(quote! {
                             .required(#name)
                         })
@@ -493,9 +720,9 @@ impl NamedStructSchema<'_> {
             .filter(|(_, field_rules, ..)| is_flatten(field_rules.as_ref()))
             .collect::<Vec<_>>();
 
+        let mut flattened_map_field = None;
         let all_of = if !flatten_fields.is_empty() {
             let mut flattened_tokens = TokenStream::new();
-            let mut flattened_map_field = None;
 
             for (options, _, _, field) in flatten_fields {
                 let NamedStructFieldOptions { property, .. } = options;
@@ -530,10 +757,17 @@ impl NamedStructSchema<'_> {
                 tokens.extend(object_tokens);
                 false
             } else {
+                // Leave out the object item when all the fields are flattened, as it would be
+                // an empty object schema.
+                let has_object_properties = flattened_map_field.is_some()
+                    || fields.iter().any(|(_, field_rules, ..)| {
+                        is_not_skipped(field_rules) && !is_flatten(field_rules.as_ref())
+                    });
+                let object_item = has_object_properties.then(|| quote! { .item(#object_tokens) });
                 tokens.extend(quote! {
                     utoipa::openapi::AllOfBuilder::new()
                         #flattened_tokens
-                    .item(#object_tokens)
+                        #object_item
                 });
                 true
             }
@@ -542,7 +776,10 @@ impl NamedStructSchema<'_> {
             false
         };
 
+        // flattened map defines the additional properties, it takes precedence over
+        // `deny_unknown_fields`
         if !all_of
+            && flattened_map_field.is_none()
             && container_rules
                 .as_ref()
                 .map(|container_rule| container_rule.deny_unknown_fields)
@@ -562,7 +799,7 @@ impl NamedStructSchema<'_> {
         }
 
         let description = CommentAttributes::from_attributes(self.attributes).as_formatted_string();
-        if !description.is_empty() {
+        if !description.is_empty() && !has_description_feature(self.features.iter().flatten()) {
             tokens.extend(quote! {
                 .description(Some(#description))
             })
@@ -586,18 +823,39 @@ struct UnnamedStructSchema<'a> {
     fields: &'a Punctuated<Field, Comma>,
     attributes: &'a [Attribute],
     features: Option<Vec<Feature>>,
-    schema_as: Option<As>,
+    schema_name: Option<SchemaName>,
+    aliases: Option<Vec<(TypeTree<'a>, &'a TypeTree<'a>)>>,
 }
 
 impl UnnamedStructSchema<'_> {
     fn tokens_or_diagnostics(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        let fields_len = self.fields.len();
-        let first_field = self.fields.first().unwrap();
-        let first_part = &TypeTree::from_type(&first_field.ty)?;
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| serde::parse_value(&field.attrs).map(|rules| (field, rules)))
+            .collect::<Result<Vec<_>, Diagnostics>>()?
+            .into_iter()
+            .filter(|(_, field_rules)| is_not_skipped(field_rules))
+            .map(|(field, _)| field)
+            .collect::<Vec<_>>();
+        let is_transparent = serde::parse_container(self.attributes)?
+            .map(|container| container.transparent)
+            .unwrap_or(false);
+        // Serde serializes struct with more than one unnamed field as an array even if only one
+        // of the fields is left after skipping.
+        let is_tuple = self.fields.len() > 1 && !is_transparent;
+        let fields_len = fields.len();
+        let first_field = fields.first().ok_or_else(|| {
+            Diagnostics::with_span(
+                self.fields.span(),
+                "unnamed field struct must have at least one field that is not skipped",
+            )
+        })?;
+        let first_part = &mut TypeTree::from_type(&first_field.ty)?;
+        replace_generics_with_aliases(first_part, &self.aliases);
 
         let all_fields_are_same = fields_len == 1
-            || self
-                .fields
+            || fields
                 .iter()
                 .skip(1)
                 .map(|field| TypeTree::from_type(&field.ty))
@@ -608,6 +866,29 @@ impl UnnamedStructSchema<'_> {
         let deprecated = super::get_deprecated(self.attributes);
         if all_fields_are_same {
             let mut unnamed_struct_features = self.features.clone();
+            if !is_tuple {
+                // features of the single field are applied to the struct, yet struct level
+                // features will take precedence
+                let field_features =
+                    features::parse_schema_features_with(&first_field.attrs, |input| {
+                        Ok(parse_features!(
+                            input as super::features::ValueType,
+                            super::features::Format,
+                            super::features::Inline
+                        ))
+                    })?;
+                if let Some(field_features) = field_features {
+                    let struct_features = unnamed_struct_features.get_or_insert_with(Vec::new);
+                    for field_feature in field_features {
+                        if !struct_features.iter().any(|feature| {
+                            std::mem::discriminant(feature)
+                                == std::mem::discriminant(&field_feature)
+                        }) {
+                            struct_features.push(field_feature);
+                        }
+                    }
+                }
+            }
             let value_type = unnamed_struct_features
                 .as_mut()
                 .and_then(|features| features.pop_value_type_feature());
@@ -615,25 +896,41 @@ impl UnnamedStructSchema<'_> {
                 .as_ref()
                 .map_try(|value_type| value_type.as_type_tree())?;
 
-            if fields_len == 1 {
+            if !is_tuple {
                 if let Some(ref mut features) = unnamed_struct_features {
                     if pop_feature!(features => Feature::Default(crate::features::Default(None)))
                         .is_some()
                     {
                         let struct_ident = format_ident!("{}", &self.struct_name);
-                        let index: syn::Index = 0.into();
+                        let member = match &first_field.ident {
+                            Some(ident) => syn::Member::Named(ident.clone()),
+                            None => syn::Member::Unnamed(
+                                self.fields
+                                    .iter()
+                                    .position(|field| std::ptr::eq(field, *first_field))
+                                    .unwrap_or_default()
+                                    .into(),
+                            ),
+                        };
                         features.push(Feature::Default(
-                            crate::features::Default::new_default_trait(struct_ident, index.into()),
+                            crate::features::Default::new_default_trait(struct_ident, member),
                         ));
                     }
                 }
             }
 
+            let type_tree = override_type_tree.as_ref().unwrap_or(first_part);
+            // description of the unnamed struct is not applied to the schema of a referenced type
+            let is_reference = type_tree.value_type == ValueType::Object
+                && type_tree.generic_type.is_none()
+                && !type_tree.is_object();
+            let description = CommentAttributes::from_attributes(self.attributes);
+
             tokens.extend(
                 ComponentSchema::new(super::ComponentSchemaProps {
-                    type_tree: override_type_tree.as_ref().unwrap_or(first_part),
+                    type_tree,
                     features: unnamed_struct_features,
-                    description: Some(&CommentAttributes::from_attributes(self.attributes)),
+                    description: (!is_reference).then_some(&description),
                     deprecated: deprecated.as_ref(),
                     object_name: self.struct_name.as_ref(),
                 })
@@ -657,11 +954,13 @@ impl UnnamedStructSchema<'_> {
             }
         }
 
-        if fields_len > 1 {
+        if is_tuple {
             let description =
                 CommentAttributes::from_attributes(self.attributes).as_formatted_string();
+            let description =
+                (!description.is_empty()).then(|| quote! { .description(Some(#description)) });
             tokens.extend(
-                quote! { .to_array_builder().description(Some(#description)).max_items(Some(#fields_len)).min_items(Some(#fields_len)) },
+                quote! { .to_array_builder() #description .max_items(Some(#fields_len)).min_items(Some(#fields_len)) },
             )
         }
 
@@ -680,7 +979,7 @@ impl_to_tokens_diagnostics! {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct EnumSchema<'a> {
     schema_type: EnumSchemaType<'a>,
-    schema_as: Option<As>,
+    schema_name: Option<SchemaName>,
 }
 
 impl<'e> EnumSchema<'e> {
@@ -688,7 +987,25 @@ impl<'e> EnumSchema<'e> {
         enum_name: Cow<'e, str>,
         variants: &'e Punctuated<Variant, Comma>,
         attributes: &'e [Attribute],
+        aliases: Option<Vec<(TypeTree<'e>, &'e TypeTree<'e>)>>,
     ) -> Result<Self, Diagnostics> {
+        if variants.is_empty() {
+            return Err(Diagnostics::new(format!(
+                "enum `{enum_name}` without variants is not supported"
+            ))
+            .help("Enum must have at least one variant to produce a schema"));
+        }
+
+        if let Some(variant) = variants.iter().find(
+            |variant| matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.is_empty()),
+        ) {
+            return Err(Diagnostics::with_span(
+                variant.span(),
+                "unnamed field enum variant without fields is not supported",
+            )
+            .help("Try using a unit variant instead e.g. `Foo`"));
+        }
+
         if variants
             .iter()
             .all(|variant| matches!(variant.fields, Fields::Unit))
@@ -711,13 +1028,14 @@ impl<'e> EnumSchema<'e> {
                                     input as super::features::Example,
                                     super::features::Default,
                                     super::features::Title,
+                                    super::features::Description,
+                                    Rename,
                                     As
                                 ))
                             })?
                             .unwrap_or_default();
 
-                        let schema_as =
-                            pop_feature_as_inner!(repr_enum_features => Feature::As(_v));
+                        let schema_name = SchemaName::pop_from(&mut repr_enum_features);
                         Result::<EnumSchema, Diagnostics>::Ok(Self {
                             schema_type: EnumSchemaType::Repr(ReprEnum {
                                 variants,
@@ -725,7 +1043,7 @@ impl<'e> EnumSchema<'e> {
                                 enum_type,
                                 enum_features: repr_enum_features,
                             }),
-                            schema_as,
+                            schema_name,
                         })
                     })?;
 
@@ -736,8 +1054,7 @@ impl<'e> EnumSchema<'e> {
                             .parse_features::<EnumFeatures>()?
                             .into_inner()
                             .unwrap_or_default();
-                        let schema_as =
-                            pop_feature_as_inner!(simple_enum_features => Feature::As(_v));
+                        let schema_name = SchemaName::pop_from(&mut simple_enum_features);
                         let rename_all = simple_enum_features.pop_rename_all_feature();
 
                         Ok(Self {
@@ -747,7 +1064,7 @@ impl<'e> EnumSchema<'e> {
                                 enum_features: simple_enum_features,
                                 rename_all,
                             }),
-                            schema_as,
+                            schema_name,
                         })
                     }
                 }
@@ -759,7 +1076,7 @@ impl<'e> EnumSchema<'e> {
                     .parse_features::<EnumFeatures>()?
                     .into_inner()
                     .unwrap_or_default();
-                let schema_as = pop_feature_as_inner!(simple_enum_features => Feature::As(_v));
+                let schema_name = SchemaName::pop_from(&mut simple_enum_features);
                 let rename_all = simple_enum_features.pop_rename_all_feature();
 
                 Ok(Self {
@@ -769,7 +1086,7 @@ impl<'e> EnumSchema<'e> {
                         enum_features: simple_enum_features,
                         rename_all,
                     }),
-                    schema_as,
+                    schema_name,
                 })
             }
         } else {
@@ -777,7 +1094,7 @@ impl<'e> EnumSchema<'e> {
                 .parse_features::<ComplexEnumFeatures>()?
                 .into_inner()
                 .unwrap_or_default();
-            let schema_as = pop_feature_as_inner!(enum_features => Feature::As(_v));
+            let schema_name = SchemaName::pop_from(&mut enum_features);
             let rename_all = enum_features.pop_rename_all_feature();
 
             Ok(Self {
@@ -787,8 +1104,9 @@ impl<'e> EnumSchema<'e> {
                     variants,
                     rename_all,
                     enum_features,
+                    aliases,
                 }),
-                schema_as,
+                schema_name,
             })
         }
     }
@@ -810,19 +1128,19 @@ enum EnumSchemaType<'e> {
 
 impl ToTokens for EnumSchemaType<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let attributes = match self {
+        let (attributes, enum_features) = match self {
             Self::Simple(simple) => {
                 ToTokens::to_tokens(simple, tokens);
-                simple.attributes
+                (simple.attributes, &simple.enum_features)
             }
             #[cfg(feature = "repr")]
             Self::Repr(repr) => {
                 ToTokens::to_tokens(repr, tokens);
-                repr.attributes
+                (repr.attributes, &repr.enum_features)
             }
             Self::Complex(complex) => {
                 ToTokens::to_tokens(complex, tokens);
-                complex.attributes
+                (complex.attributes, &complex.enum_features)
             }
         };
 
@@ -831,7 +1149,7 @@ impl ToTokens for EnumSchemaType<'_> {
         }
 
         let description = CommentAttributes::from_attributes(attributes).as_formatted_string();
-        if !description.is_empty() {
+        if !description.is_empty() && !has_description_feature(enum_features) {
             tokens.extend(quote! {
                 .description(Some(#description))
             })
@@ -1011,23 +1329,16 @@ fn regular_enum_to_tokens<T: self::enum_variant::Variant>(
     tokens.extend(match container_rules {
         Some(serde_container) => match &serde_container.enum_repr {
             SerdeEnumRepr::ExternallyTagged => Enum::new(enum_values).to_token_stream(),
-            SerdeEnumRepr::InternallyTagged { tag } => TaggedEnum::new(
+            // Unit variants of adjacently tagged enum are serialized without the content field
+            // thus they are represented same way as internally tagged unit variants.
+            SerdeEnumRepr::InternallyTagged { tag }
+            | SerdeEnumRepr::AdjacentlyTagged { tag, .. } => TaggedEnum::new(
                 enum_values
                     .into_iter()
                     .map(|variant| (Cow::Borrowed(tag.as_str()), variant)),
             )
             .to_token_stream(),
             SerdeEnumRepr::Untagged => UntaggedEnum::new().to_token_stream(),
-            SerdeEnumRepr::AdjacentlyTagged { tag, content } => {
-                AdjacentlyTaggedEnum::new(enum_values.into_iter().map(|variant| {
-                    (
-                        Cow::Borrowed(tag.as_str()),
-                        Cow::Borrowed(content.as_str()),
-                        variant,
-                    )
-                }))
-                .to_token_stream()
-            }
             // This should not be possible as serde should not let that happen
             SerdeEnumRepr::UnfinishedAdjacentlyTagged { .. } => panic!("Invalid serde enum repr"),
         },
@@ -1044,6 +1355,7 @@ struct ComplexEnum<'a> {
     enum_name: Cow<'a, str>,
     enum_features: Vec<Feature>,
     rename_all: Option<RenameAll>,
+    aliases: Option<Vec<(TypeTree<'a>, &'a TypeTree<'a>)>>,
 }
 
 impl ComplexEnum<'_> {
@@ -1074,21 +1386,24 @@ impl ComplexEnum<'_> {
                 );
 
                 let example = pop_feature!(named_struct_features => Feature::Example(_));
+                let deny_unknown_fields =
+                    enum_variant_deny_unknown_fields(container_rules, &named_fields.named)?;
+                let named_enum = NamedStructSchema {
+                    struct_name: Cow::Borrowed(&*self.enum_name),
+                    attributes: &variant.attrs,
+                    rename_all: named_struct_features.pop_rename_all_feature(),
+                    features: Some(named_struct_features),
+                    fields: &named_fields.named,
+                    generics: None,
+                    aliases: self.aliases.clone(),
+                    schema_name: None,
+                };
 
                 Ok(self::enum_variant::Variant::to_tokens(&ObjectVariant {
                     name: variant_name.unwrap_or(Cow::Borrowed(&name)),
                     title: title_features.first().map(ToTokens::to_token_stream),
                     example: example.as_ref().map(ToTokens::to_token_stream),
-                    item: NamedStructSchema {
-                        struct_name: Cow::Borrowed(&*self.enum_name),
-                        attributes: &variant.attrs,
-                        rename_all: named_struct_features.pop_rename_all_feature(),
-                        features: Some(named_struct_features),
-                        fields: &named_fields.named,
-                        generics: None,
-                        aliases: None,
-                        schema_as: None,
-                    },
+                    item: quote! { #named_enum #deny_unknown_fields },
                 }))
             }
             Fields::Unnamed(unnamed_fields) => {
@@ -1117,7 +1432,8 @@ impl ComplexEnum<'_> {
                         attributes: &variant.attrs,
                         features: Some(unnamed_struct_features),
                         fields: &unnamed_fields.unnamed,
-                        schema_as: None,
+                        schema_name: None,
+                        aliases: self.aliases.clone(),
                     },
                 }))
             }
@@ -1164,7 +1480,11 @@ impl ComplexEnum<'_> {
 
     /// Produce tokens that represent a variant of a [`ComplexEnum`] where serde enum attribute
     /// `untagged` applies.
-    fn untagged_variant_tokens(&self, variant: &Variant) -> Result<TokenStream, Diagnostics> {
+    fn untagged_variant_tokens(
+        &self,
+        variant: &Variant,
+        container_rules: &Option<SerdeContainer>,
+    ) -> Result<TokenStream, Diagnostics> {
         match &variant.fields {
             Fields::Named(named_fields) => {
                 let mut named_struct_features = variant
@@ -1172,17 +1492,20 @@ impl ComplexEnum<'_> {
                     .parse_features::<EnumNamedFieldVariantFeatures>()?
                     .into_inner()
                     .unwrap_or_default();
-
-                Ok(ToTokens::to_token_stream(&NamedStructSchema {
+                let deny_unknown_fields =
+                    enum_variant_deny_unknown_fields(container_rules, &named_fields.named)?;
+                let named_enum = NamedStructSchema {
                     struct_name: Cow::Borrowed(&*self.enum_name),
                     attributes: &variant.attrs,
                     rename_all: named_struct_features.pop_rename_all_feature(),
                     features: Some(named_struct_features),
                     fields: &named_fields.named,
                     generics: None,
-                    aliases: None,
-                    schema_as: None,
-                }))
+                    aliases: self.aliases.clone(),
+                    schema_name: None,
+                };
+
+                Ok(quote! { #named_enum #deny_unknown_fields })
             }
             Fields::Unnamed(unnamed_fields) => {
                 let unnamed_struct_features = variant
@@ -1196,7 +1519,8 @@ impl ComplexEnum<'_> {
                     attributes: &variant.attrs,
                     features: Some(unnamed_struct_features),
                     fields: &unnamed_fields.unnamed,
-                    schema_as: None,
+                    schema_name: None,
+                    aliases: self.aliases.clone(),
                 }))
             }
             Fields::Unit => {
@@ -1222,7 +1546,7 @@ impl ComplexEnum<'_> {
         variant_rules: &Option<SerdeValue>,
         container_rules: &Option<SerdeContainer>,
         rename_all: &Option<RenameAll>,
-    ) -> Result<TokenStream, Diagnostics> {
+    ) -> Result<(TokenStream, Option<(String, String)>), Diagnostics> {
         match &variant.fields {
             Fields::Named(named_fields) => {
                 let (title_features, mut named_struct_features) = variant
@@ -1246,22 +1570,28 @@ impl ComplexEnum<'_> {
                     features: Some(named_struct_features),
                     fields: &named_fields.named,
                     generics: None,
-                    aliases: None,
-                    schema_as: None,
+                    aliases: self.aliases.clone(),
+                    schema_name: None,
                 };
                 let title = title_features.first().map(ToTokens::to_token_stream);
+                let deny_unknown_fields =
+                    enum_variant_deny_unknown_fields(container_rules, &named_fields.named)?;
 
                 let variant_name_tokens = Enum::new([SimpleEnumVariant {
                     value: variant_name
                         .unwrap_or(Cow::Borrowed(&name))
                         .to_token_stream(),
                 }]);
-                Ok(quote! {
-                    #named_enum
-                        #title
-                        .property(#tag, #variant_name_tokens)
-                        .required(#tag)
-                })
+                Ok((
+                    quote! {
+                        #named_enum
+                            #deny_unknown_fields
+                            #title
+                            .property(#tag, #variant_name_tokens)
+                            .required(#tag)
+                    },
+                    None,
+                ))
             }
             Fields::Unnamed(unnamed_fields) => {
                 if unnamed_fields.unnamed.len() == 1 {
@@ -1279,12 +1609,23 @@ impl ComplexEnum<'_> {
                         rename_all,
                     );
 
+                    let mapping = self
+                        .tagged_variant_reference(
+                            &unnamed_fields.unnamed[0],
+                            &unnamed_struct_features,
+                        )?
+                        .map(|reference| {
+                            let value = variant_name.as_deref().unwrap_or(&name).to_string();
+                            (value, reference)
+                        });
+
                     let unnamed_enum = UnnamedStructSchema {
                         struct_name: Cow::Borrowed(&*self.enum_name),
                         attributes: &variant.attrs,
                         features: Some(unnamed_struct_features),
                         fields: &unnamed_fields.unnamed,
-                        schema_as: None,
+                        schema_name: None,
+                        aliases: self.aliases.clone(),
                     };
 
                     let title = title_features.first().map(ToTokens::to_token_stream);
@@ -1302,8 +1643,8 @@ impl ComplexEnum<'_> {
                         .iter()
                         .any(|type_tree| type_tree.value_type == ValueType::Object);
 
-                    if is_reference {
-                        Ok(quote! {
+                    let tokens = if is_reference {
+                        quote! {
                             utoipa::openapi::schema::AllOfBuilder::new()
                                 #title
                                 .item(#unnamed_enum)
@@ -1312,16 +1653,18 @@ impl ComplexEnum<'_> {
                                     .property(#tag, #variant_name_tokens)
                                     .required(#tag)
                                 )
-                        })
+                        }
                     } else {
-                        Ok(quote! {
+                        quote! {
                             #unnamed_enum
                                 #title
                                 .schema_type(utoipa::openapi::schema::SchemaType::Object)
                                 .property(#tag, #variant_name_tokens)
                                 .required(#tag)
-                        })
-                    }
+                        }
+                    };
+
+                    Ok((tokens, mapping))
                 } else {
                     Err(Diagnostics::with_span(variant.span(),
                         "Unnamed (tuple) enum variants are unsupported for internally tagged enums using the `tag = ` serde attribute")
@@ -1353,16 +1696,61 @@ impl ComplexEnum<'_> {
                         .to_token_stream(),
                 }]);
 
-                Ok(quote! {
-                    utoipa::openapi::schema::ObjectBuilder::new()
-                        #title
-                        .property(#tag, #variant_tokens)
-                        .required(#tag)
-                })
+                Ok((
+                    quote! {
+                        utoipa::openapi::schema::ObjectBuilder::new()
+                            #title
+                            .property(#tag, #variant_tokens)
+                            .required(#tag)
+                    },
+                    None,
+                ))
             }
         }
     }
 
+    /// Resolve name of the schema referenced by the single field of internally tagged unnamed
+    /// variant. Fields which are inlined or have overridden type are not references.
+    fn tagged_variant_reference(
+        &self,
+        field: &Field,
+        variant_features: &[Feature],
+    ) -> Result<Option<String>, Diagnostics> {
+        let field_features = features::parse_schema_features_with(&field.attrs, |input| {
+            Ok(parse_features!(
+                input as super::features::ValueType,
+                super::features::Format,
+                super::features::Inline
+            ))
+        })?
+        .unwrap_or_default();
+        if field_features.is_inline()
+            || variant_features
+                .iter()
+                .chain(&field_features)
+                .any(|feature| matches!(feature, Feature::ValueType(_)))
+        {
+            return Ok(None);
+        }
+
+        let mut type_tree = TypeTree::from_type(&field.ty)?;
+        replace_generics_with_aliases(&mut type_tree, &self.aliases);
+        if type_tree.value_type != ValueType::Object
+            || type_tree.generic_type.is_some()
+            || type_tree.is_object()
+        {
+            return Ok(None);
+        }
+
+        Ok(type_tree.reference_name().map(|name| {
+            let name = match name {
+                name if name == "Self" => self.enum_name.to_string(),
+                name => name,
+            };
+            format!("#/components/schemas/{name}")
+        }))
+    }
+
     // FIXME perhaps design this better to lessen the amount of args.
     #[allow(clippy::too_many_arguments)]
     fn adjacently_tagged_variant_tokens(
@@ -1398,10 +1786,12 @@ impl ComplexEnum<'_> {
                     features: Some(named_struct_features),
                     fields: &named_fields.named,
                     generics: None,
-                    aliases: None,
-                    schema_as: None,
+                    aliases: self.aliases.clone(),
+                    schema_name: None,
                 };
                 let title = title_features.first().map(ToTokens::to_token_stream);
+                let deny_unknown_fields =
+                    enum_variant_deny_unknown_fields(container_rules, &named_fields.named)?;
 
                 let variant_name_tokens = Enum::new([SimpleEnumVariant {
                     value: variant_name
@@ -1414,7 +1804,7 @@ impl ComplexEnum<'_> {
                         .schema_type(utoipa::openapi::schema::SchemaType::Object)
                         .property(#tag, #variant_name_tokens)
                         .required(#tag)
-                        .property(#content, #named_enum)
+                        .property(#content, #named_enum #deny_unknown_fields)
                         .required(#content)
                 })
             }
@@ -1439,7 +1829,8 @@ impl ComplexEnum<'_> {
                         attributes: &variant.attrs,
                         features: Some(unnamed_struct_features),
                         fields: &unnamed_fields.unnamed,
-                        schema_as: None,
+                        schema_name: None,
+                        aliases: self.aliases.clone(),
                     };
 
                     let title = title_features.first().map(ToTokens::to_token_stream);
@@ -1518,7 +1909,8 @@ impl ComplexEnum<'_> {
             | SerdeEnumRepr::UnfinishedAdjacentlyTagged { .. } => None,
         };
 
-        self.variants
+        let (variants, mapping) = self
+            .variants
             .iter()
             .map(|variant| match serde::parse_value(&variant.attrs) {
                 Ok(variant_rules) => Ok((variant, variant_rules)),
@@ -1537,13 +1929,15 @@ impl ComplexEnum<'_> {
                 let variant_name = &*variant.ident.to_string();
 
                 match &enum_repr {
-                    SerdeEnumRepr::ExternallyTagged => self.variant_tokens(
-                        Cow::Borrowed(variant_name),
-                        variant,
-                        &variant_serde_rules,
-                        &container_rules,
-                        &self.rename_all,
-                    ),
+                    SerdeEnumRepr::ExternallyTagged => self
+                        .variant_tokens(
+                            Cow::Borrowed(variant_name),
+                            variant,
+                            &variant_serde_rules,
+                            &container_rules,
+                            &self.rename_all,
+                        )
+                        .map(|tokens| (tokens, None)),
                     SerdeEnumRepr::InternallyTagged { tag } => self.tagged_variant_tokens(
                         tag,
                         Cow::Borrowed(variant_name),
@@ -1552,7 +1946,9 @@ impl ComplexEnum<'_> {
                         &container_rules,
                         &self.rename_all,
                     ),
-                    SerdeEnumRepr::Untagged => self.untagged_variant_tokens(variant),
+                    SerdeEnumRepr::Untagged => self
+                        .untagged_variant_tokens(variant, &container_rules)
+                        .map(|tokens| (tokens, None)),
                     SerdeEnumRepr::AdjacentlyTagged { tag, content } => self
                         .adjacently_tagged_variant_tokens(
                             tag,
@@ -1562,14 +1958,22 @@ impl ComplexEnum<'_> {
                             &variant_serde_rules,
                             &container_rules,
                             &self.rename_all,
-                        ),
+                        )
+                        .map(|tokens| (tokens, None)),
                     SerdeEnumRepr::UnfinishedAdjacentlyTagged { .. } => {
                         unreachable!("Serde should not have parsed an UnfinishedAdjacentlyTagged")
                     }
                 }
             })
-            .collect::<Result<CustomEnum<'_, TokenStream>, Diagnostics>>()?
+            .collect::<Result<Vec<_>, Diagnostics>>()?
+            .into_iter()
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        variants
+            .into_iter()
+            .collect::<CustomEnum<'_, TokenStream>>()
             .with_discriminator(tag.map(|t| Cow::Borrowed(t.as_str())))
+            .with_discriminator_mapping(mapping.into_iter().flatten())
             .to_tokens(tokens);
 
         tokens.extend(self.enum_features.to_token_stream());
@@ -1602,6 +2006,38 @@ impl ToTokens for Property {
     }
 }
 
+/// Name of the schema given with container level `as = ...` or `rename = "..."` attribute
+/// overriding the name of the Rust type.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum SchemaName {
+    As(As),
+    Rename(Rename),
+}
+
+impl SchemaName {
+    /// Pop schema name features from container features. `as` takes precedence over `rename`.
+    fn pop_from<F: FeaturesExt>(features: &mut F) -> Option<Self> {
+        let schema_as = pop_feature_as_inner!(features => Feature::As(_v));
+        let rename = features.pop_rename_feature();
+
+        schema_as.map(Self::As).or(rename.map(Self::Rename))
+    }
+
+    fn to_name(&self) -> String {
+        match self {
+            Self::As(schema_as) => format_path_ref(&schema_as.0.path),
+            Self::Rename(rename) => rename.clone().into_value(),
+        }
+    }
+}
+
+/// Explicit `description = "..."` container attribute takes precedence over doc comments.
+fn has_description_feature<'a>(features: impl IntoIterator<Item = &'a Feature>) -> bool {
+    features
+        .into_iter()
+        .any(|feature| matches!(feature, Feature::Description(_)))
+}
+
 trait SchemaFeatureExt {
     fn split_for_title(self) -> (Vec<Feature>, Vec<Feature>);
 }
@@ -1627,6 +2063,36 @@ pub(crate) fn format_path_ref(path: &Path) -> String {
     path.to_token_stream().to_string().replace(" :: ", ".")
 }
 
+/// Serde applies container level `deny_unknown_fields` of an enum to the fields of its named
+/// field variants. Variants with flattened fields are left as is since they are rendered as
+/// `allOf` schemas.
+#[inline]
+fn enum_variant_deny_unknown_fields(
+    container_rules: &Option<SerdeContainer>,
+    fields: &Punctuated<Field, Comma>,
+) -> Result<Option<TokenStream>, Diagnostics> {
+    let deny_unknown_fields = container_rules
+        .as_ref()
+        .map(|container_rule| container_rule.deny_unknown_fields)
+        .unwrap_or(false);
+    if !deny_unknown_fields {
+        return Ok(None);
+    }
+
+    let has_flattened_fields = fields
+        .iter()
+        .map(|field| serde::parse_value(&field.attrs))
+        .collect::<Result<Vec<_>, Diagnostics>>()?
+        .iter()
+        .any(|field_rules| is_flatten(field_rules.as_ref()));
+
+    Ok((!has_flattened_fields).then(|| {
+        quote! {
+            .additional_properties(Some(utoipa::openapi::schema::AdditionalProperties::FreeForm(false)))
+        }
+    }))
+}
+
 #[inline]
 fn is_not_skipped(rule: &Option<SerdeValue>) -> bool {
     rule.as_ref().map(|value| !value.skip).unwrap_or(true)
@@ -1688,6 +2154,20 @@ impl Parse for AliasSchema {
     }
 }
 
+/// Replace generic arguments of the `type_tree` with the concrete types of the aliases.
+fn replace_generics_with_aliases<'t>(
+    type_tree: &mut TypeTree<'t>,
+    aliases: &Option<Vec<(TypeTree<'t>, &'t TypeTree<'t>)>>,
+) {
+    if let Some(aliases) = aliases {
+        for (new_generic, old_generic_matcher) in aliases.iter() {
+            if let Some(generic_match) = type_tree.find_mut(old_generic_matcher) {
+                *generic_match = new_generic.clone();
+            }
+        }
+    }
+}
+
 fn parse_aliases(
     attributes: &[Attribute],
 ) -> Result<Option<Punctuated<AliasSchema, Comma>>, Diagnostics> {
@@ -262,75 +262,6 @@ impl ToTokens for UntaggedEnum {
     }
 }
 
-pub struct AdjacentlyTaggedEnum<T: Variant> {
-    items: TokenStream,
-    len: usize,
-    _p: PhantomData<T>,
-}
-
-impl<V: Variant> AdjacentlyTaggedEnum<V> {
-    pub fn new<'t, I: IntoIterator<Item = (Cow<'t, str>, Cow<'t, str>, V)>>(items: I) -> Self {
-        items.into_iter().collect()
-    }
-}
-
-impl<T> ToTokens for AdjacentlyTaggedEnum<T>
-where
-    T: Variant,
-{
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let len = &self.len;
-        let items = &self.items;
-
-        tokens.extend(quote! {
-            Into::<utoipa::openapi::schema::OneOfBuilder>::into(utoipa::openapi::OneOf::with_capacity(#len))
-                #items
-        })
-    }
-}
-
-impl<'t, V: Variant> FromIterator<(Cow<'t, str>, Cow<'t, str>, V)> for AdjacentlyTaggedEnum<V> {
-    fn from_iter<T: IntoIterator<Item = (Cow<'t, str>, Cow<'t, str>, V)>>(iter: T) -> Self {
-        let mut len = 0;
-
-        let items = iter
-            .into_iter()
-            .enumerate()
-            .map(|(index, (tag, content, variant))| {
-                len = index + 1;
-
-                let (schema_type, enum_type) = variant.get_type();
-                let item = variant.to_tokens();
-                quote! {
-                    .item(
-                        utoipa::openapi::schema::ObjectBuilder::new()
-                            .property(
-                                #tag,
-                                utoipa::openapi::schema::ObjectBuilder::new()
-                                    .schema_type(utoipa::openapi::schema::SchemaType::String)
-                                    .enum_values::<[#enum_type; 1], #enum_type>(Some([#content]))
-                            )
-                            .required(#tag)
-                            .property(
-                                #content,
-                                utoipa::openapi::schema::ObjectBuilder::new()
-                                    .schema_type(#schema_type)
-                                    .enum_values::<[#enum_type; 1], #enum_type>(Some([#item]))
-                            )
-                            .required(#content)
-                    )
-                }
-            })
-            .collect::<TokenStream>();
-
-        Self {
-            items,
-            len,
-            _p: PhantomData,
-        }
-    }
-}
-
 /// Used to create complex enums with varying Object types.
 ///
 /// Will create `oneOf` object with discriminator field for referenced schemas.
@@ -338,6 +269,7 @@ pub struct CustomEnum<'c, T: ToTokens> {
     // pub items: Cow<'c, >,
     items: T,
     tag: Option<Cow<'c, str>>,
+    mapping: Vec<(String, String)>,
 }
 
 impl<'c, T: ToTokens> CustomEnum<'c, T> {
@@ -346,6 +278,16 @@ impl<'c, T: ToTokens> CustomEnum<'c, T> {
 
         self
     }
+
+    /// Add mapping from discriminator values to schema references of the discriminator.
+    pub fn with_discriminator_mapping<I: IntoIterator<Item = (String, String)>>(
+        mut self,
+        mapping: I,
+    ) -> Self {
+        self.mapping = mapping.into_iter().collect();
+
+        self
+    }
 }
 
 impl<'c, T> ToTokens for CustomEnum<'c, T>
@@ -358,8 +300,18 @@ where
         // currently uses serde `tag` attribute as a discriminator. This discriminator
         // feature needs some refinement.
         let discriminator = self.tag.as_ref().map(|tag| {
+            let mapping = self
+                .mapping
+                .iter()
+                .map(|(value, reference)| quote! { .mapping(#value, #reference) });
+
             quote! {
-                .discriminator(Some(utoipa::openapi::schema::Discriminator::new(#tag)))
+                .discriminator(Some(
+                    utoipa::openapi::schema::DiscriminatorBuilder::new()
+                        .property_name(#tag)
+                        #( #mapping )*
+                        .build()
+                ))
             }
         });
 
@@ -396,6 +348,7 @@ impl FromIterator<TokenStream> for CustomEnum<'_, TokenStream> {
         CustomEnum {
             items: tokens,
             tag: None,
+            mapping: Vec::new(),
         }
     }
 }
@@ -6,10 +6,11 @@ use syn::{
 use crate::{
     component::features::{
         impl_into_inner, impl_merge, parse_features, AdditionalProperties, As, Default, Deprecated,
-        Example, ExclusiveMaximum, ExclusiveMinimum, Feature, Format, Inline, IntoInner, MaxItems,
-        MaxLength, MaxProperties, Maximum, Merge, MinItems, MinLength, MinProperties, Minimum,
-        MultipleOf, Nullable, Pattern, ReadOnly, Rename, RenameAll, Required, SchemaWith, Title,
-        ValueType, WriteOnly, XmlAttr,
+        Description, EnumValues, Example, ExclusiveMaximum, ExclusiveMinimum, Extensions,
+        ExternalDocs, Feature, Form, Format, Inline, IntoInner, MaxItems, MaxLength, MaxProperties,
+        Maximum, Merge, MinItems, MinLength, MinProperties, Minimum, MultipleOf, Nullable, Pattern,
+        ReadOnly, Rename, RenameAll, Required, SchemaWith, Title, UniqueItems, ValueType,
+        WriteOnly, XmlAttr,
     },
     Diagnostics,
 };
@@ -23,12 +24,17 @@ impl Parse for NamedFieldStructFeatures {
             input as Example,
             XmlAttr,
             Title,
+            Description,
+            Rename,
             RenameAll,
             MaxProperties,
             MinProperties,
             As,
             Default,
-            Deprecated
+            Deprecated,
+            ExternalDocs,
+            Extensions,
+            Form
         )))
     }
 }
@@ -47,7 +53,9 @@ impl Parse for UnnamedFieldStructFeatures {
             Format,
             ValueType,
             As,
-            Deprecated
+            Rename,
+            Deprecated,
+            EnumValues
         )))
     }
 }
@@ -62,9 +70,14 @@ impl Parse for EnumFeatures {
             input as Example,
             Default,
             Title,
+            Description,
+            Rename,
             RenameAll,
             As,
-            Deprecated
+            Deprecated,
+            ExternalDocs,
+            Extensions,
+            XmlAttr
         )))
     }
 }
@@ -78,9 +91,14 @@ impl Parse for ComplexEnumFeatures {
         Ok(ComplexEnumFeatures(parse_features!(
             input as Example,
             Default,
+            Title,
+            Description,
+            Rename,
             RenameAll,
             As,
-            Deprecated
+            Deprecated,
+            ExternalDocs,
+            Extensions
         )))
     }
 }
@@ -110,8 +128,10 @@ impl Parse for NamedFieldFeatures {
             MaxLength,
             MinLength,
             Pattern,
+            EnumValues,
             MaxItems,
             MinItems,
+            UniqueItems,
             SchemaWith,
             AdditionalProperties,
             Required,
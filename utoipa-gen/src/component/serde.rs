@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use proc_macro2::{Ident, Span, TokenTree};
+use proc_macro2::{Delimiter, Ident, Span, TokenTree};
 use syn::{buffer::Cursor, Attribute, Error};
 
 use crate::Diagnostics;
@@ -21,6 +21,27 @@ fn parse_next_lit_str(next: Cursor) -> Option<(String, Span)> {
     }
 }
 
+/// Parse next literal string either from `= "value"` or from the `serialize = "value"` of the
+/// `(serialize = "value", deserialize = "value")` form. Since the schema describes the serialized
+/// form of a type the `deserialize` value is ignored.
+#[inline]
+fn parse_next_lit_str_or_serialize(next: Cursor) -> Option<(String, Span)> {
+    match next.group(Delimiter::Parenthesis) {
+        Some((mut rest, _, _)) => {
+            while let Some((tt, next)) = rest.token_tree() {
+                match tt {
+                    TokenTree::Ident(ident) if ident == "serialize" => {
+                        return parse_next_lit_str(next)
+                    }
+                    _ => rest = next,
+                }
+            }
+            None
+        }
+        None => parse_next_lit_str(next),
+    }
+}
+
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(test, derive(PartialEq, Eq))]
@@ -28,9 +49,15 @@ pub struct SerdeValue {
     pub skip: bool,
     pub rename: Option<String>,
     pub default: bool,
+    /// Path of the function given with `#[serde(default = "path")]`.
+    pub default_path: Option<syn::ExprPath>,
     pub flatten: bool,
     pub skip_serializing_if: bool,
     pub double_option: bool,
+    /// Type argument of `serde_with::As::<T>` given with `#[serde(with = "...")]` or
+    /// `#[serde(serialize_with = "...")]`.
+    #[cfg(feature = "serde_with")]
+    pub serde_as: Option<syn::Type>,
 }
 
 impl SerdeValue {
@@ -56,7 +83,14 @@ impl SerdeValue {
                         value.skip_serializing_if = true
                     }
                     TokenTree::Ident(ident) if ident == "with" => {
-                        value.double_option = parse_next_lit_str(next)
+                        let with = parse_next_lit_str(next);
+                        #[cfg(feature = "serde_with")]
+                        {
+                            value.serde_as = with
+                                .as_ref()
+                                .and_then(|(literal, _)| super::serde_with::parse_as_type(literal));
+                        }
+                        value.double_option = with
                             .and_then(|(literal, _)| {
                                 if literal == SerdeValue::SERDE_WITH_DOUBLE_OPTION {
                                     Some(true)
@@ -66,13 +100,26 @@ impl SerdeValue {
                             })
                             .unwrap_or(false);
                     }
+                    #[cfg(feature = "serde_with")]
+                    TokenTree::Ident(ident) if ident == "serialize_with" => {
+                        value.serde_as = parse_next_lit_str(next)
+                            .and_then(|(literal, _)| super::serde_with::parse_as_type(&literal));
+                    }
                     TokenTree::Ident(ident) if ident == "flatten" => value.flatten = true,
                     TokenTree::Ident(ident) if ident == "rename" => {
-                        if let Some((literal, _)) = parse_next_lit_str(next) {
+                        if let Some((literal, _)) = parse_next_lit_str_or_serialize(next) {
                             value.rename = Some(literal)
                         };
                     }
-                    TokenTree::Ident(ident) if ident == "default" => value.default = true,
+                    TokenTree::Ident(ident) if ident == "default" => {
+                        value.default = true;
+                        if let Some((literal, span)) = parse_next_lit_str(next) {
+                            value.default_path =
+                                Some(syn::parse_str(&literal).map_err(|error| {
+                                    Error::new(span, format!("invalid default path: {error}"))
+                                })?)
+                        }
+                    }
                     _ => (),
                 }
 
@@ -123,20 +170,24 @@ pub struct SerdeContainer {
     pub enum_repr: SerdeEnumRepr,
     pub default: bool,
     pub deny_unknown_fields: bool,
+    pub remote: Option<syn::Path>,
+    pub transparent: bool,
 }
 
 impl SerdeContainer {
     /// Parse a single serde attribute, currently supported attributes are:
-    ///     * `rename_all = ...`
+    ///     * `rename_all = ...` or `rename_all(serialize = ...)`
     ///     * `tag = ...`
     ///     * `content = ...`
     ///     * `untagged = ...`
     ///     * `default = ...`
     ///     * `deny_unknown_fields`
+    ///     * `remote = ...`
+    ///     * `transparent`
     fn parse_attribute(&mut self, ident: Ident, next: Cursor) -> syn::Result<()> {
         match ident.to_string().as_str() {
             "rename_all" => {
-                if let Some((literal, span)) = parse_next_lit_str(next) {
+                if let Some((literal, span)) = parse_next_lit_str_or_serialize(next) {
                     self.rename_all = Some(
                         literal
                             .parse::<RenameRule>()
@@ -197,6 +248,16 @@ impl SerdeContainer {
             "deny_unknown_fields" => {
                 self.deny_unknown_fields = true;
             }
+            "transparent" => {
+                self.transparent = true;
+            }
+            "remote" => {
+                if let Some((literal, span)) = parse_next_lit_str(next) {
+                    self.remote = Some(syn::parse_str(&literal).map_err(|error| {
+                        Error::new(span, format!("invalid remote path: {error}"))
+                    })?);
+                }
+            }
             _ => {}
         }
         Ok(())
@@ -250,9 +311,16 @@ pub fn parse_value(attributes: &[Attribute]) -> Result<Option<SerdeValue>, Diagn
                 if value.default {
                     acc.default = value.default;
                 }
+                if value.default_path.is_some() {
+                    acc.default_path = value.default_path;
+                }
                 if value.double_option {
                     acc.double_option = value.double_option;
                 }
+                #[cfg(feature = "serde_with")]
+                if value.serde_as.is_some() {
+                    acc.serde_as = value.serde_as;
+                }
 
                 acc
             })
@@ -292,6 +360,12 @@ pub fn parse_container(attributes: &[Attribute]) -> Result<Option<SerdeContainer
                 if value.rename_all.is_some() {
                     acc.rename_all = value.rename_all;
                 }
+                if value.remote.is_some() {
+                    acc.remote = value.remote;
+                }
+                if value.transparent {
+                    acc.transparent = value.transparent;
+                }
 
                 acc
             })
@@ -416,7 +490,10 @@ impl FromStr for RenameRule {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_container, RenameRule, SerdeContainer, RENAME_RULE_NAME_MAPPING};
+    use super::{
+        parse_container, parse_value, RenameRule, SerdeContainer, SerdeValue,
+        RENAME_RULE_NAME_MAPPING,
+    };
     use syn::{parse_quote, Attribute};
 
     macro_rules! test_rename_rule {
@@ -520,4 +597,92 @@ mod tests {
         let result = parse_container(attributes).expect("parse succes").unwrap();
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_serde_parse_container_rename_all_serialize() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename_all(serialize = "camelCase", deserialize = "snake_case"))]
+        }];
+
+        let expected = SerdeContainer {
+            rename_all: Some(RenameRule::Camel),
+            ..Default::default()
+        };
+
+        let result = parse_container(attributes).expect("parse succes").unwrap();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_serde_parse_container_remote() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(remote = "other::Thing")]
+        }];
+
+        let expected = SerdeContainer {
+            remote: Some(parse_quote!(other::Thing)),
+            ..Default::default()
+        };
+
+        let result = parse_container(attributes).expect("parse succes").unwrap();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_serde_parse_value_rename_serialize() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename(deserialize = "in_name", serialize = "out_name"), default)]
+        }];
+
+        let expected = SerdeValue {
+            rename: Some("out_name".to_string()),
+            default: true,
+            ..Default::default()
+        };
+
+        let result = parse_value(attributes).expect("parse succes").unwrap();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_serde_parse_value_rename_deserialize_only() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename(deserialize = "in_name"))]
+        }];
+
+        let result = parse_value(attributes).expect("parse succes").unwrap();
+        assert_eq!(SerdeValue::default(), result);
+    }
+
+    #[test]
+    fn test_serde_parse_value_default_path() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(default = "defaults::limit")]
+        }];
+
+        let expected = SerdeValue {
+            default: true,
+            default_path: Some(parse_quote!(defaults::limit)),
+            ..Default::default()
+        };
+
+        let result = parse_value(attributes).expect("parse succes").unwrap();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    #[cfg(feature = "serde_with")]
+    fn test_serde_parse_value_serde_with_as() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(with = ":: serde_with :: As :: < Vec < DisplayFromStr > >")]
+        }];
+
+        let expected = SerdeValue {
+            serde_as: Some(parse_quote!(Vec<DisplayFromStr>)),
+            ..Default::default()
+        };
+
+        let result = parse_value(attributes).expect("parse succes").unwrap();
+        assert_eq!(expected, result);
+    }
 }
@@ -0,0 +1,201 @@
+//! Provides [serde_with](https://docs.rs/serde_with) crate support by resolving the type a field
+//! is serialized as from the `serde_with::As::<T>` adaptor.
+//!
+//! The `#[serde_as]` attribute macro translates field level `#[serde_as(as = "...")]` attributes
+//! to `#[serde(with = "::serde_with::As::<...>")]` attributes before derive macros are expanded,
+//! thus the adaptor is read from the `with` or `serialize_with` serde attribute.
+//!
+//! Supported adaptors are:
+//!     * `DisplayFromStr` serialized as `String`.
+//!     * `Base64` serialized as `String` with `byte` format.
+//!     * `DurationSeconds`, `DurationMilliSeconds`, `DurationMicroSeconds` and
+//!       `DurationNanoSeconds` serialized as their format type, `u64` by default.
+//!     * `DurationSecondsWithFrac`, `DurationMilliSecondsWithFrac`,
+//!       `DurationMicroSecondsWithFrac` and `DurationNanoSecondsWithFrac` serialized as their
+//!       format type, `f64` by default.
+//!     * `TimestampSeconds`, `TimestampMilliSeconds`, `TimestampMicroSeconds` and
+//!       `TimestampNanoSeconds` serialized as their format type, `i64` by default.
+//!     * `TimestampSecondsWithFrac`, `TimestampMilliSecondsWithFrac`,
+//!       `TimestampMicroSecondsWithFrac` and `TimestampNanoSecondsWithFrac` serialized as their
+//!       format type, `f64` by default.
+//!
+//! Adaptors can be nested within the field's own container types e.g. `Vec<DisplayFromStr>` or
+//! `Option<Base64>`. `Same`, `_` and other unknown adaptors resolve to the field's own type.
+
+use syn::{
+    parse_quote, ExprPath, GenericArgument, PathArguments, Type, TypeArray, TypePath, TypeTuple,
+};
+
+use crate::schema_type::{SchemaFormat, Variant};
+
+use super::{
+    features::{Feature, Format},
+    serde::SerdeValue,
+};
+
+/// Type a field is serialized as with `serde_with` adaptor.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SerdeAs {
+    pub ty: Type,
+    format: Option<Variant>,
+}
+
+impl SerdeAs {
+    /// Resolve [`SerdeAs`] for field of type `ty` from the field's serde attributes. Returns `None`
+    /// if field is not serialized with `serde_with::As`.
+    pub fn resolve(field_rules: Option<&SerdeValue>, ty: &Type) -> Option<Self> {
+        let as_type = field_rules.and_then(|rules| rules.serde_as.as_ref())?;
+        let mut format = None;
+        let ty = resolve_type(as_type, ty, &mut format);
+
+        Some(Self { ty, format })
+    }
+
+    /// Append format resolved from the adaptor to the `features` unless format is already
+    /// defined e.g. with `#[schema(format = ...)]` attribute.
+    pub fn extend_features(&self, features: &mut Vec<Feature>) {
+        if let Some(format) = &self.format {
+            if !features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Format(_)))
+            {
+                features.push(Format::new(SchemaFormat::Variant(format.clone())).into());
+            }
+        }
+    }
+}
+
+/// Parse the type argument of `serde_with::As::<T>` from the path given to serde `with` or
+/// `serialize_with` attribute.
+pub fn parse_as_type(path: &str) -> Option<Type> {
+    let path = syn::parse_str::<ExprPath>(path).ok()?;
+
+    path.path
+        .segments
+        .iter()
+        .find(|segment| segment.ident == "As")
+        .and_then(|segment| match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => {
+                arguments.args.iter().find_map(|argument| match argument {
+                    GenericArgument::Type(ty) => Some(ty.clone()),
+                    _ => None,
+                })
+            }
+            _ => None,
+        })
+}
+
+fn resolve_type(as_type: &Type, ty: &Type, format: &mut Option<Variant>) -> Type {
+    match (as_type, ty) {
+        (Type::Path(as_path), _) if as_path.qself.is_none() => {
+            if let Some(adaptor_type) = resolve_adaptor(as_path, format) {
+                return adaptor_type;
+            }
+
+            match ty {
+                Type::Path(TypePath { qself: None, path }) => {
+                    let (Some(as_segment), Some(segment)) =
+                        (as_path.path.segments.last(), path.segments.last())
+                    else {
+                        return ty.clone();
+                    };
+                    let (
+                        PathArguments::AngleBracketed(as_arguments),
+                        PathArguments::AngleBracketed(arguments),
+                    ) = (&as_segment.arguments, &segment.arguments)
+                    else {
+                        return ty.clone();
+                    };
+
+                    if as_segment.ident != segment.ident
+                        || as_arguments.args.len() != arguments.args.len()
+                    {
+                        return ty.clone();
+                    }
+
+                    let mut resolved = ty.clone();
+                    if let Type::Path(TypePath { path, .. }) = &mut resolved {
+                        if let Some(PathArguments::AngleBracketed(arguments)) = path
+                            .segments
+                            .last_mut()
+                            .map(|segment| &mut segment.arguments)
+                        {
+                            for (argument, as_argument) in
+                                arguments.args.iter_mut().zip(&as_arguments.args)
+                            {
+                                if let (GenericArgument::Type(ty), GenericArgument::Type(as_ty)) =
+                                    (argument, as_argument)
+                                {
+                                    *ty = resolve_type(as_ty, ty, format);
+                                }
+                            }
+                        }
+                    }
+
+                    resolved
+                }
+                _ => ty.clone(),
+            }
+        }
+        (Type::Array(as_array), Type::Array(array)) => Type::Array(TypeArray {
+            elem: Box::new(resolve_type(&as_array.elem, &array.elem, format)),
+            ..array.clone()
+        }),
+        (Type::Tuple(as_tuple), Type::Tuple(tuple))
+            if as_tuple.elems.len() == tuple.elems.len() =>
+        {
+            Type::Tuple(TypeTuple {
+                elems: tuple
+                    .elems
+                    .iter()
+                    .zip(&as_tuple.elems)
+                    .map(|(ty, as_ty)| resolve_type(as_ty, ty, format))
+                    .collect(),
+                ..tuple.clone()
+            })
+        }
+        (Type::Paren(as_paren), _) => resolve_type(&as_paren.elem, ty, format),
+        (Type::Group(as_group), _) => resolve_type(&as_group.elem, ty, format),
+        _ => ty.clone(),
+    }
+}
+
+/// Resolve the serialized type of known `serde_with` adaptor.
+fn resolve_adaptor(as_path: &TypePath, format: &mut Option<Variant>) -> Option<Type> {
+    let segment = as_path.path.segments.last()?;
+    let name = segment.ident.to_string();
+    let format_type = || match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(GenericArgument::Type(ty)) if !matches!(ty, Type::Infer(_)) => Some(ty.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match &*name {
+        "DisplayFromStr" => Some(parse_quote!(String)),
+        "Base64" => {
+            *format = Some(Variant::Byte);
+            Some(parse_quote!(String))
+        }
+        "DurationSeconds"
+        | "DurationMilliSeconds"
+        | "DurationMicroSeconds"
+        | "DurationNanoSeconds" => Some(format_type().unwrap_or_else(|| parse_quote!(u64))),
+        "TimestampSeconds"
+        | "TimestampMilliSeconds"
+        | "TimestampMicroSeconds"
+        | "TimestampNanoSeconds" => Some(format_type().unwrap_or_else(|| parse_quote!(i64))),
+        "DurationSecondsWithFrac"
+        | "DurationMilliSecondsWithFrac"
+        | "DurationMicroSecondsWithFrac"
+        | "DurationNanoSecondsWithFrac"
+        | "TimestampSecondsWithFrac"
+        | "TimestampMilliSecondsWithFrac"
+        | "TimestampMicroSecondsWithFrac"
+        | "TimestampNanoSecondsWithFrac" => {
+            Some(format_type().unwrap_or_else(|| parse_quote!(f64)))
+        }
+        _ => None,
+    }
+}
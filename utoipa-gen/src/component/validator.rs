@@ -0,0 +1,275 @@
+//! Provides [validator](https://docs.rs/validator) crate support by parsing the
+//! `#[validate(...)]` field attributes to schema features.
+//!
+//! Supported validations are:
+//!     * `length(min = ..., max = ..., equal = ...)`
+//!     * `range(min = ..., max = ..., exclusive_min = ..., exclusive_max = ...)`
+//!     * `email`
+//!     * `url`
+//!     * `regex = "..."` and `regex(path = ...)`
+//!
+//! Other validations and arguments that are not literal values are ignored.
+
+use proc_macro2::{Span, TokenStream};
+use syn::{
+    meta::ParseNestedMeta, parenthesized, spanned::Spanned, token::Paren, Attribute, Expr, Lit,
+    Token, UnOp,
+};
+
+use crate::{
+    schema_type::{SchemaFormat, SchemaType, Variant},
+    Diagnostics,
+};
+
+use super::{
+    features::{
+        ExclusiveMaximum, ExclusiveMinimum, Feature, Format, MaxItems, MaxLength, MaxProperties,
+        Maximum, MinItems, MinLength, MinProperties, Minimum, Pattern,
+    },
+    GenericType, TypeTree,
+};
+
+/// Kind of the value the `#[validate(...)]` attribute is defined for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    String,
+    Number,
+    Array,
+    Map,
+    Other,
+}
+
+impl ValueKind {
+    fn from_type_tree(type_tree: &TypeTree) -> Self {
+        match type_tree.generic_type {
+            Some(GenericType::Vec) | Some(GenericType::LinkedList) | Some(GenericType::Set) => {
+                return Self::Array
+            }
+            #[cfg(feature = "smallvec")]
+            Some(GenericType::SmallVec) => return Self::Array,
+            Some(GenericType::Map) => return Self::Map,
+            Some(_) => {
+                // Option, Box, Cow, RefCell etc. are transparent for validation
+                return type_tree
+                    .children
+                    .as_ref()
+                    .and_then(|children| children.first())
+                    .map(Self::from_type_tree)
+                    .unwrap_or(Self::Other);
+            }
+            None => (),
+        }
+
+        match type_tree.path.as_deref().map(SchemaType) {
+            Some(schema_type) if schema_type.is_string() => Self::String,
+            Some(schema_type) if schema_type.is_number() => Self::Number,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Parse `#[validate(...)]` attributes of a field and append the resolved features to the
+/// `features`. Features already defined e.g. with `#[schema(...)]` attribute take precedence
+/// over the `validate` attribute.
+pub fn extend_features(
+    attributes: &[Attribute],
+    type_tree: &TypeTree,
+    features: &mut Vec<Feature>,
+) -> Result<(), Diagnostics> {
+    let kind = ValueKind::from_type_tree(type_tree);
+
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+    {
+        let mut validator_features = Vec::new();
+        attribute
+            .parse_nested_meta(|meta| parse_validation(meta, kind, &mut validator_features))
+            .map_err(Diagnostics::from)?;
+
+        for feature in validator_features {
+            if !features.iter().any(|existing| {
+                std::mem::discriminant(existing) == std::mem::discriminant(&feature)
+            }) {
+                features.push(feature);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_validation(
+    meta: ParseNestedMeta,
+    kind: ValueKind,
+    features: &mut Vec<Feature>,
+) -> syn::Result<()> {
+    let span = meta.path.span();
+    if meta.path.is_ident("length") {
+        meta.parse_nested_meta(|meta| {
+            let value = parse_value(&meta)?;
+            let Some(length) = value.as_ref().and_then(|value| value.base10_usize()) else {
+                return Ok(());
+            };
+            let span = meta.path.span();
+
+            if meta.path.is_ident("min") || meta.path.is_ident("equal") {
+                features.extend(min_length_feature(kind, length, span));
+            }
+            if meta.path.is_ident("max") || meta.path.is_ident("equal") {
+                features.extend(max_length_feature(kind, length, span));
+            }
+            Ok(())
+        })
+    } else if meta.path.is_ident("range") {
+        meta.parse_nested_meta(|meta| {
+            let value = parse_value(&meta)?;
+            let Some(number) = value.as_ref().and_then(NumberValue::as_f64) else {
+                return Ok(());
+            };
+            if kind != ValueKind::Number {
+                return Ok(());
+            }
+            let span = meta.path.span();
+
+            if meta.path.is_ident("min") {
+                features.push(Feature::Minimum(Minimum::new(number, span)));
+            } else if meta.path.is_ident("max") {
+                features.push(Feature::Maximum(Maximum::new(number, span)));
+            } else if meta.path.is_ident("exclusive_min") {
+                features.push(Feature::ExclusiveMinimum(ExclusiveMinimum::new(
+                    number, span,
+                )));
+            } else if meta.path.is_ident("exclusive_max") {
+                features.push(Feature::ExclusiveMaximum(ExclusiveMaximum::new(
+                    number, span,
+                )));
+            }
+            Ok(())
+        })
+    } else if meta.path.is_ident("email") || meta.path.is_ident("url") {
+        skip_meta(&meta)?;
+        if kind == ValueKind::String {
+            let format = if meta.path.is_ident("email") {
+                "email"
+            } else {
+                "uri"
+            };
+            features.push(Feature::Format(Format::new(SchemaFormat::Variant(
+                Variant::Custom(format.to_string()),
+            ))));
+        }
+        Ok(())
+    } else if meta.path.is_ident("regex") {
+        let regex = if meta.input.peek(Token![=]) {
+            // validator < 0.17 syntax `regex = "PATH"`
+            let path = meta.value()?.parse::<syn::LitStr>()?;
+            Some(path.parse::<Expr>()?)
+        } else {
+            let mut regex = None;
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    let value = meta.value()?;
+                    regex = Some(if value.peek(syn::LitStr) {
+                        value.parse::<syn::LitStr>()?.parse::<Expr>()?
+                    } else {
+                        value.parse::<Expr>()?
+                    });
+                    Ok(())
+                } else {
+                    skip_meta(&meta)
+                }
+            })?;
+            regex
+        };
+
+        if let Some(regex) = regex.filter(|_| kind == ValueKind::String) {
+            features.push(Feature::Pattern(Pattern::new_regex(&regex, span)));
+        }
+        Ok(())
+    } else {
+        skip_meta(&meta)
+    }
+}
+
+fn min_length_feature(kind: ValueKind, length: usize, span: Span) -> Option<Feature> {
+    match kind {
+        ValueKind::String => Some(Feature::MinLength(MinLength::new(length, span))),
+        ValueKind::Array => Some(Feature::MinItems(MinItems::new(length, span))),
+        ValueKind::Map => Some(Feature::MinProperties(MinProperties::new(length))),
+        ValueKind::Number | ValueKind::Other => None,
+    }
+}
+
+fn max_length_feature(kind: ValueKind, length: usize, span: Span) -> Option<Feature> {
+    match kind {
+        ValueKind::String => Some(Feature::MaxLength(MaxLength::new(length, span))),
+        ValueKind::Array => Some(Feature::MaxItems(MaxItems::new(length, span))),
+        ValueKind::Map => Some(Feature::MaxProperties(MaxProperties::new(length))),
+        ValueKind::Number | ValueKind::Other => None,
+    }
+}
+
+/// Literal number value of a validation argument.
+struct NumberValue {
+    lit: Lit,
+    negative: bool,
+}
+
+impl NumberValue {
+    fn base10_usize(&self) -> Option<usize> {
+        match &self.lit {
+            Lit::Int(int) if !self.negative => int.base10_parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        let number = match &self.lit {
+            Lit::Int(int) => int.base10_parse::<f64>().ok(),
+            Lit::Float(float) => float.base10_parse::<f64>().ok(),
+            _ => None,
+        }?;
+
+        Some(if self.negative { -number } else { number })
+    }
+}
+
+/// Parse value of `name = value` argument. Returns `None` if value is not a literal number e.g.
+/// it is a path to a constant.
+fn parse_value(meta: &ParseNestedMeta) -> syn::Result<Option<NumberValue>> {
+    if !meta.input.peek(Token![=]) {
+        skip_meta(meta)?;
+        return Ok(None);
+    }
+
+    let value = match meta.value()?.parse::<Expr>()? {
+        Expr::Lit(expr) => Some(NumberValue {
+            lit: expr.lit,
+            negative: false,
+        }),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => match *unary.expr {
+            Expr::Lit(expr) => Some(NumberValue {
+                lit: expr.lit,
+                negative: true,
+            }),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    Ok(value)
+}
+
+/// Skip unsupported argument which can be a flag, `name = value` or `name(...)`.
+fn skip_meta(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(Paren) {
+        let content;
+        parenthesized!(content in meta.input);
+        content.parse::<TokenStream>()?;
+    }
+
+    Ok(())
+}
@@ -29,10 +29,30 @@ impl CommentAttributes {
         attribute.path().get_ident()
     }
 
+    /// Parse doc comment lines removing the indentation common to all non blank lines, so the
+    /// relative indentation of e.g. nested lists and code blocks is preserved.
     fn as_string_vec<'a, I: Iterator<Item = &'a Attribute>>(attributes: I) -> Vec<String> {
-        attributes
+        let lines = attributes
             .into_iter()
             .filter_map(Self::parse_doc_comment)
+            .flat_map(|comment| {
+                comment
+                    .split('\n')
+                    .map(|line| line.trim_end().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let indent = lines
+            .iter()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or_default();
+
+        lines
+            .into_iter()
+            .map(|line| line.get(indent..).unwrap_or_default().to_string())
             .collect()
     }
 
@@ -41,7 +61,7 @@ impl CommentAttributes {
             Meta::NameValue(name_value) => {
                 if let Expr::Lit(ref doc_comment) = name_value.value {
                     if let Lit::Str(ref comment) = doc_comment.lit {
-                        Some(comment.value().trim().to_string())
+                        Some(comment.value())
                     } else {
                         None
                     }
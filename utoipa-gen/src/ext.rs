@@ -19,18 +19,26 @@ pub mod auto_types;
 #[cfg(feature = "actix_extras")]
 pub mod actix;
 
-#[cfg(feature = "axum_extras")]
+#[cfg(any(feature = "axum_extras", feature = "poem_extras"))]
 pub mod axum;
 
 #[cfg(feature = "rocket_extras")]
 pub mod rocket;
 
+#[cfg(feature = "poem_extras")]
+pub mod poem;
+
+#[cfg(feature = "salvo_extras")]
+pub mod salvo;
+
 /// Represents single argument of handler operation.
 #[cfg_attr(
     not(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     )),
     allow(dead_code)
 )]
@@ -40,7 +48,9 @@ pub struct ValueArgument<'a> {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub argument_in: ArgumentIn,
     pub type_tree: Option<TypeTree<'a>>,
@@ -63,7 +73,9 @@ impl<'v> From<(MacroArg, TypeTree<'v>)> for ValueArgument<'v> {
     not(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     )),
     allow(dead_code)
 )]
@@ -73,6 +85,8 @@ impl<'v> From<(MacroArg, TypeTree<'v>)> for ValueArgument<'v> {
 pub struct IntoParamsType<'a> {
     pub parameter_in_provider: TokenStream,
     pub type_path: Option<Cow<'a, syn::Path>>,
+    /// Whether the type is resolved from a `Path` extractor.
+    pub is_path: bool,
 }
 
 impl<'i> From<(Option<Cow<'i, syn::Path>>, TokenStream)> for IntoParamsType<'i> {
@@ -80,6 +94,7 @@ impl<'i> From<(Option<Cow<'i, syn::Path>>, TokenStream)> for IntoParamsType<'i>
         IntoParamsType {
             parameter_in_provider,
             type_path,
+            is_path: false,
         }
     }
 }
@@ -87,13 +102,15 @@ impl<'i> From<(Option<Cow<'i, syn::Path>>, TokenStream)> for IntoParamsType<'i>
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(PartialEq, Eq)]
 pub enum ArgumentIn {
     Path,
-    #[cfg(feature = "rocket_extras")]
+    #[cfg(any(feature = "rocket_extras", feature = "salvo_extras"))]
     Query,
 }
 
@@ -109,6 +126,20 @@ impl<'t> From<TypeTree<'t>> for RequestBody<'t> {
 }
 
 impl RequestBody<'_> {
+    /// Get tokens collecting schemas referenced by the actual body type.
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    ))]
+    pub fn schema_references_tokens(&self) -> TokenStream {
+        get_actual_body_type(&self.ty)
+            .map(|actual_body| actual_body.schema_references_tokens(false, ""))
+            .unwrap_or_default()
+    }
+
     fn tokens_or_diagnostics(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
         let mut actual_body = get_actual_body_type(&self.ty).unwrap().clone();
 
@@ -120,6 +151,7 @@ impl RequestBody<'_> {
                 value_type: crate::component::ValueType::Object,
                 span: Some(path.span()),
                 path,
+                array_len: None,
             }
         };
 
@@ -153,7 +185,7 @@ impl RequestBody<'_> {
             let bytes_as_bytes_vec = parse_quote!(Vec<u8>);
             let ty = TypeTree::from_type(&bytes_as_bytes_vec)?;
             create_body_tokens("application/octet-stream", &ty);
-        } else if self.ty.is("Form") {
+        } else if self.ty.is("Form") || self.ty.is("FormBody") {
             create_body_tokens("application/x-www-form-urlencoded", &actual_body);
         } else {
             create_body_tokens(actual_body.get_default_content_type(), &actual_body);
@@ -178,14 +210,14 @@ fn get_actual_body_type<'t>(ty: &'t TypeTree<'t>) -> Option<&'t TypeTree<'t>> {
         .segments
         .iter()
         .find_map(|segment| match &*segment.ident.to_string() {
-            "Json" => Some(
+            "Json" | "JsonBody" => Some(
                 ty.children
                     .as_deref()
                     .expect("Json must have children")
                     .first()
                     .expect("Json must have one child"),
             ),
-            "Form" => Some(
+            "Form" | "FormBody" => Some(
                 ty.children
                     .as_deref()
                     .expect("Form must have children")
@@ -256,6 +288,52 @@ pub struct ArgValue {
     pub original_name: String,
 }
 
+/// Resolve path of a framework declaring path arguments with its own syntax. Captures of `regex`
+/// with the first capture group are path arguments named by the group and replaced with
+/// `replace(name)`. Other captures are OpenAPI style `{name}` arguments kept as is.
+#[cfg(any(
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
+))]
+fn resolve_path_with(
+    path: &Option<String>,
+    regex: &str,
+    replace: impl Fn(&str) -> String,
+) -> Option<MacroPath> {
+    path.as_ref().map(|path| {
+        let regex = regex::Regex::new(regex).unwrap();
+
+        let mut args = Vec::<MacroArg>::with_capacity(regex.find_iter(path).count());
+        MacroPath {
+            path: regex
+                .replace_all(path, |captures: &regex::Captures| {
+                    let capture = &captures[0];
+                    let original_name = String::from(capture);
+
+                    if let Some(name) = captures.get(1) {
+                        let name = name.as_str();
+                        args.push(MacroArg::Path(ArgValue {
+                            name: String::from(name),
+                            original_name,
+                        }));
+
+                        replace(name)
+                    } else {
+                        args.push(MacroArg::Path(ArgValue {
+                            name: String::from(&capture[1..capture.len() - 1]),
+                            original_name,
+                        }));
+                        // otherwise return the capture itself
+                        capture.to_string()
+                    }
+                })
+                .to_string(),
+            args,
+        }
+    })
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ResolvedOperation {
     pub path_operation: PathOperation,
@@ -296,14 +374,18 @@ pub struct PathOperations;
 #[cfg(not(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 )))]
 impl ArgumentResolver for PathOperations {}
 
 #[cfg(not(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 )))]
 impl PathResolver for PathOperations {}
 
@@ -313,20 +395,30 @@ impl PathOperationResolver for PathOperations {}
 #[cfg(any(
     feature = "actix_extras",
     feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras",
     feature = "rocket_extras"
 ))]
 pub mod fn_arg {
 
     use proc_macro2::Ident;
     // use proc_macro_error::abort;
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
     use quote::quote;
     use syn::spanned::Spanned;
     use syn::PatStruct;
     use syn::{punctuated::Punctuated, token::Comma, Pat, PatType};
 
     use crate::component::TypeTree;
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
     use crate::component::ValueType;
     use crate::Diagnostics;
 
@@ -347,7 +439,7 @@ pub mod fn_arg {
     impl FnArgType<'_> {
         /// Get best effort name `Ident` for the type. For `FnArgType::Tuple` types it will take the first one
         /// from `Vec`.
-        #[cfg(feature = "rocket_extras")]
+        #[cfg(any(feature = "rocket_extras", feature = "salvo_extras"))]
         pub(super) fn get_name(&self) -> &Ident {
             match self {
                 Self::Single(ident) => ident,
@@ -390,6 +482,8 @@ pub mod fn_arg {
     ) -> Result<impl Iterator<Item = FnArg>, Diagnostics> {
         fn_args
             .iter()
+            // receiver of a method is not an argument of the request
+            .filter(|arg| !matches!(arg, syn::FnArg::Receiver(_)))
             .filter_map(|arg| {
                 let pat_type = match get_fn_arg_pat_type(arg) {
                     Ok(pat_type) => pat_type,
@@ -463,23 +557,33 @@ pub mod fn_arg {
         }
     }
 
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
-    pub(super) fn with_parameter_in(
-        arg: FnArg<'_>,
-    ) -> Option<(
-        Option<std::borrow::Cow<'_, syn::Path>>,
-        proc_macro2::TokenStream,
-    )> {
-        let parameter_in_provider = if arg.ty.is("Path") {
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
+    pub(super) fn with_parameter_in(arg: FnArg<'_>) -> Option<super::IntoParamsType<'_>> {
+        let ty = if arg.ty.generic_type == Some(crate::component::GenericType::Option) {
+            arg.ty
+                .children
+                .expect("FnArg Option must have children")
+                .into_iter()
+                .next()
+                .expect("FnArg Option must have 1 child")
+        } else {
+            arg.ty
+        };
+
+        let parameter_in_provider = if ty.is("Path") {
             quote! { || Some (utoipa::openapi::path::ParameterIn::Path) }
-        } else if arg.ty.is("Query") {
+        } else if ty.is("Query") {
             quote! { || Some(utoipa::openapi::path::ParameterIn::Query) }
         } else {
             quote! { || None }
         };
 
-        let type_path = arg
-            .ty
+        let is_path = ty.is("Path");
+        let type_path = ty
             .children
             .expect("FnArg TypeTree generic type Path must have children")
             .into_iter()
@@ -487,11 +591,19 @@ pub mod fn_arg {
             .unwrap()
             .path;
 
-        Some((type_path, parameter_in_provider))
+        Some(super::IntoParamsType {
+            parameter_in_provider,
+            type_path,
+            is_path,
+        })
     }
 
     // if type is either Path or Query with direct children as Object types without generics
-    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "axum_extras",
+        feature = "poem_extras"
+    ))]
     pub(super) fn is_into_params(fn_arg: &FnArg) -> bool {
         use crate::component::GenericType;
         let mut ty = &fn_arg.ty;
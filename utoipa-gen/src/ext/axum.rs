@@ -1,6 +1,5 @@
 use std::borrow::Cow;
 
-use regex::Captures;
 use syn::{punctuated::Punctuated, token::Comma};
 
 use crate::{
@@ -10,12 +9,12 @@ use crate::{
 
 use super::{
     fn_arg::{self, FnArg, FnArgType},
-    ArgValue, ArgumentResolver, Arguments, MacroArg, MacroPath, PathOperations, PathResolver,
-    ValueArgument,
+    ArgumentResolver, Arguments, MacroArg, MacroPath, PathOperations, PathResolver, ValueArgument,
 };
 
-// axum framework is only able to resolve handler function arguments.
-// `PathResolver` and `PathOperationResolver` is not supported in axum.
+// axum framework is only able to resolve handler function arguments and path.
+// `PathOperationResolver` is not supported in axum. Argument resolution is shared with poem
+// which uses same `Path`, `Query`, `Json` and `Form` extractors.
 impl ArgumentResolver for PathOperations {
     fn resolve_arguments(
         args: &'_ Punctuated<syn::FnArg, Comma>,
@@ -44,7 +43,6 @@ impl ArgumentResolver for PathOperations {
                 into_params_args
                     .into_iter()
                     .flat_map(fn_arg::with_parameter_in)
-                    .map(Into::into)
                     .collect(),
             ),
             body.into_iter().next().map(Into::into),
@@ -112,28 +110,14 @@ fn to_value_argument<'a>(name: Option<Cow<'a, str>>, ty: TypeTree<'a>) -> ValueA
     }
 }
 
+#[cfg(feature = "axum_extras")]
 impl PathResolver for PathOperations {
     fn resolve_path(path: &Option<String>) -> Option<MacroPath> {
-        path.as_ref().map(|path| {
-            let regex = regex::Regex::new(r"\{[a-zA-Z0-9][^{}]*}").unwrap();
-
-            let mut args = Vec::<MacroArg>::with_capacity(regex.find_iter(path).count());
-            MacroPath {
-                path: regex
-                    .replace_all(path, |captures: &Captures| {
-                        let capture = &captures[0];
-                        let original_name = String::from(capture);
-
-                        args.push(MacroArg::Path(ArgValue {
-                            name: String::from(&capture[1..capture.len() - 1]),
-                            original_name,
-                        }));
-                        // otherwise return the capture itself
-                        capture.to_string()
-                    })
-                    .to_string(),
-                args,
-            }
-        })
+        // replace axum style `:name` or `*name` capture with `{name}`
+        super::resolve_path_with(
+            path,
+            r"\{[a-zA-Z0-9][^{}]*}|/[:*]([a-zA-Z0-9_][a-zA-Z0-9_-]*)",
+            |name| format!("/{{{name}}}"),
+        )
     }
 }
@@ -0,0 +1,14 @@
+use super::{MacroPath, PathOperations, PathResolver};
+
+// poem framework shares handler function argument resolution with axum. Only path is resolved
+// here. `PathOperationResolver` is not supported in poem.
+impl PathResolver for PathOperations {
+    fn resolve_path(path: &Option<String>) -> Option<MacroPath> {
+        // replace poem style `:name`, `:name<regex>` or `*name` capture with `{name}`
+        super::resolve_path_with(
+            path,
+            r"\{[a-zA-Z0-9][^{}]*}|/[:*]([a-zA-Z0-9_][a-zA-Z0-9_-]*)(?:<[^>]*>)?",
+            |name| format!("/{{{name}}}"),
+        )
+    }
+}
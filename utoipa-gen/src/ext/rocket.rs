@@ -152,7 +152,7 @@ impl PathOperationResolver for PathOperations {
         ast_fn
             .attrs
             .iter()
-            .find(|attribute| is_valid_route_type(attribute.path().get_ident()))
+            .find(|attribute| is_valid_route_type(get_route_ident(attribute.path())))
             .map_try(
                 |attribute| match attribute.parse_args::<Path>().map_err(Diagnostics::from) {
                     Ok(path) => Ok((path, attribute)),
@@ -177,7 +177,7 @@ impl PathOperationResolver for PathOperations {
                     } else {
                         Ok(ResolvedOperation {
                             path_operation: PathOperation::from_ident(
-                                attribute.path().get_ident().unwrap(),
+                                get_route_ident(attribute.path()).unwrap(),
                             )?,
                             path,
                             body,
@@ -265,6 +265,16 @@ impl Parse for Path {
     }
 }
 
+/// Get route type ident from either `get` or fully qualified `rocket::get` attribute path.
+#[inline]
+fn get_route_ident(path: &syn::Path) -> Option<&Ident> {
+    match path.segments.len() {
+        1 => path.get_ident(),
+        2 if path.segments[0].ident == "rocket" => Some(&path.segments[1].ident),
+        _ => None,
+    }
+}
+
 #[inline]
 fn is_valid_route_type(ident: Option<&Ident>) -> bool {
     matches!(ident, Some(operation) if ["get", "post", "put", "delete", "head", "options", "patch", "route"]
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use syn::{parse_quote, punctuated::Punctuated, token::Comma, Expr, GenericArgument, Lit};
+
+use crate::{
+    component::{GenericType, TypeTree, ValueType},
+    Diagnostics,
+};
+
+use super::{
+    fn_arg::{self, FnArg},
+    ArgumentIn, ArgumentResolver, Arguments, MacroArg, MacroPath, PathOperations, PathResolver,
+    ValueArgument,
+};
+
+// salvo framework is only able to resolve handler function arguments and path.
+// `PathOperationResolver` is not supported in salvo. Only the typed extractors `PathParam`,
+// `QueryParam`, `JsonBody` and `FormBody` can be resolved, arguments such as `&mut Request` are
+// ignored.
+impl ArgumentResolver for PathOperations {
+    fn resolve_arguments(
+        args: &'_ Punctuated<syn::FnArg, Comma>,
+        _: Option<Vec<MacroArg>>,
+        _: String,
+    ) -> Result<Arguments<'_>, Diagnostics> {
+        let (value_args, body): (Vec<FnArg>, Vec<FnArg>) = fn_arg::get_fn_args(args)?
+            .filter(|arg| {
+                arg.ty.is("PathParam")
+                    || arg.ty.is("QueryParam")
+                    || arg.ty.is("JsonBody")
+                    || arg.ty.is("FormBody")
+            })
+            .partition(|arg| arg.ty.is("PathParam") || arg.ty.is("QueryParam"));
+
+        Ok((
+            Some(
+                value_args
+                    .into_iter()
+                    .map(to_value_argument)
+                    .collect::<Result<Vec<_>, Diagnostics>>()?,
+            ),
+            None,
+            body.into_iter().next().map(|body| body.ty.into()),
+        ))
+    }
+}
+
+fn to_value_argument(arg: FnArg) -> Result<ValueArgument, Diagnostics> {
+    let argument_in = if arg.ty.is("PathParam") {
+        ArgumentIn::Path
+    } else {
+        ArgumentIn::Query
+    };
+    let is_required = is_required_query_param(&arg.ty);
+    let name = arg.arg_type.get_name().to_string();
+
+    let span = arg.ty.span;
+    let ty = arg
+        .ty
+        .children
+        .and_then(|children| children.into_iter().next())
+        .ok_or_else(|| {
+            let message = "expected salvo extractor to have a type argument e.g. `PathParam<T>`";
+            match span {
+                Some(span) => Diagnostics::with_span(span, message),
+                None => Diagnostics::new(message),
+            }
+        })?;
+
+    Ok(ValueArgument {
+        name: Some(Cow::Owned(name)),
+        argument_in,
+        type_tree: Some(if is_required { ty } else { to_option(ty) }),
+    })
+}
+
+/// Check whether `QueryParam<T, REQUIRED>` is required by its literal `REQUIRED` argument.
+/// Other extractors and `REQUIRED` given with an expression are considered required.
+fn is_required_query_param(ty: &TypeTree) -> bool {
+    let Some(segment) = ty
+        .path
+        .as_deref()
+        .and_then(|path| path.segments.last())
+        .filter(|segment| segment.ident == "QueryParam")
+    else {
+        return true;
+    };
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .iter()
+            .find_map(|argument| match argument {
+                GenericArgument::Const(Expr::Lit(expr_lit)) => match &expr_lit.lit {
+                    Lit::Bool(value) => Some(value.value),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap_or(true),
+        _ => true,
+    }
+}
+
+fn to_option(ty: TypeTree<'_>) -> TypeTree<'_> {
+    TypeTree {
+        path: Some(Cow::Owned(parse_quote!(Option))),
+        span: ty.span,
+        value_type: ValueType::Object,
+        generic_type: Some(GenericType::Option),
+        children: Some(vec![ty]),
+        array_len: None,
+    }
+}
+
+impl PathResolver for PathOperations {
+    fn resolve_path(path: &Option<String>) -> Option<MacroPath> {
+        // replace salvo style `<name>`, `<name:num>`, `<name|regex>` or `<**name>` capture with
+        // `{name}`
+        super::resolve_path_with(
+            path,
+            r"\{[a-zA-Z0-9][^{}]*}|<\*{0,2}[+?]?([a-zA-Z_][a-zA-Z0-9_]*)(?:[:|][^>]*)?>",
+            |name| format!("{{{name}}}"),
+        )
+    }
+}
@@ -30,9 +30,10 @@ use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use proc_macro2::{Group, Ident, Punct, Span, TokenStream as TokenStream2};
 use syn::{
     bracketed,
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    token::Bracket,
+    token::{Brace, Bracket},
     DeriveInput, ExprPath, ItemFn, Lit, LitStr, Member, Token,
 };
 
@@ -44,18 +45,18 @@ mod path;
 mod schema_type;
 mod security_requirement;
 
-use crate::path::{Path, PathAttr};
+use crate::path::{DeprecatedNote, Path, PathAttr};
 
 use self::{
     component::{
         features::{self, Feature},
-        ComponentSchema, ComponentSchemaProps, TypeTree,
+        ComponentSchema, ComponentSchemaProps, InlineObject, TypeTree,
     },
     openapi::parse_openapi_attrs,
-    path::response::derive::{IntoResponses, ToResponse},
+    path::response::derive::{IntoResponses, ToHeader, ToResponse},
 };
 
-#[proc_macro_derive(ToSchema, attributes(schema, aliases))]
+#[proc_macro_derive(ToSchema, attributes(schema, aliases, validate))]
 /// Generate reusable OpenAPI schema to be used
 /// together with [`OpenApi`][openapi_derive].
 ///
@@ -72,8 +73,13 @@ use self::{
 /// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
 /// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
 ///
+/// Standard library types serialized as strings such as _`IpAddr`_, _`SocketAddr`_, _`Path`_ and
+/// _`PathBuf`_ are rendered as `string` schemas. _`Ipv4Addr`_ and _`Ipv6Addr`_ additionally have
+/// _`ipv4`_ and _`ipv6`_ format respectively.
+///
 /// Doc comments on fields will resolve to field descriptions in generated OpenAPI doc. On struct
-/// level doc comments will resolve to object descriptions.
+/// level doc comments will resolve to object descriptions. If the field type is a reference to
+/// another schema the reference is wrapped in _`allOf`_ to carry the field description.
 ///
 /// ```rust
 /// /// This is a pet
@@ -91,33 +97,60 @@ use self::{
 /// * `title = ...` Literal string value. Can be used to define title for struct in OpenAPI
 ///   document. Some OpenAPI code generation libraries also use this field as a name for the
 ///   struct.
+/// * `description = "..."` Literal string value. Can be used to define description for struct in
+///   OpenAPI document. Takes precedence over the doc comment description of the struct.
 /// * `rename_all = ...` Supports same syntax as _serde_ _`rename_all`_ attribute. Will rename all fields
 ///   of the structs accordingly. If both _serde_ `rename_all` and _schema_ _`rename_all`_ are defined
 ///   __serde__ will take precedence.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `rename = "..."` Literal string value. Can be used to define alternative name for the schema
+///   what will be used in the OpenAPI instead of the Rust type name. E.g _`rename = "PetV2"`_.
+///   If both _`as`_ and _`rename`_ are defined _`as`_ will take precedence.
 /// * `default` Can be used to populate default values on all fields using the struct's
 ///   [`Default`](std::default::Default) implementation.
 /// * `deprecated` Can be used to mark all fields as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the fields as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `external_docs(...)` Can be used to reference external resource for extended documentation
+///   of the schema. E.g. _`external_docs(url = "https://...", description = "...")`_. The `url` is
+///   required and `description` is optional.
+/// * `extensions(...)` Specification extensions of the schema given as _`("x-name" = value)`_
+///   pairs. Value can be literal, _`json!(...)`_ or method reference and names must start with
+///   _`x-`_. E.g. _`extensions(("x-internal" = true))`_.
+/// * `form` Can be used to mark the struct as a form. Request bodies referencing the schema of the
+///   struct will use _`application/x-www-form-urlencoded`_ as their default content type instead of
+///   _`application/json`_.
 
 /// # Enum Optional Configuration Options for `#[schema(...)]`
 /// * `example = ...` Can be method reference or _`json!(...)`_.
 /// * `default = ...` Can be method reference or _`json!(...)`_.
 /// * `title = ...` Literal string value. Can be used to define title for enum in OpenAPI
 ///   document. Some OpenAPI code generation libraries also use this field as a name for the
-///   enum. __Note!__  ___Complex enum (enum with other than unit variants) does not support title!___
+///   enum.
+/// * `description = "..."` Literal string value. Can be used to define description for enum in
+///   OpenAPI document. Takes precedence over the doc comment description of the enum.
 /// * `rename_all = ...` Supports same syntax as _serde_ _`rename_all`_ attribute. Will rename all
 ///   variants of the enum accordingly. If both _serde_ `rename_all` and _schema_ _`rename_all`_
 ///   are defined __serde__ will take precedence.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `rename = "..."` Literal string value. Can be used to define alternative name for the schema
+///   what will be used in the OpenAPI instead of the Rust type name. E.g _`rename = "PetV2"`_.
+///   If both _`as`_ and _`rename`_ are defined _`as`_ will take precedence.
 /// * `deprecated` Can be used to mark the enum as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the enum as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `external_docs(...)` Can be used to reference external resource for extended documentation
+///   of the enum. E.g. _`external_docs(url = "https://...", description = "...")`_. The `url` is
+///   required and `description` is optional.
+/// * `extensions(...)` Specification extensions of the enum given as _`("x-name" = value)`_
+///   pairs. Value can be literal, _`json!(...)`_ or method reference and names must start with
+///   _`x-`_. E.g. _`extensions(("x-internal" = true))`_.
+/// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to the enum.
+///   __Note!__ ___Complex enum (enum with other than unit variants) does not support xml!___
 ///
 /// # Enum Variant Optional Configuration Options for `#[schema(...)]`
 /// Supports all variant specific configuration options e.g. if variant is _`UnnamedStruct`_ then
@@ -138,42 +171,68 @@ use self::{
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases where the default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not [`ToSchema`][to_schema]s nor [`primitive` types][primitive].
-///   The value can be any Rust type what normally could be used to serialize to JSON or either virtual type _`Object`_
-///   or _`Value`_, or an alias defined using `#[aliases(..)]`.
+///   The value can be any Rust type what normally could be used to serialize to JSON or either virtual type _`Object`_,
+///   _`Value`_ or _`File`_, or an alias defined using `#[aliases(..)]`.
 ///   _`Object`_ will be rendered as generic OpenAPI object _(`type: object`)_.
 ///   _`Value`_ will be rendered as any OpenAPI value (i.e. no `type` restriction).
+///   _`File`_ will be rendered as binary string _(`type: string, format: binary`)_.
 /// * `title = ...` Literal string value. Can be used to define title for struct in OpenAPI
 ///   document. Some OpenAPI code generation libraries also use this field as a name for the
 ///   struct.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `rename = "..."` Literal string value. Can be used to define alternative name for the schema
+///   what will be used in the OpenAPI instead of the Rust type name. E.g _`rename = "PetV2"`_.
+///   If both _`as`_ and _`rename`_ are defined _`as`_ will take precedence.
 /// * `deprecated` Can be used to mark the field as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the field as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `enum_values = [...]` Can be used to restrict the value to a fixed list of string or number
+///   literals e.g. _`enum_values = ["asc", "desc"]`_. Literal type must match the type of the field.
+///
+/// With single field struct the _`value_type`_ and _`format`_ can also be defined directly on the
+/// field e.g. _`struct Id(#[schema(value_type = String)] ThirdPartyId);`_. If both the struct and the
+/// field define the same attribute, the one defined on the struct is used. The field can also be
+/// marked _`inline`_ to inline the schema of the field type instead of referencing it e.g.
+/// _`struct PetWrapper(#[schema(inline)] Pet);`_.
 ///
 /// # Named Fields Optional Configuration Options for `#[schema(...)]`
-/// * `example = ...` Can be method reference or _`json!(...)`_.
-/// * `default = ...` Can be method reference or _`json!(...)`_.
+/// * `example = ...` Can be method reference or _`json!(...)`_. If the field type is a reference
+///   to another schema the reference is wrapped in _`allOf`_ to carry the example.
+/// * `default = ...` Can be literal e.g. _`default = 10`_, method reference or _`json!(...)`_.
+///   If no value is specified with _`default`_ the value is taken from the
+///   [`Default`](std::default::Default) implementation of the field's type.
 /// * `format = ...` May either be variant of the [`KnownFormat`][known_format] enum, or otherwise
-///   an open value as a string. By default the format is derived from the type of the property
-///   according OpenApi spec.
+///   an open value as a string e.g. _`format = "custom-id"`_. String value of a known format like
+///   _`"int64"`_ is same as the [`KnownFormat`][known_format] variant. By default the format is derived
+///   from the type of the property according OpenApi spec.
 /// * `write_only` Defines property is only used in **write** operations *POST,PUT,PATCH* but not in *GET*
 /// * `read_only` Defines property is only used in **read** operations *GET* but not in *POST,PUT,PATCH*
+///
+///   _`write_only`_ and _`read_only`_ are set on the array itself for [`Vec`] fields. Fields
+///   referencing other schemas are wrapped in _`allOf`_ to carry the flag alongside the reference.
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to named fields.
-///    See configuration options at xml attributes of [`ToSchema`][to_schema_xml]
+///    See configuration options at xml attributes of [`ToSchema`][to_schema_xml].
+///   Fields referencing other schemas are wrapped in _`allOf`_ to carry the xml alongside the
+///   reference.
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases where the default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not [`ToSchema`][to_schema]s nor [`primitive` types][primitive].
-///   The value can be any Rust type what normally could be used to serialize to JSON, or either virtual type _`Object`_
-///   or _`Value`_, or an alias defined using `#[aliases(..)]`.
+///   The value can be any Rust type what normally could be used to serialize to JSON, or either virtual type _`Object`_,
+///   _`Value`_ or _`File`_, or an alias defined using `#[aliases(..)]`.
 ///   _`Object`_ will be rendered as generic OpenAPI object _(`type: object`)_.
 ///   _`Value`_ will be rendered as any OpenAPI value (i.e. no `type` restriction).
+///   _`File`_ will be rendered as binary string _(`type: string, format: binary`)_.
 /// * `inline` If the type of this field implements [`ToSchema`][to_schema], then the schema definition
-///   will be inlined. **warning:** Don't use this for recursive data types!
-/// * `required = ...` Can be used to enforce required status for the field. [See
+///   will be inlined. Fields of the type itself are always referenced instead of inlined.
+///   **warning:** Don't use this for mutually recursive data types!
+/// * `required = ...` Can be used to enforce required status for the field. Supports formats
+///   _`required`_, _`required = true`_ and _`required = false`_ where the latter can be used to
+///   mark a non `Option` field as not required. [See
 ///   rules][derive@ToSchema#field-nullability-and-required-rules]
-/// * `nullable` Defines property is nullable (note this is different to non-required).
+/// * `nullable` Defines property is nullable (note this is different to non-required). Use
+///   _`nullable = false`_ to not mark _`Option`_ field as nullable.
 /// * `rename = ...` Supports same syntax as _serde_ _`rename`_ attribute. Will rename field
 ///   accordingly. If both _serde_ `rename` and _schema_ _`rename`_ are defined __serde__ will take
 ///   precedence.
@@ -186,10 +245,18 @@ use self::{
 /// * `max_length = ...` Can be used to define maximum length for `string` types.
 /// * `min_length = ...` Can be used to define minimum length for `string` types.
 /// * `pattern = ...` Can be used to define valid regular expression in _ECMA-262_ dialect the field value must match.
+/// * `enum_values = [...]` Can be used to restrict the field value to a fixed list of string or
+///   number literals e.g. _`enum_values = ["asc", "desc"]`_ or _`enum_values = [1, 2, 3]`_. Literal type
+///   must match the type of the field, string literals with `string` types and number literals with
+///   `number` types. With `array` fields such as _`Vec<String>`_ the values restrict the items.
 /// * `max_items = ...` Can be used to define maximum items allowed for `array` fields. Value must
-///   be non-negative integer.
+///   be non-negative integer. Fixed size arrays _`[T; N]`_ default to `N`. Using it with other than
+///   `array` fields is a compile error.
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
-///   be non-negative integer.
+///   be non-negative integer. Fixed size arrays _`[T; N]`_ default to `N`. Using it with other than
+///   `array` fields is a compile error.
+/// * `unique_items` Can be used to define that all items of `array` field must be unique. Can optionally
+///   be defined with explicit `bool` value as _`unique_items = bool`_. Set types are always unique.
 /// * `schema_with = ...` Use _`schema`_ created by provided function reference instead of the
 ///   default derived _`schema`_. The function must match to `fn() -> Into<RefOr<Schema>>`. It does
 ///   not accept arguments and must return anything that can be converted into `RefOr<Schema>`.
@@ -199,7 +266,8 @@ use self::{
 ///   Supports formats _`additional_properties`_ and _`additional_properties = true`_.
 /// * `deprecated` Can be used to mark the field as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the field as deprecated in the code as well use
-///   Rust's own `#[deprecated]` attribute instead.
+///   Rust's own `#[deprecated]` attribute instead. If the field type is a reference to another
+///   schema the reference is wrapped in _`allOf`_ to carry the deprecated status.
 ///
 /// #### Field nullability and required rules
 ///
@@ -210,7 +278,13 @@ use self::{
 /// * and it does not have default value provided with serde _`default`_
 ///   attribute
 ///
-/// Field is considered _`nullable`_ when field type is _`Option`_.
+/// Explicit _`required`_ attribute always takes precedence over these rules. E.g. _`Option`_ field
+/// with _`#[schema(required)]`_ is rendered as required and nullable, and a field with
+/// _`#[schema(required = false)]`_ is left out from the _`required`_ array regardless of its type.
+///
+/// Field is considered _`nullable`_ when field type is _`Option`_. This can be overridden with
+/// _`nullable = false`_ e.g. when the field is skipped with serde's _`skip_serializing_if`_ instead
+/// of serializing `null` value.
 ///
 /// ## Xml attribute Configuration Options
 ///
@@ -223,28 +297,96 @@ use self::{
 ///
 /// See [`Xml`][xml] for more details.
 ///
+/// # Partial `#[validate(...)]` attributes support
+///
+/// With the _**`validator`**_ feature enabled the [validator](https://docs.rs/validator) crate
+/// field attributes are used to add validation properties to the field schema. Values defined
+/// with `#[schema(...)]` attribute take precedence over values resolved from `#[validate(...)]`.
+///
+/// * `length(min = ..., max = ..., equal = ...)` Will set _`min_length`_ and _`max_length`_ for
+///   strings, _`min_items`_ and _`max_items`_ for arrays and _`min_properties`_ and
+///   _`max_properties`_ for maps.
+/// * `range(min = ..., max = ..., exclusive_min = ..., exclusive_max = ...)` Will set
+///   _`minimum`_, _`maximum`_, _`exclusive_minimum`_ and _`exclusive_maximum`_ for numbers.
+/// * `email` Will set _`format = "email"`_ for strings.
+/// * `url` Will set _`format = "uri"`_ for strings.
+/// * `regex = "..."` and `regex(path = ...)` Will set _`pattern`_ for strings from the
+///   regex's `as_str()`.
+///
+/// Only literal values are supported. Other validations and arguments are ignored.
+///
+/// # Partial `#[serde_as(...)]` attributes support
+///
+/// With the _**`serde_with`**_ feature enabled fields annotated with [serde_with](https://docs.rs/serde_with)
+/// crate's `#[serde_as(as = "...")]` attribute are rendered with the schema of the serialized
+/// value instead of the field's Rust type. Adaptors can be nested within the field's type e.g.
+/// `Vec<DisplayFromStr>` or `Option<Base64>`.
+///
+/// * `DisplayFromStr` Will be rendered as _`string`_.
+/// * `Base64` Will be rendered as _`string`_ with _`format = "byte"`_.
+/// * `DurationSeconds<...>`, `DurationMilliSeconds<...>`, `DurationMicroSeconds<...>` and
+///   `DurationNanoSeconds<...>` Will be rendered as their format type, _`u64`_ by default.
+/// * `TimestampSeconds<...>`, `TimestampMilliSeconds<...>`, `TimestampMicroSeconds<...>` and
+///   `TimestampNanoSeconds<...>` Will be rendered as their format type, _`i64`_ by default.
+/// * `*WithFrac<...>` variants of the above Will be rendered as their format type, _`f64`_ by default.
+///
+/// Other adaptors are rendered as the field's own type. Use `#[schema(value_type = ...)]` to
+/// override the resolved type.
+///
+/// ```rust
+/// # #[cfg(feature = "serde_with")]
+/// # {
+/// # use serde_with::{serde_as, DisplayFromStr, DurationSeconds};
+/// #[serde_as]
+/// #[derive(serde::Serialize, utoipa::ToSchema)]
+/// struct Config {
+///     #[serde_as(as = "DisplayFromStr")]
+///     id: u64,
+///     #[serde_as(as = "DurationSeconds<String>")]
+///     timeout: std::time::Duration,
+/// }
+/// # }
+/// ```
+///
 /// # Partial `#[serde(...)]` attributes support
 ///
 /// ToSchema derive has partial support for [serde attributes]. These supported attributes will reflect to the
 /// generated OpenAPI doc. For example if _`#[serde(skip)]`_ is defined the attribute will not show up in the OpenAPI spec at all since it will not never
 /// be serialized anyway. Similarly the _`rename`_ and _`rename_all`_ will reflect to the generated OpenAPI doc.
 ///
-/// * `rename_all = "..."` Supported at the container level.
-/// * `rename = "..."` Supported **only** at the field or variant level.
+/// * `rename_all = "..."` Supported at the container level. The `rename_all(serialize = "...")` form is
+///   supported as well and only the `serialize` value is used.
+/// * `rename = "..."` Supported **only** at the field or variant level. The `rename(serialize = "...")`
+///   form is supported as well and only the `serialize` value is used.
 /// * `skip = "..."` Supported  **only** at the field or variant level.
 /// * `skip_serializing = "..."` Supported  **only** at the field or variant level.
 /// * `skip_deserializing = "..."` Supported  **only** at the field or variant level.
+///   Skipped fields of unnamed field structs and variants are left out of the array schema as well.
 /// * `skip_serializing_if = "..."` Supported  **only** at the field level.
 /// * `with = ...` Supported **only at field level.**
 /// * `tag = "..."` Supported at the container level. `tag` attribute works as a [discriminator field][discriminator] for an enum.
+///   Internally tagged unnamed variants wrapping a single referenced schema e.g. _`Cat(Cat)`_ are
+///   added to the discriminator _`mapping`_ with the variant's tag value.
 /// * `content = "..."` Supported at the container level, allows [adjacently-tagged enums](https://serde.rs/enum-representations.html#adjacently-tagged).
 ///   This attribute requires that a `tag` is present, otherwise serde will trigger a compile-time
 ///   failure.
 /// * `untagged` Supported at the container level. Allows [untagged
 /// enum representation](https://serde.rs/enum-representations.html#untagged).
 /// * `default` Supported at the container level and field level according to [serde attributes].
-/// * `deny_unknown_fields` Supported at the container level.
-/// * `flatten` Supported at the field level.
+///   Fields with `default` are not required. For field level `default` and `default = "path"` of
+///   a primitive type field the default value is rendered to the schema as well.
+/// * `deny_unknown_fields` Supported at the container level. Renders _`additionalProperties: false`_
+///   for the object schema. On enums it is rendered for the named field variants.
+/// * `transparent` Supported at the container level. The struct is rendered as the schema of its
+///   single non skipped field, same way as single unnamed field struct, e.g. _`struct UserId { id: u64 }`_
+///   is rendered as _`integer`_ instead of an object with _`id`_ property.
+/// * `flatten` Supported at the field level. Flattened map field e.g. _`HashMap<String, Value>`_ renders
+///   _`additionalProperties`_ with the map value schema and takes precedence over `deny_unknown_fields`.
+/// * `remote = "..."` Supported at the container level. The schema of the local mirror type is named
+///   after the remote type in same manner as with _`as = ...`_, e.g. _`remote = "other::Thing"`_ appears
+///   as _`other.Thing`_ in the OpenAPI spec. This allows fields and bodies of types from crates you
+///   don't control to reference the schema of the mirror type when the remote type is written with the
+///   same path. _`as = ...`_ takes precedence over `remote`.
 ///
 /// Other _`serde`_ attributes works as is but does not have any effect on the generated OpenAPI doc.
 ///
@@ -321,10 +463,15 @@ use self::{
 /// * `default = ...` Can be method reference or _`json!(...)`_.
 /// * `title = ...` Literal string value. Can be used to define title for enum in OpenAPI
 ///   document. Some OpenAPI code generation libraries also use this field as a name for the
-///   enum. __Note!__  ___Complex enum (enum with other than unit variants) does not support title!___
+///   enum.
+/// * `description = "..."` Literal string value. Can be used to define description for enum in
+///   OpenAPI document. Takes precedence over the doc comment description of the enum.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `rename = "..."` Literal string value. Can be used to define alternative name for the schema
+///   what will be used in the OpenAPI instead of the Rust type name. E.g _`rename = "PetV2"`_.
+///   If both _`as`_ and _`rename`_ are defined _`as`_ will take precedence.
 ///
 /// _**Create enum with numeric values.**_
 /// ```rust
@@ -379,9 +526,55 @@ use self::{
 /// struct ApiDoc;
 /// ```
 ///
+/// Aliases can be declared for generic unnamed field structs and complex enums as well. The
+/// generic arguments are resolved in all fields of the enum variants.
+/// ```rust
+/// # use utoipa::ToSchema;
+/// # #[derive(ToSchema)]
+/// # struct Pet {
+/// #     name: String,
+/// # }
+/// #[derive(ToSchema)]
+/// #[aliases(PetResult = ApiResult<Pet>)]
+/// enum ApiResult<T> {
+///     Ok(T),
+///     Err { message: String },
+/// }
+/// ```
+///
 /// The `#[aliases(...)]` is just syntactic sugar and will create Rust [type aliases](https://doc.rust-lang.org/reference/items/type-aliases.html)
 /// behind the scenes which then can be later referenced anywhere in code.
 ///
+/// Generic types can also be referenced with concrete generic arguments without declaring aliases
+/// for them e.g. in response bodies or fields of other schemas. Such a reference is named by
+/// appending the names of the generic arguments to the name of the type, so _`Page<Pet>`_ is
+/// referenced as _`PagePet`_ and _`Page<Vec<Pet>>`_ as _`PageVecPet`_. The schema is rendered with the
+/// generic arguments in place of the generic type parameters when it is collected automatically
+/// with _`auto_collect_schemas`_ feature. If the generic type declares an alias with the same
+/// name, the schema of the alias is used instead, thus without the feature the schema can be
+/// registered by declaring _`#[aliases(PagePet = Page<Pet>)]`_ and listing the alias in
+/// _`components(schemas(...))`_. To use a different name reference the alias instead.
+/// ```rust
+/// # use utoipa::{ToSchema, OpenApi};
+/// # #[derive(ToSchema)]
+/// # struct Pet {
+/// #     name: String,
+/// # }
+/// #[derive(ToSchema)]
+/// #[aliases(PagePet = Page<Pet>)]
+/// struct Page<T> {
+///     items: Vec<T>,
+///     total: u64,
+/// }
+///
+/// #[utoipa::path(get, path = "/pets", responses((status = 200, body = Page<Pet>)))]
+/// fn list_pets() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(paths(list_pets), components(schemas(Pet, PagePet)))]
+/// struct ApiDoc;
+/// ```
+///
 /// **Note!** You should never register generic type itself in `components(...)` so according above example `Status<...>` should not be registered
 /// because it will not render the type correctly and will cause an error in generated OpenAPI spec.
 ///
@@ -550,6 +743,17 @@ use self::{
 /// };
 /// ```
 ///
+/// _**Self referencing types are rendered as references to the schema itself.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Category {
+///     name: String,
+///     children: Vec<Category>,
+///     parent: Option<Box<Self>>,
+/// }
+/// ```
+///
 /// _**Serde `rename` / `rename_all` will take precedence over schema `rename` / `rename_all`.**_
 /// ```rust
 /// #[derive(utoipa::ToSchema, serde::Deserialize)]
@@ -632,6 +836,7 @@ use self::{
 /// [discriminator]: openapi/schema/struct.Discriminator.html
 /// [enum_schema]: derive.ToSchema.html#enum-optional-configuration-options-for-schema
 /// [openapi_derive]: derive.OpenApi.html
+/// [path]: attr.path.html
 /// [to_schema_xml]: macro@ToSchema#xml-attribute-configuration-options
 pub fn derive_to_schema(input: TokenStream) -> TokenStream {
     let DeriveInput {
@@ -655,15 +860,19 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// be used to configure and override default values what are resolved automatically.
 ///
 /// You can use the Rust's own `#[deprecated]` attribute on functions to mark it as deprecated and it will
-/// reflect to the generated OpenAPI spec. Only **parameters** has a special **deprecated** attribute to define them as deprecated.
+/// reflect to the generated OpenAPI spec. Alternatively the operation and **parameters** can be marked deprecated with
+/// the **deprecated** attribute of the macro.
 ///
 /// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but this is is not supported in
-/// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. The reason given with
+/// `#[deprecated = "There is better way to do this"]` or `#[deprecated(note = "...")]` is appended to the operation
+/// _`description`_ prefixed with _`Deprecated: `_ in the same manner as with the **deprecated** attribute of the macro.
+/// The note of the macro attribute takes precedence if both are defined.
 ///
 /// Doc comment at decorated function will be used for _`description`_ and _`summary`_ of the path.
 /// First line of the doc comment will be used as the _`summary`_ while the remaining lines will be
-/// used as _`description`_.
+/// used as _`description`_. The description supports CommonMark and the relative indentation of
+/// the lines is preserved, so e.g. nested lists and indented code blocks render as expected.
 /// ```rust
 /// /// This is a summary of the operation
 /// ///
@@ -672,50 +881,137 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// fn operation() {}
 /// ```
 ///
+/// The _`summary`_ and _`description`_ can also be defined independently of each other with the
+/// attributes of the same name which take precedence over the doc comment.
+///
 /// # Path Attributes
 ///
 /// * `operation` _**Must be first parameter!**_ Accepted values are known http operations such as
-///   _`get, post, put, delete, head, options, connect, patch, trace`_.
+///   _`get, post, put, delete, head, options, connect, patch, trace`_. Multiple operations can be
+///   listed e.g. _`get, head`_ in which case same operation is documented under each of them.
+///   Operation id of a shared operation is suffixed with the operation to keep it unique e.g.
+///   _`get_items_get`_ and _`get_items_head`_. Each operation can be listed only once. Explicit
+///   _`operation_id`_ cannot be used with multiple operations since it would not be unique.
+///
+/// * `method = "..."` Alternative way to define the `operation` as a literal string. This is useful
+///   when attribute is generated by other macros. Accepts same values as `operation`,
+///   e.g. _`method = "post"`_. Both forms produce identical operation.
 ///
 /// * `path = "..."` Must be OpenAPI format compatible str with arguments within curly braces. E.g _`{id}`_
 ///
 /// * `impl_for = ...` Optional type to implement the [`Path`][path] trait. By default a new type
 ///   is used for the implementation.
 ///
+/// * `associated` or `associated = bool` Declare the handler as an associated function of an
+///   `impl` block or a trait. Types cannot be declared within `impl` blocks so the path of an
+///   associated function is a hidden associated constant instead of a type implementing
+///   [`Path`][path]. Methods having a `self` receiver are always associated. Associated functions
+///   are referenced by their type in [`OpenApi`][openapi] e.g. _`paths(PetController::get_pet)`_.
+///   Not supported with _`impl_for`_ or _`register`_.
+///
+/// * `api_version = "..."` Version of the API the operation belongs to e.g. _`api_version = "v2"`_.
+///   Operations of other versions are left out from the document returned by
+///   `OpenApi::openapi_for_version(...)`. Operations without a version belong to every version.
+///
+/// * `register` Register the path to be collected with `OpenApi::gather()` instead of listing it
+///   in _`#[openapi(paths(...))]`_. Requires _`register_paths`_ feature. The default tag of a
+///   registered path is the name of the parent module of the handler function.
+///
+/// * `hidden` or `hidden = bool` Leave the operation out from the [`OpenApi`][openapi] documents
+///   while still compiling it, e.g. for internal endpoints.
+///
+/// * `include_if = "..."` Include the operation to the [`OpenApi`][openapi] documents only when
+///   the given feature of the crate declaring the path is enabled e.g.
+///   _`include_if = "experimental"`_. Otherwise the operation is hidden like with _`hidden`_.
+///
+///   Handler functions compiled with _`#[cfg(...)]`_ are listed with the same predicate in
+///   _`#[openapi(paths(cfg(...) handler))]`_ instead.
+///
+/// * `summary = ...` Short summary of the operation typically shown in the list of operations.
+///   Overrides the first line of the doc comment. Value can be a literal string or an expression
+///   resolving to a string e.g. a constant. Empty string leaves the summary out.
+///
+/// * `description = ...` Long description of the operation supporting CommonMark. Overrides the
+///   doc comment lines after the summary. Value can be a literal string, e.g.
+///   _`description = include_str!("get_pet.md")`_ or any other expression resolving to a string.
+///   Empty string leaves the description out.
+///
 /// * `operation_id = ...` Unique operation id for the endpoint. By default this is mapped to function name.
+///   Raw identifiers are used without the _`r#`_ prefix e.g. _`fn r#type()`_ results operation id _`type`_.
 ///   The operation_id can be any valid expression (e.g. string literals, macro invocations, variables) so long
 ///   as its result can be converted to a `String` using `String::from`.
 ///
 /// * `context_path = "..."` Can add optional scope for **path**. The **context_path** will be prepended to beginning of **path**.
 ///   This is particularly useful when **path** does not contain the full path to the endpoint. For example if web framework
 ///   allows operation to be defined under some context path or scope which does not reflect to the resolved path then this
-///   **context_path** can become handy to alter the path.
+///   **context_path** can become handy to alter the path. Trailing _`/`_ of the **context_path** is
+///   not duplicated when **path** starts with _`/`_. The **context_path** can be any expression
+///   resolving to a string e.g. a _`const`_ shared by all handlers of a module mounted under the
+///   same scope. Alternatively handlers of a module can be listed in their own
+///   [`OpenApi`][openapi] which is nested with _`nest((path = "/api/v1", api = ...))`_.
 ///
 /// * `tag = "..."` Can be used to group operations. Operations with same tag are grouped together. By default
-///   this is derived from the handler that is given to [`OpenApi`][openapi]. If derive results empty str
-///   then default value _`crate`_ is used instead.
+///   this is the name of the parent module of the handler that is given to [`OpenApi`][openapi] e.g.
+///   _`pets`_ for _`handlers::pets::get_pet`_ or the name of the type of an associated function e.g.
+///   _`PetController`_ for _`PetController::get_pet`_. If the handler has no parent module then default value
+///   _`crate`_ is used instead. See _`infer_tags`_ of [`OpenApi`][openapi] to disable the default tag.
 ///
 /// * `tags = ["tag1", ...]` Can be used to group operations. Operations with same tag are grouped
-///   toghether. Tags attribute can be used to add addtional _tags_ for the operation.
+///   toghether. Tags attribute can be used to add addtional _tags_ for the operation. Each tag
+///   can be a literal string or a constant expression. When only _`tags`_ is defined the default
+///   tag derived from the handler is not added to the operation, so the operation is only listed
+///   under the given _tags_. E.g. _`tags = ["pets", "admin"]`_.
 ///
 /// * `request_body = ... | request_body(...)` Defining request body indicates that the request is expecting request body within
 ///   the performed request.
 ///
 /// * `responses(...)` Slice of responses the endpoint is going to possibly return to the caller.
+///   Each response can be prefixed with _`cfg(...)`_ predicate to only include it when the
+///   predicate holds, see [conditional responses](#conditional-responses).
 ///
 /// * `params(...)` Slice of params that the endpoint accepts.
 ///
 /// * `security(...)` List of [`SecurityRequirement`][security]s local to the path operation.
 ///
+/// * `deprecated` or `deprecated = "..."` Can be used to mark the operation as deprecated in the
+///   generated OpenAPI spec without marking the function deprecated with Rust's own
+///   `#[deprecated]` attribute. Optional literal string is a note e.g. pointing to the replacement
+///   and will be appended to the operation _`description`_ prefixed with _`Deprecated: `_.
+///
+/// * `external_docs(...)` Can be used to reference external resource for extended documentation
+///   of the operation. E.g. _`external_docs(url = "https://...", description = "...")`_. The `url`
+///   is required and `description` is optional.
+///
+/// * `extensions(...)` Specification extensions of the operation given as _`("x-name" = value)`_
+///   pairs. Value can be literal, _`json!(...)`_ or method reference and names must start with
+///   _`x-`_. API gateways such as AWS API Gateway or Kong are commonly configured with these e.g.
+///   _`extensions(("x-amazon-apigateway-integration" = json!({ "type": "mock" })))`_.
+///
+/// * `servers(...)` List of [`Server`][server]s overriding the global servers of the
+///   [`OpenApi`][openapi] for the operation, e.g. when an upload endpoint is served from a
+///   different host. Syntax is same as in [`OpenApi`][openapi_servers] derive
+///   _`servers(...)`_ attribute e.g. _`servers((url = "https://upload.example.com"))`_.
+///
+/// * `callbacks(...)` Map of out-of-band [`Callback`][callback]s the API may initiate related to
+///   the operation. Each callback is defined as _`("name" = path::to::handler)`_ where the
+///   _`handler`_ is a function annotated with `#[utoipa::path(...)]` and its _`path`_ is a
+///   [runtime expression][runtime_expression] e.g. _`"{$request.body#/callbackUrl}"`_.
+///   The handler should not be added to [`OpenApi`][openapi] _`paths(...)`_ since callbacks
+///   are not part of the API paths. See the [example](#examples) for more details.
+///
 /// # Request Body Attributes
 ///
 /// **Simple format definition by `request_body = ...`**
 /// * _`request_body = Type`_, _`request_body = inline(Type)`_ or _`request_body = ref("...")`_.
 ///   The given _`Type`_ can be any Rust type that is JSON parseable. It can be Option, Vec or Map etc.
 ///   With _`inline(...)`_ the schema will be inlined instead of a referenced which is the default for
-///   [`ToSchema`][to_schema] types. _`ref("./external.json")`_ can be used to reference external
+///   [`ToSchema`][to_schema] types. Inlined request body omits properties marked as _`read_only`_.
+///   _`ref("./external.json")`_ can be used to reference external
 ///   json file for body schema. **Note!** Utoipa does **not** guarantee that free form _`ref`_ is accessible via
-///   OpenAPI doc or Swagger UI, users are responsible for making these guarantees.
+///   OpenAPI doc or Swagger UI, users are responsible for making these guarantees. Each form can be
+///   wrapped in _`Option<...>`_ e.g. _`request_body = Option<inline(Type)>`_ or
+///   _`request_body = Option<ref("...")>`_ to define optional nullable request body. The _`Type`_
+///   must be an actual type in scope.
 ///
 /// **Advanced format definition by `request_body(...)`**
 /// * `content = ...` Can be _`content = Type`_, _`content = inline(Type)`_ or _`content = ref("...")`_. The
@@ -724,16 +1020,26 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   which is the default for [`ToSchema`][to_schema] types. _`ref("./external.json")`_
 ///   can be used to reference external json file for body schema. **Note!** Utoipa does **not** guarantee
 ///   that free form _`ref`_ is accessible via OpenAPI doc or Swagger UI, users are responsible for making
-///   these guarantees.
+///   these guarantees. Ad-hoc inline object can be given with _`content = { id: i64, name: String }`_
+///   without a named type, see [`schema!`][schema_macro] for the syntax. Each form can be wrapped in
+///   _`Option<...>`_ e.g. _`content = Option<ref("...")>`_ to define optional nullable request body.
+///   The _`Type`_ must be an actual type in scope since its default content type is resolved from
+///   the type, see _`content_type`_.
 ///
 /// * `description = "..."` Define the description for the request body object as str.
 ///
+/// * `required` or `required = bool` Can be used to override whether the request body is required.
+///   By default request body is required unless the _`content`_ type is _`Option<Type>`_.
+///
 /// * `content_type = "..."` or `content_type = [...]` Can be used to override the default behavior
 ///   of auto resolving the content type from the `content` attribute. If defined the value should be valid
 ///   content type such as _`application/json`_  or a slice of content types within brackets e.g.
 ///   _`content_type = ["application/json", "text/html"]`_. By default the content type is _`text/plain`_
-///   for [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and _`application/json`_
-///   for struct and complex enum types.
+///   for [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and virtual _`File`_
+///   type and _`application/json`_ for struct and complex enum types. Types marked with _`#[schema(form)]`_ default to
+///   _`application/x-www-form-urlencoded`_.
+///   With _`actix_extras`_ and _`axum_extras`_ features the content type is resolved automatically
+///   from _`Form<T>`_ extractor.
 ///
 /// * `example = ...` Can be _`json!(...)`_. _`json!(...)`_ should be something that
 ///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_.
@@ -743,56 +1049,110 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   This has same syntax as _`examples(...)`_ in [Response Attributes](#response-attributes)
 ///   _examples(...)_
 ///
+/// * `encoding(...)` Define [encoding][encoding] of the request body properties. This is only
+///   applicable for _`multipart`_ and _`application/x-www-form-urlencoded`_ request bodies.
+///   Encoding is defined in format _`("property_name" = (...))`_ where the supported attributes are:
+///     * `content_type = ...` Content type of the property e.g. _`"image/png"`_ for a file upload.
+///     * `style = ...` Serialization style of the property. See [`ParameterStyle`][style].
+///     * `explode` or `explode = bool` Whether arrays and objects generate separate parameters.
+///     * `allow_reserved` or `allow_reserved = bool` Whether reserved characters are allowed
+///       without percent encoding.
+///
+///   File properties can be documented as binary with the virtual _`File`_ type e.g.
+///   _`#[schema(value_type = File)]`_, with _`#[schema(value_type = String, format = Binary)]`_
+///   or by using _`Vec<u8>`_.
+///
 /// _**Example request body definitions.**_
 /// ```text
 ///  request_body(content = String, description = "Xml as string request", content_type = "text/xml"),
 ///  request_body = Pet,
 ///  request_body = Option<[Pet]>,
+///  request_body(content = UploadForm, content_type = "multipart/form-data",
+///     encoding(("file" = (content_type = "image/png")))
+///  ),
 /// ```
 ///
 /// # Response Attributes
 ///
 /// * `status = ...` Is either a valid http status code integer. E.g. _`200`_ or a string value representing
-///   a range such as _`"4XX"`_ or `"default"` or a valid _`http::status::StatusCode`_.
+///   a status code such as _`"404"`_, a range such as _`"4XX"`_ or `"default"` or a valid _`http::status::StatusCode`_.
 ///   _`StatusCode`_ can either be use path to the status code or _status code_ constant directly.
+///   Path to a value of user type implementing [`ToResponseStatus`][to_response_status] e.g.
+///   _`status = ApiStatus::NotFound`_ can be used as well.
 ///
-/// * `description = "..."` Define description for the response as str.
+/// * `description = ...` Define description for the response. It can be a literal string or an
+///   expression such as a path to a `const &str` e.g. _`description = NOT_FOUND_DESCRIPTION`_ which
+///   is resolved at runtime.
 ///
 /// * `body = ...` Optional response body object type. When left empty response does not expect to send any
 ///   response body. Can be _`body = Type`_, _`body = inline(Type)`_, or _`body = ref("...")`_.
 ///   The given _`Type`_ can be any Rust type that is JSON parseable. It can be Option, Vec or Map etc.
 ///   With _`inline(...)`_ the schema will be inlined instead of a referenced which is the default for
-///   [`ToSchema`][to_schema] types. _`ref("./external.json")`_
+///   [`ToSchema`][to_schema] types. Inlined response body omits properties marked as _`write_only`_.
+///   _`ref("./external.json")`_
 ///   can be used to reference external json file for body schema. **Note!** Utoipa does **not** guarantee
 ///   that free form _`ref`_ is accessible via OpenAPI doc or Swagger UI, users are responsible for making
-///   these guarantees.
+///   these guarantees. Ad-hoc inline object can be given with _`body = { id: i64, name: String }`_
+///   without a named type, see [`schema!`][schema_macro] for the syntax. Each form can be wrapped in
+///   _`Option<...>`_ e.g. _`body = Option<inline(Type)>`_ to define nullable response body.
+///   Trait object _`body = dyn Trait`_ references a schema named after the trait which is defined
+///   by registering the implementors of the trait with _`components(implementors(...))`_ of
+///   [`OpenApi`][openapi].
 ///
 /// * `content_type = "..."` or `content_type = [...]` Can be used to override the default behavior of auto resolving the content type
 ///   from the `body` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
-///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and
-///   _`application/json`_ for struct and complex enum types.
+///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_, _`Vec<u8>`_ and
+///   virtual _`File`_ type and _`application/json`_ for struct and complex enum types. Optional body e.g. _`Option<Vec<u8>>`_
+///   resolves the content type of the type within the _`Option`_.
 ///   Content type can also be slice of **content_type** values if the endpoint support returning multiple
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
 ///  _`json`_ and _`xml`_ formats. **The order** of the content types define the default example show first in
 ///  the Swagger UI. Swagger UI will use the first _`content_type`_ value as a default example.
+///   Response without _`body`_ documents _`text/plain`_ and _`text/html`_ content types as plain
+///   string content e.g. _`(status = 200, content_type = "text/html", example = "<html></html>")`_.
 ///
-/// * `headers(...)` Slice of response headers that are returned back to a caller.
+/// * `events = [...]` Names of the events of a server-sent events stream e.g.
+///   _`events = ["pet_created", "pet_deleted"]`_. The _`body`_ describes the payload of a single
+///   event and the names are added to its content as _`x-events`_ extension. Content type defaults
+///   to _`text/event-stream`_ when _`content_type`_ is not defined.
+///
+/// * `json_primitive` Shorthand to serve a [primitive Rust type][primitive] `body` as
+///   _`application/json`_ instead of the default _`text/plain`_. E.g. a count endpoint returning
+///   a bare `42` can be described with _`(status = 200, body = i64, json_primitive)`_. This is
+///   equal to defining _`content_type = "application/json"`_ and has no effect if _`content_type`_
+///   is defined explicitly.
+///
+/// * `headers(...)` Slice of response headers that are returned back to a caller. Headers are
+///   either header tuples or types implementing [`ToHeader`][to_header].
 ///
 /// * `example = ...` Can be _`json!(...)`_. _`json!(...)`_ should be something that
-///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_.
+///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_. The example is set to the media
+///   type object of every content type of the response and requires _`body`_ or
+///   _`text/plain`_ or _`text/html`_ _`content_type`_ to be defined.
 ///
 /// * `response = ...` Type what implements [`ToResponse`][to_response_trait] trait. This can alternatively be used to
 ///    define response attributes. _`response`_ attribute cannot co-exist with other than _`status`_ attribute.
 ///
 /// * `content((...), (...))` Can be used to define multiple return types for single response status. Supported format for single
-///   _content_ is `(content_type = response_body, example = "...", examples(...))`. _`example`_
+///   _content_ is `(content_type = response_body, example = "...", examples(...))`. The content can also
+///   be defined body first e.g. _`(Pet = "application/json")`_. _`example`_
 ///   and _`examples`_ are optional arguments. Examples attribute behaves exactly same way as in
 ///   the response and is mutually exclusive with the example attribute.
 ///
+/// * `deprecated = "..."` Can be used to add a deprecation note e.g. pointing to the replacement
+///   for the response. OpenAPI does not support deprecating responses so the note will be appended
+///   to the response _`description`_ prefixed with _`Deprecated: `_.
+///
+/// * `external_docs(...)` Can be used to link an external resource such as an error code catalog
+///   to the response. E.g. _`external_docs(url = "https://...", description = "...")`_. The `url`
+///   is mandatory. OpenAPI does not support external docs of responses so the link will be
+///   appended to the response _`description`_ as markdown link.
+///
 /// * `examples(...)` Define multiple examples for single response. This attribute is mutually
 ///   exclusive to the _`example`_ attribute and if both are defined this will override the _`example`_.
-///     * `name = ...` This is first attribute and value must be literal string.
+///     * `name = ...` This is first attribute and value must be literal string. Names must be
+///       unique within the _`examples(...)`_.
 ///     * `summary = ...` Short description of example. Value must be literal string.
 ///     * `description = ...` Long description of example. Attribute supports markdown for rich text
 ///       representation. Value must be literal string.
@@ -806,6 +1166,29 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///      ("John" = (summary = "This is John", value = json!({"name": "John"})))
 ///     ```
 ///
+/// * `links(...)` Define [links][link] to other operations that can be followed from the response.
+///     * `name = ...` This is first attribute and value must be literal string. Names must be
+///       unique within the _`links(...)`_.
+///     * `operation_id = ...` Name of an existing operation as defined with its unique
+///       _`operation_id`_. Value must be literal string.
+///     * `operation_ref = ...` Relative or absolute reference to an operation e.g.
+///       _`"#/paths/~1pets~1{id}/get"`_. This is mutually exclusive to the _`operation_id`_ and
+///       one of them must be defined. Value must be literal string.
+///     * `parameters(...)` Parameters passed to the linked operation in format
+///       _`("name" = value)`_. Value can be literal, _`json!(...)`_ or method reference and is
+///       typically a [runtime expression][runtime_expression] e.g. _`"$response.body#/id"`_.
+///     * `request_body = ...` Request body passed to the linked operation. Value can be literal,
+///       _`json!(...)`_ or method reference.
+///     * `description = ...` Description of the link. Value must be literal string.
+///
+///      _**Example of link definition.**_
+///     ```text
+///      ("get_pet" = (operation_id = "get_pet_by_id", parameters(("id" = "$response.body#/id"))))
+///     ```
+///
+/// * `extensions(...)` Specification extensions of the response given as _`("x-name" = value)`_
+///   pairs. Same syntax as _`extensions(...)`_ of the operation.
+///
 /// **Minimal response format:**
 /// ```text
 /// responses(
@@ -834,6 +1217,15 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// )
 /// ```
 ///
+/// **Response with a link to another operation:**
+/// ```text
+/// responses(
+///     (status = 201, description = "Pet created", body = Pet, links(
+///         ("get_pet" = (operation_id = "get_pet_by_id", parameters(("id" = "$response.body#/id"))))
+///     ))
+/// )
+/// ```
+///
 /// **Multiple response return types with _`content(...)`_ attribute:**
 ///
 /// _**Define multiple response return types for single response status with their own example.**_
@@ -841,12 +1233,25 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// responses(
 ///    (status = 200, content(
 ///            ("application/vnd.user.v1+json" = User, example = json!(User {id: "id".to_string()})),
-///            ("application/vnd.user.v2+json" = User2, example = json!(User2 {id: 2}))
+///            ("application/vnd.user.v2+json" = User2, example = json!(User2 {id: 2})),
+///            (UserXml = "text/xml")
 ///        )
 ///    )
 /// )
 /// ```
 ///
+/// ### Conditional responses
+///
+/// _**Response prefixed with _`cfg(...)`_ is only included when the predicate holds.**_ The
+/// predicate is the same as in Rust's own `#[cfg(...)]` attribute and is evaluated in the crate
+/// declaring the path.
+/// ```text
+/// responses(
+///     (status = 200, description = "success response"),
+///     cfg(feature = "debug") (status = 500, description = "debug error", body = DebugError)
+/// )
+/// ```
+///
 /// ### Using `ToResponse` for reusable responses
 ///
 /// _**`ReusableResponse` must be a type that implements [`ToResponse`][to_response_trait].**_
@@ -871,6 +1276,15 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// responses(MyResponse)
 /// ```
 ///
+/// _**With _`auto_into_responses`_ feature responses are derived from the return type of the
+/// handler implementing [`IntoResponses`][into_responses_trait].**_ _`Result<T, E>`_ combines the
+/// responses of _`T`_ and _`E`_. Explicit _`responses(...)`_ override the derived responses with
+/// the same status code.
+/// ```text
+/// #[utoipa::path(get, path = "/pet/{id}", responses((status = 404, description = "No pet")))]
+/// async fn get_pet() -> Result<PetResponse, ErrorResponse> { ... }
+/// ```
+///
 /// # Response Header Attributes
 ///
 /// * `name` Name of the header. E.g. _`x-csrf-token`_
@@ -879,15 +1293,43 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   The given _`Type`_ can be any Rust type that is JSON parseable. It can be Option, Vec or Map etc.
 ///   With _`inline(...)`_ the schema will be inlined instead of a referenced which is the default for
 ///   [`ToSchema`][to_schema] types. **Reminder!** It's up to the user to use valid type for the
-///   response header.
+///   response header. Type can also be _`ref("...")`_ to reference a reusable header from
+///   _`components/headers`_ instead of repeating the same header on every response. Reusable
+///   headers can be added to the [`Components`][components] e.g. with a [`Modify`][modify]
+///   implementation.
 ///
 /// * `description = "..."` Can be used to define optional description for the response header as str.
+///   Description is not supported with _`ref("...")`_.
+///
+/// * `required` or `required = bool` Can be used to declare whether the response header is
+///   mandatory.
+///
+/// * `deprecated` or `deprecated = bool` Can be used to mark the response header deprecated.
+///
+/// * `example = ...` Can be _`json!(...)`_ or a literal e.g. _`"abc-123"`_ or _`10`_. Example of the
+///   header's potential value.
 ///
 /// **Header supported formats:**
 ///
 /// ```text
 /// ("x-csrf-token"),
 /// ("x-csrf-token" = String, description = "New csrf token"),
+/// ("x-request-id" = String, required, example = "abc-123"),
+/// ("x-rate-limit" = ref("#/components/headers/RateLimit")),
+/// ```
+///
+/// **Headers from a type:**
+///
+/// Path to a type implementing [`ToHeader`][to_header] can be given within _`headers(...)`_ in
+/// place of a header tuple. The type expands into all of its headers which allows documenting the
+/// same set of headers on multiple responses without repeating them. See
+/// [`derive@ToHeader`] for more details.
+///
+/// ```text
+/// headers(
+///     RateLimitHeaders,
+///     ("x-request-id" = String)
+/// )
 /// ```
 ///
 /// # Params Attributes
@@ -902,7 +1344,8 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `name` _**Must be the first argument**_. Define the name for parameter.
 ///
-/// * `parameter_type` Define possible type for the parameter. Can be `Type` or `inline(Type)`.
+/// * `parameter_type` Define possible type for the parameter. Can be `Type`, `inline(Type)` or
+///   `Option<inline(Type)>`.
 ///   The given _`Type`_ can be any Rust type that is JSON parseable. It can be Option, Vec or Map etc.
 ///   With _`inline(...)`_ the schema will be inlined instead of a referenced which is the default for
 ///   [`ToSchema`][to_schema] types. Parameter type is placed after `name` with
@@ -910,22 +1353,42 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `in` _**Must be placed after name or parameter_type**_. Define the place of the parameter.
 ///   This must be one of the variants of [`openapi::path::ParameterIn`][in_enum].
-///   E.g. _`Path, Query, Header, Cookie`_
+///   E.g. _`Path, Query, Header, Cookie`_. Lowercase form _`path, query, header, cookie`_ is
+///   accepted as well. Parameter is required unless the _`parameter_type`_ is _`Option<T>`_, but
+///   _`Path`_ parameters are always required as mandated by the OpenAPI specification.
 ///
 /// * `deprecated` Define whether the parameter is deprecated or not. Can optionally be defined
 ///    with explicit `bool` value as _`deprecated = bool`_.
 ///
+/// * `required` Override whether the parameter is required instead of resolving it from
+///   _`Option<T>`_ of the _`parameter_type`_. Can optionally be defined with explicit `bool`
+///   value as _`required = bool`_. _`Path`_ parameters are always required regardless.
+///
 /// * `description = "..."` Define possible description for the parameter as str.
 ///
 /// * `style = ...` Defines how parameters are serialized by [`ParameterStyle`][style]. Default values are based on _`in`_ attribute.
+///   Style can be given either as variant _`style = Form`_ or as OpenAPI value _`style = "form"`_. Style must be
+///   allowed for the parameter location, e.g. _`DeepObject`_ is only allowed for _`Query`_ parameters. The location
+///   is only validated when _`in`_ is explicitly defined. Array _`Query`_ parameters e.g. _`Vec<String>`_ default to
+///   exploded _`Form`_ style representing repeated parameters _`?tag=a&tag=b`_.
 ///
 /// * `explode` Defines whether new _`parameter=value`_ is created for each parameter within _`object`_ or _`array`_.
+///   Can optionally be defined with explicit `bool` value as _`explode = bool`_. If `style` is defined without
+///   `explode`, explode defaults to _`true`_ for _`Form`_ style and to _`false`_ for other styles.
 ///
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
+///   Can optionally be defined with explicit `bool` value as _`allow_reserved = bool`_. Reserved
+///   characters can only be allowed for _`Query`_ parameters. The location is only validated when
+///   _`in`_ is explicitly defined.
 ///
 /// * `example = ...` Can method reference or _`json!(...)`_. Given example
 ///   will override any example in underlying parameter type.
 ///
+/// * `examples(...)` Define multiple named examples for the parameter. This attribute is mutually
+///   exclusive to the _`example`_ attribute and if both are defined this will override the _`example`_.
+///   This has same syntax as _`examples(...)`_ in [Response Attributes](#response-attributes)
+///   _examples(...)_
+///
 /// ##### Parameter type attributes
 ///
 /// These attributes supported when _`parameter_type`_ is present. Either by manually providing one
@@ -943,7 +1406,8 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties for the parameter type.
 ///    See configuration options at xml attributes of [`ToSchema`][to_schema_xml]
 ///
-/// * `nullable` Defines property is nullable (note this is different to non-required).
+/// * `nullable` Defines property is nullable (note this is different to non-required). Use
+///   _`nullable = false`_ to not mark _`Option`_ field as nullable.
 ///
 /// * `multiple_of = ...` Can be used to define multiplier for a value. Value is considered valid
 ///   division will result an `integer`. Value must be strictly above _`0`_.
@@ -963,10 +1427,19 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `pattern = ...` Can be used to define valid regular expression in _ECMA-262_ dialect the field value must match.
 ///
 /// * `max_items = ...` Can be used to define maximum items allowed for `array` fields. Value must
-///   be non-negative integer.
+///   be non-negative integer. Fixed size arrays _`[T; N]`_ default to `N`. Using it with other than
+///   `array` fields is a compile error.
 ///
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
-///   be non-negative integer.
+///   be non-negative integer. Fixed size arrays _`[T; N]`_ default to `N`. Using it with other than
+///   `array` fields is a compile error.
+///
+/// * `unique_items` Can be used to define that all items of `array` field must be unique. Can optionally
+///   be defined with explicit `bool` value as _`unique_items = bool`_. Set types are always unique.
+///
+/// * `enum_values = [...]` Can be used to restrict the parameter value to a fixed list of string or
+///   number literals e.g. _`enum_values = ["asc", "desc"]`_. Literal type must match the type of the
+///   parameter.
 ///
 /// **For example:**
 ///
@@ -1046,7 +1519,10 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// 1. Ability to parse `path` from **actix-web** path attribute macros e.g. _`#[get(...)]`_.
 /// 2. Ability to parse [`std::primitive`]  or [`String`] or [`tuple`] typed `path` parameters from **actix-web** _`web::Path<...>`_.
 /// 3. Ability to parse `path` and `query` parameters form **actix-web** _`web::Path<...>`_, _`web::Query<...>`_ types
-///    with [`IntoParams`][into_params] trait.
+///    with [`IntoParams`][into_params] trait. The types need not to be listed in _`params(...)`_ unless path parameters
+///    are declared explicitly, thus the types of _`web::Query<...>`_ extractors of named structs must implement
+///    [`IntoParams`][into_params].
+/// 4. Ability to parse `request_body` from **actix-web** _`web::Json<...>`_, _`web::Form<...>`_ and _`Bytes`_ types.
 ///
 /// See the **actix_extras** in action in examples [todo-actix](https://github.com/juhaku/utoipa/tree/master/examples/todo-actix).
 ///
@@ -1089,14 +1565,47 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Resolve path and query parameters and request body from the handler arguments.**_
+/// ```rust
+/// use actix_web::{post, web, HttpResponse, Responder};
+/// use serde::Deserialize;
+/// use utoipa::{IntoParams, ToSchema};
+///
+/// #[derive(Deserialize, IntoParams)]
+/// struct Filter {
+///     /// Only pets of the given age
+///     age: Option<u32>,
+/// }
+///
+/// #[derive(Deserialize, ToSchema)]
+/// struct CreatePet {
+///     name: String,
+/// }
+///
+/// #[utoipa::path(
+///     responses(
+///         (status = 201, description = "Pet created")
+///     )
+/// )]
+/// #[post("/owners/{id}/pets")]
+/// async fn create_pet(
+///     id: web::Path<(u64,)>,
+///     filter: web::Query<Filter>,
+///     pet: web::Json<CreatePet>,
+/// ) -> impl Responder {
+///     HttpResponse::Created()
+/// }
+/// ```
+///
 /// # rocket_extras feature support for rocket
 ///
 /// **rocket_extras** feature enhances path operation parameter support. It gives **utoipa** ability to parse `path`, `path parameters`
 /// and `query parameters` based on arguments given to **rocket**  proc macros such as _**`#[get(...)]`**_.
 ///
 /// 1. It is able to parse parameter types for [primitive types][primitive], [`String`], [`Vec`], [`Option`] or [`std::path::PathBuf`]
-///    type.
+///    type. [`std::path::PathBuf`] is rendered as `string` regardless of the feature.
 /// 2. It is able to determine `parameter_in` for [`IntoParams`][into_params] trait used for `FromForm` type of query parameters.
+/// 3. Route attributes can be used either imported _**`#[get(...)]`**_ or fully qualified _**`#[rocket::get(...)]`**_.
 ///
 /// See the **rocket_extras** in action in examples [rocket-todo](https://github.com/juhaku/utoipa/tree/master/examples/rocket-todo).
 ///
@@ -1108,7 +1617,12 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// 1. It allows users to use tuple style path parameters e.g. _`Path((id, name)): Path<(i32, String)>`_ and resolves
 ///    parameter names and types from it.
 /// 2. It enhances [`IntoParams` derive][into_params_derive] functionality by automatically resolving _`parameter_in`_ from
-///   _`Path<...>`_ or _`Query<...>`_ handler function arguments.
+///    _`Path<...>`_ or _`Query<...>`_ handler function arguments. The [`IntoParams`][into_params] types need not to be
+///    listed in _`params(...)`_ unless path parameters are declared explicitly.
+/// 3. It resolves _`request_body`_ from _`Json<...>`_, _`Form<...>`_ and _`Bytes`_ handler function arguments. Other
+///    extractors such as _`State<...>`_ are ignored.
+/// 4. It allows using axum path syntax in _`path = "..."`_ e.g. _`"/todo/:id"`_ or _`"/files/*path"`_ which
+///    will be converted to OpenAPI path syntax _`"/todo/{id}"`_ and _`"/files/{path}"`_.
 ///
 /// _**Resole path argument types from tuple style handler arguments.**_
 /// ```rust
@@ -1132,6 +1646,39 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Resolve parameters and request body from the handler function arguments.**_
+/// ```rust
+/// # use axum::{extract::{Path, Query, State}, Json};
+/// # use serde::Deserialize;
+/// # use utoipa::{IntoParams, ToSchema};
+/// #[derive(Deserialize, IntoParams)]
+/// struct Filter {
+///     age: Option<u32>,
+/// }
+///
+/// #[derive(Deserialize, ToSchema)]
+/// struct CreatePet {
+///     name: String,
+/// }
+///
+/// #[derive(Clone)]
+/// struct AppState;
+///
+/// #[utoipa::path(
+///     post,
+///     path = "/owners/:id/pets",
+///     responses(
+///         (status = 201, description = "Pet created")
+///     )
+/// )]
+/// async fn create_pet(
+///     State(state): State<AppState>,
+///     Path(id): Path<u64>,
+///     Query(filter): Query<Filter>,
+///     Json(pet): Json<CreatePet>,
+/// ) {}
+/// ```
+///
 /// _**Use `IntoParams` to resolve query parameters.**_
 /// ```rust
 /// # use serde::Deserialize;
@@ -1163,6 +1710,96 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Use axum path syntax in `path`.**_
+/// ```rust
+/// # use axum::extract::Path;
+/// /// Get todo by id.
+/// #[utoipa::path(
+///     get,
+///     path = "/todo/:id",
+///     responses(
+///         (status = 200, description = "Get todo success", body = String)
+///     )
+/// )]
+/// async fn get_todo(Path(id): Path<i32>) -> String {
+///     String::new()
+/// }
+/// ```
+///
+/// # poem_extras feature support for poem
+///
+/// **poem_extras** feature enhances parameter support for path operation in same way as
+/// **axum_extras** since [poem](https://github.com/poem-web/poem) uses similar _`Path<...>`_,
+/// _`Query<...>`_, _`Json<...>`_ and _`Form<...>`_ extractors. In addition poem path syntax
+/// _`"/todo/:id"`_, _`"/todo/:id<\d+>"`_ and _`"/files/*path"`_ is converted to OpenAPI path syntax
+/// _`"/todo/{id}"`_ and _`"/files/{path}"`_.
+///
+/// _**Resolve parameters and request body from poem handler function arguments.**_
+/// ```rust
+/// # use poem::web::{Json, Path, Query};
+/// # use serde::Deserialize;
+/// # use utoipa::{IntoParams, ToSchema};
+/// #[derive(Deserialize, IntoParams)]
+/// struct Filter {
+///     age: Option<u32>,
+/// }
+///
+/// #[derive(Deserialize, ToSchema)]
+/// struct CreatePet {
+///     name: String,
+/// }
+///
+/// #[utoipa::path(
+///     post,
+///     path = "/owners/:id/pets",
+///     responses(
+///         (status = 201, description = "Pet created")
+///     )
+/// )]
+/// async fn create_pet(
+///     Path(id): Path<u64>,
+///     Query(filter): Query<Filter>,
+///     Json(pet): Json<CreatePet>,
+/// ) {}
+/// ```
+///
+/// # salvo_extras feature support for salvo
+///
+/// **salvo_extras** feature enhances parameter support for path operation in following ways.
+///
+/// 1. It resolves path and query parameters from _`PathParam<...>`_ and _`QueryParam<...>`_
+///    handler function arguments using the argument name as parameter name. _`QueryParam<T, true>`_
+///    is resolved as required and _`QueryParam<T, false>`_ as not required parameter.
+/// 2. It resolves _`request_body`_ from _`JsonBody<...>`_ and _`FormBody<...>`_ handler function
+///    arguments. Other arguments such as _`&mut Request`_ or _`&mut Depot`_ are ignored.
+/// 3. It allows using salvo path syntax in _`path = "..."`_ e.g. _`"/todo/<id>"`_,
+///    _`"/todo/<id:num>"`_ or _`"/files/<**path>"`_ which will be converted to OpenAPI path syntax
+///    _`"/todo/{id}"`_ and _`"/files/{path}"`_.
+///
+/// _**Resolve parameters and request body from salvo handler function arguments.**_
+/// ```rust
+/// # use salvo::oapi::extract::{JsonBody, PathParam, QueryParam};
+/// # use serde::Deserialize;
+/// # use utoipa::ToSchema;
+/// #[derive(Deserialize, ToSchema)]
+/// struct CreatePet {
+///     name: String,
+/// }
+///
+/// #[utoipa::path(
+///     post,
+///     path = "/owners/<id>/pets",
+///     responses(
+///         (status = 201, description = "Pet created")
+///     )
+/// )]
+/// async fn create_pet(
+///     id: PathParam<u64>,
+///     notify: QueryParam<bool, false>,
+///     pet: JsonBody<CreatePet>,
+/// ) {}
+/// ```
+///
 /// # Examples
 ///
 /// _**More complete example.**_
@@ -1315,6 +1952,51 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Define callback the API will call after order status has changed.**_
+/// ```rust
+/// /// Order status changed
+/// #[utoipa::path(
+///     post,
+///     path = "{$request.body#/callbackUrl}",
+///     responses(
+///         (status = 200, description = "Callback received successfully")
+///     )
+/// )]
+/// fn order_status_changed() {}
+///
+/// #[utoipa::path(
+///     post,
+///     path = "/orders",
+///     callbacks(
+///         ("orderStatus" = order_status_changed)
+///     ),
+///     responses(
+///         (status = 201, description = "Order created")
+///     )
+/// )]
+/// fn create_order() {}
+/// ```
+///
+/// _**Define paths for associated functions and methods of a controller.**_
+/// ```rust
+/// # use utoipa::OpenApi;
+/// struct PetController;
+///
+/// impl PetController {
+///     /// List all pets
+///     #[utoipa::path(get, path = "/pets", associated, responses((status = 200)))]
+///     pub async fn list_pets() {}
+///
+///     /// Get pet by id
+///     #[utoipa::path(get, path = "/pets/{id}", responses((status = 200)))]
+///     pub async fn get_pet(&self) {}
+/// }
+///
+/// #[derive(OpenApi)]
+/// #[openapi(paths(PetController::list_pets, PetController::get_pet))]
+/// struct ApiDoc;
+/// ```
+///
 /// [in_enum]: openapi/path/enum.ParameterIn.html
 /// [path]: trait.Path.html
 /// [to_schema]: trait.ToSchema.html
@@ -1324,12 +2006,23 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [into_params]: trait.IntoParams.html
 /// [style]: openapi/path/enum.ParameterStyle.html
+/// [encoding]: openapi/encoding/struct.Encoding.html
 /// [into_responses_trait]: trait.IntoResponses.html
 /// [into_params_derive]: derive.IntoParams.html
 /// [to_response_trait]: trait.ToResponse.html
+/// [to_header]: trait.ToHeader.html
+/// [to_response_status]: trait.ToResponseStatus.html
+/// [schema_macro]: macro.schema.html
+/// [components]: openapi/schema/struct.Components.html
+/// [modify]: trait.Modify.html
 /// [known_format]: openapi/schema/enum.KnownFormat.html
 /// [xml]: openapi/xml/struct.Xml.html
 /// [to_schema_xml]: macro@ToSchema#xml-attribute-configuration-options
+/// [link]: openapi/link/struct.Link.html
+/// [runtime_expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+/// [callback]: openapi/path/struct.Callback.html
+/// [server]: openapi/server/struct.Server.html
+/// [openapi_servers]: derive.OpenApi.html#servers-attribute-syntax
 pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     let path_attribute = syn::parse_macro_input!(attr as PathAttr);
 
@@ -1337,6 +2030,8 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         feature = "actix_extras",
         feature = "rocket_extras",
         feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras",
         feature = "auto_into_responses"
     ))]
     let mut path_attribute = path_attribute;
@@ -1345,7 +2040,7 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         Ok(ast_fn) => ast_fn,
         Err(error) => return error.into_compile_error().into_token_stream().into(),
     };
-    let fn_name = &*ast_fn.sig.ident.to_string();
+    let fn_name = &*ast_fn.sig.ident.unraw().to_string();
 
     #[cfg(feature = "auto_into_responses")]
     {
@@ -1368,14 +2063,18 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     let mut resolved_path = resolved_path;
 
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     {
         use ext::ArgumentResolver;
@@ -1402,19 +2101,32 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         path_attribute.update_request_body(body);
     }
 
+    // axum and poem path parameters `:id` and `*rest` as well as salvo path parameters `<id>` and
+    // `<**rest>` are resolved to `{id}` and `{rest}`
+    #[cfg(any(
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    ))]
+    if let (Some(parse_utils::Value::LitStr(path)), Some(resolved_path)) =
+        (path_attribute.path.as_mut(), resolved_path.as_ref())
+    {
+        *path = syn::LitStr::new(&resolved_path.path, path.span());
+    }
+
     let path = Path::new(path_attribute, fn_name)
         .path_operation(resolved_operation.map(|operation| operation.path_operation))
         .path(|| resolved_path.map(|path| path.path))
         .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
-        .deprecated(ast_fn.attrs.iter().find_map(|attr| {
-
-            if !matches!(attr.path().get_ident(), Some(ident) if &*ident.to_string() == "deprecated")
-            {
-                None
-            } else {
-                Some(true)
-            }
-        }));
+        .deprecated(
+            ast_fn
+                .attrs
+                .iter()
+                .find(|attribute| attribute.path().is_ident("deprecated"))
+                .map(DeprecatedNote::from_attribute),
+        )
+        .vis(ast_fn.vis.clone())
+        .receiver(ast_fn.sig.receiver().is_some());
 
     quote! {
         #path
@@ -1432,23 +2144,69 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// # OpenApi `#[openapi(...)]` attributes
 ///
 /// * `paths(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
-/// * `components(schemas(...), responses(...))` Takes available _`component`_ configurations. Currently only
-///    _`schema`_ and _`response`_ components are supported.
-///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema.
+///   Method reference prefixed with _`cfg(...)`_ is only included when the predicate holds which
+///   allows listing handlers compiled with _`#[cfg(...)]`_ e.g.
+///   _`paths(get_pet, cfg(feature = "admin") admin::delete_pet)`_. Paths declared _`hidden`_ are
+///   left out from the document. Associated functions and trait methods are referenced through
+///   their type e.g. _`paths(PetController::get_pet)`_ where the trait of a trait method must be
+///   in scope.
+/// * `webhooks(...)` List of webhooks the API may deliver to its consumers defined as
+///   _`("name" = path::to::handler)`_ where the _`handler`_ is a method reference having attribute
+///   [`#[utoipa::path]`][path] macro. Webhooks are added to the _`webhooks`_ map of the document
+///   instead of _`paths`_ keyed by the event name, and the _`path`_ of the handler is not used.
+///   Webhooks are only supported by OpenAPI 3.1, see _`openapi_3_1`_ feature of `utoipa`.
+/// * `components(schemas(...), responses(...), implementors(...))` Takes available _`component`_
+///    configurations. Currently only _`schema`_ and _`response`_ components are supported.
+///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema. With
+///      _`auto_collect_schemas`_ feature schemas referenced by the fields of the listed schemas
+///      are collected automatically, see [schema collection](#schema-collection). Different types
+///      sharing the same schema name e.g. _`pets::Error`_ and _`users::Error`_ overwrite each
+///      other, see _`deny_schema_name_collisions`_ to detect them.
 ///    * `responses(...)` List of types that implement
 /// [`ToResponse`][to_response_trait].
+///    * `implementors(...)` List of trait objects with their concrete implementors defined as
+///      _`dyn Trait = [Type, ...]`_ e.g. _`implementors(dyn ErrorResponse = [NotFound, Conflict])`_.
+///      Each trait is added as a _`oneOf`_ schema of the listed [`ToSchema`][to_schema] types named
+///      after the trait, and the schemas of the types are added to the components. Paths can then
+///      use the trait object as body type e.g. _`body = dyn ErrorResponse`_ or
+///      _`body = Box<dyn ErrorResponse>`_. Listed types must implement the trait.
 /// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
-///   See the [trait documentation][modify] for more details.
+///   See the [trait documentation][modify] for more details. Modifiers can be given with a path
+///   e.g. _`modifiers(&addons::SecurityAddon, &ServerAddon)`_ and they are applied in the order
+///   of declaration.
 /// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
 ///   See more details in [`#[utoipa::path(...)]`][path] [attribute macro security options][path_security].
+///   Operations defining their own _`security(...)`_ override the global requirements, e.g.
+///   _`security(())`_ in [`#[utoipa::path(...)]`][path] makes security optional for the operation.
 /// * `tags(...)` List of [`Tag`][tags] which must match the tag _**path operation**_. By default
-///   the tag is derived from path given to **handlers** list or if undefined then `crate` is used by default.
+///   the tag is the parent module of the path given to **handlers** list or if undefined then `crate` is used by default.
 ///   Alternatively the tag name can be given to path operation via [`#[utoipa::path(...)]`][path] macro.
 ///   Tag can be used to define extra information for the api to produce richer documentation.
+///   Tag names must be unique and tags are listed in the generated document in the order of
+///   declaration which documentation UIs typically use for ordering the operation groups.
 /// * `external_docs(...)` Can be used to reference external resource to the OpenAPI doc for extended documentation.
 ///   External docs can be in [`OpenApi`][openapi_struct] or in [`Tag`][tags] level.
+/// * `extensions(...)` Root level specification extensions of the OpenAPI doc given as
+///   _`("x-name" = value)`_ pairs. Value can be literal, _`json!(...)`_ or method reference and
+///   names must start with _`x-`_. E.g. _`extensions(("x-internal" = true))`_.
 /// * `servers(...)` Define [`servers`][servers] as derive argument to the _`OpenApi`_. Servers
 ///   are completely optional and thus can be omitted from the declaration.
+/// * `infer_tags = bool` Whether operations without explicit _`tag`_ are tagged with the name of
+///   the parent module or type of the handler listed in _`paths(...)`_. Defaults to _`true`_. With
+///   _`infer_tags = false`_ such operations are left without tags.
+/// * `deny_schema_name_collisions = bool` Whether [`OpenApi::openapi`][openapi] panics pointing
+///   at the types when schemas of different types share the same schema name e.g. _`pets::Error`_
+///   and _`users::Error`_. Defaults to _`false`_ in which case only one of the schemas is added to
+///   the components. Give one of the types a module qualified name with _`as = ...`_ e.g.
+///   _`#[schema(as = users::Error)]`_ or a unique name with _`rename = "..."`_ attribute of
+///   [`ToSchema`][to_schema].
+/// * `nest(...)` List of other _`OpenApi`_ documents nested under a path defined as
+///   _`(path = "/api/v1/pets", api = path::to::PetApi)`_. The _`api`_ must implement the
+///   [`OpenApi`][openapi] trait. Paths of the nested document are prefixed with the _`path`_ and
+///   then merged to this document along with its components, tags and other items. See
+///   [`OpenApi::nest`][openapi_nest] for more details.
+///   Optional _`tags = ["admin"]`_ replaces the tags of every operation of the nested document
+///   e.g. _`(path = "/admin", api = AdminApi, tags = ["admin"])`_.
 /// * `info(...)` Declare [`Info`][info] attribute values used to override the default values
 ///   generated from Cargo environment variables. **Note!** Defined attributes will override the
 ///   whole attribute from generated values of Cargo environment variables. E.g. defining
@@ -1464,12 +2222,18 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * env `CARGO_PKG_AUTHORS` map to contact `name` and `email` **only first author will be used**
 /// * env `CARGO_PKG_LICENSE` map to info `license`
 ///
+/// Values missing from _`Cargo.toml`_ e.g. _`description`_ or _`license`_ are left out from the
+/// [`Info`][info] instead of rendering them empty. Each of the values can be overridden with
+/// _`info(...)`_ attribute.
+///
 /// # `info(...)` attribute syntax
 ///
 /// * `title = ...` Define title of the API. It can be literal string.
 /// * `description = ...` Define description of the API. Markdown can be used for rich text
 ///   representation. It can be literal string or [`include_str!`] statement.
 /// * `version = ...` Override default version from _`Cargo.toml`_. Value must be literal string.
+/// * `terms_of_service = ...` Define URL to the terms of service of the API. It must be URL
+///   formatted literal string.
 /// * `contact(...)` Used to override the whole contact generated from environment variables.
 ///     * `name = ...` Define identifying name of contact person / organization. It Can be a literal string.
 ///     * `email = ...` Define email address of the contact person / organization. It can be a literal string.
@@ -1477,16 +2241,21 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * `license(...)` Used to override the whole license generated from environment variables.
 ///     * `name = ...` License name of the API. It can be a literal string.
 ///     * `url = ...` Define optional URL of the license. It must be URL formatted string.
+/// * `extensions(...)` Specification extensions of the info given as _`("x-name" = value)`_ pairs
+///   e.g. _`extensions(("x-logo" = json!({ "url": "logo.png" })))`_.
 ///
 /// # `servers(...)` attribute syntax
 ///
-/// * `url = ...` Define the url for server. It can be literal string.
+/// * `url = ...` Define the url for server. It can be literal string. Url is required and it
+///   can be a template with variables in curly braces e.g.
+///   _`"https://{region}.api.example.com/{basePath}"`_.
 /// * `description = ...` Define description for the server. It can be literal string.
-/// * `variables(...)` Can be used to define variables for the url.
+/// * `variables(...)` Can be used to define variables for the url. Every variable used in the url
+///   template must be defined here.
 ///     * `name = ...` Is the first argument within parentheses. It must be literal string.
 ///     * `default = ...` Defines a default value for the variable if nothing else will be
-///       provided. If _`enum_values`_ is defined the _`default`_ must be found within the enum
-///       options. It can be a literal string.
+///       provided. Default is required and if _`enum_values`_ is defined the _`default`_ must be
+///       found within the enum options. It can be a literal string.
 ///     * `description = ...` Define the description for the variable. It can be a literal string.
 ///     * `enum_values(...)` Define list of possible values for the variable. Values must be
 ///       literal strings.
@@ -1494,9 +2263,33 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// _**Example server variable definition.**_
 /// ```text
 /// ("username" = (default = "demo", description = "Default username for API")),
-/// ("port" = (enum_values("8080", "5000", "4545")))
+/// ("port" = (default = "8080", enum_values("8080", "5000", "4545")))
 /// ```
 ///
+/// # Schema collection
+///
+/// With _`auto_collect_schemas`_ feature schemas referenced by the listed _`schemas(...)`_,
+/// _`paths(...)`_ and _`webhooks(...)`_ are collected to the components automatically along with
+/// the schemas they reference in turn. Explicitly listed schemas take precedence over the
+/// collected ones and the collection is done before _`modifiers(...)`_ are applied. Without the
+/// feature only the explicitly listed schemas are added to the components.
+///
+/// * Fields of [`ToSchema`][to_schema] derived types are collected unless they are inlined, or
+///   defined with _`value_type`_ or _`schema_with`_. Schemas referenced by inlined fields are
+///   still collected.
+/// * Request body and parameter types resolved from the handler function arguments with
+///   _`actix_extras`_, _`rocket_extras`_ or _`axum_extras`_ are collected.
+/// * Request body, response body and parameter types declared within
+///   [`#[utoipa::path(...)]`][path] attribute are collected. With the feature the types must be
+///   actual types in scope instead of only names of the schemas.
+/// * Schemas are collected with the name they are referenced with. Aliases of generic types
+///   e.g. _`PageOfPet = Page<Pet>`_ are collected as separate schemas by the alias name.
+/// * Types which do not implement [`ToSchema`][to_schema] are ignored.
+/// * Only one of the different types collected with the same schema name, e.g. _`pets::Error`_
+///   and _`users::Error`_, is added to the components. With _`deny_schema_name_collisions`_ they
+///   panic in [`OpenApi::openapi`][openapi] instead. Give one of them a unique name with
+///   _`#[schema(as = ...)]`_.
+///
 /// # Examples
 ///
 /// _**Define OpenApi schema with some paths and components.**_
@@ -1545,6 +2338,41 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// struct ApiDoc;
 /// ```
 ///
+/// _**Document trait object response body with registered implementors.**_
+/// ```rust
+/// # use utoipa::{OpenApi, ToSchema};
+/// trait ErrorResponse {}
+///
+/// #[derive(ToSchema)]
+/// struct NotFound {
+///     resource: String,
+/// }
+/// impl ErrorResponse for NotFound {}
+///
+/// #[derive(ToSchema)]
+/// struct Conflict {
+///     reason: String,
+/// }
+/// impl ErrorResponse for Conflict {}
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/pets/{id}",
+///     responses(
+///         (status = 200, description = "Pet found", body = String),
+///         (status = "4XX", description = "Pet not available", body = Box<dyn ErrorResponse>)
+///     )
+/// )]
+/// fn get_pet() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(
+///     paths(get_pet),
+///     components(implementors(dyn ErrorResponse = [NotFound, Conflict]))
+/// )]
+/// struct ApiDoc;
+/// ```
+///
 /// _**Define servers to OpenApi.**_
 /// ```rust
 /// # use utoipa::OpenApi;
@@ -1576,6 +2404,21 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// struct ApiDoc;
 /// ```
 ///
+/// _**Define all info attribute values.**_
+/// ```rust
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi(info(
+///     title = "Pet api",
+///     version = "1.0.0",
+///     description = "Api for managing pets",
+///     terms_of_service = "https://example.com/terms",
+///     contact(name = "Pet team", email = "pets@example.com", url = "https://example.com/pets"),
+///     license(name = "MIT", url = "https://opensource.org/licenses/MIT")
+/// ))]
+/// struct ApiDoc;
+/// ```
+///
 /// _**Create OpenAPI with reusable response.**_
 /// ```rust
 /// #[derive(utoipa::ToSchema)]
@@ -1608,8 +2451,48 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// struct ApiDoc;
 /// ```
 ///
+/// _**Define webhook delivering new pets to the API consumers.**_
+/// ```rust
+/// #[derive(utoipa::ToSchema)]
+/// struct Pet {
+///     name: String,
+/// }
+///
+/// /// New pet added to the store
+/// #[utoipa::path(
+///     post,
+///     path = "/new-pet",
+///     request_body = Pet,
+///     responses(
+///         (status = 200, description = "Webhook received successfully")
+///     )
+/// )]
+/// fn new_pet() {}
+///
+/// #[derive(utoipa::OpenApi)]
+/// #[openapi(webhooks(("newPet" = new_pet)), components(schemas(Pet)))]
+/// struct ApiDoc;
+/// ```
+///
+/// _**Nest pet api defined in its own module under `/api/v1/pets` path.**_
+/// ```rust
+/// mod pets {
+///     #[utoipa::path(get, path = "/{id}", responses((status = 200, description = "Pet found")))]
+///     pub fn get_pet() {}
+///
+///     #[derive(utoipa::OpenApi)]
+///     #[openapi(paths(get_pet))]
+///     pub struct PetApi;
+/// }
+///
+/// #[derive(utoipa::OpenApi)]
+/// #[openapi(nest((path = "/api/v1/pets", api = pets::PetApi)))]
+/// struct ApiDoc;
+/// ```
+///
 /// [openapi]: trait.OpenApi.html
 /// [openapi_struct]: openapi/struct.OpenApi.html
+/// [openapi_nest]: openapi/struct.OpenApi.html#method.nest
 /// [to_schema]: derive.ToSchema.html
 /// [path]: attr.path.html
 /// [modify]: trait.Modify.html
@@ -1636,7 +2519,7 @@ pub fn openapi(input: TokenStream) -> TokenStream {
         .into()
 }
 
-#[proc_macro_derive(IntoParams, attributes(param, into_params))]
+#[proc_macro_derive(IntoParams, attributes(param, into_params, validate))]
 /// Generate [path parameters][path_params] from struct's
 /// fields.
 ///
@@ -1702,25 +2585,43 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// The following attributes are available for use in the `#[param(...)]` on struct fields:
 ///
 /// * `style = ...` Defines how the parameter is serialized by [`ParameterStyle`][style]. Default values are based on _`parameter_in`_ attribute.
+///   If _`parameter_in`_ is defined in the container attribute the style must be allowed for the
+///   location, e.g. _`DeepObject`_ is only allowed for _`Query`_ parameters. Array fields of
+///   _`Query`_ parameters e.g. _`Vec<String>`_ default to exploded _`Form`_ style representing
+///   repeated parameters _`?tag=a&tag=b`_.
 ///
 /// * `explode` Defines whether new _`parameter=value`_ pair is created for each parameter within _`object`_ or _`array`_.
+///   Can optionally be defined with explicit `bool` value as _`explode = bool`_ e.g. _`explode = false`_ to
+///   represent array query parameter as comma separated values.
 ///
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
+///   If _`parameter_in`_ is defined in the container attribute reserved characters can only be
+///   allowed for _`Query`_ parameters.
 ///
 /// * `example = ...` Can be method reference or _`json!(...)`_. Given example
 ///   will override any example in underlying parameter type.
 ///
+/// * `examples(...)` Define multiple named examples for the parameter. This attribute is mutually
+///   exclusive to the _`example`_ attribute and if both are defined this will override the _`example`_.
+///   This has same syntax as _`examples(...)`_ in [Response Attributes][path_response_attributes]
+///   of the path macro.
+///
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases where the default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not [`ToSchema`][to_schema]s nor [`primitive` types][primitive].
-///   The value can be any Rust type what normally could be used to serialize to JSON, or either virtual type _`Object`_
-///   or _`Value`_, or an alias defined using `#[aliases(..)]`.
+///   The value can be any Rust type what normally could be used to serialize to JSON, or either virtual type _`Object`_,
+///   _`Value`_ or _`File`_, or an alias defined using `#[aliases(..)]`.
 ///   _`Object`_ will be rendered as generic OpenAPI object _(`type: object`)_.
 ///   _`Value`_ will be rendered as any OpenAPI value (i.e. no `type` restriction).
+///   _`File`_ will be rendered as binary string _(`type: string, format: binary`)_.
 ///
 /// * `inline` If set, the schema for this field's type needs to be a [`ToSchema`][to_schema], and
 ///   the schema definition will be inlined.
 ///
+/// * `flatten` Add the parameters of the field's type in place of the field. The type of the field
+///   must implement [`IntoParams`][into_params] e.g. pagination parameters shared by several
+///   parameter structs. Same as _`#[serde(flatten)]`_ without requiring _`serde`_.
+///
 /// * `default = ...` Can be method reference or _`json!(...)`_.
 ///
 /// * `format = ...` May either be variant of the [`KnownFormat`][known_format] enum, or otherwise
@@ -1734,7 +2635,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to named fields.
 ///    See configuration options at xml attributes of [`ToSchema`][to_schema_xml]
 ///
-/// * `nullable` Defines property is nullable (note this is different to non-required).
+/// * `nullable` Defines property is nullable (note this is different to non-required). Use
+///   _`nullable = false`_ to not mark _`Option`_ field as nullable.
 ///
 /// * `required = ...` Can be used to enforce required status for the parameter. [See
 ///    rules][derive@IntoParams#field-nullability-and-required-rules]
@@ -1758,11 +2660,21 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///
 /// * `pattern = ...` Can be used to define valid regular expression in _ECMA-262_ dialect the field value must match.
 ///
+/// * `enum_values = [...]` Can be used to restrict the field value to a fixed list of string or
+///   number literals e.g. _`enum_values = ["asc", "desc"]`_ or _`enum_values = [1, 2, 3]`_. Literal type
+///   must match the type of the field, string literals with `string` types and number literals with
+///   `number` types. With `array` fields such as _`Vec<String>`_ the values restrict the items.
+///
 /// * `max_items = ...` Can be used to define maximum items allowed for `array` fields. Value must
-///   be non-negative integer.
+///   be non-negative integer. Fixed size arrays _`[T; N]`_ default to `N`. Using it with other than
+///   `array` fields is a compile error.
 ///
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
-///   be non-negative integer.
+///   be non-negative integer. Fixed size arrays _`[T; N]`_ default to `N`. Using it with other than
+///   `array` fields is a compile error.
+///
+/// * `unique_items` Can be used to define that all items of `array` field must be unique. Can optionally
+///   be defined with explicit `bool` value as _`unique_items = bool`_. Set types are always unique.
 ///
 /// * `schema_with = ...` Use _`schema`_ created by provided function reference instead of the
 ///   default derived _`schema`_. The function must match to `fn() -> Into<RefOr<Schema>>`. It does
@@ -1778,19 +2690,36 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// Same rules for nullability and required status apply for _`IntoParams`_ field attributes as for
 /// _`ToSchema`_ field attributes. [See the rules][`derive@ToSchema#field-nullability-and-required-rules`].
 ///
+/// # Partial `#[validate(...)]` attributes support
+///
+/// With the _**`validator`**_ feature enabled the [validator](https://docs.rs/validator) crate
+/// field attributes are supported the same way as for _`ToSchema`_ fields.
+/// [See the supported validations][`derive@ToSchema#partial-validate-attributes-support`].
+///
+/// # Partial `#[serde_as(...)]` attributes support
+///
+/// With the _**`serde_with`**_ feature enabled the [serde_with](https://docs.rs/serde_with) crate
+/// field attributes are supported the same way as for _`ToSchema`_ fields.
+/// [See the supported adaptors][`derive@ToSchema#partial-serde_as-attributes-support`].
+///
 /// # Partial `#[serde(...)]` attributes support
 ///
 /// IntoParams derive has partial support for [serde attributes]. These supported attributes will reflect to the
 /// generated OpenAPI doc. The following attributes are currently supported:
 ///
-/// * `rename_all = "..."` Supported at the container level.
-/// * `rename = "..."` Supported **only** at the field level.
+/// * `rename_all = "..."` Supported at the container level. The `rename_all(serialize = "...")` form is
+///   supported as well and only the `serialize` value is used.
+/// * `rename = "..."` Supported **only** at the field level. The `rename(serialize = "...")` form is
+///   supported as well and only the `serialize` value is used.
 /// * `default` Supported at the container level and field level according to [serde attributes].
 /// * `skip_serializing_if = "..."` Supported  **only** at the field level.
 /// * `with = ...` Supported **only** at field level.
 /// * `skip_serializing = "..."` Supported  **only** at the field or variant level.
 /// * `skip_deserializing = "..."` Supported  **only** at the field or variant level.
 /// * `skip = "..."` Supported  **only** at the field level.
+/// * `flatten` Supported **only** at the field level. Flattened field type must implement
+///   [`IntoParams`][into_params] and its parameters are added in place of the field. Flattened map
+///   field is still documented as single parameter.
 ///
 /// Other _`serde`_ attributes will impact the serialization but will not be reflected on the generated OpenAPI doc.
 ///
@@ -1975,6 +2904,7 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// [xml]: openapi/xml/struct.Xml.html
 /// [into_params]: trait.IntoParams.html
 /// [path_params]: attr.path.html#params-attributes
+/// [path_response_attributes]: attr.path.html#response-attributes
 /// [struct]: https://doc.rust-lang.org/std/keyword.struct.html
 /// [style]: openapi/path/enum.ParameterStyle.html
 /// [in_enum]: openapi/path/enum.ParameterIn.html
@@ -2000,7 +2930,7 @@ pub fn into_params(input: TokenStream) -> TokenStream {
     into_params.to_token_stream().into()
 }
 
-#[proc_macro_derive(ToResponse, attributes(response, content, to_schema))]
+#[proc_macro_derive(ToResponse, attributes(response, content, to_schema, schema))]
 /// Generate reusable OpenAPI response that can be used
 /// in [`utoipa::path`][path] or in [`OpenApi`][openapi].
 ///
@@ -2023,7 +2953,8 @@ pub fn into_params(input: TokenStream) -> TokenStream {
 ///
 /// 1. By decorating `struct` or `enum` with [`derive@ToResponse`] derive macro. This will create a
 ///    response with inlined schema resolved from the fields of the `struct` or `variants` of the
-///    enum.
+///    enum. Fields of the `struct` can be configured with the same _`#[schema(...)]`_ field
+///    attributes as in [`derive@ToSchema`].
 ///
 ///    ```rust
 ///     # use utoipa::ToResponse;
@@ -2098,21 +3029,32 @@ pub fn into_params(input: TokenStream) -> TokenStream {
 /// * `content_type = "..." | content_type = [...]` Can be used to override the default behavior of auto resolving the content type
 ///   from the `body` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
-///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and
-///   _`application/json`_ for struct and complex enum types.
+///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and virtual
+///   _`File`_ type and _`application/json`_ for struct and complex enum types.
 ///   Content type can also be slice of **content_type** values if the endpoint support returning multiple
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
 ///  _`json`_ and _`xml`_ formats. **The order** of the content types define the default example show first in
 ///  the Swagger UI. Swagger UI will use the first _`content_type`_ value as a default example.
 ///
-/// * `headers(...)` Slice of response headers that are returned back to a caller.
+/// * `headers(...)` Slice of response headers that are returned back to a caller. Headers are
+///   either header tuples or types implementing [`ToHeader`][to_header].
 ///
 /// * `example = ...` Can be _`json!(...)`_. _`json!(...)`_ should be something that
 ///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_.
 ///
+/// * `deprecated = "..."` Can be used to add a deprecation note e.g. pointing to the replacement
+///   for the response. OpenAPI does not support deprecating responses so the note will be appended
+///   to the response _`description`_ prefixed with _`Deprecated: `_.
+///
+/// * `external_docs(...)` Can be used to link an external resource such as an error code catalog
+///   to the response. E.g. _`external_docs(url = "https://...", description = "...")`_. The `url`
+///   is mandatory. OpenAPI does not support external docs of responses so the link will be
+///   appended to the response _`description`_ as markdown link.
+///
 /// * `examples(...)` Define multiple examples for single response. This attribute is mutually
 ///   exclusive to the _`example`_ attribute and if both are defined this will override the _`example`_.
-///     * `name = ...` This is first attribute and value must be literal string.
+///     * `name = ...` This is first attribute and value must be literal string. Names must be
+///       unique within the _`examples(...)`_.
 ///     * `summary = ...` Short description of example. Value must be literal string.
 ///     * `description = ...` Long description of example. Attribute supports markdown for rich text
 ///       representation. Value must be literal string.
@@ -2194,6 +3136,7 @@ pub fn into_params(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// [to_response]: trait.ToResponse.html
+/// [to_header]: trait.ToHeader.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [path]: attr.path.html
 /// [openapi]: derive.OpenApi.html
@@ -2258,6 +3201,8 @@ pub fn to_response(input: TokenStream) -> TokenStream {
 /// * `status = ...` Must be provided. Is either a valid http status code integer. E.g. _`200`_ or a
 ///   string value representing a range such as _`"4XX"`_ or `"default"` or a valid _`http::status::StatusCode`_.
 ///   _`StatusCode`_ can either be use path to the status code or _status code_ constant directly.
+///   Path to a value of user type implementing [`ToResponseStatus`][to_response_status] can be used
+///   as well.
 ///
 /// * `description = "..."` Define description for the response as str. This can be used to
 ///   override the default description resolved from doc comments if present.
@@ -2265,21 +3210,32 @@ pub fn to_response(input: TokenStream) -> TokenStream {
 /// * `content_type = "..." | content_type = [...]` Can be used to override the default behavior of auto resolving the content type
 ///   from the `body` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
-///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and
-///   _`application/json`_ for struct and complex enum types.
+///   [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and virtual
+///   _`File`_ type and _`application/json`_ for struct and complex enum types.
 ///   Content type can also be slice of **content_type** values if the endpoint support returning multiple
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
 ///  _`json`_ and _`xml`_ formats. **The order** of the content types define the default example show first in
 ///  the Swagger UI. Swagger UI will use the first _`content_type`_ value as a default example.
 ///
-/// * `headers(...)` Slice of response headers that are returned back to a caller.
+/// * `headers(...)` Slice of response headers that are returned back to a caller. Headers are
+///   either header tuples or types implementing [`ToHeader`][to_header].
 ///
 /// * `example = ...` Can be _`json!(...)`_. _`json!(...)`_ should be something that
 ///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_.
 ///
+/// * `deprecated = "..."` Can be used to add a deprecation note e.g. pointing to the replacement
+///   for the response. OpenAPI does not support deprecating responses so the note will be appended
+///   to the response _`description`_ prefixed with _`Deprecated: `_.
+///
+/// * `external_docs(...)` Can be used to link an external resource such as an error code catalog
+///   to the response. E.g. _`external_docs(url = "https://...", description = "...")`_. The `url`
+///   is mandatory. OpenAPI does not support external docs of responses so the link will be
+///   appended to the response _`description`_ as markdown link.
+///
 /// * `examples(...)` Define multiple examples for single response. This attribute is mutually
 ///   exclusive to the _`example`_ attribute and if both are defined this will override the _`example`_.
-///     * `name = ...` This is first attribute and value must be literal string.
+///     * `name = ...` This is first attribute and value must be literal string. Names must be
+///       unique within the _`examples(...)`_.
 ///     * `summary = ...` Short description of example. Value must be literal string.
 ///     * `description = ...` Long description of example. Attribute supports markdown for rich text
 ///       representation. Value must be literal string.
@@ -2383,7 +3339,9 @@ pub fn to_response(input: TokenStream) -> TokenStream {
 /// [into_responses]: trait.IntoResponses.html
 /// [to_schema]: trait.ToSchema.html
 /// [to_response]: trait.ToResponse.html
+/// [to_header]: trait.ToHeader.html
 /// [path_into_responses]: attr.path.html#responses-from-intoresponses
+/// [to_response_status]: trait.ToResponseStatus.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [path]: macro@crate::path
 pub fn into_responses(input: TokenStream) -> TokenStream {
@@ -2405,12 +3363,97 @@ pub fn into_responses(input: TokenStream) -> TokenStream {
     ToTokens::into_token_stream(into_responses).into()
 }
 
+#[proc_macro_derive(ToHeader, attributes(header))]
+/// Generate a set of response headers that can be attached to responses of
+/// [`utoipa::path`][path], [`derive@ToResponse`] and [`derive@IntoResponses`] at once.
+///
+/// This is `#[derive]` implementation for [`ToHeader`][to_header] trait. Derive can only be used
+/// with structs having named fields. Each field of the struct becomes a response header.
+///
+/// * Header name is the name of the field. It can be changed with _`rename = "..."`_ field
+///   attribute or with _`rename_all = "..."`_ container attribute.
+/// * Schema of the header is resolved from the type of the field. It can be overridden with
+///   _`value_type = ...`_ field attribute.
+/// * Header is required unless type of the field is _`Option<T>`_.
+/// * Doc comment of the field is used as a description of the header.
+/// * Field with _`#[deprecated]`_ attribute marks the header deprecated.
+///
+/// # ToHeader `#[header(...)]` container attributes
+///
+/// * `rename_all = "..."` Change case of all header names. Supports the same rules as serde
+///   _`rename_all`_ e.g. _`"kebab-case"`_.
+///
+/// # ToHeader `#[header(...)]` field attributes
+///
+/// * `rename = "..."` Name of the header.
+///
+/// * `value_type = ...` Override the type used to resolve the schema of the header.
+///
+/// * `example = ...` Can be _`json!(...)`_ or a literal. Example of the header's potential value.
+///
+/// # Examples
+///
+/// _**Document rate limit headers on multiple responses.**_
+/// ```rust
+/// #[derive(utoipa::ToHeader)]
+/// #[header(rename_all = "kebab-case")]
+/// struct RateLimitHeaders {
+///     /// Allowed requests per hour.
+///     #[header(rename = "x-rate-limit-limit")]
+///     limit: u32,
+///     /// Remaining requests in current window.
+///     #[header(rename = "x-rate-limit-remaining", example = 59)]
+///     remaining: u32,
+///     /// Time the window resets as unix timestamp.
+///     #[header(rename = "x-rate-limit-reset")]
+///     reset: u64,
+///     /// Optional retry hint.
+///     retry_after: Option<u64>,
+/// }
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/pets",
+///     responses(
+///         (status = 200, description = "List pets", headers(RateLimitHeaders)),
+///         (status = 429, description = "Too many requests", headers(
+///             RateLimitHeaders,
+///             ("x-request-id" = String)
+///         )),
+///     )
+/// )]
+/// fn list_pets() {}
+/// ```
+///
+/// [to_header]: trait.ToHeader.html
+/// [path]: macro@crate::path
+pub fn to_header(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        attrs,
+        ident,
+        generics,
+        data,
+        ..
+    } = syn::parse_macro_input!(input);
+
+    let to_header = ToHeader {
+        attributes: attrs,
+        ident,
+        generics,
+        data,
+    };
+
+    ToTokens::into_token_stream(to_header).into()
+}
+
 /// Create OpenAPI Schema from arbitrary type.
 ///
 /// This macro provides a quick way to render arbitrary types as OpenAPI Schema Objects. It
-/// supports two call formats.
+/// supports three call formats.
 /// 1. With type only
 /// 2. With _`#[inline]`_ attribute to inline the referenced schemas.
+/// 3. With ad-hoc object _`{ field: Type, ... }`_ for one-off objects which do not deserve
+///    a named type.
 ///
 /// By default the macro will create references `($ref)` for non primitive types like _`Pet`_.
 /// However when used with _`#[inline]`_ the non [`primitive`][primitive] type schemas will
@@ -2455,15 +3498,43 @@ pub fn into_responses(input: TokenStream) -> TokenStream {
 /// # assert_json_diff::assert_json_eq!(serde_json::to_value(&schema).unwrap(), serde_json::to_value(&generated).unwrap());
 /// ```
 ///
+/// _**Create ad-hoc object schema.**_
+///
+/// Fields are separated with comma and each field is either _`name: Type`_ or
+/// _`name: { ... }`_ for a nested object. Fields are required unless the type is an _`Option`_.
+/// Raw identifiers such as _`r#type`_ can be used for field names clashing with Rust keywords.
+/// ```rust
+/// # use utoipa::openapi::schema::{Object, ObjectBuilder, SchemaFormat, KnownFormat, SchemaType};
+/// let schema = utoipa::schema!({ id: i64, name: Option<String>, owner: { name: String } });
+/// // will output
+/// let generated = ObjectBuilder::new()
+///     .property("id", ObjectBuilder::new()
+///         .schema_type(SchemaType::Integer)
+///         .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64))))
+///     .required("id")
+///     .property("name", ObjectBuilder::new()
+///         .schema_type(SchemaType::String)
+///         .nullable(true))
+///     .property("owner", ObjectBuilder::new()
+///         .property("name", Object::with_type(SchemaType::String))
+///         .required("name"))
+///     .required("owner");
+/// # assert_json_diff::assert_json_eq!(serde_json::to_value(&schema.build()).unwrap(), serde_json::to_value(&generated.build()).unwrap());
+/// ```
+///
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 #[proc_macro]
 pub fn schema(input: TokenStream) -> TokenStream {
-    struct Schema {
-        inline: bool,
-        ty: syn::Type,
+    enum Schema {
+        Type { inline: bool, ty: syn::Type },
+        Object(InlineObject),
     }
     impl Parse for Schema {
         fn parse(input: ParseStream) -> syn::Result<Self> {
+            if input.peek(Brace) {
+                return Ok(Self::Object(input.parse()?));
+            }
+
             let inline = if input.peek(Token![#]) && input.peek2(Bracket) {
                 input.parse::<Token![#]>()?;
 
@@ -2477,18 +3548,23 @@ pub fn schema(input: TokenStream) -> TokenStream {
 
             let ty = input.parse()?;
 
-            Ok(Self { inline, ty })
+            Ok(Self::Type { inline, ty })
         }
     }
 
-    let schema = syn::parse_macro_input!(input as Schema);
-    let type_tree = match TypeTree::from_type(&schema.ty) {
+    let (inline, ty) = match syn::parse_macro_input!(input as Schema) {
+        Schema::Type { inline, ty } => (inline, ty),
+        Schema::Object(inline_object) => {
+            return ToTokensDiagnostics::to_token_stream(&inline_object).into()
+        }
+    };
+    let type_tree = match TypeTree::from_type(&ty) {
         Ok(type_tree) => type_tree,
         Err(diagnostics) => return diagnostics.into_token_stream().into(),
     };
 
     let schema = ComponentSchema::new(ComponentSchemaProps {
-        features: Some(vec![Feature::Inline(schema.inline.into())]),
+        features: Some(vec![Feature::Inline(inline.into())]),
         type_tree: &type_tree,
         deprecated: None,
         description: None,
@@ -2584,7 +3660,7 @@ impl ToTokens for Deprecated {
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum Required {
     True,
@@ -2617,7 +3693,7 @@ impl ToTokens for Required {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct ExternalDocs {
     url: String,
@@ -2629,6 +3705,7 @@ impl Parse for ExternalDocs {
         const EXPECTED_ATTRIBUTE: &str = "unexpected attribute, expected any of: url, description";
 
         let mut external_docs = ExternalDocs::default();
+        let span = input.span();
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
@@ -2651,6 +3728,13 @@ impl Parse for ExternalDocs {
             }
         }
 
+        if external_docs.url.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "missing required attribute: url, external docs must have non empty url",
+            ));
+        }
+
         Ok(external_docs)
     }
 }
@@ -2673,6 +3757,55 @@ impl ToTokens for ExternalDocs {
     }
 }
 
+/// Specification extensions parsed from `("x-name" = value), ...` where the value can be any
+/// literal, `json!(...)` or method reference.
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Extensions(Vec<Extension>);
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Extension {
+    name: LitStr,
+    value: AnyValue,
+}
+
+impl Parse for Extension {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<LitStr>()?;
+        if !name.value().starts_with("x-") {
+            return Err(syn::Error::new(
+                name.span(),
+                "invalid extension name, extension names must start with `x-`",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+
+        Ok(Self {
+            name,
+            value: AnyValue::parse_any(input)?,
+        })
+    }
+}
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        parse_utils::parse_groups(input).map(Self)
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let extensions = self.0.iter().map(|Extension { name, value }| {
+            quote! { (String::from(#name), #value) }
+        });
+
+        tokens.extend(quote! {
+            std::collections::HashMap::<String, serde_json::Value>::from([#(#extensions),*])
+        })
+    }
+}
+
 /// Represents OpenAPI Any value used in example and default fields.
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -2683,6 +3816,9 @@ enum AnyValue {
         struct_ident: Ident,
         field_ident: Member,
     },
+    /// Function producing the default value e.g. `<i32 as Default>::default` or path given with
+    /// serde's `default = "path"`.
+    DefaultFn(TokenStream2),
 }
 
 impl AnyValue {
@@ -2744,6 +3880,10 @@ impl AnyValue {
             field_ident,
         }
     }
+
+    fn new_default_fn(default_fn: TokenStream2) -> Self {
+        Self::DefaultFn(default_fn)
+    }
 }
 
 impl ToTokens for AnyValue {
@@ -2759,6 +3899,9 @@ impl ToTokens for AnyValue {
             } => tokens.extend(quote! {
                 serde_json::to_value(#struct_ident::default().#field_ident).unwrap()
             }),
+            Self::DefaultFn(default_fn) => tokens.extend(quote! {
+                serde_json::to_value(#default_fn()).unwrap()
+            }),
         }
     }
 }
@@ -2852,6 +3995,7 @@ mod parse_utils {
         Error, Expr, LitBool, LitStr, Token,
     };
 
+    #[derive(Clone)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub enum Value {
         LitStr(LitStr),
@@ -1,20 +1,23 @@
 use proc_macro2::Ident;
 use syn::{
+    bracketed,
+    ext::IdentExt,
     parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
     token::{And, Comma},
-    Attribute, Error, ExprPath, LitStr, Token, TypePath,
+    Attribute, Error, ExprPath, LitStr, Path, Token, TypePath,
 };
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 
+use crate::component::schema::format_path_ref;
 use crate::parse_utils::Str;
 use crate::{
     parse_utils, path::PATH_STRUCT_PREFIX, security_requirement::SecurityRequirementsAttr, Array,
-    ExternalDocs,
+    Extensions, ExternalDocs,
 };
 
 use self::info::Info;
@@ -25,13 +28,18 @@ mod info;
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct OpenApiAttr<'o> {
     info: Option<Info<'o>>,
-    paths: Punctuated<ExprPath, Comma>,
+    paths: Punctuated<PathHandler, Comma>,
+    webhooks: Punctuated<Webhook, Comma>,
     components: Components,
     modifiers: Punctuated<Modifier, Comma>,
     security: Option<Array<'static, SecurityRequirementsAttr>>,
     tags: Option<Array<'static, Tag>>,
     external_docs: Option<ExternalDocs>,
     servers: Punctuated<Server, Comma>,
+    nested: Punctuated<NestOpenApi, Comma>,
+    extensions: Option<Extensions>,
+    infer_tags: Option<bool>,
+    deny_schema_name_collisions: Option<bool>,
 }
 
 impl<'o> OpenApiAttr<'o> {
@@ -42,12 +50,18 @@ impl<'o> OpenApiAttr<'o> {
         if !other.paths.is_empty() {
             self.paths = other.paths;
         }
+        if !other.webhooks.is_empty() {
+            self.webhooks = other.webhooks;
+        }
         if !other.components.schemas.is_empty() {
             self.components.schemas = other.components.schemas;
         }
         if !other.components.responses.is_empty() {
             self.components.responses = other.components.responses;
         }
+        if !other.components.implementors.is_empty() {
+            self.components.implementors = other.components.implementors;
+        }
         if other.security.is_some() {
             self.security = other.security;
         }
@@ -60,6 +74,18 @@ impl<'o> OpenApiAttr<'o> {
         if !other.servers.is_empty() {
             self.servers = other.servers;
         }
+        if !other.nested.is_empty() {
+            self.nested = other.nested;
+        }
+        if other.extensions.is_some() {
+            self.extensions = other.extensions;
+        }
+        if other.infer_tags.is_some() {
+            self.infer_tags = other.infer_tags;
+        }
+        if other.deny_schema_name_collisions.is_some() {
+            self.deny_schema_name_collisions = other.deny_schema_name_collisions;
+        }
 
         self
     }
@@ -77,7 +103,7 @@ pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Result<Option<OpenApiAttr>, E
 impl Parse for OpenApiAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers";
+            "unexpected attribute, expected any of: handlers, webhooks, components, modifiers, security, tags, external_docs, servers, nest, extensions, infer_tags, deny_schema_name_collisions";
         let mut openapi = OpenApiAttr::default();
 
         while !input.is_empty() {
@@ -95,6 +121,16 @@ impl Parse for OpenApiAttr<'_> {
                 "paths" => {
                     openapi.paths = parse_utils::parse_punctuated_within_parenthesis(input)?;
                 }
+                "webhooks" => {
+                    openapi.webhooks = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                }
+                "infer_tags" => {
+                    openapi.infer_tags = Some(parse_utils::parse_bool_or_true(input)?);
+                }
+                "deny_schema_name_collisions" => {
+                    openapi.deny_schema_name_collisions =
+                        Some(parse_utils::parse_bool_or_true(input)?);
+                }
                 "components" => {
                     openapi.components = input.parse()?;
                 }
@@ -109,7 +145,21 @@ impl Parse for OpenApiAttr<'_> {
                 "tags" => {
                     let tags;
                     parenthesized!(tags in input);
-                    openapi.tags = Some(parse_utils::parse_groups(&tags)?);
+                    let tags: Array<Tag> = parse_utils::parse_groups(&tags)?;
+                    if let Some(duplicate) = tags.iter().enumerate().find_map(|(index, tag)| {
+                        tags.iter()
+                            .skip(index + 1)
+                            .find(|other| other.name == tag.name)
+                    }) {
+                        return Err(Error::new(
+                            ident.span(),
+                            format!(
+                                "duplicate tag: {}, tag names must be unique",
+                                duplicate.name
+                            ),
+                        ));
+                    }
+                    openapi.tags = Some(tags);
                 }
                 "external_docs" => {
                     let external_docs;
@@ -119,6 +169,14 @@ impl Parse for OpenApiAttr<'_> {
                 "servers" => {
                     openapi.servers = parse_utils::parse_punctuated_within_parenthesis(input)?;
                 }
+                "nest" => {
+                    openapi.nested = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    openapi.extensions = Some(extensions.parse()?);
+                }
                 _ => {
                     return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE));
                 }
@@ -151,18 +209,85 @@ impl Parse for Response {
     }
 }
 
+/// Concrete implementors of a trait used as _`dyn Trait`_ body type e.g.
+/// _`dyn ErrorResponse = [NotFound, Conflict]`_.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Implementors {
+    trait_path: Path,
+    types: Punctuated<TypePath, Comma>,
+}
+
+impl Parse for Implementors {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![dyn]>()?;
+        let trait_path = input.parse::<Path>()?;
+        input.parse::<Token![=]>()?;
+
+        let types;
+        bracketed!(types in input);
+
+        Ok(Self {
+            trait_path,
+            types: Punctuated::parse_terminated(&types)?,
+        })
+    }
+}
+
+impl Implementors {
+    /// Get tokens asserting that every listed type implements the trait.
+    fn assert_implements_tokens(&self) -> TokenStream {
+        let trait_path = &self.trait_path;
+        let assertions = self.types.iter().map(|ty| {
+            quote_spanned! {ty.span()=>
+                assert_implements::<#ty>();
+            }
+        });
+
+        quote! {
+            {
+                fn assert_implements<T: ?Sized + #trait_path>() {}
+                #( #assertions )*
+            }
+        }
+    }
+}
+
+impl ToTokens for Implementors {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // referenced with the same name as `dyn Trait` body types are referenced in paths
+        let name = format_path_ref(&self.trait_path);
+        let items = self.types.iter().map(|ty| {
+            quote_spanned! {ty.span()=>
+                .item(utoipa::openapi::Ref::from_schema_name(
+                    <#ty as utoipa::ToSchema>::schema_name()
+                ))
+            }
+        });
+        let schemas = self.types.iter().map(|ty| {
+            quote_spanned! {ty.span()=>
+                .schema_from::<#ty>()
+            }
+        });
+
+        tokens.extend(quote! {
+            .schema(#name, utoipa::openapi::schema::OneOfBuilder::new() #( #items )*)
+            #( #schemas )*
+        })
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Modifier {
     and: And,
-    ident: Ident,
+    path: ExprPath,
 }
 
 impl ToTokens for Modifier {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let and = &self.and;
-        let ident = &self.ident;
+        let path = &self.path;
         tokens.extend(quote! {
-            #and #ident
+            #and #path
         })
     }
 }
@@ -171,7 +296,7 @@ impl Parse for Modifier {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
             and: input.parse()?,
-            ident: input.parse()?,
+            path: input.parse()?,
         })
     }
 }
@@ -190,6 +315,7 @@ impl Parse for Tag {
             "unexpected token, expected any of: name, description, external_docs";
 
         let mut tag = Tag::default();
+        let span = input.span();
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
@@ -216,6 +342,13 @@ impl Parse for Tag {
             }
         }
 
+        if tag.name.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "missing required attribute: name, tag must have a name e.g. `name = \"items\"`",
+            ));
+        }
+
         Ok(tag)
     }
 }
@@ -246,7 +379,7 @@ impl ToTokens for Tag {
 // (url = "http:://url", description = "description", variables(...))
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-struct Server {
+pub(crate) struct Server {
     url: String,
     description: Option<String>,
     variables: Punctuated<ServerVariable, Comma>,
@@ -254,16 +387,20 @@ struct Server {
 
 impl Parse for Server {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
         let server_stream;
         parenthesized!(server_stream in input);
         let mut server = Server::default();
+        let mut url_span = span;
         while !server_stream.is_empty() {
             let ident = server_stream.parse::<Ident>()?;
             let attribute_name = &*ident.to_string();
 
             match attribute_name {
                 "url" => {
-                    server.url = parse_utils::parse_next(&server_stream, || server_stream.parse::<LitStr>())?.value()
+                    let url = parse_utils::parse_next(&server_stream, || server_stream.parse::<LitStr>())?;
+                    url_span = url.span();
+                    server.url = url.value()
                 }
                 "description" => {
                     server.description =
@@ -282,10 +419,40 @@ impl Parse for Server {
             }
         }
 
+        if server.url.is_empty() {
+            return Err(Error::new(
+                span,
+                "missing required attribute: url, server must have an url e.g. `url = \"/api\"`",
+            ));
+        }
+
+        if let Some(undefined) = server.url_variables().find(|url_variable| {
+            !server
+                .variables
+                .iter()
+                .any(|variable| &variable.name == url_variable)
+        }) {
+            return Err(Error::new(
+                url_span,
+                format!("server url variable: {undefined} must be defined in `variables(...)`"),
+            ));
+        }
+
         Ok(server)
     }
 }
 
+impl Server {
+    /// Get names of the variables within url template e.g. `region` of
+    /// `https://{region}.api.example.com`.
+    fn url_variables(&self) -> impl Iterator<Item = &str> {
+        self.url
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(variable, _)| variable))
+    }
+}
+
 impl ToTokens for Server {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let url = &self.url;
@@ -345,10 +512,12 @@ impl Parse for ServerVariable {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let variable_stream;
         parenthesized!(variable_stream in input);
+        let name = variable_stream.parse::<LitStr>()?;
         let mut server_variable = ServerVariable {
-            name: variable_stream.parse::<LitStr>()?.value(),
+            name: name.value(),
             ..ServerVariable::default()
         };
+        let mut default_span = name.span();
 
         variable_stream.parse::<Token![=]>()?;
         let content;
@@ -360,8 +529,9 @@ impl Parse for ServerVariable {
 
             match attribute_name {
                 "default" => {
-                    server_variable.default =
-                        parse_utils::parse_next(&content, || content.parse::<LitStr>())?.value()
+                    let default = parse_utils::parse_next(&content, || content.parse::<LitStr>())?;
+                    default_span = default.span();
+                    server_variable.default = default.value()
                 }
                 "description" => {
                     server_variable.description =
@@ -381,6 +551,31 @@ impl Parse for ServerVariable {
             }
         }
 
+        if server_variable.default.is_empty() {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "missing required attribute: default, server variable: {} must have a default value e.g. `default = \"...\"`",
+                    server_variable.name
+                ),
+            ));
+        }
+
+        if let Some(enum_values) = &server_variable.enum_values {
+            if !enum_values
+                .iter()
+                .any(|value| value.value() == server_variable.default)
+            {
+                return Err(Error::new(
+                    default_span,
+                    format!(
+                        "server variable: {} default value: {} must be one of the `enum_values`",
+                        server_variable.name, server_variable.default
+                    ),
+                ));
+            }
+        }
+
         Ok(server_variable)
     }
 }
@@ -404,7 +599,19 @@ impl ToTokens for OpenApi<'_> {
         let modifiers = &attributes.modifiers;
         let modifiers_len = modifiers.len();
 
-        let path_items = impl_paths(&attributes.paths);
+        let infer_tags = attributes.infer_tags.unwrap_or(true);
+        let path_items = impl_paths(&attributes.paths, infer_tags);
+        let webhooks = impl_webhooks(&attributes.webhooks, infer_tags);
+        let schemas = impl_schema_references(attributes);
+        // schemas of different types sharing the same name would overwrite each other
+        let schema_name_assertion = (attributes.deny_schema_name_collisions == Some(true)
+            && attributes.components.schemas.len() > 1)
+            .then(|| {
+                let schema_names = schema_names(&attributes.components);
+                quote! {
+                    utoipa::__dev::assert_unique_schema_names(&[#( #schema_names ),*]);
+                }
+            });
 
         let securities = attributes.security.as_ref().map(|securities| {
             quote! {
@@ -421,6 +628,11 @@ impl ToTokens for OpenApi<'_> {
                 .external_docs(Some(#external_docs))
             }
         });
+        let extensions = attributes.extensions.as_ref().map(|extensions| {
+            quote! {
+                .extensions(Some(#extensions))
+            }
+        });
         let servers = if !attributes.servers.is_empty() {
             let servers = attributes.servers.iter().collect::<Array<&Server>>();
             Some(quote! { .servers(Some(#servers)) })
@@ -428,19 +640,37 @@ impl ToTokens for OpenApi<'_> {
             None
         };
 
+        let nested = attributes.nested.iter().map(|nest| {
+            let NestOpenApi { path, api, tags } = nest;
+            let api = quote! { <#api as utoipa::OpenApi>::openapi() };
+            if let Some(tags) = tags {
+                let tags = tags.iter();
+                quote! { .nest(#path, utoipa::__dev::retag_operations(#api, &[#(#tags),*])) }
+            } else {
+                quote! { .nest(#path, #api) }
+            }
+        });
+
         tokens.extend(quote! {
-            impl utoipa::OpenApi for #ident {
-                fn openapi() -> utoipa::openapi::OpenApi {
+            impl #ident {
+                #[doc(hidden)]
+                fn __openapi_for_version(api_version: Option<&str>) -> utoipa::openapi::OpenApi {
                     use utoipa::{ToSchema, Path};
+                    #schema_name_assertion
                     let mut openapi = utoipa::openapi::OpenApiBuilder::new()
                         .info(#info)
                         .paths(#path_items)
+                        #webhooks
                         #components
                         #securities
                         #tags
                         #servers
                         #external_docs
-                        .build();
+                        #extensions
+                        .build()
+                        #(#nested)*;
+
+                    #schemas
 
                     let _mods: [&dyn utoipa::Modify; #modifiers_len] = [#modifiers];
                     _mods.iter().for_each(|modifier| modifier.modify(&mut openapi));
@@ -448,6 +678,16 @@ impl ToTokens for OpenApi<'_> {
                     openapi
                 }
             }
+
+            impl utoipa::OpenApi for #ident {
+                fn openapi() -> utoipa::openapi::OpenApi {
+                    Self::__openapi_for_version(None)
+                }
+
+                fn openapi_for_version(api_version: &str) -> utoipa::openapi::OpenApi {
+                    Self::__openapi_for_version(Some(api_version))
+                }
+            }
         });
     }
 }
@@ -457,6 +697,7 @@ impl ToTokens for OpenApi<'_> {
 struct Components {
     schemas: Vec<Schema>,
     responses: Vec<Response>,
+    implementors: Vec<Implementors>,
 }
 
 impl Parse for Components {
@@ -464,10 +705,11 @@ impl Parse for Components {
         let content;
         parenthesized!(content in input);
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute. expected one of: schemas, responses";
+            "unexpected attribute. expected one of: schemas, responses, implementors";
 
         let mut schemas: Vec<Schema> = Vec::new();
         let mut responses: Vec<Response> = Vec::new();
+        let mut implementors: Vec<Implementors> = Vec::new();
 
         while !content.is_empty() {
             let ident = content.parse::<Ident>().map_err(|error| {
@@ -486,6 +728,11 @@ impl Parse for Components {
                         .into_iter()
                         .collect(),
                 ),
+                "implementors" => implementors.append(
+                    &mut parse_utils::parse_punctuated_within_parenthesis(&content)?
+                        .into_iter()
+                        .collect(),
+                ),
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
 
@@ -494,13 +741,17 @@ impl Parse for Components {
             }
         }
 
-        Ok(Self { schemas, responses })
+        Ok(Self {
+            schemas,
+            responses,
+            implementors,
+        })
     }
 }
 
 impl ToTokens for Components {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if self.schemas.is_empty() && self.responses.is_empty() {
+        if self.schemas.is_empty() && self.responses.is_empty() && self.implementors.is_empty() {
             return;
         }
 
@@ -517,7 +768,7 @@ impl ToTokens for Components {
             },
         );
 
-        let builder_tokens =
+        let mut builder_tokens =
             self.responses
                 .iter()
                 .fold(builder_tokens, |mut builder_tokens, responses| {
@@ -528,45 +779,422 @@ impl ToTokens for Components {
                     });
                     builder_tokens
                 });
+        for implementors in &self.implementors {
+            implementors.to_tokens(&mut builder_tokens);
+        }
+        let implementor_assertions = self
+            .implementors
+            .iter()
+            .map(Implementors::assert_implements_tokens);
+
+        if !self.implementors.is_empty() {
+            tokens.extend(quote! {
+                {
+                    #( #implementor_assertions )*
+                    #builder_tokens.build()
+                }
+            });
+        } else {
+            tokens.extend(quote! { #builder_tokens.build() });
+        }
+    }
+}
 
-        tokens.extend(quote! { #builder_tokens.build() });
+/// Build paths of the listed handlers belonging to the _`api_version`_ in scope of the
+/// generated code. Every handler is listed when _`api_version`_ is `None`.
+fn impl_paths(handler_paths: &Punctuated<PathHandler, Comma>, infer_tags: bool) -> TokenStream {
+    let paths = handler_paths.iter().map(|PathHandler { cfg, handler }| {
+        let (usage, tag) = path_struct_usage(handler);
+        let tag = default_tag_tokens(tag, infer_tags);
+        let cfg = cfg.as_ref().map(|predicate| quote! { #[cfg(#predicate)] });
+
+        quote! {
+            #cfg
+            {
+                let path = utoipa::__dev::path_fns(#usage);
+                if path.is_included(api_version) {
+                    paths = paths.path((path.path)(), (path.path_item)(#tag));
+                }
+            }
+        }
+    });
+
+    quote! {
+        {
+            let mut paths = utoipa::openapi::path::PathsBuilder::new();
+            #( #paths )*
+            paths
+        }
     }
 }
 
-fn impl_paths(handler_paths: &Punctuated<ExprPath, Comma>) -> TokenStream {
-    handler_paths.iter().fold(
-        quote! { utoipa::openapi::path::PathsBuilder::new() },
-        |mut paths, handler| {
-            let segments = handler.path.segments.iter().collect::<Vec<_>>();
-            let handler_fn_name = &*segments.last().unwrap().ident.to_string();
+/// Collect schemas referenced by the paths, webhooks and component schemas to the
+/// components of the [`OpenApi`]. Explicitly declared schemas take precedence over the collected
+/// ones. With _`deny_schema_name_collisions`_ different schemas collected with the same name
+/// panic. Schemas are only collected with `auto_collect_schemas` feature.
+fn impl_schema_references(attributes: &OpenApiAttr) -> TokenStream {
+    if !cfg!(feature = "auto_collect_schemas") {
+        return TokenStream::new();
+    }
 
-            let tag = &*segments
+    let paths = attributes
+        .paths
+        .iter()
+        .map(|PathHandler { cfg, handler }| (cfg.as_ref(), handler))
+        .chain(
+            attributes
+                .webhooks
                 .iter()
-                .take(segments.len() - 1)
-                .map(|part| part.ident.to_string())
-                .collect::<Vec<_>>()
-                .join("::");
-
-            let handler_ident = format_ident!("{}{}", PATH_STRUCT_PREFIX, handler_fn_name);
-            let handler_ident_name = &*handler_ident.to_string();
-
-            let usage = syn::parse_str::<ExprPath>(
-                &vec![
-                    if tag.is_empty() { None } else { Some(tag) },
-                    Some(handler_ident_name),
-                ]
-                .into_iter()
-                .flatten()
-                .collect::<Vec<_>>()
-                .join("::"),
-            )
-            .unwrap();
+                .map(|webhook| (None, &webhook.handler)),
+        )
+        .map(|(cfg, handler)| {
+            let (usage, _) = path_struct_usage(handler);
+            let cfg = cfg.map(|predicate| quote! { #[cfg(#predicate)] });
+            quote! { #cfg (utoipa::__dev::path_fns(#usage).schemas)(&mut schemas); }
+        });
+    let component_schemas = attributes
+        .components
+        .schemas
+        .iter()
+        .map(|Schema(path)| path)
+        .chain(
+            attributes
+                .components
+                .implementors
+                .iter()
+                .flat_map(|implementors| implementors.types.iter()),
+        )
+        .map(|path| {
+            quote_spanned! {path.span()=>
+                <#path as utoipa::ToSchema>::schemas(&mut schemas);
+            }
+        });
+
+    if attributes.deny_schema_name_collisions == Some(true) {
+        let schema_names = schema_names(&attributes.components);
 
-            paths.extend(quote! {
-                .path(#usage::path(), #usage::path_item(Some(#tag)))
+        quote! {
+            let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
+            let type_names = utoipa::__dev::collect_type_names(|| {
+                #( #paths )*
+                #( #component_schemas )*
             });
+            if !schemas.is_empty() {
+                let components = openapi
+                    .components
+                    .get_or_insert_with(utoipa::openapi::Components::new);
+                utoipa::__dev::add_collected_schemas(
+                    components,
+                    &[#( #schema_names ),*],
+                    schemas,
+                    &type_names,
+                );
+            }
+        }
+    } else {
+        quote! {
+            let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
+            #( #paths )*
+            #( #component_schemas )*
+            if !schemas.is_empty() {
+                let components = openapi
+                    .components
+                    .get_or_insert_with(utoipa::openapi::Components::new);
+                for (name, schema) in schemas {
+                    components.schemas.entry(name).or_insert(schema);
+                }
+            }
+        }
+    }
+}
 
-            paths
-        },
+/// Names of the schemas listed in _`components(schemas(...))`_ along with their type names.
+fn schema_names(components: &Components) -> impl Iterator<Item = TokenStream> + '_ {
+    components.schemas.iter().map(|Schema(path)| {
+        quote_spanned! {path.span()=> utoipa::__dev::schema_name_of::<#path>() }
+    })
+}
+
+fn impl_webhooks(webhooks: &Punctuated<Webhook, Comma>, infer_tags: bool) -> Option<TokenStream> {
+    if webhooks.is_empty() {
+        return None;
+    }
+
+    let webhooks = webhooks.iter().map(|Webhook { name, handler }| {
+        let (usage, tag) = path_struct_usage(handler);
+        let tag = default_tag_tokens(tag, infer_tags);
+
+        quote! {
+            {
+                let path = utoipa::__dev::path_fns(#usage);
+                (!(path.is_hidden)()).then(|| (#name, (path.path_item)(#tag)))
+            }
+        }
+    });
+
+    Some(quote! { .webhooks([#(#webhooks),*].into_iter().flatten()) })
+}
+
+/// Default tag given to [`Path::path_item`][utoipa::Path] of the handler. Empty tag leaves the
+/// operation without a default tag when tag inference is disabled and `None` results _`crate`_.
+fn default_tag_tokens(tag: Option<String>, infer_tags: bool) -> TokenStream {
+    match tag {
+        _ if !infer_tags => quote! { Some("") },
+        Some(tag) => quote! { Some(#tag) },
+        None => quote! { None },
+    }
+}
+
+/// Resolve usage of the `__path_...` struct or associated constant of the handler along with the
+/// default tag which is the name of the parent module or type of the handler e.g. _`pets`_ for
+/// _`handlers::pets::get_pet`_ and _`PetController`_ for _`PetController::get_pet`_.
+fn path_struct_usage(handler: &ExprPath) -> (ExprPath, Option<String>) {
+    let segments = handler.path.segments.iter().collect::<Vec<_>>();
+    let handler_fn_name = &*segments.last().unwrap().ident.unraw().to_string();
+
+    let module_path = segments
+        .iter()
+        .take(segments.len() - 1)
+        .map(|part| part.ident.to_string())
+        .collect::<Vec<_>>();
+    let tag = module_path
+        .last()
+        .filter(|module| !matches!(module.as_str(), "crate" | "self" | "super"))
+        .map(|module| module.trim_start_matches("r#").to_string());
+
+    let handler_ident = format_ident!("{}{}", PATH_STRUCT_PREFIX, handler_fn_name);
+    let usage = syn::parse_str::<ExprPath>(
+        &module_path
+            .iter()
+            .map(String::as_str)
+            .chain([&*handler_ident.to_string()])
+            .collect::<Vec<_>>()
+            .join("::"),
     )
+    .unwrap();
+
+    (usage, tag)
+}
+
+// get_pet or cfg(feature = "...") path::to::get_pet
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct PathHandler {
+    cfg: Option<TokenStream>,
+    handler: ExprPath,
+}
+
+impl Parse for PathHandler {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        let cfg = if matches!(fork.parse::<Ident>(), Ok(ident) if ident == "cfg")
+            && fork.peek(syn::token::Paren)
+        {
+            input.parse::<Ident>()?;
+            let predicate;
+            parenthesized!(predicate in input);
+            let predicate = predicate.parse::<TokenStream>()?;
+            if predicate.is_empty() {
+                return Err(Error::new(
+                    input.span(),
+                    "expected cfg predicate, e.g. cfg(feature = \"...\")",
+                ));
+            }
+            Some(predicate)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            cfg,
+            handler: input.parse()?,
+        })
+    }
+}
+
+// ("newPet" = path::to::handler)
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Webhook {
+    name: String,
+    handler: ExprPath,
+}
+
+impl Parse for Webhook {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name = content.parse::<LitStr>()?.value();
+        content.parse::<Token![=]>()?;
+        let handler = content.parse::<ExprPath>()?;
+
+        Ok(Self { name, handler })
+    }
+}
+
+// (path = "/api/v1/pets", api = pets::PetApi, tags = ["pets"])
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct NestOpenApi {
+    path: String,
+    api: TypePath,
+    tags: Option<Vec<String>>,
+}
+
+impl Parse for NestOpenApi {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let nest;
+        parenthesized!(nest in input);
+        let mut path = None::<String>;
+        let mut api = None::<TypePath>;
+        let mut tags = None::<Vec<String>>;
+
+        while !nest.is_empty() {
+            let ident = nest.parse::<Ident>()?;
+            let attribute_name = &*ident.to_string();
+
+            match attribute_name {
+                "path" => path = Some(parse_utils::parse_next_literal_str(&nest)?),
+                "api" => api = Some(parse_utils::parse_next(&nest, || nest.parse())?),
+                "tags" => {
+                    tags = Some(parse_utils::parse_next(&nest, || {
+                        let tags;
+                        syn::bracketed!(tags in nest);
+                        Ok(Punctuated::<LitStr, Comma>::parse_terminated(&tags)?
+                            .iter()
+                            .map(LitStr::value)
+                            .collect())
+                    })?)
+                }
+                _ => {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!(
+                        "unexpected attribute: {attribute_name}, expected one of: path, api, tags"
+                    ),
+                    ))
+                }
+            }
+
+            if !nest.is_empty() {
+                nest.parse::<Comma>()?;
+            }
+        }
+
+        Ok(Self {
+            path: path.ok_or_else(|| {
+                Error::new(
+                    span,
+                    "missing required attribute: path, e.g. `path = \"/api/v1/pets\"`",
+                )
+            })?,
+            api: api.ok_or_else(|| {
+                Error::new(
+                    span,
+                    "missing required attribute: api, e.g. `api = pets::PetApi`",
+                )
+            })?,
+            tags,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    fn parse_server(tokens: TokenStream) -> syn::Result<Server> {
+        syn::parse2::<Server>(tokens)
+    }
+
+    #[test]
+    fn parse_openapi_with_duplicate_tags_fails() {
+        let error = syn::parse2::<OpenApiAttr>(quote! {
+            tags(
+                (name = "pets", description = "Pet management"),
+                (name = "pets", description = "More pets")
+            )
+        })
+        .map(drop)
+        .expect_err("openapi with duplicate tags should fail");
+
+        assert_eq!(
+            error.to_string(),
+            "duplicate tag: pets, tag names must be unique"
+        );
+    }
+
+    #[test]
+    fn parse_server_with_url_template_variables() {
+        let server = parse_server(quote! {
+            (url = "https://{region}.api.example.com/{basePath}", variables(
+                ("region" = (default = "eu", enum_values("eu", "us"))),
+                ("basePath" = (default = "v1"))
+            ))
+        })
+        .unwrap();
+
+        assert_eq!(
+            server.url_variables().collect::<Vec<_>>(),
+            ["region", "basePath"]
+        );
+    }
+
+    #[test]
+    fn parse_server_without_url_fails() {
+        let error = parse_server(quote! { (description = "no url") })
+            .map(drop)
+            .expect_err("server without url should fail");
+
+        assert!(error
+            .to_string()
+            .contains("missing required attribute: url"));
+    }
+
+    #[test]
+    fn parse_server_with_undefined_url_variable_fails() {
+        let error = parse_server(quote! {
+            (url = "https://{region}.api.example.com/{basePath}", variables(
+                ("region" = (default = "eu"))
+            ))
+        })
+        .map(drop)
+        .expect_err("server with undefined url variable should fail");
+
+        assert_eq!(
+            error.to_string(),
+            "server url variable: basePath must be defined in `variables(...)`"
+        );
+    }
+
+    #[test]
+    fn parse_server_variable_without_default_fails() {
+        let error = parse_server(quote! {
+            (url = "https://{region}.api.example.com", variables(
+                ("region" = (enum_values("eu", "us")))
+            ))
+        })
+        .map(drop)
+        .expect_err("server variable without default should fail");
+
+        assert!(error
+            .to_string()
+            .contains("missing required attribute: default"));
+    }
+
+    #[test]
+    fn parse_server_variable_with_default_not_in_enum_values_fails() {
+        let error = parse_server(quote! {
+            (url = "https://{region}.api.example.com", variables(
+                ("region" = (default = "asia", enum_values("eu", "us")))
+            ))
+        })
+        .map(drop)
+        .expect_err("server variable default outside of enum values should fail");
+
+        assert_eq!(
+            error.to_string(),
+            "server variable: region default value: asia must be one of the `enum_values`"
+        );
+    }
 }
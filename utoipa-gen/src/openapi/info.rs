@@ -7,8 +7,8 @@ use syn::parse::Parse;
 use syn::token::Comma;
 use syn::{parenthesized, Error, LitStr};
 
-use crate::parse_utils;
 use crate::parse_utils::Str;
+use crate::{parse_utils, Extensions};
 
 #[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -16,23 +16,27 @@ pub(super) struct Info<'i> {
     title: Option<String>,
     version: Option<String>,
     description: Option<Str>,
+    terms_of_service: Option<String>,
     license: Option<License<'i>>,
     contact: Option<Contact<'i>>,
+    extensions: Option<Extensions>,
 }
 
 impl Info<'_> {
     /// Construct new [`Info`] from _`cargo`_ env variables such as
-    /// * `CARGO_PGK_NAME`
-    /// * `CARGO_PGK_VERSION`
-    /// * `CARGO_PGK_DESCRIPTION`
-    /// * `CARGO_PGK_AUTHORS`
-    /// * `CARGO_PGK_LICENSE`
+    /// * `CARGO_PKG_NAME`
+    /// * `CARGO_PKG_VERSION`
+    /// * `CARGO_PKG_DESCRIPTION`
+    /// * `CARGO_PKG_AUTHORS`
+    /// * `CARGO_PKG_LICENSE`
+    ///
+    /// Cargo sets the variables of fields missing from _`Cargo.toml`_ to empty values which are
+    /// ignored.
     fn from_env() -> Self {
-        let name = std::env::var("CARGO_PKG_NAME").ok();
-        let version = std::env::var("CARGO_PKG_VERSION").ok();
-        let description = std::env::var("CARGO_PKG_DESCRIPTION").ok().map(Str::String);
-        let contact = std::env::var("CARGO_PKG_AUTHORS")
-            .ok()
+        let name = env_var("CARGO_PKG_NAME");
+        let version = env_var("CARGO_PKG_VERSION");
+        let description = env_var("CARGO_PKG_DESCRIPTION").map(Str::String);
+        let contact = env_var("CARGO_PKG_AUTHORS")
             .and_then(|authors| Contact::try_from(authors).ok())
             .and_then(|contact| {
                 if contact.name.is_none() && contact.email.is_none() && contact.url.is_none() {
@@ -41,7 +45,7 @@ impl Info<'_> {
                     Some(contact)
                 }
             });
-        let license = std::env::var("CARGO_PKG_LICENSE").ok().map(License::from);
+        let license = env_var("CARGO_PKG_LICENSE").map(License::from);
 
         Info {
             title: name,
@@ -49,6 +53,7 @@ impl Info<'_> {
             description,
             contact,
             license,
+            ..Default::default()
         }
     }
 }
@@ -74,6 +79,10 @@ impl Parse for Info<'_> {
                     info.description =
                         Some(parse_utils::parse_next(input, || input.parse::<Str>())?)
                 }
+                "terms_of_service" => {
+                    info.terms_of_service =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?.value())
+                }
                 "license" => {
                     let license_stream;
                     parenthesized!(license_stream in input);
@@ -84,8 +93,13 @@ impl Parse for Info<'_> {
                     parenthesized!(contact_stream in input);
                     info.contact = Some(contact_stream.parse()?)
                 }
+                "extensions" => {
+                    let extensions_stream;
+                    parenthesized!(extensions_stream in input);
+                    info.extensions = Some(extensions_stream.parse()?)
+                }
                 _ => {
-                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, version, description, license, contact")));
+                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, version, description, terms_of_service, license, contact, extensions")));
                 }
             }
             if !input.is_empty() {
@@ -108,6 +122,10 @@ impl ToTokens for Info<'_> {
             .description
             .as_ref()
             .map(|description| quote! { .description(Some(#description)) });
+        let terms_of_service = self
+            .terms_of_service
+            .as_ref()
+            .map(|terms_of_service| quote! { .terms_of_service(Some(#terms_of_service)) });
         let license = self
             .license
             .as_ref()
@@ -116,14 +134,20 @@ impl ToTokens for Info<'_> {
             .contact
             .as_ref()
             .map(|contact| quote! { .contact(Some(#contact)) });
+        let extensions = self
+            .extensions
+            .as_ref()
+            .map(|extensions| quote! { .extensions(Some(#extensions)) });
 
         tokens.extend(quote! {
             utoipa::openapi::InfoBuilder::new()
                 #title
                 #version
                 #description
+                #terms_of_service
                 #license
                 #contact
+                #extensions
         })
     }
 }
@@ -288,6 +312,13 @@ impl TryFrom<String> for Contact<'_> {
     }
 }
 
+/// Get value of env variable `name` if it is set and not empty.
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
 pub(super) fn impl_info(parsed: Option<Info>) -> Info {
     let mut info = Info::from_env();
 
@@ -300,6 +331,10 @@ pub(super) fn impl_info(parsed: Option<Info>) -> Info {
             info.description = parsed.description;
         }
 
+        if parsed.terms_of_service.is_some() {
+            info.terms_of_service = parsed.terms_of_service;
+        }
+
         if parsed.license.is_some() {
             info.license = parsed.license;
         }
@@ -311,6 +346,10 @@ pub(super) fn impl_info(parsed: Option<Info>) -> Info {
         if parsed.version.is_some() {
             info.version = parsed.version;
         }
+
+        if parsed.extensions.is_some() {
+            info.extensions = parsed.extensions;
+        }
     }
 
     info
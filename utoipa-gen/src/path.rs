@@ -6,19 +6,27 @@ use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::token::Paren;
-use syn::{parenthesized, parse::Parse, Token};
-use syn::{Expr, ExprLit, Lit, LitStr, Type};
+use syn::token::{Brace, Paren};
+use syn::{parenthesized, parse::Parse, parse_quote, Token};
+use syn::{Expr, ExprLit, Lit, LitStr, Type, Visibility};
 
-use crate::component::{GenericType, TypeTree};
+use crate::component::{GenericType, InlineObject, TypeTree};
+use crate::openapi::Server;
 use crate::path::request_body::RequestBody;
-use crate::{impl_to_tokens_diagnostics, parse_utils, Deprecated, Diagnostics};
+use crate::{
+    impl_to_tokens_diagnostics, parse_utils, Deprecated, Diagnostics, Extensions, ExternalDocs,
+    ToTokensDiagnostics,
+};
 use crate::{schema_type::SchemaType, security_requirement::SecurityRequirementsAttr, Array};
 
+use self::callback::Callback;
 use self::response::Response;
 use self::{parameter::Parameter, request_body::RequestBodyAttr, response::Responses};
 
+mod callback;
+mod encoding;
 pub mod example;
+mod link;
 pub mod parameter;
 mod request_body;
 pub mod response;
@@ -29,30 +37,61 @@ pub(crate) const PATH_STRUCT_PREFIX: &str = "__path_";
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct PathAttr<'p> {
-    path_operation: Option<PathOperation>,
+    path_operations: Vec<PathOperation>,
     request_body: Option<RequestBody<'p>>,
     responses: Vec<Response<'p>>,
     pub(super) path: Option<parse_utils::Value>,
     operation_id: Option<Expr>,
+    summary: Option<parse_utils::Value>,
+    description: Option<parse_utils::Value>,
     tag: Option<parse_utils::Value>,
     tags: Vec<parse_utils::Value>,
     params: Vec<Parameter<'p>>,
     security: Option<Array<'p, SecurityRequirementsAttr>>,
     context_path: Option<parse_utils::Value>,
     impl_for: Option<Ident>,
+    associated: bool,
+    register: bool,
+    api_version: Option<LitStr>,
+    hidden: bool,
+    include_if: Option<LitStr>,
+    deprecated: Option<DeprecatedNote>,
+    external_docs: Option<ExternalDocs>,
+    callbacks: Vec<Callback>,
+    servers: Punctuated<Server, Token![,]>,
+    extensions: Option<Extensions>,
 }
 
 impl<'p> PathAttr<'p> {
+    fn push_path_operation(
+        &mut self,
+        path_operation: PathOperation,
+        span: Span,
+    ) -> syn::Result<()> {
+        if self.path_operations.contains(&path_operation) {
+            return Err(syn::Error::new(
+                span,
+                format!("duplicate path operation: {}", path_operation.as_str()),
+            ));
+        }
+        self.path_operations.push(path_operation);
+
+        Ok(())
+    }
+
     #[cfg(feature = "auto_into_responses")]
     pub fn responses_from_into_responses(&mut self, ty: &'p syn::TypePath) {
+        // explicitly defined responses are added after the derived ones to override them
         self.responses
-            .push(Response::IntoResponses(Cow::Borrowed(ty)))
+            .insert(0, Response::IntoResponses(Cow::Borrowed(ty)))
     }
 
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub fn update_request_body(&mut self, request_body: Option<crate::ext::RequestBody<'p>>) {
         use std::mem;
@@ -68,7 +107,9 @@ impl<'p> PathAttr<'p> {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     pub fn update_parameters_ext<I: IntoIterator<Item = Parameter<'p>>>(
         &mut self,
@@ -85,17 +126,27 @@ impl<'p> PathAttr<'p> {
             }
         }
 
-        self.params.extend(
-            new_params
-                .into_iter()
-                .filter(|param| !matches!(param, Parameter::IntoParamsIdent(_))),
-        );
+        // with actix, axum and poem `IntoParams` types of the `Path` and `Query` extractors are
+        // added automatically unless path parameters are declared explicitly, rocket requires them
+        // to be declared with `params(...)`
+        let has_path_values = self.params.iter().any(Parameter::is_path_value);
+        self.params
+            .extend(new_params.into_iter().filter(|param| match param {
+                Parameter::IntoParamsIdent(_) => {
+                    cfg!(any(
+                        feature = "actix_extras",
+                        feature = "axum_extras",
+                        feature = "poem_extras"
+                    )) && !(has_path_values && param.is_path_into_params())
+                }
+                Parameter::Value(_) => true,
+            }));
     }
 }
 
 impl Parse for PathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, path, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, params, tag, security, context_path";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, summary, description, path, method, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, params, tag, security, context_path, deprecated, external_docs, callbacks, servers, impl_for, associated, register, api_version, hidden, include_if, extensions";
         let mut path_attr = PathAttr::default();
 
         while !input.is_empty() {
@@ -112,9 +163,25 @@ impl Parse for PathAttr<'_> {
                     path_attr.operation_id =
                         Some(parse_utils::parse_next(input, || Expr::parse(input))?);
                 }
+                "summary" => {
+                    path_attr.summary = Some(parse::description(input)?);
+                }
+                "description" => {
+                    path_attr.description = Some(parse::description(input)?);
+                }
                 "path" => {
                     path_attr.path = Some(parse_utils::parse_next_literal_str_or_expr(input)?);
                 }
+                "method" => {
+                    let method = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+                    path_attr.push_path_operation(
+                        method
+                            .value()
+                            .parse::<PathOperation>()
+                            .map_err(|error| syn::Error::new(method.span(), error.to_string()))?,
+                        method.span(),
+                    )?;
+                }
                 "request_body" => {
                     path_attr.request_body =
                         Some(RequestBody::Parsed(input.parse::<RequestBodyAttr>()?));
@@ -154,16 +221,61 @@ impl Parse for PathAttr<'_> {
                     path_attr.context_path =
                         Some(parse_utils::parse_next_literal_str_or_expr(input)?)
                 }
+                "deprecated" => {
+                    path_attr.deprecated = Some(input.parse::<DeprecatedNote>()?);
+                }
+                "external_docs" => {
+                    let external_docs;
+                    parenthesized!(external_docs in input);
+                    path_attr.external_docs = Some(external_docs.parse::<ExternalDocs>()?);
+                }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    path_attr.extensions = Some(extensions.parse::<Extensions>()?);
+                }
+                "servers" => {
+                    path_attr.servers = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                }
+                "callbacks" => {
+                    path_attr.callbacks =
+                        parse_utils::parse_punctuated_within_parenthesis::<Callback>(input)?
+                            .into_iter()
+                            .collect();
+                }
                 "impl_for" => {
                     path_attr.impl_for =
                         Some(parse_utils::parse_next(input, || input.parse::<Ident>())?);
                 }
+                "associated" => {
+                    path_attr.associated = parse_utils::parse_bool_or_true(input)?;
+                }
+                "api_version" => {
+                    path_attr.api_version =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?);
+                }
+                "hidden" => {
+                    path_attr.hidden = parse_utils::parse_bool_or_true(input)?;
+                }
+                "include_if" => {
+                    path_attr.include_if =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?);
+                }
+                "register" => {
+                    if !cfg!(feature = "register_paths") {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "register requires `register_paths` feature",
+                        ));
+                    }
+                    path_attr.register = true;
+                }
                 _ => {
                     // any other case it is expected to be path operation
                     if let Some(path_operation) =
                         attribute_name.parse::<PathOperation>().into_iter().next()
                     {
-                        path_attr.path_operation = Some(path_operation)
+                        path_attr.push_path_operation(path_operation, ident.span())?
                     } else {
                         return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE));
                     }
@@ -191,6 +303,7 @@ impl Parse for PathAttr<'_> {
 ///   * "patch"
 ///   * "trace"
 #[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum PathOperation {
     Get,
     Post,
@@ -204,6 +317,21 @@ pub enum PathOperation {
 }
 
 impl PathOperation {
+    /// Get the path operation as lower case http request type such as `get`.
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+            Self::Put => "put",
+            Self::Delete => "delete",
+            Self::Options => "options",
+            Self::Head => "head",
+            Self::Patch => "patch",
+            Self::Trace => "trace",
+            Self::Connect => "connect",
+        }
+    }
+
     /// Create path operation from ident
     ///
     /// Ident must have value of http request type as lower case string such as `get`.
@@ -262,7 +390,9 @@ pub struct Path<'p> {
     path_operation: Option<PathOperation>,
     path: Option<String>,
     doc_comments: Option<Vec<String>>,
-    deprecated: Option<bool>,
+    deprecated: Option<DeprecatedNote>,
+    vis: Visibility,
+    has_receiver: bool,
 }
 
 impl<'p> Path<'p> {
@@ -274,6 +404,8 @@ impl<'p> Path<'p> {
             path: None,
             doc_comments: None,
             deprecated: None,
+            vis: Visibility::Inherited,
+            has_receiver: false,
         }
     }
 
@@ -295,12 +427,24 @@ impl<'p> Path<'p> {
         self
     }
 
-    pub fn deprecated(mut self, deprecated: Option<bool>) -> Self {
+    pub fn deprecated(mut self, deprecated: Option<DeprecatedNote>) -> Self {
         self.deprecated = deprecated;
 
         self
     }
 
+    pub fn vis(mut self, vis: Visibility) -> Self {
+        self.vis = vis;
+
+        self
+    }
+
+    pub fn receiver(mut self, has_receiver: bool) -> Self {
+        self.has_receiver = has_receiver;
+
+        self
+    }
+
     fn tokens_or_diagnostics(&self, tokens: &mut TokenStream2) -> Result<(), Diagnostics> {
         let operation_id = self
             .path_attr
@@ -330,35 +474,77 @@ impl<'p> Path<'p> {
         } else {
             None::<TokenStream2>
         };
-        let tag = self
-            .path_attr
-            .tag
-            .as_ref()
-            .map(ToTokens::to_token_stream)
-            .unwrap_or_else(|| quote!(""));
-        let path_operation = self
-            .path_attr
-            .path_operation
-            .as_ref()
-            .or(self.path_operation.as_ref())
-            .ok_or_else(|| {
-                let diagnostics = Diagnostics::new("path operation is not defined for path")
-                    .help("Did you forget to define it, e.g. #[utoipa::path(get, ...)]");
+        // default tag is only used when no tags are explicitly defined for the operation
+        let tag = if self.path_attr.tag.is_none() && !self.path_attr.tags.is_empty() {
+            None
+        } else {
+            let tag = self
+                .path_attr
+                .tag
+                .as_ref()
+                .map(ToTokens::to_token_stream)
+                .unwrap_or_else(|| quote!(""));
+            // empty default tag means the operation has no default tag
+            Some(quote! {
+                let operation = match [Some(#tag), default_tag.or(Some("crate"))]
+                    .into_iter()
+                    .flatten()
+                    .find(|tag| !tag.is_empty())
+                {
+                    Some(tag) => operation.tag(tag),
+                    None => operation,
+                };
+            })
+        };
+        let path_operations = if !self.path_attr.path_operations.is_empty() {
+            self.path_attr.path_operations.iter().collect::<Vec<_>>()
+        } else {
+            self.path_operation.iter().collect::<Vec<_>>()
+        };
+        if path_operations.is_empty() {
+            let diagnostics = Diagnostics::new("path operation is not defined for path")
+                .help("Did you forget to define it, e.g. #[utoipa::path(get, ...)]");
 
-                #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
-                let diagnostics = diagnostics.help(
-                    "Did you forget to define operation path attribute macro e.g #[get(...)]",
-                );
+            #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
+            let diagnostics = diagnostics
+                .help("Did you forget to define operation path attribute macro e.g #[get(...)]");
 
-                diagnostics
-            })?;
+            return Err(diagnostics);
+        }
+        // operation shared by multiple path operations gets the path operation as suffix for
+        // its operation id since operation ids must be unique within the OpenAPI document
+        let is_shared = path_operations.len() > 1;
+        if is_shared {
+            if let Some(operation_id) = &self.path_attr.operation_id {
+                return Err(Diagnostics::with_span(
+                    operation_id.span(),
+                    "operation_id cannot be used with multiple path operations",
+                )
+                .help("Operation ids must be unique, remove the operation_id to use the function name suffixed with the path operation")
+                .help("Or define each path operation in its own #[utoipa::path(...)] with its own operation_id"));
+            }
+        }
+        let path_operations = path_operations.into_iter().map(|path_operation| {
+            if is_shared {
+                let suffix = format!("_{}", path_operation.as_str());
+                quote! {
+                    .operation(#path_operation, {
+                        let mut operation = operation.clone();
+                        operation.operation_id = operation.operation_id.map(|id| id + #suffix);
+                        operation
+                    })
+                }
+            } else {
+                quote! { .operation(#path_operation, operation) }
+            }
+        });
 
         let path = self
             .path_attr
             .path
             .as_ref()
             .map(|path| path.to_token_stream())
-            .or(Some(self.path.to_token_stream()))
+            .or_else(|| self.path.as_ref().map(|path| path.to_token_stream()))
             .ok_or_else(|| {
                 let diagnostics = Diagnostics::new("path is not defined for path")
                     .help(r#"Did you forget to define it in #[utoipa::path(path = "...")]"#);
@@ -371,24 +557,37 @@ impl<'p> Path<'p> {
                 diagnostics
             })?;
 
+        // literal paths are resolved here instead of converting them at runtime
+        let literal_path = match &self.path_attr.path {
+            Some(parse_utils::Value::LitStr(path)) => Some(path.value().replace('"', "")),
+            Some(parse_utils::Value::Expr(_)) => None,
+            None => self.path.as_ref().map(|path| path.replace('"', "")),
+        };
+        let path_str = literal_path
+            .as_ref()
+            .map(|path| path.to_token_stream())
+            .unwrap_or_else(|| quote! { &#path.to_string().replace('"', "") });
+
         let path_with_context_path = self
             .path_attr
             .context_path
             .as_ref()
             .map(|context_path| {
-                let context_path = context_path.to_token_stream();
-                let context_path_tokens = quote! {
-                    format!("{}{}",
-                        #context_path.to_string().replace('"', ""),
-                        #path.to_string().replace('"', "")
-                    )
+                let context_path = match context_path {
+                    parse_utils::Value::LitStr(context_path) => {
+                        context_path.value().replace('"', "").to_token_stream()
+                    }
+                    parse_utils::Value::Expr(context_path) => {
+                        quote! { &#context_path.to_string().replace('"', "") }
+                    }
                 };
-                context_path_tokens
-            })
-            .unwrap_or_else(|| {
                 quote! {
-                    #path.to_string().replace('"', "")
+                    utoipa::__dev::join_context_path(#context_path, #path_str)
                 }
+            })
+            .unwrap_or_else(|| match &literal_path {
+                Some(path) => quote! { String::from(#path) },
+                None => quote! { #path.to_string().replace('"', "") },
             });
 
         let split_comment = self
@@ -408,20 +607,133 @@ impl<'p> Path<'p> {
                 (summary, trimmed)
             });
 
+        // note of `deprecated` in path attribute takes precedence over Rust's `#[deprecated]`
+        let deprecated_notes = [self.path_attr.deprecated.as_ref(), self.deprecated.as_ref()];
+        let deprecated = deprecated_notes.iter().any(Option::is_some).then_some(true);
+
         let operation: Operation = Operation {
-            deprecated: &self.deprecated,
+            deprecated: &deprecated,
+            deprecated_note: deprecated_notes
+                .into_iter()
+                .flatten()
+                .find_map(DeprecatedNote::description),
             operation_id,
-            summary: split_comment.map(|(summary, _)| summary),
-            description: split_comment.map(|(_, description)| description),
+            // explicitly defined summary and description take precedence over doc comments
+            summary: self.path_attr.summary.clone().or_else(|| {
+                split_comment.map(|(summary, _)| {
+                    parse_utils::Value::LitStr(LitStr::new(summary, Span::call_site()))
+                })
+            }),
+            description: self.path_attr.description.clone().or_else(|| {
+                split_comment.map(|(_, description)| {
+                    parse_utils::Value::LitStr(LitStr::new(
+                        &description.join("\n"),
+                        Span::call_site(),
+                    ))
+                })
+            }),
             parameters: self.path_attr.params.as_ref(),
             request_body: self.path_attr.request_body.as_ref(),
             responses: self.path_attr.responses.as_ref(),
             security: self.path_attr.security.as_ref(),
+            external_docs: self.path_attr.external_docs.as_ref(),
+            callbacks: &self.path_attr.callbacks,
+            servers: &self.path_attr.servers,
+            extensions: self.path_attr.extensions.as_ref(),
+        };
+        let path_struct = format_ident!("{}{}", PATH_STRUCT_PREFIX, self.fn_name);
+        // types cannot be declared within `impl` blocks and traits thus paths of associated
+        // functions are constants of the functions of the path
+        let associated = self.path_attr.associated || self.has_receiver;
+        if associated && self.path_attr.impl_for.is_some() {
+            return Err(Diagnostics::new(
+                "impl_for is not supported for associated functions",
+            ));
+        }
+        if associated && self.path_attr.register {
+            return Err(
+                Diagnostics::new("register is not supported for associated functions")
+                    .help("List the associated function in #[openapi(paths(...))] instead"),
+            );
+        }
+
+        let schemas = if cfg!(feature = "auto_collect_schemas") {
+            let schemas = self
+                .path_attr
+                .request_body
+                .iter()
+                .map(RequestBody::schema_references_tokens)
+                .chain(
+                    self.path_attr
+                        .responses
+                        .iter()
+                        .map(Response::schema_references_tokens),
+                )
+                .chain(
+                    self.path_attr
+                        .params
+                        .iter()
+                        .map(Parameter::schema_references_tokens),
+                )
+                .collect::<Result<TokenStream2, Diagnostics>>()?;
+            schemas
+        } else {
+            TokenStream2::new()
         };
+
+        let api_version = self
+            .path_attr
+            .api_version
+            .as_ref()
+            .map(|api_version| quote! { Some(#api_version) });
+
+        let is_hidden = match (self.path_attr.hidden, &self.path_attr.include_if) {
+            (true, _) => Some(quote! { true }),
+            (false, Some(feature)) => Some(quote! { !cfg!(feature = #feature) }),
+            (false, None) => None,
+        };
+
+        let path_item = quote! {
+            use utoipa::openapi::ToArray;
+            use std::iter::FromIterator;
+            let operation = #operation
+                #tags;
+            #tag
+            let operation: utoipa::openapi::path::Operation = operation.build();
+            utoipa::openapi::path::PathItemBuilder::new()
+                #( #path_operations )*
+                .build()
+        };
+
+        if associated {
+            let vis = &self.vis;
+            let api_version = api_version.unwrap_or_else(|| quote! { None });
+            let is_hidden = is_hidden.unwrap_or_else(|| quote! { false });
+            tokens.extend(quote! {
+                #[allow(non_upper_case_globals)]
+                #[doc(hidden)]
+                #vis const #path_struct: utoipa::__dev::PathFns = utoipa::__dev::PathFns {
+                    path: || #path_with_context_path,
+                    path_item: |default_tag: Option<&str>| -> utoipa::openapi::path::PathItem {
+                        #path_item
+                    },
+                    schemas: |schemas: &mut Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>| {
+                        #[allow(unused_imports)]
+                        use utoipa::__dev::{CollectNothing as _, CollectSchema as _};
+                        let _ = schemas;
+                        #schemas
+                    },
+                    api_version: || #api_version,
+                    is_hidden: || #is_hidden,
+                };
+            });
+
+            return Ok(());
+        }
+
         let impl_for = if let Some(impl_for) = &self.path_attr.impl_for {
             impl_for.clone()
         } else {
-            let path_struct = format_ident!("{}{}", PATH_STRUCT_PREFIX, self.fn_name);
             tokens.extend(quote! {
                 #[allow(non_camel_case_types)]
                 #[doc(hidden)]
@@ -430,25 +742,44 @@ impl<'p> Path<'p> {
             path_struct
         };
 
+        let schemas = crate::component::schemas_fn_tokens(schemas);
+        let api_version = api_version.map(|api_version| {
+            quote! {
+                fn api_version() -> Option<&'static str> {
+                    #api_version
+                }
+            }
+        });
+        let is_hidden = is_hidden.map(|is_hidden| {
+            quote! {
+                fn is_hidden() -> bool {
+                    #is_hidden
+                }
+            }
+        });
+
+        if self.path_attr.register {
+            tokens.extend(quote! {
+                utoipa::__dev::inventory::submit! {
+                    utoipa::__dev::RegisteredPath::new::<#impl_for>(module_path!())
+                }
+            });
+        }
+
         tokens.extend(quote! {
             impl utoipa::Path for #impl_for {
                 fn path() -> String {
                     #path_with_context_path
                 }
 
+                #schemas
+
+                #api_version
+
+                #is_hidden
+
                 fn path_item(default_tag: Option<&str>) -> utoipa::openapi::path::PathItem {
-                    use utoipa::openapi::ToArray;
-                    use std::iter::FromIterator;
-                    utoipa::openapi::PathItem::new(
-                        #path_operation,
-                        #operation
-                        #tags
-                            .tag(
-                            *[Some(#tag), default_tag, Some("crate")].iter()
-                            .flatten()
-                            .find(|t| !t.is_empty()).unwrap()
-                        )
-                    )
+                    #path_item
                 }
             }
         });
@@ -468,13 +799,18 @@ impl_to_tokens_diagnostics! {
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Operation<'a> {
     operation_id: Expr,
-    summary: Option<&'a String>,
-    description: Option<&'a [String]>,
+    summary: Option<parse_utils::Value>,
+    description: Option<parse_utils::Value>,
     deprecated: &'a Option<bool>,
+    deprecated_note: Option<String>,
     parameters: &'a Vec<Parameter<'a>>,
     request_body: Option<&'a RequestBody<'a>>,
     responses: &'a Vec<Response<'a>>,
     security: Option<&'a Array<'a, SecurityRequirementsAttr>>,
+    external_docs: Option<&'a ExternalDocs>,
+    callbacks: &'a [Callback],
+    servers: &'a Punctuated<Server, Token![,]>,
+    extensions: Option<&'a Extensions>,
 }
 
 impl ToTokens for Operation<'_> {
@@ -505,29 +841,128 @@ impl ToTokens for Operation<'_> {
             tokens.extend(quote!( .deprecated(Some(#deprecated))))
         }
 
-        if let Some(summary) = self.summary {
+        if let Some(summary) = self.summary.as_ref().filter(|summary| !summary.is_empty()) {
             tokens.extend(quote! {
                 .summary(Some(#summary))
             })
         }
 
-        if let Some(description) = self.description {
-            let description = description.join("\n");
+        if let Some(external_docs) = self.external_docs {
+            tokens.extend(quote! {
+                .external_docs(Some(#external_docs))
+            })
+        }
 
-            if !description.is_empty() {
-                tokens.extend(quote! {
-                    .description(Some(#description))
-                })
+        if !self.servers.is_empty() {
+            let servers = self.servers.iter().collect::<Array<&Server>>();
+            tokens.extend(quote! {
+                .servers(Some(#servers))
+            })
+        }
+
+        if let Some(extensions) = self.extensions {
+            tokens.extend(quote! {
+                .extensions(Some(#extensions))
+            })
+        }
+
+        let description = self
+            .description
+            .as_ref()
+            .filter(|description| !description.is_empty());
+        let description = match (description, &self.deprecated_note) {
+            (Some(parse_utils::Value::LitStr(description)), Some(note)) => {
+                Some(format!("{}\n\n{note}", description.value()).to_token_stream())
+            }
+            (Some(parse_utils::Value::Expr(description)), Some(note)) => {
+                Some(quote! { format!("{}\n\n{}", #description, #note) })
             }
+            (None, Some(note)) => Some(note.to_token_stream()),
+            (description, None) => description.map(ToTokens::to_token_stream),
+        };
+
+        if let Some(description) = description {
+            tokens.extend(quote! {
+                .description(Some(#description))
+            })
         }
 
         self.parameters
             .iter()
             .for_each(|parameter| parameter.to_tokens(tokens));
+
+        for callback in self.callbacks {
+            let name = &callback.name;
+            tokens.extend(quote! {
+                .callback(#name, #callback)
+            })
+        }
+    }
+}
+
+/// Parsed `deprecated` or `deprecated = "..."` attribute. The optional literal string is a note
+/// e.g. pointing to the replacement which will be appended to the description since OpenAPI
+/// only supports boolean flag for deprecation.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DeprecatedNote(Option<String>);
+
+impl DeprecatedNote {
+    /// Get the note formatted as description text prefixed with _`Deprecated: `_.
+    pub(super) fn description(&self) -> Option<String> {
+        self.0.as_ref().map(|note| format!("Deprecated: {note}"))
+    }
+
+    /// Get [`DeprecatedNote`] from Rust's own `#[deprecated]`, `#[deprecated = "..."]` or
+    /// `#[deprecated(note = "...")]` attribute.
+    pub fn from_attribute(attribute: &syn::Attribute) -> Self {
+        let note = match &attribute.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(note),
+                        ..
+                    }),
+                ..
+            }) => Some(note.value()),
+            syn::Meta::List(list) => list
+                .parse_args_with(Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated)
+                .ok()
+                .and_then(|values| {
+                    values.into_iter().find_map(|value| match value.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(note),
+                            ..
+                        }) if value.path.is_ident("note") => Some(note.value()),
+                        _ => None,
+                    })
+                }),
+            _ => None,
+        };
+
+        Self(note)
     }
 }
 
-/// Represents either `ref("...")` or `Type` that can be optionally inlined with `inline(Type)`.
+impl From<String> for DeprecatedNote {
+    fn from(note: String) -> Self {
+        Self(Some(note))
+    }
+}
+
+impl Parse for DeprecatedNote {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![=]) {
+            Ok(Self(Some(parse_utils::parse_next_literal_str(input)?)))
+        } else {
+            Ok(Self(None))
+        }
+    }
+}
+
+/// Represents either `ref("...")`, `Type` that can be optionally inlined with `inline(Type)` or
+/// ad-hoc inline object `{ field: Type, ... }`. Each form can be wrapped in `Option<...>` to make it
+/// optional.
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum PathType<'p> {
     Ref(String),
@@ -535,8 +970,57 @@ enum PathType<'p> {
     InlineSchema(TokenStream2, Type),
 }
 
+impl PathType<'_> {
+    /// Get tokens collecting schemas referenced by the [`PathType`]. Only types defined with
+    /// `Type` or `inline(Type)` are collected.
+    fn schema_references_tokens(&self) -> Result<TokenStream2, Diagnostics> {
+        match self {
+            Self::MediaType(inline_type) => inline_type.schema_references_tokens(),
+            Self::Ref(_) | Self::InlineSchema(..) => Ok(TokenStream2::new()),
+        }
+    }
+}
+
+impl PathType<'_> {
+    /// Check whether `input` starts with `Option<ref(...)>` or `Option<{ ... }>`. Other optional
+    /// types are parsed by [`InlineType`].
+    fn peek_optional(input: syn::parse::ParseStream) -> bool {
+        let fork = input.fork();
+
+        matches!(fork.parse::<Ident>(), Ok(ident) if ident == "Option")
+            && fork.parse::<Token![<]>().is_ok()
+            && ((fork.peek(Token![ref]) && fork.peek2(Paren)) || fork.peek(Brace))
+    }
+}
+
 impl Parse for PathType<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if Self::peek_optional(input) {
+            input.parse::<Ident>()?;
+            input.parse::<Token![<]>()?;
+            let schema = if input.peek(Token![ref]) {
+                input.parse::<Token![ref]>()?;
+                let ref_stream;
+                parenthesized!(ref_stream in input);
+                let ref_type = ref_stream.parse::<LitStr>()?.value();
+                quote! { utoipa::openapi::schema::Ref::new(#ref_type) }
+            } else {
+                ToTokensDiagnostics::to_token_stream(&input.parse::<InlineObject>()?)
+            };
+            input.parse::<Token![>]>()?;
+
+            // optional `ref` and inline object are wrapped in nullable `allOf` while the body
+            // type is `Option` to mark the body not required
+            return Ok(Self::InlineSchema(
+                quote! {
+                    utoipa::openapi::schema::AllOfBuilder::new()
+                        .nullable(true)
+                        .item(#schema)
+                },
+                parse_quote!(Option<serde_json::Value>),
+            ));
+        }
+
         let fork = input.fork();
         let is_ref = if (fork.parse::<Option<Token![ref]>>()?).is_some() {
             fork.peek(Paren)
@@ -549,6 +1033,12 @@ impl Parse for PathType<'_> {
             let ref_stream;
             parenthesized!(ref_stream in input);
             Ok(Self::Ref(ref_stream.parse::<LitStr>()?.value()))
+        } else if input.peek(Brace) {
+            let inline_object = input.parse::<InlineObject>()?;
+            Ok(Self::InlineSchema(
+                ToTokensDiagnostics::to_token_stream(&inline_object),
+                parse_quote!(serde_json::Value),
+            ))
         } else {
             Ok(Self::MediaType(input.parse()?))
         }
@@ -567,10 +1057,95 @@ impl InlineType<'_> {
     fn as_type_tree(&self) -> Result<TypeTree, Diagnostics> {
         TypeTree::from_type(&self.ty)
     }
+
+    /// Get tokens collecting schemas referenced by the underlying [`syn::Type`]. Trait objects
+    /// e.g. _`dyn ErrorResponse`_ are not collected since their schemas are provided by the
+    /// implementors registered to the `OpenApi`.
+    fn schema_references_tokens(&self) -> Result<TokenStream2, Diagnostics> {
+        type_schema_references_tokens(&self.ty, self.is_inline)
+    }
+}
+
+/// Get tokens collecting schemas referenced by the `ty` skipping only the trait object subtrees.
+/// E.g. for _`Result<Pet, Box<dyn Error>>`_ the _`Pet`_ is still collected.
+fn type_schema_references_tokens(ty: &Type, is_inline: bool) -> Result<TokenStream2, Diagnostics> {
+    if !contains_trait_object(ty) {
+        return Ok(TypeTree::from_type(ty)?.schema_references_tokens(is_inline, ""));
+    }
+
+    match ty {
+        Type::Reference(reference) => type_schema_references_tokens(&reference.elem, is_inline),
+        Type::Group(group) => type_schema_references_tokens(&group.elem, is_inline),
+        Type::Paren(paren) => type_schema_references_tokens(&paren.elem, is_inline),
+        Type::Slice(slice) => type_schema_references_tokens(&slice.elem, is_inline),
+        Type::Array(array) => type_schema_references_tokens(&array.elem, is_inline),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .map(|ty| type_schema_references_tokens(ty, is_inline))
+            .collect(),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .iter()
+            .filter_map(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => Some(&arguments.args),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .map(|ty| type_schema_references_tokens(ty, is_inline))
+            .collect(),
+        _ => Ok(TokenStream2::new()),
+    }
+}
+
+/// Check whether the [`Type`] is a trait object or contains one e.g. _`Box<dyn ErrorResponse>`_.
+fn contains_trait_object(ty: &Type) -> bool {
+    match ty {
+        Type::TraitObject(_) => true,
+        Type::Reference(reference) => contains_trait_object(&reference.elem),
+        Type::Group(group) => contains_trait_object(&group.elem),
+        Type::Paren(paren) => contains_trait_object(&paren.elem),
+        Type::Slice(slice) => contains_trait_object(&slice.elem),
+        Type::Array(array) => contains_trait_object(&array.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().any(contains_trait_object),
+        Type::Path(type_path) => type_path.path.segments.iter().any(|segment| {
+            let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                return false;
+            };
+            arguments.args.iter().any(|argument| match argument {
+                syn::GenericArgument::Type(ty) => contains_trait_object(ty),
+                _ => false,
+            })
+        }),
+        _ => false,
+    }
 }
 
 impl Parse for InlineType<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        // `Option<inline(Type)>` is same as `inline(Option<Type>)`
+        let fork = input.fork();
+        let is_optional_inline = matches!(fork.parse::<Ident>(), Ok(ident) if ident == "Option")
+            && fork.parse::<Token![<]>().is_ok()
+            && matches!(fork.parse::<Ident>(), Ok(ident) if ident == "inline")
+            && fork.peek(Paren);
+        if is_optional_inline {
+            input.parse::<Ident>()?;
+            input.parse::<Token![<]>()?;
+            let InlineType { ty, is_inline } = input.parse::<InlineType>()?;
+            input.parse::<Token![>]>()?;
+
+            return Ok(InlineType {
+                ty: Cow::Owned(parse_quote!(Option<#ty>)),
+                is_inline,
+            });
+        }
+
         let fork = input.fork();
         let is_inline = if let Some(ident) = fork.parse::<Option<Ident>>()? {
             ident == "inline" && fork.peek(Paren)
@@ -597,7 +1172,7 @@ impl Parse for InlineType<'_> {
 
 pub trait PathTypeTree {
     /// Resolve default content type based on current [`Type`].
-    fn get_default_content_type(&self) -> &str;
+    fn get_default_content_type(&self) -> &'static str;
 
     /// Check whether [`TypeTree`] an option
     fn is_option(&self) -> bool;
@@ -609,6 +1184,13 @@ pub trait PathTypeTree {
 impl PathTypeTree for TypeTree<'_> {
     /// Resolve default content type based on current [`Type`].
     fn get_default_content_type(&self) -> &'static str {
+        if self.is_option() || self.is_smart_pointer() {
+            // content type of the optional or smart pointer body is resolved by the type within
+            if let Some(child) = self.children.as_ref().and_then(|children| children.first()) {
+                return child.get_default_content_type();
+            }
+        }
+
         if self.is_array()
             && self
                 .children
@@ -620,6 +1202,11 @@ impl PathTypeTree for TypeTree<'_> {
                         .any(|path| SchemaType(path).is_byte())
                 })
                 .unwrap_or(false)
+            || self
+                .path
+                .as_ref()
+                .map(|path| SchemaType(path.deref()).is_file())
+                .unwrap_or(false)
         {
             "application/octet-stream"
         } else if self
@@ -655,7 +1242,7 @@ impl PathTypeTree for TypeTree<'_> {
     }
 }
 
-mod parse {
+pub(crate) mod parse {
     use syn::parse::ParseStream;
     use syn::punctuated::Punctuated;
     use syn::token::{Bracket, Comma};
@@ -687,13 +1274,85 @@ mod parse {
         })
     }
 
+    /// Parse `deprecated = "..."` note of a response.
+    #[inline]
+    pub(super) fn deprecated_note(input: ParseStream) -> Result<super::DeprecatedNote> {
+        parse_utils::parse_next_literal_str(input)
+            .map(super::DeprecatedNote::from)
+            .map_err(|error| {
+                syn::Error::new(
+                    error.span(),
+                    format!("expected deprecated = \"...\" with a note, {error}"),
+                )
+            })
+    }
+
     #[inline]
     pub(super) fn example(input: ParseStream) -> Result<AnyValue> {
         parse_utils::parse_next(input, || AnyValue::parse_lit_str_or_json(input))
     }
 
     #[inline]
-    pub(super) fn examples(input: ParseStream) -> Result<Punctuated<Example, Comma>> {
-        parse_utils::parse_punctuated_within_parenthesis(input)
+    pub(crate) fn examples(input: ParseStream) -> Result<Punctuated<Example, Comma>> {
+        let span = input.span();
+        let examples: Punctuated<Example, Comma> =
+            parse_utils::parse_punctuated_within_parenthesis(input)?;
+
+        let mut names = std::collections::HashSet::new();
+        if let Some(example) = examples
+            .iter()
+            .find(|example| !names.insert(example.name.as_str()))
+        {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "duplicate example name: {}, example names must be unique",
+                    example.name
+                ),
+            ));
+        }
+
+        Ok(examples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    fn parse_path_attr_error(tokens: TokenStream2) -> String {
+        syn::parse2::<PathAttr>(tokens)
+            .map(drop)
+            .expect_err("path attribute should fail to parse")
+            .to_string()
+    }
+
+    #[test]
+    fn parse_path_with_invalid_method_fails() {
+        let error = parse_path_attr_error(quote! {
+            method = "fetch", path = "/pets"
+        });
+
+        assert_eq!(
+            error,
+            "invalid PathOperation expected one of: get, post, put, delete, options, head, patch, trace, connect"
+        );
+    }
+
+    #[test]
+    fn parse_path_with_duplicate_methods_fails() {
+        let error = parse_path_attr_error(quote! {
+            get, method = "get", path = "/pets"
+        });
+
+        assert_eq!(error, "duplicate path operation: get");
+
+        let error = parse_path_attr_error(quote! {
+            method = "post", method = "post", path = "/pets"
+        });
+
+        assert_eq!(error, "duplicate path operation: post");
     }
 }
@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{parenthesized, ExprPath, LitStr, Token};
+
+use super::PATH_STRUCT_PREFIX;
+
+// ("name" = path::to::callback_handler)
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Callback {
+    pub(super) name: String,
+    handler: ExprPath,
+}
+
+impl Parse for Callback {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name = content.parse::<LitStr>()?.value();
+        content.parse::<Token![=]>()?;
+        let handler = content.parse::<ExprPath>()?;
+
+        Ok(Self { name, handler })
+    }
+}
+
+impl ToTokens for Callback {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut path_struct = self.handler.clone();
+        if let Some(handler) = path_struct.path.segments.last_mut() {
+            handler.ident = format_ident!("{}{}", PATH_STRUCT_PREFIX, handler.ident);
+        }
+
+        tokens.extend(quote! {
+            {
+                let mut path_item = <#path_struct as utoipa::Path>::path_item(None);
+                // tags are not used within callbacks
+                path_item
+                    .operations
+                    .values_mut()
+                    .for_each(|operation| operation.tags = None);
+                utoipa::openapi::path::Callback::new(<#path_struct as utoipa::Path>::path(), path_item)
+            }
+        })
+    }
+}
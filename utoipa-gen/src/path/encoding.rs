@@ -0,0 +1,92 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::token::Comma;
+use syn::{parenthesized, Error, LitStr, Token};
+
+use crate::parse_utils;
+
+use super::parameter::ParameterStyle;
+
+// (name = (content_type = "...", style = ..., explode = bool, allow_reserved = bool))
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(super) struct Encoding {
+    pub(super) name: String,
+    content_type: Option<parse_utils::Value>,
+    style: Option<ParameterStyle>,
+    explode: Option<bool>,
+    allow_reserved: Option<bool>,
+}
+
+impl Parse for Encoding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let encoding_stream;
+        parenthesized!(encoding_stream in input);
+        let mut encoding = Encoding {
+            name: encoding_stream.parse::<LitStr>()?.value(),
+            ..Default::default()
+        };
+        encoding_stream.parse::<Token![=]>()?;
+
+        let content;
+        parenthesized!(content in encoding_stream);
+
+        while !content.is_empty() {
+            let ident = content.parse::<Ident>()?;
+            let attribute_name = &*ident.to_string();
+            match attribute_name {
+                "content_type" => {
+                    encoding.content_type =
+                        Some(parse_utils::parse_next(&content, || content.parse())?)
+                }
+                "style" => {
+                    encoding.style = Some(parse_utils::parse_next(&content, || content.parse())?)
+                }
+                "explode" => encoding.explode = Some(parse_utils::parse_bool_or_true(&content)?),
+                "allow_reserved" => {
+                    encoding.allow_reserved = Some(parse_utils::parse_bool_or_true(&content)?)
+                }
+                _ => {
+                    return Err(
+                        Error::new(
+                            ident.span(),
+                            format!("unexpected attribute: {attribute_name}, expected one of: content_type, style, explode, allow_reserved")
+                        )
+                    )
+                }
+            }
+
+            if !content.is_empty() {
+                content.parse::<Comma>()?;
+            }
+        }
+
+        Ok(encoding)
+    }
+}
+
+impl ToTokens for Encoding {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let content_type = self
+            .content_type
+            .as_ref()
+            .map(|content_type| quote!(.content_type(Some(#content_type))));
+        let style = self
+            .style
+            .as_ref()
+            .map(|style| quote!(.style(Some(#style))));
+        let explode = self.explode.map(|explode| quote!(.explode(Some(#explode))));
+        let allow_reserved = self
+            .allow_reserved
+            .map(|allow_reserved| quote!(.allow_reserved(Some(#allow_reserved))));
+
+        tokens.extend(quote! {
+            utoipa::openapi::encoding::EncodingBuilder::new()
+                #content_type
+                #style
+                #explode
+                #allow_reserved
+        })
+    }
+}
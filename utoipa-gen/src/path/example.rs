@@ -7,10 +7,10 @@ use syn::{parenthesized, Error, LitStr, Token};
 use crate::{parse_utils, AnyValue};
 
 // (name = (summary = "...", description = "...", value = "..", external_value = "..."))
-#[derive(Default)]
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub(super) struct Example {
-    pub(super) name: String,
+pub(crate) struct Example {
+    pub(crate) name: String,
     pub(super) summary: Option<String>,
     pub(super) description: Option<String>,
     pub(super) value: Option<AnyValue>,
@@ -21,8 +21,9 @@ impl Parse for Example {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let example_stream;
         parenthesized!(example_stream in input);
+        let name = example_stream.parse::<LitStr>()?;
         let mut example = Example {
-            name: example_stream.parse::<LitStr>()?.value(),
+            name: name.value(),
             ..Default::default()
         };
         example_stream.parse::<Token![=]>()?;
@@ -72,6 +73,16 @@ impl Parse for Example {
             }
         }
 
+        if example.value.is_some() && example.external_value.is_some() {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "example: {} cannot define both `value` and `external_value`, they are mutually exclusive",
+                    example.name
+                ),
+            ));
+        }
+
         Ok(example)
     }
 }
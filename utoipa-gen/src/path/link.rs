@@ -0,0 +1,152 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parenthesized, Error, LitStr, Token};
+
+use crate::{parse_utils, AnyValue};
+
+// ("name" = (operation_id = "...", operation_ref = "...", parameters(("id" = "$request.path.id")), request_body = "...", description = "..."))
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Link {
+    pub(super) name: String,
+    operation_ref: Option<String>,
+    operation_id: Option<String>,
+    parameters: Punctuated<LinkParameter, Comma>,
+    request_body: Option<AnyValue>,
+    description: Option<String>,
+}
+
+impl Parse for Link {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let link_stream;
+        parenthesized!(link_stream in input);
+        let name = link_stream.parse::<LitStr>()?;
+        let mut link = Link {
+            name: name.value(),
+            ..Default::default()
+        };
+        link_stream.parse::<Token![=]>()?;
+
+        let content;
+        parenthesized!(content in link_stream);
+
+        while !content.is_empty() {
+            let ident = content.parse::<Ident>()?;
+            let attribute_name = &*ident.to_string();
+            match attribute_name {
+                "operation_ref" => {
+                    link.operation_ref = Some(
+                        parse_utils::parse_next(&content, || content.parse::<LitStr>())?
+                            .value(),
+                    )
+                }
+                "operation_id" => {
+                    link.operation_id = Some(
+                        parse_utils::parse_next(&content, || content.parse::<LitStr>())?
+                            .value(),
+                    )
+                }
+                "parameters" => {
+                    link.parameters = parse_utils::parse_punctuated_within_parenthesis(&content)?
+                }
+                "request_body" => {
+                    link.request_body = Some(parse_utils::parse_next(&content, || {
+                        AnyValue::parse_any(&content)
+                    })?)
+                }
+                "description" => {
+                    link.description = Some(
+                        parse_utils::parse_next(&content, || content.parse::<LitStr>())?
+                            .value(),
+                    )
+                }
+                _ => {
+                    return Err(
+                        Error::new(
+                            ident.span(),
+                            format!("unexpected attribute: {attribute_name}, expected one of: operation_ref, operation_id, parameters, request_body, description")
+                        )
+                    )
+                }
+            }
+
+            if !content.is_empty() {
+                content.parse::<Comma>()?;
+            }
+        }
+
+        match (&link.operation_ref, &link.operation_id) {
+            (Some(_), Some(_)) => Err(Error::new(
+                name.span(),
+                format!(
+                    "link: {} cannot define both `operation_ref` and `operation_id`, they are mutually exclusive",
+                    link.name
+                ),
+            )),
+            (None, None) => Err(Error::new(
+                name.span(),
+                format!(
+                    "link: {} must define either `operation_ref` or `operation_id`",
+                    link.name
+                ),
+            )),
+            _ => Ok(link),
+        }
+    }
+}
+
+impl ToTokens for Link {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let operation_ref = self
+            .operation_ref
+            .as_ref()
+            .map(|operation_ref| quote!(.operation_ref(#operation_ref)));
+        let operation_id = self
+            .operation_id
+            .as_ref()
+            .map(|operation_id| quote!(.operation_id(#operation_id)));
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|LinkParameter { name, value }| quote!(.parameter(#name, #value)));
+        let request_body = self
+            .request_body
+            .as_ref()
+            .map(|request_body| quote!(.request_body(Some(#request_body))));
+        let description = self
+            .description
+            .as_ref()
+            .map(|description| quote!(.description(#description)));
+
+        tokens.extend(quote! {
+            utoipa::openapi::link::LinkBuilder::new()
+                #operation_ref
+                #operation_id
+                #(#parameters)*
+                #request_body
+                #description
+                .build()
+        })
+    }
+}
+
+// ("id" = "$request.path.id")
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct LinkParameter {
+    name: String,
+    value: AnyValue,
+}
+
+impl Parse for LinkParameter {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name = content.parse::<LitStr>()?.value();
+        let value = parse_utils::parse_next(&content, || AnyValue::parse_any(&content))?;
+
+        Ok(Self { name, value })
+    }
+}
@@ -12,17 +12,18 @@ use crate::{
     component::{
         self,
         features::{
-            impl_into_inner, parse_features, AllowReserved, Description, Example, ExclusiveMaximum,
-            ExclusiveMinimum, Explode, Feature, Format, MaxItems, MaxLength, Maximum, MinItems,
-            MinLength, Minimum, MultipleOf, Nullable, Pattern, ReadOnly, Style, ToTokensExt,
-            WriteOnly, XmlAttr,
+            impl_into_inner, parse_features, pop_feature_as_inner, AllowReserved, Description,
+            EnumValues, Example, Examples, ExclusiveMaximum, ExclusiveMinimum, Explode, Feature,
+            FeaturesExt, Format, MaxItems, MaxLength, Maximum, MinItems, MinLength, Minimum,
+            MultipleOf, Nullable, Pattern, ReadOnly, Style, ToTokensExt, UniqueItems, WriteOnly,
+            XmlAttr,
         },
         ComponentSchema,
     },
     impl_to_tokens_diagnostics, parse_utils, Diagnostics, Required, ToTokensDiagnostics,
 };
 
-use super::InlineType;
+use super::{InlineType, PathTypeTree};
 
 /// Parameter of request such as in path, header, query or cookie
 ///
@@ -46,7 +47,9 @@ pub enum Parameter<'a> {
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
 impl<'p> Parameter<'p> {
     pub fn merge(&mut self, other: Parameter<'p>) {
@@ -60,6 +63,43 @@ impl<'p> Parameter<'p> {
             _ => (),
         }
     }
+
+    /// Check whether the parameter is a value parameter declared in path.
+    pub fn is_path_value(&self) -> bool {
+        matches!(self, Self::Value(value) if value.parameter_in == ParameterIn::Path)
+    }
+
+    /// Check whether the parameter is an [`IntoParams`][utoipa::IntoParams] type resolved from
+    /// a `Path` extractor of the handler function.
+    pub fn is_path_into_params(&self) -> bool {
+        matches!(self, Self::IntoParamsIdent(into_params) if into_params.is_path)
+    }
+}
+
+impl Parameter<'_> {
+    /// Get tokens collecting schemas referenced by the parameter type. Parameters defined with
+    /// `IntoParams` are not collected.
+    pub fn schema_references_tokens(&self) -> Result<TokenStream, Diagnostics> {
+        match self {
+            Self::Value(ValueParameter {
+                parameter_schema: Some(parameter_schema),
+                ..
+            }) => match &parameter_schema.parameter_type {
+                #[cfg(any(
+                    feature = "actix_extras",
+                    feature = "rocket_extras",
+                    feature = "axum_extras",
+                    feature = "poem_extras",
+                    feature = "salvo_extras"
+                ))]
+                ParameterType::External(type_tree) => {
+                    Ok(type_tree.schema_references_tokens(false, ""))
+                }
+                ParameterType::Parsed(inline_type) => inline_type.schema_references_tokens(),
+            },
+            Self::Value(_) | Self::IntoParamsIdent(_) => Ok(TokenStream::new()),
+        }
+    }
 }
 
 impl Parse for Parameter<'_> {
@@ -68,6 +108,14 @@ impl Parse for Parameter<'_> {
             Ok(Self::IntoParamsIdent(IntoParamsIdentParameter {
                 path: Cow::Owned(input.parse::<TypePath>()?.path),
                 parameter_in_fn: None,
+                #[cfg(any(
+                    feature = "actix_extras",
+                    feature = "rocket_extras",
+                    feature = "axum_extras",
+                    feature = "poem_extras",
+                    feature = "salvo_extras"
+                ))]
+                is_path: false,
             }))
         } else {
             Ok(Self::Value(input.parse()?))
@@ -82,6 +130,7 @@ impl ToTokens for Parameter<'_> {
             Parameter::IntoParamsIdent(IntoParamsIdentParameter {
                 path,
                 parameter_in_fn,
+                ..
             }) => {
                 let last_ident = &path.segments.last().unwrap().ident;
 
@@ -102,7 +151,9 @@ impl ToTokens for Parameter<'_> {
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
 impl<'a> From<crate::ext::ValueArgument<'a>> for Parameter<'a> {
     fn from(argument: crate::ext::ValueArgument<'a>) -> Self {
@@ -125,13 +176,16 @@ impl<'a> From<crate::ext::ValueArgument<'a>> for Parameter<'a> {
 #[cfg(any(
     feature = "actix_extras",
     feature = "rocket_extras",
-    feature = "axum_extras"
+    feature = "axum_extras",
+    feature = "poem_extras",
+    feature = "salvo_extras"
 ))]
 impl<'a> From<crate::ext::IntoParamsType<'a>> for Parameter<'a> {
     fn from(value: crate::ext::IntoParamsType<'a>) -> Self {
         Self::IntoParamsIdent(IntoParamsIdentParameter {
             path: value.type_path.expect("IntoParams type must have a path"),
             parameter_in_fn: Some(value.parameter_in_provider),
+            is_path: value.is_path,
         })
     }
 }
@@ -142,49 +196,110 @@ struct ParameterSchema<'p> {
     features: Vec<Feature>,
 }
 
+impl ParameterSchema<'_> {
+    /// Parameters are required by default unless the type is an _`Option`_.
+    fn required(&self) -> Result<Required, Diagnostics> {
+        let is_option = match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras",
+                feature = "poem_extras",
+                feature = "salvo_extras"
+            ))]
+            ParameterType::External(type_tree) => type_tree.is_option(),
+            ParameterType::Parsed(inline_type) => inline_type.as_type_tree()?.is_option(),
+        };
+
+        Ok((!is_option).into())
+    }
+
+    fn is_array(&self) -> Result<bool, Diagnostics> {
+        match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras",
+                feature = "poem_extras",
+                feature = "salvo_extras"
+            ))]
+            ParameterType::External(type_tree) => Ok(type_tree.is_array()),
+            ParameterType::Parsed(inline_type) => Ok(inline_type.as_type_tree()?.is_array()),
+        }
+    }
+}
+
+/// Default style of array query parameters is exploded _`Form`_ which represents repeated query
+/// parameters e.g. _`?tag=a&tag=b`_. Nothing is returned when style is explicitly defined and
+/// explicitly defined explode takes precedence over the default one.
+///
+/// The `is_query` is tokens of `bool` expression resolved at runtime or `None` if the parameter
+/// is known to be a query parameter.
+pub fn array_query_style_tokens(
+    features: &[Feature],
+    is_query: Option<TokenStream>,
+) -> Option<TokenStream> {
+    if features
+        .iter()
+        .any(|feature| matches!(feature, Feature::Style(_)))
+    {
+        return None;
+    }
+    let has_explode = features
+        .iter()
+        .any(|feature| matches!(feature, Feature::Explode(_)));
+
+    let form = quote! { utoipa::openapi::path::ParameterStyle::Form };
+    Some(match is_query {
+        None => {
+            let explode = (!has_explode).then(|| quote! { .explode(Some(true)) });
+            quote! { .style(Some(#form)) #explode }
+        }
+        Some(is_query) => {
+            let explode = (!has_explode).then(|| quote! { .explode((#is_query).then_some(true)) });
+            quote! { .style((#is_query).then_some(#form)) #explode }
+        }
+    })
+}
+
 impl ToTokensDiagnostics for ParameterSchema<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        let mut to_tokens = |param_schema, required| {
-            tokens.extend(quote! { .schema(Some(#param_schema)).required(#required) });
+        let mut to_tokens = |param_schema| {
+            tokens.extend(quote! { .schema(Some(#param_schema)) });
         };
 
         match &self.parameter_type {
             #[cfg(any(
                 feature = "actix_extras",
                 feature = "rocket_extras",
-                feature = "axum_extras"
+                feature = "axum_extras",
+                feature = "poem_extras",
+                feature = "salvo_extras"
             ))]
-            ParameterType::External(type_tree) => {
-                let required: Required = (!type_tree.is_option()).into();
-
-                Ok(to_tokens(
-                    ComponentSchema::new(component::ComponentSchemaProps {
-                        type_tree,
-                        features: Some(self.features.clone()),
-                        description: None,
-                        deprecated: None,
-                        object_name: "",
-                    }),
-                    required,
-                ))
-            }
+            ParameterType::External(type_tree) => Ok(to_tokens(ComponentSchema::new(
+                component::ComponentSchemaProps {
+                    type_tree,
+                    features: Some(self.features.clone()),
+                    description: None,
+                    deprecated: None,
+                    object_name: "",
+                },
+            ))),
             ParameterType::Parsed(inline_type) => {
                 let type_tree = inline_type.as_type_tree()?;
-                let required: Required = (!type_tree.is_option()).into();
                 let mut schema_features = Vec::<Feature>::new();
                 schema_features.clone_from(&self.features);
                 schema_features.push(Feature::Inline(inline_type.is_inline.into()));
 
-                Ok(to_tokens(
-                    ComponentSchema::new(component::ComponentSchemaProps {
+                Ok(to_tokens(ComponentSchema::new(
+                    component::ComponentSchemaProps {
                         type_tree: &type_tree,
                         features: Some(schema_features),
                         description: None,
                         deprecated: None,
                         object_name: "",
-                    }),
-                    required,
-                ))
+                    },
+                )))
             }
         }
     }
@@ -195,7 +310,9 @@ enum ParameterType<'p> {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     External(crate::component::TypeTree<'p>),
     Parsed(InlineType<'p>),
@@ -208,6 +325,8 @@ pub struct ValueParameter<'a> {
     parameter_in: ParameterIn,
     parameter_schema: Option<ParameterSchema<'a>>,
     features: (Vec<Feature>, Vec<Feature>),
+    /// Explicitly defined required status overriding the one resolved from the parameter type.
+    required: Option<Required>,
 }
 
 impl PartialEq for ValueParameter<'_> {
@@ -247,17 +366,52 @@ impl Parse for ValueParameter<'_> {
             return Err(input.error("unparsable parameter name, expected literal string"));
         }
 
-        input.parse::<Token![,]>()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
 
+        // parameter in is only known if explicitly defined, otherwise it might be resolved later
+        // from the framework extractors
+        let mut explicit_parameter_in = None;
         if input.fork().parse::<ParameterIn>().is_ok() {
             parameter.parameter_in = input.parse()?;
-            input.parse::<Token![,]>()?;
+            explicit_parameter_in = Some(parameter.parameter_in);
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
         }
 
         let (schema_features, parameter_features) = input
             .parse::<ParameterFeatures>()?
             .split_for_parameter_type();
 
+        let mut parameter_features = parameter_features;
+        parameter.required =
+            pop_feature_as_inner!(parameter_features => Feature::Required(_v)).map(Required::from);
+        if let (Some(Feature::AllowReserved(allow_reserved)), Some(parameter_in)) = (
+            parameter_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::AllowReserved(_))),
+            &explicit_parameter_in,
+        ) {
+            allow_reserved.validate_parameter_in(parameter_in)?;
+        }
+        if let Some(Feature::Style(style)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::Style(_)))
+        {
+            if let Some(parameter_in) = &explicit_parameter_in {
+                style.validate_parameter_in(parameter_in)?;
+            }
+            let explode = style.default_explode();
+            if !parameter_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Explode(_)))
+            {
+                parameter_features.push(explode.into());
+            }
+        }
+
         parameter.features = (schema_features.clone(), parameter_features);
         if let Some(parameter_schema) = &mut parameter.parameter_schema {
             parameter_schema.features = schema_features;
@@ -279,7 +433,9 @@ impl Parse for ParameterFeatures {
             Explode,
             AllowReserved,
             Example,
+            Examples,
             crate::component::features::Deprecated,
+            crate::component::features::Required,
             Description,
             // param schema features
             Format,
@@ -296,7 +452,9 @@ impl Parse for ParameterFeatures {
             MinLength,
             Pattern,
             MaxItems,
-            MinItems
+            MinItems,
+            UniqueItems,
+            EnumValues
         )))
     }
 }
@@ -325,7 +483,9 @@ impl ParameterFeatures {
                     | Feature::MinLength(_)
                     | Feature::Pattern(_)
                     | Feature::MaxItems(_)
-                    | Feature::MinItems(_) => {
+                    | Feature::MinItems(_)
+                    | Feature::UniqueItems(_)
+                    | Feature::EnumValues(_) => {
                         schema_features.push(feature);
                     }
                     _ => {
@@ -354,6 +514,14 @@ impl ValueParameter<'_> {
 
         tokens.extend(param_features.to_token_stream());
 
+        if self.parameter_in == ParameterIn::Query {
+            if let Some(parameter_schema) = &self.parameter_schema {
+                if parameter_schema.is_array()? {
+                    tokens.extend(array_query_style_tokens(param_features, None));
+                }
+            }
+        }
+
         if !schema_features.is_empty() && self.parameter_schema.is_none() {
             return Err(
                 Diagnostics::new("Missing `parameter_type` attribute, cannot define schema features without it.")
@@ -365,6 +533,21 @@ impl ValueParameter<'_> {
             parameter_schema.to_tokens(tokens)?;
         }
 
+        // OpenAPI requires path parameters to be always required
+        let required = if self.parameter_in == ParameterIn::Path {
+            Some(Required::True)
+        } else if let Some(required) = &self.required {
+            Some(*required)
+        } else {
+            self.parameter_schema
+                .as_ref()
+                .map(ParameterSchema::required)
+                .transpose()?
+        };
+        if let Some(required) = required {
+            tokens.extend(quote! { .required(#required) });
+        }
+
         Ok(())
     }
 }
@@ -382,6 +565,15 @@ pub struct IntoParamsIdentParameter<'i> {
     pub path: Cow<'i, syn::Path>,
     /// quote!{ ... } of function which should implement `parameter_in_provider` for [`utoipa::IntoParams::into_param`]
     parameter_in_fn: Option<TokenStream>,
+    /// Whether the type is resolved from a `Path` extractor of the handler function.
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
+    ))]
+    is_path: bool,
 }
 
 // Compare paths loosely only by segment idents ignoring possible generics
@@ -446,10 +638,10 @@ impl Parse for ParameterIn {
         let style = input.parse::<Ident>()?;
 
         match &*style.to_string() {
-            "Path" => Ok(Self::Path),
-            "Query" => Ok(Self::Query),
-            "Header" => Ok(Self::Header),
-            "Cookie" => Ok(Self::Cookie),
+            "Path" | "path" => Ok(Self::Path),
+            "Query" | "query" => Ok(Self::Query),
+            "Header" | "header" => Ok(Self::Header),
+            "Cookie" | "cookie" => Ok(Self::Cookie),
             _ => Err(Error::new(style.span(), expected_style())),
         }
     }
@@ -467,7 +659,7 @@ impl ToTokens for ParameterIn {
 }
 
 /// See definitions from `utoipa` crate path.rs
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum ParameterStyle {
     Matrix,
@@ -479,9 +671,50 @@ pub enum ParameterStyle {
     DeepObject,
 }
 
+impl ParameterStyle {
+    /// Get [`ParameterIn`]s the style is allowed to be used with.
+    pub fn allowed_parameter_in(&self) -> &'static [ParameterIn] {
+        match self {
+            Self::Matrix | Self::Label => &[ParameterIn::Path],
+            Self::Form => &[ParameterIn::Query, ParameterIn::Cookie],
+            Self::Simple => &[ParameterIn::Path, ParameterIn::Header],
+            Self::SpaceDelimited | Self::PipeDelimited | Self::DeepObject => &[ParameterIn::Query],
+        }
+    }
+}
+
+impl Display for ParameterStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterStyle::Matrix => write!(f, "Matrix"),
+            ParameterStyle::Label => write!(f, "Label"),
+            ParameterStyle::Form => write!(f, "Form"),
+            ParameterStyle::Simple => write!(f, "Simple"),
+            ParameterStyle::SpaceDelimited => write!(f, "SpaceDelimited"),
+            ParameterStyle::PipeDelimited => write!(f, "PipeDelimited"),
+            ParameterStyle::DeepObject => write!(f, "DeepObject"),
+        }
+    }
+}
+
 impl Parse for ParameterStyle {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_STYLE: &str =  "unexpected style, expected one of: Matrix, Label, Form, Simple, SpaceDelimited, PipeDelimited, DeepObject";
+
+        // style can be given either as variant name `Form` or as OpenAPI string value `"form"`
+        if input.peek(LitStr) {
+            let style = input.parse::<LitStr>()?;
+            return match &*style.value() {
+                "matrix" => Ok(ParameterStyle::Matrix),
+                "label" => Ok(ParameterStyle::Label),
+                "form" => Ok(ParameterStyle::Form),
+                "simple" => Ok(ParameterStyle::Simple),
+                "spaceDelimited" => Ok(ParameterStyle::SpaceDelimited),
+                "pipeDelimited" => Ok(ParameterStyle::PipeDelimited),
+                "deepObject" => Ok(ParameterStyle::DeepObject),
+                _ => Err(Error::new(style.span(), "unexpected style, expected one of: \"matrix\", \"label\", \"form\", \"simple\", \"spaceDelimited\", \"pipeDelimited\", \"deepObject\"")),
+            };
+        }
         let style = input.parse::<Ident>()?;
 
         match &*style.to_string() {
@@ -1,13 +1,15 @@
 use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{parenthesized, parse::Parse, token::Paren, Error, Token};
 
 use crate::component::features::Inline;
-use crate::component::ComponentSchema;
+use crate::component::{ComponentSchema, TypeTree, ValueType};
 use crate::{impl_to_tokens_diagnostics, parse_utils, AnyValue, Array, Diagnostics, Required};
 
+use super::encoding::Encoding;
 use super::example::Example;
 use super::{parse, PathType, PathTypeTree};
 
@@ -17,11 +19,33 @@ pub enum RequestBody<'r> {
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
-        feature = "axum_extras"
+        feature = "axum_extras",
+        feature = "poem_extras",
+        feature = "salvo_extras"
     ))]
     Ext(crate::ext::RequestBody<'r>),
 }
 
+impl RequestBody<'_> {
+    /// Get tokens collecting schemas referenced by the request body type.
+    pub fn schema_references_tokens(&self) -> Result<TokenStream2, Diagnostics> {
+        match self {
+            Self::Parsed(parsed) => parsed.content.as_ref().map_or_else(
+                || Ok(TokenStream2::new()),
+                PathType::schema_references_tokens,
+            ),
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras",
+                feature = "poem_extras",
+                feature = "salvo_extras"
+            ))]
+            Self::Ext(ext) => Ok(ext.schema_references_tokens()),
+        }
+    }
+}
+
 impl ToTokens for RequestBody<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         match self {
@@ -29,7 +53,9 @@ impl ToTokens for RequestBody<'_> {
             #[cfg(any(
                 feature = "actix_extras",
                 feature = "rocket_extras",
-                feature = "axum_extras"
+                feature = "axum_extras",
+                feature = "poem_extras",
+                feature = "salvo_extras"
             ))]
             Self::Ext(ext) => ToTokens::to_tokens(ext, tokens),
         }
@@ -44,6 +70,9 @@ impl ToTokens for RequestBody<'_> {
 ///     If not provided really rough guess logic is used. Basically all primitive types are treated as `text/plain`
 ///     and Object types are expected to be `application/json` by default.
 ///   * **description** Additional description for request body content type.
+///   * **required** Override whether the request body is required. By default request body is
+///     required unless the content type is `Option<type>`.
+///   * **encoding** Encoding of the request body properties e.g. for `multipart/form-data`.
 /// # Examples
 ///
 /// Request body in path with all supported info. Where content type is treated as a String and expected
@@ -79,14 +108,16 @@ pub struct RequestBodyAttr<'r> {
     content: Option<PathType<'r>>,
     content_type: Vec<parse_utils::Value>,
     description: Option<parse_utils::Value>,
+    required: Option<bool>,
     example: Option<AnyValue>,
     examples: Option<Punctuated<Example, Comma>>,
+    encoding: Option<Punctuated<Encoding, Comma>>,
 }
 
 impl Parse for RequestBodyAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: content, content_type, description, examples";
+            "unexpected attribute, expected any of: content, content_type, description, required, example, examples, encoding";
         let lookahead = input.lookahead1();
 
         if lookahead.peek(Paren) {
@@ -119,12 +150,19 @@ impl Parse for RequestBodyAttr<'_> {
                     "description" => {
                         request_body_attr.description = Some(parse::description(&group)?);
                     }
+                    "required" => {
+                        request_body_attr.required = Some(parse_utils::parse_bool_or_true(&group)?);
+                    }
                     "example" => {
                         request_body_attr.example = Some(parse::example(&group)?);
                     }
                     "examples" => {
                         request_body_attr.examples = Some(parse::examples(&group)?);
                     }
+                    "encoding" => {
+                        request_body_attr.encoding =
+                            Some(parse_utils::parse_punctuated_within_parenthesis(&group)?);
+                    }
                     _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
                 }
 
@@ -163,7 +201,7 @@ impl RequestBodyAttr<'_> {
                     let type_tree = body_type.as_type_tree()?;
                     ComponentSchema::new(crate::component::ComponentSchemaProps {
                         type_tree: &type_tree,
-                        features: Some(vec![Inline::from(body_type.is_inline).into()]),
+                        features: Some(vec![Inline::request(body_type.is_inline).into()]),
                         description: None,
                         deprecated: None,
                         object_name: "",
@@ -194,41 +232,69 @@ impl RequestBodyAttr<'_> {
                     .examples_from_iter(#examples)
                 ))
             }
+            if let Some(ref encoding) = self.encoding {
+                encoding.iter().for_each(|encoding| {
+                    let name = &encoding.name;
+                    content.extend(quote! {
+                        .encoding(#name, #encoding)
+                    })
+                });
+            }
 
-            match body_type {
-                PathType::Ref(_) => {
-                    tokens.extend(quote! {
-                        utoipa::openapi::request_body::RequestBodyBuilder::new()
-                            .content("application/json", #content.build())
-                    });
-                }
+            let (default_content_type, required) = match body_type {
+                PathType::Ref(_) => (quote!("application/json"), self.required),
                 PathType::MediaType(body_type) => {
                     let type_tree = body_type.as_type_tree()?;
-                    let required: Required = (!type_tree.is_option()).into();
-                    let content_types = if self.content_type.is_empty() {
-                        let content_type = type_tree.get_default_content_type();
-                        vec![quote!(#content_type)]
-                    } else {
-                        self.content_type
-                            .iter()
-                            .map(|content_type| content_type.to_token_stream())
-                            .collect()
+                    let default_content_type = type_tree.get_default_content_type();
+                    let default_content_type = match schema_type_path(&type_tree) {
+                        Some(path) => quote_spanned! {path.span()=>
+                            {
+                                #[allow(unused_imports)]
+                                use utoipa::__dev::{CollectNothing as _, CollectSchema as _};
+                                (&utoipa::__dev::SchemaCollector::<#path>::new())
+                                    .content_type()
+                                    .unwrap_or(#default_content_type)
+                            }
+                        },
+                        None => quote!(#default_content_type),
                     };
-
-                    tokens.extend(quote! {
-                        utoipa::openapi::request_body::RequestBodyBuilder::new()
-                            .required(Some(#required))
-                    });
-
-                    for content_type in content_types {
-                        tokens.extend(quote! {
-                            .content(#content_type, #content.build())
-                        });
-                    }
+                    (
+                        default_content_type,
+                        Some(self.required.unwrap_or(!type_tree.is_option())),
+                    )
                 }
-                PathType::InlineSchema(_, _) => {
-                    unreachable!("PathType::InlineSchema is not implemented for RequestBodyAttr");
+                PathType::InlineSchema(_, ty) => {
+                    let type_tree = TypeTree::from_type(ty)?;
+                    let default_content_type = type_tree.get_default_content_type();
+                    (
+                        quote!(#default_content_type),
+                        Some(self.required.unwrap_or(!type_tree.is_option())),
+                    )
                 }
+            };
+            let content_types = if self.content_type.is_empty() {
+                vec![quote!(#default_content_type)]
+            } else {
+                self.content_type
+                    .iter()
+                    .map(|content_type| content_type.to_token_stream())
+                    .collect()
+            };
+
+            tokens.extend(quote! {
+                utoipa::openapi::request_body::RequestBodyBuilder::new()
+            });
+            if let Some(required) = required {
+                let required: Required = required.into();
+                tokens.extend(quote! {
+                    .required(Some(#required))
+                });
+            }
+
+            for content_type in content_types {
+                tokens.extend(quote! {
+                    .content(#content_type, #content.build())
+                });
             }
         }
 
@@ -244,6 +310,29 @@ impl RequestBodyAttr<'_> {
     }
 }
 
+/// Resolve path of the [`ToSchema`][to_schema] type of the request body which may define the
+/// default content type of the request body. Only non generic object types are resolved, content
+/// of optional and smart pointer types is resolved by the type within.
+///
+/// [to_schema]: https://docs.rs/utoipa/latest/utoipa/trait.ToSchema.html
+fn schema_type_path<'t>(type_tree: &'t TypeTree<'t>) -> Option<&'t syn::Path> {
+    if type_tree.is_option() || type_tree.is_smart_pointer() {
+        return type_tree
+            .children
+            .as_ref()
+            .and_then(|children| children.first())
+            .and_then(schema_type_path);
+    }
+
+    let is_schema_type = type_tree.value_type == ValueType::Object
+        && type_tree.generic_type.is_none()
+        && type_tree.children.is_none()
+        && !type_tree.is_object()
+        && !type_tree.is_value();
+
+    type_tree.path.as_deref().filter(|_| is_schema_type)
+}
+
 impl_to_tokens_diagnostics! {
     impl ToTokensDiagnostics for RequestBodyAttr<'_> {
         fn to_tokens(&self, tokens: &mut TokenStream2) -> Result<(), Diagnostics> {
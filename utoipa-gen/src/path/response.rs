@@ -1,4 +1,4 @@
-use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use proc_macro2::{Group, Ident, Span, TokenStream as TokenStream2};
 use quote::{quote, quote_spanned, ToTokens};
 use std::borrow::Cow;
 use syn::{
@@ -6,7 +6,7 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    token::Comma,
+    token::{Comma, Paren},
     Attribute, Error, ExprPath, LitInt, LitStr, Token, TypePath,
 };
 
@@ -15,10 +15,14 @@ use crate::{
         features::{impl_merge, Inline},
         ComponentSchema, TypeTree,
     },
-    impl_to_tokens_diagnostics, parse_utils, AnyValue, Array, Diagnostics,
+    impl_to_tokens_diagnostics, parse_utils, AnyValue, Array, Deprecated, Diagnostics, Extensions,
+    ExternalDocs, Required,
 };
 
-use super::{example::Example, parse, status::STATUS_CODES, InlineType, PathType, PathTypeTree};
+use super::{
+    example::Example, link::Link, parse, status::STATUS_CODES, DeprecatedNote, InlineType,
+    PathType, PathTypeTree,
+};
 
 pub mod derive;
 
@@ -28,11 +32,60 @@ pub enum Response<'r> {
     IntoResponses(Cow<'r, TypePath>),
     /// The tuple definition of a response.
     Tuple(ResponseTuple<'r>),
+    /// Response which is only included when the `cfg(...)` predicate holds.
+    Cfg(TokenStream2, Box<Response<'r>>),
+}
+
+impl Response<'_> {
+    /// Get tokens collecting schemas referenced by the response body types. Responses defined
+    /// with `IntoResponses` are collected with `IntoResponses::schemas`. Responses defined with
+    /// `response = ...` are not collected.
+    pub fn schema_references_tokens(&self) -> Result<TokenStream2, Diagnostics> {
+        match self {
+            Self::Tuple(response) => response.schema_references_tokens(),
+            Self::IntoResponses(path) => Ok(quote! {
+                <#path as utoipa::IntoResponses>::schemas(schemas);
+            }),
+            Self::Cfg(predicate, response) => {
+                let references = response.schema_references_tokens()?;
+                if references.is_empty() {
+                    Ok(references)
+                } else {
+                    Ok(quote! { #[cfg(#predicate)] { #references } })
+                }
+            }
+        }
+    }
+
+    fn cfg_predicates(&self) -> Vec<&TokenStream2> {
+        match self {
+            Self::Cfg(predicate, response) => {
+                let mut predicates = vec![predicate];
+                predicates.extend(response.cfg_predicates());
+                predicates
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl Parse for Response<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.fork().parse::<ExprPath>().is_ok() {
+        let fork = input.fork();
+        if matches!(fork.parse::<Ident>(), Ok(ident) if ident == "cfg") && fork.peek(Paren) {
+            input.parse::<Ident>()?;
+            let predicate;
+            parenthesized!(predicate in input);
+            let predicate = predicate.parse::<TokenStream2>()?;
+            if predicate.is_empty() {
+                return Err(Error::new(
+                    input.span(),
+                    "expected cfg predicate, e.g. cfg(feature = \"...\")",
+                ));
+            }
+
+            Ok(Self::Cfg(predicate, Box::new(input.parse()?)))
+        } else if input.fork().parse::<ExprPath>().is_ok() {
             Ok(Self::IntoResponses(Cow::Owned(input.parse::<TypePath>()?)))
         } else {
             let response;
@@ -77,6 +130,19 @@ impl<'r> ResponseTuple<'r> {
         }
         Ok(())
     }
+
+    /// Get tokens collecting schemas referenced by the response body types.
+    pub fn schema_references_tokens(&self) -> Result<TokenStream2, Diagnostics> {
+        match &self.inner {
+            Some(ResponseTupleInner::Value(value)) => value
+                .response_type
+                .iter()
+                .chain(value.content.iter().map(|content| &content.1))
+                .map(PathType::schema_references_tokens)
+                .collect(),
+            _ => Ok(TokenStream2::new()),
+        }
+    }
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -87,9 +153,10 @@ enum ResponseTupleInner<'r> {
 
 impl Parse for ResponseTuple<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: status, description, body, content_type, headers, example, examples, response";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: status, description, body, content_type, events, json_primitive, headers, example, examples, links, response, deprecated, external_docs, extensions";
 
         let mut response = ResponseTuple::default();
+        let mut example_ident: Option<Ident> = None;
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
@@ -116,19 +183,48 @@ impl Parse for ResponseTuple<'_> {
                     response.as_value(input.span())?.content_type =
                         Some(parse::content_type(input)?);
                 }
+                "events" => {
+                    response.as_value(input.span())?.events =
+                        parse_utils::parse_next(input, || {
+                            let events;
+                            syn::bracketed!(events in input);
+                            Punctuated::<LitStr, Comma>::parse_terminated(&events)
+                        })?;
+                }
+                "json_primitive" => {
+                    response.as_value(input.span())?.json_primitive =
+                        parse_utils::parse_bool_or_true(input)?;
+                }
                 "headers" => {
                     response.as_value(input.span())?.headers = headers(input)?;
                 }
                 "example" => {
                     response.as_value(input.span())?.example = Some(parse::example(input)?);
+                    example_ident = Some(ident);
                 }
                 "examples" => {
                     response.as_value(input.span())?.examples = Some(parse::examples(input)?);
+                    example_ident = Some(ident);
                 }
                 "content" => {
                     response.as_value(input.span())?.content =
                         parse_utils::parse_punctuated_within_parenthesis(input)?;
                 }
+                "links" => {
+                    response.as_value(input.span())?.links = links(input)?;
+                }
+                "deprecated" => {
+                    response.as_value(input.span())?.deprecated =
+                        Some(parse::deprecated_note(input)?);
+                }
+                "external_docs" => {
+                    response.as_value(input.span())?.external_docs = Some(external_docs(input)?);
+                }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    response.as_value(input.span())?.extensions = Some(extensions.parse()?);
+                }
                 "response" => {
                     response.set_ref_type(
                         input.span(),
@@ -147,6 +243,17 @@ impl Parse for ResponseTuple<'_> {
             response.inner = Some(ResponseTupleInner::Value(ResponseValue::default()))
         }
 
+        if let (Some(ident), Some(ResponseTupleInner::Value(value))) =
+            (example_ident, &response.inner)
+        {
+            if value.response_type.is_none() && !value.has_text_content_type() {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("`{ident}` requires `body` or text `content_type` to be defined, add `body = ...` to the response or use `content(...)` instead"),
+                ));
+            }
+        }
+
         Ok(response)
     }
 }
@@ -204,10 +311,16 @@ pub struct ResponseValue<'r> {
     description: parse_utils::Value,
     response_type: Option<PathType<'r>>,
     content_type: Option<Vec<parse_utils::Value>>,
-    headers: Vec<Header>,
+    events: Punctuated<LitStr, Comma>,
+    json_primitive: bool,
+    headers: Vec<ResponseHeader>,
     example: Option<AnyValue>,
     examples: Option<Punctuated<Example, Comma>>,
     content: Punctuated<Content<'r>, Comma>,
+    links: Punctuated<Link, Comma>,
+    deprecated: Option<DeprecatedNote>,
+    external_docs: Option<ExternalDocs>,
+    extensions: Option<Extensions>,
 }
 
 impl<'r> ResponseValue<'r> {
@@ -225,6 +338,8 @@ impl<'r> ResponseValue<'r> {
             example: derive_value.example.map(|(example, _)| example),
             examples: derive_value.examples.map(|(examples, _)| examples),
             content_type: derive_value.content_type,
+            deprecated: derive_value.deprecated,
+            external_docs: derive_value.external_docs,
             ..Default::default()
         }
     }
@@ -243,6 +358,8 @@ impl<'r> ResponseValue<'r> {
             example: response_value.example.map(|(example, _)| example),
             examples: response_value.examples.map(|(examples, _)| examples),
             content_type: response_value.content_type,
+            deprecated: response_value.deprecated,
+            external_docs: response_value.external_docs,
             ..Default::default()
         }
     }
@@ -252,6 +369,31 @@ impl<'r> ResponseValue<'r> {
 
         self
     }
+
+    /// Check whether any of the content types is a text content type which is documented as
+    /// a plain string when response has no `body`.
+    fn has_text_content_type(&self) -> bool {
+        self.content_type.iter().flatten().any(is_text_content_type)
+    }
+}
+
+/// Check whether `content_type` is literal _`text/plain`_ or _`text/html`_ content type. Content
+/// type parameters such as _`charset`_ are ignored.
+fn is_text_content_type(content_type: &parse_utils::Value) -> bool {
+    match content_type {
+        parse_utils::Value::LitStr(content_type) => {
+            let content_type = content_type.value();
+            let media_type = content_type
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase();
+
+            matches!(&*media_type, "text/plain" | "text/html")
+        }
+        _ => false,
+    }
 }
 
 impl ResponseTuple<'_> {
@@ -270,14 +412,40 @@ impl ResponseTuple<'_> {
                 }
             }
             ResponseTupleInner::Value(val) => {
-                let description = &val.description;
+                // OpenAPI does not support deprecated responses nor external docs of responses
+                // thus they are appended to the description
+                let note = val
+                    .deprecated
+                    .as_ref()
+                    .and_then(DeprecatedNote::description)
+                    .into_iter()
+                    .chain(val.external_docs.as_ref().map(external_docs_link))
+                    .collect::<Vec<_>>();
+                let description = match (!note.is_empty()).then(|| note.join("\n\n")) {
+                    Some(note) => match &val.description {
+                        parse_utils::Value::LitStr(description)
+                            if description.value().is_empty() =>
+                        {
+                            note.to_token_stream()
+                        }
+                        parse_utils::Value::LitStr(description) => {
+                            format!("{}\n\n{note}", description.value()).to_token_stream()
+                        }
+                        parse_utils::Value::Expr(description) => {
+                            quote! { format!("{}\n\n{}", #description, #note) }
+                        }
+                    },
+                    None => val.description.to_token_stream(),
+                };
+
                 tokens.extend(quote! {
                     utoipa::openapi::ResponseBuilder::new().description(#description)
                 });
 
                 let create_content = |path_type: &PathType,
                                       example: &Option<AnyValue>,
-                                      examples: &Option<Punctuated<Example, Comma>>|
+                                      examples: &Option<Punctuated<Example, Comma>>,
+                                      events: &Punctuated<LitStr, Comma>|
                  -> Result<TokenStream2, Diagnostics> {
                     let content_schema = match path_type {
                         PathType::Ref(ref_type) => quote! {
@@ -289,7 +457,7 @@ impl ResponseTuple<'_> {
 
                             ComponentSchema::new(crate::component::ComponentSchemaProps {
                                 type_tree: &type_tree,
-                                features: Some(vec![Inline::from(path_type.is_inline).into()]),
+                                features: Some(vec![Inline::response(path_type.is_inline).into()]),
                                 description: None,
                                 deprecated: None,
                                 object_name: "",
@@ -302,10 +470,15 @@ impl ResponseTuple<'_> {
                     let mut content =
                         quote! { utoipa::openapi::ContentBuilder::new().schema(#content_schema) };
 
-                    if let Some(ref example) = example {
-                        content.extend(quote! {
+                    match example {
+                        // string literal example e.g. of text content is a JSON string
+                        Some(AnyValue::String(example)) => content.extend(quote! {
+                            .example(Some(serde_json::Value::String(#example.into())))
+                        }),
+                        Some(example) => content.extend(quote! {
                             .example(Some(#example))
-                        })
+                        }),
+                        None => (),
                     }
                     if let Some(ref examples) = examples {
                         let examples = examples
@@ -319,6 +492,16 @@ impl ResponseTuple<'_> {
                             .examples_from_iter(#examples)
                         ))
                     }
+                    // names of the events of server-sent events stream
+                    if !events.is_empty() {
+                        let events = events.iter();
+                        content.extend(quote! {
+                            .extensions(Some(std::collections::HashMap::<String, serde_json::Value>::from([(
+                                String::from("x-events"),
+                                serde_json::json!([#(#events),*]),
+                            )])))
+                        })
+                    }
 
                     Ok(quote! {
                         #content.build()
@@ -326,7 +509,8 @@ impl ResponseTuple<'_> {
                 };
 
                 if let Some(response_type) = &val.response_type {
-                    let content = create_content(response_type, &val.example, &val.examples)?;
+                    let content =
+                        create_content(response_type, &val.example, &val.examples, &val.events)?;
 
                     if let Some(content_types) = val.content_type.as_ref() {
                         content_types.iter().for_each(|content_type| {
@@ -334,6 +518,10 @@ impl ResponseTuple<'_> {
                                 .content(#content_type, #content)
                             })
                         })
+                    } else if !val.events.is_empty() {
+                        tokens.extend(quote! {
+                            .content("text/event-stream", #content)
+                        });
                     } else {
                         match response_type {
                             PathType::Ref(_) => {
@@ -341,6 +529,13 @@ impl ResponseTuple<'_> {
                                     .content("application/json", #content)
                                 });
                             }
+                            PathType::MediaType(_) | PathType::InlineSchema(_, _)
+                                if val.json_primitive =>
+                            {
+                                tokens.extend(quote! {
+                                    .content("application/json", #content)
+                                });
+                            }
                             PathType::MediaType(path_type) => {
                                 let type_tree = path_type.as_type_tree()?;
                                 let default_type = type_tree.get_default_content_type();
@@ -357,12 +552,29 @@ impl ResponseTuple<'_> {
                             }
                         }
                     }
+                } else if let Some(content_types) = val.content_type.as_ref() {
+                    // text content without body is a plain string
+                    let string_type = PathType::MediaType(InlineType {
+                        ty: Cow::Owned(syn::parse_quote!(String)),
+                        is_inline: false,
+                    });
+                    let content =
+                        create_content(&string_type, &val.example, &val.examples, &val.events)?;
+
+                    content_types
+                        .iter()
+                        .filter(|content_type| is_text_content_type(content_type))
+                        .for_each(|content_type| {
+                            tokens.extend(quote! {
+                                .content(#content_type, #content)
+                            })
+                        });
                 }
 
                 val.content
                     .iter()
                     .map(|Content(content_type, body, example, examples)| {
-                        match create_content(body, example, examples) {
+                        match create_content(body, example, examples, &Punctuated::new()) {
                             Ok(content) => Ok((Cow::Borrowed(&**content_type), content)),
                             Err(diagnostics) => Err(diagnostics),
                         }
@@ -373,13 +585,31 @@ impl ResponseTuple<'_> {
                         tokens.extend(quote! { .content(#content_type, #content) })
                     });
 
-                val.headers.iter().for_each(|header| {
-                    let name = &header.name;
+                val.headers.iter().for_each(|header| match header {
+                    ResponseHeader::Header(header) => {
+                        let name = &header.name;
+                        tokens.extend(quote! {
+                            .header(#name, #header)
+                        })
+                    }
+                    ResponseHeader::ToHeader(ty) => tokens.extend(quote! {
+                        .headers_from_iter(<#ty as utoipa::ToHeader>::headers())
+                    }),
+                });
+
+                val.links.iter().for_each(|link| {
+                    let name = &link.name;
                     tokens.extend(quote! {
-                        .header(#name, #header)
+                        .link(#name, #link)
                     })
                 });
 
+                if let Some(extensions) = &val.extensions {
+                    tokens.extend(quote! {
+                        .extensions(Some(#extensions))
+                    })
+                }
+
                 tokens.extend(quote! { .build() });
             }
         }
@@ -414,10 +644,12 @@ trait DeriveResponseValue: Parse {
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct DeriveToResponseValue {
     content_type: Option<Vec<parse_utils::Value>>,
-    headers: Vec<Header>,
+    headers: Vec<ResponseHeader>,
     description: parse_utils::Value,
     example: Option<(AnyValue, Ident)>,
     examples: Option<(Punctuated<Example, Comma>, Ident)>,
+    deprecated: Option<DeprecatedNote>,
+    external_docs: Option<ExternalDocs>,
 }
 
 impl DeriveResponseValue for DeriveToResponseValue {
@@ -437,6 +669,12 @@ impl DeriveResponseValue for DeriveToResponseValue {
         if other.examples.is_some() {
             self.examples = other.examples;
         }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated;
+        }
+        if other.external_docs.is_some() {
+            self.external_docs = other.external_docs;
+        }
 
         self
     }
@@ -466,10 +704,16 @@ impl Parse for DeriveToResponseValue {
                 "examples" => {
                     response.examples = Some((parse::examples(input)?, ident));
                 }
+                "deprecated" => {
+                    response.deprecated = Some(parse::deprecated_note(input)?);
+                }
+                "external_docs" => {
+                    response.external_docs = Some(external_docs(input)?);
+                }
                 _ => {
                     return Err(Error::new(
                         ident.span(),
-                        format!("unexpected attribute: {attribute_name}, expected any of: inline, description, content_type, headers, example"),
+                        format!("unexpected attribute: {attribute_name}, expected any of: inline, description, content_type, headers, example, examples, deprecated, external_docs"),
                     ));
                 }
             }
@@ -487,10 +731,12 @@ impl Parse for DeriveToResponseValue {
 struct DeriveIntoResponsesValue {
     status: ResponseStatus,
     content_type: Option<Vec<parse_utils::Value>>,
-    headers: Vec<Header>,
+    headers: Vec<ResponseHeader>,
     description: parse_utils::Value,
     example: Option<(AnyValue, Ident)>,
     examples: Option<(Punctuated<Example, Comma>, Ident)>,
+    deprecated: Option<DeprecatedNote>,
+    external_docs: Option<ExternalDocs>,
 }
 
 impl DeriveResponseValue for DeriveIntoResponsesValue {
@@ -512,6 +758,12 @@ impl DeriveResponseValue for DeriveIntoResponsesValue {
         if other.examples.is_some() {
             self.examples = other.examples;
         }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated;
+        }
+        if other.external_docs.is_some() {
+            self.external_docs = other.external_docs;
+        }
 
         self
     }
@@ -561,10 +813,16 @@ impl Parse for DeriveIntoResponsesValue {
                 "examples" => {
                     response.examples = Some((parse::examples(input)?, ident));
                 }
+                "deprecated" => {
+                    response.deprecated = Some(parse::deprecated_note(input)?);
+                }
+                "external_docs" => {
+                    response.external_docs = Some(external_docs(input)?);
+                }
                 _ => {
                     return Err(Error::new(
                         ident.span(),
-                        format!("unexpected attribute: {attribute_name}, expected any of: description, content_type, headers, example, examples"),
+                        format!("unexpected attribute: {attribute_name}, expected any of: description, content_type, headers, example, examples, deprecated, external_docs"),
                     ));
                 }
             }
@@ -585,7 +843,16 @@ struct ResponseStatus(TokenStream2);
 impl Parse for ResponseStatus {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         fn parse_lit_int(input: ParseStream) -> syn::Result<Cow<'_, str>> {
-            input.parse::<LitInt>()?.base10_parse().map(Cow::Owned)
+            let lit_int = input.parse::<LitInt>()?;
+            let code = lit_int.base10_parse::<u16>().unwrap_or_default();
+            if (100..600).contains(&code) {
+                Ok(Cow::Owned(code.to_string()))
+            } else {
+                Err(Error::new(
+                    lit_int.span(),
+                    "Invalid status code, expected http status code between 100 and 599",
+                ))
+            }
         }
 
         fn parse_lit_str_status_range(input: ParseStream) -> syn::Result<Cow<'_, str>> {
@@ -595,11 +862,15 @@ impl Parse for ResponseStatus {
                 .parse::<LitStr>()
                 .and_then(|lit_str| {
                     let value = lit_str.value();
-                    if !VALID_STATUS_RANGES.contains(&value.as_str()) {
+                    let is_status_code = value
+                        .parse::<u16>()
+                        .map(|code| (100..600).contains(&code))
+                        .unwrap_or(false);
+                    if !is_status_code && !VALID_STATUS_RANGES.contains(&value.as_str()) {
                         Err(Error::new(
-                            value.span(),
+                            lit_str.span(),
                             format!(
-                                "Invalid status range, expected one of: {}",
+                                "Invalid status range, expected status code or one of: {}",
                                 VALID_STATUS_RANGES.join(", "),
                             ),
                         ))
@@ -618,6 +889,13 @@ impl Parse for ResponseStatus {
                 .last()
                 .expect("Expected at least one segment in http StatusCode");
 
+            let is_http_status_code = http_status_path.path.segments.len() == 1
+                || http_status_path
+                    .path
+                    .segments
+                    .iter()
+                    .any(|segment| segment.ident == "StatusCode");
+
             STATUS_CODES
                 .iter()
                 .find_map(|(code, name)| {
@@ -627,6 +905,12 @@ impl Parse for ResponseStatus {
                         None
                     }
                 })
+                .or_else(|| {
+                    // path to other than http StatusCode is resolved at runtime via `ToResponseStatus`
+                    (!is_http_status_code).then(|| {
+                        quote! { utoipa::ToResponseStatus::to_response_status(&#http_status_path) }
+                    })
+                })
                 .ok_or_else(|| {
                     Error::new(
                         last_segment.span(),
@@ -659,7 +943,8 @@ impl ToTokens for ResponseStatus {
 
 // content(
 //   ("application/json" = Response, example = "...", examples(..., ...)),
-//   ("application/json2" = Response2, example = "...", examples("...", "..."))
+//   ("application/json2" = Response2, example = "...", examples("...", "...")),
+//   (Response3 = "text/xml", example = "...")
 // )
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Content<'c>(
@@ -674,9 +959,16 @@ impl Parse for Content<'_> {
         let content;
         parenthesized!(content in input);
 
-        let content_type = content.parse::<LitStr>()?;
-        content.parse::<Token![=]>()?;
-        let body = content.parse()?;
+        // content can be defined either as `"content/type" = Type` or `Type = "content/type"`
+        let (content_type, body) = if content.peek(LitStr) {
+            let content_type = content.parse::<LitStr>()?;
+            content.parse::<Token![=]>()?;
+            (content_type, content.parse()?)
+        } else {
+            let body = content.parse()?;
+            content.parse::<Token![=]>()?;
+            (content.parse::<LitStr>()?, body)
+        };
         content.parse::<Option<Comma>>()?;
         let mut example = None::<AnyValue>;
         let mut examples = None::<Punctuated<Example, Comma>>;
@@ -714,29 +1006,65 @@ impl Parse for Content<'_> {
 
 pub struct Responses<'a>(pub &'a [Response<'a>]);
 
+impl Responses<'_> {
+    fn response_tokens(response: &Response) -> TokenStream2 {
+        match response {
+            Response::IntoResponses(path) => {
+                let span = path.span();
+                quote_spanned! {span =>
+                    .responses_from_into_responses::<#path>()
+                }
+            }
+            Response::Tuple(response) => {
+                let code = &response.status_code;
+                quote! { .response(#code, #response) }
+            }
+            Response::Cfg(_, response) => Self::response_tokens(response),
+        }
+    }
+}
+
 impl ToTokens for Responses<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        tokens.extend(self.0.iter().fold(
-            quote! { utoipa::openapi::ResponsesBuilder::new() },
-            |mut acc, response| {
-                match response {
-                    Response::IntoResponses(path) => {
-                        let span = path.span();
-                        acc.extend(quote_spanned! {span =>
-                            .responses_from_into_responses::<#path>()
-                        })
-                    }
-                    Response::Tuple(response) => {
-                        let code = &response.status_code;
-                        acc.extend(quote! { .response(#code, #response) });
-                    }
+        if self
+            .0
+            .iter()
+            .any(|response| matches!(response, Response::Cfg(..)))
+        {
+            // cfg attributes cannot be placed within a builder chain, so conditional responses
+            // are added one statement at a time.
+            let responses = self.0.iter().map(|response| {
+                let cfgs = response
+                    .cfg_predicates()
+                    .into_iter()
+                    .map(|predicate| quote! { #[cfg(#predicate)] });
+                let response = Self::response_tokens(response);
+
+                quote! {
+                    #( #cfgs )*
+                    let responses = responses #response;
                 }
+            });
 
-                acc
-            },
-        ));
+            tokens.extend(quote! {
+                {
+                    let responses = utoipa::openapi::ResponsesBuilder::new();
+                    #( #responses )*
+                    responses.build()
+                }
+            });
+        } else {
+            tokens.extend(self.0.iter().fold(
+                quote! { utoipa::openapi::ResponsesBuilder::new() },
+                |mut acc, response| {
+                    acc.extend(Self::response_tokens(response));
 
-        tokens.extend(quote! { .build() });
+                    acc
+                },
+            ));
+
+            tokens.extend(quote! { .build() });
+        }
     }
 }
 
@@ -744,11 +1072,15 @@ impl ToTokens for Responses<'_> {
 ///
 /// Supported configuration format is `("x-my-header-name" = type, description = "optional description of header")`.
 /// The `= type` and the `description = ".."` are optional configurations thus so the same configuration
-/// could be written as follows: `("x-my-header-name")`.
+/// could be written as follows: `("x-my-header-name")`. Optional `required`, `deprecated` and
+/// `example = ...` can be defined after the type as well.
 ///
 /// The `type` can be any typical type supported as a header argument such as `String, i32, u64, bool` etc.
 /// and if not provided it will default to `String`.
 ///
+/// Header can also be a reference to a reusable header component defined in `components/headers`
+/// e.g. `("x-rate-limit" = ref("#/components/headers/RateLimit"))`.
+///
 /// # Examples
 ///
 /// Example of 200 success response which does return nothing back in response body, but returns a
@@ -799,7 +1131,11 @@ impl ToTokens for Responses<'_> {
 struct Header {
     name: String,
     value_type: Option<InlineType<'static>>,
+    reference: Option<String>,
     description: Option<String>,
+    required: Option<bool>,
+    deprecated: Option<bool>,
+    example: Option<AnyValue>,
 }
 
 impl Parse for Header {
@@ -812,6 +1148,15 @@ impl Parse for Header {
         if input.peek(Token![=]) {
             input.parse::<Token![=]>()?;
 
+            if input.peek(Token![ref]) && input.peek2(Paren) {
+                input.parse::<Token![ref]>()?;
+                let reference;
+                parenthesized!(reference in input);
+                header.reference = Some(reference.parse::<LitStr>()?.value());
+
+                return Ok(header);
+            }
+
             header.value_type = Some(input.parse().map_err(|error| {
                 Error::new(
                     error.span(),
@@ -820,30 +1165,35 @@ impl Parse for Header {
             })?);
         }
 
-        if !input.is_empty() {
+        while !input.is_empty() {
             input.parse::<Token![,]>()?;
-        }
+            if input.is_empty() {
+                break;
+            }
 
-        if input.peek(syn::Ident) {
-            input
-                .parse::<Ident>()
-                .map_err(|error| {
-                    Error::new(
-                        error.span(),
-                        format!("unexpected attribute, expected: description, {error}"),
-                    )
-                })
-                .and_then(|ident| {
-                    if ident != "description" {
-                        return Err(Error::new(
-                            ident.span(),
-                            "unexpected attribute, expected: description",
-                        ));
-                    }
-                    Ok(ident)
-                })?;
-            input.parse::<Token![=]>()?;
-            header.description = Some(input.parse::<LitStr>()?.value());
+            const EXPECTED_ATTRIBUTE_MESSAGE: &str =
+                "unexpected attribute, expected any of: description, required, deprecated, example";
+            let ident = input.parse::<Ident>().map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{EXPECTED_ATTRIBUTE_MESSAGE}, {error}"),
+                )
+            })?;
+
+            match &*ident.to_string() {
+                "description" => {
+                    input.parse::<Token![=]>()?;
+                    header.description = Some(input.parse::<LitStr>()?.value());
+                }
+                "required" => header.required = Some(parse_utils::parse_bool_or_true(input)?),
+                "deprecated" => header.deprecated = Some(parse_utils::parse_bool_or_true(input)?),
+                "example" => {
+                    header.example = Some(parse_utils::parse_next(input, || {
+                        AnyValue::parse_any(input)
+                    })?)
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
         }
 
         Ok(header)
@@ -852,6 +1202,13 @@ impl Parse for Header {
 
 impl Header {
     fn tokens_or_diagnostics(&self, tokens: &mut TokenStream2) -> Result<(), Diagnostics> {
+        if let Some(reference) = &self.reference {
+            // reference to reusable header component
+            tokens.extend(quote! { utoipa::openapi::Ref::new(#reference) });
+
+            return Ok(());
+        }
+
         if let Some(header_type) = &self.value_type {
             // header property with custom type
             let type_tree = header_type.as_type_tree()?;
@@ -881,6 +1238,26 @@ impl Header {
             })
         }
 
+        if let Some(required) = self.required {
+            let required = Required::from(required);
+            tokens.extend(quote! {
+                .required(Some(#required))
+            })
+        }
+
+        if let Some(deprecated) = self.deprecated {
+            let deprecated = Deprecated::from(deprecated);
+            tokens.extend(quote! {
+                .deprecated(Some(#deprecated))
+            })
+        }
+
+        if let Some(ref example) = self.example {
+            tokens.extend(quote! {
+                .example(Some(#example))
+            })
+        }
+
         tokens.extend(quote! { .build() });
 
         Ok(())
@@ -895,10 +1272,69 @@ impl_to_tokens_diagnostics! {
     }
 }
 
+/// Response header defined either as a single header tuple `("x-my-header" = ...)` or as a path
+/// to a type implementing `utoipa::ToHeader` which expands into multiple headers.
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum ResponseHeader {
+    Header(Box<Header>),
+    ToHeader(TypePath),
+}
+
+impl Parse for ResponseHeader {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.fork().parse::<TypePath>().is_ok() {
+            Ok(Self::ToHeader(input.parse()?))
+        } else {
+            let group = input.parse::<Group>()?;
+            Ok(Self::Header(Box::new(syn::parse2(group.stream())?)))
+        }
+    }
+}
+
 #[inline]
-fn headers(input: ParseStream) -> syn::Result<Vec<Header>> {
+fn headers(input: ParseStream) -> syn::Result<Vec<ResponseHeader>> {
     let headers;
     syn::parenthesized!(headers in input);
 
-    parse_utils::parse_groups(&headers)
+    Ok(
+        Punctuated::<ResponseHeader, Comma>::parse_terminated(&headers)?
+            .into_iter()
+            .collect(),
+    )
+}
+
+#[inline]
+fn external_docs(input: ParseStream) -> syn::Result<ExternalDocs> {
+    let external_docs;
+    parenthesized!(external_docs in input);
+
+    external_docs.parse()
+}
+
+/// Format external docs as markdown link to be appended to the response description.
+fn external_docs_link(external_docs: &ExternalDocs) -> String {
+    let url = &external_docs.url;
+    match external_docs.description.as_deref() {
+        Some(description) => format!("[{description}]({url})"),
+        None => format!("<{url}>"),
+    }
+}
+
+#[inline]
+fn links(input: ParseStream) -> syn::Result<Punctuated<Link, Comma>> {
+    let span = input.span();
+    let links: Punctuated<Link, Comma> = parse_utils::parse_punctuated_within_parenthesis(input)?;
+
+    let mut names = std::collections::HashSet::new();
+    if let Some(link) = links.iter().find(|link| !names.insert(link.name.as_str())) {
+        return Err(Error::new(
+            span,
+            format!(
+                "duplicate link name: {}, link names must be unique",
+                link.name
+            ),
+        ));
+    }
+
+    Ok(links)
 }
@@ -1,25 +1,27 @@
 use std::borrow::Cow;
-use std::{iter, mem};
+use std::mem;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::parse::ParseStream;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{
-    Attribute, Data, Field, Fields, Generics, Lifetime, LifetimeParam, LitStr, Path, Type,
-    TypePath, Variant,
+    Attribute, Data, Field, Fields, GenericArgument, Generics, Lifetime, LifetimeParam, LitStr,
+    Path, PathArguments, Type, TypePath, Variant,
 };
 
 use crate::component::schema::{EnumSchema, NamedStructSchema};
+use crate::component::serde::RenameRule;
 use crate::doc_comment::CommentAttributes;
 use crate::path::{InlineType, PathType};
-use crate::{impl_to_tokens_diagnostics, parse_utils, Array, Diagnostics, OptionExt};
+use crate::{impl_to_tokens_diagnostics, parse_utils, AnyValue, Array, Diagnostics, OptionExt};
 
 use super::{
     Content, DeriveIntoResponsesValue, DeriveResponseValue, DeriveResponsesAttributes,
-    DeriveToResponseValue, ResponseTuple, ResponseTupleInner, ResponseValue,
+    DeriveToResponseValue, Header, ResponseTuple, ResponseTupleInner, ResponseValue,
 };
 
 pub struct ToResponse<'r> {
@@ -115,11 +117,7 @@ impl IntoResponses {
         let responses = match &self.data {
             Data::Struct(struct_value) => match &struct_value.fields {
                 Fields::Named(fields) => {
-                    let response =
-                        NamedStructResponse::new(&self.attributes, &self.ident, &fields.named)?.0;
-                    let status = &response.status_code;
-
-                    Array::from_iter(iter::once(quote!((#status, #response))))
+                    vec![NamedStructResponse::new(&self.attributes, &self.ident, &fields.named)?.0]
                 }
                 Fields::Unnamed(fields) => {
                     let field = fields
@@ -128,18 +126,9 @@ impl IntoResponses {
                         .next()
                         .expect("Unnamed struct must have 1 field");
 
-                    let response =
-                        UnnamedStructResponse::new(&self.attributes, &field.ty, &field.attrs)?.0;
-                    let status = &response.status_code;
-
-                    Array::from_iter(iter::once(quote!((#status, #response))))
-                }
-                Fields::Unit => {
-                    let response = UnitStructResponse::new(&self.attributes)?.0;
-                    let status = &response.status_code;
-
-                    Array::from_iter(iter::once(quote!((#status, #response))))
+                    vec![UnnamedStructResponse::new(&self.attributes, &field.ty, &field.attrs)?.0]
                 }
+                Fields::Unit => vec![UnitStructResponse::new(&self.attributes)?.0],
             },
             Data::Enum(enum_value) => enum_value
                 .variants
@@ -164,13 +153,7 @@ impl IntoResponses {
                     }
                     Fields::Unit => Ok(UnitStructResponse::new(&variant.attrs)?.0),
                 })
-                .collect::<Result<Array<ResponseTuple>, Diagnostics>>()?
-                .iter()
-                .map(|response| {
-                    let status = &response.status_code;
-                    quote!((#status, utoipa::openapi::RefOr::from(#response)))
-                })
-                .collect(),
+                .collect::<Result<Vec<ResponseTuple>, Diagnostics>>()?,
             Data::Union(_) => {
                 return Err(Diagnostics::with_span(
                     self.ident.span(),
@@ -182,6 +165,23 @@ impl IntoResponses {
         let ident = &self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
+        let schemas = if cfg!(feature = "auto_collect_schemas") {
+            responses
+                .iter()
+                .map(ResponseTuple::schema_references_tokens)
+                .collect::<Result<TokenStream, Diagnostics>>()?
+        } else {
+            TokenStream::new()
+        };
+        let schemas = crate::component::schemas_fn_tokens(schemas);
+
+        let responses = responses
+            .iter()
+            .map(|response| {
+                let status = &response.status_code;
+                quote!((String::from(#status), utoipa::openapi::RefOr::from(#response)))
+            })
+            .collect::<Array<TokenStream>>();
         let responses = if responses.len() > 0 {
             Some(quote!( .responses_from_iter(#responses)))
         } else {
@@ -195,6 +195,8 @@ impl IntoResponses {
                             .build()
                             .into()
                     }
+
+                    #schemas
                 }
             });
 
@@ -210,6 +212,254 @@ impl_to_tokens_diagnostics! {
     }
 }
 
+pub struct ToHeader {
+    pub attributes: Vec<Attribute>,
+    pub data: Data,
+    pub generics: Generics,
+    pub ident: Ident,
+}
+
+impl ToHeader {
+    fn tokens_or_diagnostics(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
+        let fields = match &self.data {
+            Data::Struct(struct_value) => match &struct_value.fields {
+                Fields::Named(fields) => &fields.named,
+                _ => {
+                    return Err(Diagnostics::with_span(
+                        self.ident.span(),
+                        "`ToHeader` only supports structs with named fields",
+                    ))
+                }
+            },
+            _ => {
+                return Err(Diagnostics::with_span(
+                    self.ident.span(),
+                    "`ToHeader` only supports structs with named fields",
+                ))
+            }
+        };
+
+        let rename_all = DeriveToHeaderAttributes::from_attributes(&self.attributes)?
+            .and_then(|attributes| attributes.rename_all);
+        let headers = fields
+            .iter()
+            .map(|field| {
+                let header = Header::from_field(field, rename_all.as_ref())?;
+                let name = &header.name;
+
+                Ok(quote! { (String::from(#name), utoipa::openapi::RefOr::from(#header)) })
+            })
+            .collect::<Result<Array<TokenStream>, Diagnostics>>()?;
+
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        tokens.extend(quote! {
+            impl #impl_generics utoipa::ToHeader for #ident #ty_generics #where_clause {
+                fn headers() -> Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::header::Header>)> {
+                    Vec::from(#headers)
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+impl_to_tokens_diagnostics! {
+    impl ToTokensDiagnostics for ToHeader {
+        fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
+            self.tokens_or_diagnostics(tokens)
+        }
+    }
+}
+
+/// Container `#[header(...)]` attributes of [`ToHeader`].
+#[derive(Default)]
+struct DeriveToHeaderAttributes {
+    rename_all: Option<RenameRule>,
+}
+
+impl DeriveToHeaderAttributes {
+    fn from_attributes(attributes: &[Attribute]) -> Result<Option<Self>, Diagnostics> {
+        attributes
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("header"))
+            .map(|attribute| attribute.parse_args::<Self>().map_err(Diagnostics::from))
+            .reduce(|acc, attributes| {
+                let mut acc = acc?;
+                let attributes = attributes?;
+                if attributes.rename_all.is_some() {
+                    acc.rename_all = attributes.rename_all;
+                }
+                Ok(acc)
+            })
+            .transpose()
+    }
+}
+
+impl Parse for DeriveToHeaderAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            match &*ident.to_string() {
+                "rename_all" => {
+                    let rename_all = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+                    attributes.rename_all = Some(
+                        rename_all
+                            .value()
+                            .parse::<RenameRule>()
+                            .map_err(|error| syn::Error::new(rename_all.span(), error))?,
+                    );
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "unexpected attribute, expected any of: rename_all",
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// Field `#[header(...)]` attributes of [`ToHeader`].
+#[derive(Default)]
+struct DeriveToHeaderFieldAttributes {
+    rename: Option<String>,
+    value_type: Option<Type>,
+    example: Option<AnyValue>,
+}
+
+impl Parse for DeriveToHeaderFieldAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str =
+            "unexpected attribute, expected any of: rename, value_type, example";
+        let mut attributes = Self::default();
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            match &*ident.to_string() {
+                "rename" => attributes.rename = Some(parse_utils::parse_next_literal_str(input)?),
+                "value_type" => {
+                    attributes.value_type = Some(parse_utils::parse_next(input, || input.parse())?)
+                }
+                "example" => {
+                    attributes.example = Some(parse_utils::parse_next(input, || {
+                        AnyValue::parse_any(input)
+                    })?)
+                }
+                _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+impl Header {
+    /// Resolve header from a field of [`ToHeader`] struct. `Option` fields are not required and
+    /// the header schema is resolved from the inner type of the `Option`.
+    fn from_field(field: &Field, rename_all: Option<&RenameRule>) -> Result<Self, Diagnostics> {
+        let mut attributes = field
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("header"))
+            .map(|attribute| attribute.parse_args::<DeriveToHeaderFieldAttributes>())
+            .collect::<Result<Vec<_>, syn::Error>>()?
+            .into_iter()
+            .fold(
+                DeriveToHeaderFieldAttributes::default(),
+                |mut acc, attributes| {
+                    if attributes.rename.is_some() {
+                        acc.rename = attributes.rename;
+                    }
+                    if attributes.value_type.is_some() {
+                        acc.value_type = attributes.value_type;
+                    }
+                    if attributes.example.is_some() {
+                        acc.example = attributes.example;
+                    }
+                    acc
+                },
+            );
+
+        let field_name = field
+            .ident
+            .as_ref()
+            .expect("named field must have ident")
+            .unraw()
+            .to_string();
+        let name = attributes.rename.take().unwrap_or_else(|| {
+            rename_all
+                .map(|rename_all| rename_all.rename(&field_name))
+                .unwrap_or(field_name)
+        });
+
+        let inner_type = option_inner_type(&field.ty);
+        let required = inner_type.is_none();
+        let ty = attributes
+            .value_type
+            .take()
+            .unwrap_or_else(|| inner_type.unwrap_or(&field.ty).clone());
+        let description = CommentAttributes::from_attributes(&field.attrs).as_formatted_string();
+        let deprecated = field
+            .attrs
+            .iter()
+            .any(|attribute| attribute.path().is_ident("deprecated"));
+
+        Ok(Self {
+            name,
+            value_type: Some(InlineType {
+                ty: Cow::Owned(ty),
+                is_inline: false,
+            }),
+            reference: None,
+            description: if description.is_empty() {
+                None
+            } else {
+                Some(description)
+            },
+            required: Some(required),
+            deprecated: if deprecated { Some(true) } else { None },
+            example: attributes.example,
+        })
+    }
+}
+
+/// Get the inner type of `Option<T>` type.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first()? {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 trait Response {
     fn to_type(ident: &Ident) -> Type {
         let path = Path::from(ident.clone());
@@ -351,7 +601,7 @@ impl NamedStructResponse<'_> {
             generics: None,
             rename_all: None,
             struct_name: Cow::Owned(ident.to_string()),
-            schema_as: None,
+            schema_name: None,
         };
 
         let ty = Self::to_type(ident);
@@ -437,7 +687,7 @@ impl<'p> ToResponseNamedStructResponse<'p> {
             attributes,
             struct_name: Cow::Owned(ident.to_string()),
             rename_all: None,
-            schema_as: None,
+            schema_name: None,
         };
         let response_type = PathType::InlineSchema(inline_schema.to_token_stream(), ty);
 
@@ -565,7 +815,7 @@ impl<'r> EnumResponse<'r> {
         });
         response_value.response_type = if content.is_empty() {
             let inline_schema =
-                EnumSchema::new(Cow::Owned(ident.to_string()), variants, attributes)?;
+                EnumSchema::new(Cow::Owned(ident.to_string()), variants, attributes, None)?;
 
             Some(PathType::InlineSchema(
                 inline_schema.into_token_stream(),
@@ -18,6 +18,14 @@ impl SchemaType<'_> {
             .to_string()
     }
 
+    /// Name of the last segment where `NonZero*` integers are resolved to their primitive type.
+    fn primitive_name(&self) -> String {
+        let name = self.last_segment_to_string();
+        non_zero_integer(&name)
+            .map(ToString::to_string)
+            .unwrap_or(name)
+    }
+
     pub fn is_value(&self) -> bool {
         matches!(&*self.last_segment_to_string(), "Value")
     }
@@ -30,12 +38,17 @@ impl SchemaType<'_> {
             None => return false,
         };
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
+
+        // `std::path::Path` is a string but generic `Path<T>` is a path extractor of a framework
+        if name == "Path" {
+            return last_segment.arguments.is_none();
+        }
 
         #[cfg(not(any(
             feature = "chrono",
             feature = "decimal",
             feature = "decimal_float",
-            feature = "rocket_extras",
             feature = "uuid",
             feature = "ulid",
             feature = "url",
@@ -49,7 +62,6 @@ impl SchemaType<'_> {
             feature = "chrono",
             feature = "decimal",
             feature = "decimal_float",
-            feature = "rocket_extras",
             feature = "uuid",
             feature = "ulid",
             feature = "url",
@@ -65,12 +77,7 @@ impl SchemaType<'_> {
 
             #[cfg(any(feature = "decimal", feature = "decimal_float"))]
             if !primitive {
-                primitive = is_primitive_rust_decimal(name);
-            }
-
-            #[cfg(feature = "rocket_extras")]
-            if !primitive {
-                primitive = matches!(name, "PathBuf");
+                primitive = is_primitive_decimal(name);
             }
 
             #[cfg(feature = "uuid")]
@@ -101,31 +108,37 @@ impl SchemaType<'_> {
     }
 
     pub fn is_integer(&self) -> bool {
-        matches!(
-            &*self.last_segment_to_string(),
-            "i8" | "i16"
-                | "i32"
-                | "i64"
-                | "i128"
-                | "isize"
-                | "u8"
-                | "u16"
-                | "u32"
-                | "u64"
-                | "u128"
-                | "usize"
-        )
+        !self.is_large_number()
+            && matches!(
+                &*self.primitive_name(),
+                "i8" | "i16"
+                    | "i32"
+                    | "i64"
+                    | "i128"
+                    | "isize"
+                    | "u8"
+                    | "u16"
+                    | "u32"
+                    | "u64"
+                    | "u128"
+                    | "usize"
+            )
     }
 
     pub fn is_unsigned_integer(&self) -> bool {
-        matches!(
-            &*self.last_segment_to_string(),
-            "u8" | "u16" | "u32" | "u64" | "u128" | "usize"
-        )
+        !self.is_large_number()
+            && matches!(
+                &*self.primitive_name(),
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize"
+            )
+    }
+
+    pub fn is_non_zero_integer(&self) -> bool {
+        non_zero_integer(&self.last_segment_to_string()).is_some()
     }
 
     pub fn is_number(&self) -> bool {
-        match &*self.last_segment_to_string() {
+        match &*self.primitive_name() {
             "f32" | "f64" => true,
             _ if self.is_integer() => true,
             _ => false,
@@ -133,13 +146,34 @@ impl SchemaType<'_> {
     }
 
     pub fn is_string(&self) -> bool {
-        matches!(&*self.last_segment_to_string(), "str" | "String")
+        let name = self.last_segment_to_string();
+        matches!(&*name, "str" | "String")
+            || is_std_string(&name)
+            || (name == "Path"
+                && self
+                    .0
+                    .segments
+                    .last()
+                    .map(|segment| segment.arguments.is_none())
+                    .unwrap_or(false))
+            || self.is_large_number()
+    }
+
+    /// Check whether type is 64 or 128 bit integer represented as `string` with
+    /// `large_numbers_as_string` feature.
+    pub fn is_large_number(&self) -> bool {
+        is_large_number(&self.primitive_name())
     }
 
     pub fn is_byte(&self) -> bool {
         matches!(&*self.last_segment_to_string(), "u8")
     }
 
+    /// `File` virtual type is rendered as binary `string` and is typically used with file uploads.
+    pub fn is_file(&self) -> bool {
+        matches!(&*self.last_segment_to_string(), "File")
+    }
+
     fn tokens_or_diagnostics(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
         let last_segment = self.0.segments.last().ok_or_else(|| {
             Diagnostics::with_span(
@@ -148,12 +182,25 @@ impl SchemaType<'_> {
             )
         })?;
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
 
         match name {
+            _ if is_large_number(name) => {
+                tokens.extend(quote! { utoipa::openapi::SchemaType::String })
+            }
             "String" | "str" | "char" => {
                 tokens.extend(quote! {utoipa::openapi::SchemaType::String})
             }
 
+            "File" => tokens.extend(quote! { utoipa::openapi::SchemaType::String }),
+
+            _ if is_std_string(name) => {
+                tokens.extend(quote! { utoipa::openapi::SchemaType::String })
+            }
+            "Path" if last_segment.arguments.is_none() => {
+                tokens.extend(quote! { utoipa::openapi::SchemaType::String })
+            }
+
             "bool" => tokens.extend(quote! { utoipa::openapi::SchemaType::Boolean }),
 
             "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
@@ -169,13 +216,14 @@ impl SchemaType<'_> {
             "Date" | "Duration" => tokens.extend(quote! { utoipa::openapi::SchemaType::String }),
 
             #[cfg(feature = "decimal")]
-            "Decimal" => tokens.extend(quote! { utoipa::openapi::SchemaType::String }),
+            "Decimal" | "BigDecimal" => {
+                tokens.extend(quote! { utoipa::openapi::SchemaType::String })
+            }
 
             #[cfg(feature = "decimal_float")]
-            "Decimal" => tokens.extend(quote! { utoipa::openapi::SchemaType::Number }),
-
-            #[cfg(feature = "rocket_extras")]
-            "PathBuf" => tokens.extend(quote! { utoipa::openapi::SchemaType::String }),
+            "Decimal" | "BigDecimal" => {
+                tokens.extend(quote! { utoipa::openapi::SchemaType::Number })
+            }
 
             #[cfg(feature = "uuid")]
             "Uuid" => tokens.extend(quote! { utoipa::openapi::SchemaType::String }),
@@ -197,6 +245,35 @@ impl SchemaType<'_> {
     }
 }
 
+/// Resolve primitive integer type of `std::num::NonZero*` integer type.
+#[inline]
+fn non_zero_integer(name: &str) -> Option<&'static str> {
+    match name {
+        "NonZeroI8" => Some("i8"),
+        "NonZeroI16" => Some("i16"),
+        "NonZeroI32" => Some("i32"),
+        "NonZeroI64" => Some("i64"),
+        "NonZeroI128" => Some("i128"),
+        "NonZeroIsize" => Some("isize"),
+        "NonZeroU8" => Some("u8"),
+        "NonZeroU16" => Some("u16"),
+        "NonZeroU32" => Some("u32"),
+        "NonZeroU64" => Some("u64"),
+        "NonZeroU128" => Some("u128"),
+        "NonZeroUsize" => Some("usize"),
+        _ => None,
+    }
+}
+
+/// 64 and 128 bit integers lose precision in JavaScript clients thus they can be represented
+/// as `string` with `large_numbers_as_string` feature. Only the schema is changed, users must
+/// serialize the values as strings themselves e.g. with `serde_with::DisplayFromStr`. `isize` and
+/// `usize` are left out as their width depends on the target platform.
+#[inline]
+fn is_large_number(name: &str) -> bool {
+    cfg!(feature = "large_numbers_as_string") && matches!(name, "i64" | "u64" | "i128" | "u128")
+}
+
 #[inline]
 fn is_primitive(name: &str) -> bool {
     matches!(
@@ -219,6 +296,24 @@ fn is_primitive(name: &str) -> bool {
             | "i128"
             | "f32"
             | "f64"
+            | "File"
+    ) || is_std_string(name)
+}
+
+/// Standard library and other common types which are serialized as `string`, e.g. network
+/// addresses and file system paths.
+#[inline]
+fn is_std_string(name: &str) -> bool {
+    matches!(
+        name,
+        "IpAddr"
+            | "Ipv4Addr"
+            | "Ipv6Addr"
+            | "SocketAddr"
+            | "SocketAddrV4"
+            | "SocketAddrV6"
+            | "PathBuf"
+            | "NonEmptyString"
     )
 }
 
@@ -233,8 +328,8 @@ fn is_primitive_chrono(name: &str) -> bool {
 
 #[inline]
 #[cfg(any(feature = "decimal", feature = "decimal_float"))]
-fn is_primitive_rust_decimal(name: &str) -> bool {
-    matches!(name, "Decimal")
+fn is_primitive_decimal(name: &str) -> bool {
+    matches!(name, "Decimal" | "BigDecimal")
 }
 
 impl_to_tokens_diagnostics! {
@@ -302,6 +397,7 @@ impl Type<'_> {
             None => return false,
         };
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
 
         #[cfg(not(any(
             feature = "chrono",
@@ -333,7 +429,7 @@ impl Type<'_> {
 
             #[cfg(feature = "decimal_float")]
             if !known_format {
-                known_format = matches!(name, "Decimal");
+                known_format = matches!(name, "Decimal" | "BigDecimal");
             }
 
             #[cfg(feature = "uuid")]
@@ -363,10 +459,22 @@ impl Type<'_> {
 
 #[inline]
 fn is_known_format(name: &str) -> bool {
-    matches!(
-        name,
-        "i8" | "i16" | "i32" | "u8" | "u16" | "u32" | "i64" | "u64" | "f32" | "f64"
-    )
+    !is_large_number(name)
+        && matches!(
+            name,
+            "i8" | "i16"
+                | "i32"
+                | "u8"
+                | "u16"
+                | "u32"
+                | "i64"
+                | "u64"
+                | "f32"
+                | "f64"
+                | "File"
+                | "Ipv4Addr"
+                | "Ipv6Addr"
+        )
 }
 
 impl ToTokensDiagnostics for Type<'_> {
@@ -378,6 +486,10 @@ impl ToTokensDiagnostics for Type<'_> {
             )
         })?;
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
+        if is_large_number(name) {
+            return Ok(());
+        }
 
         match name {
             #[cfg(feature="non_strict_integers")]
@@ -407,6 +519,11 @@ impl ToTokensDiagnostics for Type<'_> {
             "f32" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Float) }),
             "f64" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Double) }),
 
+            "File" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Binary) }),
+
+            "Ipv4Addr" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Ipv4) }),
+            "Ipv6Addr" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Ipv6) }),
+
             #[cfg(feature = "chrono")]
             "NaiveDate" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Date) }),
 
@@ -420,7 +537,7 @@ impl ToTokensDiagnostics for Type<'_> {
             "Date" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Date) }),
 
             #[cfg(any(feature = "decimal_float"))]
-            "Decimal" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Double) }),
+            "Decimal" | "BigDecimal" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Double) }),
 
             #[cfg(feature = "uuid")]
             "Uuid" => tokens.extend(quote! { utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Uuid) }),
@@ -455,6 +572,8 @@ pub enum Variant {
     Date,
     DateTime,
     Password,
+    Ipv4,
+    Ipv6,
     #[cfg(feature = "uuid")]
     Uuid,
     #[cfg(feature = "ulid")]
@@ -466,9 +585,9 @@ pub enum Variant {
 
 impl Parse for Variant {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const FORMATS: [&str; 12] = [
+        const FORMATS: [&str; 14] = [
             "Int32", "Int64", "Float", "Double", "Byte", "Binary", "Date", "DateTime", "Password",
-            "Uuid", "Ulid", "Uri",
+            "Ipv4", "Ipv6", "Uuid", "Ulid", "Uri",
         ];
         let excluded_format: &[&str] = &[
             #[cfg(not(feature = "uuid"))]
@@ -498,6 +617,8 @@ impl Parse for Variant {
                 "Date" => Ok(Self::Date),
                 "DateTime" => Ok(Self::DateTime),
                 "Password" => Ok(Self::Password),
+                "Ipv4" => Ok(Self::Ipv4),
+                "Ipv6" => Ok(Self::Ipv6),
                 #[cfg(feature = "uuid")]
                 "Uuid" => Ok(Self::Uuid),
                 #[cfg(feature = "ulid")]
@@ -514,7 +635,27 @@ impl Parse for Variant {
             }
         } else if lookahead.peek(LitStr) {
             let value = input.parse::<LitStr>()?.value();
-            Ok(Self::Custom(value))
+            // string literal of a known format is same as the known format variant
+            match &*value {
+                "int32" => Ok(Self::Int32),
+                "int64" => Ok(Self::Int64),
+                "float" => Ok(Self::Float),
+                "double" => Ok(Self::Double),
+                "byte" => Ok(Self::Byte),
+                "binary" => Ok(Self::Binary),
+                "date" => Ok(Self::Date),
+                "date-time" => Ok(Self::DateTime),
+                "password" => Ok(Self::Password),
+                "ipv4" => Ok(Self::Ipv4),
+                "ipv6" => Ok(Self::Ipv6),
+                #[cfg(feature = "uuid")]
+                "uuid" => Ok(Self::Uuid),
+                #[cfg(feature = "ulid")]
+                "ulid" => Ok(Self::Ulid),
+                #[cfg(feature = "url")]
+                "uri" => Ok(Self::Uri),
+                _ => Ok(Self::Custom(value)),
+            }
         } else {
             Err(lookahead.error())
         }
@@ -551,6 +692,12 @@ impl ToTokens for Variant {
             Self::Password => tokens.extend(quote!(utoipa::openapi::SchemaFormat::KnownFormat(
                 utoipa::openapi::KnownFormat::Password
             ))),
+            Self::Ipv4 => tokens.extend(quote!(utoipa::openapi::SchemaFormat::KnownFormat(
+                utoipa::openapi::KnownFormat::Ipv4
+            ))),
+            Self::Ipv6 => tokens.extend(quote!(utoipa::openapi::SchemaFormat::KnownFormat(
+                utoipa::openapi::KnownFormat::Ipv6
+            ))),
             #[cfg(feature = "uuid")]
             Self::Uuid => tokens.extend(quote!(utoipa::openapi::SchemaFormat::KnownFormat(
                 utoipa::openapi::KnownFormat::Uuid
@@ -4,8 +4,8 @@ use syn::{
     bracketed,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    token::Comma,
-    LitStr, Token,
+    token::{Bracket, Comma},
+    Error, LitStr, Token,
 };
 
 use crate::Array;
@@ -32,6 +32,12 @@ impl Parse for SecurityRequirementsAttrItem {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let name = input.parse::<LitStr>()?.value();
 
+        if !input.peek(Token![=]) || !input.peek2(Bracket) {
+            return Err(Error::new(
+                input.span(),
+                format!("expected scopes for security requirement, e.g. (\"{name}\" = [\"scope\"]) or (\"{name}\" = [])"),
+            ));
+        }
         input.parse::<Token![=]>()?;
 
         let scopes_stream;
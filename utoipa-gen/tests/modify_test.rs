@@ -42,3 +42,45 @@ fn modify_openapi_add_security_schema() {
         "components.securitySchemes.api_jwt_token.bearerFormat" = r###""JWT""###, "api_jwt_token bearerFormat"
     }
 }
+
+#[test]
+fn modify_openapi_with_multiple_modifiers_in_modules() {
+    mod addons {
+        use utoipa::{
+            openapi::{self, security::SecurityRequirement, server::Server},
+            Modify,
+        };
+
+        pub struct ServerAddon;
+
+        impl Modify for ServerAddon {
+            fn modify(&self, openapi: &mut openapi::OpenApi) {
+                openapi.servers = Some(vec![Server::new("http://localhost:8080")]);
+            }
+        }
+
+        pub struct SecurityAddon;
+
+        impl Modify for SecurityAddon {
+            fn modify(&self, openapi: &mut openapi::OpenApi) {
+                // modifiers are applied in the order of declaration
+                assert!(openapi.servers.is_some(), "server addon should run first");
+                openapi.security = Some(vec![SecurityRequirement::new(
+                    "api_key",
+                    Vec::<String>::new(),
+                )]);
+            }
+        }
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(modifiers(&addons::ServerAddon, &addons::SecurityAddon))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "servers.[0].url" = r###""http://localhost:8080""###, "Server url"
+        "security.[0].api_key" = r###"[]"###, "Security requirement"
+    }
+}
@@ -34,6 +34,27 @@ fn derive_openapi_with_security_requirement() {
     }
 }
 
+#[test]
+fn derive_openapi_with_global_security_and_operation_override() {
+    #[utoipa::path(get, path = "/items", responses((status = 200)))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    #[utoipa::path(get, path = "/health", responses((status = 200)), security(()))]
+    #[allow(unused)]
+    fn get_health() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_items, get_health), security(("bearer_auth" = [])))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(doc.pointer("/security"), json!([{ "bearer_auth": [] }]));
+    assert_json_eq!(doc.pointer("/paths/~1items/get/security"), Value::Null);
+    assert_json_eq!(doc.pointer("/paths/~1health/get/security"), json!([{}]));
+}
+
 #[test]
 fn derive_openapi_tags() {
     #[derive(OpenApi)]
@@ -58,6 +79,35 @@ fn derive_openapi_tags() {
     }
 }
 
+#[test]
+fn derive_openapi_tags_serialize_with_description_and_external_docs() {
+    #[derive(OpenApi)]
+    #[openapi(tags(
+        (name = "items", description = "Item management", external_docs(url = "https://docs.example.com/items")),
+        (name = "users")
+    ))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let tags = doc.pointer("/tags").unwrap();
+
+    assert_json_eq!(
+        tags,
+        json!([
+            {
+                "name": "items",
+                "description": "Item management",
+                "externalDocs": {
+                    "url": "https://docs.example.com/items"
+                }
+            },
+            {
+                "name": "users"
+            }
+        ])
+    );
+}
+
 #[test]
 fn derive_openapi_tags_include_str() {
     #[derive(OpenApi)]
@@ -105,6 +155,26 @@ fn derive_openapi_with_external_docs_only_url() {
     }
 }
 
+#[test]
+fn derive_openapi_with_extensions() {
+    fn gateway() -> serde_json::Value {
+        json!({ "type": "http_proxy" })
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        info(title = "api", version = "1.0.0", extensions(("x-logo" = json!({ "url": "logo.png" })))),
+        extensions(("x-internal" = true), ("x-gateway" = gateway))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(doc["x-internal"], json!(true));
+    assert_eq!(doc["x-gateway"], json!({ "type": "http_proxy" }));
+    assert_eq!(doc["info"]["x-logo"], json!({ "url": "logo.png" }));
+}
+
 #[test]
 fn derive_openapi_with_components_in_different_module() {
     mod custom {
@@ -244,6 +314,285 @@ fn derive_openapi_with_custom_info() {
     )
 }
 
+#[test]
+fn derive_openapi_for_api_versions() {
+    #![allow(unused)]
+
+    #[utoipa::path(get, path = "/pets", api_version = "v1", responses((status = 200)))]
+    fn get_pets_v1() {}
+
+    #[utoipa::path(post, path = "/pets", api_version = "v1", responses((status = 200)))]
+    fn create_pet_v1() {}
+
+    #[utoipa::path(get, path = "/pets", api_version = "v2", responses((status = 200)))]
+    fn get_pets_v2() {}
+
+    #[utoipa::path(get, path = "/health", responses((status = 200)))]
+    fn health() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pets_v1, create_pet_v1, get_pets_v2, health))]
+    struct ApiDoc;
+
+    let operation_ids = |openapi: utoipa::openapi::OpenApi| {
+        openapi
+            .paths
+            .paths
+            .into_iter()
+            .flat_map(|(path, path_item)| {
+                path_item.operations.into_values().map(move |operation| {
+                    format!("{path} {}", operation.operation_id.unwrap_or_default())
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        operation_ids(ApiDoc::openapi_for_version("v1")),
+        ["/health health", "/pets get_pets_v1", "/pets create_pet_v1"]
+    );
+    assert_eq!(
+        operation_ids(ApiDoc::openapi_for_version("v2")),
+        ["/health health", "/pets get_pets_v2"]
+    );
+    assert_eq!(
+        operation_ids(ApiDoc::openapi_for_version("v3")),
+        ["/health health"]
+    );
+}
+
+#[test]
+fn derive_openapi_with_hidden_paths() {
+    #![allow(unused)]
+
+    #[utoipa::path(get, path = "/pets", responses((status = 200)))]
+    fn get_pets() {}
+
+    #[utoipa::path(get, path = "/internal", hidden, responses((status = 200)))]
+    fn internal() {}
+
+    #[utoipa::path(get, path = "/debug", include_if = "debug", responses((status = 200)))]
+    fn debug() {}
+
+    #[cfg(any())]
+    #[utoipa::path(get, path = "/disabled", responses((status = 200)))]
+    fn disabled() {}
+
+    #[utoipa::path(get, path = "/enabled", responses((status = 200)))]
+    fn enabled() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(
+        get_pets,
+        internal,
+        debug,
+        cfg(any()) disabled,
+        cfg(all()) enabled
+    ))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let paths = doc.paths.paths.keys().collect::<Vec<_>>();
+
+    let expected: &[&str] = if cfg!(feature = "debug") {
+        &["/debug", "/enabled", "/pets"]
+    } else {
+        &["/enabled", "/pets"]
+    };
+    assert_eq!(paths, expected);
+}
+
+#[test]
+fn derive_openapi_with_tags_inferred_from_parent_module() {
+    #![allow(unused)]
+
+    mod handlers {
+        pub mod pets {
+            #[utoipa::path(get, path = "/pets", responses((status = 200)))]
+            pub fn get_pets() {}
+
+            #[utoipa::path(get, path = "/pets/{id}", tag = "pet", responses((status = 200)))]
+            pub fn get_pet() {}
+        }
+    }
+
+    #[utoipa::path(get, path = "/health", responses((status = 200)))]
+    fn health() {}
+
+    let tags = |openapi: utoipa::openapi::OpenApi| {
+        openapi
+            .paths
+            .paths
+            .into_iter()
+            .flat_map(|(path, path_item)| {
+                path_item
+                    .operations
+                    .into_values()
+                    .map(move |operation| (path.clone(), operation.tags))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    #[derive(OpenApi)]
+    #[openapi(paths(handlers::pets::get_pets, handlers::pets::get_pet, health))]
+    struct ApiDoc;
+
+    assert_eq!(
+        tags(ApiDoc::openapi()),
+        [
+            ("/health".to_string(), Some(vec!["crate".to_string()])),
+            ("/pets".to_string(), Some(vec!["pets".to_string()])),
+            ("/pets/{id}".to_string(), Some(vec!["pet".to_string()])),
+        ]
+    );
+
+    #[derive(OpenApi)]
+    #[openapi(
+        infer_tags = false,
+        paths(handlers::pets::get_pets, handlers::pets::get_pet, health)
+    )]
+    struct NoInferredTagsApiDoc;
+
+    assert_eq!(
+        tags(NoInferredTagsApiDoc::openapi()),
+        [
+            ("/health".to_string(), None),
+            ("/pets".to_string(), None),
+            ("/pets/{id}".to_string(), Some(vec!["pet".to_string()])),
+        ]
+    );
+}
+
+#[test]
+fn derive_openapi_with_associated_function_paths() {
+    #![allow(unused)]
+
+    mod handlers {
+        pub struct PetController;
+
+        impl PetController {
+            #[utoipa::path(get, path = "/pets", associated, responses((status = 200)))]
+            pub fn get_pets() {}
+
+            #[utoipa::path(get, path = "/pets/{id}", responses((status = 200)))]
+            pub fn get_pet(&self) {}
+        }
+
+        pub trait HealthApi {
+            #[utoipa::path(get, path = "/health", responses((status = 200)))]
+            fn health(&self) {}
+        }
+
+        impl HealthApi for PetController {}
+    }
+
+    use handlers::{HealthApi, PetController};
+
+    #[derive(OpenApi)]
+    #[openapi(paths(
+        handlers::PetController::get_pets,
+        PetController::get_pet,
+        PetController::health
+    ))]
+    struct ApiDoc;
+
+    let operations = ApiDoc::openapi()
+        .paths
+        .paths
+        .into_iter()
+        .flat_map(|(path, path_item)| {
+            path_item
+                .operations
+                .into_values()
+                .map(move |operation| (path.clone(), operation.operation_id, operation.tags))
+        })
+        .collect::<Vec<_>>();
+
+    let tags = Some(vec!["PetController".to_string()]);
+    assert_eq!(
+        operations,
+        [
+            (
+                "/health".to_string(),
+                Some("health".to_string()),
+                tags.clone()
+            ),
+            (
+                "/pets".to_string(),
+                Some("get_pets".to_string()),
+                tags.clone()
+            ),
+            ("/pets/{id}".to_string(), Some("get_pet".to_string()), tags),
+        ]
+    );
+}
+
+#[test]
+fn derive_openapi_with_full_custom_info() {
+    #[derive(OpenApi)]
+    #[openapi(info(
+        title = "Pet api",
+        version = "1.0.0",
+        description = "Api for managing pets",
+        terms_of_service = "https://example.com/terms",
+        contact(
+            name = "Pet team",
+            email = "pets@example.com",
+            url = "https://example.com/pets"
+        ),
+        license(name = "MIT", url = "https://opensource.org/licenses/MIT")
+    ))]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let info = value.pointer("/info");
+
+    assert_json_eq!(
+        info,
+        json!({
+            "title": "Pet api",
+            "version": "1.0.0",
+            "description": "Api for managing pets",
+            "termsOfService": "https://example.com/terms",
+            "contact": {
+                "name": "Pet team",
+                "email": "pets@example.com",
+                "url": "https://example.com/pets"
+            },
+            "license": {
+                "name": "MIT",
+                "url": "https://opensource.org/licenses/MIT"
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_openapi_with_default_info_from_cargo_env() {
+    #[derive(OpenApi)]
+    #[openapi()]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let info = value.pointer("/info");
+
+    assert_json_eq!(
+        info,
+        json!({
+            "title": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": env!("CARGO_PKG_DESCRIPTION"),
+            "license": {
+                "name": env!("CARGO_PKG_LICENSE"),
+            },
+            "contact": {
+                "name": "Juha Kukkonen",
+                "email": "juha7kukkonen@gmail.com"
+            }
+        })
+    )
+}
+
 #[test]
 fn derive_openapi_with_include_str_description() {
     #[derive(OpenApi)]
@@ -275,6 +624,73 @@ fn derive_openapi_with_include_str_description() {
     )
 }
 
+#[test]
+fn derive_openapi_with_reusable_response_referenced_from_paths() {
+    /// Requested resource was not found
+    #[derive(Serialize, ToResponse)]
+    #[allow(unused)]
+    struct NotFoundResponse {
+        message: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets/{id}",
+        responses(
+            (status = 200, description = "Pet found"),
+            (status = 404, response = NotFoundResponse)
+        )
+    )]
+    #[allow(unused)]
+    fn get_pet() {}
+
+    #[utoipa::path(
+        delete,
+        path = "/pets/{id}",
+        responses(
+            (status = 204, description = "Pet deleted"),
+            (status = 404, response = NotFoundResponse)
+        )
+    )]
+    #[allow(unused)]
+    fn delete_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet, delete_pet), components(responses(NotFoundResponse)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    for method in ["get", "delete"] {
+        assert_json_eq!(
+            doc.pointer(&format!("/paths/~1pets~1{{id}}/{method}/responses/404")),
+            json!({ "$ref": "#/components/responses/NotFoundResponse" })
+        );
+    }
+    assert_json_eq!(
+        doc.pointer("/components/responses"),
+        json!({
+            "NotFoundResponse": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "description": "Requested resource was not found",
+                            "properties": {
+                                "message": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["message"],
+                            "type": "object"
+                        }
+                    }
+                },
+                "description": "Requested resource was not found"
+            }
+        })
+    );
+}
+
 #[test]
 fn derive_openapi_with_generic_response() {
     struct Resp;
@@ -378,3 +794,382 @@ fn derive_openapi_with_generic_schema_with_as() {
         })
     )
 }
+
+#[test]
+fn derive_openapi_deserialize_generated_document() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+        tags: Vec<String>,
+        age: Option<u8>,
+        owner: Option<Box<Pet>>,
+        value: serde_json::Value,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets/{id}",
+        params(("id" = i32, Path, deprecated, example = 1)),
+        request_body(content = Pet, examples(("Rex" = (value = json!({"name": "Rex"}))))),
+        responses(
+            (status = 200, description = "success", body = [Pet], headers(("x-request-id" = String))),
+            (status = "5XX", description = "error")
+        )
+    )]
+    #[allow(unused)]
+    fn post_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(post_pet), components(schemas(Pet)))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let json = doc.to_json().unwrap();
+    let deserialized: utoipa::openapi::OpenApi = serde_json::from_str(&json).unwrap();
+
+    assert!(
+        doc == deserialized,
+        "expected deserialized OpenApi to equal the generated OpenApi"
+    );
+    assert_eq!(json, deserialized.to_json().unwrap());
+}
+
+#[test]
+fn derive_openapi_with_webhooks() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    /// New pet added to the store
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        request_body = Pet,
+        responses(
+            (status = 200, description = "Webhook received"),
+        )
+    )]
+    #[allow(unused)]
+    fn new_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(webhooks(("newPet" = new_pet)), components(schemas(Pet)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(doc.pointer("/paths").unwrap(), json!({}));
+    assert_json_eq!(
+        doc.pointer("/webhooks").unwrap(),
+        json!({
+            "newPet": {
+                "post": {
+                    "operationId": "new_pet",
+                    "summary": "New pet added to the store",
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "$ref": "#/components/schemas/Pet"
+                                }
+                            }
+                        },
+                        "required": true
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "Webhook received"
+                        }
+                    },
+                    "tags": ["crate"]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_with_nested_api() {
+    mod pets {
+        use utoipa::{OpenApi, ToSchema};
+
+        #[derive(ToSchema)]
+        #[allow(unused)]
+        pub struct Pet {
+            name: String,
+        }
+
+        #[utoipa::path(
+            get,
+            path = "/{id}",
+            responses(
+                (status = 200, description = "Pet found", body = Pet),
+            )
+        )]
+        #[allow(unused)]
+        fn get_pet() {}
+
+        #[derive(OpenApi)]
+        #[openapi(paths(get_pet), components(schemas(Pet)))]
+        pub struct PetApi;
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/health",
+        responses(
+            (status = 200, description = "Healthy"),
+        )
+    )]
+    #[allow(unused)]
+    fn health() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(health),
+        nest((path = "/api/v1/pets", api = pets::PetApi))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let paths = doc.pointer("/paths").unwrap().as_object().unwrap();
+
+    assert_eq!(
+        paths.keys().collect::<Vec<_>>(),
+        ["/api/v1/pets/{id}", "/health"],
+        "nested paths should be prefixed"
+    );
+    assert_json_eq!(
+        doc.pointer("/paths/~1api~1v1~1pets~1{id}/get/operationId")
+            .unwrap(),
+        json!("get_pet")
+    );
+    assert!(
+        doc.pointer("/components/schemas/Pet").is_some(),
+        "nested components should be merged"
+    );
+}
+
+#[test]
+fn derive_openapi_with_nested_api_tags() {
+    mod admin {
+        use utoipa::OpenApi;
+
+        #[utoipa::path(
+            get,
+            path = "/users",
+            tag = "users",
+            responses(
+                (status = 200, description = "Users found"),
+            )
+        )]
+        #[allow(unused)]
+        fn get_users() {}
+
+        #[utoipa::path(
+            delete,
+            path = "/users/{id}",
+            responses(
+                (status = 200, description = "User deleted"),
+            )
+        )]
+        #[allow(unused)]
+        fn delete_user() {}
+
+        #[derive(OpenApi)]
+        #[openapi(paths(get_users, delete_user))]
+        pub struct AdminApi;
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(nest((path = "/admin", api = admin::AdminApi, tags = ["admin", "internal"])))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1admin~1users/get/tags").unwrap(),
+        json!(["admin", "internal"])
+    );
+    assert_json_eq!(
+        doc.pointer("/paths/~1admin~1users~1{id}/delete/tags")
+            .unwrap(),
+        json!(["admin", "internal"])
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "pets::Error` and `openapi_derive::derive_openapi_with_colliding_schema_names::users::Error`"
+)]
+fn derive_openapi_with_colliding_schema_names() {
+    #![allow(unused)]
+
+    mod pets {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            message: String,
+        }
+    }
+
+    mod users {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            code: i32,
+        }
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        components(schemas(pets::Error, users::Error)),
+        deny_schema_name_collisions
+    )]
+    struct ApiDoc;
+
+    ApiDoc::openapi();
+}
+
+#[test]
+fn derive_openapi_with_allowed_colliding_schema_names() {
+    #![allow(unused)]
+
+    mod pets {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            message: String,
+        }
+    }
+
+    mod users {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            code: i32,
+        }
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(pets::Error, users::Error)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/components/schemas/Error/properties").unwrap(),
+        json!({
+            "code": {
+                "type": "integer",
+                "format": "int32"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_with_renamed_colliding_schema_names() {
+    #![allow(unused, clippy::duplicated_attributes)]
+
+    mod pets {
+        #[derive(utoipa::ToSchema)]
+        #[schema(as = pets::Error)]
+        pub struct Error {
+            message: String,
+        }
+    }
+
+    mod users {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            code: i32,
+        }
+    }
+
+    #[derive(utoipa::ToSchema)]
+    #[aliases(PageString = Page<String>, PageInt = Page<i32>)]
+    struct Page<T> {
+        items: Vec<T>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        components(schemas(pets::Error, users::Error, PageString, PageInt, users::Error)),
+        deny_schema_name_collisions
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc.pointer("/components/schemas").unwrap();
+
+    assert!(schemas.get("pets.Error").is_some());
+    assert!(schemas.get("Error").is_some());
+    assert!(schemas.get("PageString").is_some());
+    assert!(schemas.get("PageInt").is_some());
+}
+
+#[test]
+fn derive_openapi_with_trait_object_body_implementors() {
+    #![allow(unused)]
+
+    trait ErrorResponse {}
+
+    #[derive(utoipa::ToSchema)]
+    struct NotFound {
+        resource: String,
+    }
+    impl ErrorResponse for NotFound {}
+
+    #[derive(utoipa::ToSchema)]
+    struct Conflict {
+        reason: String,
+    }
+    impl ErrorResponse for Conflict {}
+
+    #[utoipa::path(
+        get,
+        path = "/pets/{id}",
+        responses(
+            (status = 404, description = "Pet not found", body = dyn ErrorResponse),
+            (status = 409, description = "Pet conflict", body = Box<dyn ErrorResponse>)
+        )
+    )]
+    fn get_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(get_pet),
+        components(implementors(dyn ErrorResponse = [NotFound, Conflict]))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let responses = doc.pointer("/paths/~1pets~1{id}/get/responses").unwrap();
+    let schemas = doc.pointer("/components/schemas").unwrap();
+
+    for status in ["404", "409"] {
+        assert_json_eq!(
+            responses
+                .pointer(&format!("/{status}/content/application~1json/schema"))
+                .unwrap(),
+            json!({
+                "$ref": "#/components/schemas/ErrorResponse"
+            })
+        );
+    }
+    assert_json_eq!(
+        schemas.get("ErrorResponse").unwrap(),
+        json!({
+            "oneOf": [
+                {
+                    "$ref": "#/components/schemas/NotFound"
+                },
+                {
+                    "$ref": "#/components/schemas/Conflict"
+                }
+            ]
+        })
+    );
+    assert!(schemas.get("NotFound").is_some());
+    assert!(schemas.get("Conflict").is_some());
+}
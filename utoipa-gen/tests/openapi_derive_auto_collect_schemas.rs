@@ -0,0 +1,530 @@
+#![cfg(feature = "auto_collect_schemas")]
+
+use utoipa::{OpenApi, ToSchema};
+
+#[test]
+fn derive_openapi_collect_path_schemas() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        owner: Owner,
+    }
+
+    #[derive(ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct NewPet {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct PetId(u64);
+
+    #[derive(ToSchema)]
+    struct PetError {
+        message: String,
+    }
+
+    #[derive(ToSchema)]
+    struct DebugInfo {
+        trace: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Inlined {
+        tag: Tag,
+    }
+
+    #[derive(ToSchema)]
+    struct Tag(String);
+
+    #[utoipa::path(
+        post,
+        path = "/pets/{id}",
+        request_body = NewPet,
+        params(
+            ("id" = PetId, Path, description = "Pet id"),
+        ),
+        responses(
+            (status = 200, description = "Pet updated", body = [Pet]),
+            (status = 400, description = "Invalid pet", content(
+                ("application/json" = PetError),
+                ("text/plain" = inline(Inlined))
+            )),
+            cfg(any()) (status = 500, description = "Server error", body = DebugInfo)
+        )
+    )]
+    fn update_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(update_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    assert_eq!(
+        schemas.keys().collect::<Vec<_>>(),
+        ["NewPet", "Owner", "Pet", "PetError", "PetId", "Tag"],
+        "schemas referenced by path should be collected"
+    );
+}
+
+#[test]
+fn derive_openapi_collect_into_responses_schemas() {
+    #![allow(unused)]
+
+    mod shared {
+        use utoipa::{IntoResponses, ToSchema};
+
+        #[derive(ToSchema)]
+        pub struct ErrorBody {
+            message: String,
+            details: Vec<ErrorDetail>,
+        }
+
+        #[derive(ToSchema)]
+        pub struct ErrorDetail {
+            field: String,
+        }
+
+        #[derive(IntoResponses)]
+        pub enum CommonErrors {
+            /// Not authenticated
+            #[response(status = 401)]
+            Unauthorized(ErrorBody),
+
+            /// Server error
+            #[response(status = 500)]
+            Internal(#[to_schema] ErrorDetail),
+        }
+    }
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "Pets found", body = [Pet]),
+            shared::CommonErrors,
+        )
+    )]
+    fn list_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    assert_eq!(
+        schemas.keys().collect::<Vec<_>>(),
+        ["ErrorBody", "ErrorDetail", "Pet"],
+        "schemas referenced by shared responses should be collected"
+    );
+    assert!(doc.pointer("/paths/~1pets/get/responses/401").is_some());
+    assert!(doc.pointer("/paths/~1pets/get/responses/500").is_some());
+}
+
+#[test]
+fn derive_openapi_collect_path_schemas_explicit_schema_takes_precedence() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pet",
+        responses(
+            (status = 200, description = "Pet found", body = Pet),
+        )
+    )]
+    fn get_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet), components(schemas(Pet)), modifiers(&Description))]
+    struct ApiDoc;
+
+    struct Description;
+
+    impl utoipa::Modify for Description {
+        fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+            assert!(
+                openapi
+                    .components
+                    .as_ref()
+                    .map(|components| components.schemas.contains_key("Pet"))
+                    .unwrap_or(false),
+                "schemas should be collected before modifiers"
+            );
+        }
+    }
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert!(doc
+        .pointer("/components/schemas/Pet/properties/name")
+        .is_some());
+}
+
+#[test]
+fn derive_openapi_with_nested_component_schemas() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        owner: Option<Owner>,
+        tags: Vec<Tag>,
+        #[schema(inline)]
+        info: Info,
+    }
+
+    #[derive(ToSchema)]
+    struct Owner {
+        pets: Vec<Pet>,
+    }
+
+    #[derive(ToSchema)]
+    struct Tag(String);
+
+    #[derive(ToSchema)]
+    struct Info {
+        category: Category,
+        #[schema(value_type = Object)]
+        extra: Extra,
+    }
+
+    #[derive(ToSchema)]
+    enum Category {
+        Dog,
+        Cat,
+    }
+
+    #[derive(ToSchema)]
+    struct Extra;
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Pet)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    assert_eq!(
+        schemas.keys().collect::<Vec<_>>(),
+        ["Category", "Owner", "Pet", "Tag"],
+        "referenced schemas should be collected, inlined and value_type schemas should not"
+    );
+}
+
+#[test]
+fn derive_openapi_collect_generic_schema_aliases() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    #[aliases(PageOfPet = Page<Pet>, PageOfTag = Page<Tag>)]
+    struct Page<T> {
+        items: Vec<T>,
+    }
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Tag {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Listing {
+        pets: PageOfPet,
+        tags: PageOfTag,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Listing)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(
+        doc.pointer("/components/schemas/PageOfPet/properties/items/items/$ref"),
+        Some(&serde_json::json!("#/components/schemas/Pet")),
+        "alias should be collected with alias name and schema"
+    );
+    assert_eq!(
+        doc.pointer("/components/schemas/PageOfTag/properties/items/items/$ref"),
+        Some(&serde_json::json!("#/components/schemas/Tag")),
+        "alias should be collected with alias name and schema"
+    );
+    assert!(doc.pointer("/components/schemas/Page").is_none());
+}
+
+#[test]
+fn derive_openapi_collect_generic_schemas() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    #[aliases(PageTag = Page<Tag>)]
+    struct Page<T> {
+        items: Vec<T>,
+    }
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Tag {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Owner {
+        pets: Page<Pet>,
+        lost_pets: Page<Option<Pet>>,
+    }
+
+    #[utoipa::path(get, path = "/tags", responses((status = 200, body = Page<Tag>)))]
+    fn list_tags() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_tags), components(schemas(Owner)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    assert_eq!(
+        schemas.keys().collect::<Vec<_>>(),
+        ["Owner", "PageOptionPet", "PagePet", "PageTag", "Pet", "Tag"]
+    );
+    assert_eq!(
+        doc.pointer("/components/schemas/Owner/properties/pets/$ref"),
+        Some(&serde_json::json!("#/components/schemas/PagePet"))
+    );
+    assert_eq!(
+        doc.pointer("/components/schemas/PagePet/properties/items/items/$ref"),
+        Some(&serde_json::json!("#/components/schemas/Pet")),
+        "generic schema should be collected with generic arguments"
+    );
+    assert_eq!(
+        doc.pointer("/components/schemas/PageTag/properties/items/items/$ref"),
+        Some(&serde_json::json!("#/components/schemas/Tag")),
+        "alias with the same name should be collected for the generic schema"
+    );
+    assert_eq!(
+        doc.pointer("/components/schemas/PageOptionPet/properties/items/items"),
+        Some(&serde_json::json!({
+            "allOf": [{ "$ref": "#/components/schemas/Pet" }],
+            "nullable": true
+        }))
+    );
+}
+
+#[test]
+fn derive_openapi_collect_self_referencing_schemas() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Category {
+        #[schema(inline)]
+        parent: Option<Box<Category>>,
+        children: Vec<Self>,
+        owner: Owner,
+    }
+
+    #[derive(ToSchema)]
+    struct Owner {
+        categories: Vec<Category>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Category)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    assert_eq!(schemas.keys().collect::<Vec<_>>(), ["Category", "Owner"]);
+}
+
+#[test]
+#[should_panic(
+    expected = "schema name `Error` is shared by different schemas of `openapi_derive_auto_collect_schemas::derive_openapi_collect_path_schemas_with_colliding_schema_names::pets::Error` and `openapi_derive_auto_collect_schemas::derive_openapi_collect_path_schemas_with_colliding_schema_names::users::Error`"
+)]
+fn derive_openapi_collect_path_schemas_with_colliding_schema_names() {
+    #![allow(unused)]
+
+    mod pets {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            message: String,
+        }
+
+        #[utoipa::path(get, path = "/pets", responses((status = 500, body = Error)))]
+        pub fn get_pets() {}
+    }
+
+    mod users {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            code: i32,
+        }
+
+        #[utoipa::path(get, path = "/users", responses((status = 500, body = Error)))]
+        pub fn get_users() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(pets::get_pets, users::get_users), deny_schema_name_collisions)]
+    struct ApiDoc;
+
+    ApiDoc::openapi();
+}
+
+#[test]
+#[should_panic(
+    expected = "schema name `Error` is shared by different schemas of `openapi_derive_auto_collect_schemas::derive_openapi_collect_path_schemas_colliding_with_declared_schema::Error` and `openapi_derive_auto_collect_schemas::derive_openapi_collect_path_schemas_colliding_with_declared_schema::users::Error`"
+)]
+fn derive_openapi_collect_path_schemas_colliding_with_declared_schema() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Error {
+        message: String,
+    }
+
+    mod users {
+        #[derive(utoipa::ToSchema)]
+        pub struct Error {
+            code: i32,
+        }
+
+        #[utoipa::path(get, path = "/users", responses((status = 500, body = Error)))]
+        pub fn get_users() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(users::get_users),
+        components(schemas(Error)),
+        deny_schema_name_collisions
+    )]
+    struct ApiDoc;
+
+    ApiDoc::openapi();
+}
+
+#[test]
+fn derive_openapi_collect_schemas_next_to_trait_object() {
+    #![allow(unused)]
+
+    trait ErrorResponse {}
+
+    #[derive(ToSchema)]
+    struct NotFound {
+        resource: String,
+    }
+    impl ErrorResponse for NotFound {}
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "Pet found", body = (Pet, Box<dyn ErrorResponse>))
+        )
+    )]
+    fn get_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(get_pet),
+        components(implementors(dyn ErrorResponse = [NotFound]))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    assert_eq!(
+        schemas.keys().collect::<Vec<_>>(),
+        ["ErrorResponse", "NotFound", "Pet"],
+        "schemas next to trait object should be collected"
+    );
+}
+
+#[cfg(feature = "axum_extras")]
+#[test]
+fn derive_openapi_collect_axum_resolved_body_schemas() {
+    #[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+    struct Item {
+        owner: Owner,
+    }
+
+    #[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+    struct Owner {
+        name: String,
+    }
+
+    #[utoipa::path(post, path = "/item")]
+    #[allow(unused)]
+    async fn post_item(_body: axum::Json<Item>) {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(post_item))]
+    struct Doc;
+
+    let doc = serde_json::to_value(Doc::openapi()).unwrap();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    assert_eq!(schemas.keys().collect::<Vec<_>>(), ["Item", "Owner"]);
+}
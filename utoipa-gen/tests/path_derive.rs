@@ -280,6 +280,39 @@ fn derive_path_with_security_requirements() {
     }
 }
 
+#[test]
+fn derive_path_with_simultaneous_security_requirements() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        security(
+            ("api_key" = [], "api_oauth" = ["read:items"])
+        )
+    )]
+    #[allow(unused)]
+    fn get_items() -> String {
+        "".to_string()
+    }
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        operation.pointer("/security").unwrap(),
+        json!([
+            {
+                "api_key": [],
+                "api_oauth": ["read:items"]
+            }
+        ])
+    );
+}
+
 #[test]
 fn derive_path_with_datetime_format_query_parameter() {
     #[derive(serde::Deserialize, utoipa::ToSchema)]
@@ -678,6 +711,98 @@ fn derive_path_params_with_examples() {
     }
 }
 
+#[test]
+fn derive_into_params_with_named_examples() {
+    #[derive(serde::Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct MyParams {
+        #[param(examples(("Small" = (value = json!(1))), ("Large" = (value = json!(1000)))))]
+        limit: i32,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        params(MyParams)
+    )]
+    #[allow(unused)]
+    fn get_foo(params: MyParams) {}
+
+    let operation: Value = test_api_fn_doc! {
+        get_foo,
+        operation: get,
+        path: "/foo"
+    };
+
+    let examples = operation.pointer("/parameters/0/examples").unwrap();
+
+    assert_json_eq! {
+        examples,
+        json!({
+            "Small": {
+                "value": 1
+            },
+            "Large": {
+                "value": 1000
+            }
+        })
+    }
+}
+
+#[test]
+fn derive_path_params_with_named_examples() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        params(
+            ("since" = String, Query, examples(
+                ("Date" = (summary = "Date only", value = json!("2020-04-12"))),
+                ("DateTime" = (value = json!("2020-04-12T10:23:00Z")))
+            ))
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    let operation: Value = test_api_fn_doc! {
+        get_foo,
+        operation: get,
+        path: "/foo"
+    };
+
+    let parameters = operation.get("parameters").unwrap();
+
+    assert_json_eq! {
+        parameters,
+        json!{[
+            {
+                "in": "query",
+                "name": "since",
+                "required": true,
+                "examples": {
+                    "Date": {
+                        "summary": "Date only",
+                        "value": "2020-04-12"
+                    },
+                    "DateTime": {
+                        "value": "2020-04-12T10:23:00Z"
+                    }
+                },
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ]}
+    }
+}
+
 #[test]
 fn path_parameters_with_free_form_properties() {
     let operation = api_fn_doc_with_params! {get: "/foo" =>
@@ -749,6 +874,141 @@ fn derive_path_query_params_with_schema_features() {
     }
 }
 
+#[test]
+fn derive_path_params_with_enum_values() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        params(
+            ("order" = String, Query, enum_values = ["asc", "desc"]),
+            ("levels" = Vec<i32>, Query, enum_values = [1, 2, 3])
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    let operation: Value = test_api_fn_doc! {
+        get_foo,
+        operation: get,
+        path: "/foo"
+    };
+
+    let parameters = operation.get("parameters").unwrap();
+
+    assert_json_eq! {
+        parameters,
+        json!{[
+            {
+                "in": "query",
+                "name": "order",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "enum": ["asc", "desc"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "levels",
+                "style": "form",
+                "explode": true,
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32",
+                        "enum": [1, 2, 3]
+                    }
+                }
+            }
+        ]}
+    }
+}
+
+#[test]
+fn derive_path_params_array_query_style() {
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        params(
+            ("tag" = Vec<String>, Query),
+            ("ids" = Vec<i32>, Query, explode = false),
+            ("names" = Vec<String>, Query, style = PipeDelimited),
+            ("ids" = Vec<i32>, Header)
+        )
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    let operation: Value = test_api_fn_doc! {
+        get_pets,
+        operation: get,
+        path: "/pets"
+    };
+    let parameters = operation
+        .get("parameters")
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|parameter| {
+            (
+                &parameter["name"],
+                &parameter["style"],
+                &parameter["explode"],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        parameters,
+        [
+            (&json!("tag"), &json!("form"), &json!(true)),
+            (&json!("ids"), &json!("form"), &json!(false)),
+            (&json!("names"), &json!("pipeDelimited"), &json!(false)),
+            (&json!("ids"), &Value::Null, &Value::Null),
+        ]
+    );
+
+    let operation = api_fn_doc_with_params! {get: "/pets" =>
+        #[into_params(parameter_in = Query)]
+        struct MyParams {
+            tag: Vec<String>,
+            #[param(explode = false)]
+            ids: Option<Vec<i32>>,
+        }
+    };
+    let parameters = operation
+        .get("parameters")
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|parameter| {
+            (
+                &parameter["name"],
+                &parameter["style"],
+                &parameter["explode"],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        parameters,
+        [
+            (&json!("tag"), &json!("form"), &json!(true)),
+            (&json!("ids"), &json!("form"), &json!(false)),
+        ]
+    );
+}
+
 #[test]
 fn derive_path_params_always_required() {
     let operation = api_fn_doc_with_params! {get: "/foo" =>
@@ -775,6 +1035,102 @@ fn derive_path_params_always_required() {
     }
 }
 
+#[test]
+fn derive_path_params_with_required_and_deprecated_overrides() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        params(
+            ("legacy" = bool, Query, required = false, deprecated),
+            ("token" = Option<String>, Header, required),
+            ("limit" = Option<i32>, Query, deprecated = false),
+        ),
+        responses(
+            (status = 200, description = "success response"),
+        ),
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        &operation["parameters"],
+        json!([
+            {
+                "in": "query",
+                "name": "legacy",
+                "required": false,
+                "deprecated": true,
+                "schema": {
+                    "type": "boolean"
+                }
+            },
+            {
+                "in": "header",
+                "name": "token",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "nullable": true
+                }
+            },
+            {
+                "in": "query",
+                "name": "limit",
+                "required": false,
+                "deprecated": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "nullable": true
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_in_cookie() {
+    let operation = api_fn_doc_with_params! {get: "/foo" =>
+        #[into_params(parameter_in = Cookie)]
+        struct MyParams {
+            /// Session id
+            session_id: String,
+            theme: Option<String>,
+        }
+    };
+    let parameters = operation.get("parameters").unwrap();
+
+    assert_json_eq! {
+        parameters,
+        json!{[
+            {
+                "in": "cookie",
+                "name": "session_id",
+                "description": "Session id",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                }
+            },
+            {
+                "in": "cookie",
+                "name": "theme",
+                "required": false,
+                "schema": {
+                    "type": "string",
+                    "nullable": true
+                }
+            }
+        ]}
+    }
+}
+
 #[test]
 fn derive_required_path_params() {
     let operation = api_fn_doc_with_params! {get: "/list/{id}" =>
@@ -810,6 +1166,8 @@ fn derive_required_path_params() {
             {
                 "in": "query",
                 "name": "vec_default",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "type": "array",
@@ -831,6 +1189,8 @@ fn derive_required_path_params() {
             {
                 "in": "query",
                 "name": "vec_default_required",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "type": "array",
@@ -850,6 +1210,8 @@ fn derive_required_path_params() {
             {
                 "in": "query",
                 "name": "vec_option",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "nullable": true,
@@ -871,6 +1233,8 @@ fn derive_required_path_params() {
             {
                 "in": "query",
                 "name": "vec",
+                "style": "form",
+                "explode": true,
                 "required": true,
                 "schema": {
                     "type": "array",
@@ -921,6 +1285,8 @@ fn derive_path_params_with_serde_and_custom_rename() {
             {
                 "in": "query",
                 "name": "vecDefault",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "type": "array",
@@ -942,6 +1308,8 @@ fn derive_path_params_with_serde_and_custom_rename() {
             {
                 "in": "query",
                 "name": "VEC",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "type": "array",
@@ -986,6 +1354,8 @@ fn derive_path_params_custom_rename_all() {
             {
                 "in": "query",
                 "name": "vecDefault",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "type": "array",
@@ -1016,6 +1386,8 @@ fn derive_path_params_custom_rename_all_serde_will_override() {
             {
                 "in": "query",
                 "name": "VEC_DEFAULT",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "type": "array",
@@ -1048,6 +1420,8 @@ fn derive_path_parameters_container_level_default() {
             {
                 "in": "query",
                 "name": "vec_default",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "type": "array",
@@ -1242,7 +1616,7 @@ fn derive_path_params_into_params_with_value_type() {
     #[into_params(parameter_in = Query)]
     #[allow(unused)]
     struct Filter {
-        #[param(value_type = i64, style = Simple)]
+        #[param(value_type = i64, style = Form)]
         id: String,
         #[param(value_type = Object)]
         another_id: String,
@@ -1286,7 +1660,7 @@ fn derive_path_params_into_params_with_value_type() {
             "in": "query",
             "name": "id",
             "required": true,
-            "style": "simple",
+            "style": "form",
             "schema": {
                 "format": "int64",
                 "type": "integer"
@@ -1303,6 +1677,8 @@ fn derive_path_params_into_params_with_value_type() {
         {
             "in": "query",
             "name": "value1",
+            "style": "form",
+            "explode": true,
             "required": true,
             "schema": {
                 "items": {
@@ -1317,6 +1693,8 @@ fn derive_path_params_into_params_with_value_type() {
         {
             "in": "query",
             "name": "value2",
+            "style": "form",
+            "explode": true,
             "required": true,
             "schema": {
                 "items": {
@@ -1346,6 +1724,8 @@ fn derive_path_params_into_params_with_value_type() {
         {
             "in": "query",
             "name": "value5",
+            "style": "form",
+            "explode": true,
             "required": true,
             "schema": {
                 "items": {
@@ -1357,6 +1737,8 @@ fn derive_path_params_into_params_with_value_type() {
         {
             "in": "query",
             "name": "value6",
+            "style": "form",
+            "explode": true,
             "required": true,
             "schema": {
                 "items": {
@@ -1411,10 +1793,164 @@ fn derive_path_params_into_params_with_raw_identifier() {
 }
 
 #[test]
-fn derive_path_params_into_params_with_unit_type() {
-    #[derive(IntoParams)]
-    #[into_params(parameter_in = Path)]
-    struct Filter {
+fn derive_path_params_into_params_with_flattened_params() {
+    #[derive(serde::Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Pagination {
+        /// Page number
+        page: Option<u32>,
+        per_page: Option<u32>,
+    }
+
+    #[derive(serde::Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct PetFilter {
+        /// Pet name
+        name: String,
+        #[serde(flatten)]
+        pagination: Pagination,
+        #[serde(flatten)]
+        extra: HashMap<String, String>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        params(PetFilter)
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1pets/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "name",
+                "description": "Pet name",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "query",
+                "name": "page",
+                "description": "Page number",
+                "required": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0,
+                    "nullable": true
+                }
+            },
+            {
+                "in": "query",
+                "name": "per_page",
+                "required": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0,
+                    "nullable": true
+                }
+            },
+            {
+                "in": "query",
+                "name": "extra",
+                "required": true,
+                "schema": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "string"
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_into_params_with_param_flatten() {
+    #[derive(IntoParams)]
+    #[allow(unused)]
+    struct Pagination {
+        /// Page number
+        page: u32,
+    }
+
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct PetFilter {
+        /// Pet name
+        name: String,
+        #[param(flatten)]
+        pagination: Pagination,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        params(PetFilter)
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1pets/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "name",
+                "description": "Pet name",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "query",
+                "name": "page",
+                "description": "Page number",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_into_params_with_unit_type() {
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Path)]
+    struct Filter {
         #[allow(unused)]
         r#in: (),
     }
@@ -1476,6 +2012,28 @@ fn arbitrary_expr_in_operation_id() {
     assert_json_eq!(operation_id, json!("8"))
 }
 
+#[test]
+fn derive_path_with_raw_identifier_handler() {
+    #[utoipa::path(
+        get,
+        path = "/type",
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn r#type() {}
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(r#type))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let operation_id = doc.pointer("/paths/~1type/get/operationId").unwrap();
+
+    assert_json_eq!(operation_id, json!("type"))
+}
+
 #[test]
 fn derive_path_with_validation_attributes() {
     #[derive(IntoParams)]
@@ -1634,6 +2192,111 @@ fn derive_path_with_uuid() {
     }
 }
 
+#[cfg(feature = "uuid")]
+#[test]
+fn derive_path_with_uuid_into_params() {
+    use uuid::Uuid;
+
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        ids: Vec<Uuid>,
+        parent: Option<Uuid>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success response", body = [Uuid])
+        ),
+        params(Filter)
+    )]
+    #[allow(unused)]
+    fn get_items() -> String {
+        "".to_string()
+    }
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "in": "query",
+                "name": "ids",
+                "style": "form",
+                "explode": true,
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "format": "uuid"
+                    }
+                }
+            },
+            {
+                "in": "query",
+                "name": "parent",
+                "required": false,
+                "schema": {
+                    "type": "string",
+                    "format": "uuid",
+                    "nullable": true
+                }
+            }
+        ])
+    );
+    assert_value! {operation=>
+        "responses.200.content.application~1json.schema.items.type" = r#""string""#, "Response items type"
+        "responses.200.content.application~1json.schema.items.format" = r#""uuid""#, "Response items format"
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn derive_path_with_chrono() {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+    #[utoipa::path(
+        post,
+        path = "/items/{day}",
+        request_body = NaiveDateTime,
+        responses(
+            (status = 200, description = "success response", body = Vec<DateTime<Utc>>)
+        ),
+        params(
+            ("day" = NaiveDate, Path, description = "Item day"),
+            ("since" = Option<DateTime<Utc>>, Query, description = "Items since"),
+        )
+    )]
+    #[allow(unused)]
+    fn post_items(day: NaiveDate) -> String {
+        "".to_string()
+    }
+    let operation = test_api_fn_doc! {
+        post_items,
+        operation: post,
+        path: "/items/{day}"
+    };
+
+    assert_value! {operation=>
+        "parameters.[0].schema.type" = r#""string""#, "Parameter day type"
+        "parameters.[0].schema.format" = r#""date""#, "Parameter day format"
+        "parameters.[1].schema.type" = r#""string""#, "Parameter since type"
+        "parameters.[1].schema.format" = r#""date-time""#, "Parameter since format"
+        "requestBody.content.text~1plain.schema.type" = r#""string""#, "Request body type"
+        "requestBody.content.text~1plain.schema.format" = r#""date-time""#, "Request body format"
+        "responses.200.content.application~1json.schema.items.type" = r#""string""#, "Response items type"
+        "responses.200.content.application~1json.schema.items.format" = r#""date-time""#, "Response items format"
+    }
+}
+
 #[cfg(feature = "ulid")]
 #[test]
 fn derive_path_with_ulid() {
@@ -1976,6 +2639,124 @@ fn derive_path_with_const_expression_reference_context_path() {
     assert_ne!(operation, Value::Null);
 }
 
+#[test]
+fn derive_path_with_summary_and_description_overrides() {
+    #![allow(unused)]
+
+    const PET_DESCRIPTION: &str = "Get pet by its **id**.";
+
+    /// Doc summary
+    ///
+    /// Doc description
+    #[utoipa::path(get, path = "/summary", summary = "Explicit summary")]
+    fn summary_override() {}
+
+    /// Doc summary
+    ///
+    /// Doc description
+    #[utoipa::path(get, path = "/description", description = "Explicit description")]
+    fn description_override() {}
+
+    #[utoipa::path(
+        get,
+        path = "/pet",
+        summary = "Get pet",
+        description = PET_DESCRIPTION,
+        deprecated = "Use `/pets/{id}` instead"
+    )]
+    fn expression_description() {}
+
+    /// Doc summary
+    ///
+    /// Doc description
+    #[utoipa::path(get, path = "/empty", summary = "")]
+    fn empty_summary() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(
+        summary_override,
+        description_override,
+        expression_description,
+        empty_summary
+    ))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let operation = |path: &str| {
+        let operation = &doc["paths"][path]["get"];
+        (
+            operation.get("summary").cloned(),
+            operation.get("description").cloned(),
+        )
+    };
+
+    assert_eq!(
+        operation("/summary"),
+        (
+            Some(json!("Explicit summary")),
+            Some(json!("Doc description"))
+        )
+    );
+    assert_eq!(
+        operation("/description"),
+        (
+            Some(json!("Doc summary")),
+            Some(json!("Explicit description"))
+        )
+    );
+    assert_eq!(
+        operation("/pet"),
+        (
+            Some(json!("Get pet")),
+            Some(json!(
+                "Get pet by its **id**.\n\nDeprecated: Use `/pets/{id}` instead"
+            ))
+        )
+    );
+    assert_eq!(operation("/empty"), (None, Some(json!("Doc description"))));
+}
+
+#[test]
+fn derive_path_with_module_context_path() {
+    mod pets {
+        pub const CONTEXT_PATH: &str = "/api/v1/";
+
+        #[utoipa::path(
+            context_path = CONTEXT_PATH,
+            get,
+            path = "/pets",
+            responses(
+                (status = 200, description = "success response")
+            ),
+        )]
+        #[allow(unused)]
+        pub fn get_pets() {}
+
+        #[utoipa::path(
+            context_path = CONTEXT_PATH,
+            delete,
+            path = "pets/{id}",
+            responses(
+                (status = 200, description = "success response")
+            ),
+        )]
+        #[allow(unused)]
+        pub fn delete_pet(id: i32) {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(pets::get_pets, pets::delete_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let paths = doc.pointer("/paths").unwrap().as_object().unwrap();
+
+    assert_eq!(
+        paths.keys().collect::<Vec<_>>(),
+        ["/api/v1/pets", "/api/v1/pets/{id}"]
+    );
+}
+
 #[test]
 fn derive_path_with_const_expression() {
     const FOOBAR: &str = "/items";
@@ -2078,3 +2859,649 @@ fn derive_path_with_multiple_tags() {
         })
     );
 }
+
+#[test]
+fn derive_path_with_method_attribute() {
+    #[utoipa::path(
+        method = "post",
+        path = "/items",
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn post_items() {}
+
+    let operation = test_api_fn_doc! {
+        post_items,
+        operation: post,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "operationId": "post_items",
+            "responses": {
+                "200": {
+                    "description": "success response",
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_method_attribute_equals_bare_operation() {
+    mod method_attr {
+        #[utoipa::path(
+            method = "put",
+            path = "/items",
+            responses(
+                (status = 200, description = "success response")
+            ),
+        )]
+        #[allow(unused)]
+        pub fn put_items() {}
+    }
+
+    mod bare_operation {
+        #[utoipa::path(
+            put,
+            path = "/items",
+            responses(
+                (status = 200, description = "success response")
+            ),
+        )]
+        #[allow(unused)]
+        pub fn put_items() {}
+    }
+
+    let method_attr_operation = test_api_fn_doc! {
+        method_attr::put_items,
+        operation: put,
+        path: "/items"
+    };
+    let bare_operation = test_api_fn_doc! {
+        bare_operation::put_items,
+        operation: put,
+        path: "/items"
+    };
+
+    assert_ne!(method_attr_operation, Value::Null);
+    assert_eq!(
+        method_attr_operation.pointer("/operationId"),
+        bare_operation.pointer("/operationId")
+    );
+    assert_eq!(
+        method_attr_operation.pointer("/responses"),
+        bare_operation.pointer("/responses")
+    );
+}
+
+#[test]
+fn derive_path_with_multiple_operations() {
+    #[utoipa::path(
+        get,
+        head,
+        path = "/items",
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_items))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let path_item = doc.pointer("/paths/~1items").unwrap();
+
+    assert_json_eq!(
+        path_item,
+        json!({
+            "get": {
+                "operationId": "get_items_get",
+                "responses": {
+                    "200": {
+                        "description": "success response",
+                    },
+                },
+                "tags": ["crate"]
+            },
+            "head": {
+                "operationId": "get_items_head",
+                "responses": {
+                    "200": {
+                        "description": "success response",
+                    },
+                },
+                "tags": ["crate"]
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_deprecated_attribute() {
+    /// Get items
+    #[utoipa::path(
+        get,
+        path = "/items",
+        deprecated,
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "deprecated": true,
+            "operationId": "get_items",
+            "summary": "Get items",
+            "responses": {
+                "200": {
+                    "description": "success response",
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_deprecated_note() {
+    /// Get items
+    ///
+    /// Get all items.
+    #[utoipa::path(
+        get,
+        path = "/items",
+        deprecated = "use /v2/items instead",
+        responses(
+            (status = 200, description = "success response"),
+            (status = 404, description = "not found", deprecated = "items are never missing"),
+            (status = 500, deprecated = "use 5XX instead"),
+        ),
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "deprecated": true,
+            "operationId": "get_items",
+            "summary": "Get items",
+            "description": "Get all items.\n\nDeprecated: use /v2/items instead",
+            "responses": {
+                "200": {
+                    "description": "success response",
+                },
+                "404": {
+                    "description": "not found\n\nDeprecated: items are never missing",
+                },
+                "500": {
+                    "description": "Deprecated: use 5XX instead",
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_rust_deprecated_note() {
+    /// Get items
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success response"),
+        ),
+    )]
+    #[deprecated(since = "1.2.0", note = "use /v2/items instead")]
+    #[allow(unused)]
+    fn get_items() {}
+
+    /// Get users
+    #[utoipa::path(
+        get,
+        path = "/users",
+        responses(
+            (status = 200, description = "success response"),
+        ),
+    )]
+    #[deprecated = "use /v2/users instead"]
+    #[allow(unused)]
+    fn get_users() {}
+
+    #[allow(deprecated)]
+    let items = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+    #[allow(deprecated)]
+    let users = test_api_fn_doc! {
+        get_users,
+        operation: get,
+        path: "/users"
+    };
+
+    assert_value! {items=>
+        "deprecated" = r#"true"#, "Items operation deprecated"
+        "description" = r#""Deprecated: use /v2/items instead""#, "Items operation description"
+    };
+    assert_value! {users=>
+        "deprecated" = r#"true"#, "Users operation deprecated"
+        "description" = r#""Deprecated: use /v2/users instead""#, "Users operation description"
+    };
+}
+
+#[test]
+fn derive_path_with_external_docs() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        external_docs(url = "https://docs.example.com/items", description = "Find more about items"),
+        responses(
+            (status = 200, description = "success response"),
+        ),
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "operationId": "get_items",
+            "externalDocs": {
+                "url": "https://docs.example.com/items",
+                "description": "Find more about items"
+            },
+            "responses": {
+                "200": {
+                    "description": "success response",
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_extensions() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        extensions(("x-amazon-apigateway-integration" = json!({ "type": "mock" }))),
+        responses(
+            (status = 200, description = "success response", extensions(("x-cache" = 60))),
+        ),
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "operationId": "get_items",
+            "x-amazon-apigateway-integration": {
+                "type": "mock"
+            },
+            "responses": {
+                "200": {
+                    "description": "success response",
+                    "x-cache": 60
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_smart_pointer_bodies() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        request_body = Box<[u8]>,
+        responses(
+            (status = 200, body = std::borrow::Cow<'static, str>),
+            (status = 201, body = Box<Pet>),
+        ),
+    )]
+    #[allow(unused)]
+    fn post_pet() {}
+
+    let operation = test_api_fn_doc! {
+        post_pet,
+        operation: post,
+        path: "/pets"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "operationId": "post_pet",
+            "requestBody": {
+                "content": {
+                    "application/octet-stream": {
+                        "schema": {
+                            "type": "string",
+                            "format": "binary"
+                        }
+                    }
+                },
+                "required": true
+            },
+            "responses": {
+                "200": {
+                    "description": "",
+                    "content": {
+                        "text/plain": {
+                            "schema": {
+                                "type": "string"
+                            }
+                        }
+                    }
+                },
+                "201": {
+                    "description": "",
+                    "content": {
+                        "application/json": {
+                            "schema": {
+                                "$ref": "#/components/schemas/Pet"
+                            }
+                        }
+                    }
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_callbacks() {
+    /// Order status changed
+    #[utoipa::path(
+        post,
+        path = "{$request.body#/callbackUrl}",
+        operation_id = "order_status_callback",
+        responses(
+            (status = 200, description = "Callback received"),
+        ),
+    )]
+    #[allow(unused)]
+    fn order_status_callback() {}
+
+    #[utoipa::path(
+        post,
+        path = "/orders",
+        callbacks(
+            ("orderStatus" = order_status_callback),
+        ),
+        responses(
+            (status = 201, description = "Order created"),
+        ),
+    )]
+    #[allow(unused)]
+    fn create_order() {}
+
+    let operation = test_api_fn_doc! {
+        create_order,
+        operation: post,
+        path: "/orders"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "operationId": "create_order",
+            "callbacks": {
+                "orderStatus": {
+                    "{$request.body#/callbackUrl}": {
+                        "post": {
+                            "operationId": "order_status_callback",
+                            "summary": "Order status changed",
+                            "responses": {
+                                "200": {
+                                    "description": "Callback received",
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+            "responses": {
+                "201": {
+                    "description": "Order created",
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_servers() {
+    #[utoipa::path(
+        post,
+        path = "/files",
+        servers(
+            (url = "https://upload.example.com", description = "Upload server"),
+            (url = "https://{region}.upload.example.com", variables(
+                ("region" = (default = "eu", enum_values("eu", "us")))
+            )),
+        ),
+        responses(
+            (status = 201, description = "File uploaded"),
+        ),
+    )]
+    #[allow(unused)]
+    fn upload_file() {}
+
+    let operation = test_api_fn_doc! {
+        upload_file,
+        operation: post,
+        path: "/files"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "operationId": "upload_file",
+            "servers": [
+                {
+                    "url": "https://upload.example.com",
+                    "description": "Upload server"
+                },
+                {
+                    "url": "https://{region}.upload.example.com",
+                    "variables": {
+                        "region": {
+                            "default": "eu",
+                            "enum": ["eu", "us"]
+                        }
+                    }
+                }
+            ],
+            "responses": {
+                "201": {
+                    "description": "File uploaded",
+                },
+            },
+            "tags": ["crate"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_only_tags_does_not_add_default_tag() {
+    #[utoipa::path(
+        get,
+        tags = ["pets", "admin"],
+        path = "/pets",
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    let operation = test_api_fn_doc! {
+        get_pets,
+        operation: get,
+        path: "/pets"
+    };
+
+    assert_json_eq!(
+        &operation,
+        json!({
+            "operationId": "get_pets",
+            "responses": {
+                "200": {
+                    "description": "success response",
+                },
+            },
+            "tags": ["pets", "admin"]
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_doc_comment_markdown_indentation() {
+    /// List pets
+    ///
+    /// Supported filters:
+    /// * `name`
+    ///   * exact match
+    ///
+    ///     GET /pets?name=rex
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success response"),
+        ),
+    )]
+    #[allow(unused)]
+    fn list_pets() {}
+
+    let operation = test_api_fn_doc! {
+        list_pets,
+        operation: get,
+        path: "/pets"
+    };
+
+    assert_value! {operation=>
+        "summary" = r#""List pets""#, "Operation summary"
+        "description" = r#""Supported filters:\n* `name`\n  * exact match\n\n    GET /pets?name=rex""#, "Operation description"
+    };
+}
+
+#[test]
+fn derive_path_inline_bodies_omit_read_only_and_write_only_properties() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct User {
+        #[schema(read_only)]
+        id: i64,
+        name: String,
+        #[schema(write_only)]
+        password: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/users",
+        request_body = inline(User),
+        responses(
+            (status = 200, description = "created user", body = inline(User))
+        )
+    )]
+    #[allow(unused)]
+    fn create_user() {}
+
+    let operation = test_api_fn_doc! {
+        create_user,
+        operation: post,
+        path: "/users"
+    };
+
+    assert_json_eq!(
+        operation
+            .pointer("/requestBody/content/application~1json/schema")
+            .unwrap(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string"
+                },
+                "password": {
+                    "type": "string",
+                    "writeOnly": true
+                }
+            },
+            "required": ["name", "password"]
+        })
+    );
+    assert_json_eq!(
+        operation
+            .pointer("/responses/200/content/application~1json/schema")
+            .unwrap(),
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "readOnly": true
+                },
+                "name": {
+                    "type": "string"
+                }
+            },
+            "required": ["id", "name"]
+        })
+    );
+}
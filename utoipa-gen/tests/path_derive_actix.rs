@@ -864,6 +864,92 @@ fn path_with_all_args() {
     )
 }
 
+#[test]
+fn path_with_args_resolved_from_extractors() {
+    #[derive(utoipa::ToSchema, serde::Deserialize)]
+    struct CreatePet {
+        name: String,
+    }
+
+    #[derive(serde::Deserialize, IntoParams)]
+    struct Filter {
+        age: i32,
+    }
+
+    #[derive(serde::Deserialize, IntoParams)]
+    struct Page {
+        page: Option<u32>,
+    }
+
+    #[utoipa::path(responses((status = 201, description = "Pet created")))]
+    #[post("/owners/{id}/pets")]
+    async fn create_pet(
+        _path: Path<(u64,)>,
+        _query: Query<Filter>,
+        _page: Option<Query<Page>>,
+        _body: Json<CreatePet>,
+    ) -> Json<()> {
+        Json(())
+    }
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(create_pet))]
+    struct Doc;
+
+    let doc = serde_json::to_value(Doc::openapi()).unwrap();
+    let operation = doc.pointer("/paths/~1owners~1{id}~1pets/post").unwrap();
+
+    assert_json_eq!(
+        &operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "minimum": 0,
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "query",
+                "name": "age",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "query",
+                "name": "page",
+                "required": false,
+                "schema": {
+                    "format": "int32",
+                    "minimum": 0,
+                    "nullable": true,
+                    "type": "integer"
+                }
+            }
+        ])
+    );
+    assert_json_eq!(
+        &operation.pointer("/requestBody"),
+        json!({
+            "description": "",
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/CreatePet"
+                    }
+                }
+            },
+            "required": true,
+        })
+    )
+}
+
 #[test]
 #[cfg(feature = "uuid")]
 fn path_with_all_args_using_uuid() {
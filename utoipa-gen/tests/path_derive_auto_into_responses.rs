@@ -72,3 +72,71 @@ fn path_operation_auto_types_default_response_type() {
 
     assert_json_eq!(&path.pointer("/responses").unwrap(), serde_json::json!({}))
 }
+
+#[test]
+fn path_operation_auto_types_result_responses_with_overrides() {
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum PetResponse {
+        /// Pet found
+        #[response(status = 200)]
+        Success(Pet),
+    }
+
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum ApiError {
+        /// Pet not found
+        #[response(status = NOT_FOUND)]
+        NotFound,
+        /// Internal server error
+        #[response(status = 500)]
+        Internal,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pet",
+        responses(
+            (status = 404, description = "No pet with the given id"),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_pet() -> Result<PetResponse, ApiError> {
+        Err(ApiError::NotFound)
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let value = serde_json::to_value(&doc).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/paths/~1pet/get/responses").unwrap(),
+        serde_json::json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    }
+                },
+                "description": "Pet found",
+            },
+            "404": {
+                "description": "No pet with the given id"
+            },
+            "500": {
+                "description": "Internal server error"
+            }
+        })
+    )
+}
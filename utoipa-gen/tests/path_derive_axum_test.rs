@@ -81,6 +81,8 @@ fn derive_path_params_into_params_axum() {
                 "description":  "Age filter for user",
                 "in":  "query",
                 "name": "age",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "items": {
@@ -611,3 +613,127 @@ fn path_param_single_arg_non_primitive_type_into_params() {
         ])
     )
 }
+
+#[test]
+fn path_with_axum_style_path_parameters() {
+    #[utoipa::path(
+        get,
+        path = "/person/:id/files/*path",
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_person_file(Path((id, path)): Path<(u64, String)>) {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_person_file))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc
+        .pointer("/paths/~1person~1{id}~1files~1{path}/get/parameters")
+        .unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "type": "integer",
+                    "minimum": 0
+                },
+            },
+            {
+                "in": "path",
+                "name": "path",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                },
+            },
+        ])
+    )
+}
+
+#[test]
+fn path_with_args_resolved_from_extractors() {
+    #[derive(utoipa::ToSchema, Deserialize)]
+    #[allow(unused)]
+    struct CreatePet {
+        name: String,
+    }
+
+    #[derive(Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        age: i32,
+    }
+
+    #[derive(Clone)]
+    struct AppState;
+
+    #[utoipa::path(
+        post,
+        path = "/owners/:id/pets",
+        responses((status = 201, description = "Pet created"))
+    )]
+    #[allow(unused)]
+    async fn create_pet(
+        _state: axum::extract::State<AppState>,
+        Path(id): Path<u64>,
+        _query: Query<Filter>,
+        _body: Json<CreatePet>,
+    ) {
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(create_pet))]
+    struct Doc;
+
+    let doc = serde_json::to_value(Doc::openapi()).unwrap();
+    let operation = doc.pointer("/paths/~1owners~1{id}~1pets/post").unwrap();
+
+    assert_json_eq!(
+        &operation.pointer("/parameters").unwrap(),
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "minimum": 0,
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "query",
+                "name": "age",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer"
+                }
+            }
+        ])
+    );
+    assert_json_eq!(
+        &operation.pointer("/requestBody"),
+        json!({
+            "description": "",
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/CreatePet"
+                    }
+                }
+            },
+            "required": true,
+        })
+    )
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "poem_extras")]
+
+use assert_json_diff::assert_json_eq;
+use poem::web::{Json, Path, Query};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+#[test]
+fn derive_path_with_poem_path_syntax() {
+    #[utoipa::path(
+        get,
+        path = "/person/:id<\\d+>/files/*path",
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_person_file(Path((id, path)): Path<(i64, String)>) {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_person_file))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc
+        .pointer("/paths/~1person~1{id}~1files~1{path}/get/parameters")
+        .unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "type": "integer",
+                },
+            },
+            {
+                "in": "path",
+                "name": "path",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                },
+            },
+        ])
+    )
+}
+
+#[test]
+fn derive_path_with_poem_into_params_and_request_body() {
+    #[derive(Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        /// Age filter for pet
+        age: Option<u32>,
+    }
+
+    #[derive(Deserialize, ToSchema)]
+    #[allow(unused)]
+    struct CreatePet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        responses(
+            (status = 201, description = "Pet created")
+        )
+    )]
+    #[allow(unused)]
+    async fn create_pet(Query(filter): Query<Filter>, Json(pet): Json<CreatePet>) {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(create_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let operation = doc.pointer("/paths/~1pets/post").unwrap();
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        &json!([
+            {
+                "description": "Age filter for pet",
+                "in": "query",
+                "name": "age",
+                "required": false,
+                "schema": {
+                    "format": "int32",
+                    "minimum": 0,
+                    "nullable": true,
+                    "type": "integer",
+                },
+            },
+        ])
+    );
+    assert_json_eq!(
+        operation.pointer("/requestBody").unwrap(),
+        &json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/CreatePet"
+                    }
+                }
+            },
+            "description": "",
+            "required": true,
+        })
+    );
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "register_paths")]
+
+use assert_json_diff::assert_json_eq;
+use serde_json::json;
+use utoipa::OpenApi;
+
+mod pets {
+    #[utoipa::path(register, get, path = "/pets", responses((status = 200, description = "List pets")))]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    pub mod admin {
+        #[utoipa::path(register, delete, path = "/pets/{id}", responses((status = 204)))]
+        #[allow(unused)]
+        fn delete_pet(id: u64) {}
+    }
+}
+
+#[utoipa::path(register, get, path = "/health", responses((status = 200)))]
+#[allow(unused)]
+fn health() {}
+
+#[utoipa::path(get, path = "/pets/{id}", responses((status = 200)))]
+#[allow(unused)]
+fn get_pet(id: u64) {}
+
+#[utoipa::path(register, get, path = "/internal", hidden, responses((status = 200)))]
+#[allow(unused)]
+fn internal() {}
+
+#[test]
+fn gather_registered_paths() {
+    #[derive(OpenApi)]
+    #[openapi()]
+    struct ApiDoc;
+
+    let openapi = ApiDoc::gather();
+    let paths = serde_json::to_value(&openapi.paths.paths).unwrap();
+
+    assert_json_eq!(
+        paths["/pets"],
+        json!({
+            "get": {
+                "tags": ["pets"],
+                "operationId": "get_pets",
+                "responses": {
+                    "200": {
+                        "description": "List pets"
+                    }
+                }
+            }
+        })
+    );
+    assert_eq!(paths["/health"]["get"]["tags"], json!(["crate"]));
+    assert_eq!(paths["/pets/{id}"]["delete"]["tags"], json!(["admin"]));
+    assert!(
+        openapi.paths.get_path_item("/internal").is_none(),
+        "hidden paths should not be gathered"
+    );
+}
+
+#[test]
+fn gather_registered_paths_merged_with_listed_paths() {
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet))]
+    struct ApiDoc;
+
+    let openapi = ApiDoc::gather();
+    let path_item = openapi.paths.get_path_item("/pets/{id}").unwrap();
+    let operations = serde_json::to_value(path_item).unwrap();
+
+    assert_eq!(operations["get"]["operationId"], "get_pet");
+    assert_eq!(operations["delete"]["operationId"], "delete_pet");
+    assert!(ApiDoc::openapi().paths.get_path_item("/pets").is_none());
+}
+
+#[cfg(feature = "auto_collect_schemas")]
+#[test]
+fn gather_registered_path_schemas() {
+    #![allow(unused)]
+
+    #[derive(utoipa::ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(utoipa::ToSchema)]
+    struct NewOwner {
+        name: String,
+    }
+
+    #[utoipa::path(
+        register,
+        post,
+        path = "/owners",
+        request_body = NewOwner,
+        responses((status = 200, body = Owner))
+    )]
+    fn create_owner() {}
+
+    #[derive(OpenApi)]
+    #[openapi()]
+    struct ApiDoc;
+
+    let openapi = ApiDoc::gather();
+    let schemas = &openapi.components.expect("components must exist").schemas;
+
+    assert!(schemas.contains_key("Owner"));
+    assert!(schemas.contains_key("NewOwner"));
+}
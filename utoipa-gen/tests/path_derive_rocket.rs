@@ -39,6 +39,45 @@ fn resolve_route_with_simple_url() {
     assert_ne!(operation, &Value::Null, "expected paths.hello.get not null");
 }
 
+#[test]
+fn resolve_get_with_fully_qualified_route_attribute() {
+    mod rocket_get_operation {
+        #[utoipa::path(responses(
+                (status = 200, description = "Hello from server")
+            ))]
+        #[rocket::get("/hello/<id>")]
+        #[allow(unused)]
+        fn hello(id: i32) -> String {
+            "Hello".to_string()
+        }
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(rocket_get_operation::hello))]
+    struct ApiDoc;
+
+    let openapi = ApiDoc::openapi();
+    let value = &serde_json::to_value(&openapi).unwrap();
+    let parameters = value
+        .pointer("/paths/~1hello~1{id}/get/parameters")
+        .unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer"
+                }
+            }
+        ])
+    );
+}
+
 #[test]
 fn resolve_get_with_multiple_args() {
     mod rocket_get_operation {
@@ -132,6 +171,8 @@ fn resolve_get_with_optional_query_args() {
             {
                 "in": "query",
                 "name": "colors",
+                "style": "form",
+                "explode": true,
                 "required": false,
                 "schema": {
                     "items": {
@@ -504,6 +545,8 @@ fn path_with_all_args_and_body() {
             {
                 "in": "query",
                 "name": "colors",
+                "style": "form",
+                "explode": true,
                 "required": true,
                 "schema": {
                     "type": "array",
@@ -0,0 +1,127 @@
+#![cfg(feature = "salvo_extras")]
+
+use assert_json_diff::assert_json_eq;
+use salvo::oapi::extract::{JsonBody, PathParam, QueryParam};
+use salvo::{Depot, Request};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::{OpenApi, ToSchema};
+
+#[test]
+fn derive_path_with_salvo_path_syntax() {
+    #[utoipa::path(
+        get,
+        path = "/person/<id:num>/files/<**path>",
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_person_file(
+        request: &mut Request,
+        depot: &mut Depot,
+        id: PathParam<i64>,
+        path: PathParam<String>,
+    ) {
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_person_file))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc
+        .pointer("/paths/~1person~1{id}~1files~1{path}/get/parameters")
+        .unwrap();
+
+    assert_json_eq!(
+        parameters,
+        &json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "type": "integer",
+                },
+            },
+            {
+                "in": "path",
+                "name": "path",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                },
+            },
+        ])
+    )
+}
+
+#[test]
+fn derive_path_with_salvo_query_params_and_request_body() {
+    #[derive(Deserialize, ToSchema)]
+    #[allow(unused)]
+    struct CreatePet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        responses(
+            (status = 201, description = "Pet created")
+        )
+    )]
+    #[allow(unused)]
+    async fn create_pet(
+        owner: QueryParam<String, true>,
+        notify: QueryParam<bool, false>,
+        pet: JsonBody<CreatePet>,
+    ) {
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(create_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let operation = doc.pointer("/paths/~1pets/post").unwrap();
+
+    assert_json_eq!(
+        operation.pointer("/parameters").unwrap(),
+        &json!([
+            {
+                "in": "query",
+                "name": "owner",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                },
+            },
+            {
+                "in": "query",
+                "name": "notify",
+                "required": false,
+                "schema": {
+                    "type": "boolean",
+                    "nullable": true,
+                },
+            },
+        ])
+    );
+    assert_json_eq!(
+        operation.pointer("/requestBody").unwrap(),
+        &json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/CreatePet"
+                    }
+                }
+            },
+            "description": "",
+            "required": true,
+        })
+    );
+}
@@ -346,3 +346,361 @@ fn derive_path_params_with_parameter_type_args() {
         ])
     );
 }
+
+#[test]
+fn derive_params_with_form_style_exploded_array_query() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("ids" = [i64], query, style = "form", explode = true, description = "Foo ids")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1foo/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "ids",
+                "required": true,
+                "description": "Foo ids",
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int64"
+                    }
+                },
+                "style": "form",
+                "explode": true
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_params_with_style_default_explode() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("ids" = [i64], Query, style = Form),
+            ("filter" = Object, Query, style = DeepObject),
+            ("X-Tags" = [String], Header, style = "simple"),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1foo/get/parameters").unwrap();
+
+    common::assert_json_array_len(parameters, 3);
+    assert_value! {parameters=>
+        "[0].style" = r#""form""#, "Parameter style"
+        "[0].explode" = r#"true"#, "Parameter explode"
+        "[1].style" = r#""deepObject""#, "Parameter style"
+        "[1].explode" = r#"false"#, "Parameter explode"
+        "[2].style" = r#""simple""#, "Parameter style"
+        "[2].explode" = r#"false"#, "Parameter explode"
+    };
+}
+
+#[test]
+fn derive_params_optional_path_parameter_is_required() {
+    #[utoipa::path(
+        get,
+        path = "/pets/{id}",
+        params(
+            ("id" = Option<u64>, path, description = "Pet id"),
+            ("limit" = Option<u32>, query)
+        ),
+        responses(
+            (status = 200, description = "success"),
+        )
+    )]
+    #[allow(unused)]
+    fn get_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1pets~1{id}/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "description": "Pet id",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0,
+                    "nullable": true
+                }
+            },
+            {
+                "in": "query",
+                "name": "limit",
+                "required": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0,
+                    "nullable": true
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_params_optional_inline_parameter() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    enum Order {
+        Asc,
+        Desc,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        params(
+            ("order" = Option<inline(Order)>, query)
+        ),
+        responses(
+            (status = 200, description = "success"),
+        )
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1pets/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "order",
+                "required": false,
+                "schema": {
+                    "allOf": [
+                        {
+                            "type": "string",
+                            "enum": ["Asc", "Desc"]
+                        }
+                    ],
+                    "nullable": true
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_params_with_style_explode_and_allow_reserved() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("ids" = [u64], query, style = Form, explode = false, allow_reserved),
+            ("filter" = Option<String>, query, allow_reserved = false)
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1foo/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "ids",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int64",
+                        "minimum": 0
+                    }
+                },
+                "style": "form",
+                "explode": false,
+                "allowReserved": true
+            },
+            {
+                "in": "query",
+                "name": "filter",
+                "required": false,
+                "schema": {
+                    "type": "string",
+                    "nullable": true
+                },
+                "allowReserved": false
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_params_with_deep_object_style() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        name: String,
+        age: Option<i32>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = inline(Filter), query, style = DeepObject, explode)
+        )
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1pets/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "name": {
+                            "type": "string"
+                        },
+                        "age": {
+                            "type": "integer",
+                            "format": "int32",
+                            "nullable": true
+                        }
+                    },
+                    "required": ["name"]
+                },
+                "style": "deepObject",
+                "explode": true
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_params_with_style_without_parameter_in() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("ids" = [i64], style = Form),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1foo/get/parameters").unwrap();
+
+    assert_value! {parameters=>
+        "[0].style" = r#""form""#, "Parameter style"
+        "[0].explode" = r#"true"#, "Parameter explode"
+    };
+}
+
+#[test]
+fn derive_params_with_allow_reserved_without_parameter_in() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = String, allow_reserved),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1foo/get/parameters").unwrap();
+
+    assert_value! {parameters=>
+        "[0].allowReserved" = r#"true"#, "Parameter allow reserved"
+    };
+}
@@ -91,6 +91,35 @@ fn derive_path_with_multiple_simple_responses() {
     }
 }
 
+test_fn! {
+    module: string_status_code_responses,
+    responses: (
+        (status = "200", description = "success"),
+        (status = "4XX", description = "client error"),
+        (status = "default", description = "unexpected error")
+    )
+}
+
+#[test]
+fn derive_path_with_string_status_code_responses() {
+    let doc = api_doc!(module: string_status_code_responses);
+
+    assert_json_eq!(
+        doc.pointer("/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "success"
+            },
+            "4XX": {
+                "description": "client error"
+            },
+            "default": {
+                "description": "unexpected error"
+            }
+        })
+    );
+}
+
 test_fn! {
     module: http_status_code_responses,
     responses: (
@@ -250,6 +279,48 @@ binary_octet_stream => body: [u8], assert:
     "responses.200.headers" = r###"null"###, "Response headers"
 }
 
+test_fn! {
+    module: binary_responses,
+    responses: (
+        (status = 200, description = "file content", body = Vec<u8>),
+        (status = 202, description = "optional file content", body = Option<Vec<u8>>)
+    )
+}
+
+#[test]
+fn derive_response_with_binary_body() {
+    let doc = api_doc!(module: binary_responses);
+
+    assert_json_eq!(
+        doc.pointer("/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "file content",
+                "content": {
+                    "application/octet-stream": {
+                        "schema": {
+                            "type": "string",
+                            "format": "binary"
+                        }
+                    }
+                }
+            },
+            "202": {
+                "description": "optional file content",
+                "content": {
+                    "application/octet-stream": {
+                        "schema": {
+                            "type": "string",
+                            "format": "binary",
+                            "nullable": true
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
 test_fn! {
     module: response_with_json_example,
     responses: (
@@ -269,6 +340,65 @@ fn derive_response_with_json_example_success() {
     }
 }
 
+#[test]
+fn derive_response_with_json_example_multiple_content_types() {
+    test_fn! {
+        module: response_with_json_example_multiple_content_types,
+        responses: (
+            (status = 200, description = "success", body = Foo, content_type = ["text/xml", "application/json"], example = json!({"foo": "bar"}))
+        )
+    }
+
+    let doc = api_doc!(module: response_with_json_example_multiple_content_types);
+
+    assert_value! {doc=>
+        "responses.200.content.application~1json.example" = r###"{"foo":"bar"}"###, "Response content example"
+        "responses.200.content.text~1xml.example" = r###"{"foo":"bar"}"###, "Response content example"
+    }
+}
+
+#[test]
+fn derive_response_with_links() {
+    test_fn! {
+        module: response_with_links,
+        responses: (
+            (status = 201, description = "created", body = Foo, links(
+                ("get_foo" = (
+                    operation_id = "get_foo",
+                    parameters(("name" = "$response.body#/name"), ("limit" = 10)),
+                    description = "Get created foo"
+                )),
+                ("update_foo" = (
+                    operation_ref = "#/paths/~1foo/put",
+                    request_body = json!({"name": "$response.body#/name"})
+                ))
+            ))
+        )
+    }
+
+    let doc = api_doc!(module: response_with_links);
+
+    assert_json_eq!(
+        doc.pointer("/responses/201/links").unwrap(),
+        json!({
+            "get_foo": {
+                "operationId": "get_foo",
+                "parameters": {
+                    "limit": 10,
+                    "name": "$response.body#/name"
+                },
+                "description": "Get created foo"
+            },
+            "update_foo": {
+                "operationRef": "#/paths/~1foo/put",
+                "requestBody": {
+                    "name": "$response.body#/name"
+                }
+            }
+        })
+    );
+}
+
 #[test]
 fn derive_response_multiple_content_types() {
     test_fn! {
@@ -290,6 +420,308 @@ fn derive_response_multiple_content_types() {
     }
 }
 
+#[test]
+fn derive_path_with_user_response_status() {
+    #[derive(Clone, Copy)]
+    #[allow(unused)]
+    enum ApiStatus {
+        Conflict = 409,
+        Gone = 410,
+    }
+
+    impl utoipa::ToResponseStatus for ApiStatus {
+        fn to_response_status(&self) -> String {
+            (*self as u16).to_string()
+        }
+    }
+
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum ApiError {
+        #[response(status = ApiStatus::Gone, description = "Resource is gone")]
+        Gone,
+        #[response(status = 400, description = "Invalid request")]
+        BadRequest,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = http::StatusCode::OK, description = "success"),
+            (status = ApiStatus::Conflict, description = "conflict"),
+            ApiError
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1foo/get/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "success"
+            },
+            "400": {
+                "description": "Invalid request"
+            },
+            "409": {
+                "description": "conflict"
+            },
+            "410": {
+                "description": "Resource is gone"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_into_responses_error_enum() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct ErrorBody {
+        message: String,
+    }
+
+    #[derive(utoipa::IntoResponses)]
+    #[allow(unused)]
+    enum ApiError {
+        #[response(status = 400, description = "Invalid request")]
+        BadRequest(ErrorBody),
+
+        /// Resource was not found
+        #[response(status = 404)]
+        NotFound,
+
+        #[response(status = "5XX", description = "Server failure")]
+        Internal { message: String },
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+            ApiError
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1foo/get/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "success"
+            },
+            "400": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/ErrorBody"
+                        }
+                    }
+                },
+                "description": "Invalid request"
+            },
+            "404": {
+                "description": "Resource was not found"
+            },
+            "5XX": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "properties": {
+                                "message": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["message"],
+                            "type": "object"
+                        }
+                    }
+                },
+                "description": "Server failure"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_response_with_external_docs() {
+    const BAD_REQUEST: &str = "Invalid request";
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 400, description = BAD_REQUEST, external_docs(url = "https://docs.example.com/errors", description = "Error codes")),
+            (status = 410, deprecated = "use 404 instead", external_docs(url = "https://docs.example.com/errors")),
+            (status = 500, external_docs(url = "https://docs.example.com/errors"))
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1foo/get/responses").unwrap(),
+        json!({
+            "400": {
+                "description": "Invalid request\n\n[Error codes](https://docs.example.com/errors)"
+            },
+            "410": {
+                "description": "Deprecated: use 404 instead\n\n<https://docs.example.com/errors>"
+            },
+            "500": {
+                "description": "<https://docs.example.com/errors>"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_response_description_from_const() {
+    const NOT_FOUND: &str = "Foo was not found";
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+            (status = 404, description = NOT_FOUND),
+            (status = 410, description = NOT_FOUND, deprecated = "use 404 instead")
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1foo/get/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "success"
+            },
+            "404": {
+                "description": "Foo was not found"
+            },
+            "410": {
+                "description": "Foo was not found\n\nDeprecated: use 404 instead"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_response_with_cfg() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+            cfg(all()) (status = 400, description = "included"),
+            cfg(any()) (status = 500, description = "excluded"),
+            cfg(all()) cfg(any()) (status = 501, description = "excluded")
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1foo/get/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "success"
+            },
+            "400": {
+                "description": "included"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_response_primitive_body_with_json_content_type() {
+    test_fn! {
+        module: response_primitive_json,
+        responses: (
+            (status = 200, description = "count", body = i64, content_type = "application/json"),
+            (status = 201, description = "created count", body = i64, json_primitive),
+            (status = 202, description = "plain count", body = i64)
+        )
+    }
+
+    let doc: Value = api_doc!(module: response_primitive_json);
+
+    assert_json_eq!(
+        doc.pointer("/responses").unwrap(),
+        json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "integer",
+                            "format": "int64"
+                        }
+                    }
+                },
+                "description": "count"
+            },
+            "201": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "integer",
+                            "format": "int64"
+                        }
+                    }
+                },
+                "description": "created count"
+            },
+            "202": {
+                "content": {
+                    "text/plain": {
+                        "schema": {
+                            "type": "integer",
+                            "format": "int64"
+                        }
+                    }
+                },
+                "description": "plain count"
+            }
+        })
+    );
+}
+
 #[test]
 fn derive_response_body_inline_schema_component() {
     test_fn! {
@@ -397,16 +829,403 @@ fn derive_path_with_multiple_responses_via_content_attribute() {
 }
 
 #[test]
-fn derive_path_with_multiple_examples() {
+fn derive_path_with_multiple_responses_via_content_attribute_body_first() {
     #[derive(serde::Serialize, utoipa::ToSchema)]
     #[allow(unused)]
-    struct User {
+    struct Pet {
         name: String,
     }
 
-    #[utoipa::path(
-        get,
-        path = "/foo", 
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct PetXml {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success", content(
+                    (Pet = "application/json", example = json!({"name": "bob"})),
+                    (PetXml = "text/xml"),
+                    ("text/plain" = String)
+                )
+            )
+        )
+    )]
+    #[allow(unused)]
+    fn get_item() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(get_item), components(schemas(Pet, PetXml)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let responses = doc.pointer("/paths/~1foo/get/responses").unwrap();
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "example": {
+                            "name": "bob",
+                        },
+                        "schema": {
+                            "$ref": "#/components/schemas/Pet",
+                        },
+                    },
+                    "text/xml": {
+                        "schema": {
+                            "$ref": "#/components/schemas/PetXml",
+                        },
+                    },
+                    "text/plain": {
+                        "schema": {
+                            "type": "string",
+                        },
+                    },
+                },
+                "description": "success",
+            },
+        })
+    )
+}
+
+#[test]
+fn derive_path_with_different_schema_and_example_per_content_type() {
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "Pets in requested format", content(
+                    ("application/json" = Vec<Pet>, example = json!([{"name": "bob"}])),
+                    ("text/csv" = String, example = json!("name\nbob"))
+                )
+            )
+        )
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(get_pets), components(schemas(Pet)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let content = doc
+        .pointer("/paths/~1pets/get/responses/200/content")
+        .unwrap();
+
+    assert_json_eq!(
+        content,
+        json!({
+            "application/json": {
+                "example": [{ "name": "bob" }],
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/Pet",
+                    },
+                },
+            },
+            "text/csv": {
+                "example": "name\nbob",
+                "schema": {
+                    "type": "string",
+                },
+            },
+        })
+    )
+}
+
+#[test]
+fn derive_path_with_server_sent_events_response() {
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct PetEvent {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets/events",
+        responses(
+            (status = 200, description = "Stream of pet events", body = PetEvent,
+                events = ["pet_created", "pet_deleted"]),
+            (status = 201, description = "Stream of pet events", body = PetEvent,
+                content_type = "text/event-stream")
+        )
+    )]
+    #[allow(unused)]
+    fn pet_events() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(pet_events), components(schemas(PetEvent)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let responses = doc.pointer("/paths/~1pets~1events/get/responses").unwrap();
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "description": "Stream of pet events",
+                "content": {
+                    "text/event-stream": {
+                        "schema": {
+                            "$ref": "#/components/schemas/PetEvent"
+                        },
+                        "x-events": ["pet_created", "pet_deleted"]
+                    }
+                }
+            },
+            "201": {
+                "description": "Stream of pet events",
+                "content": {
+                    "text/event-stream": {
+                        "schema": {
+                            "$ref": "#/components/schemas/PetEvent"
+                        }
+                    }
+                }
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_path_with_inline_object_response_body() {
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success", body = {
+                id: i64,
+                r#type: Option<String>,
+                pets: Vec<Pet>,
+                owner: { name: String },
+            }),
+            (status = 400, content(
+                ({ message: String } = "application/json")
+            ))
+        )
+    )]
+    #[allow(unused)]
+    fn get_item() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(get_item))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let responses = doc.pointer("/paths/~1foo/get/responses").unwrap();
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "integer",
+                                    "format": "int64",
+                                },
+                                "type": {
+                                    "type": "string",
+                                    "nullable": true,
+                                },
+                                "pets": {
+                                    "type": "array",
+                                    "items": {
+                                        "$ref": "#/components/schemas/Pet",
+                                    },
+                                },
+                                "owner": {
+                                    "type": "object",
+                                    "properties": {
+                                        "name": {
+                                            "type": "string",
+                                        },
+                                    },
+                                    "required": ["name"],
+                                },
+                            },
+                            "required": ["id", "pets", "owner"],
+                        },
+                    },
+                },
+                "description": "success",
+            },
+            "400": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "message": {
+                                    "type": "string",
+                                },
+                            },
+                            "required": ["message"],
+                        },
+                    },
+                },
+                "description": "",
+            },
+        })
+    )
+}
+
+#[test]
+fn derive_path_with_reusable_header_reference() {
+    use utoipa::openapi::{Header, HeaderBuilder, Object, SchemaType};
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success", headers(
+                ("x-rate-limit" = ref("#/components/headers/RateLimit")),
+                ("x-request-id")
+            ))
+        )
+    )]
+    #[allow(unused)]
+    fn get_item() {}
+
+    struct RateLimitHeader;
+
+    impl utoipa::Modify for RateLimitHeader {
+        fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+            let header: Header = HeaderBuilder::new()
+                .schema(Object::with_type(SchemaType::Integer))
+                .description(Some("Remaining requests"))
+                .build();
+            openapi
+                .components
+                .get_or_insert_with(Default::default)
+                .headers
+                .insert(String::from("RateLimit"), header.into());
+        }
+    }
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(get_item), modifiers(&RateLimitHeader))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1foo/get/responses/200/headers")
+            .unwrap(),
+        json!({
+            "x-rate-limit": {
+                "$ref": "#/components/headers/RateLimit"
+            },
+            "x-request-id": {
+                "schema": {
+                    "type": "string"
+                }
+            }
+        })
+    );
+    assert_json_eq!(
+        doc.pointer("/components/headers").unwrap(),
+        json!({
+            "RateLimit": {
+                "schema": {
+                    "type": "integer"
+                },
+                "description": "Remaining requests"
+            }
+        })
+    );
+    assert_eq!(ApiDoc::openapi().validate(), Ok(()));
+}
+
+#[test]
+fn derive_path_with_header_required_deprecated_and_example() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success", headers(
+                ("x-request-id" = String, required, example = "abc-123"),
+                ("x-old-token", description = "Old token", deprecated, required = false),
+                ("x-count" = i32, example = json!(10), deprecated = false)
+            ))
+        )
+    )]
+    #[allow(unused)]
+    fn get_item() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(get_item))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1foo/get/responses/200/headers")
+            .unwrap(),
+        json!({
+            "x-request-id": {
+                "schema": {
+                    "type": "string"
+                },
+                "required": true,
+                "example": "abc-123"
+            },
+            "x-old-token": {
+                "schema": {
+                    "type": "string"
+                },
+                "description": "Old token",
+                "required": false,
+                "deprecated": true
+            },
+            "x-count": {
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                },
+                "deprecated": false,
+                "example": 10
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_path_with_multiple_examples() {
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct User {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo", 
         responses(
             (status = 200, body = User,
                 examples(
@@ -636,3 +1455,227 @@ fn path_response_with_inline_ref_type() {
         })
     )
 }
+
+#[test]
+fn path_response_with_generic_type_body() {
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[aliases(PagePet = Page<Pet>)]
+    #[allow(unused)]
+    struct Page<T> {
+        items: Vec<T>,
+        total: usize,
+    }
+
+    #[derive(serde::Serialize, utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, body = Page<Pet>),
+            (status = 206, body = inline(Page<String>))
+        )
+    )]
+    #[allow(unused)]
+    fn list_pets() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(list_pets), components(schemas(Pet, PagePet)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1pets/get/responses").unwrap(),
+        json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/PagePet"
+                        }
+                    }
+                },
+                "description": ""
+            },
+            "206": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "items": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "string"
+                                    }
+                                },
+                                "total": {
+                                    "type": "integer",
+                                    "minimum": 0
+                                }
+                            },
+                            "required": ["items", "total"]
+                        }
+                    }
+                },
+                "description": ""
+            }
+        })
+    );
+    assert_json_eq!(
+        doc.pointer("/components/schemas/PagePet").unwrap(),
+        json!({
+            "type": "object",
+            "properties": {
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/Pet"
+                    }
+                },
+                "total": {
+                    "type": "integer",
+                    "minimum": 0
+                }
+            },
+            "required": ["items", "total"]
+        })
+    );
+}
+
+#[test]
+fn path_response_with_to_header_headers() {
+    #![allow(unused)]
+
+    #[derive(utoipa::ToHeader)]
+    #[header(rename_all = "kebab-case")]
+    struct RateLimitHeaders {
+        /// Allowed requests per hour
+        #[header(rename = "x-rate-limit-limit")]
+        limit: u32,
+        #[header(rename = "x-rate-limit-remaining", example = 59)]
+        remaining: u32,
+        #[deprecated]
+        #[header(value_type = String)]
+        reset_at: u64,
+        retry_after: Option<u64>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success", headers(RateLimitHeaders)),
+            (status = 429, description = "too many requests", headers(
+                RateLimitHeaders,
+                ("x-request-id" = String, required)
+            ))
+        )
+    )]
+    fn list_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let rate_limit_headers = json!({
+        "x-rate-limit-limit": {
+            "schema": {
+                "type": "integer",
+                "format": "int32",
+                "minimum": 0
+            },
+            "description": "Allowed requests per hour",
+            "required": true
+        },
+        "x-rate-limit-remaining": {
+            "schema": {
+                "type": "integer",
+                "format": "int32",
+                "minimum": 0
+            },
+            "required": true,
+            "example": 59
+        },
+        "reset-at": {
+            "schema": {
+                "type": "string"
+            },
+            "required": true,
+            "deprecated": true
+        },
+        "retry-after": {
+            "schema": {
+                "type": "integer",
+                "format": "int64",
+                "minimum": 0
+            },
+            "required": false
+        }
+    });
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1pets/get/responses/200/headers")
+            .unwrap(),
+        rate_limit_headers
+    );
+
+    let mut too_many_requests_headers = rate_limit_headers;
+    too_many_requests_headers["x-request-id"] = json!({
+        "schema": {
+            "type": "string"
+        },
+        "required": true
+    });
+    assert_json_eq!(
+        doc.pointer("/paths/~1pets/get/responses/429/headers")
+            .unwrap(),
+        too_many_requests_headers
+    );
+}
+
+#[test]
+fn derive_response_text_content_type_without_body() {
+    test_fn! {
+        module: response_text_content_type_without_body,
+        responses: (
+            (status = 200, description = "page", content_type = "text/html; charset=utf-8", example = "<html></html>"),
+            (status = 400, description = "error", content_type = ["text/plain", "application/json"]),
+        )
+    }
+
+    let doc = api_doc!(module: response_text_content_type_without_body);
+
+    assert_json_eq!(
+        doc.pointer("/responses").unwrap(),
+        json!({
+            "200": {
+                "description": "page",
+                "content": {
+                    "text/html; charset=utf-8": {
+                        "schema": {
+                            "type": "string"
+                        },
+                        "example": "<html></html>"
+                    }
+                }
+            },
+            "400": {
+                "description": "error",
+                "content": {
+                    "text/plain": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
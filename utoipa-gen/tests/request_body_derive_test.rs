@@ -455,26 +455,40 @@ fn derive_request_body_complex_primitive_array_success() {
     );
 }
 
-test_fn! {
-    module: derive_request_body_ref_path,
-    body: = path::to::Foo
+mod path {
+    pub mod to {
+        /// Some struct
+        #[derive(utoipa::ToSchema)]
+        #[schema(as = path::to::Foo)]
+        #[allow(unused)]
+        pub struct Foo {
+            /// Some name
+            name: String,
+        }
+    }
+}
+
+#[allow(unused)]
+mod derive_request_body_ref_path {
+    use super::path;
+
+    #[utoipa::path(
+        post,
+        path = "/foo",
+        request_body = path::to::Foo,
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    fn post_foo() {}
 }
 
 #[test]
 fn derive_request_body_ref_path_success() {
-    /// Some struct
-    #[derive(ToSchema)]
-    #[schema(as = path::to::Foo)]
-    #[allow(unused)]
-    pub struct Foo {
-        /// Some name
-        name: String,
-    }
-
     #[derive(OpenApi, Default)]
     #[openapi(
         paths(derive_request_body_ref_path::post_foo),
-        components(schemas(Foo))
+        components(schemas(path::to::Foo))
     )]
     struct ApiDoc;
 
@@ -615,6 +629,46 @@ fn request_body_with_examples() {
     )
 }
 
+#[test]
+fn request_body_with_examples_summary_and_external_value() {
+    #[utoipa::path(
+        get,
+        path = "/item",
+        request_body(content = String,
+            examples(
+                ("Cat" = (summary = "A cat", description = "Cat **example**", value = json!("cat"))),
+                ("Dog" = (summary = "A dog", external_value = "https://example.com/dog.json"))
+            )
+        )
+    )]
+    #[allow(dead_code)]
+    fn get_item() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_item))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    let examples = doc
+        .pointer("/paths/~1item/get/requestBody/content/text~1plain/examples")
+        .unwrap();
+    assert_json_eq!(
+        examples,
+        json!({
+            "Cat": {
+                "summary": "A cat",
+                "description": "Cat **example**",
+                "value": "cat"
+            },
+            "Dog": {
+                "summary": "A dog",
+                "externalValue": "https://example.com/dog.json"
+            }
+        })
+    )
+}
+
 #[test]
 fn request_body_with_binary() {
     #[utoipa::path(get, path = "/item", request_body(content = [u8]))]
@@ -669,3 +723,454 @@ fn request_body_with_external_ref() {
         })
     )
 }
+
+#[test]
+fn request_body_with_optional_external_ref() {
+    #[utoipa::path(get, path = "/item", request_body = Option<ref("./MyUser.json")>)]
+    #[allow(dead_code)]
+    fn get_item() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(get_item))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    let body = doc.pointer("/paths/~1item/get/requestBody").unwrap();
+    assert_json_eq!(
+        body,
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "allOf": [
+                            {
+                                "$ref": "./MyUser.json"
+                            }
+                        ],
+                        "nullable": true
+                    }
+                }
+            },
+            "required": false
+        })
+    )
+}
+
+#[test]
+fn request_body_with_external_ref_content_type_and_required() {
+    #[utoipa::path(
+        get,
+        path = "/item",
+        request_body(content = ref("./MyUser.xml"), content_type = "text/xml", required)
+    )]
+    #[allow(dead_code)]
+    fn get_item() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(get_item))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    let body = doc.pointer("/paths/~1item/get/requestBody").unwrap();
+    assert_json_eq!(
+        body,
+        json!({
+            "content": {
+                "text/xml": {
+                    "schema": {
+                        "$ref": "./MyUser.xml"
+                    }
+                }
+            },
+            "required": true
+        })
+    )
+}
+
+test_fn! {
+    module: derive_request_body_option_required_override,
+    body: (content = Option<Foo>, description = "Create new Foo", required = true)
+}
+
+#[test]
+fn derive_request_body_option_required_override_success() {
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(derive_request_body_option_required_override::post_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let body = doc.pointer("/paths/~1foo/post/requestBody").unwrap();
+
+    assert_json_eq!(
+        body,
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "allOf": [
+                            {
+                                "$ref": "#/components/schemas/Foo"
+                            }
+                        ],
+                        "nullable": true,
+                    }
+                }
+            },
+            "description": "Create new Foo",
+            "required": true,
+        })
+    );
+}
+
+#[test]
+fn request_body_multipart_with_encoding() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct UploadForm {
+        #[schema(value_type = String, format = Binary)]
+        file: Vec<u8>,
+        tags: Vec<String>,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/upload",
+        request_body(
+            content = UploadForm,
+            content_type = "multipart/form-data",
+            encoding(
+                ("file" = (content_type = "image/png, image/jpeg")),
+                ("tags" = (style = Form, explode, allow_reserved = false))
+            )
+        )
+    )]
+    #[allow(dead_code)]
+    fn upload() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(upload), components(schemas(UploadForm)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1upload/post/requestBody").unwrap(),
+        json!({
+            "content": {
+                "multipart/form-data": {
+                    "schema": {
+                        "$ref": "#/components/schemas/UploadForm"
+                    },
+                    "encoding": {
+                        "file": {
+                            "contentType": "image/png, image/jpeg"
+                        },
+                        "tags": {
+                            "style": "form",
+                            "explode": true,
+                            "allowReserved": false
+                        }
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+    assert_json_eq!(
+        doc.pointer("/components/schemas/UploadForm/properties/file")
+            .unwrap(),
+        json!({
+            "type": "string",
+            "format": "binary"
+        })
+    );
+}
+
+#[test]
+fn request_body_with_virtual_file_type() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct UploadForm {
+        #[schema(value_type = File)]
+        file: Vec<u8>,
+        #[schema(value_type = Vec<File>)]
+        attachments: Vec<Vec<u8>>,
+    }
+
+    #[utoipa::path(post, path = "/upload", request_body = File)]
+    #[allow(dead_code)]
+    fn upload() {}
+
+    #[utoipa::path(
+        post,
+        path = "/upload-form",
+        request_body(content = UploadForm, content_type = "multipart/form-data")
+    )]
+    #[allow(dead_code)]
+    fn upload_form() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(upload, upload_form), components(schemas(UploadForm)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1upload/post/requestBody").unwrap(),
+        json!({
+            "content": {
+                "application/octet-stream": {
+                    "schema": {
+                        "type": "string",
+                        "format": "binary"
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+    assert_json_eq!(
+        doc.pointer("/components/schemas/UploadForm/properties")
+            .unwrap(),
+        json!({
+            "file": {
+                "type": "string",
+                "format": "binary"
+            },
+            "attachments": {
+                "type": "array",
+                "items": {
+                    "type": "string",
+                    "format": "binary"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn request_body_form_urlencoded() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct LoginForm {
+        username: String,
+        remember_me: Option<bool>,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/login",
+        request_body(content = inline(LoginForm), content_type = "application/x-www-form-urlencoded")
+    )]
+    #[allow(dead_code)]
+    fn login() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(login))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1login/post/requestBody").unwrap(),
+        json!({
+            "content": {
+                "application/x-www-form-urlencoded": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "username": {
+                                "type": "string"
+                            },
+                            "remember_me": {
+                                "type": "boolean",
+                                "nullable": true
+                            }
+                        },
+                        "required": ["username"]
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+}
+
+#[test]
+fn request_body_with_inline_object() {
+    #[utoipa::path(
+        post,
+        path = "/login",
+        request_body = { username: String, remember_me: Option<bool> }
+    )]
+    #[allow(dead_code)]
+    fn login() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(login))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1login/post/requestBody").unwrap(),
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "username": {
+                                "type": "string"
+                            },
+                            "remember_me": {
+                                "type": "boolean",
+                                "nullable": true
+                            }
+                        },
+                        "required": ["username"]
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+}
+
+#[test]
+fn request_body_with_optional_inline_object() {
+    #[utoipa::path(
+        post,
+        path = "/login",
+        request_body(content = Option<{ username: String }>, description = "Optional login")
+    )]
+    #[allow(dead_code)]
+    fn login() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(paths(login))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1login/post/requestBody").unwrap(),
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "allOf": [
+                            {
+                                "type": "object",
+                                "properties": {
+                                    "username": {
+                                        "type": "string"
+                                    }
+                                },
+                                "required": ["username"]
+                            }
+                        ],
+                        "nullable": true
+                    }
+                }
+            },
+            "description": "Optional login",
+            "required": false
+        })
+    );
+}
+
+test_fn! {
+    module: derive_request_body_option_inline,
+    body: = Option<inline(Foo)>
+}
+
+#[test]
+fn derive_request_body_option_inline_success() {
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(derive_request_body_option_inline::post_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let body = doc.pointer("/paths/~1foo/post/requestBody").unwrap();
+
+    assert_json_eq!(
+        body,
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "allOf": [
+                            {
+                                "description": "Some struct",
+                                "type": "object",
+                                "properties": {
+                                    "name": {
+                                        "description": "Some name",
+                                        "type": "string"
+                                    }
+                                },
+                                "required": ["name"]
+                            }
+                        ],
+                        "nullable": true
+                    }
+                }
+            },
+            "required": false
+        })
+    );
+}
+
+#[test]
+fn derive_request_body_form_content_type_from_schema() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    #[schema(form)]
+    struct LoginForm {
+        username: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Login {
+        username: String,
+    }
+
+    #[utoipa::path(post, path = "/login", request_body = LoginForm)]
+    fn login() {}
+
+    #[utoipa::path(post, path = "/api/login", request_body = Option<Login>)]
+    fn api_login() {}
+
+    #[utoipa::path(
+        post,
+        path = "/login/text",
+        request_body(content = LoginForm, content_type = "text/plain")
+    )]
+    fn login_text() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(login, api_login, login_text))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let content_types = |path: &str| {
+        doc.pointer(&format!("/paths/{path}/post/requestBody/content"))
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        content_types("~1login"),
+        ["application/x-www-form-urlencoded"]
+    );
+    assert_eq!(content_types("~1api~1login"), ["application/json"]);
+    assert_eq!(content_types("~1login~1text"), ["text/plain"]);
+}
@@ -140,6 +140,49 @@ fn derive_struct_response_with_description() {
     )
 }
 
+#[test]
+fn derive_struct_response_with_schema_field_attributes() {
+    #[derive(serde::Serialize, ToResponse)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(unused)]
+    struct Person {
+        first_name: String,
+        #[serde(skip)]
+        secret: String,
+        #[schema(example = "Doe", max_length = 50)]
+        last_name: Option<String>,
+    }
+    let (name, v) = <Person as utoipa::ToResponse>::response();
+    let value = serde_json::to_value(v).unwrap();
+
+    assert_eq!("Person", name);
+    assert_json_eq!(
+        value,
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "properties": {
+                            "firstName": {
+                                "type": "string"
+                            },
+                            "lastName": {
+                                "type": "string",
+                                "example": "Doe",
+                                "maxLength": 50,
+                                "nullable": true
+                            }
+                        },
+                        "type": "object",
+                        "required": ["firstName"]
+                    }
+                }
+            },
+            "description": ""
+        })
+    )
+}
+
 #[test]
 fn derive_response_with_attributes() {
     /// This is description
@@ -800,3 +843,69 @@ fn derive_into_responses_enum_with_multiple_responses() {
         })
     )
 }
+
+#[test]
+fn derive_response_with_deprecated_note() {
+    /// Old person response
+    #[derive(ToResponse)]
+    #[response(deprecated = "Use NewPerson instead")]
+    #[allow(unused)]
+    struct Person {
+        name: String,
+    }
+    let (_, v) = <Person as utoipa::ToResponse>::response();
+    let value = serde_json::to_value(v).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/description").unwrap(),
+        json!("Old person response\n\nDeprecated: Use NewPerson instead")
+    );
+}
+
+#[test]
+fn derive_into_responses_with_deprecated_note() {
+    let responses = into_responses! {
+        enum Responses {
+            /// Success
+            #[response(status = 200)]
+            Success,
+            #[response(status = 404, deprecated = "Use 400 instead")]
+            NotFound,
+        }
+    };
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "description": "Success"
+            },
+            "404": {
+                "description": "Deprecated: Use 400 instead"
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_into_responses_with_external_docs() {
+    let responses = into_responses! {
+        enum Responses {
+            /// Bad request
+            #[response(
+                status = 400,
+                external_docs(url = "https://docs.example.com/errors", description = "Error codes")
+            )]
+            BadRequest,
+        }
+    };
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "400": {
+                "description": "Bad request\n\n[Error codes](https://docs.example.com/errors)"
+            }
+        })
+    )
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "serde_with")]
+
+use std::time::{Duration, SystemTime};
+
+use assert_json_diff::assert_json_eq;
+use serde_json::json;
+use serde_with::{
+    base64::Base64, serde_as, DisplayFromStr, DurationSeconds, DurationSecondsWithFrac,
+    TimestampSeconds,
+};
+use utoipa::{IntoParams, ToSchema};
+
+#[test]
+fn derive_schema_with_serde_as_attributes() {
+    #[serde_as]
+    #[derive(serde::Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Config {
+        #[serde_as(as = "DisplayFromStr")]
+        id: u64,
+        #[serde_as(as = "Base64")]
+        key: Vec<u8>,
+        #[serde_as(as = "DurationSeconds")]
+        timeout: Duration,
+        #[serde_as(as = "DurationSeconds<String>")]
+        retry: Duration,
+        #[serde_as(as = "DurationSecondsWithFrac")]
+        interval: Duration,
+        #[serde_as(as = "TimestampSeconds<i64>")]
+        created: SystemTime,
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        parent: Option<u32>,
+        #[serde_as(as = "Vec<DisplayFromStr>")]
+        children: Vec<u32>,
+    }
+
+    let schema = serde_json::to_value(<Config as ToSchema>::schema().1).unwrap();
+    let properties = schema.pointer("/properties").unwrap();
+
+    assert_json_eq!(
+        properties,
+        json!({
+            "id": {
+                "type": "string"
+            },
+            "key": {
+                "type": "string",
+                "format": "byte"
+            },
+            "timeout": {
+                "type": "integer",
+                "format": "int64",
+                "minimum": 0
+            },
+            "retry": {
+                "type": "string"
+            },
+            "interval": {
+                "type": "number",
+                "format": "double"
+            },
+            "created": {
+                "type": "integer",
+                "format": "int64"
+            },
+            "parent": {
+                "type": "string",
+                "nullable": true
+            },
+            "children": {
+                "type": "array",
+                "items": {
+                    "type": "string"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_schema_value_type_overrides_serde_as_attribute() {
+    #[serde_as]
+    #[derive(serde::Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Config {
+        #[serde_as(as = "DisplayFromStr")]
+        #[schema(value_type = f64)]
+        ratio: Ratio,
+    }
+
+    struct Ratio(f64);
+
+    impl std::fmt::Display for Ratio {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    let schema = serde_json::to_value(<Config as ToSchema>::schema().1).unwrap();
+    let ratio = schema.pointer("/properties/ratio").unwrap();
+
+    assert_json_eq!(
+        ratio,
+        json!({
+            "type": "number",
+            "format": "double"
+        })
+    );
+}
+
+#[test]
+fn derive_into_params_with_serde_as_attributes() {
+    #[serde_as]
+    #[derive(serde::Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        id: Option<u64>,
+        #[serde_as(as = "Base64")]
+        token: Vec<u8>,
+    }
+
+    let parameters = serde_json::to_value(Filter::into_params(|| None)).unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "id",
+                "required": false,
+                "schema": {
+                    "type": "string",
+                    "nullable": true
+                }
+            },
+            {
+                "in": "query",
+                "name": "token",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "format": "byte"
+                }
+            }
+        ])
+    );
+}
@@ -1,4 +1,10 @@
-use std::{borrow::Cow, cell::RefCell, collections::HashMap, marker::PhantomData, vec};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+    vec,
+};
 
 use assert_json_diff::{assert_json_eq, assert_json_matches, CompareMode, Config, NumericMode};
 use serde::Serialize;
@@ -101,6 +107,52 @@ fn derive_map_ref() {
     )
 }
 
+#[test]
+fn derive_map_with_nullable_ref_values() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        name: String,
+    }
+
+    let map = api_doc! {
+        struct Map {
+            map: BTreeMap<String, Option<Foo>>,
+            items: HashMap<String, Vec<Foo>>,
+        }
+    };
+
+    assert_json_eq!(
+        map,
+        json!({
+            "properties": {
+                "map": {
+                    "additionalProperties": {
+                        "allOf": [
+                            {
+                                "$ref": "#/components/schemas/Foo"
+                            }
+                        ],
+                        "nullable": true
+                    },
+                    "type": "object",
+                },
+                "items": {
+                    "additionalProperties": {
+                        "items": {
+                            "$ref": "#/components/schemas/Foo"
+                        },
+                        "type": "array"
+                    },
+                    "type": "object"
+                }
+            },
+            "required": ["map", "items"],
+            "type": "object"
+        })
+    )
+}
+
 #[test]
 fn derive_map_free_form_property() {
     let map = api_doc! {
@@ -169,6 +221,52 @@ fn derive_flattened_map_ref_property() {
     )
 }
 
+#[test]
+fn derive_flattened_map_value_property() {
+    let map = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            id: String,
+            #[serde(flatten)]
+            extra: HashMap<String, serde_json::Value>,
+        }
+    };
+
+    assert_json_eq!(
+        map,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "string"
+                }
+            },
+            "required": ["id"],
+            "additionalProperties": {},
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_flattened_map_with_deny_unknown_fields() {
+    let map = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Map {
+            #[serde(flatten)]
+            map: HashMap<String, String>,
+        }
+    };
+
+    assert_json_eq!(
+        map,
+        json!({
+            "additionalProperties": {"type": "string"},
+            "type": "object"
+        })
+    )
+}
+
 #[test]
 fn derive_enum_with_additional_properties_success() {
     let mode = api_doc! {
@@ -379,6 +477,54 @@ fn derive_struct_with_default_attr_field() {
     );
 }
 
+#[test]
+fn derive_struct_with_default_attr_field_from_type_default() {
+    let value = api_doc! {
+        struct Value {
+            #[schema(default)]
+            count: i32,
+            #[schema(default)]
+            enabled: bool,
+            #[schema(default = 10)]
+            limit: u8,
+            #[schema(default)]
+            tags: Vec<String>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "count": {
+                    "type": "integer",
+                    "format": "int32",
+                    "default": 0,
+                },
+                "enabled": {
+                    "type": "boolean",
+                    "default": false,
+                },
+                "limit": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0,
+                    "default": 10,
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                    },
+                    "default": [],
+                },
+            },
+            "required": ["count", "enabled", "limit", "tags"],
+            "type": "object",
+        })
+    );
+}
+
 #[test]
 fn derive_struct_with_serde_default_attr() {
     let book = api_doc! {
@@ -601,6 +747,34 @@ fn derive_struct_unnamed_fields_tuple_with_different_types_success() {
     }
 }
 
+#[test]
+fn derive_struct_unnamed_fields_tuple_with_skipped_field() {
+    let point = api_doc! {
+        #[derive(Serialize)]
+        struct Point(f64, #[serde(skip)] String, f64);
+    };
+
+    assert_value! {point=>
+        "type" = r#""array""#, "Point type"
+        "items.type" = r#""number""#, "Point items type"
+        "items.format" = r#""double""#, "Point items format"
+        "maxItems" = r#"2"#, "Point max items"
+        "minItems" = r#"2"#, "Point min items"
+    }
+
+    let value = api_doc! {
+        #[derive(Serialize)]
+        struct Value(String, #[serde(skip_serializing)] i32);
+    };
+
+    assert_value! {value=>
+        "type" = r#""array""#, "Value type"
+        "items.type" = r#""string""#, "Value items type"
+        "maxItems" = r#"1"#, "Value max items"
+        "minItems" = r#"1"#, "Value min items"
+    }
+}
+
 #[test]
 fn derive_struct_unnamed_field_with_generic_types_success() {
     let point = api_doc! {
@@ -1103,6 +1277,54 @@ fn derive_simple_enum_serde_tag() {
     );
 }
 
+#[test]
+fn derive_simple_enum_serde_adjacently_tagged() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content", rename_all = "lowercase")]
+        enum Bar {
+            A,
+            B,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "a",
+                            ],
+                        },
+                    },
+                    "required": [
+                        "tag",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "b",
+                            ],
+                        },
+                    },
+                    "required": [
+                        "tag",
+                    ],
+                },
+            ],
+        })
+    );
+}
+
 #[test]
 fn derive_simple_enum_serde_untagged() {
     let value: Value = api_doc! {
@@ -1145,6 +1367,42 @@ fn derive_struct_unnamed_field_reference_with_comment() {
     );
 }
 
+#[test]
+fn derive_enum_with_tag_discriminator_mapping() {
+    #[derive(Serialize, ToSchema)]
+    struct Cat {
+        name: String,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    struct Dog {
+        name: String,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "kind", rename_all = "lowercase")]
+        enum Pet {
+            Cat(Cat),
+            #[serde(rename = "doggo")]
+            Dog(Dog),
+            Stray(#[schema(inline)] Cat),
+            Unknown,
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/discriminator").unwrap(),
+        json!({
+            "propertyName": "kind",
+            "mapping": {
+                "cat": "#/components/schemas/Cat",
+                "doggo": "#/components/schemas/Dog"
+            }
+        })
+    );
+}
+
 /// Derive a complex enum with named and unnamed fields.
 #[test]
 fn derive_complex_unnamed_field_reference_with_comment() {
@@ -1315,7 +1573,10 @@ fn derive_enum_with_unnamed_single_field_with_tag() {
                 }
             ],
             "discriminator": {
-                "propertyName": "enum"
+                "propertyName": "enum",
+                "mapping": {
+                    "Value": "#/components/schemas/ReferenceValue"
+                }
             }
         })
     );
@@ -1399,7 +1660,10 @@ fn derive_enum_with_named_fields_with_reference_with_tag() {
                 }
             ],
             "discriminator": {
-                "propertyName": "enum"
+                "propertyName": "enum",
+                "mapping": {
+                    "UnnamedValue": "#/components/schemas/ReferenceValue"
+                }
             }
         })
     );
@@ -1471,6 +1735,48 @@ fn derive_complex_enum() {
     );
 }
 
+#[test]
+fn derive_complex_enum_with_skipped_and_multi_field_variants() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Bar {
+            UnitValue,
+            #[serde(skip)]
+            Skipped { id: i32 },
+            Point(i32, i32),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "string",
+                    "enum": [
+                        "UnitValue",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Point": {
+                            "type": "array",
+                            "items": {
+                                "type": "integer",
+                                "format": "int32",
+                            },
+                            "maxItems": 2,
+                            "minItems": 2,
+                        },
+                    },
+                    "required": ["Point"],
+                },
+            ],
+        })
+    );
+}
+
 #[test]
 fn derive_complex_enum_title() {
     #[derive(Serialize)]
@@ -1774,32 +2080,65 @@ fn derive_struct_custom_rename() {
 }
 
 #[test]
-fn derive_complex_enum_custom_rename() {
+fn derive_struct_serde_rename_serialize() {
     let value: Value = api_doc! {
-        #[schema(rename_all = "UPPERCASE")]
-        enum PostType {
-            NewPost(String),
-
-            #[schema(rename = "update_post", rename_all = "PascalCase")]
-            Update {
-                post_id: i64,
-                created_at: i64,
-                #[schema(rename = "post_comment")]
-                comment: String,
-            },
-
-            RandomValue {
-                id: i64,
-            },
+        #[derive(Serialize)]
+        #[serde(rename_all(serialize = "camelCase", deserialize = "snake_case"))]
+        struct Post {
+            post_id: i64,
+            #[serde(rename(serialize = "comment", deserialize = "post_comment"))]
+            post_comment: String,
         }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "oneOf": [
-                {
-                    "properties": {
+            "properties": {
+                "postId": {
+                    "type": "integer",
+                    "format": "int64",
+                },
+                "comment": {
+                    "type": "string",
+                },
+            },
+            "type": "object",
+            "required": [
+                "postId",
+                "comment"
+            ]
+        })
+    )
+}
+
+#[test]
+fn derive_complex_enum_custom_rename() {
+    let value: Value = api_doc! {
+        #[schema(rename_all = "UPPERCASE")]
+        enum PostType {
+            NewPost(String),
+
+            #[schema(rename = "update_post", rename_all = "PascalCase")]
+            Update {
+                post_id: i64,
+                created_at: i64,
+                #[schema(rename = "post_comment")]
+                comment: String,
+            },
+
+            RandomValue {
+                id: i64,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
                         "NEWPOST": {
                             "type": "string"
                         }
@@ -2179,6 +2518,43 @@ fn derive_serde_flatten() {
     );
 }
 
+#[test]
+fn derive_serde_flatten_all_fields() {
+    #[derive(Serialize)]
+    struct Metadata {
+        category: String,
+    }
+
+    #[derive(Serialize)]
+    struct Pagination {
+        page: i64,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        struct Page {
+            #[serde(flatten)]
+            metadata: Metadata,
+            #[serde(flatten)]
+            pagination: Pagination,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "allOf": [
+                {
+                    "$ref": "#/components/schemas/Metadata"
+                },
+                {
+                    "$ref": "#/components/schemas/Pagination"
+                },
+            ]
+        })
+    );
+}
+
 #[test]
 fn derive_complex_enum_serde_untagged() {
     let value: Value = api_doc! {
@@ -2716,6 +3092,193 @@ fn derive_struct_with_read_only_and_write_only() {
     }
 }
 
+#[test]
+fn derive_struct_with_self_references() {
+    let category = api_doc! {
+        struct Category {
+            name: String,
+            children: Vec<Category>,
+            parent: Option<Box<Self>>,
+            #[schema(inline)]
+            root: Box<Category>,
+        }
+    };
+
+    assert_json_eq!(
+        category,
+        json!({
+            "properties": {
+                "name": {
+                    "type": "string"
+                },
+                "children": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/Category"
+                    }
+                },
+                "parent": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Category"
+                        }
+                    ],
+                    "nullable": true
+                },
+                "root": {
+                    "$ref": "#/components/schemas/Category"
+                }
+            },
+            "required": ["name", "children", "root"],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn derive_enum_with_self_references() {
+    let tree = api_doc! {
+        enum Tree {
+            Leaf(String),
+            Node {
+                #[schema(inline)]
+                children: Vec<Tree>,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        tree,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Leaf": {
+                            "type": "string"
+                        }
+                    },
+                    "required": ["Leaf"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Node": {
+                            "type": "object",
+                            "properties": {
+                                "children": {
+                                    "type": "array",
+                                    "items": {
+                                        "$ref": "#/components/schemas/Tree"
+                                    }
+                                }
+                            },
+                            "required": ["children"]
+                        }
+                    },
+                    "required": ["Node"]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_read_only_and_write_only_reference_and_array_fields() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        id: i32,
+    }
+    let pet = api_doc! {
+        struct Pet {
+            #[schema(read_only)]
+            owner: Owner,
+            #[schema(write_only)]
+            previous_owner: Option<Owner>,
+            #[schema(read_only)]
+            tags: Vec<String>,
+        }
+    };
+
+    assert_json_eq!(
+        pet,
+        json!({
+            "properties": {
+                "owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "readOnly": true
+                },
+                "previous_owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "nullable": true,
+                    "writeOnly": true
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "readOnly": true
+                }
+            },
+            "required": ["owner", "tags"],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_option_fields_not_nullable() {
+    #[derive(ToSchema, Serialize)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+    let pet = api_doc! {
+        #[derive(Serialize)]
+        struct Pet {
+            #[schema(nullable = false)]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            name: Option<String>,
+            #[schema(nullable = false)]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            owner: Option<Owner>,
+            tags: Option<Vec<String>>,
+        }
+    };
+
+    assert_json_eq!(
+        pet,
+        json!({
+            "properties": {
+                "name": {
+                    "type": "string"
+                },
+                "owner": {
+                    "$ref": "#/components/schemas/Owner"
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "nullable": true
+                }
+            },
+            "type": "object"
+        })
+    )
+}
+
 #[test]
 fn derive_struct_with_nullable_and_required() {
     let user = api_doc! {
@@ -2786,6 +3349,47 @@ fn derive_struct_with_nullable_and_required() {
     )
 }
 
+#[test]
+fn derive_struct_with_explicit_required_overrides() {
+    let user = api_doc! {
+        struct User {
+            #[schema(required = false)]
+            nickname: String,
+            #[schema(required = true)]
+            email: Option<String>,
+            #[schema(required = false, nullable = false)]
+            phone: Option<String>,
+            name: String,
+        }
+    };
+
+    assert_json_eq!(
+        user,
+        json!({
+            "properties": {
+                "nickname": {
+                    "type": "string",
+                },
+                "email": {
+                    "type": "string",
+                    "nullable": true,
+                },
+                "phone": {
+                    "type": "string",
+                },
+                "name": {
+                    "type": "string",
+                }
+            },
+            "required": [
+                "email",
+                "name",
+            ],
+            "type": "object"
+        })
+    )
+}
+
 #[test]
 fn derive_struct_xml() {
     let user = api_doc! {
@@ -2886,20 +3490,105 @@ fn derive_struct_xml_with_optional_vec() {
     );
 }
 
-#[cfg(feature = "chrono")]
 #[test]
-fn derive_component_with_chrono_feature() {
-    #![allow(deprecated)] // allow deprecated Date in tests as long as it is available from chrono
-    use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+fn derive_struct_xml_with_reference_fields() {
+    #[derive(ToSchema)]
+    #[schema(xml(name = "pet"))]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+    let user = api_doc! {
+        struct User {
+            #[schema(xml(name = "favorite_pet"))]
+            pet: Pet,
+            #[schema(xml(name = "previous_pet", prefix = "u"))]
+            previous_pet: Option<Pet>,
+            #[schema(xml(wrapped(name = "pets")))]
+            pets: Vec<Pet>,
+        }
+    };
 
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            value: String,
-            datetime: DateTime<Utc>,
-            naive_datetime: NaiveDateTime,
-            date: Date<Utc>,
-            naive_date: NaiveDate,
+    assert_json_eq!(
+        user,
+        json!({
+            "properties": {
+                "pet": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    ],
+                    "xml": {
+                        "name": "favorite_pet"
+                    }
+                },
+                "previous_pet": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    ],
+                    "nullable": true,
+                    "xml": {
+                        "name": "previous_pet",
+                        "prefix": "u"
+                    }
+                },
+                "pets": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/Pet"
+                    },
+                    "xml": {
+                        "name": "pets",
+                        "wrapped": true
+                    }
+                }
+            },
+            "required": ["pet", "pets"],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn derive_unit_enum_xml() {
+    let color = api_doc! {
+        #[schema(xml(name = "color", prefix = "c"))]
+        enum Color {
+            Red,
+            Blue,
+        }
+    };
+
+    assert_json_eq!(
+        color,
+        json!({
+            "type": "string",
+            "enum": ["Red", "Blue"],
+            "xml": {
+                "name": "color",
+                "prefix": "c"
+            }
+        })
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn derive_component_with_chrono_feature() {
+    #![allow(deprecated)] // allow deprecated Date in tests as long as it is available from chrono
+    use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            value: String,
+            datetime: DateTime<Utc>,
+            naive_datetime: NaiveDateTime,
+            date: Date<Utc>,
+            naive_date: NaiveDate,
             naive_time: NaiveTime,
             duration: Duration,
         }
@@ -3083,6 +3772,40 @@ fn derive_unnamed_struct_schema_type_override_with_format() {
     }
 }
 
+#[test]
+fn derive_struct_with_string_literal_format() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Value {
+        #[schema(format = "date-time")]
+        created: String,
+        #[schema(format = "custom-id")]
+        id: String,
+    }
+
+    let schema = serde_json::from_value::<utoipa::openapi::RefOr<utoipa::openapi::Schema>>(json!({
+        "type": "object",
+        "properties": {
+            "created": {
+                "type": "string",
+                "format": "date-time"
+            },
+            "id": {
+                "type": "string",
+                "format": "custom-id"
+            }
+        },
+        "required": ["created", "id"]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        Value::schema().1,
+        schema,
+        "derived schema should equal deserialized schema"
+    );
+}
+
 #[test]
 fn derive_struct_override_type_with_object_type() {
     let value = api_doc! {
@@ -3134,6 +3857,94 @@ fn derive_struct_override_type_with_a_reference() {
     )
 }
 
+#[test]
+fn derive_unnamed_struct_with_inline_field() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    let wrapper = api_doc! {
+        struct PetWrapper(#[schema(inline)] Pet);
+    };
+    let list = api_doc! {
+        struct PetList(#[schema(inline)] Vec<Pet>);
+    };
+
+    assert_json_eq!(
+        wrapper,
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string"
+                }
+            },
+            "required": ["name"]
+        })
+    );
+    assert_json_eq!(
+        list,
+        json!({
+            "type": "array",
+            "items": {
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string"
+                    }
+                },
+                "required": ["name"]
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_unnamed_struct_with_field_value_type() {
+    mod external {
+        pub struct Id;
+        pub struct Tags;
+    }
+
+    let id = api_doc! {
+        struct Id(#[schema(value_type = String, format = Uuid)] external::Id);
+    };
+    let tags = api_doc! {
+        struct Tags(#[schema(value_type = Option<[String]>)] external::Tags);
+    };
+    let overridden = api_doc! {
+        #[schema(value_type = i64)]
+        struct Overridden(#[schema(value_type = String)] external::Id);
+    };
+
+    assert_json_eq!(
+        id,
+        json!({
+            "type": "string",
+            "format": "uuid"
+        })
+    );
+    assert_json_eq!(
+        tags,
+        json!({
+            "type": "array",
+            "items": {
+                "type": "string"
+            },
+            "nullable": true
+        })
+    );
+    assert_json_eq!(
+        overridden,
+        json!({
+            "type": "integer",
+            "format": "int64"
+        })
+    );
+}
+
 #[cfg(feature = "decimal")]
 #[test]
 fn derive_struct_with_rust_decimal() {
@@ -3216,6 +4027,46 @@ fn derive_struct_with_rust_decimal_float_with_type_override() {
     }
 }
 
+#[cfg(feature = "decimal")]
+#[test]
+fn derive_struct_with_bigdecimal() {
+    use bigdecimal::BigDecimal;
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            balance: BigDecimal,
+            #[schema(value_type = f64)]
+            rating: BigDecimal,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.balance.type" = r#""string""#, "Post balance type"
+        "properties.balance.format" = r#"null"#, "Post balance format"
+        "properties.rating.type" = r#""number""#, "Post rating type"
+        "properties.rating.format" = r#""double""#, "Post rating format"
+    }
+}
+
+#[cfg(feature = "decimal_float")]
+#[test]
+fn derive_struct_with_bigdecimal_float() {
+    use bigdecimal::BigDecimal;
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            balance: BigDecimal,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.balance.type" = r#""number""#, "Post balance type"
+        "properties.balance.format" = r#""double""#, "Post balance format"
+    }
+}
+
 #[cfg(feature = "uuid")]
 #[test]
 fn derive_struct_with_uuid_type() {
@@ -3450,817 +4301,678 @@ fn derive_complex_enum_as() {
 }
 
 #[test]
-fn derive_component_with_primitive_aliases() {
-    #[derive(Debug, OpenApi)]
-    #[openapi(components(schemas(BarString, BarInt, Foo)))]
-    struct ApiDoc;
+fn derive_schema_name_with_as() {
+    #[derive(ToSchema)]
+    #[schema(as = UserDTO)]
+    #[allow(unused)]
+    struct User {
+        id: u64,
+    }
 
     #[derive(ToSchema)]
-    #[aliases(BarString = Bar<String>, BarInt = Bar<i32>)]
-    struct Bar<R> {
-        #[allow(dead_code)]
-        bar: R,
+    #[allow(unused)]
+    struct Account {
+        id: u64,
     }
+
     #[derive(ToSchema)]
-    struct Foo {
-        #[allow(dead_code)]
-        #[schema(value_type=BarString)]
-        baz: Bar<String>,
+    #[schema(as = named::Item)]
+    #[allow(unused)]
+    enum Item {
+        Foo,
     }
 
-    let doc = ApiDoc::openapi();
-    let doc_value = &serde_json::to_value(doc).unwrap();
+    assert_eq!(<User as ToSchema>::schema_name(), "UserDTO");
+    assert_eq!(<Account as ToSchema>::schema_name(), "Account");
+    assert_eq!(<Item as ToSchema>::schema_name(), "named.Item");
 
-    let value = doc_value.pointer("/components/schemas").unwrap();
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(User)))]
+    struct ApiDoc;
 
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    assert!(
+        doc.pointer("/components/schemas/UserDTO").is_some(),
+        "Should have User named as UserDTO"
+    );
+
+    let reference =
+        serde_json::to_value(utoipa::openapi::Ref::from_schema_name(User::schema_name())).unwrap();
     assert_json_eq!(
-        value,
+        reference,
         json!({
-            "BarString": {
-                "properties": {
-                    "bar": {
-                        "type": "string"
-                    }
-                },
-                "type": "object",
-                "required": ["bar"]
-            },
-            "BarInt": {
-                "properties": {
-                    "bar": {
-                        "type": "integer",
-                        "format": "int32",
-                    }
-               },
-                "type": "object",
-                "required": ["bar"]
-            },
-            "Foo": {
-                "properties": {
-                    "baz": {
-                        "$ref": "#/components/schemas/BarString",
-                    }
-               },
-                "type": "object",
-                "required": ["baz"]
-            }
+            "$ref": "#/components/schemas/UserDTO"
         })
     );
 }
 
 #[test]
-fn derive_component_with_to_schema_value_type() {
+fn derive_schema_name_with_rename() {
     #[derive(ToSchema)]
-    struct Foo {
-        #[allow(unused)]
-        value: String,
+    #[schema(rename = "PetV2")]
+    #[allow(unused)]
+    struct Pet {
+        id: u64,
     }
 
-    let doc = api_doc! {
-        #[allow(unused)]
-        struct Random {
-            #[schema(value_type = i64)]
-            id: String,
-            #[schema(value_type = Object)]
-            another_id: String,
-            #[schema(value_type = Vec<Vec<String>>)]
-            value1: Vec<i64>,
-            #[schema(value_type = Vec<String>)]
-            value2: Vec<i64>,
-            #[schema(value_type = Option<String>)]
-            value3: i64,
-            #[schema(value_type = Option<Object>)]
-            value4: i64,
-            #[schema(value_type = Vec<Object>)]
-            value5: i64,
-            #[schema(value_type = Vec<Foo>)]
-            value6: i64,
-        }
-    };
+    #[derive(ToSchema)]
+    #[schema(rename = "PetStatusV2")]
+    #[allow(unused)]
+    enum PetStatus {
+        Available,
+    }
 
+    #[derive(ToSchema)]
+    #[schema(rename = "PetIdV2")]
+    #[allow(unused)]
+    struct PetId(u64);
+
+    #[derive(ToSchema)]
+    #[schema(as = api::Owner, rename = "OwnerV2")]
+    #[allow(unused)]
+    struct Owner {
+        pets: Vec<Pet>,
+    }
+
+    assert_eq!(<Pet as ToSchema>::schema_name(), "PetV2");
+    assert_eq!(<PetStatus as ToSchema>::schema_name(), "PetStatusV2");
+    assert_eq!(<PetId as ToSchema>::schema_name(), "PetIdV2");
+    assert_eq!(<Owner as ToSchema>::schema_name(), "api.Owner");
+
+    let value = serde_json::to_value(<Pet as ToSchema>::schema().1).unwrap();
     assert_json_eq!(
-        doc,
+        value,
         json!({
             "properties": {
-                "another_id": {
-                    "type": "object"
-                },
                 "id": {
                     "type": "integer",
-                    "format": "int64"
-                },
-                "value1": {
-                    "items": {
-                        "items": {
-                            "type": "string"
-                        },
-                        "type": "array"
-                    },
-                    "type": "array"
-                },
-                "value2": {
-                    "items": {
-                        "type": "string"
-                    },
-                    "type": "array"
-                },
-                "value3": {
-                    "type": "string",
-                    "nullable": true,
-                },
-                "value4": {
-                    "type": "object",
-                    "nullable": true,
-                },
-                "value5": {
-                    "items": {
-                        "type": "object"
-                    },
-                    "type": "array"
-                },
-                "value6": {
-                    "items": {
-                        "$ref": "#/components/schemas/Foo"
-                    },
-                    "type": "array"
+                    "format": "int64",
+                    "minimum": 0
                 }
             },
-            "required": [
-                "id",
-                "another_id",
-                "value1",
-                "value2",
-                "value5",
-                "value6",
-            ],
+            "required": ["id"],
             "type": "object"
         })
-    )
+    );
 }
 
 #[test]
-fn derive_component_with_complex_enum_lifetimes() {
+fn derive_schema_with_container_title_description_and_example() {
+    /// Doc comment is overridden.
     #[derive(ToSchema)]
-    struct Foo<'foo> {
-        #[allow(unused)]
-        field: &'foo str,
+    #[schema(
+        title = "Pet",
+        description = "Pet in the store",
+        example = json!({"name": "Lassie"})
+    )]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
     }
 
-    let doc = api_doc! {
-        enum Bar<'bar> {
-            A { foo: Foo<'bar> },
-            B,
-            C,
-        }
-    };
-
-    assert_json_eq!(
-        doc,
-        json!({
-            "oneOf": [
-                {
-                    "properties": {
-                        "A": {
-                            "properties": {
-                                "foo": {
-                                    "$ref": "#/components/schemas/Foo"
-                                }
-                            },
-                            "required": ["foo"],
-                            "type": "object"
-                        },
-                    },
-                    "required": ["A"],
-                    "type": "object"
-                },
-                {
-                    "enum": ["B"],
-                    "type": "string"
-                },
-                {
-                    "enum": ["C"],
-                    "type": "string"
-                }
-            ]
-        })
-    )
-}
+    /// Doc comment is overridden.
+    #[derive(ToSchema)]
+    #[schema(title = "Status", description = "Status of the pet")]
+    #[allow(unused)]
+    enum Status {
+        Available,
+        Sold,
+    }
 
-#[test]
-fn derive_component_with_raw_identifier() {
-    let doc = api_doc! {
-        struct Bar {
-            r#in: String
-        }
-    };
+    #[derive(ToSchema)]
+    #[schema(title = "Animal", description = "Any animal")]
+    #[allow(unused)]
+    enum Animal {
+        Dog { name: String },
+        Cat(String),
+    }
 
     assert_json_eq!(
-        doc,
+        serde_json::to_value(<Pet as ToSchema>::schema().1).unwrap(),
         json!({
+            "title": "Pet",
+            "description": "Pet in the store",
+            "example": {
+                "name": "Lassie"
+            },
             "properties": {
-                "in": {
+                "name": {
                     "type": "string"
                 }
             },
-            "required": ["in"],
+            "required": ["name"],
             "type": "object"
         })
-    )
-}
-
-#[test]
-fn derive_component_with_linked_list() {
-    use std::collections::LinkedList;
-
-    let example_schema = api_doc! {
-        struct ExampleSchema {
-            values: LinkedList<f64>
-        }
-    };
-
+    );
     assert_json_eq!(
-        example_schema,
+        serde_json::to_value(<Status as ToSchema>::schema().1).unwrap(),
         json!({
-            "properties": {
-                "values": {
-                    "items": {
-                        "type": "number",
-                        "format": "double"
-                    },
-                    "type": "array"
-                }
-            },
-            "required": ["values"],
-            "type": "object"
+            "title": "Status",
+            "description": "Status of the pet",
+            "enum": ["Available", "Sold"],
+            "type": "string"
         })
-    )
+    );
+
+    let animal = serde_json::to_value(<Animal as ToSchema>::schema().1).unwrap();
+    assert_eq!(animal["title"], "Animal");
+    assert_eq!(animal["description"], "Any animal");
 }
 
 #[test]
-#[cfg(feature = "smallvec")]
-fn derive_component_with_smallvec_feature() {
-    use smallvec::SmallVec;
+fn derive_serde_transparent_struct() {
+    #[derive(Serialize, ToSchema)]
+    #[serde(transparent)]
+    #[allow(unused)]
+    struct UserId {
+        id: u64,
+    }
 
-    let bar = api_doc! {
-        struct Bar<'b> {
-            links: SmallVec<[&'b str; 2]>
-        }
-    };
+    #[derive(Serialize, ToSchema)]
+    #[serde(transparent)]
+    #[allow(unused)]
+    struct Tagged<T> {
+        #[serde(skip)]
+        marker: PhantomData<T>,
+        name: String,
+    }
+
+    #[derive(Serialize, Default, ToSchema)]
+    #[serde(transparent)]
+    #[schema(default)]
+    #[allow(unused)]
+    struct Wrapper(#[serde(skip)] PhantomData<u8>, i32);
 
     assert_json_eq!(
-        bar,
+        serde_json::to_value(<UserId as ToSchema>::schema().1).unwrap(),
         json!({
-            "properties": {
-                "links": {
-                    "items": {
-                        "type": "string"
-                    },
-                    "type": "array",
-                }
-            },
-            "required": ["links"],
-            "type": "object"
+            "type": "integer",
+            "format": "int64",
+            "minimum": 0
         })
-    )
-}
-
-#[test]
-fn derive_schema_with_default_field() {
-    let value = api_doc! {
-        #[derive(serde::Deserialize)]
-        struct MyValue {
-            #[serde(default)]
-            field: String
-        }
-    };
-
+    );
     assert_json_eq!(
-        value,
+        serde_json::to_value(<Tagged<String> as ToSchema>::schema().1).unwrap(),
         json!({
-            "properties": {
-                "field": {
-                    "type": "string"
-                }
-            },
-            "type": "object"
+            "type": "string"
         })
-    )
+    );
+    assert_json_eq!(
+        serde_json::to_value(<Wrapper as ToSchema>::schema().1).unwrap(),
+        json!({
+            "type": "integer",
+            "format": "int32",
+            "default": 0
+        })
+    );
 }
 
 #[test]
-fn derive_schema_with_default_struct() {
-    let value = api_doc! {
-        #[derive(serde::Deserialize, Default)]
-        #[serde(default)]
-        struct MyValue {
-            field: String
+fn derive_schema_name_with_serde_remote() {
+    mod remote {
+        pub struct Thing {
+            pub id: u64,
+            pub name: String,
         }
-    };
+    }
+
+    #[derive(Serialize, ToSchema)]
+    #[serde(remote = "remote::Thing")]
+    #[allow(unused)]
+    struct ThingDef {
+        id: u64,
+        name: String,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Wrapper {
+        #[serde(with = "ThingDef")]
+        thing: remote::Thing,
+    }
+
+    assert_eq!(<ThingDef as ToSchema>::schema_name(), "remote.Thing");
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(ThingDef, Wrapper)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
 
     assert_json_eq!(
-        value,
+        doc.pointer("/components/schemas").unwrap(),
         json!({
-            "properties": {
-                "field": {
-                    "type": "string",
-                    "default": ""
-                }
+            "remote.Thing": {
+                "type": "object",
+                "properties": {
+                    "id": {
+                        "type": "integer",
+                        "format": "int64",
+                        "minimum": 0
+                    },
+                    "name": {
+                        "type": "string"
+                    }
+                },
+                "required": ["id", "name"]
             },
-            "type": "object"
+            "Wrapper": {
+                "type": "object",
+                "properties": {
+                    "thing": {
+                        "$ref": "#/components/schemas/remote.Thing"
+                    }
+                },
+                "required": ["thing"]
+            }
         })
-    )
+    );
 }
 
 #[test]
-fn derive_struct_with_no_additional_properties() {
-    let value = api_doc! {
-        #[derive(serde::Deserialize, Default)]
-        #[serde(deny_unknown_fields)]
-        struct MyValue {
-            field: String
-        }
-    };
+fn derive_component_with_primitive_aliases() {
+    #[derive(Debug, OpenApi)]
+    #[openapi(components(schemas(BarString, BarInt, Foo)))]
+    struct ApiDoc;
 
-    assert_json_eq!(
-        value,
-        json!({
-            "properties": {
-                "field": {
-                    "type": "string",
-                }
-            },
-            "required": ["field"],
-            "additionalProperties": false,
-            "type": "object"
-        })
-    )
-}
+    #[derive(ToSchema)]
+    #[aliases(BarString = Bar<String>, BarInt = Bar<i32>)]
+    struct Bar<R> {
+        #[allow(dead_code)]
+        bar: R,
+    }
+    #[derive(ToSchema)]
+    struct Foo {
+        #[allow(dead_code)]
+        #[schema(value_type=BarString)]
+        baz: Bar<String>,
+    }
 
-#[test]
-#[cfg(feature = "repr")]
-fn derive_schema_for_repr_enum() {
-    let value = api_doc! {
-        #[derive(serde::Deserialize)]
-        #[repr(i32)]
-        #[schema(example = 1, default = 0)]
-        enum ExitCode {
-            Error  = -1,
-            Ok     = 0,
-            Unknow = 1,
-        }
-    };
+    let doc = ApiDoc::openapi();
+    let doc_value = &serde_json::to_value(doc).unwrap();
+
+    let value = doc_value.pointer("/components/schemas").unwrap();
 
     assert_json_eq!(
         value,
         json!({
-            "enum": [-1, 0, 1],
-            "type": "integer",
-            "default": 0,
-            "example": 1,
+            "BarString": {
+                "properties": {
+                    "bar": {
+                        "type": "string"
+                    }
+                },
+                "type": "object",
+                "required": ["bar"]
+            },
+            "BarInt": {
+                "properties": {
+                    "bar": {
+                        "type": "integer",
+                        "format": "int32",
+                    }
+               },
+                "type": "object",
+                "required": ["bar"]
+            },
+            "Foo": {
+                "properties": {
+                    "baz": {
+                        "$ref": "#/components/schemas/BarString",
+                    }
+               },
+                "type": "object",
+                "required": ["baz"]
+            }
         })
     );
 }
 
 #[test]
-#[cfg(feature = "repr")]
-fn derive_schema_for_tagged_repr_enum() {
-    let value: Value = api_doc! {
-        #[derive(serde::Deserialize, serde::Serialize)]
-        #[serde(tag = "tag")]
-        #[repr(u8)]
-        enum TaggedEnum {
-            One = 0,
-            Two,
-            Three,
-        }
-    };
+fn derive_enum_with_aliases() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        name: String,
+    }
+
+    #[derive(Debug, OpenApi)]
+    #[openapi(components(schemas(ResultFoo)))]
+    struct ApiDoc;
+
+    #[derive(ToSchema)]
+    #[aliases(ResultFoo = ApiResult<Foo>)]
+    #[allow(unused)]
+    enum ApiResult<T> {
+        Ok(T),
+        Partial { value: T, missing: Vec<String> },
+    }
+
+    let doc = ApiDoc::openapi();
+    let doc_value = &serde_json::to_value(doc).unwrap();
 
+    let value = doc_value.pointer("/components/schemas/ResultFoo").unwrap();
     assert_json_eq!(
         value,
         json!({
             "oneOf": [
                 {
                     "type": "object",
+                    "required": ["Ok"],
                     "properties": {
-                        "tag": {
-                            "type": "integer",
-                            "enum": [
-                                0,
-                            ],
-                        },
-                    },
-                    "required": [
-                        "tag",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "integer",
-                            "enum": [
-                                1,
-                            ],
-                        },
-                    },
-                    "required": [
-                        "tag",
-                    ],
+                        "Ok": {
+                            "$ref": "#/components/schemas/Foo"
+                        }
+                    }
                 },
                 {
                     "type": "object",
+                    "required": ["Partial"],
                     "properties": {
-                        "tag": {
-                            "type": "integer",
-                            "enum": [
-                                2,
-                            ],
-                        },
-                    },
-                    "required": [
-                        "tag",
-                    ],
-                },
-            ],
+                        "Partial": {
+                            "type": "object",
+                            "required": ["value", "missing"],
+                            "properties": {
+                                "value": {
+                                    "$ref": "#/components/schemas/Foo"
+                                },
+                                "missing": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "string"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            ]
         })
     );
 }
 
 #[test]
-#[cfg(feature = "repr")]
-fn derive_schema_for_skipped_repr_enum() {
-    let value: Value = api_doc! {
-        #[derive(serde::Deserialize, serde::Serialize)]
-        #[repr(i32)]
-        enum SkippedEnum {
-            Error  = -1,
-            Ok     = 0,
-            #[serde(skip)]
-            Unknown = 1,
-        }
-    };
-
-    assert_value! {value=>
-        "enum" = r#"[-1,0]"#, "SkippedEnum enum variants"
-        "type" = r#""integer""#, "SkippedEnum enum type"
-    };
-}
-
-#[test]
-#[cfg(feature = "repr")]
-fn derive_repr_enum_with_with_custom_default_fn_success() {
-    let mode = api_doc! {
-        #[schema(default = repr_mode_default_fn)]
-        #[repr(u16)]
-        enum ReprDefaultMode {
-            Mode1 = 0,
-            Mode2
-        }
-    };
+fn derive_unnamed_struct_with_aliases() {
+    struct T;
 
-    assert_value! {mode=>
-        "default" = r#"1"#, "ReprDefaultMode default"
-        "enum" = r#"[0,1]"#, "ReprDefaultMode enum variants"
-        "type" = r#""integer""#, "ReprDefaultMode type"
+    let value = api_doc_aliases! {
+        #[aliases(WrapperInt = Wrapper<i32>)]
+        struct Wrapper<T>(T);
     };
-    assert_value! {mode=>
-        "example" = Value::Null, "ReprDefaultMode example"
-    }
-}
 
-#[cfg(feature = "repr")]
-fn repr_mode_default_fn() -> u16 {
-    1
+    assert_json_eq!(
+        value,
+        json!([
+            [
+                "WrapperInt",
+                {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            ]
+        ])
+    );
 }
 
 #[test]
-#[cfg(feature = "repr")]
-fn derive_repr_enum_with_with_custom_default_fn_and_example() {
-    let mode = api_doc! {
-        #[schema(default = repr_mode_default_fn, example = 1)]
-        #[repr(u16)]
-        enum ReprDefaultMode {
-            Mode1 = 0,
-            Mode2
-        }
-    };
-
-    assert_value! {mode=>
-        "default" = r#"1"#, "ReprDefaultMode default"
-        "enum" = r#"[0,1]"#, "ReprDefaultMode enum variants"
-        "type" = r#""integer""#, "ReprDefaultMode type"
-        "example" = r#"1"#, "ReprDefaultMode example"
-    };
-}
+fn derive_component_with_to_schema_value_type() {
+    #[derive(ToSchema)]
+    struct Foo {
+        #[allow(unused)]
+        value: String,
+    }
 
-#[test]
-fn derive_struct_with_vec_field_with_example() {
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            #[schema(example = json!(["foobar", "barfoo"]))]
-            value: Vec<String>,
+    let doc = api_doc! {
+        #[allow(unused)]
+        struct Random {
+            #[schema(value_type = i64)]
+            id: String,
+            #[schema(value_type = Object)]
+            another_id: String,
+            #[schema(value_type = Vec<Vec<String>>)]
+            value1: Vec<i64>,
+            #[schema(value_type = Vec<String>)]
+            value2: Vec<i64>,
+            #[schema(value_type = Option<String>)]
+            value3: i64,
+            #[schema(value_type = Option<Object>)]
+            value4: i64,
+            #[schema(value_type = Vec<Object>)]
+            value5: i64,
+            #[schema(value_type = Vec<Foo>)]
+            value6: i64,
         }
     };
 
     assert_json_eq!(
-        post,
+        doc,
         json!({
-            "type": "object",
             "properties": {
-                "id": {
-                    "type": "integer",
-                    "format": "int32"
+                "another_id": {
+                    "type": "object"
                 },
-                "value": {
-                    "type": "array",
-                    "example": ["foobar", "barfoo"],
+                "id": {
+                    "type": "integer",
+                    "format": "int64"
+                },
+                "value1": {
+                    "items": {
+                        "items": {
+                            "type": "string"
+                        },
+                        "type": "array"
+                    },
+                    "type": "array"
+                },
+                "value2": {
                     "items": {
                         "type": "string"
-                    }
+                    },
+                    "type": "array"
+                },
+                "value3": {
+                    "type": "string",
+                    "nullable": true,
+                },
+                "value4": {
+                    "type": "object",
+                    "nullable": true,
+                },
+                "value5": {
+                    "items": {
+                        "type": "object"
+                    },
+                    "type": "array"
+                },
+                "value6": {
+                    "items": {
+                        "$ref": "#/components/schemas/Foo"
+                    },
+                    "type": "array"
                 }
             },
-            "required": ["id", "value"]
+            "required": [
+                "id",
+                "another_id",
+                "value1",
+                "value2",
+                "value5",
+                "value6",
+            ],
+            "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_struct_field_with_example() {
-    struct MyStruct;
+fn derive_component_with_complex_enum_lifetimes() {
+    #[derive(ToSchema)]
+    struct Foo<'foo> {
+        #[allow(unused)]
+        field: &'foo str,
+    }
+
     let doc = api_doc! {
-        struct MyValue {
-            #[schema(example = "test")]
-            field1: String,
-            #[schema(example = json!("test"))]
-            field2: String,
-            #[schema(example = json!({
-                "key1": "value1"
-            }))]
-            field3: HashMap<String, String>,
-            #[schema(example = json!({
-                "key1": "value1"
-            }))]
-            field4: HashMap<String, MyStruct>
+        enum Bar<'bar> {
+            A { foo: Foo<'bar> },
+            B,
+            C,
         }
     };
 
     assert_json_eq!(
         doc,
         json!({
-            "properties": {
-                "field1": {
-                    "type": "string",
-                    "example": "test"
-                },
-                "field2": {
-                    "type": "string",
-                    "example": "test"
-                },
-                "field3": {
-                    "type": "object",
-                    "additionalProperties": {
-                        "type": "string",
+            "oneOf": [
+                {
+                    "properties": {
+                        "A": {
+                            "properties": {
+                                "foo": {
+                                    "$ref": "#/components/schemas/Foo"
+                                }
+                            },
+                            "required": ["foo"],
+                            "type": "object"
+                        },
                     },
-                    "example": {
-                        "key1": "value1"
-                    }
+                    "required": ["A"],
+                    "type": "object"
                 },
-                "field4": {
-                    "type": "object",
-                    "additionalProperties": {
-                        "$ref": "#/components/schemas/MyStruct",
-                    },
-                    "example": {
-                        "key1": "value1"
-                    }
+                {
+                    "enum": ["B"],
+                    "type": "string"
+                },
+                {
+                    "enum": ["C"],
+                    "type": "string"
                 }
-            },
-            "required": [
-                "field1",
-                "field2",
-                "field3",
-                "field4"
-            ],
-            "type": "object"
+            ]
         })
     )
 }
 
 #[test]
-fn derive_struct_with_self_reference() {
-    let value = api_doc! {
-        struct Item {
-            id: String,
-            previous: Box<Self>,
+fn derive_component_with_raw_identifier() {
+    let doc = api_doc! {
+        struct Bar {
+            r#in: String
         }
     };
 
     assert_json_eq!(
-        value,
+        doc,
         json!({
             "properties": {
-                "id": {
-                    "type": "string",
-                },
-                "previous": {
-                    "$ref": "#/components/schemas/Item",
-                },
+                "in": {
+                    "type": "string"
+                }
             },
-            "type": "object",
-            "required": ["id", "previous"]
+            "required": ["in"],
+            "type": "object"
         })
     )
 }
 
 #[test]
-fn derive_unnamed_struct_with_self_reference() {
-    let value = api_doc! {
-        struct Item(Box<Item>);
+fn derive_component_with_linked_list() {
+    use std::collections::LinkedList;
+
+    let example_schema = api_doc! {
+        struct ExampleSchema {
+            values: LinkedList<f64>
+        }
     };
 
     assert_json_eq!(
-        value,
+        example_schema,
         json!({
-            "$ref": "#/components/schemas/Item"
+            "properties": {
+                "values": {
+                    "items": {
+                        "type": "number",
+                        "format": "double"
+                    },
+                    "type": "array"
+                }
+            },
+            "required": ["values"],
+            "type": "object"
         })
     )
 }
 
 #[test]
-fn derive_enum_with_self_reference() {
-    let value = api_doc! {
-        enum EnumValue {
-            Item(Box<Self>),
-            Item2 {
-                value: Box<Self>
-            }
+#[cfg(feature = "smallvec")]
+fn derive_component_with_smallvec_feature() {
+    use smallvec::SmallVec;
+
+    let bar = api_doc! {
+        struct Bar<'b> {
+            links: SmallVec<[&'b str; 2]>
         }
     };
 
     assert_json_eq!(
-        value,
+        bar,
         json!({
-            "oneOf": [
-                {
-                    "properties": {
-                        "Item": {
-                            "$ref": "#/components/schemas/EnumValue"
-                        }
-                    },
-                    "type": "object",
-                    "required": ["Item"],
-                },
-                {
-                    "properties": {
-                        "Item2": {
-                            "properties": {
-                                "value": {
-                                    "$ref": "#/components/schemas/EnumValue"
-                                }
-                            },
-                            "required": ["value"],
-                            "type": "object",
-                        }
+            "properties": {
+                "links": {
+                    "items": {
+                        "type": "string"
                     },
-                    "required": ["Item2"],
-                    "type": "object",
+                    "type": "array",
                 }
-            ]
+            },
+            "required": ["links"],
+            "type": "object"
         })
     )
 }
 
 #[test]
-fn derive_struct_with_validation_fields() {
+fn derive_schema_with_default_field() {
     let value = api_doc! {
-        struct Item {
-            #[schema(maximum = 10, minimum = 5, multiple_of = 2.5)]
-            id: i32,
-
-            #[schema(max_length = 10, min_length = 5, pattern = "[a-z]*")]
-            value: String,
-
-            #[schema(max_items = 5, min_items = 1, min_length = 1)]
-            items: Vec<String>,
-
-            unsigned: u16,
-
-            #[schema(minimum = 2)]
-            unsigned_value: u32,
-
+        #[derive(serde::Deserialize)]
+        struct MyValue {
+            #[serde(default)]
+            field: String
         }
     };
 
-    let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
-
-    #[cfg(feature = "non_strict_integers")]
-    assert_json_matches!(
-        value,
-        json!({
-            "properties": {
-                "id": {
-                    "format": "int32",
-                    "type": "integer",
-                    "maximum": 10.0,
-                    "minimum": 5.0,
-                    "multipleOf": 2.5,
-                },
-                "value": {
-                    "type": "string",
-                    "maxLength": 10,
-                    "minLength": 5,
-                    "pattern": "[a-z]*"
-                },
-                "items": {
-                    "type": "array",
-                    "items": {
-                        "type": "string",
-                        "minLength": 1,
-                    },
-                    "maxItems": 5,
-                    "minItems": 1,
-                },
-                "unsigned": {
-                    "type": "integer",
-                    "format": "uint16",
-                    "minimum": 0.0
-                },
-                "unsigned_value": {
-                    "type": "integer",
-                    "format": "uint32",
-                    "minimum": 2.0,
-                }
-            },
-            "type": "object",
-            "required": [
-                "id",
-                "value",
-                "items",
-                "unsigned",
-                "unsigned_value"
-            ]
-        }),
-        config
-    );
-
-    #[cfg(not(feature = "non_strict_integers"))]
-    assert_json_matches!(
+    assert_json_eq!(
         value,
         json!({
             "properties": {
-                "id": {
-                    "format": "int32",
-                    "type": "integer",
-                    "maximum": 10.0,
-                    "minimum": 5.0,
-                    "multipleOf": 2.5,
-                },
-                "value": {
+                "field": {
                     "type": "string",
-                    "maxLength": 10,
-                    "minLength": 5,
-                    "pattern": "[a-z]*"
-                },
-                "items": {
-                    "type": "array",
-                    "items": {
-                        "type": "string",
-                        "minLength": 1,
-                    },
-                    "maxItems": 5,
-                    "minItems": 1,
-                },
-                "unsigned": {
-                    "type": "integer",
-                    "format": "int32",
-                    "minimum": 0.0
-                },
-                "unsigned_value": {
-                    "type": "integer",
-                    "format": "int32",
-                    "minimum": 2.0,
+                    "default": ""
                 }
             },
-            "type": "object",
-            "required": [
-                "id",
-                "value",
-                "items",
-                "unsigned",
-                "unsigned_value"
-            ]
-        }),
-        config
-    );
+            "type": "object"
+        })
+    )
 }
 
 #[test]
-fn derive_schema_with_slice_and_array() {
+fn derive_schema_with_default_fn_and_type_default() {
+    fn default_limit() -> u32 {
+        10
+    }
+
+    #[derive(serde::Deserialize, Default)]
+    #[allow(unused)]
+    struct Meta {
+        value: String,
+    }
+
     let value = api_doc! {
-        struct Item<'a> {
-            array: [&'a str; 10],
-            slice: &'a [&'a str],
+        #[derive(serde::Deserialize)]
+        struct MyValue {
+            #[serde(default = "default_limit")]
+            limit: u32,
+            #[serde(default)]
+            meta: Meta,
         }
     };
 
@@ -4268,36 +4980,28 @@ fn derive_schema_with_slice_and_array() {
         value,
         json!({
             "properties": {
-                "array": {
-                    "type": "array",
-                    "items": {
-                        "type": "string"
-                    }
+                "limit": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0,
+                    "default": 10
                 },
-                "slice": {
-                    "type": "array",
-                    "items": {
-                        "type": "string"
-                    }
+                "meta": {
+                    "$ref": "#/components/schemas/Meta"
                 }
             },
-            "required": [
-                "array",
-                "slice"
-            ],
             "type": "object"
         })
     )
 }
 
 #[test]
-fn derive_schema_multiple_serde_definitions() {
+fn derive_schema_with_default_struct() {
     let value = api_doc! {
-        #[derive(serde::Deserialize)]
-        struct Value {
-            #[serde(default)]
-            #[serde(rename = "ID")]
-            id: String
+        #[derive(serde::Deserialize, Default)]
+        #[serde(default)]
+        struct MyValue {
+            field: String
         }
     };
 
@@ -4305,30 +5009,23 @@ fn derive_schema_multiple_serde_definitions() {
         value,
         json!({
             "properties": {
-                "ID": {
+                "field": {
                     "type": "string",
+                    "default": ""
                 }
             },
-            "type": "object",
+            "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_schema_with_custom_field_with_schema() {
-    fn custom_type() -> Object {
-        ObjectBuilder::new()
-            .schema_type(utoipa::openapi::SchemaType::String)
-            .format(Some(utoipa::openapi::SchemaFormat::Custom(
-                "email".to_string(),
-            )))
-            .description(Some("this is the description"))
-            .build()
-    }
+fn derive_struct_with_no_additional_properties() {
     let value = api_doc! {
-        struct Value {
-            #[schema(schema_with = custom_type)]
-            id: String,
+        #[derive(serde::Deserialize, Default)]
+        #[serde(deny_unknown_fields)]
+        struct MyValue {
+            field: String
         }
     };
 
@@ -4336,279 +5033,1005 @@ fn derive_schema_with_custom_field_with_schema() {
         value,
         json!({
             "properties": {
-                "id": {
-                    "description": "this is the description",
+                "field": {
                     "type": "string",
-                    "format": "email"
                 }
             },
-            "required": [ "id" ],
+            "required": ["field"],
+            "additionalProperties": false,
             "type": "object"
         })
     )
 }
 
 #[test]
-fn derive_unit_type() {
-    let data = api_doc! {
-        struct Data {
-            unit_type: ()
+fn derive_complex_enum_with_no_additional_properties() {
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields, tag = "type")]
+        enum MyValue {
+            Named { field: String },
+            Unit,
         }
     };
 
     assert_json_eq!(
-        data,
+        value,
         json!({
-            "type": "object",
-            "required": [ "unit_type" ],
-            "properties": {
-                "unit_type": {
-                    "default": null,
-                    "nullable": true
-                }
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "field": {
+                            "type": "string"
+                        },
+                        "type": {
+                            "type": "string",
+                            "enum": ["Named"]
+                        }
+                    },
+                    "required": ["field", "type"],
+                    "additionalProperties": false,
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": {
+                            "type": "string",
+                            "enum": ["Unit"]
+                        }
+                    },
+                    "required": ["type"],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "type"
             }
         })
     )
 }
 
 #[test]
-fn derive_unit_struct_schema() {
+#[cfg(feature = "repr")]
+fn derive_schema_for_repr_enum() {
     let value = api_doc! {
-        struct UnitValue;
+        #[derive(serde::Deserialize)]
+        #[repr(i32)]
+        #[schema(example = 1, default = 0)]
+        enum ExitCode {
+            Error  = -1,
+            Ok     = 0,
+            Unknow = 1,
+        }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "nullable": true,
-            "default": null,
+            "enum": [-1, 0, 1],
+            "type": "integer",
+            "default": 0,
+            "example": 1,
         })
-    )
+    );
 }
 
 #[test]
-fn derive_schema_with_multiple_schema_attributes() {
-    let value = api_doc! {
-        struct UserName {
-            #[schema(min_length = 5)]
-            #[schema(max_length = 10)]
-            name: String,
+#[cfg(feature = "repr")]
+fn derive_schema_for_tagged_repr_enum() {
+    let value: Value = api_doc! {
+        #[derive(serde::Deserialize, serde::Serialize)]
+        #[serde(tag = "tag")]
+        #[repr(u8)]
+        enum TaggedEnum {
+            One = 0,
+            Two,
+            Three,
         }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "type": "object",
-            "properties": {
-                "name": {
-                    "type": "string",
-                    "minLength": 5,
-                    "maxLength": 10,
-                }
-            },
-            "required": ["name"]
-        })
-    )
-}
-
-#[test]
-fn derive_schema_with_generics_and_lifetimes() {
-    struct TResult;
-
-    let value = api_doc_aliases! {
-        #[aliases(Paginated1 = Paginated<'b, String>, Paginated2 = Paginated<'b, Cow<'c, bool>>)]
-        struct Paginated<'r, TResult> {
-            pub total: usize,
-            pub data: Vec<TResult>,
-            pub next: Option<&'r str>,
-            pub prev: Option<&'r str>,
-        }
-    };
-
-    let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
-
-    assert_json_matches!(
-        value,
-        json!([
-            [
-                "Paginated1",
+            "oneOf": [
                 {
+                    "type": "object",
                     "properties": {
-                        "data": {
-                            "type": "array",
-                            "items": {
-                                "type": "string"
-                            }
-                    },
-                    "next": {
-                        "type": "string",
-                        "nullable": true,
-                    },
-                    "prev": {
-                        "type": "string",
-                        "nullable": true,
-                    },
-                    "total": {
-                        "type": "integer",
-                        "minimum": 0.0,
-                     }
+                        "tag": {
+                            "type": "integer",
+                            "enum": [
+                                0,
+                            ],
+                        },
                     },
                     "required": [
-                        "total",
-                        "data",
+                        "tag",
                     ],
-                    "type": "object"
-                }
-            ],
-            [
-                "Paginated2",
+                },
                 {
+                    "type": "object",
                     "properties": {
-                        "data": {
-                            "type": "array",
-                            "items": {
-                                "type": "boolean"
-                            }
-                        },
-                        "next": {
-                            "type": "string",
-                            "nullable": true,
-                        },
-                        "prev": {
-                            "type": "string",
-                            "nullable": true,
+                        "tag": {
+                            "type": "integer",
+                            "enum": [
+                                1,
+                            ],
                         },
-                        "total": {
+                    },
+                    "required": [
+                        "tag",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
                             "type": "integer",
-                            "minimum": 0.0
-                        }
+                            "enum": [
+                                2,
+                            ],
+                        },
                     },
                     "required": [
-                        "total",
-                        "data",
+                        "tag",
                     ],
-                    "type": "object"
-                }
-            ]
-        ]),
-        config
-    )
+                },
+            ],
+        })
+    );
 }
 
 #[test]
-fn derive_struct_with_unit_alias() {
-    struct V;
+#[cfg(feature = "repr")]
+fn derive_schema_for_skipped_repr_enum() {
+    let value: Value = api_doc! {
+        #[derive(serde::Deserialize, serde::Serialize)]
+        #[repr(i32)]
+        enum SkippedEnum {
+            Error  = -1,
+            Ok     = 0,
+            #[serde(skip)]
+            Unknown = 1,
+        }
+    };
 
-    let value = api_doc_aliases! {
-        #[aliases(UnitDataValue = DataValue<TupleUnit>)]
-        struct DataValue<V> {
-            name: String,
-            v: V,
+    assert_value! {value=>
+        "enum" = r#"[-1,0]"#, "SkippedEnum enum variants"
+        "type" = r#""integer""#, "SkippedEnum enum type"
+    };
+}
+
+#[test]
+#[cfg(feature = "repr")]
+fn derive_repr_enum_with_with_custom_default_fn_success() {
+    let mode = api_doc! {
+        #[schema(default = repr_mode_default_fn)]
+        #[repr(u16)]
+        enum ReprDefaultMode {
+            Mode1 = 0,
+            Mode2
         }
     };
 
-    #[derive(utoipa::OpenApi)]
-    #[openapi(components(schemas(TupleUnit)))]
-    struct ApiDoc;
+    assert_value! {mode=>
+        "default" = r#"1"#, "ReprDefaultMode default"
+        "enum" = r#"[0,1]"#, "ReprDefaultMode enum variants"
+        "type" = r#""integer""#, "ReprDefaultMode type"
+    };
+    assert_value! {mode=>
+        "example" = Value::Null, "ReprDefaultMode example"
+    }
+}
 
-    let doc = ApiDoc::openapi();
-    let doc_value = serde_json::to_value(&doc).unwrap();
-    let unit = doc_value.pointer("/components/schemas/TupleUnit").unwrap();
+#[cfg(feature = "repr")]
+fn repr_mode_default_fn() -> u16 {
+    1
+}
 
-    assert_json_eq!(
-        value,
-        json!([[
-              "UnitDataValue",
-              {
-                  "properties": {
-                      "name": {
-                          "type": "string",
-                      },
-                      "v": {
-                          "$ref": "#/components/schemas/TupleUnit"
-                      },
-                  },
-                  "required": ["name", "v"],
-                  "type": "object"
-              }
-        ]])
-    );
+#[test]
+#[cfg(feature = "repr")]
+fn derive_repr_enum_with_with_custom_default_fn_and_example() {
+    let mode = api_doc! {
+        #[schema(default = repr_mode_default_fn, example = 1)]
+        #[repr(u16)]
+        enum ReprDefaultMode {
+            Mode1 = 0,
+            Mode2
+        }
+    };
 
-    assert_json_eq!(
-        unit,
-        json!({
-            "default": null,
-            "nullable": true,
-        })
-    );
+    assert_value! {mode=>
+        "default" = r#"1"#, "ReprDefaultMode default"
+        "enum" = r#"[0,1]"#, "ReprDefaultMode enum variants"
+        "type" = r#""integer""#, "ReprDefaultMode type"
+        "example" = r#"1"#, "ReprDefaultMode example"
+    };
 }
 
 #[test]
-fn derive_struct_with_deprecated_fields() {
-    struct Foobar;
-    let account = api_doc! {
-        struct Account {
-            #[deprecated]
-            id: i64,
-            #[deprecated]
-            username: String,
-            #[deprecated]
-            role_ids: Vec<i32>,
-            #[deprecated]
-            foobars: Vec<Foobar>,
-            #[deprecated]
-            map: HashMap<String, String>
+fn derive_struct_with_vec_field_with_example() {
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(example = json!(["foobar", "barfoo"]))]
+            value: Vec<String>,
         }
     };
 
     assert_json_eq!(
-        account,
+        post,
         json!({
+            "type": "object",
             "properties": {
                 "id": {
                     "type": "integer",
-                    "format": "int64",
-                    "deprecated": true
-                },
-                "username": {
-                    "type": "string",
-                    "deprecated": true
-                },
-                "role_ids": {
-                    "type": "array",
-                    "deprecated": true,
-                    "items": {
-                        "type": "integer",
-                        "format": "int32"
-                    }
+                    "format": "int32"
                 },
-                "foobars": {
+                "value": {
                     "type": "array",
-                    "deprecated": true,
+                    "example": ["foobar", "barfoo"],
                     "items": {
-                        "$ref": "#/components/schemas/Foobar"
-                    }
-                },
-                "map": {
-                    "additionalProperties": {
                         "type": "string"
-                    },
-                    "deprecated": true,
-                    "type": "object"
+                    }
                 }
             },
-            "required": ["id", "username", "role_ids", "foobars", "map"],
-            "type": "object"
+            "required": ["id", "value"]
         })
-    )
+    );
 }
 
 #[test]
-fn derive_struct_with_schema_deprecated_fields() {
-    struct Foobar;
-    let account = api_doc! {
-        struct AccountA {
+fn derive_struct_ref_field_with_example() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    let doc = api_doc! {
+        struct Owner {
+            #[schema(example = json!({"name": "Rex"}))]
+            pet: Pet,
+            #[schema(example = json!({"name": "Rex"}))]
+            optional_pet: Option<Pet>,
+            #[schema(inline, example = json!({"name": "Rex"}))]
+            inline_pet: Pet,
+        }
+    };
+
+    assert_json_eq!(
+        doc,
+        json!({
+            "properties": {
+                "pet": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    ],
+                    "example": {
+                        "name": "Rex"
+                    }
+                },
+                "optional_pet": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    ],
+                    "example": {
+                        "name": "Rex"
+                    },
+                    "nullable": true
+                },
+                "inline_pet": {
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "name": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["name"]
+                        }
+                    ],
+                    "example": {
+                        "name": "Rex"
+                    }
+                }
+            },
+            "required": [
+                "pet",
+                "inline_pet"
+            ],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn derive_struct_field_with_example() {
+    struct MyStruct;
+    let doc = api_doc! {
+        struct MyValue {
+            #[schema(example = "test")]
+            field1: String,
+            #[schema(example = json!("test"))]
+            field2: String,
+            #[schema(example = json!({
+                "key1": "value1"
+            }))]
+            field3: HashMap<String, String>,
+            #[schema(example = json!({
+                "key1": "value1"
+            }))]
+            field4: HashMap<String, MyStruct>
+        }
+    };
+
+    assert_json_eq!(
+        doc,
+        json!({
+            "properties": {
+                "field1": {
+                    "type": "string",
+                    "example": "test"
+                },
+                "field2": {
+                    "type": "string",
+                    "example": "test"
+                },
+                "field3": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "string",
+                    },
+                    "example": {
+                        "key1": "value1"
+                    }
+                },
+                "field4": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "$ref": "#/components/schemas/MyStruct",
+                    },
+                    "example": {
+                        "key1": "value1"
+                    }
+                }
+            },
+            "required": [
+                "field1",
+                "field2",
+                "field3",
+                "field4"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_self_reference() {
+    let value = api_doc! {
+        struct Item {
+            id: String,
+            previous: Box<Self>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "string",
+                },
+                "previous": {
+                    "$ref": "#/components/schemas/Item",
+                },
+            },
+            "type": "object",
+            "required": ["id", "previous"]
+        })
+    )
+}
+
+#[test]
+fn derive_unnamed_struct_with_self_reference() {
+    let value = api_doc! {
+        struct Item(Box<Item>);
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "$ref": "#/components/schemas/Item"
+        })
+    )
+}
+
+#[test]
+fn derive_enum_with_self_reference() {
+    let value = api_doc! {
+        enum EnumValue {
+            Item(Box<Self>),
+            Item2 {
+                value: Box<Self>
+            }
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
+                        "Item": {
+                            "$ref": "#/components/schemas/EnumValue"
+                        }
+                    },
+                    "type": "object",
+                    "required": ["Item"],
+                },
+                {
+                    "properties": {
+                        "Item2": {
+                            "properties": {
+                                "value": {
+                                    "$ref": "#/components/schemas/EnumValue"
+                                }
+                            },
+                            "required": ["value"],
+                            "type": "object",
+                        }
+                    },
+                    "required": ["Item2"],
+                    "type": "object",
+                }
+            ]
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_validation_fields() {
+    let value = api_doc! {
+        struct Item {
+            #[schema(maximum = 10, minimum = 5, multiple_of = 2.5)]
+            id: i32,
+
+            #[schema(max_length = 10, min_length = 5, pattern = "[a-z]*")]
+            value: String,
+
+            #[schema(max_items = 5, min_items = 1, min_length = 1)]
+            items: Vec<String>,
+
+            unsigned: u16,
+
+            #[schema(minimum = 2)]
+            unsigned_value: u32,
+
+        }
+    };
+
+    let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
+
+    #[cfg(feature = "non_strict_integers")]
+    assert_json_matches!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "format": "int32",
+                    "type": "integer",
+                    "maximum": 10.0,
+                    "minimum": 5.0,
+                    "multipleOf": 2.5,
+                },
+                "value": {
+                    "type": "string",
+                    "maxLength": 10,
+                    "minLength": 5,
+                    "pattern": "[a-z]*"
+                },
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "minLength": 1,
+                    },
+                    "maxItems": 5,
+                    "minItems": 1,
+                },
+                "unsigned": {
+                    "type": "integer",
+                    "format": "uint16",
+                    "minimum": 0.0
+                },
+                "unsigned_value": {
+                    "type": "integer",
+                    "format": "uint32",
+                    "minimum": 2.0,
+                }
+            },
+            "type": "object",
+            "required": [
+                "id",
+                "value",
+                "items",
+                "unsigned",
+                "unsigned_value"
+            ]
+        }),
+        config
+    );
+
+    #[cfg(not(feature = "non_strict_integers"))]
+    assert_json_matches!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "format": "int32",
+                    "type": "integer",
+                    "maximum": 10.0,
+                    "minimum": 5.0,
+                    "multipleOf": 2.5,
+                },
+                "value": {
+                    "type": "string",
+                    "maxLength": 10,
+                    "minLength": 5,
+                    "pattern": "[a-z]*"
+                },
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "minLength": 1,
+                    },
+                    "maxItems": 5,
+                    "minItems": 1,
+                },
+                "unsigned": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0.0
+                },
+                "unsigned_value": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 2.0,
+                }
+            },
+            "type": "object",
+            "required": [
+                "id",
+                "value",
+                "items",
+                "unsigned",
+                "unsigned_value"
+            ]
+        }),
+        config
+    );
+}
+
+#[test]
+fn derive_schema_with_slice_and_array() {
+    let value = api_doc! {
+        struct Item<'a> {
+            array: [&'a str; 10],
+            slice: &'a [&'a str],
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "array": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "maxItems": 10,
+                    "minItems": 10
+                },
+                "slice": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            },
+            "required": [
+                "array",
+                "slice"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_fixed_size_arrays() {
+    const CHANNELS: usize = 3;
+
+    let value = api_doc! {
+        struct Point {
+            coordinates: [f32; 3],
+            color: Option<[u16; CHANNELS]>,
+            #[schema(min_items = 1)]
+            tags: [String; 4],
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "coordinates": {
+                    "type": "array",
+                    "items": {
+                        "type": "number",
+                        "format": "float"
+                    },
+                    "maxItems": 3,
+                    "minItems": 3
+                },
+                "color": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32",
+                        "minimum": 0
+                    },
+                    "maxItems": 3,
+                    "minItems": 3,
+                    "nullable": true
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "maxItems": 4,
+                    "minItems": 1
+                }
+            },
+            "required": [
+                "coordinates",
+                "tags"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_schema_multiple_serde_definitions() {
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        struct Value {
+            #[serde(default)]
+            #[serde(rename = "ID")]
+            id: String
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "ID": {
+                    "type": "string",
+                    "default": "",
+                }
+            },
+            "type": "object",
+        })
+    );
+}
+
+#[test]
+fn derive_schema_with_custom_field_with_schema() {
+    fn custom_type() -> Object {
+        ObjectBuilder::new()
+            .schema_type(utoipa::openapi::SchemaType::String)
+            .format(Some(utoipa::openapi::SchemaFormat::Custom(
+                "email".to_string(),
+            )))
+            .description(Some("this is the description"))
+            .build()
+    }
+    let value = api_doc! {
+        struct Value {
+            #[schema(schema_with = custom_type)]
+            id: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "description": "this is the description",
+                    "type": "string",
+                    "format": "email"
+                }
+            },
+            "required": [ "id" ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_unit_type() {
+    let data = api_doc! {
+        struct Data {
+            unit_type: ()
+        }
+    };
+
+    assert_json_eq!(
+        data,
+        json!({
+            "type": "object",
+            "required": [ "unit_type" ],
+            "properties": {
+                "unit_type": {
+                    "default": null,
+                    "nullable": true
+                }
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_unit_struct_schema() {
+    let value = api_doc! {
+        struct UnitValue;
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "nullable": true,
+            "default": null,
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_multiple_schema_attributes() {
+    let value = api_doc! {
+        struct UserName {
+            #[schema(min_length = 5)]
+            #[schema(max_length = 10)]
+            name: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 5,
+                    "maxLength": 10,
+                }
+            },
+            "required": ["name"]
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_generics_and_lifetimes() {
+    struct TResult;
+
+    let value = api_doc_aliases! {
+        #[aliases(Paginated1 = Paginated<'b, String>, Paginated2 = Paginated<'b, Cow<'c, bool>>)]
+        struct Paginated<'r, TResult> {
+            pub total: usize,
+            pub data: Vec<TResult>,
+            pub next: Option<&'r str>,
+            pub prev: Option<&'r str>,
+        }
+    };
+
+    let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
+
+    assert_json_matches!(
+        value,
+        json!([
+            [
+                "Paginated1",
+                {
+                    "properties": {
+                        "data": {
+                            "type": "array",
+                            "items": {
+                                "type": "string"
+                            }
+                    },
+                    "next": {
+                        "type": "string",
+                        "nullable": true,
+                    },
+                    "prev": {
+                        "type": "string",
+                        "nullable": true,
+                    },
+                    "total": {
+                        "type": "integer",
+                        "minimum": 0.0,
+                     }
+                    },
+                    "required": [
+                        "total",
+                        "data",
+                    ],
+                    "type": "object"
+                }
+            ],
+            [
+                "Paginated2",
+                {
+                    "properties": {
+                        "data": {
+                            "type": "array",
+                            "items": {
+                                "type": "boolean"
+                            }
+                        },
+                        "next": {
+                            "type": "string",
+                            "nullable": true,
+                        },
+                        "prev": {
+                            "type": "string",
+                            "nullable": true,
+                        },
+                        "total": {
+                            "type": "integer",
+                            "minimum": 0.0
+                        }
+                    },
+                    "required": [
+                        "total",
+                        "data",
+                    ],
+                    "type": "object"
+                }
+            ]
+        ]),
+        config
+    )
+}
+
+#[test]
+fn derive_struct_with_unit_alias() {
+    struct V;
+
+    let value = api_doc_aliases! {
+        #[aliases(UnitDataValue = DataValue<TupleUnit>)]
+        struct DataValue<V> {
+            name: String,
+            v: V,
+        }
+    };
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(components(schemas(TupleUnit)))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let doc_value = serde_json::to_value(&doc).unwrap();
+    let unit = doc_value.pointer("/components/schemas/TupleUnit").unwrap();
+
+    assert_json_eq!(
+        value,
+        json!([[
+              "UnitDataValue",
+              {
+                  "properties": {
+                      "name": {
+                          "type": "string",
+                      },
+                      "v": {
+                          "$ref": "#/components/schemas/TupleUnit"
+                      },
+                  },
+                  "required": ["name", "v"],
+                  "type": "object"
+              }
+        ]])
+    );
+
+    assert_json_eq!(
+        unit,
+        json!({
+            "default": null,
+            "nullable": true,
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_deprecated_fields() {
+    struct Foobar;
+    let account = api_doc! {
+        struct Account {
+            #[deprecated]
+            id: i64,
+            #[deprecated]
+            username: String,
+            #[deprecated]
+            role_ids: Vec<i32>,
+            #[deprecated]
+            foobars: Vec<Foobar>,
+            #[deprecated]
+            map: HashMap<String, String>
+        }
+    };
+
+    assert_json_eq!(
+        account,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "deprecated": true
+                },
+                "username": {
+                    "type": "string",
+                    "deprecated": true
+                },
+                "role_ids": {
+                    "type": "array",
+                    "deprecated": true,
+                    "items": {
+                        "type": "integer",
+                        "format": "int32"
+                    }
+                },
+                "foobars": {
+                    "type": "array",
+                    "deprecated": true,
+                    "items": {
+                        "$ref": "#/components/schemas/Foobar"
+                    }
+                },
+                "map": {
+                    "additionalProperties": {
+                        "type": "string"
+                    },
+                    "deprecated": true,
+                    "type": "object"
+                }
+            },
+            "required": ["id", "username", "role_ids", "foobars", "map"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_schema_deprecated_fields() {
+    struct Foobar;
+    let account = api_doc! {
+        struct AccountA {
             #[schema(deprecated)]
             id: i64,
             #[schema(deprecated)]
@@ -4623,54 +6046,843 @@ fn derive_struct_with_schema_deprecated_fields() {
     };
 
     assert_json_eq!(
-        account,
+        account,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "deprecated": true
+                },
+                "username": {
+                    "type": "string",
+                    "deprecated": true
+                },
+                "role_ids": {
+                    "type": "array",
+                    "deprecated": true,
+                    "items": {
+                        "type": "integer",
+                        "format": "int32"
+                    }
+                },
+                "foobars": {
+                    "type": "array",
+                    "deprecated": true,
+                    "items": {
+                        "$ref": "#/components/schemas/Foobar"
+                    }
+                },
+                "map": {
+                    "additionalProperties": {
+                        "type": "string"
+                    },
+                    "deprecated": true,
+                    "type": "object"
+                }
+            },
+            "required": ["id", "username", "role_ids", "foobars", "map"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_deprecated_reference_fields() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+    let pet = api_doc! {
+        struct Pet {
+            #[deprecated]
+            owner: Owner,
+            #[schema(deprecated)]
+            previous_owner: Option<Owner>,
+            #[schema(inline, deprecated)]
+            inline_owner: Owner,
+        }
+    };
+
+    assert_json_eq!(
+        pet,
+        json!({
+            "properties": {
+                "owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "deprecated": true
+                },
+                "previous_owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "nullable": true,
+                    "deprecated": true
+                },
+                "inline_owner": {
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "name": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["name"]
+                        }
+                    ],
+                    "deprecated": true
+                }
+            },
+            "required": ["owner", "inline_owner"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_documented_reference_fields() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+    let pet = api_doc! {
+        /// Pet in the store
+        struct Pet {
+            /// Current owner of the pet
+            owner: Owner,
+            /// Previous owner of the pet
+            previous_owner: Option<Owner>,
+        }
+    };
+
+    assert_json_eq!(
+        pet,
+        json!({
+            "description": "Pet in the store",
+            "properties": {
+                "owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "description": "Current owner of the pet"
+                },
+                "previous_owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "description": "Previous owner of the pet",
+                    "nullable": true
+                }
+            },
+            "required": ["owner"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_object_type_description() {
+    let value = api_doc! {
+        struct Value {
+            /// This is object value
+            #[schema(value_type = Object)]
+            object: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "object": {
+                    "description": "This is object value",
+                    "type": "object"
+                },
+            },
+            "required": ["object"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_explicit_value_type() {
+    let value = api_doc! {
+        struct Value {
+            #[schema(value_type = Value)]
+            any: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "any": {
+                },
+            },
+            "required": ["any"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_implicit_value_type() {
+    let value = api_doc! {
+        struct Value {
+            any: serde_json::Value,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "any": {
+                },
+            },
+            "required": ["any"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_tuple_named_struct_field() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Person {
+        name: String,
+    }
+
+    let value = api_doc! {
+        struct Post {
+            info: (String, i64, bool, Person)
+        }
+    };
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "info": {
+                    "items": {
+                        "oneOf": [
+                            {
+                                "type": "string"
+                            },
+                            {
+                                "type": "integer",
+                                "format": "int64",
+                            },
+                            {
+                                "type": "boolean",
+                            },
+                            {
+                                "$ref": "#/components/schemas/Person"
+                            }
+                        ]
+                    },
+                    "minItems": 4,
+                    "maxItems": 4,
+                    "type": "array"
+                }
+            },
+            "type": "object",
+            "required": ["info"]
+        })
+    )
+}
+
+#[test]
+fn derive_tuple_with_same_item_types() {
+    let value = api_doc! {
+        struct Point {
+            coordinates: (f64, f64)
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "coordinates": {
+                    "items": {
+                        "type": "number",
+                        "format": "double"
+                    },
+                    "minItems": 2,
+                    "maxItems": 2,
+                    "type": "array"
+                }
+            },
+            "type": "object",
+            "required": ["coordinates"]
+        })
+    )
+}
+
+#[test]
+fn derive_nullable_tuple() {
+    let value = api_doc! {
+        struct Post {
+            /// This is description
+            #[deprecated]
+            info: Option<(String, i64)>
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "info": {
+                    "items": {
+                        "oneOf": [
+                            {
+                                "type": "string"
+                            },
+                            {
+                                "type": "integer",
+                                "format": "int64",
+                            },
+                        ]
+                    },
+                    "minItems": 2,
+                    "maxItems": 2,
+                    "type": "array",
+                    "nullable": true,
+                    "deprecated": true,
+                    "description": "This is description",
+                }
+            },
+            "type": "object",
+        })
+    )
+}
+
+#[test]
+fn derive_unit_type_untagged_enum() {
+    #[derive(Serialize)]
+    struct AggregationRequest;
+
+    let value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum ComputeRequest {
+            Aggregation(AggregationRequest),
+            Breakdown,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "$ref": "#/components/schemas/AggregationRequest"
+                },
+                {
+                    "type": "object",
+                    "nullable": true,
+                    "default": null,
+                }
+            ]
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_unit_hashmap() {
+    let value = api_doc! {
+        struct Container {
+            volumes: HashMap<String, HashMap<(), ()>>
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "volumes": {
+                    "additionalProperties": {
+                        "additionalProperties": {
+                            "default": null,
+                            "nullable": true,
+                        },
+                        "type": "object"
+                    },
+                    "type": "object"
+                },
+            },
+            "required": [
+                "volumes"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+#[cfg(feature = "rc_schema")]
+fn derive_struct_with_arc() {
+    use std::sync::Arc;
+
+    let greeting = api_doc! {
+        struct Greeting {
+            greeting: Arc<String>
+        }
+    };
+
+    assert_json_eq!(
+        greeting,
+        json!({
+            "properties": {
+                "greeting": {
+                    "type": "string"
+                },
+            },
+            "required": [
+                "greeting"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+#[cfg(feature = "rc_schema")]
+fn derive_struct_with_nested_arc() {
+    use std::sync::Arc;
+
+    let greeting = api_doc! {
+        struct Greeting {
+            #[allow(clippy::redundant_allocation)]
+            greeting: Arc<Arc<String>>
+        }
+    };
+
+    assert_json_eq!(
+        greeting,
+        json!({
+            "properties": {
+                "greeting": {
+                    "type": "string"
+                },
+            },
+            "required": [
+                "greeting"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+#[cfg(feature = "rc_schema")]
+fn derive_struct_with_collection_of_arcs() {
+    use std::sync::Arc;
+
+    let greeting = api_doc! {
+        struct Greeting {
+            greeting: Arc<Vec<String>>
+        }
+    };
+
+    assert_json_eq!(
+        greeting,
+        json!({
+            "properties": {
+                "greeting": {
+                    "items": {
+                        "type": "string",
+                    },
+                    "type": "array"
+                },
+            },
+            "required": [
+                "greeting"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+#[cfg(feature = "rc_schema")]
+fn derive_struct_with_rc() {
+    use std::rc::Rc;
+
+    let greeting = api_doc! {
+        struct Greeting {
+            greeting: Rc<String>
+        }
+    };
+
+    assert_json_eq!(
+        greeting,
+        json!({
+            "properties": {
+                "greeting": {
+                    "type": "string"
+                },
+            },
+            "required": [
+                "greeting"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_btreeset() {
+    use std::collections::BTreeSet;
+
+    let greeting = api_doc! {
+        struct Greeting {
+            values: BTreeSet<String>,
+        }
+    };
+
+    assert_json_eq!(
+        greeting,
+        json!({
+            "properties": {
+                "values": {
+                    "type": "array",
+                    "uniqueItems": true,
+                    "items": {
+                        "type": "string"
+                    }
+                },
+            },
+            "required": [
+                "values"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_hashset() {
+    use std::collections::HashSet;
+
+    let greeting = api_doc! {
+        struct Greeting {
+            values: HashSet<String>,
+        }
+    };
+
+    assert_json_eq!(
+        greeting,
+        json!({
+            "properties": {
+                "values": {
+                    "type": "array",
+                    "uniqueItems": true,
+                    "items": {
+                        "type": "string"
+                    }
+                },
+            },
+            "required": [
+                "values"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_non_zero_integers() {
+    use std::num::{NonZeroI64, NonZeroU32};
+
+    let value = api_doc! {
+        struct Counter {
+            id: NonZeroU32,
+            delta: NonZeroI64,
+            #[schema(minimum = 10)]
+            threshold: std::num::NonZeroUsize,
+            limit: Option<NonZeroU32>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
         json!({
             "properties": {
                 "id": {
                     "type": "integer",
-                    "format": "int64",
-                    "deprecated": true
+                    "format": "int32",
+                    "minimum": 1
                 },
-                "username": {
+                "delta": {
+                    "type": "integer",
+                    "format": "int64"
+                },
+                "threshold": {
+                    "type": "integer",
+                    "minimum": 10
+                },
+                "limit": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 1,
+                    "nullable": true
+                }
+            },
+            "required": [
+                "id",
+                "delta",
+                "threshold"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_std_string_types() {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::path::PathBuf;
+
+    let value = api_doc! {
+        struct Config {
+            host: IpAddr,
+            v4: Ipv4Addr,
+            v6: Option<Ipv6Addr>,
+            listen: SocketAddr,
+            #[schema(pattern = "^/")]
+            root: PathBuf,
+            files: Vec<std::path::PathBuf>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "host": {
+                    "type": "string"
+                },
+                "v4": {
                     "type": "string",
-                    "deprecated": true
+                    "format": "ipv4"
                 },
-                "role_ids": {
+                "v6": {
+                    "type": "string",
+                    "format": "ipv6",
+                    "nullable": true
+                },
+                "listen": {
+                    "type": "string"
+                },
+                "root": {
+                    "type": "string",
+                    "pattern": "^/"
+                },
+                "files": {
                     "type": "array",
-                    "deprecated": true,
                     "items": {
-                        "type": "integer",
-                        "format": "int32"
+                        "type": "string"
                     }
+                }
+            },
+            "required": [
+                "host",
+                "v4",
+                "listen",
+                "root",
+                "files"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_doc_hidden() {
+    let map = api_doc! {
+        #[doc(hidden)]
+        struct Map {
+            map: HashMap<String, String>,
+        }
+    };
+
+    assert_value! { map=>
+        "properties.map.additionalProperties.type" = r#""string""#, "Additional Property Type"
+    };
+}
+
+#[test]
+fn derive_schema_with_docstring_on_unit_variant_of_enum() {
+    let value: Value = api_doc! {
+        /// top level doc for My enum
+        #[derive(Serialize)]
+        enum MyEnum {
+            /// unit variant doc
+            UnitVariant,
+            /// non-unit doc
+            NonUnitVariant(String),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "description": "top level doc for My enum",
+            "oneOf": [
+                {
+                    "description": "unit variant doc",
+                    "enum": [
+                        "UnitVariant"
+                    ],
+                    "type": "string"
                 },
-                "foobars": {
-                    "type": "array",
-                    "deprecated": true,
-                    "items": {
-                        "$ref": "#/components/schemas/Foobar"
+                {
+                    "properties": {
+                        "NonUnitVariant": {
+                            "description": "non-unit doc",
+                            "type": "string"
+                        }
+                    },
+                    "required": [
+                        "NonUnitVariant"
+                    ],
+                    "type": "object"
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_schema_with_docstring_on_tuple_variant_first_element_option() {
+    let value: Value = api_doc! {
+        /// top level doc for My enum
+        enum MyEnum {
+            /// doc for tuple variant with Option as first element - I now produce a description
+            TupleVariantWithOptionFirst(Option<String>),
+
+            /// doc for tuple variant without Option as first element - I produce a description
+            TupleVariantWithNoOption(String),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!(
+                {
+                  "oneOf": [
+                    {
+                      "type": "object",
+                      "required": [
+                        "TupleVariantWithOptionFirst"
+                      ],
+                      "properties": {
+                        "TupleVariantWithOptionFirst": {
+                          "type": "string",
+                          "nullable": true,
+                          "description": "doc for tuple variant with Option as first element - I now produce a description"
+                        }
+                      }
+                    },
+                    {
+                      "type": "object",
+                      "required": [
+                        "TupleVariantWithNoOption"
+                      ],
+                      "properties": {
+                        "TupleVariantWithNoOption": {
+                          "type": "string",
+                          "description": "doc for tuple variant without Option as first element - I produce a description"
+                        }
+                      }
                     }
+                  ],
+                  "description": "top level doc for My enum"
+                }
+        )
+    );
+
+    let value: Value = api_doc! {
+        /// top level doc for My enum
+        enum MyEnum {
+            /// doc for tuple variant with Option as first element - I now produce a description
+            TupleVariantWithOptionFirst(Option<String>, String),
+
+            /// doc for tuple variant without Option as first element - I produce a description
+            TupleVariantWithOptionSecond(String, Option<String>),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "description": "top level doc for My enum",
+            "oneOf": [
+                {
+                    "properties": {
+                        "TupleVariantWithOptionFirst": {
+                            "description": "doc for tuple variant with Option as first element - I now produce a description",
+                            "items": {
+                                "type": "object"
+                            },
+                            "maxItems": 2,
+                            "minItems": 2,
+                            "type": "array"
+                        }
+                    },
+                    "required": [
+                        "TupleVariantWithOptionFirst"
+                    ],
+                    "type": "object"
                 },
-                "map": {
-                    "additionalProperties": {
-                        "type": "string"
+                {
+                    "properties": {
+                        "TupleVariantWithOptionSecond": {
+                            "description": "doc for tuple variant without Option as first element - I produce a description",
+                            "items": {
+                                "type": "object"
+                            },
+                            "maxItems": 2,
+                            "minItems": 2,
+                            "type": "array"
+                        }
                     },
-                    "deprecated": true,
+                    "required": [
+                        "TupleVariantWithOptionSecond"
+                    ],
                     "type": "object"
                 }
-            },
-            "required": ["id", "username", "role_ids", "foobars", "map"],
-            "type": "object"
+            ]
         })
-    )
+    );
 }
 
 #[test]
-fn derive_schema_with_object_type_description() {
+fn derive_struct_with_string_enum_values() {
     let value = api_doc! {
-        struct Value {
-            /// This is object value
-            #[schema(value_type = Object)]
-            object: String,
+        struct Query {
+            #[schema(enum_values = ["asc", "desc"])]
+            order: String,
+            #[schema(enum_values = ["name", "date"])]
+            sort: Option<String>,
         }
     };
 
@@ -4678,23 +6890,30 @@ fn derive_schema_with_object_type_description() {
         value,
         json!({
             "properties": {
-                "object": {
-                    "description": "This is object value",
-                    "type": "object"
+                "order": {
+                    "type": "string",
+                    "enum": ["asc", "desc"]
                 },
+                "sort": {
+                    "type": "string",
+                    "enum": ["name", "date"],
+                    "nullable": true
+                }
             },
-            "required": ["object"],
+            "required": ["order"],
             "type": "object"
         })
-    )
+    );
 }
 
 #[test]
-fn derive_schema_with_explicit_value_type() {
+fn derive_struct_with_integer_enum_values() {
     let value = api_doc! {
-        struct Value {
-            #[schema(value_type = Value)]
-            any: String,
+        struct Rating {
+            #[schema(enum_values = [1, 2, 3])]
+            stars: i32,
+            #[schema(enum_values = [0.5, 1.5])]
+            weight: f64,
         }
     };
 
@@ -4702,20 +6921,29 @@ fn derive_schema_with_explicit_value_type() {
         value,
         json!({
             "properties": {
-                "any": {
+                "stars": {
+                    "type": "integer",
+                    "format": "int32",
+                    "enum": [1, 2, 3]
                 },
+                "weight": {
+                    "type": "number",
+                    "format": "double",
+                    "enum": [0.5, 1.5]
+                }
             },
-            "required": ["any"],
+            "required": ["stars", "weight"],
             "type": "object"
         })
-    )
+    );
 }
 
 #[test]
-fn derive_schema_with_implicit_value_type() {
+fn derive_struct_with_vec_enum_values() {
     let value = api_doc! {
-        struct Value {
-            any: serde_json::Value,
+        struct Query {
+            #[schema(enum_values = ["asc", "desc"])]
+            orders: Vec<String>,
         }
     };
 
@@ -4723,486 +6951,411 @@ fn derive_schema_with_implicit_value_type() {
         value,
         json!({
             "properties": {
-                "any": {
-                },
+                "orders": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "enum": ["asc", "desc"]
+                    }
+                }
             },
-            "required": ["any"],
+            "required": ["orders"],
             "type": "object"
         })
-    )
+    );
 }
 
 #[test]
-fn derive_tuple_named_struct_field() {
+fn derive_struct_request_and_response_schema() {
     #[derive(ToSchema)]
     #[allow(unused)]
-    struct Person {
+    struct User {
+        #[schema(read_only)]
+        id: i64,
         name: String,
+        #[schema(write_only)]
+        password: String,
+        #[schema(read_only)]
+        created: Option<String>,
     }
 
-    let value = api_doc! {
-        struct Post {
-            info: (String, i64, bool, Person)
-        }
-    };
-    assert_json_eq!(
-        value,
-        json!({
-            "properties": {
-                "info": {
-                    "items": {
-                        "allOf": [
-                            {
-                                "type": "string"
-                            },
-                            {
-                                "type": "integer",
-                                "format": "int64",
-                            },
-                            {
-                                "type": "boolean",
-                            },
-                            {
-                                "$ref": "#/components/schemas/Person"
-                            }
-                        ]
-                    },
-                    "type": "array"
-                }
-            },
-            "type": "object",
-            "required": ["info"]
-        })
-    )
-}
-
-#[test]
-fn derive_nullable_tuple() {
-    let value = api_doc! {
-        struct Post {
-            /// This is description
-            #[deprecated]
-            info: Option<(String, i64)>
-        }
-    };
+    let (name, schema) = User::schema();
+    assert_eq!(name, "User");
+    let schema = serde_json::to_value(schema).unwrap();
+    let properties = schema.pointer("/properties").unwrap().as_object().unwrap();
+    assert_eq!(
+        properties.keys().collect::<Vec<_>>(),
+        ["created", "id", "name", "password"],
+        "schema should have all properties"
+    );
 
+    let (name, request) = User::request_schema();
+    assert_eq!(name, "User");
     assert_json_eq!(
-        value,
+        serde_json::to_value(request).unwrap(),
         json!({
+            "type": "object",
             "properties": {
-                "info": {
-                    "items": {
-                        "allOf": [
-                            {
-                                "type": "string"
-                            },
-                            {
-                                "type": "integer",
-                                "format": "int64",
-                            },
-                        ]
-                    },
-                    "type": "array",
-                    "nullable": true,
-                    "deprecated": true,
-                    "description": "This is description",
+                "name": {
+                    "type": "string"
+                },
+                "password": {
+                    "type": "string",
+                    "writeOnly": true
                 }
             },
-            "type": "object",
+            "required": ["name", "password"]
         })
-    )
-}
-
-#[test]
-fn derive_unit_type_untagged_enum() {
-    #[derive(Serialize)]
-    struct AggregationRequest;
-
-    let value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(untagged)]
-        enum ComputeRequest {
-            Aggregation(AggregationRequest),
-            Breakdown,
-        }
-    };
+    );
 
+    let (name, response) = User::response_schema();
+    assert_eq!(name, "User");
     assert_json_eq!(
-        value,
+        serde_json::to_value(response).unwrap(),
         json!({
-            "oneOf": [
-                {
-                    "$ref": "#/components/schemas/AggregationRequest"
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "readOnly": true
                 },
-                {
-                    "type": "object",
+                "name": {
+                    "type": "string"
+                },
+                "created": {
+                    "type": "string",
                     "nullable": true,
-                    "default": null,
+                    "readOnly": true
                 }
-            ]
-        })
-    )
-}
-
-#[test]
-fn derive_schema_with_unit_hashmap() {
-    let value = api_doc! {
-        struct Container {
-            volumes: HashMap<String, HashMap<(), ()>>
-        }
-    };
-
-    assert_json_eq!(
-        value,
-        json!({
-            "properties": {
-                "volumes": {
-                    "additionalProperties": {
-                        "additionalProperties": {
-                            "default": null,
-                            "nullable": true,
-                        },
-                        "type": "object"
-                    },
-                    "type": "object"
-                },
             },
-            "required": [
-                "volumes"
-            ],
-            "type": "object"
+            "required": ["id", "name"]
         })
-    )
+    );
 }
 
 #[test]
-#[cfg(feature = "rc_schema")]
-fn derive_struct_with_arc() {
-    use std::sync::Arc;
+fn derive_struct_request_and_response_schema_with_array_and_reference_fields() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        id: i64,
+    }
 
-    let greeting = api_doc! {
-        struct Greeting {
-            greeting: Arc<String>
-        }
-    };
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+        #[schema(read_only)]
+        tags: Vec<String>,
+        #[schema(read_only)]
+        owner: Owner,
+        #[schema(write_only)]
+        aliases: Vec<String>,
+        #[schema(write_only)]
+        previous_owner: Owner,
+    }
 
+    let (_, request) = Pet::request_schema();
     assert_json_eq!(
-        greeting,
+        serde_json::to_value(request).unwrap(),
         json!({
+            "type": "object",
             "properties": {
-                "greeting": {
+                "name": {
                     "type": "string"
                 },
+                "aliases": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "writeOnly": true
+                },
+                "previous_owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "writeOnly": true
+                }
             },
-            "required": [
-                "greeting"
-            ],
-            "type": "object"
+            "required": ["name", "aliases", "previous_owner"]
         })
-    )
-}
-
-#[test]
-#[cfg(feature = "rc_schema")]
-fn derive_struct_with_nested_arc() {
-    use std::sync::Arc;
-
-    let greeting = api_doc! {
-        struct Greeting {
-            #[allow(clippy::redundant_allocation)]
-            greeting: Arc<Arc<String>>
-        }
-    };
+    );
 
+    let (_, response) = Pet::response_schema();
     assert_json_eq!(
-        greeting,
+        serde_json::to_value(response).unwrap(),
         json!({
+            "type": "object",
             "properties": {
-                "greeting": {
+                "name": {
                     "type": "string"
                 },
-            },
-            "required": [
-                "greeting"
-            ],
-            "type": "object"
-        })
-    )
-}
-
-#[test]
-#[cfg(feature = "rc_schema")]
-fn derive_struct_with_collection_of_arcs() {
-    use std::sync::Arc;
-
-    let greeting = api_doc! {
-        struct Greeting {
-            greeting: Arc<Vec<String>>
-        }
-    };
-
-    assert_json_eq!(
-        greeting,
-        json!({
-            "properties": {
-                "greeting": {
+                "tags": {
+                    "type": "array",
                     "items": {
-                        "type": "string",
+                        "type": "string"
                     },
-                    "type": "array"
+                    "readOnly": true
                 },
+                "owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "readOnly": true
+                }
             },
-            "required": [
-                "greeting"
-            ],
-            "type": "object"
+            "required": ["name", "tags", "owner"]
         })
-    )
+    );
 }
 
 #[test]
-#[cfg(feature = "rc_schema")]
-fn derive_struct_with_rc() {
-    use std::rc::Rc;
+fn derive_struct_request_and_response_schema_with_inlined_fields() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        #[schema(read_only)]
+        id: i64,
+        name: String,
+    }
 
-    let greeting = api_doc! {
-        struct Greeting {
-            greeting: Rc<String>
-        }
-    };
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        #[schema(inline)]
+        owner: Owner,
+        #[schema(inline)]
+        previous_owners: Vec<Owner>,
+    }
 
+    let (_, request) = Pet::request_schema();
+    let owner = json!({
+        "type": "object",
+        "properties": {
+            "name": {
+                "type": "string"
+            }
+        },
+        "required": ["name"]
+    });
     assert_json_eq!(
-        greeting,
+        serde_json::to_value(request).unwrap(),
         json!({
+            "type": "object",
             "properties": {
-                "greeting": {
-                    "type": "string"
-                },
+                "owner": owner,
+                "previous_owners": {
+                    "type": "array",
+                    "items": owner
+                }
             },
-            "required": [
-                "greeting"
-            ],
-            "type": "object"
+            "required": ["owner", "previous_owners"]
         })
-    )
+    );
 }
 
 #[test]
-fn derive_btreeset() {
-    use std::collections::BTreeSet;
-
-    let greeting = api_doc! {
-        struct Greeting {
-            values: BTreeSet<String>,
+fn derive_struct_with_array_constraints() {
+    let value = api_doc! {
+        struct Tags {
+            #[schema(min_items = 1, max_items = 10, unique_items = true)]
+            tags: Vec<String>,
+            #[schema(max_items = 5)]
+            ids: std::collections::HashSet<i32>,
+            #[schema(unique_items)]
+            names: Option<Vec<String>>,
         }
     };
 
     assert_json_eq!(
-        greeting,
+        value,
         json!({
             "properties": {
-                "values": {
+                "tags": {
                     "type": "array",
-                    "uniqueItems": true,
                     "items": {
                         "type": "string"
-                    }
+                    },
+                    "minItems": 1,
+                    "maxItems": 10,
+                    "uniqueItems": true
+                },
+                "ids": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32"
+                    },
+                    "maxItems": 5,
+                    "uniqueItems": true
                 },
+                "names": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "uniqueItems": true,
+                    "nullable": true
+                }
             },
-            "required": [
-                "values"
-            ],
+            "required": ["tags", "ids"],
             "type": "object"
         })
-    )
+    );
 }
 
 #[test]
-fn derive_hashset() {
-    use std::collections::HashSet;
-
-    let greeting = api_doc! {
-        struct Greeting {
-            values: HashSet<String>,
+fn derive_schema_with_external_docs() {
+    let value = api_doc! {
+        #[schema(external_docs(url = "https://docs.example.com/user", description = "User docs"))]
+        struct User {
+            id: i64,
         }
     };
 
     assert_json_eq!(
-        greeting,
+        value,
         json!({
             "properties": {
-                "values": {
-                    "type": "array",
-                    "uniqueItems": true,
-                    "items": {
-                        "type": "string"
-                    }
-                },
+                "id": {
+                    "type": "integer",
+                    "format": "int64"
+                }
             },
-            "required": [
-                "values"
-            ],
-            "type": "object"
+            "required": ["id"],
+            "type": "object",
+            "externalDocs": {
+                "url": "https://docs.example.com/user",
+                "description": "User docs"
+            }
         })
-    )
-}
+    );
 
-#[test]
-fn derive_doc_hidden() {
-    let map = api_doc! {
-        #[doc(hidden)]
-        struct Map {
-            map: HashMap<String, String>,
+    let value = api_doc! {
+        #[schema(external_docs(url = "https://docs.example.com/status"))]
+        enum Status {
+            Active,
+            Inactive,
         }
     };
 
-    assert_value! { map=>
-        "properties.map.additionalProperties.type" = r#""string""#, "Additional Property Type"
-    };
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "string",
+            "enum": ["Active", "Inactive"],
+            "externalDocs": {
+                "url": "https://docs.example.com/status"
+            }
+        })
+    );
 }
 
 #[test]
-fn derive_schema_with_docstring_on_unit_variant_of_enum() {
-    let value: Value = api_doc! {
-        /// top level doc for My enum
-        #[derive(Serialize)]
-        enum MyEnum {
-            /// unit variant doc
-            UnitVariant,
-            /// non-unit doc
-            NonUnitVariant(String),
+fn derive_schema_with_extensions() {
+    let value = api_doc! {
+        #[schema(extensions(("x-internal" = true), ("x-owner" = "pets")))]
+        struct User {
+            id: i64,
         }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "description": "top level doc for My enum",
-            "oneOf": [
-                {
-                    "description": "unit variant doc",
-                    "enum": [
-                        "UnitVariant"
-                    ],
-                    "type": "string"
-                },
-                {
-                    "properties": {
-                        "NonUnitVariant": {
-                            "description": "non-unit doc",
-                            "type": "string"
-                        }
-                    },
-                    "required": [
-                        "NonUnitVariant"
-                    ],
-                    "type": "object"
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64"
                 }
-            ]
+            },
+            "required": ["id"],
+            "type": "object",
+            "x-internal": true,
+            "x-owner": "pets"
         })
     );
-}
-
-#[test]
-fn derive_schema_with_docstring_on_tuple_variant_first_element_option() {
-    let value: Value = api_doc! {
-        /// top level doc for My enum
-        enum MyEnum {
-            /// doc for tuple variant with Option as first element - I now produce a description
-            TupleVariantWithOptionFirst(Option<String>),
 
-            /// doc for tuple variant without Option as first element - I produce a description
-            TupleVariantWithNoOption(String),
+    let value = api_doc! {
+        #[schema(extensions(("x-enum-varnames" = json!(["ACTIVE", "INACTIVE"]))))]
+        enum Status {
+            Active,
+            Inactive,
         }
     };
 
     assert_json_eq!(
         value,
-        json!(
-                {
-                  "oneOf": [
-                    {
-                      "type": "object",
-                      "required": [
-                        "TupleVariantWithOptionFirst"
-                      ],
-                      "properties": {
-                        "TupleVariantWithOptionFirst": {
-                          "type": "string",
-                          "nullable": true,
-                          "description": "doc for tuple variant with Option as first element - I now produce a description"
-                        }
-                      }
-                    },
-                    {
-                      "type": "object",
-                      "required": [
-                        "TupleVariantWithNoOption"
-                      ],
-                      "properties": {
-                        "TupleVariantWithNoOption": {
-                          "type": "string",
-                          "description": "doc for tuple variant without Option as first element - I produce a description"
-                        }
-                      }
-                    }
-                  ],
-                  "description": "top level doc for My enum"
-                }
-        )
+        json!({
+            "type": "string",
+            "enum": ["Active", "Inactive"],
+            "x-enum-varnames": ["ACTIVE", "INACTIVE"]
+        })
     );
 
-    let value: Value = api_doc! {
-        /// top level doc for My enum
-        enum MyEnum {
-            /// doc for tuple variant with Option as first element - I now produce a description
-            TupleVariantWithOptionFirst(Option<String>, String),
+    let value = api_doc! {
+        #[schema(extensions(("x-internal" = true)))]
+        enum Shape {
+            Circle { radius: f64 },
+            Square(f64),
+        }
+    };
 
-            /// doc for tuple variant without Option as first element - I produce a description
-            TupleVariantWithOptionSecond(String, Option<String>),
+    assert_eq!(value["x-internal"], json!(true));
+    assert!(value["oneOf"].is_array());
+}
+
+#[test]
+fn derive_complex_enum_with_external_docs() {
+    let value = api_doc! {
+        #[schema(external_docs(url = "https://docs.example.com/shape", description = "Shape docs"))]
+        enum Shape {
+            Circle { radius: f64 },
+            Square(f64),
         }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "description": "top level doc for My enum",
             "oneOf": [
                 {
+                    "type": "object",
                     "properties": {
-                        "TupleVariantWithOptionFirst": {
-                            "description": "doc for tuple variant with Option as first element - I now produce a description",
-                            "items": {
-                                "type": "object"
+                        "Circle": {
+                            "type": "object",
+                            "properties": {
+                                "radius": {
+                                    "type": "number",
+                                    "format": "double"
+                                }
                             },
-                            "maxItems": 2,
-                            "minItems": 2,
-                            "type": "array"
+                            "required": ["radius"]
                         }
                     },
-                    "required": [
-                        "TupleVariantWithOptionFirst"
-                    ],
-                    "type": "object"
+                    "required": ["Circle"]
                 },
                 {
+                    "type": "object",
                     "properties": {
-                        "TupleVariantWithOptionSecond": {
-                            "description": "doc for tuple variant without Option as first element - I produce a description",
-                            "items": {
-                                "type": "object"
-                            },
-                            "maxItems": 2,
-                            "minItems": 2,
-                            "type": "array"
+                        "Square": {
+                            "type": "number",
+                            "format": "double"
                         }
                     },
-                    "required": [
-                        "TupleVariantWithOptionSecond"
-                    ],
-                    "type": "object"
+                    "required": ["Square"]
                 }
-            ]
+            ],
+            "externalDocs": {
+                "url": "https://docs.example.com/shape",
+                "description": "Shape docs"
+            }
         })
     );
 }
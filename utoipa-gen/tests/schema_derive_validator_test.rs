@@ -0,0 +1,187 @@
+#![cfg(feature = "validator")]
+
+use std::{collections::HashMap, ops::Deref};
+
+use assert_json_diff::assert_json_eq;
+use serde_json::json;
+use utoipa::{IntoParams, ToSchema};
+
+/// Stand-in for `regex::Regex` as only `as_str()` is needed to render the pattern.
+struct Regex(&'static str);
+
+impl Regex {
+    fn as_str(&self) -> &str {
+        self.0
+    }
+}
+
+/// Stand-in for lazily initialized regex e.g. `LazyLock<Regex>`.
+struct LazyRegex(Regex);
+
+impl Deref for LazyRegex {
+    type Target = Regex;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+static NAME_REGEX: LazyRegex = LazyRegex(Regex("^[a-z]+$"));
+
+#[test]
+fn derive_schema_with_validator_attributes() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct User {
+        #[validate(length(min = 1, max = 50, message = "invalid length"))]
+        name: String,
+        #[validate(range(min = -10, max = 10.5))]
+        score: f64,
+        #[validate(range(exclusive_min = 0, exclusive_max = 150))]
+        age: Option<u8>,
+        #[validate(email)]
+        email: String,
+        #[validate(url)]
+        homepage: Option<String>,
+        #[validate(regex(path = *NAME_REGEX, code = "name"))]
+        nick: String,
+        #[validate(regex = "NAME_REGEX")]
+        alias: String,
+        #[validate(length(equal = 2))]
+        tags: Vec<String>,
+        #[validate(length(max = 5))]
+        labels: HashMap<String, String>,
+        #[validate(required, length(min = 3))]
+        city: Option<String>,
+    }
+
+    let schema = serde_json::to_value(<User as ToSchema>::schema().1).unwrap();
+    let properties = schema.pointer("/properties").unwrap();
+
+    assert_json_eq!(
+        properties,
+        json!({
+            "name": {
+                "type": "string",
+                "minLength": 1,
+                "maxLength": 50
+            },
+            "score": {
+                "type": "number",
+                "format": "double",
+                "minimum": -10,
+                "maximum": 10.5
+            },
+            "age": {
+                "type": "integer",
+                "format": "int32",
+                "minimum": 0,
+                "nullable": true,
+                "exclusiveMinimum": 0,
+                "exclusiveMaximum": 150
+            },
+            "email": {
+                "type": "string",
+                "format": "email"
+            },
+            "homepage": {
+                "type": "string",
+                "format": "uri",
+                "nullable": true
+            },
+            "nick": {
+                "type": "string",
+                "pattern": "^[a-z]+$"
+            },
+            "alias": {
+                "type": "string",
+                "pattern": "^[a-z]+$"
+            },
+            "tags": {
+                "type": "array",
+                "items": {
+                    "type": "string"
+                },
+                "minItems": 2,
+                "maxItems": 2
+            },
+            "labels": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": "string"
+                },
+                "maxProperties": 5
+            },
+            "city": {
+                "type": "string",
+                "minLength": 3,
+                "nullable": true
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_schema_attributes_override_validator_attributes() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct User {
+        #[validate(length(min = 1, max = 50))]
+        #[schema(max_length = 20)]
+        name: String,
+    }
+
+    let schema = serde_json::to_value(<User as ToSchema>::schema().1).unwrap();
+    let name = schema.pointer("/properties/name").unwrap();
+
+    assert_json_eq!(
+        name,
+        json!({
+            "type": "string",
+            "minLength": 1,
+            "maxLength": 20
+        })
+    );
+}
+
+#[test]
+fn derive_into_params_with_validator_attributes() {
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Filter {
+        #[validate(length(min = 3))]
+        name: String,
+        #[validate(range(min = 1, max = 100))]
+        limit: Option<i32>,
+    }
+
+    let parameters = serde_json::to_value(Filter::into_params(|| None)).unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "minLength": 3
+                }
+            },
+            {
+                "in": "query",
+                "name": "limit",
+                "required": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "nullable": true,
+                    "minimum": 1,
+                    "maximum": 100
+                }
+            }
+        ])
+    );
+}
@@ -56,8 +56,10 @@
 //!
 //! # Customization
 //!
-//! Utoipa-rapidoc can be customized and configured only via [`RapiDoc::custom_html`] method. This
+//! Utoipa-rapidoc can be customized and configured via [`RapiDoc::custom_html`] method. This
 //! method empowers users to use a custom HTML template to modify the looks of the RapiDoc UI.
+//! Most common options [`RapiDoc::theme`] and [`RapiDoc::render_style`] can be set without a
+//! custom HTML template.
 //!
 //! * [All allowed RapiDoc configuration options][rapidoc_api]
 //! * [Default HTML template][rapidoc_quickstart]
@@ -66,6 +68,17 @@
 //! OpenAPI spec url provided with [`RapiDoc::new`] function when creating a new [`RapiDoc`]
 //! instance. Variable will be replaced during [`RapiDoc::to_html`] function execution.
 //!
+//! Optionally the template may contain _**`$theme`**_ and _**`$renderStyle`**_ variables which
+//! will be replaced with [`Theme`] and [`RenderStyle`] of the [`RapiDoc`] instance.
+//!
+//! _**Use dark theme with focused render style.**_
+//! ```rust
+//! # use utoipa_rapidoc::{RapiDoc, RenderStyle, Theme};
+//! RapiDoc::new("/api-docs/openapi.json")
+//!     .theme(Theme::Dark)
+//!     .render_style(RenderStyle::Focused);
+//! ```
+//!
 //! _**Overriding the HTML template with a custom one.**_
 //! ```rust
 //! # use utoipa_rapidoc::RapiDoc;
@@ -79,6 +92,16 @@
 //! RapiDoc::new("/api-docs/openapi.json").custom_html(html);
 //! ```
 //!
+//! # Access control
+//!
+//! RapiDoc and the api doc url can be protected with a [`guard::Guard`] checking the
+//! `Authorization` header of the requests, e.g. with [`guard::BasicAuth`] or
+//! [`guard::BearerAuth`]. See [`RapiDoc::guard`].
+//!
+//! **Note!** RapiDoc and the api doc url are only served in debug builds by default to keep
+//! internal api documentation private. Serve them in release builds as well with
+//! [`RapiDoc::enabled`], preferably behind a guard.
+//!
 //! # Examples
 //!
 //! _**Serve [`RapiDoc`] via `actix-web` framework.**_
@@ -135,6 +158,8 @@
 
 use std::borrow::Cow;
 
+pub use utoipa::guard;
+
 const DEFAULT_HTML: &str = include_str!("../res/rapidoc.html");
 
 /// Is [RapiDoc][rapidoc] UI.
@@ -151,8 +176,14 @@ pub struct RapiDoc {
     path: Cow<'static, str>,
     spec_url: Cow<'static, str>,
     html: Cow<'static, str>,
+    theme: Theme,
+    render_style: RenderStyle,
     #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
     openapi: Option<utoipa::openapi::OpenApi>,
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    guard: Option<guard::SharedGuard>,
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    enabled: bool,
 }
 
 impl RapiDoc {
@@ -172,8 +203,14 @@ impl RapiDoc {
             path: Cow::Borrowed(""),
             spec_url: spec_url.into(),
             html: Cow::Borrowed(DEFAULT_HTML),
+            theme: Theme::default(),
+            render_style: RenderStyle::default(),
             #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
             openapi: None,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            guard: None,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            enabled: cfg!(debug_assertions),
         }
     }
 
@@ -211,7 +248,11 @@ impl RapiDoc {
             path: Cow::Borrowed(""),
             spec_url: spec_url.into(),
             html: Cow::Borrowed(DEFAULT_HTML),
+            theme: Theme::default(),
+            render_style: RenderStyle::default(),
             openapi: Some(openapi),
+            guard: None,
+            enabled: cfg!(debug_assertions),
         }
     }
 
@@ -226,6 +267,40 @@ impl RapiDoc {
         self
     }
 
+    /// Set the [`Theme`] of the [`RapiDoc`]. By default [`Theme::Light`] is used.
+    ///
+    /// # Examples
+    ///
+    /// _**Use dark theme.**_
+    /// ```
+    /// # use utoipa_rapidoc::{RapiDoc, Theme};
+    /// let rapidoc = RapiDoc::new("/api-docs/openapi.json").theme(Theme::Dark);
+    ///
+    /// assert!(rapidoc.to_html().contains(r#"theme="dark""#));
+    /// ```
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Set the [`RenderStyle`] of the [`RapiDoc`]. By default [`RenderStyle::View`] is used.
+    ///
+    /// # Examples
+    ///
+    /// _**Use read render style.**_
+    /// ```
+    /// # use utoipa_rapidoc::{RapiDoc, RenderStyle};
+    /// let rapidoc = RapiDoc::new("/api-docs/openapi.json").render_style(RenderStyle::Read);
+    ///
+    /// assert!(rapidoc.to_html().contains(r#"render-style="read""#));
+    /// ```
+    pub fn render_style(mut self, render_style: RenderStyle) -> Self {
+        self.render_style = render_style;
+
+        self
+    }
+
     /// Add `path` the [`RapiDoc`] will be served from.
     ///
     /// # Examples
@@ -244,10 +319,61 @@ impl RapiDoc {
         self
     }
 
+    /// Add [`Guard`][guard::Guard] to protect RapiDoc and the api doc url from unauthorized access.
+    ///
+    /// Every request to the RapiDoc or the api doc url is checked with the guard and unauthorized
+    /// requests are responded with `401 Unauthorized`. See [`guard`] module for the built-in guards.
+    ///
+    /// # Examples
+    ///
+    /// Require basic authentication to access the documentation.
+    /// ```rust
+    /// # use utoipa_rapidoc::{guard::BasicAuth, RapiDoc};
+    /// let rapidoc = RapiDoc::new("/api-docs/openapi.json")
+    ///     .path("/rapidoc")
+    ///     .guard(BasicAuth::new("admin", "secret"));
+    /// ```
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    )]
+    pub fn guard<G: guard::Guard>(mut self, guard: G) -> Self {
+        self.guard = Some(guard::SharedGuard::new(guard));
+
+        self
+    }
+
+    /// Define whether RapiDoc and the api doc url are served at all. By default they are served
+    /// only in debug builds.
+    ///
+    /// When disabled no routes are registered and the documentation is not reachable.
+    ///
+    /// # Examples
+    ///
+    /// Serve RapiDoc in release builds as well.
+    /// ```rust
+    /// # use utoipa_rapidoc::{RapiDoc};
+    /// let rapidoc = RapiDoc::new("/api-docs/openapi.json")
+    ///     .path("/rapidoc")
+    ///     .enabled(true);
+    /// ```
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    )]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+
+        self
+    }
+
     /// Converts this [`RapiDoc`] instance to servable HTML file.
     ///
     /// This will replace _**`$specUrl`**_ variable placeholder with the spec
-    /// url provided to the [`RapiDoc`] instance. If HTML template is not overridden with
+    /// url provided to the [`RapiDoc`] instance and _**`$theme`**_ and _**`$renderStyle`**_ with
+    /// [`Theme`] and [`RenderStyle`] of the instance. If HTML template is not overridden with
     /// [`RapiDoc::custom_html`] then the [default HTML template][rapidoc_quickstart]
     /// will be used.
     ///
@@ -256,7 +382,55 @@ impl RapiDoc {
     /// [rapidoc_quickstart]: <https://rapidocweb.com/quickstart.html>
     /// [customization]: index.html#customization
     pub fn to_html(&self) -> String {
-        self.html.replace("$specUrl", self.spec_url.as_ref())
+        self.html
+            .replace("$specUrl", self.spec_url.as_ref())
+            .replace("$theme", self.theme.as_str())
+            .replace("$renderStyle", self.render_style.as_str())
+    }
+}
+
+/// Color theme of the [RapiDoc][rapidoc] UI.
+///
+/// [rapidoc]: <https://rapidocweb.com/api.html#att-general>
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Theme {
+    /// Light theme, the default theme of RapiDoc.
+    #[default]
+    Light,
+    /// Dark theme.
+    Dark,
+}
+
+impl Theme {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+/// Render style of the [RapiDoc][rapidoc] UI defining how the operations are laid out.
+///
+/// [rapidoc]: <https://rapidocweb.com/api.html#att-layout>
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum RenderStyle {
+    /// Operations are rendered as collapsible blocks, the default style of RapiDoc.
+    #[default]
+    View,
+    /// Operations are rendered in a single continuous page suitable for reading.
+    Read,
+    /// Only the selected operation is rendered at a time.
+    Focused,
+}
+
+impl RenderStyle {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::View => "view",
+            Self::Read => "read",
+            Self::Focused => "focused",
+        }
     }
 }
 
@@ -265,35 +439,52 @@ mod actix {
 
     use actix_web::dev::HttpServiceFactory;
     use actix_web::guard::Get;
+    use actix_web::http::header;
     use actix_web::web::Data;
-    use actix_web::{HttpResponse, Resource, Responder};
+    use actix_web::{HttpRequest, HttpResponse, Resource};
 
+    use crate::guard::SharedGuard;
     use crate::RapiDoc;
 
     impl HttpServiceFactory for RapiDoc {
         fn register(self, config: &mut actix_web::dev::AppService) {
+            if !self.enabled {
+                return;
+            }
+
             let html = self.to_html();
 
-            async fn serve_rapidoc(rapidoc: Data<String>) -> impl Responder {
+            async fn serve_rapidoc(request: HttpRequest, rapidoc: Data<String>) -> HttpResponse {
+                if let Some(unauthorized) = unauthorized(&request) {
+                    return unauthorized;
+                }
+
                 HttpResponse::Ok()
                     .content_type("text/html")
                     .body(rapidoc.to_string())
             }
 
-            Resource::new(self.path.as_ref())
+            with_guard(Resource::new(self.path.as_ref()), self.guard.clone())
                 .guard(Get())
                 .app_data(Data::new(html))
                 .to(serve_rapidoc)
                 .register(config);
 
             if let Some(openapi) = self.openapi {
-                async fn serve_openapi(openapi: Data<String>) -> impl Responder {
+                async fn serve_openapi(
+                    request: HttpRequest,
+                    openapi: Data<String>,
+                ) -> HttpResponse {
+                    if let Some(unauthorized) = unauthorized(&request) {
+                        return unauthorized;
+                    }
+
                     HttpResponse::Ok()
                         .content_type("application/json")
                         .body(openapi.into_inner().to_string())
                 }
 
-                Resource::new(self.spec_url.as_ref())
+                with_guard(Resource::new(self.spec_url.as_ref()), self.guard)
                     .guard(Get())
                     .app_data(Data::new(
                         openapi.to_json().expect("Should serialize to JSON"),
@@ -303,14 +494,43 @@ mod actix {
             }
         }
     }
+
+    fn with_guard(resource: Resource, guard: Option<SharedGuard>) -> Resource {
+        match guard {
+            Some(guard) => resource.app_data(Data::new(guard)),
+            None => resource,
+        }
+    }
+
+    /// Get unauthorized response if resource has [`SharedGuard`] which does not authorize the
+    /// request.
+    fn unauthorized(request: &HttpRequest) -> Option<HttpResponse> {
+        let guard = request.app_data::<Data<SharedGuard>>()?;
+        let authorization = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|authorization| authorization.to_str().ok());
+
+        guard.unauthorized(authorization).map(|challenge| {
+            let mut response = HttpResponse::Unauthorized();
+            if let Some(challenge) = challenge {
+                response.insert_header((header::WWW_AUTHENTICATE, challenge.into_owned()));
+            }
+            response.finish()
+        })
+    }
 }
 
 mod axum {
     #![cfg(feature = "axum")]
 
-    use axum::response::Html;
+    use axum::extract::Request;
+    use axum::http::{header, StatusCode};
+    use axum::middleware::{self, Next};
+    use axum::response::{Html, IntoResponse, Response};
     use axum::{routing, Json, Router};
 
+    use crate::guard::SharedGuard;
     use crate::RapiDoc;
 
     impl<R> From<RapiDoc> for Router<R>
@@ -318,6 +538,10 @@ mod axum {
         R: Clone + Send + Sync + 'static,
     {
         fn from(value: RapiDoc) -> Self {
+            if !value.enabled {
+                return Router::new();
+            }
+
             let html = value.to_html();
             let openapi = value.openapi;
 
@@ -333,7 +557,30 @@ mod axum {
                 );
             }
 
-            router
+            if let Some(guard) = value.guard {
+                router.route_layer(middleware::from_fn(move |request: Request, next: Next| {
+                    authorize(guard.clone(), request, next)
+                }))
+            } else {
+                router
+            }
+        }
+    }
+
+    async fn authorize(guard: SharedGuard, request: Request, next: Next) -> Response {
+        let authorization = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|authorization| authorization.to_str().ok());
+
+        match guard.unauthorized(authorization) {
+            Some(Some(challenge)) => (
+                StatusCode::UNAUTHORIZED,
+                [(header::WWW_AUTHENTICATE, challenge.into_owned())],
+            )
+                .into_response(),
+            Some(None) => StatusCode::UNAUTHORIZED.into_response(),
+            None => next.run(request).await,
         }
     }
 }
@@ -341,27 +588,38 @@ mod axum {
 mod rocket {
     #![cfg(feature = "rocket")]
 
-    use rocket::http::Method;
+    use std::borrow::Cow;
+
+    use rocket::http::{Method, Status};
     use rocket::response::content::RawHtml;
+    use rocket::response::Responder;
     use rocket::route::{Handler, Outcome};
     use rocket::serde::json::Json;
-    use rocket::{Data, Request, Route};
+    use rocket::{Data, Request, Response, Route};
 
+    use crate::guard::SharedGuard;
     use crate::RapiDoc;
 
     impl From<RapiDoc> for Vec<Route> {
         fn from(value: RapiDoc) -> Self {
+            if !value.enabled {
+                return Vec::new();
+            }
+
             let mut routes = vec![Route::new(
                 Method::Get,
                 value.path.as_ref(),
-                RapiDocHandler(value.to_html()),
+                RapiDocHandler(value.to_html(), value.guard.clone()),
             )];
 
             if let Some(openapi) = value.openapi {
                 routes.push(Route::new(
                     Method::Get,
                     value.spec_url.as_ref(),
-                    OpenApiHandler(openapi.to_json().expect("Should serialize to JSON")),
+                    OpenApiHandler(
+                        openapi.to_json().expect("Should serialize to JSON"),
+                        value.guard,
+                    ),
                 ));
             }
 
@@ -370,22 +628,53 @@ mod rocket {
     }
 
     #[derive(Clone)]
-    struct RapiDocHandler(String);
+    struct RapiDocHandler(String, Option<SharedGuard>);
 
     #[rocket::async_trait]
     impl Handler for RapiDocHandler {
         async fn handle<'r>(&self, request: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
+            if let Some(unauthorized) = Unauthorized::from_request(self.1.as_ref(), request) {
+                return Outcome::from(request, unauthorized);
+            }
+
             Outcome::from(request, RawHtml(self.0.clone()))
         }
     }
 
     #[derive(Clone)]
-    struct OpenApiHandler(String);
+    struct OpenApiHandler(String, Option<SharedGuard>);
 
     #[rocket::async_trait]
     impl Handler for OpenApiHandler {
         async fn handle<'r>(&self, request: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
+            if let Some(unauthorized) = Unauthorized::from_request(self.1.as_ref(), request) {
+                return Outcome::from(request, unauthorized);
+            }
+
             Outcome::from(request, Json(self.0.clone()))
         }
     }
+
+    /// Unauthorized response with optional `WWW-Authenticate` challenge.
+    struct Unauthorized(Option<Cow<'static, str>>);
+
+    impl Unauthorized {
+        fn from_request(guard: Option<&SharedGuard>, request: &Request<'_>) -> Option<Self> {
+            guard?
+                .unauthorized(request.headers().get_one("Authorization"))
+                .map(Self)
+        }
+    }
+
+    impl<'r, 'a: 'r> Responder<'r, 'a> for Unauthorized {
+        fn respond_to(self, _request: &'r Request<'_>) -> rocket::response::Result<'a> {
+            let mut response = Response::build();
+            response.status(Status::Unauthorized);
+            if let Some(challenge) = self.0 {
+                response.raw_header("WWW-Authenticate", challenge);
+            }
+
+            response.ok()
+        }
+    }
 }
@@ -66,6 +66,9 @@
 //! * **`$spec`** Will be the [`Spec`] that will be rendered via [Redoc][redoc].
 //! * **`$config`** Will be the current [`Config`]. By default this is [`EmptyConfig`].
 //!
+//! Optionally the HTML template may contain **`$title`** variable which will be replaced with the
+//! title given with [`Redoc::title`]. By default the title is `Redoc`.
+//!
 //! _**Overriding the HTML template with a custom one.**_
 //! ```rust
 //! # use utoipa_redoc::Redoc;
@@ -112,6 +115,14 @@
 //!
 //! Read more details in [`Config`].
 //!
+//! # Access control
+//!
+//! Redoc can be protected with a [`guard::Guard`] checking the `Authorization` header of the
+//! requests, e.g. with [`guard::BasicAuth`] or [`guard::BearerAuth`]. See [`Redoc::guard`].
+//!
+//! **Note!** Redoc is only served in debug builds by default to keep internal api documentation
+//! private. Serve it in release builds as well with [`Redoc::enabled`], preferably behind a guard.
+//!
 //! # Examples
 //!
 //! _**Serve [`Redoc`] via `actix-web` framework.**_
@@ -188,11 +199,14 @@ use serde::Serialize;
 use serde_json::{json, Value};
 use utoipa::openapi::OpenApi;
 
+pub use utoipa::guard;
+
 mod actix;
 mod axum;
 mod rocket;
 
 const DEFAULT_HTML: &str = include_str!("../res/redoc.html");
+const DEFAULT_TITLE: &str = "Redoc";
 
 /// Trait makes [`Redoc`] to accept an _`URL`_ the [Redoc][redoc] will be served via predefined web
 /// server.
@@ -242,8 +256,13 @@ impl<S: Spec> Servable<S> for Redoc<S> {
         Self {
             url: url.into(),
             html: Cow::Borrowed(DEFAULT_HTML),
+            title: Cow::Borrowed(DEFAULT_TITLE),
             openapi,
             config: config.load(),
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            guard: None,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            enabled: cfg!(debug_assertions),
         }
     }
 }
@@ -261,8 +280,13 @@ pub struct Redoc<S: Spec> {
     #[allow(unused)]
     url: Cow<'static, str>,
     html: Cow<'static, str>,
+    title: Cow<'static, str>,
     openapi: S,
     config: Value,
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    guard: Option<guard::SharedGuard>,
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    enabled: bool,
 }
 
 impl<S: Spec> Redoc<S> {
@@ -295,9 +319,14 @@ impl<S: Spec> Redoc<S> {
     pub fn with_config<C: Config>(openapi: S, config: C) -> Self {
         Self {
             html: Cow::Borrowed(DEFAULT_HTML),
+            title: Cow::Borrowed(DEFAULT_TITLE),
             url: Cow::Borrowed(""),
             openapi,
             config: config.load(),
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            guard: None,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            enabled: cfg!(debug_assertions),
         }
     }
 
@@ -312,11 +341,79 @@ impl<S: Spec> Redoc<S> {
         self
     }
 
+    /// Set the title of the Redoc page replacing _**`$title`**_ variable of the HTML template.
+    /// By default the title is `Redoc`.
+    ///
+    /// # Examples
+    ///
+    /// _**Render [`Redoc`] with custom title.**_
+    /// ```
+    /// # use utoipa_redoc::Redoc;
+    /// # use serde_json::json;
+    /// let redoc = Redoc::new(json!({"openapi": "3.1.0"})).title("Pet Store API");
+    ///
+    /// assert!(redoc.to_html().contains("<title>Pet Store API</title>"));
+    /// ```
+    pub fn title<T: Into<Cow<'static, str>>>(mut self, title: T) -> Self {
+        self.title = title.into();
+
+        self
+    }
+
+    /// Add [`Guard`][guard::Guard] to protect Redoc from unauthorized access.
+    ///
+    /// Every request to Redoc is checked with the guard and unauthorized requests are
+    /// responded with `401 Unauthorized`. See [`guard`] module for the built-in guards.
+    ///
+    /// # Examples
+    ///
+    /// Require basic authentication to access the documentation.
+    /// ```rust
+    /// # use utoipa_redoc::{guard::BasicAuth, Redoc, Servable};
+    /// # use serde_json::json;
+    /// let redoc = Redoc::with_url("/redoc", json!({"openapi": "3.1.0"}))
+    ///     .guard(BasicAuth::new("admin", "secret"));
+    /// ```
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    )]
+    pub fn guard<G: guard::Guard>(mut self, guard: G) -> Self {
+        self.guard = Some(guard::SharedGuard::new(guard));
+
+        self
+    }
+
+    /// Define whether Redoc is served at all. By default it is served only in debug builds.
+    ///
+    /// When disabled no routes are registered and the documentation is not reachable.
+    ///
+    /// # Examples
+    ///
+    /// Serve Redoc in release builds as well.
+    /// ```rust
+    /// # use utoipa_redoc::{Redoc, Servable};
+    /// # use serde_json::json;
+    /// let redoc = Redoc::with_url("/redoc", json!({"openapi": "3.1.0"}))
+    ///     .enabled(true);
+    /// ```
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    )]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+
+        self
+    }
+
     /// Converts this [`Redoc`] instance to servable HTML file.
     ///
-    /// This will replace _**`$config`**_ variable placeholder with [`Config`] of this instance and
-    /// _**`$spec`**_ with [`Spec`] provided to this instance serializing it to JSON from the HTML
-    /// template used with the [`Redoc`]. If HTML template is not overridden with
+    /// This will replace _**`$config`**_ variable placeholder with [`Config`] of this instance,
+    /// _**`$title`**_ with the title of this instance and _**`$spec`**_ with [`Spec`] provided to
+    /// this instance serializing it to JSON from the HTML template used with the [`Redoc`]. If HTML template is not overridden with
     /// [`Redoc::custom_html`] then the [default HTML template][redoc_html_quickstart] will be used.
     ///
     /// See more details in [customization][customization].
@@ -326,6 +423,7 @@ impl<S: Spec> Redoc<S> {
     pub fn to_html(&self) -> String {
         self.html
             .replace("$config", &self.config.to_string())
+            .replace("$title", &self.title)
             .replace(
                 "$spec",
                 &serde_json::to_string(&self.openapi).expect(
@@ -1,28 +1,63 @@
 #![cfg(feature = "rocket")]
 
-use rocket::http::Method;
+use std::borrow::Cow;
+
+use rocket::http::{Method, Status};
 use rocket::response::content::RawHtml;
+use rocket::response::Responder;
 use rocket::route::{Handler, Outcome};
-use rocket::{Data, Request, Route};
+use rocket::{Data, Request, Response, Route};
 
+use crate::guard::SharedGuard;
 use crate::{Redoc, Spec};
 
 impl<S: Spec> From<Redoc<S>> for Vec<Route> {
     fn from(value: Redoc<S>) -> Self {
+        if !value.enabled {
+            return Vec::new();
+        }
+
         vec![Route::new(
             Method::Get,
             value.url.as_ref(),
-            RedocHandler(value.to_html()),
+            RedocHandler(value.to_html(), value.guard),
         )]
     }
 }
 
 #[derive(Clone)]
-struct RedocHandler(String);
+struct RedocHandler(String, Option<SharedGuard>);
 
 #[rocket::async_trait]
 impl Handler for RedocHandler {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
+        if let Some(unauthorized) = Unauthorized::from_request(self.1.as_ref(), request) {
+            return Outcome::from(request, unauthorized);
+        }
+
         Outcome::from(request, RawHtml(self.0.clone()))
     }
 }
+
+/// Unauthorized response with optional `WWW-Authenticate` challenge.
+struct Unauthorized(Option<Cow<'static, str>>);
+
+impl Unauthorized {
+    fn from_request(guard: Option<&SharedGuard>, request: &Request<'_>) -> Option<Self> {
+        guard?
+            .unauthorized(request.headers().get_one("Authorization"))
+            .map(Self)
+    }
+}
+
+impl<'r, 'a: 'r> Responder<'r, 'a> for Unauthorized {
+    fn respond_to(self, _request: &'r Request<'_>) -> rocket::response::Result<'a> {
+        let mut response = Response::build();
+        response.status(Status::Unauthorized);
+        if let Some(challenge) = self.0 {
+            response.raw_header("WWW-Authenticate", challenge);
+        }
+
+        response.ok()
+    }
+}
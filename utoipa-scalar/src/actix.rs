@@ -2,25 +2,58 @@
 
 use actix_web::dev::HttpServiceFactory;
 use actix_web::guard::Get;
+use actix_web::http::header;
 use actix_web::web::Data;
-use actix_web::{HttpResponse, Resource, Responder};
+use actix_web::{HttpRequest, HttpResponse, Resource};
 
+use crate::guard::SharedGuard;
 use crate::{Scalar, Spec};
 
 impl<S: Spec> HttpServiceFactory for Scalar<S> {
     fn register(self, config: &mut actix_web::dev::AppService) {
+        if !self.enabled {
+            return;
+        }
+
         let html = self.to_html();
 
-        async fn serve_scalar(scalar: Data<String>) -> impl Responder {
+        async fn serve_scalar(request: HttpRequest, scalar: Data<String>) -> HttpResponse {
+            if let Some(unauthorized) = unauthorized(&request) {
+                return unauthorized;
+            }
+
             HttpResponse::Ok()
                 .content_type("text/html")
                 .body(scalar.to_string())
         }
 
-        Resource::new(self.url.as_ref())
+        let resource = Resource::new(self.url.as_ref());
+        let resource = match self.guard {
+            Some(guard) => resource.app_data(Data::new(guard)),
+            None => resource,
+        };
+
+        resource
             .guard(Get())
             .app_data(Data::new(html))
             .to(serve_scalar)
             .register(config);
     }
 }
+
+/// Get unauthorized response if resource has [`SharedGuard`] which does not authorize the request.
+fn unauthorized(request: &HttpRequest) -> Option<HttpResponse> {
+    let guard = request.app_data::<Data<SharedGuard>>()?;
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok());
+
+    guard.unauthorized(authorization).map(|challenge| {
+        let mut response = HttpResponse::Unauthorized();
+        if let Some(challenge) = challenge {
+            response.insert_header((header::WWW_AUTHENTICATE, challenge.into_owned()));
+        }
+        response.finish()
+    })
+}
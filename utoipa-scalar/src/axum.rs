@@ -1,8 +1,12 @@
 #![cfg(feature = "axum")]
 
-use axum::response::Html;
+use axum::extract::Request;
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{Html, IntoResponse, Response};
 use axum::{routing, Router};
 
+use crate::guard::SharedGuard;
 use crate::{Scalar, Spec};
 
 impl<S: Spec, R> From<Scalar<S>> for Router<R>
@@ -10,10 +14,39 @@ where
     R: Clone + Send + Sync + 'static,
 {
     fn from(value: Scalar<S>) -> Self {
+        if !value.enabled {
+            return Router::new();
+        }
+
         let html = value.to_html();
-        Router::<R>::new().route(
+        let router = Router::<R>::new().route(
             value.url.as_ref(),
             routing::get(move || async { Html(html) }),
+        );
+
+        if let Some(guard) = value.guard {
+            router.route_layer(middleware::from_fn(move |request: Request, next: Next| {
+                authorize(guard.clone(), request, next)
+            }))
+        } else {
+            router
+        }
+    }
+}
+
+async fn authorize(guard: SharedGuard, request: Request, next: Next) -> Response {
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok());
+
+    match guard.unauthorized(authorization) {
+        Some(Some(challenge)) => (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, challenge.into_owned())],
         )
+            .into_response(),
+        Some(None) => StatusCode::UNAUTHORIZED.into_response(),
+        None => next.run(request).await,
     }
 }
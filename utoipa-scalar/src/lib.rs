@@ -54,6 +54,15 @@
 //! };
 //! ```
 //!
+//! # Access control
+//!
+//! Scalar can be protected with a [`guard::Guard`] checking the `Authorization` header of the
+//! requests, e.g. with [`guard::BasicAuth`] or [`guard::BearerAuth`]. See [`Scalar::guard`].
+//!
+//! **Note!** Scalar is only served in debug builds by default to keep internal api documentation
+//! private. Serve it in release builds as well with [`Scalar::enabled`], preferably behind a
+//! guard.
+//!
 //! # Examples
 //!
 //! _**Serve [`Scalar`] via `actix-web` framework.**_
@@ -119,6 +128,8 @@ use serde::Serialize;
 use serde_json::Value;
 use utoipa::openapi::OpenApi;
 
+pub use utoipa::guard;
+
 mod actix;
 mod axum;
 mod rocket;
@@ -155,6 +166,10 @@ impl<S: Spec> Servable<S> for Scalar<S> {
             html: Cow::Borrowed(DEFAULT_HTML),
             url: url.into(),
             openapi,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            guard: None,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            enabled: cfg!(debug_assertions),
         }
     }
 }
@@ -173,6 +188,10 @@ pub struct Scalar<S: Spec> {
     url: Cow<'static, str>,
     html: Cow<'static, str>,
     openapi: S,
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    guard: Option<guard::SharedGuard>,
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    enabled: bool,
 }
 
 impl<S: Spec> Scalar<S> {
@@ -191,9 +210,62 @@ impl<S: Spec> Scalar<S> {
             html: Cow::Borrowed(DEFAULT_HTML),
             url: Cow::Borrowed("/"),
             openapi,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            guard: None,
+            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            enabled: cfg!(debug_assertions),
         }
     }
 
+    /// Add [`Guard`][guard::Guard] to protect Scalar from unauthorized access.
+    ///
+    /// Every request to Scalar is checked with the guard and unauthorized requests are
+    /// responded with `401 Unauthorized`. See [`guard`] module for the built-in guards.
+    ///
+    /// # Examples
+    ///
+    /// Require basic authentication to access the documentation.
+    /// ```rust
+    /// # use utoipa_scalar::{guard::BasicAuth, Scalar, Servable};
+    /// # use serde_json::json;
+    /// let scalar = Scalar::with_url("/scalar", json!({"openapi": "3.1.0"}))
+    ///     .guard(BasicAuth::new("admin", "secret"));
+    /// ```
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    )]
+    pub fn guard<G: guard::Guard>(mut self, guard: G) -> Self {
+        self.guard = Some(guard::SharedGuard::new(guard));
+
+        self
+    }
+
+    /// Define whether Scalar is served at all. By default it is served only in debug builds.
+    ///
+    /// When disabled no routes are registered and the documentation is not reachable.
+    ///
+    /// # Examples
+    ///
+    /// Serve Scalar in release builds as well.
+    /// ```rust
+    /// # use utoipa_scalar::{Scalar, Servable};
+    /// # use serde_json::json;
+    /// let scalar = Scalar::with_url("/scalar", json!({"openapi": "3.1.0"}))
+    ///     .enabled(true);
+    /// ```
+    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    )]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+
+        self
+    }
+
     /// Converts this [`Scalar`] instance to servable HTML file.
     ///
     /// This will replace _**`$spec`**_ variable placeholder with [`Spec`] of this instance
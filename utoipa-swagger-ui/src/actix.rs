@@ -1,29 +1,49 @@
 #![cfg(feature = "actix-web")]
 
 use actix_web::{
-    dev::HttpServiceFactory, guard::Get, web, web::Data, HttpResponse, Resource,
-    Responder as ActixResponder,
+    dev::HttpServiceFactory, guard::Get, http::header, web, web::Data, HttpRequest, HttpResponse,
+    Resource,
 };
 
-use crate::{ApiDoc, Config, SwaggerUi};
+use crate::{guard::SharedGuard, ApiDoc, Config, SwaggerUi};
 
 impl HttpServiceFactory for SwaggerUi {
     fn register(self, config: &mut actix_web::dev::AppService) {
+        if !self.enabled {
+            return;
+        }
+
+        let guard = self.guard;
         let mut urls = self
             .urls
             .into_iter()
             .map(|(url, openapi)| {
-                register_api_doc_url_resource(url.url.as_ref(), ApiDoc::Utoipa(openapi), config);
+                register_api_doc_url_resource(
+                    url.url.as_ref(),
+                    ApiDoc::Utoipa(openapi),
+                    guard.clone(),
+                    config,
+                );
                 url
             })
             .collect::<Vec<_>>();
         let external_api_docs = self.external_urls.into_iter().map(|(url, api_doc)| {
-            register_api_doc_url_resource(url.url.as_ref(), ApiDoc::Value(api_doc), config);
+            register_api_doc_url_resource(
+                url.url.as_ref(),
+                ApiDoc::Value(api_doc),
+                guard.clone(),
+                config,
+            );
             url
         });
         urls.extend(external_api_docs);
 
-        let swagger_resource = Resource::new(self.path.as_ref())
+        if let Some(base_path) = base_path(self.path.as_ref()) {
+            let redirect = web::redirect(base_path.to_string(), format!("{base_path}/"));
+            HttpServiceFactory::register(redirect, config);
+        }
+
+        let swagger_resource = with_guard(Resource::new(self.path.as_ref()), guard)
             .guard(Get())
             .app_data(Data::new(if let Some(config) = self.config {
                 if config.url.is_some() || !config.urls.is_empty() {
@@ -40,19 +60,71 @@ impl HttpServiceFactory for SwaggerUi {
     }
 }
 
-fn register_api_doc_url_resource(url: &str, api: ApiDoc, config: &mut actix_web::dev::AppService) {
-    async fn get_api_doc(api_doc: web::Data<ApiDoc>) -> impl ActixResponder {
+/// Get the base path of Swagger UI path with tail pattern e.g. `/swagger-ui/{_:.*}` -> `/swagger-ui`.
+fn base_path(path: &str) -> Option<&str> {
+    let (base_path, tail) = path.rsplit_once('/')?;
+
+    if !base_path.is_empty() && tail.starts_with('{') && tail.ends_with('}') {
+        Some(base_path)
+    } else {
+        None
+    }
+}
+
+fn register_api_doc_url_resource(
+    url: &str,
+    api: ApiDoc,
+    guard: Option<SharedGuard>,
+    config: &mut actix_web::dev::AppService,
+) {
+    async fn get_api_doc(request: HttpRequest, api_doc: web::Data<ApiDoc>) -> HttpResponse {
+        if let Some(unauthorized) = unauthorized(&request) {
+            return unauthorized;
+        }
+
         HttpResponse::Ok().json(api_doc.as_ref())
     }
 
-    let url_resource = Resource::new(url)
+    let url_resource = with_guard(Resource::new(url), guard)
         .guard(Get())
         .app_data(Data::new(api))
         .to(get_api_doc);
     HttpServiceFactory::register(url_resource, config);
 }
 
-async fn serve_swagger_ui(path: web::Path<String>, data: web::Data<Config<'_>>) -> HttpResponse {
+fn with_guard(resource: Resource, guard: Option<SharedGuard>) -> Resource {
+    match guard {
+        Some(guard) => resource.app_data(Data::new(guard)),
+        None => resource,
+    }
+}
+
+/// Get unauthorized response if resource has [`SharedGuard`] which does not authorize the request.
+fn unauthorized(request: &HttpRequest) -> Option<HttpResponse> {
+    let guard = request.app_data::<Data<SharedGuard>>()?;
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok());
+
+    guard.unauthorized(authorization).map(|challenge| {
+        let mut response = HttpResponse::Unauthorized();
+        if let Some(challenge) = challenge {
+            response.insert_header((header::WWW_AUTHENTICATE, challenge.into_owned()));
+        }
+        response.finish()
+    })
+}
+
+async fn serve_swagger_ui(
+    request: HttpRequest,
+    path: web::Path<String>,
+    data: web::Data<Config<'_>>,
+) -> HttpResponse {
+    if let Some(unauthorized) = unauthorized(&request) {
+        return unauthorized;
+    }
+
     match super::serve(&path.into_inner(), data.into_inner()) {
         Ok(swagger_file) => swagger_file
             .map(|file| {
@@ -64,3 +136,95 @@ async fn serve_swagger_ui(path: web::Path<String>, data: web::Data<Config<'_>>)
         Err(error) => HttpResponse::InternalServerError().body(error.to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        App,
+    };
+
+    use super::*;
+
+    #[test]
+    fn base_path_of_tail_path() {
+        assert_eq!(base_path("/swagger-ui/{_:.*}"), Some("/swagger-ui"));
+        assert_eq!(base_path("/api/docs/{tail:.*}"), Some("/api/docs"));
+        assert_eq!(base_path("/{_:.*}"), None);
+        assert_eq!(base_path("/swagger-ui/"), None);
+    }
+
+    #[actix_web::test]
+    async fn redirect_swagger_ui_base_path_to_trailing_slash() {
+        let app = init_service(App::new().service(SwaggerUi::new("/swagger-ui/{_:.*}"))).await;
+
+        let request = TestRequest::get().uri("/swagger-ui").to_request();
+        let response = call_service(&app, request).await;
+
+        assert!(response.status().is_redirection());
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/swagger-ui/"
+        );
+    }
+
+    #[actix_web::test]
+    async fn guard_unauthorized_requests() {
+        let app = init_service(
+            App::new().service(
+                SwaggerUi::new("/swagger-ui/{_:.*}")
+                    .url(
+                        "/api-docs/openapi.json",
+                        utoipa::openapi::OpenApi::new(
+                            utoipa::openapi::Info::new("api", "0.1.0"),
+                            utoipa::openapi::Paths::new(),
+                        ),
+                    )
+                    .guard(crate::guard::BasicAuth::new("admin", "secret")),
+            ),
+        )
+        .await;
+
+        for uri in ["/api-docs/openapi.json", "/swagger-ui/"] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = call_service(&app, request).await;
+
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert!(response.headers().contains_key(header::WWW_AUTHENTICATE));
+
+            let request = TestRequest::get()
+                .uri(uri)
+                .insert_header((header::AUTHORIZATION, "Basic YWRtaW46c2VjcmV0"))
+                .to_request();
+            let response = call_service(&app, request).await;
+
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[actix_web::test]
+    async fn disabled_swagger_ui_is_not_served() {
+        let app = init_service(
+            App::new().service(
+                SwaggerUi::new("/swagger-ui/{_:.*}")
+                    .url(
+                        "/api-docs/openapi.json",
+                        utoipa::openapi::OpenApi::new(
+                            utoipa::openapi::Info::new("api", "0.1.0"),
+                            utoipa::openapi::Paths::new(),
+                        ),
+                    )
+                    .enabled(false),
+            ),
+        )
+        .await;
+
+        for uri in ["/api-docs/openapi.json", "/swagger-ui/"] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = call_service(&app, request).await;
+
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+    }
+}
@@ -3,16 +3,24 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::Path, http::StatusCode, response::IntoResponse, routing, Extension, Json, Router,
+    extract::{Path, Request},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing, Extension, Json, Router,
 };
 
-use crate::{ApiDoc, Config, SwaggerUi, Url};
+use crate::{guard::SharedGuard, ApiDoc, Config, SwaggerUi, Url};
 
 impl<S> From<SwaggerUi> for Router<S>
 where
     S: Clone + Send + Sync + 'static,
 {
     fn from(swagger_ui: SwaggerUi) -> Self {
+        if !swagger_ui.enabled {
+            return Router::new();
+        }
+
         let urls_capacity = swagger_ui.urls.len();
         let external_urls_capacity = swagger_ui.external_urls.len();
 
@@ -46,13 +54,38 @@ where
         let path: &str = swagger_ui.path.as_ref();
         let slash_path = format!("{}/", path);
 
-        router
+        let router = router
             .route(
                 path,
                 routing::get(|| async move { axum::response::Redirect::to(&slash_path) }),
             )
             .route(&format!("{}/", path), handler.clone())
-            .route(&format!("{}/*rest", path), handler)
+            .route(&format!("{}/*rest", path), handler);
+
+        if let Some(guard) = swagger_ui.guard {
+            router.route_layer(middleware::from_fn(move |request: Request, next: Next| {
+                authorize(guard.clone(), request, next)
+            }))
+        } else {
+            router
+        }
+    }
+}
+
+async fn authorize(guard: SharedGuard, request: Request, next: Next) -> Response {
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok());
+
+    match guard.unauthorized(authorization) {
+        Some(Some(challenge)) => (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, challenge.into_owned())],
+        )
+            .into_response(),
+        Some(None) => StatusCode::UNAUTHORIZED.into_response(),
+        None => next.run(request).await,
     }
 }
 
@@ -10,6 +10,9 @@
 //! * **actix-web** `version >= 4`
 //! * **rocket** `version >=0.5`
 //! * **axum** `version >=0.7`
+//! * **warp** `version >=0.3`
+//! * **poem** `version >=3`
+//! * **salvo** `version >=0.68`
 //!
 //! Serving Swagger UI is framework independent thus this crate also supports serving the Swagger UI with
 //! other frameworks as well. With other frameworks there is bit more manual implementation to be done. See
@@ -25,6 +28,12 @@
 //!   and api doc without a hassle.
 //! * **axum** Enables `axum` integration with pre-configured Router serving Swagger UI and OpenAPI specs
 //!   hassle free.
+//! * **warp** Enables `warp` integration with pre-configured Filter serving Swagger UI and OpenAPI specs
+//!   hassle free.
+//! * **poem** Enables `poem` integration with pre-configured Route serving Swagger UI and OpenAPI specs
+//!   hassle free.
+//! * **salvo** Enables `salvo` integration with pre-configured Router serving Swagger UI and OpenAPI specs
+//!   hassle free.
 //! * **debug-embed** Enables `debug-embed` feature on `rust_embed` crate to allow embedding files in debug
 //!   builds as well.
 //!
@@ -59,6 +68,16 @@
 //!   * absolute path to a folder containing files to overwrite the default swagger-ui files
 //!   * typically you might want to overwrite `index.html`
 //!
+//! # Access control
+//!
+//! Swagger UI and the api docs can be protected with a [`guard::Guard`] checking the
+//! `Authorization` header of the requests, e.g. with [`guard::BasicAuth`] or
+//! [`guard::BearerAuth`].
+//!
+//! **Note!** Swagger UI and the api docs are only served in debug builds by default to keep
+//! internal api documentation private. Serve them in release builds as well with
+//! [`SwaggerUi::enabled`], preferably behind a guard.
+//!
 //! # Examples
 //!
 //! Serve Swagger UI with api doc via **`actix-web`**. See full example from
@@ -123,16 +142,86 @@
 //!         .url("/api-docs/openapi.json", ApiDoc::openapi()));
 //!# }
 //! ```
+//!
+//! Setup Filter to serve Swagger UI with **`warp`** framework. See full implementation of how to serve
+//! Swagger UI with warp from [examples](https://github.com/juhaku/utoipa/tree/master/examples/todo-warp).
+//!```no_run
+//! # use utoipa_swagger_ui::SwaggerUi;
+//! # use utoipa::OpenApi;
+//! # use warp::{filters::BoxedFilter, Filter, Reply};
+//!# #[derive(OpenApi)]
+//!# #[openapi()]
+//!# struct ApiDoc;
+//!#
+//!# async fn inner() {
+//! let swagger_ui: BoxedFilter<(Box<dyn Reply>,)> = SwaggerUi::new("/swagger-ui")
+//!     .url("/api-docs/openapi.json", ApiDoc::openapi())
+//!     .into();
+//!
+//! warp::serve(swagger_ui).run(([127, 0, 0, 1], 8080)).await;
+//!# }
+//! ```
+//!
+//! Setup Route to serve Swagger UI with **`poem`** framework.
+//!```no_run
+//! # use poem::{handler, get, Route};
+//! # use utoipa_swagger_ui::SwaggerUi;
+//! # use utoipa::OpenApi;
+//!# #[derive(OpenApi)]
+//!# #[openapi()]
+//!# struct ApiDoc;
+//!#
+//!# #[handler]
+//!# fn hello() -> &'static str {
+//!#     "hello"
+//!# }
+//!#
+//!# fn inner() {
+//! let app = Route::from(
+//!     SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()),
+//! )
+//! .at("/hello", get(hello));
+//!# }
+//! ```
+//!
+//! Setup Router to serve Swagger UI with **`salvo`** framework.
+//!```no_run
+//! # use salvo::Router;
+//! # use utoipa_swagger_ui::SwaggerUi;
+//! # use utoipa::OpenApi;
+//!# #[derive(OpenApi)]
+//!# #[openapi()]
+//!# struct ApiDoc;
+//!#
+//!# fn inner() {
+//! let router = Router::new().push(
+//!     SwaggerUi::new("/swagger-ui")
+//!         .url("/api-docs/openapi.json", ApiDoc::openapi())
+//!         .into(),
+//! );
+//!# }
+//! ```
 use std::{borrow::Cow, error::Error, mem, sync::Arc};
 
 mod actix;
 mod axum;
 pub mod oauth;
+mod poem;
 mod rocket;
+mod salvo;
+mod warp;
 
 use rust_embed::RustEmbed;
 use serde::Serialize;
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+pub use utoipa::guard;
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "warp",
+    feature = "poem",
+    feature = "salvo"
+))]
 use utoipa::openapi::OpenApi;
 
 include!(concat!(env!("OUT_DIR"), "/embed.rs"));
@@ -169,22 +258,52 @@ include!(concat!(env!("OUT_DIR"), "/embed.rs"));
 #[non_exhaustive]
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "warp",
+    feature = "poem",
+    feature = "salvo"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "warp",
+        feature = "poem",
+        feature = "salvo"
+    )))
 )]
 pub struct SwaggerUi {
     path: Cow<'static, str>,
     urls: Vec<(Url<'static>, OpenApi)>,
     config: Option<Config<'static>>,
     external_urls: Vec<(Url<'static>, serde_json::Value)>,
+    guard: Option<guard::SharedGuard>,
+    enabled: bool,
 }
 
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "warp",
+    feature = "poem",
+    feature = "salvo"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "warp",
+        feature = "poem",
+        feature = "salvo"
+    )))
 )]
 impl SwaggerUi {
     /// Create a new [`SwaggerUi`] for given path.
@@ -192,6 +311,10 @@ impl SwaggerUi {
     /// Path argument will expose the Swagger UI to the user and should be something that
     /// the underlying application framework / library supports.
     ///
+    /// With **`actix-web`**, **`axum`**, **`warp`**, **`poem`** and **`salvo`** the base path
+    /// without trailing slash e.g. `/swagger-ui` is redirected to `/swagger-ui/` so relative
+    /// Swagger UI resources are resolved correctly.
+    ///
     /// # Examples
     ///
     /// Exposes Swagger UI using path `/swagger-ui` using actix-web supported syntax.
@@ -206,6 +329,8 @@ impl SwaggerUi {
             urls: Vec::new(),
             config: None,
             external_urls: Vec::new(),
+            guard: None,
+            enabled: cfg!(debug_assertions),
         }
     }
 
@@ -402,6 +527,56 @@ impl SwaggerUi {
 
         self
     }
+
+    /// Add [`Guard`][guard::Guard] to protect Swagger UI and the api doc urls from unauthorized
+    /// access.
+    ///
+    /// Every request to the Swagger UI or the api docs is checked with the guard and
+    /// unauthorized requests are responded with `401 Unauthorized`. See [`guard`] module for
+    /// the built-in guards.
+    ///
+    /// # Examples
+    ///
+    /// Require basic authentication to access the documentation.
+    /// ```rust
+    /// # use utoipa_swagger_ui::{guard::BasicAuth, SwaggerUi};
+    /// # use utoipa::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// let swagger = SwaggerUi::new("/swagger-ui/{_:.*}")
+    ///     .url("/api-docs/openapi.json", ApiDoc::openapi())
+    ///     .guard(BasicAuth::new("admin", "secret"));
+    /// ```
+    pub fn guard<G: guard::Guard>(mut self, guard: G) -> Self {
+        self.guard = Some(guard::SharedGuard::new(guard));
+
+        self
+    }
+
+    /// Define whether Swagger UI and the api doc urls are served at all. By default they are
+    /// served only in debug builds.
+    ///
+    /// When disabled no routes are registered and the documentation is not reachable.
+    ///
+    /// # Examples
+    ///
+    /// Serve Swagger UI in release builds as well.
+    /// ```rust
+    /// # use utoipa_swagger_ui::SwaggerUi;
+    /// # use utoipa::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// let swagger = SwaggerUi::new("/swagger-ui/{_:.*}")
+    ///     .url("/api-docs/openapi.json", ApiDoc::openapi())
+    ///     .enabled(true);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+
+        self
+    }
 }
 
 /// Rust type for Swagger UI url configuration object.
@@ -428,10 +603,17 @@ impl<'a> Url<'a> {
     /// # use utoipa_swagger_ui::Url;
     /// let url = Url::new("My Api", "/api-docs/openapi.json");
     /// ```
-    pub fn new(name: &'a str, url: &'a str) -> Self {
+    ///
+    /// Name and url can also be owned e.g. when they are built at runtime.
+    /// ```rust
+    /// # use utoipa_swagger_ui::Url;
+    /// let version = 2;
+    /// let url = Url::new(format!("My Api v{version}"), format!("/api-docs/v{version}/openapi.json"));
+    /// ```
+    pub fn new<N: Into<Cow<'a, str>>, U: Into<Cow<'a, str>>>(name: N, url: U) -> Self {
         Self {
-            name: Cow::Borrowed(name),
-            url: Cow::Borrowed(url),
+            name: name.into(),
+            url: url.into(),
             ..Default::default()
         }
     }
@@ -453,10 +635,14 @@ impl<'a> Url<'a> {
     /// # use utoipa_swagger_ui::Url;
     /// let url = Url::with_primary("My Api", "/api-docs/openapi.json", true);
     /// ```
-    pub fn with_primary(name: &'a str, url: &'a str, primary: bool) -> Self {
+    pub fn with_primary<N: Into<Cow<'a, str>>, U: Into<Cow<'a, str>>>(
+        name: N,
+        url: U,
+        primary: bool,
+    ) -> Self {
         Self {
-            name: Cow::Borrowed(name),
-            url: Cow::Borrowed(url),
+            name: name.into(),
+            url: url.into(),
             primary,
         }
     }
@@ -614,6 +800,10 @@ pub struct Config<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     request_snippets_enabled: Option<bool>,
 
+    /// Configuration of the request snippets section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_snippets: Option<RequestSnippets>,
+
     /// Oauth redirect url.
     #[serde(skip_serializing_if = "Option::is_none")]
     oauth2_redirect_url: Option<String>,
@@ -756,10 +946,24 @@ impl<'a> Config<'a> {
     /// is called on.
     ///
     /// Current config will be returned with configured default values.
-    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "warp",
+        feature = "poem",
+        feature = "salvo"
+    ))]
     #[cfg_attr(
         doc_cfg,
-        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "warp",
+            feature = "poem",
+            feature = "salvo"
+        )))
     )]
     fn configure_defaults<I: IntoIterator<Item = U>, U: Into<Url<'a>>>(mut self, urls: I) -> Self {
         let Config {
@@ -1091,6 +1295,28 @@ impl<'a> Config<'a> {
         self
     }
 
+    /// Set [`RequestSnippets`] configuration for the request snippets section. Request snippets
+    /// must be enabled with [`Config::request_snippets_enabled`] for this to take effect.
+    ///
+    /// # Examples
+    ///
+    /// Show only curl bash snippets expanded by default.
+    /// ```rust
+    /// # use utoipa_swagger_ui::{Config, RequestSnippets, RequestSnippetLanguage};
+    /// let config = Config::new(["/api-docs/openapi.json"])
+    ///     .request_snippets_enabled(true)
+    ///     .request_snippets(
+    ///         RequestSnippets::new()
+    ///             .default_expanded(true)
+    ///             .languages([RequestSnippetLanguage::CurlBash]),
+    ///     );
+    /// ```
+    pub fn request_snippets(mut self, request_snippets: RequestSnippets) -> Self {
+        self.request_snippets = Some(request_snippets);
+
+        self
+    }
+
     /// Add oauth redirect url.
     ///
     /// # Examples
@@ -1249,6 +1475,7 @@ impl Default for Config<'_> {
             show_common_extensions: Default::default(),
             try_it_out_enabled: Default::default(),
             request_snippets_enabled: Default::default(),
+            request_snippets: Default::default(),
             oauth2_redirect_url: Default::default(),
             show_mutated_request: Default::default(),
             supported_submit_methods: Default::default(),
@@ -1369,6 +1596,61 @@ pub fn serve<'a>(
     }
 }
 
+/// Configuration of the request snippets section of Swagger UI.
+///
+/// See [`Config::request_snippets`] for more details.
+#[non_exhaustive]
+#[derive(Serialize, Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct RequestSnippets {
+    /// Defines whether request snippets section is expanded by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_expanded: Option<bool>,
+
+    /// [`RequestSnippetLanguage`]s shown in the request snippets section. By default all
+    /// languages are shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    languages: Option<Vec<RequestSnippetLanguage>>,
+}
+
+impl RequestSnippets {
+    /// Construct a new [`RequestSnippets`] with Swagger UI defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define whether request snippets section is expanded by default.
+    pub fn default_expanded(mut self, default_expanded: bool) -> Self {
+        self.default_expanded = Some(default_expanded);
+
+        self
+    }
+
+    /// Define [`RequestSnippetLanguage`]s shown in the request snippets section.
+    pub fn languages<I: IntoIterator<Item = RequestSnippetLanguage>>(
+        mut self,
+        languages: I,
+    ) -> Self {
+        self.languages = Some(languages.into_iter().collect());
+
+        self
+    }
+}
+
+/// Languages of the request snippets supported by Swagger UI.
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "snake_case")]
+pub enum RequestSnippetLanguage {
+    /// cURL snippet for bash.
+    CurlBash,
+    /// cURL snippet for PowerShell.
+    CurlPowershell,
+    /// cURL snippet for Windows CMD.
+    CurlCmd,
+}
+
 #[inline]
 fn format_config(config: &Config, file: String) -> Result<String, Box<dyn Error>> {
     let config_json = match serde_json::to_string_pretty(&config) {
@@ -1381,7 +1663,14 @@ fn format_config(config: &Config, file: String) -> Result<String, Box<dyn Error>
 }
 
 /// Is used to provide general way to deliver multiple types of OpenAPI docs via `utoipa-swagger-ui`.
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "warp",
+    feature = "poem",
+    feature = "salvo"
+))]
 #[derive(Clone)]
 enum ApiDoc {
     Utoipa(utoipa::openapi::OpenApi),
@@ -1389,7 +1678,14 @@ enum ApiDoc {
 }
 
 // Delegate serde's `Serialize` to the variant itself.
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "warp",
+    feature = "poem",
+    feature = "salvo"
+))]
 impl Serialize for ApiDoc {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1603,6 +1899,47 @@ window.ui = SwaggerUIBundle({
         assert_diff_equal(EXPECTED, &formatted_config);
     }
 
+    #[test]
+    fn format_swagger_config_multiple_urls_with_owned_names() {
+        let urls = ["Public API v1", "Admin API"].map(|name| {
+            Url::new(
+                name.to_string(),
+                format!("/api-docs/{}.json", name.to_lowercase().replace(' ', "-")),
+            )
+        });
+        let formatted_config =
+            match format_config(&Config::new(urls), String::from(TEST_INITIAL_CONFIG)) {
+                Ok(file) => file,
+                Err(error) => panic!("{error}"),
+            };
+
+        const EXPECTED: &str = r###"
+window.ui = SwaggerUIBundle({
+    "dom_id": "#swagger-ui",
+  "urls": [
+    {
+      "name": "Public API v1",
+      "url": "/api-docs/public-api-v1.json"
+    },
+    {
+      "name": "Admin API",
+      "url": "/api-docs/admin-api.json"
+    }
+  ],
+  "deepLinking": true,
+  "layout": "StandaloneLayout",
+  presets: [
+    SwaggerUIBundle.presets.apis,
+    SwaggerUIStandalonePreset
+  ],
+  plugins: [
+    SwaggerUIBundle.plugins.DownloadUrl
+  ],
+});"###;
+
+        assert_diff_equal(EXPECTED, &formatted_config);
+    }
+
     #[test]
     fn format_swagger_config_with_multiple_fields() {
         let formatted_config = match format_config(
@@ -1673,4 +2010,45 @@ window.ui = SwaggerUIBundle({
 
         assert_diff_equal(EXPECTED, &formatted_config);
     }
+
+    #[test]
+    fn format_swagger_config_with_request_snippets() {
+        let formatted_config = match format_config(
+            &Config::new(["/api-docs/openapi1.json"])
+                .request_snippets_enabled(true)
+                .request_snippets(RequestSnippets::new().default_expanded(false).languages([
+                    RequestSnippetLanguage::CurlBash,
+                    RequestSnippetLanguage::CurlPowershell,
+                ])),
+            String::from(TEST_INITIAL_CONFIG),
+        ) {
+            Ok(file) => file,
+            Err(error) => panic!("{error}"),
+        };
+
+        const EXPECTED: &str = r###"
+window.ui = SwaggerUIBundle({
+    "dom_id": "#swagger-ui",
+  "url": "/api-docs/openapi1.json",
+  "deepLinking": true,
+  "requestSnippetsEnabled": true,
+  "requestSnippets": {
+    "defaultExpanded": false,
+    "languages": [
+      "curl_bash",
+      "curl_powershell"
+    ]
+  },
+  "layout": "StandaloneLayout",
+  presets: [
+    SwaggerUIBundle.presets.apis,
+    SwaggerUIStandalonePreset
+  ],
+  plugins: [
+    SwaggerUIBundle.plugins.DownloadUrl
+  ],
+});"###;
+
+        assert_diff_equal(EXPECTED, &formatted_config);
+    }
 }
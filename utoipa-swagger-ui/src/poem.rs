@@ -0,0 +1,177 @@
+#![cfg(feature = "poem")]
+
+use std::sync::Arc;
+
+use poem::{
+    http::{header, StatusCode},
+    web::{Json, Redirect},
+    Endpoint, IntoResponse, Request, Response, Route,
+};
+
+use crate::{guard::SharedGuard, ApiDoc, Config, SwaggerUi, Url};
+
+impl From<SwaggerUi> for Route {
+    fn from(swagger_ui: SwaggerUi) -> Self {
+        if !swagger_ui.enabled {
+            return Route::new();
+        }
+
+        let api_docs = swagger_ui
+            .urls
+            .into_iter()
+            .map(|(url, openapi)| (url, ApiDoc::Utoipa(openapi)))
+            .chain(
+                swagger_ui
+                    .external_urls
+                    .into_iter()
+                    .map(|(url, api_doc)| (url, ApiDoc::Value(api_doc))),
+            )
+            .collect::<Vec<_>>();
+        let urls = api_docs
+            .iter()
+            .map(|(url, _)| url.clone())
+            .collect::<Vec<Url>>();
+
+        let config = if let Some(config) = swagger_ui.config {
+            if config.url.is_some() || !config.urls.is_empty() {
+                config
+            } else {
+                config.configure_defaults(urls)
+            }
+        } else {
+            Config::new(urls)
+        };
+
+        let base_path = swagger_ui.path.trim_end_matches('/').to_string();
+        let endpoint = PoemSwaggerUi(Arc::new(PoemSwaggerUiState {
+            base_path: base_path.clone(),
+            api_docs,
+            config: Arc::new(config),
+            guard: swagger_ui.guard,
+        }));
+
+        let route = endpoint
+            .0
+            .api_docs
+            .iter()
+            .fold(Route::new(), |route, (url, _)| {
+                route.at(url.url.as_ref(), endpoint.clone())
+            });
+
+        route
+            .at(&base_path, endpoint.clone())
+            .at(format!("{base_path}/"), endpoint.clone())
+            .at(format!("{base_path}/*rest"), endpoint)
+    }
+}
+
+/// Poem [`Endpoint`] serving the [`SwaggerUi`] and the api docs.
+#[derive(Clone)]
+struct PoemSwaggerUi(Arc<PoemSwaggerUiState>);
+
+/// State of the [`SwaggerUi`] served with poem route.
+struct PoemSwaggerUiState {
+    base_path: String,
+    api_docs: Vec<(Url<'static>, ApiDoc)>,
+    config: Arc<Config<'static>>,
+    guard: Option<SharedGuard>,
+}
+
+impl Endpoint for PoemSwaggerUi {
+    type Output = Response;
+
+    async fn call(&self, request: Request) -> poem::Result<Self::Output> {
+        let state = &self.0;
+        let authorization = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|authorization| authorization.to_str().ok());
+
+        if let Some(challenge) = state
+            .guard
+            .as_ref()
+            .and_then(|guard| guard.unauthorized(authorization))
+        {
+            let mut response = Response::builder().status(StatusCode::UNAUTHORIZED);
+            if let Some(challenge) = challenge {
+                response = response.header(header::WWW_AUTHENTICATE, challenge.into_owned());
+            }
+            return Ok(response.finish());
+        }
+
+        let path = request.uri().path();
+        if let Some((_, api_doc)) = state.api_docs.iter().find(|(url, _)| url.url == path) {
+            return Ok(Json(api_doc.clone()).into_response());
+        }
+
+        if path == state.base_path {
+            return Ok(Redirect::temporary(format!("{}/", state.base_path)).into_response());
+        }
+
+        let tail = path
+            .strip_prefix(&*state.base_path)
+            .and_then(|tail| tail.strip_prefix('/'))
+            .unwrap_or_default();
+
+        match super::serve(tail, state.config.clone()) {
+            Ok(file) => Ok(file
+                .map(|file| {
+                    Response::builder()
+                        .content_type(file.content_type)
+                        .body(file.bytes.into_owned())
+                })
+                .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response())),
+            Err(error) => {
+                Ok((StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use poem::test::TestClient;
+    use utoipa::openapi::{Info, OpenApi, Paths};
+
+    use super::*;
+
+    fn swagger_ui() -> Route {
+        SwaggerUi::new("/swagger-ui")
+            .url(
+                "/api-docs/openapi.json",
+                OpenApi::new(Info::new("api", "0.1.0"), Paths::new()),
+            )
+            .into()
+    }
+
+    #[tokio::test]
+    async fn serve_api_doc() {
+        let client = TestClient::new(swagger_ui());
+
+        let response = client.get("/api-docs/openapi.json").send().await;
+        response.assert_status_is_ok();
+        let api_doc: serde_json::Value = response.0.into_body().into_json().await.unwrap();
+        assert_eq!(api_doc["info"]["title"], "api");
+    }
+
+    #[tokio::test]
+    async fn redirect_swagger_ui_base_path_to_trailing_slash() {
+        let client = TestClient::new(swagger_ui());
+
+        let response = client.get("/swagger-ui").send().await;
+        response.assert_status(StatusCode::TEMPORARY_REDIRECT);
+        response.assert_header(header::LOCATION, "/swagger-ui/");
+    }
+
+    #[tokio::test]
+    async fn serve_swagger_ui_files() {
+        let client = TestClient::new(swagger_ui());
+
+        let response = client.get("/swagger-ui/").send().await;
+        response.assert_status_is_ok();
+        response.assert_content_type("text/html");
+
+        let response = client.get("/swagger-ui/not-found.js").send().await;
+        response.assert_status(StatusCode::NOT_FOUND);
+    }
+}
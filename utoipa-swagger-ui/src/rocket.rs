@@ -10,10 +10,14 @@ use rocket::{
     Data as RocketData, Request, Response, Route,
 };
 
-use crate::{ApiDoc, Config, SwaggerFile, SwaggerUi};
+use crate::{guard::SharedGuard, ApiDoc, Config, SwaggerFile, SwaggerUi};
 
 impl From<SwaggerUi> for Vec<Route> {
     fn from(swagger_ui: SwaggerUi) -> Self {
+        if !swagger_ui.enabled {
+            return Vec::new();
+        }
+
         let mut routes =
             Vec::<Route>::with_capacity(swagger_ui.urls.len() + 1 + swagger_ui.external_urls.len());
         let mut api_docs =
@@ -33,7 +37,7 @@ impl From<SwaggerUi> for Vec<Route> {
                 api_docs.push(Route::new(
                     rocket::http::Method::Get,
                     &url.url,
-                    ServeApiDoc(openapi),
+                    ServeApiDoc(openapi, swagger_ui.guard.clone()),
                 ));
                 url
             });
@@ -52,6 +56,7 @@ impl From<SwaggerUi> for Vec<Route> {
                 } else {
                     Config::new(urls)
                 }),
+                swagger_ui.guard.clone(),
             ),
         ));
         routes.extend(api_docs);
@@ -61,21 +66,29 @@ impl From<SwaggerUi> for Vec<Route> {
 }
 
 #[derive(Clone)]
-struct ServeApiDoc(ApiDoc);
+struct ServeApiDoc(ApiDoc, Option<SharedGuard>);
 
 #[rocket::async_trait]
 impl Handler for ServeApiDoc {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: RocketData<'r>) -> Outcome<'r> {
+        if let Some(unauthorized) = Unauthorized::from_request(self.1.as_ref(), request) {
+            return Outcome::from(request, unauthorized);
+        }
+
         Outcome::from(request, Json(self.0.clone()))
     }
 }
 
 #[derive(Clone)]
-struct ServeSwagger(Cow<'static, str>, Arc<Config<'static>>);
+struct ServeSwagger(Cow<'static, str>, Arc<Config<'static>>, Option<SharedGuard>);
 
 #[rocket::async_trait]
 impl Handler for ServeSwagger {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: RocketData<'r>) -> Outcome<'r> {
+        if let Some(unauthorized) = Unauthorized::from_request(self.2.as_ref(), request) {
+            return Outcome::from(request, unauthorized);
+        }
+
         let mut base_path = self.0.as_ref();
         if let Some(index) = self.0.find('<') {
             base_path = &base_path[..index];
@@ -117,3 +130,26 @@ impl<'r, 'a: 'r> RocketResponder<'r, 'a> for RedirectResponder {
             .ok()
     }
 }
+
+/// Unauthorized response with optional `WWW-Authenticate` challenge.
+struct Unauthorized(Option<Cow<'static, str>>);
+
+impl Unauthorized {
+    fn from_request(guard: Option<&SharedGuard>, request: &Request<'_>) -> Option<Self> {
+        guard?
+            .unauthorized(request.headers().get_one("Authorization"))
+            .map(Self)
+    }
+}
+
+impl<'r, 'a: 'r> RocketResponder<'r, 'a> for Unauthorized {
+    fn respond_to(self, _request: &'r Request<'_>) -> rocket::response::Result<'a> {
+        let mut response = Response::build();
+        response.status(Status::Unauthorized);
+        if let Some(challenge) = self.0 {
+            response.raw_header("WWW-Authenticate", challenge);
+        }
+
+        response.ok()
+    }
+}
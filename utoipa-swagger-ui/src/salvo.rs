@@ -0,0 +1,200 @@
+#![cfg(feature = "salvo")]
+
+use std::sync::Arc;
+
+use salvo::{
+    async_trait,
+    http::{header, StatusCode},
+    writing::{Json, Redirect},
+    Depot, FlowCtrl, Handler, Request, Response, Router,
+};
+
+use crate::{guard::SharedGuard, ApiDoc, Config, SwaggerUi, Url};
+
+impl From<SwaggerUi> for Router {
+    fn from(swagger_ui: SwaggerUi) -> Self {
+        if !swagger_ui.enabled {
+            return Router::new();
+        }
+
+        let api_docs = swagger_ui
+            .urls
+            .into_iter()
+            .map(|(url, openapi)| (url, ApiDoc::Utoipa(openapi)))
+            .chain(
+                swagger_ui
+                    .external_urls
+                    .into_iter()
+                    .map(|(url, api_doc)| (url, ApiDoc::Value(api_doc))),
+            )
+            .collect::<Vec<_>>();
+        let urls = api_docs
+            .iter()
+            .map(|(url, _)| url.clone())
+            .collect::<Vec<Url>>();
+
+        let config = if let Some(config) = swagger_ui.config {
+            if config.url.is_some() || !config.urls.is_empty() {
+                config
+            } else {
+                config.configure_defaults(urls)
+            }
+        } else {
+            Config::new(urls)
+        };
+
+        let base_path = swagger_ui.path.trim_end_matches('/').to_string();
+        let handler = SalvoSwaggerUi(Arc::new(SalvoSwaggerUiState {
+            base_path: base_path.clone(),
+            api_docs,
+            config: Arc::new(config),
+            guard: swagger_ui.guard,
+        }));
+
+        let router = handler
+            .0
+            .api_docs
+            .iter()
+            .fold(Router::new(), |router, (url, _)| {
+                router.push(Router::with_path(url.url.as_ref()).get(handler.clone()))
+            });
+
+        router
+            .push(Router::with_path(&base_path).get(handler.clone()))
+            .push(Router::with_path(format!("{base_path}/<**rest>")).get(handler))
+    }
+}
+
+/// Salvo [`Handler`] serving the [`SwaggerUi`] and the api docs.
+#[derive(Clone)]
+struct SalvoSwaggerUi(Arc<SalvoSwaggerUiState>);
+
+/// State of the [`SwaggerUi`] served with salvo router.
+struct SalvoSwaggerUiState {
+    base_path: String,
+    api_docs: Vec<(Url<'static>, ApiDoc)>,
+    config: Arc<Config<'static>>,
+    guard: Option<SharedGuard>,
+}
+
+#[async_trait]
+impl Handler for SalvoSwaggerUi {
+    async fn handle(
+        &self,
+        request: &mut Request,
+        _: &mut Depot,
+        response: &mut Response,
+        _: &mut FlowCtrl,
+    ) {
+        let state = &self.0;
+        let authorization = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|authorization| authorization.to_str().ok());
+
+        if let Some(challenge) = state
+            .guard
+            .as_ref()
+            .and_then(|guard| guard.unauthorized(authorization))
+        {
+            response.status_code(StatusCode::UNAUTHORIZED);
+            if let Some(challenge) = challenge {
+                let _ = response.add_header(header::WWW_AUTHENTICATE, challenge.into_owned(), true);
+            }
+            return;
+        }
+
+        let path = request.uri().path();
+        if let Some((_, api_doc)) = state.api_docs.iter().find(|(url, _)| url.url == path) {
+            response.render(Json(api_doc.clone()));
+            return;
+        }
+
+        if path == state.base_path {
+            response.render(Redirect::found(format!("{}/", state.base_path)));
+            return;
+        }
+
+        let tail = path
+            .strip_prefix(&*state.base_path)
+            .and_then(|tail| tail.strip_prefix('/'))
+            .unwrap_or_default();
+
+        match super::serve(tail, state.config.clone()) {
+            Ok(Some(file)) => {
+                let _ = response.add_header(header::CONTENT_TYPE, file.content_type, true);
+                let _ = response.write_body(file.bytes.into_owned());
+            }
+            Ok(None) => {
+                response.status_code(StatusCode::NOT_FOUND);
+            }
+            Err(error) => {
+                response.status_code(StatusCode::INTERNAL_SERVER_ERROR);
+                response.render(error.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::test::{ResponseExt, TestClient};
+    use salvo::Service;
+    use utoipa::openapi::{Info, OpenApi, Paths};
+
+    use super::*;
+
+    fn swagger_ui() -> Service {
+        let router: Router = SwaggerUi::new("/swagger-ui")
+            .url(
+                "/api-docs/openapi.json",
+                OpenApi::new(Info::new("api", "0.1.0"), Paths::new()),
+            )
+            .into();
+
+        Service::new(router)
+    }
+
+    #[tokio::test]
+    async fn serve_api_doc() {
+        let mut response = TestClient::get("http://127.0.0.1:5800/api-docs/openapi.json")
+            .send(&swagger_ui())
+            .await;
+
+        assert_eq!(response.status_code, Some(StatusCode::OK));
+        let api_doc: serde_json::Value = response.take_json().await.unwrap();
+        assert_eq!(api_doc["info"]["title"], "api");
+    }
+
+    #[tokio::test]
+    async fn redirect_swagger_ui_base_path_to_trailing_slash() {
+        let response = TestClient::get("http://127.0.0.1:5800/swagger-ui")
+            .send(&swagger_ui())
+            .await;
+
+        assert_eq!(response.status_code, Some(StatusCode::FOUND));
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/swagger-ui/"
+        );
+    }
+
+    #[tokio::test]
+    async fn serve_swagger_ui_files() {
+        let service = swagger_ui();
+
+        let response = TestClient::get("http://127.0.0.1:5800/swagger-ui/")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code, Some(StatusCode::OK));
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/html"
+        );
+
+        let response = TestClient::get("http://127.0.0.1:5800/swagger-ui/not-found.js")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code, Some(StatusCode::NOT_FOUND));
+    }
+}
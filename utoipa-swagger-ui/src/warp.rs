@@ -0,0 +1,254 @@
+#![cfg(feature = "warp")]
+
+use std::sync::Arc;
+
+use warp::{
+    filters::BoxedFilter,
+    http::{header, Response, StatusCode, Uri},
+    path::FullPath,
+    Filter, Rejection, Reply,
+};
+
+use crate::{guard::SharedGuard, ApiDoc, Config, SwaggerUi, Url};
+
+impl From<SwaggerUi> for BoxedFilter<(Box<dyn Reply>,)> {
+    fn from(swagger_ui: SwaggerUi) -> Self {
+        if !swagger_ui.enabled {
+            return warp::any()
+                .and_then(|| async { Err::<Box<dyn Reply>, _>(warp::reject::not_found()) })
+                .boxed();
+        }
+
+        let api_docs = swagger_ui
+            .urls
+            .into_iter()
+            .map(|(url, openapi)| (url, ApiDoc::Utoipa(openapi)))
+            .chain(
+                swagger_ui
+                    .external_urls
+                    .into_iter()
+                    .map(|(url, api_doc)| (url, ApiDoc::Value(api_doc))),
+            )
+            .collect::<Vec<_>>();
+        let urls = api_docs
+            .iter()
+            .map(|(url, _)| url.clone())
+            .collect::<Vec<Url>>();
+
+        let config = if let Some(config) = swagger_ui.config {
+            if config.url.is_some() || !config.urls.is_empty() {
+                config
+            } else {
+                config.configure_defaults(urls)
+            }
+        } else {
+            Config::new(urls)
+        };
+
+        let guard = swagger_ui.guard;
+        let base_path = swagger_ui.path.trim_end_matches('/').to_string();
+        let redirect = Uri::try_from(format!("{base_path}/"))
+            .expect("Swagger UI path must be a valid uri path");
+        let swagger_ui = Arc::new(WarpSwaggerUi {
+            base_path,
+            redirect,
+            api_docs,
+            config: Arc::new(config),
+            guard,
+        });
+
+        warp::get()
+            .and(warp::path::full())
+            .and(warp::header::optional::<String>("authorization"))
+            .and_then(move |full_path: FullPath, authorization: Option<String>| {
+                let swagger_ui = swagger_ui.clone();
+                async move { swagger_ui.reply(full_path.as_str(), authorization.as_deref()) }
+            })
+            .boxed()
+    }
+}
+
+/// State of the [`SwaggerUi`] served with warp filter.
+struct WarpSwaggerUi {
+    base_path: String,
+    redirect: Uri,
+    api_docs: Vec<(Url<'static>, ApiDoc)>,
+    config: Arc<Config<'static>>,
+    guard: Option<SharedGuard>,
+}
+
+impl WarpSwaggerUi {
+    fn reply(&self, path: &str, authorization: Option<&str>) -> Result<Box<dyn Reply>, Rejection> {
+        if !self.is_served_path(path) {
+            return Err(warp::reject::not_found());
+        }
+
+        if let Some(challenge) = self
+            .guard
+            .as_ref()
+            .and_then(|guard| guard.unauthorized(authorization))
+        {
+            let mut response = Response::builder().status(StatusCode::UNAUTHORIZED);
+            if let Some(challenge) = challenge {
+                response = response.header(header::WWW_AUTHENTICATE, challenge.as_ref());
+            }
+            return Ok(Box::new(response.body(Vec::new())));
+        }
+
+        if let Some((_, api_doc)) = self.api_docs.iter().find(|(url, _)| url.url == path) {
+            return Ok(Box::new(warp::reply::json(api_doc)));
+        }
+
+        if path == self.base_path {
+            return Ok(Box::new(warp::redirect::found(self.redirect.clone())));
+        }
+
+        let tail = path
+            .strip_prefix(&*self.base_path)
+            .and_then(|tail| tail.strip_prefix('/'))
+            .ok_or_else(warp::reject::not_found)?;
+
+        match super::serve(tail, self.config.clone()) {
+            Ok(file) => file
+                .map(|file| -> Box<dyn Reply> {
+                    Box::new(
+                        Response::builder()
+                            .header("Content-Type", file.content_type)
+                            .body(file.bytes),
+                    )
+                })
+                .ok_or_else(warp::reject::not_found),
+            Err(error) => Ok(Box::new(warp::reply::with_status(
+                error.to_string(),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ))),
+        }
+    }
+
+    /// Check whether the `path` is api doc url or within the Swagger UI base path.
+    fn is_served_path(&self, path: &str) -> bool {
+        self.api_docs.iter().any(|(url, _)| url.url == path)
+            || path
+                .strip_prefix(&*self.base_path)
+                .map(|tail| tail.is_empty() || tail.starts_with('/'))
+                .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::openapi::{Info, OpenApi, Paths};
+    use warp::http::header;
+
+    use super::*;
+
+    fn swagger_ui() -> BoxedFilter<(Box<dyn Reply>,)> {
+        SwaggerUi::new("/swagger-ui")
+            .url(
+                "/api-docs/openapi.json",
+                OpenApi::new(Info::new("api", "0.1.0"), Paths::new()),
+            )
+            .into()
+    }
+
+    #[tokio::test]
+    async fn serve_api_doc() {
+        let response = warp::test::request()
+            .path("/api-docs/openapi.json")
+            .reply(&swagger_ui())
+            .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let api_doc: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(api_doc["info"]["title"], "api");
+    }
+
+    #[tokio::test]
+    async fn redirect_swagger_ui_base_path_to_trailing_slash() {
+        let response = warp::test::request()
+            .path("/swagger-ui")
+            .reply(&swagger_ui())
+            .await;
+
+        assert!(response.status().is_redirection());
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/swagger-ui/"
+        );
+    }
+
+    #[tokio::test]
+    async fn serve_swagger_ui_files() {
+        let filter = swagger_ui();
+
+        let response = warp::test::request()
+            .path("/swagger-ui/")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/html"
+        );
+
+        let response = warp::test::request()
+            .path("/swagger-ui/swagger-initializer.js")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let initializer = String::from_utf8_lossy(response.body());
+        assert!(initializer.contains("/api-docs/openapi.json"));
+
+        let response = warp::test::request()
+            .path("/swagger-ui/not-found.js")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn guard_unauthorized_requests() {
+        let filter: BoxedFilter<(Box<dyn Reply>,)> = SwaggerUi::new("/swagger-ui")
+            .url(
+                "/api-docs/openapi.json",
+                OpenApi::new(Info::new("api", "0.1.0"), Paths::new()),
+            )
+            .guard(crate::guard::BearerAuth::new("token"))
+            .into();
+
+        for path in ["/api-docs/openapi.json", "/swagger-ui/"] {
+            let response = warp::test::request().path(path).reply(&filter).await;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(
+                response.headers().get(header::WWW_AUTHENTICATE).unwrap(),
+                "Bearer"
+            );
+
+            let response = warp::test::request()
+                .path(path)
+                .header(header::AUTHORIZATION, "Bearer token")
+                .reply(&filter)
+                .await;
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = warp::test::request().path("/other").reply(&filter).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn disabled_swagger_ui_is_not_served() {
+        let filter: BoxedFilter<(Box<dyn Reply>,)> = SwaggerUi::new("/swagger-ui")
+            .url(
+                "/api-docs/openapi.json",
+                OpenApi::new(Info::new("api", "0.1.0"), Paths::new()),
+            )
+            .enabled(false)
+            .into();
+
+        for path in ["/api-docs/openapi.json", "/swagger-ui/"] {
+            let response = warp::test::request().path(path).reply(&filter).await;
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+    }
+}
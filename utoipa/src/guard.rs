@@ -0,0 +1,266 @@
+//! Implements access control for the documentation served by the UI integrations of utoipa such
+//! as _`utoipa-swagger-ui`_, _`utoipa-redoc`_, _`utoipa-rapidoc`_ and _`utoipa-scalar`_.
+//!
+//! Every request to the served documentation is checked with the [`Guard`] given to the
+//! integration e.g. with _`SwaggerUi::guard`_ or _`Redoc::guard`_. Unauthorized requests are
+//! answered with `401 Unauthorized` response. Secrets of [`BasicAuth`] and [`BearerAuth`] are
+//! compared in constant time.
+
+use std::{borrow::Cow, sync::Arc};
+
+/// Decides whether a request is allowed to access the served documentation.
+///
+/// [`Guard`] is implemented for [`BasicAuth`], [`BearerAuth`] and for closures of type
+/// `Fn(Option<&str>) -> bool` receiving the value of the request's `Authorization` header.
+///
+/// # Examples
+///
+/// _**Allow requests with custom api key in the `Authorization` header.**_
+/// ```rust
+/// # use utoipa::guard::Guard;
+/// let guard = |authorization: Option<&str>| authorization == Some("ApiKey secret");
+///
+/// assert!(guard.authorize(Some("ApiKey secret")));
+/// ```
+pub trait Guard: Send + Sync + 'static {
+    /// Check whether request with given `Authorization` header value is allowed to access the
+    /// documentation. `authorization` is `None` if request has no `Authorization` header.
+    fn authorize(&self, authorization: Option<&str>) -> bool;
+
+    /// Value of the `WWW-Authenticate` header returned with unauthorized response. By default
+    /// no header is returned.
+    fn challenge(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+}
+
+impl<F> Guard for F
+where
+    F: Fn(Option<&str>) -> bool + Send + Sync + 'static,
+{
+    fn authorize(&self, authorization: Option<&str>) -> bool {
+        self(authorization)
+    }
+}
+
+/// [`Guard`] requiring [HTTP Basic authentication](https://datatracker.ietf.org/doc/html/rfc7617)
+/// with given username and password.
+///
+/// Unauthorized responses have `WWW-Authenticate: Basic realm="..."` header which makes browsers
+/// prompt for the credentials.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::guard::{BasicAuth, Guard};
+/// let guard = BasicAuth::new("admin", "secret").realm("api docs");
+///
+/// assert!(guard.authorize(Some("Basic YWRtaW46c2VjcmV0")));
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct BasicAuth {
+    credentials: String,
+    realm: Cow<'static, str>,
+}
+
+impl BasicAuth {
+    /// Construct a new [`BasicAuth`] guard accepting given `username` and `password`.
+    pub fn new<U: AsRef<str>, P: AsRef<str>>(username: U, password: P) -> Self {
+        Self {
+            credentials: encode_base64(
+                format!("{}:{}", username.as_ref(), password.as_ref()).as_bytes(),
+            ),
+            realm: Cow::Borrowed("utoipa"),
+        }
+    }
+
+    /// Set realm of the `WWW-Authenticate` challenge. Defaults to `utoipa`.
+    pub fn realm<R: Into<Cow<'static, str>>>(mut self, realm: R) -> Self {
+        self.realm = realm.into();
+
+        self
+    }
+}
+
+impl Guard for BasicAuth {
+    fn authorize(&self, authorization: Option<&str>) -> bool {
+        authorization
+            .and_then(|authorization| strip_scheme(authorization, "Basic"))
+            .map(|credentials| constant_time_eq(credentials, &self.credentials))
+            .unwrap_or(false)
+    }
+
+    fn challenge(&self) -> Option<Cow<'static, str>> {
+        Some(Cow::Owned(format!(
+            "Basic realm=\"{}\", charset=\"UTF-8\"",
+            self.realm
+        )))
+    }
+}
+
+/// [`Guard`] requiring [Bearer token](https://datatracker.ietf.org/doc/html/rfc6750) in the
+/// `Authorization` header.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::guard::{BearerAuth, Guard};
+/// let guard = BearerAuth::new("my-secret-token");
+///
+/// assert!(guard.authorize(Some("Bearer my-secret-token")));
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct BearerAuth {
+    token: Cow<'static, str>,
+}
+
+impl BearerAuth {
+    /// Construct a new [`BearerAuth`] guard accepting given `token`.
+    pub fn new<T: Into<Cow<'static, str>>>(token: T) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+impl Guard for BearerAuth {
+    fn authorize(&self, authorization: Option<&str>) -> bool {
+        authorization
+            .and_then(|authorization| strip_scheme(authorization, "Bearer"))
+            .map(|token| constant_time_eq(token, &self.token))
+            .unwrap_or(false)
+    }
+
+    fn challenge(&self) -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("Bearer"))
+    }
+}
+
+/// Cheaply cloneable [`Guard`] stored within the served documentation by the UI integrations.
+#[derive(Clone)]
+pub struct SharedGuard(Arc<dyn Guard>);
+
+impl SharedGuard {
+    /// Construct a new [`SharedGuard`] from given `guard`.
+    pub fn new<G: Guard>(guard: G) -> Self {
+        Self(Arc::new(guard))
+    }
+
+    /// Returns `Some` value of the `WWW-Authenticate` header if request with given
+    /// `Authorization` header is unauthorized. The inner `Option` is `None` if guard has no
+    /// challenge.
+    pub fn unauthorized(&self, authorization: Option<&str>) -> Option<Option<Cow<'static, str>>> {
+        if self.0.authorize(authorization) {
+            None
+        } else {
+            Some(self.0.challenge())
+        }
+    }
+}
+
+impl std::fmt::Debug for SharedGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedGuard").finish_non_exhaustive()
+    }
+}
+
+/// Strip case insensitive authentication `scheme` from the `authorization` header value.
+fn strip_scheme<'a>(authorization: &'a str, scheme: &str) -> Option<&'a str> {
+    let (value_scheme, value) = authorization.trim().split_once(' ')?;
+
+    if value_scheme.eq_ignore_ascii_case(scheme) {
+        Some(value.trim())
+    } else {
+        None
+    }
+}
+
+/// Compare `value` to the `secret` in time depending only on their lengths.
+fn constant_time_eq(value: &str, secret: &str) -> bool {
+    value.len() == secret.len()
+        && value
+            .bytes()
+            .zip(secret.bytes())
+            .fold(0, |difference, (value, secret)| {
+                difference | (value ^ secret)
+            })
+            == 0
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+
+        for (index, shift) in [18, 12, 6, 0].into_iter().enumerate() {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[((n >> shift) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_base64_with_padding() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(b"admin:secret"), "YWRtaW46c2VjcmV0");
+    }
+
+    #[test]
+    fn constant_time_eq_compares_whole_values() {
+        assert!(constant_time_eq("secret", "secret"));
+        assert!(!constant_time_eq("secreT", "secret"));
+        assert!(!constant_time_eq("secret", "secrets"));
+        assert!(constant_time_eq("", ""));
+    }
+
+    #[test]
+    fn basic_auth_authorizes_matching_credentials() {
+        let guard = BasicAuth::new("admin", "secret");
+
+        assert!(guard.authorize(Some("Basic YWRtaW46c2VjcmV0")));
+        assert!(guard.authorize(Some("basic YWRtaW46c2VjcmV0")));
+        assert!(!guard.authorize(Some("Basic Zm9v")));
+        assert!(!guard.authorize(Some("Bearer YWRtaW46c2VjcmV0")));
+        assert!(!guard.authorize(None));
+    }
+
+    #[test]
+    fn bearer_auth_authorizes_matching_token() {
+        let guard = BearerAuth::new("token");
+
+        assert!(guard.authorize(Some("Bearer token")));
+        assert!(!guard.authorize(Some("Bearer other")));
+        assert!(!guard.authorize(Some("token")));
+        assert!(!guard.authorize(None));
+    }
+
+    #[test]
+    fn closure_guard_receives_authorization() {
+        let guard = |authorization: Option<&str>| authorization.is_some();
+
+        assert!(Guard::authorize(&guard, Some("anything")));
+        assert!(!Guard::authorize(&guard, None));
+        assert_eq!(Guard::challenge(&guard), None);
+    }
+}
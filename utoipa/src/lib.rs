@@ -44,16 +44,25 @@
 //!
 //! * **yaml** Enables **serde_yaml** serialization of OpenAPI objects.
 //! * **actix_extras** Enhances [actix-web](https://github.com/actix/actix-web/) integration with being able to
-//!   parse `path`, `path` and `query` parameters from actix web path attribute macros. See [actix extras support][actix_path] or
+//!   parse `path`, `path` and `query` parameters and `request_body` from actix web path attribute macros
+//!   and handler arguments. See [actix extras support][actix_path] or
 //!   [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more details.
 //! * **rocket_extras** Enhances [rocket](https://github.com/SergioBenitez/Rocket) framework integration with being
 //!   able to parse `path`, `path` and `query` parameters from rocket path attribute macros. See [rocket extras support][rocket_path]
 //!   or [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more details
 //! * **axum_extras** Enhances [axum](https://github.com/tokio-rs/axum) framework integration allowing users to use `IntoParams`
-//!   without defining the `parameter_in` attribute. See [axum extras support][axum_path]
+//!   without defining the `parameter_in` attribute or listing them in `params(...)`, and resolving `request_body` from
+//!   handler arguments. See [axum extras support][axum_path]
 //!   or [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more details.
+//! * **poem_extras** Enhances [poem](https://github.com/poem-web/poem) framework integration the same way as
+//!   **axum_extras** and converts poem path syntax e.g. `/todo/:id` to OpenAPI path syntax. See
+//!   [poem extras support][poem_path] for more details.
+//! * **salvo_extras** Enhances [salvo](https://github.com/salvo-rs/salvo) framework integration with being able to
+//!   resolve path and query parameters and `request_body` from `PathParam`, `QueryParam`, `JsonBody` and `FormBody`
+//!   handler arguments and converting salvo path syntax e.g. `/todo/<id>` to OpenAPI path syntax. See
+//!   [salvo extras support][salvo_path] for more details.
 //! * **debug** Add extra traits such as debug traits to openapi definitions and elsewhere.
-//! * **chrono** Add support for [chrono](https://crates.io/crates/chrono) `DateTime`, `Date`, `NaiveDate`, `NaiveTime` and `Duration`
+//! * **chrono** Add support for [chrono](https://crates.io/crates/chrono) `DateTime`, `Date`, `NaiveDate`, `NaiveDateTime`, `NaiveTime` and `Duration`
 //!   types. By default these types are parsed to `string` types with additional `format` information.
 //!   `format: date-time` for `DateTime` and `format: date` for `Date` and `NaiveDate` according
 //!   [RFC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14) as `ISO-8601`. To
@@ -63,12 +72,14 @@
 //!   By default these types are parsed as `string`. `OffsetDateTime` and `PrimitiveDateTime` will use `date-time` format. `Date` will use
 //!   `date` format and `Duration` will not have any format. To override default `string` representation users have to use `value_type` attribute
 //!   to override the type. See [docs](https://docs.rs/utoipa/latest/utoipa/derive.ToSchema.html) for more details.
-//! * **decimal** Add support for [rust_decimal](https://crates.io/crates/rust_decimal) `Decimal` type. **By default**
-//!   it is interpreted as `String`. If you wish to change the format you need to override the type.
+//! * **decimal** Add support for [rust_decimal](https://crates.io/crates/rust_decimal) `Decimal` and
+//!   [bigdecimal](https://crates.io/crates/bigdecimal) `BigDecimal` types. **By default**
+//!   they are interpreted as `String`. If you wish to change the format you need to override the type.
 //!   See the `value_type` in [`ToSchema` derive docs][to_schema_derive].
-//! * **decimal_float** Add support for [rust_decimal](https://crates.io/crates/rust_decimal) `Decimal` type. **By default**
-//!   it is interpreted as `Number`. This feature is mutually exclusive with **decimal** and allow to change the default type used in your
-//!   documentation for `Decimal` much like `serde_with_float` feature exposed by rust_decimal.
+//! * **decimal_float** Add support for [rust_decimal](https://crates.io/crates/rust_decimal) `Decimal` and
+//!   [bigdecimal](https://crates.io/crates/bigdecimal) `BigDecimal` types. **By default**
+//!   they are interpreted as `Number`. This feature is mutually exclusive with **decimal** and allow to change the default type used in your
+//!   documentation for `Decimal` and `BigDecimal` much like `serde_with_float` feature exposed by rust_decimal.
 //! * **uuid** Add support for [uuid](https://github.com/uuid-rs/uuid). `Uuid` type will be presented as `String` with
 //!   format `uuid` in OpenAPI spec.
 //! * **ulid** Add support for [ulid](https://github.com/dylanhart/ulid-rs). `Ulid` type will be presented as `String` with
@@ -83,15 +94,40 @@
 //!   C-like enum representation. See [docs](https://docs.rs/utoipa/latest/utoipa/derive.ToSchema.html) for more details.
 //! * **preserve_order** Preserve order of properties when serializing the schema for a component.
 //!   When enabled, the properties are listed in order of fields in the corresponding struct definition.
-//!   When disabled, the properties are listed in alphabetical order.
+//!   When disabled, the properties are listed in alphabetical order. Enables _`preserve_order`_ of
+//!   `serde_json` as well, so that schemas serialized through `serde_json::Value` keep their order.
 //! * **preserve_path_order** Preserve order of OpenAPI Paths according to order they have been
 //!   introduced to the `#[openapi(paths(...))]` macro attribute. If disabled the paths will be
 //!   ordered in alphabetical order.
 //! * **indexmap** Add support for [indexmap](https://crates.io/crates/indexmap). When enabled `IndexMap` will be rendered as a map similar to
 //!   `BTreeMap` and `HashMap`.
 //! * **non_strict_integers** Add support for non-standard integer formats `int8`, `int16`, `uint8`, `uint16`, `uint32`, and `uint64`.
+//! * **large_numbers_as_string** Represent 64 and 128 bit integers `i64`, `u64`, `i128` and `u128` as `string`
+//!   types instead of `integer` for clients such as JavaScript that cannot represent them without precision loss.
+//!   **Note!** The feature only changes the schemas; the values must be serialized as strings as well, e.g. with
+//!   `#[serde_as(as = "DisplayFromStr")]` of [serde_with](https://crates.io/crates/serde_with), for the document to match
+//!   the serialized data. `isize` and `usize` are not affected since their width depends on the target platform. Single
+//!   fields can be overridden with `#[schema(value_type = ...)]` regardless of the feature.
 //! * **rc_schema** Add `ToSchema` support for `Arc<T>` and `Rc<T>` types. **Note!** serde `rc` feature flag must be enabled separately to allow
 //!   serialization and deserialization of `Arc<T>` and `Rc<T>` types. See more about [serde feature flags](https://serde.rs/feature-flags.html).
+//! * **validator** Add support for [validator](https://crates.io/crates/validator) crate `#[validate(...)]` field attributes. When enabled
+//!   `length`, `range`, `email`, `url` and `regex` validations are rendered to the schemas of `ToSchema` and `IntoParams` fields.
+//! * **serde_with** Add support for [serde_with](https://crates.io/crates/serde_with) crate `#[serde_as(as = ...)]` field attributes. When enabled
+//!   fields serialized with `DisplayFromStr`, `Base64`, `Duration*` and `Timestamp*` adaptors are rendered with the schema of the serialized value.
+//! * **openapi_3_1** Generate OpenAPI 3.1 documents instead of OpenAPI 3.0. Document version will be `3.1.0` and nullable
+//!   schemas will be serialized with `"null"` type instead of the `nullable` keyword. Nullable enums will also have `null`
+//!   in their `enum` values. Tuple types are described by the position of their items with `prefixItems`.
+//! * **auto_collect_schemas** Collect schemas referenced by the component schemas, and the request body, response body
+//!   and parameter types declared in `#[utoipa::path(...)]` attributes automatically to the `OpenApi` components. When enabled
+//!   the path types must be actual types in scope instead of only names of the schemas, e.g. a schema declared with
+//!   _`as = path::to::Pet`_ must be referenced with its type.
+//!   See [`OpenApi` derive][openapi_derive] for more details.
+//! * **register_paths** Allow registering paths with `#[utoipa::path(register, ...)]` attribute. Registered paths are
+//!   collected with [`OpenApi::gather`] at runtime without listing them in `#[openapi(paths(...))]`. Registration is
+//!   implemented with [inventory](https://crates.io/crates/inventory) crate.
+//! * **auto_into_responses** Derive the responses of `#[utoipa::path(...)]` from the return type of the handler function
+//!   implementing [`IntoResponses`]. `Result<T, E>` combines the responses of `T` and `E`. Explicit `responses(...)` entries
+//!   override the derived ones with the same status code.
 //!
 //! Utoipa implicitly has partial support for `serde` attributes. See [`ToSchema` derive][serde] for more details.
 //!
@@ -251,23 +287,32 @@
 //! # Go beyond the surface
 //!
 //! * See how to serve OpenAPI doc via Swagger UI check [`utoipa-swagger-ui`][utoipa_swagger] crate for more details.
+//!   Served documentation can be protected with the [`guard`]s.
 //! * Browse to [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more comprehensive examples.
 //! * Check [`derive@IntoResponses`] and [`derive@ToResponse`] for examples on deriving responses.
+//! * Check [`derive@ToHeader`] for documenting a set of response headers with a type.
 //! * More about OpenAPI security in [security documentation][security].
-//! * Dump generated API doc to file at build time. See [issue 214 comment](https://github.com/juhaku/utoipa/issues/214#issuecomment-1179589373).
+//! * Write generated API doc to file during `cargo test` with [`export!`] macro.
+//! * Assert generated API doc in tests with [`assert_openapi_snapshot!`] and [`assert_json_path!`]
+//!   macros.
 //!
 //! [path]: attr.path.html
 //! [rocket_path]: attr.path.html#rocket_extras-support-for-rocket
 //! [actix_path]: attr.path.html#actix_extras-support-for-actix-web
 //! [axum_path]: attr.path.html#axum_extras-support-for-axum
+//! [poem_path]: attr.path.html#poem_extras-feature-support-for-poem
+//! [salvo_path]: attr.path.html#salvo_extras-feature-support-for-salvo
 //! [serde]: derive.ToSchema.html#partial-serde-attributes-support
 //!
 //! [security]: openapi/security/index.html
 //! [to_schema_derive]: derive.ToSchema.html
+//! [openapi_derive]: derive.OpenApi.html
 
+pub mod guard;
 pub mod openapi;
+pub mod testing;
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 pub use utoipa_gen::*;
 
@@ -317,6 +362,327 @@ pub use utoipa_gen::*;
 /// [derive]: derive.OpenApi.html
 pub trait OpenApi {
     fn openapi() -> openapi::OpenApi;
+
+    /// Return the [`OpenApi::openapi`] with only the operations belonging to given
+    /// `api_version`.
+    ///
+    /// Paths declared with _`#[utoipa::path(api_version = "...")]`_ of other versions are left
+    /// out from the document while paths without a version are included in every version. The
+    /// same path and operation can thus be declared for multiple versions. All versions share the
+    /// same components. This allows serving e.g. deprecated _`v1`_ and current _`v2`_ documents
+    /// side by side from the same [`OpenApi`] type.
+    ///
+    /// Derived implementation filters the paths listed in _`#[openapi(paths(...))]`_. Paths of
+    /// nested [`OpenApi`]s are not filtered. The default implementation returns
+    /// [`OpenApi::openapi`] as is.
+    ///
+    /// # Examples
+    ///
+    /// _**Serve separate documents of two api versions.**_
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[utoipa::path(get, path = "/pets", api_version = "v1", responses((status = 200)))]
+    /// fn get_pets_v1() {}
+    ///
+    /// #[utoipa::path(get, path = "/v2/pets", api_version = "v2", responses((status = 200)))]
+    /// fn get_pets_v2() {}
+    ///
+    /// #[utoipa::path(get, path = "/health", responses((status = 200)))]
+    /// fn health() {}
+    ///
+    /// #[derive(OpenApi)]
+    /// #[openapi(paths(get_pets_v1, get_pets_v2, health))]
+    /// struct ApiDoc;
+    ///
+    /// let v1 = ApiDoc::openapi_for_version("v1");
+    /// assert!(v1.paths.get_path_item("/pets").is_some());
+    /// assert!(v1.paths.get_path_item("/v2/pets").is_none());
+    /// assert!(v1.paths.get_path_item("/health").is_some());
+    /// ```
+    fn openapi_for_version(api_version: &str) -> openapi::OpenApi {
+        let _ = api_version;
+        Self::openapi()
+    }
+
+    /// Return the [`OpenApi::openapi`] with all the paths registered with
+    /// _`#[utoipa::path(register, ...)]`_ merged to it.
+    ///
+    /// Registered paths are merged the same way as in [`openapi::OpenApi::merge`], thus paths
+    /// and operations already listed in the [`OpenApi::openapi`] take precedence. The default tag
+    /// of a registered path is the name of the parent module of the handler function.
+    /// Schemas of the registered paths are collected to the components with
+    /// _`auto_collect_schemas`_ feature.
+    ///
+    /// # Examples
+    ///
+    /// _**Gather paths registered in any module of the crate.**_
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[utoipa::path(register, get, path = "/pets", responses((status = 200)))]
+    /// fn get_pets() {}
+    ///
+    /// #[derive(OpenApi)]
+    /// #[openapi()]
+    /// struct ApiDoc;
+    ///
+    /// let openapi = ApiDoc::gather();
+    /// assert!(openapi.paths.get_path_item("/pets").is_some());
+    /// ```
+    #[cfg(feature = "register_paths")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "register_paths")))]
+    fn gather() -> openapi::OpenApi {
+        let mut registered_paths = __dev::inventory::iter::<__dev::RegisteredPath>
+            .into_iter()
+            .filter(|registered_path| !(registered_path.is_hidden)())
+            .collect::<Vec<_>>();
+        registered_paths.sort_by_cached_key(|registered_path| (registered_path.path)());
+
+        let mut paths = openapi::path::PathsBuilder::new();
+        let mut schemas = Vec::new();
+        for registered_path in registered_paths {
+            let default_tag = registered_path
+                .module_path
+                .rsplit_once("::")
+                .map(|(_, module)| module);
+            paths = paths.path(
+                (registered_path.path)(),
+                (registered_path.path_item)(default_tag),
+            );
+            (registered_path.schemas)(&mut schemas);
+        }
+
+        let mut registered = openapi::OpenApiBuilder::new().paths(paths).build();
+        if !schemas.is_empty() {
+            registered.components = Some(
+                openapi::ComponentsBuilder::new()
+                    .schemas_from_iter(schemas)
+                    .build(),
+            );
+        }
+
+        let mut openapi = Self::openapi();
+        openapi.merge(registered);
+        openapi
+    }
+
+    /// Return a reference to the [`OpenApi::openapi`] which is built only once on the first call
+    /// and cached for the rest of the program.
+    ///
+    /// This avoids rebuilding the whole document e.g. every time the OpenAPI spec endpoint is
+    /// called. Use [`OpenApi::openapi`] instead if the document needs to be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[derive(OpenApi)]
+    /// #[openapi()]
+    /// struct ApiDoc;
+    ///
+    /// assert!(std::ptr::eq(ApiDoc::openapi_cached(), ApiDoc::openapi_cached()));
+    /// ```
+    fn openapi_cached() -> &'static openapi::OpenApi
+    where
+        Self: Sized + 'static,
+    {
+        cached::<Self, _>(Self::openapi)
+    }
+
+    /// Return the [`OpenApi::openapi_cached`] serialized to JSON. The document is serialized
+    /// only once on the first call and serving it is thus only a copy of the returned bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[derive(OpenApi)]
+    /// #[openapi()]
+    /// struct ApiDoc;
+    ///
+    /// let json: &'static str = ApiDoc::openapi_json();
+    /// assert_eq!(json, ApiDoc::openapi().to_json().unwrap());
+    /// ```
+    fn openapi_json() -> &'static str
+    where
+        Self: Sized + 'static,
+    {
+        cached::<Self, String>(|| {
+            Self::openapi_cached()
+                .to_json()
+                .expect("OpenApi document must serialize to JSON")
+        })
+    }
+}
+
+/// Get value of type `V` cached for type `T` or initialize it with `init` on the first call.
+/// `init` is called only once, concurrent first calls wait for it to finish. Values are leaked
+/// to be available for the rest of the program which is fine since there is at most one value
+/// per pair of types.
+fn cached<T: 'static, V: Send + Sync + 'static>(init: impl FnOnce() -> V) -> &'static V {
+    use std::any::{Any, TypeId};
+    use std::sync::{OnceLock, RwLock};
+
+    type Cache = RwLock<HashMap<(TypeId, TypeId), &'static (dyn Any + Send + Sync)>>;
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    let key = (TypeId::of::<T>(), TypeId::of::<V>());
+    let cached = cache
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .get(&key)
+        .copied();
+    // cell of the value is created under the lock but the value is initialized outside of it
+    // since `init` may need cached values of other types
+    let cell = cached.unwrap_or_else(|| {
+        *cache
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+            .entry(key)
+            .or_insert_with(|| {
+                let cell: &'static (dyn Any + Send + Sync) =
+                    Box::leak(Box::new(OnceLock::<V>::new()));
+                cell
+            })
+    });
+
+    cell.downcast_ref::<OnceLock<V>>()
+        .expect("cached value must be of the type it was cached with")
+        .get_or_init(init)
+}
+
+/// Export the OpenAPI document of an [`OpenApi`] type to files when running `cargo test`.
+///
+/// The macro generates a `#[test]` function named _`export_openapi`_ which writes the
+/// [`OpenApi::openapi`] of given type with [`openapi::OpenApi::write_to_file`] to each of the
+/// given paths. Relative paths are resolved against the directory of the `Cargo.toml` of the
+/// crate invoking the macro. This allows committing the contract file to the repository and
+/// consuming it e.g. in client code generation pipelines without starting the server.
+///
+/// Files with _`yaml`_ or _`yml`_ extension are written as YAML and require _`yaml`_ feature.
+/// Other files are written as pretty JSON.
+///
+/// Optionally the name of the generated test function can be given as the first argument,
+/// which allows exporting multiple documents from the same module.
+///
+/// # Examples
+///
+/// _**Write `openapi.json` next to the `Cargo.toml` on `cargo test`.**_
+/// ```rust
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi()]
+/// struct ApiDoc;
+///
+/// utoipa::export!(ApiDoc, "openapi.json");
+/// ```
+///
+/// _**Write the document to multiple files with a custom test function name.**_
+/// ```rust
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi()]
+/// struct AdminApiDoc;
+///
+/// utoipa::export!(export_admin_openapi: AdminApiDoc, "admin.json", "docs/admin.yaml");
+/// ```
+#[macro_export]
+macro_rules! export {
+    ($name:ident : $api:ty, $( $path:expr ),+ $(,)?) => {
+        #[test]
+        fn $name() {
+            let openapi = <$api as $crate::OpenApi>::openapi();
+            $(
+                let path = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path);
+                openapi
+                    .write_to_file(&path)
+                    .unwrap_or_else(|error| panic!("failed to export OpenAPI to {}: {error}", path.display()));
+            )+
+        }
+    };
+    ($api:ty, $( $path:expr ),+ $(,)?) => {
+        $crate::export!(export_openapi: $api, $( $path ),+);
+    };
+}
+
+/// Assert that the given [`OpenApi`][openapi] document matches a JSON snapshot file.
+///
+/// The path of the snapshot is resolved against the directory of the `Cargo.toml` of the crate
+/// invoking the macro. Run the tests with _`UTOIPA_UPDATE_SNAPSHOTS=1`_ environment variable to
+/// create missing snapshots and to update existing ones after intended changes, which allows
+/// committing the snapshot to the repository and catching unintended changes of the generated
+/// spec in unit tests. Missing snapshot fails the assertion otherwise.
+///
+/// On mismatch the assertion panics listing the JSON pointers of the differences e.g.
+/// _`/paths/~1pets/get/operationId: expected "list_pets", found "get_pets"`_.
+/// See [`testing::assert_snapshot`] for more details.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use utoipa::OpenApi;
+/// #[derive(OpenApi)]
+/// #[openapi()]
+/// struct ApiDoc;
+///
+/// #[test]
+/// fn openapi_snapshot() {
+///     utoipa::assert_openapi_snapshot!(ApiDoc::openapi(), "snapshots/openapi.json");
+/// }
+/// ```
+///
+/// [openapi]: openapi/struct.OpenApi.html
+#[macro_export]
+macro_rules! assert_openapi_snapshot {
+    ($openapi:expr, $path:expr $(,)?) => {
+        $crate::testing::assert_snapshot(
+            &$openapi,
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+    };
+}
+
+/// Assert that the given document has a value at a [JSON pointer][json_pointer].
+///
+/// The document can be any serializable value such as [`OpenApi`][openapi] or
+/// [`serde_json::Value`]. With two arguments the macro returns the value at the pointer as
+/// [`serde_json::Value`] and panics showing the available keys of the deepest existing parent if
+/// there is no value at the pointer. With three arguments the value at the pointer is also
+/// asserted to equal the serialized third argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::OpenApi;
+/// # use serde_json::json;
+/// #[utoipa::path(get, path = "/pets", responses((status = 200, description = "List pets")))]
+/// fn list_pets() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(paths(list_pets))]
+/// struct ApiDoc;
+///
+/// let doc = ApiDoc::openapi();
+/// let response = utoipa::assert_json_path!(doc, "/paths/~1pets/get/responses/200");
+/// assert_eq!(response["description"], "List pets");
+///
+/// utoipa::assert_json_path!(
+///     doc,
+///     "/paths/~1pets/get/responses/200",
+///     json!({ "description": "List pets" })
+/// );
+/// ```
+///
+/// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+/// [openapi]: openapi/struct.OpenApi.html
+#[macro_export]
+macro_rules! assert_json_path {
+    ($document:expr, $pointer:expr $(,)?) => {
+        $crate::testing::assert_json_path(&$document, $pointer)
+    };
+    ($document:expr, $pointer:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_json_path_eq(&$document, $pointer, &$expected)
+    };
 }
 
 /// Trait for implementing OpenAPI Schema object.
@@ -390,6 +756,79 @@ pub trait ToSchema<'__s> {
     /// name or inlined directly to responses, request bodies or parameters.
     fn schema() -> (&'__s str, openapi::RefOr<openapi::schema::Schema>);
 
+    /// Return name of the schema. The name is used as the key of the schema in
+    /// [`Components`][components] and as the target of `$ref` references pointing to the schema.
+    ///
+    /// By default the name is taken from [`ToSchema::schema`]. Derive [`macro@ToSchema`] implements
+    /// this method with the type name or with the name given in `#[schema(as = ...)]` attribute.
+    ///
+    /// # Examples
+    ///
+    /// Build a reference to a renamed schema.
+    /// ```rust
+    /// # use utoipa::ToSchema;
+    /// # use utoipa::openapi::Ref;
+    /// #[derive(ToSchema)]
+    /// #[schema(as = UserDTO)]
+    /// struct User {
+    ///     id: u64,
+    /// }
+    ///
+    /// assert_eq!(User::schema_name(), "UserDTO");
+    /// let reference = Ref::from_schema_name(User::schema_name());
+    /// assert_eq!(reference.ref_location, "#/components/schemas/UserDTO");
+    /// ```
+    ///
+    /// [components]: openapi/schema/struct.Components.html
+    fn schema_name() -> &'__s str {
+        Self::schema().0
+    }
+
+    /// Return a tuple of name and schema to be used as a request body. Same as
+    /// [`ToSchema::schema`] but properties marked as `read_only` are omitted. Properties of inlined
+    /// schemas are omitted as well, referenced schemas are left as is.
+    ///
+    /// [`macro@path`] uses this schema for inlined request bodies e.g.
+    /// _`request_body = inline(User)`_. Referenced request bodies point to the full schema in
+    /// the components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::ToSchema;
+    /// # use utoipa::openapi::{RefOr, schema::Schema};
+    /// #[derive(ToSchema)]
+    /// struct User {
+    ///     #[schema(read_only)]
+    ///     id: u64,
+    ///     name: String,
+    /// }
+    ///
+    /// let (_, schema) = User::request_schema();
+    /// if let RefOr::T(Schema::Object(object)) = schema {
+    ///     assert!(!object.properties.contains_key("id"));
+    ///     assert!(object.properties.contains_key("name"));
+    /// }
+    /// ```
+    fn request_schema() -> (&'__s str, openapi::RefOr<openapi::schema::Schema>) {
+        let (name, mut schema) = Self::schema();
+        openapi::schema::exclude_properties(&mut schema, |read_only, _| read_only == Some(true));
+        (name, schema)
+    }
+
+    /// Return a tuple of name and schema to be used as a response body. Same as
+    /// [`ToSchema::schema`] but properties marked as `write_only` are omitted. Properties of inlined
+    /// schemas are omitted as well, referenced schemas are left as is.
+    ///
+    /// [`macro@path`] uses this schema for inlined response bodies e.g.
+    /// _`body = inline(User)`_. Referenced response bodies point to the full schema in
+    /// the components.
+    fn response_schema() -> (&'__s str, openapi::RefOr<openapi::schema::Schema>) {
+        let (name, mut schema) = Self::schema();
+        openapi::schema::exclude_properties(&mut schema, |_, write_only| write_only == Some(true));
+        (name, schema)
+    }
+
     /// Optional set of alias schemas for the [`ToSchema::schema`].
     ///
     /// Typically there is no need to manually implement this method but it is instead implemented
@@ -397,6 +836,75 @@ pub trait ToSchema<'__s> {
     fn aliases() -> Vec<(&'__s str, openapi::schema::Schema)> {
         Vec::new()
     }
+
+    /// Return schema of a generic type with references to its generic type parameters replaced
+    /// with the given `generics` schemas in the declaration order of the type parameters.
+    ///
+    /// Typically there is no need to manually implement this method but it is instead implemented
+    /// by derive [`macro@ToSchema`] for generic types. It is used to render generic types
+    /// referenced with concrete generic arguments e.g. _`Page<Pet>`_ without declaring
+    /// _`#[aliases(...)]`_ for them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::ToSchema;
+    /// # use utoipa::openapi::{Ref, RefOr, schema::Schema};
+    /// #[derive(ToSchema)]
+    /// struct Page<T> {
+    ///     items: Vec<T>,
+    /// }
+    ///
+    /// let schema = <Page<()> as ToSchema>::generic_schema(vec![Ref::from_schema_name("Pet").into()]);
+    /// # let RefOr::T(Schema::Object(object)) = schema else { panic!() };
+    /// # let RefOr::T(Schema::Array(items)) = &object.properties["items"] else { panic!() };
+    /// # assert!(matches!(&*items.items, RefOr::Ref(reference) if reference.ref_location == "#/components/schemas/Pet"));
+    /// ```
+    fn generic_schema(
+        generics: Vec<openapi::RefOr<openapi::schema::Schema>>,
+    ) -> openapi::RefOr<openapi::schema::Schema> {
+        let _ = generics;
+        Self::schema().1
+    }
+
+    /// Optional default content type of request bodies referencing the [`ToSchema::schema`].
+    ///
+    /// [`macro@path`] uses the returned content type instead of inferred _`application/json`_ for
+    /// request bodies of this type without explicit _`content_type`_. Typically there is no need to manually implement this method but it is
+    /// instead implemented by derive [`macro@ToSchema`] when `#[schema(form)]` attribute is defined.
+    fn content_type() -> Option<&'__s str> {
+        None
+    }
+
+    /// Collect schemas referenced by [`ToSchema::schema`] to `schemas` as name and schema pairs.
+    /// Schemas referenced by the collected schemas are collected as well.
+    ///
+    /// With _`auto_collect_schemas`_ feature derive [`macro@ToSchema`] implements this method for
+    /// fields and variants referencing other types implementing [`ToSchema`]. [`derive@OpenApi`]
+    /// uses the collected schemas to register schemas used by the paths automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::ToSchema;
+    /// #[derive(ToSchema)]
+    /// struct Owner {
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(ToSchema)]
+    /// struct Pet {
+    ///     owner: Owner,
+    /// }
+    ///
+    /// let mut schemas = Vec::new();
+    /// Pet::schemas(&mut schemas);
+    /// # #[cfg(feature = "auto_collect_schemas")]
+    /// assert_eq!(schemas[0].0, "Owner");
+    /// ```
+    fn schemas(schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>) {
+        let _ = schemas;
+    }
 }
 
 impl<'__s, T: ToSchema<'__s>> From<T> for openapi::RefOr<openapi::schema::Schema> {
@@ -445,6 +953,406 @@ mod utoipa {
     pub use super::*;
 }
 
+/// Internal types used by the code generated by `utoipa-gen`.
+///
+/// ONLY for internal use! Contents of this module are not part of the public API.
+#[doc(hidden)]
+pub mod __dev {
+    use std::cell::RefCell;
+    use std::marker::PhantomData;
+
+    use crate::{openapi, ToSchema};
+
+    /// Collects schema of `T` only when `T` implements [`ToSchema`]. Types not implementing
+    /// [`ToSchema`] fall back to [`CollectNothing`] via auto-ref method resolution.
+    pub struct SchemaCollector<T: ?Sized>(PhantomData<T>);
+
+    impl<T: ?Sized> SchemaCollector<T> {
+        pub fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<T: ?Sized> Default for SchemaCollector<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub trait CollectSchema {
+        fn collect(
+            &self,
+            schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>,
+            name: &str,
+            is_inline: bool,
+        ) {
+            self.collect_generic(schemas, name, is_inline, Vec::new())
+        }
+
+        fn collect_generic(
+            &self,
+            schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>,
+            name: &str,
+            is_inline: bool,
+            generics: Vec<openapi::RefOr<openapi::schema::Schema>>,
+        );
+
+        /// Default content type of request bodies of the collected type, see
+        /// [`ToSchema::content_type`].
+        fn content_type(&self) -> Option<&'static str>;
+    }
+
+    impl<T: for<'s> ToSchema<'s>> CollectSchema for SchemaCollector<T> {
+        /// Collect schema of `T` with the `name` it is referenced with. If `name` is one of the
+        /// aliases of `T` the schema of the alias is collected instead, otherwise generic type
+        /// parameters of `T` are replaced with the `generics` schemas.
+        fn collect_generic(
+            &self,
+            schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>,
+            name: &str,
+            is_inline: bool,
+            generics: Vec<openapi::RefOr<openapi::schema::Schema>>,
+        ) {
+            if !is_inline {
+                let schema = T::aliases()
+                    .into_iter()
+                    .find(|(alias, _)| *alias == name)
+                    .map(|(_, schema)| openapi::RefOr::T(schema))
+                    .unwrap_or_else(|| T::generic_schema(generics));
+                if let Some((_, collected)) =
+                    schemas.iter().find(|(collected, _)| collected == name)
+                {
+                    if *collected != schema {
+                        record_type_name::<T>(name);
+                    }
+                    return;
+                }
+                record_type_name::<T>(name);
+                schemas.push((name.to_string(), schema));
+            }
+
+            T::schemas(schemas);
+        }
+
+        fn content_type(&self) -> Option<&'static str> {
+            <T as ToSchema<'static>>::content_type()
+        }
+    }
+
+    pub trait CollectNothing {
+        fn collect(
+            &self,
+            _: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>,
+            _: &str,
+            _: bool,
+        ) {
+        }
+
+        fn collect_generic(
+            &self,
+            _: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>,
+            _: &str,
+            _: bool,
+            _: Vec<openapi::RefOr<openapi::schema::Schema>>,
+        ) {
+        }
+
+        fn content_type(&self) -> Option<&'static str> {
+            None
+        }
+    }
+
+    /// Replace references to the generic type `params` in the `schema` with the `generics`
+    /// schemas given in the same order. Used by derive [`macro@ToSchema`] to implement
+    /// [`ToSchema::generic_schema`] of generic types.
+    pub fn replace_generics(
+        mut schema: openapi::RefOr<openapi::schema::Schema>,
+        params: &[&str],
+        generics: Vec<openapi::RefOr<openapi::schema::Schema>>,
+    ) -> openapi::RefOr<openapi::schema::Schema> {
+        let replacements = params
+            .iter()
+            .map(|param| openapi::Ref::from_schema_name(*param).ref_location)
+            .zip(generics)
+            .collect::<Vec<_>>();
+        if !replacements.is_empty() {
+            replace_references(&mut schema, &replacements);
+        }
+
+        schema
+    }
+
+    fn replace_references(
+        schema: &mut openapi::RefOr<openapi::schema::Schema>,
+        replacements: &[(String, openapi::RefOr<openapi::schema::Schema>)],
+    ) {
+        use openapi::schema::{AdditionalProperties, Schema};
+
+        match schema {
+            openapi::RefOr::Ref(reference) => {
+                if let Some((_, replacement)) = replacements
+                    .iter()
+                    .find(|(location, _)| *location == reference.ref_location)
+                {
+                    *schema = replacement.clone();
+                }
+            }
+            openapi::RefOr::T(Schema::Object(object)) => {
+                object
+                    .properties
+                    .values_mut()
+                    .for_each(|property| replace_references(property, replacements));
+                if let Some(AdditionalProperties::RefOr(additional_properties)) =
+                    object.additional_properties.as_deref_mut()
+                {
+                    replace_references(additional_properties, replacements)
+                }
+            }
+            openapi::RefOr::T(Schema::Array(array)) => {
+                replace_references(&mut array.items, replacements);
+                array
+                    .prefix_items
+                    .iter_mut()
+                    .for_each(|item| replace_references(item, replacements));
+            }
+            openapi::RefOr::T(Schema::OneOf(one_of)) => one_of
+                .items
+                .iter_mut()
+                .for_each(|item| replace_references(item, replacements)),
+            openapi::RefOr::T(Schema::AllOf(all_of)) => all_of
+                .items
+                .iter_mut()
+                .for_each(|item| replace_references(item, replacements)),
+            openapi::RefOr::T(Schema::AnyOf(any_of)) => any_of
+                .items
+                .iter_mut()
+                .for_each(|item| replace_references(item, replacements)),
+        }
+    }
+
+    impl<T: ?Sized> CollectNothing for &SchemaCollector<T> {}
+
+    /// Prepend `context_path` to the `path` of an operation without duplicating the `/` separator
+    /// between them.
+    pub fn join_context_path(context_path: &str, path: &str) -> String {
+        let context_path = if path.starts_with('/') {
+            context_path.trim_end_matches('/')
+        } else {
+            context_path
+        };
+
+        format!("{context_path}{path}")
+    }
+
+    /// Replace tags of every operation of the `openapi` with the given `tags`. Used to re-tag
+    /// operations of documents nested with _`nest((path = ..., api = ..., tags = [...]))`_.
+    pub fn retag_operations(mut openapi: openapi::OpenApi, tags: &[&str]) -> openapi::OpenApi {
+        for path_item in openapi.paths.paths.values_mut() {
+            for operation in path_item.operations.values_mut() {
+                operation.tags = Some(tags.iter().map(|tag| tag.to_string()).collect());
+            }
+        }
+
+        openapi
+    }
+
+    /// Name of the schema of `T` along with the type name of `T`. Generic types with aliases are
+    /// added to the components with the names of the aliases thus `None` is returned for them.
+    pub fn schema_name_of<'s, T: ToSchema<'s>>() -> Option<(&'s str, &'static str)> {
+        T::aliases()
+            .is_empty()
+            .then(|| (T::schema_name(), std::any::type_name::<T>()))
+    }
+
+    /// Panic if two different types listed in _`components(schemas(...))`_ share the same schema
+    /// name since the schema of the latter would silently overwrite the former one.
+    pub fn assert_unique_schema_names(schemas: &[Option<(&str, &str)>]) {
+        let schemas = schemas.iter().flatten().collect::<Vec<_>>();
+        for (index, (name, type_name)) in schemas.iter().enumerate() {
+            if let Some((_, other_type_name)) =
+                schemas[..index]
+                    .iter()
+                    .find(|(other_name, other_type_name)| {
+                        other_name == name && other_type_name != type_name
+                    })
+            {
+                panic!(
+                    "schema name `{name}` is shared by `{other_type_name}` and `{type_name}` in `components(schemas(...))`, \
+                    give one of them a unique name with `#[schema(as = ...)]` or `#[schema(rename = \"...\")]`"
+                );
+            }
+        }
+    }
+
+    thread_local! {
+        /// Schema names and type names of the schemas collected within [`collect_type_names`].
+        static COLLECTED_TYPE_NAMES: RefCell<Option<Vec<(String, &'static str)>>> =
+            const { RefCell::new(None) };
+    }
+
+    fn record_type_name<T: ?Sized>(name: &str) {
+        COLLECTED_TYPE_NAMES.with_borrow_mut(|type_names| {
+            if let Some(type_names) = type_names {
+                type_names.push((name.to_string(), std::any::type_name::<T>()));
+            }
+        })
+    }
+
+    /// Record the type names of the schemas collected by `collect` along with the names of the
+    /// schemas. Types collected with the name of an already collected schema are recorded only
+    /// when their schema differs from the collected one.
+    pub fn collect_type_names(collect: impl FnOnce()) -> Vec<(String, &'static str)> {
+        COLLECTED_TYPE_NAMES.set(Some(Vec::new()));
+        collect();
+
+        COLLECTED_TYPE_NAMES.take().unwrap_or_default()
+    }
+
+    /// Add the `schemas` collected from the paths and the component schemas to the `components`.
+    /// Explicitly declared schemas take precedence over the collected ones. Panic if schemas of
+    /// different types are collected with the same name or if a collected schema differs from
+    /// the schema declared in _`components(schemas(...))`_ with the same name.
+    pub fn add_collected_schemas(
+        components: &mut openapi::Components,
+        component_schemas: &[Option<(&str, &str)>],
+        schemas: Vec<(String, openapi::RefOr<openapi::schema::Schema>)>,
+        type_names: &[(String, &'static str)],
+    ) {
+        for (name, schema) in schemas {
+            let declared_type_name = component_schemas
+                .iter()
+                .flatten()
+                .find(|(declared_name, _)| *declared_name == name)
+                .map(|(_, type_name)| *type_name);
+            let mut collided_type_names = Vec::<&str>::new();
+            for (_, type_name) in type_names
+                .iter()
+                .filter(|(collected_name, _)| *collected_name == name)
+            {
+                if !collided_type_names.contains(type_name) {
+                    collided_type_names.push(type_name);
+                }
+            }
+
+            match (components.schemas.get(&name), declared_type_name) {
+                (Some(declared), Some(declared_type_name)) if *declared != schema => {
+                    collided_type_names.retain(|type_name| *type_name != declared_type_name);
+                    collided_type_names.insert(0, declared_type_name);
+                    panic_on_collision(&name, &collided_type_names)
+                }
+                (Some(_), _) => (),
+                (None, _) if collided_type_names.len() > 1 => {
+                    panic_on_collision(&name, &collided_type_names)
+                }
+                (None, _) => {
+                    components.schemas.insert(name, schema);
+                }
+            }
+        }
+    }
+
+    /// Panic since the schema of only one of the types sharing the schema `name` would be added
+    /// to the components.
+    fn panic_on_collision(name: &str, type_names: &[&str]) -> ! {
+        let type_names = type_names
+            .iter()
+            .map(|type_name| format!("`{type_name}`"))
+            .collect::<Vec<_>>()
+            .join(" and ");
+        panic!(
+            "schema name `{name}` is shared by different schemas of {type_names} collected to the components, \
+            give one of the types a unique name with `#[schema(as = ...)]` or `#[schema(rename = \"...\")]`"
+        );
+    }
+
+    /// Functions of a [`Path`][crate::Path] referenced in _`#[openapi(paths(...))]`_. Paths of
+    /// associated functions are constants of this type since types cannot be declared within
+    /// `impl` blocks.
+    #[derive(Clone, Copy)]
+    pub struct PathFns {
+        pub path: fn() -> String,
+        pub path_item: fn(Option<&str>) -> openapi::path::PathItem,
+        pub schemas: fn(&mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>),
+        pub api_version: fn() -> Option<&'static str>,
+        pub is_hidden: fn() -> bool,
+    }
+
+    impl PathFns {
+        pub const fn new<P: crate::Path>() -> Self {
+            Self {
+                path: P::path,
+                path_item: P::path_item,
+                schemas: P::schemas,
+                api_version: P::api_version,
+                is_hidden: P::is_hidden,
+            }
+        }
+
+        /// Check whether the path is included to the document of the `api_version`. Hidden paths
+        /// are never included. Paths without a version belong to every version and every path
+        /// belongs to `None` version.
+        pub fn is_included(&self, api_version: Option<&str>) -> bool {
+            !(self.is_hidden)()
+                && match ((self.api_version)(), api_version) {
+                    (Some(path_version), Some(api_version)) => path_version == api_version,
+                    _ => true,
+                }
+        }
+    }
+
+    /// Resolve [`PathFns`] of the `__path_...` struct of a function or the `__path_...` constant
+    /// of an associated function.
+    pub trait IntoPathFns {
+        fn into_path_fns(self) -> PathFns;
+    }
+
+    impl<P: crate::Path> IntoPathFns for P {
+        fn into_path_fns(self) -> PathFns {
+            PathFns::new::<P>()
+        }
+    }
+
+    impl IntoPathFns for PathFns {
+        fn into_path_fns(self) -> PathFns {
+            self
+        }
+    }
+
+    pub fn path_fns<P: IntoPathFns>(path: P) -> PathFns {
+        path.into_path_fns()
+    }
+
+    #[cfg(feature = "register_paths")]
+    pub use inventory;
+
+    /// Path registered with _`#[utoipa::path(register, ...)]`_ to be collected with
+    /// [`OpenApi::gather`][crate::OpenApi::gather].
+    #[cfg(feature = "register_paths")]
+    pub struct RegisteredPath {
+        pub(crate) path: fn() -> String,
+        pub(crate) path_item: fn(Option<&str>) -> openapi::path::PathItem,
+        pub(crate) schemas: fn(&mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>),
+        pub(crate) module_path: &'static str,
+        pub(crate) is_hidden: fn() -> bool,
+    }
+
+    #[cfg(feature = "register_paths")]
+    impl RegisteredPath {
+        pub const fn new<P: crate::Path>(module_path: &'static str) -> Self {
+            Self {
+                path: P::path,
+                path_item: P::path_item,
+                schemas: P::schemas,
+                module_path,
+                is_hidden: P::is_hidden,
+            }
+        }
+    }
+
+    #[cfg(feature = "register_paths")]
+    inventory::collect!(RegisteredPath);
+}
+
 /// Trait used to implement only _`Schema`_ part of the OpenAPI doc.
 ///
 /// This trait is by default implemented for Rust [`primitive`][primitive] types and some well known types like
@@ -525,6 +1433,12 @@ impl_partial_schema_primitive!(
 
 impl_partial_schema!(&str);
 
+#[rustfmt::skip]
+impl_partial_schema_primitive!(
+    std::net::IpAddr, std::net::Ipv4Addr, std::net::Ipv6Addr, std::net::SocketAddr,
+    std::net::SocketAddrV4, std::net::SocketAddrV6, std::path::Path, std::path::PathBuf
+);
+
 impl<'__s, T: ToSchema<'__s>> PartialSchema for Vec<T> {
     fn schema() -> openapi::RefOr<openapi::schema::Schema> {
         schema!(#[inline] Vec<T>).into()
@@ -633,6 +1547,46 @@ impl<'__s, K: PartialSchema, V: ToSchema<'__s>> PartialSchema for Option<HashMap
     }
 }
 
+impl<'__s, T: ToSchema<'__s>> PartialSchema for BTreeSet<T> {
+    fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+        schema!(
+            #[inline]
+            BTreeSet<T>
+        )
+        .into()
+    }
+}
+
+impl<'__s, T: ToSchema<'__s>> PartialSchema for Option<BTreeSet<T>> {
+    fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+        schema!(
+            #[inline]
+            Option<BTreeSet<T>>
+        )
+        .into()
+    }
+}
+
+impl<'__s, T: ToSchema<'__s>> PartialSchema for HashSet<T> {
+    fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+        schema!(
+            #[inline]
+            HashSet<T>
+        )
+        .into()
+    }
+}
+
+impl<'__s, T: ToSchema<'__s>> PartialSchema for Option<HashSet<T>> {
+    fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+        schema!(
+            #[inline]
+            Option<HashSet<T>>
+        )
+        .into()
+    }
+}
+
 /// Trait for implementing OpenAPI PathItem object with path.
 ///
 /// This trait is implemented via [`#[utoipa::path(...)]`][derive] attribute macro and there
@@ -717,7 +1671,31 @@ impl<'__s, K: PartialSchema, V: ToSchema<'__s>> PartialSchema for Option<HashMap
 pub trait Path {
     fn path() -> String;
 
+    /// Build the [`openapi::path::PathItem`] of the path. The `default_tag` is used as tag of the
+    /// operation when no tag is explicitly defined. `None` defaults to _`crate`_ tag and empty
+    /// `default_tag` leaves the operation without a default tag.
     fn path_item(default_tag: Option<&str>) -> openapi::path::PathItem;
+
+    /// Collect schemas of request bodies, responses and parameters of the path to `schemas`
+    /// along with the schemas they reference. See [`ToSchema::schemas`] for more details.
+    fn schemas(schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>) {
+        let _ = schemas;
+    }
+
+    /// Version of the API the path belongs to. Paths without a version belong to every version.
+    /// See [`OpenApi::openapi_for_version`] for more details.
+    fn api_version() -> Option<&'static str> {
+        None
+    }
+
+    /// Whether the path is left out from the [`OpenApi`] documents. Hidden paths are still
+    /// compiled but not published. See _`hidden`_ and _`include_if`_ of
+    /// [`#[utoipa::path(...)]`][path] for more details.
+    ///
+    /// [path]: attr.path.html
+    fn is_hidden() -> bool {
+        false
+    }
 }
 
 /// Trait that allows OpenApi modification at runtime.
@@ -883,9 +1861,62 @@ pub trait IntoParams {
 ///     }
 /// }
 /// ```
+///
+/// # Sharing responses across crates
+///
+/// Types implementing [`IntoResponses`] can be published from a shared library crate as
+/// canonical response sets, e.g. the standard error envelope of an organization. Downstream
+/// services list the type within `responses(...)` of [`#[utoipa::path(...)]`][path] the same way
+/// as a local type, and the responses are merged with the other responses of the operation.
+///
+/// ```rust
+/// mod shared {
+///     # use utoipa::{IntoResponses, ToSchema};
+///     #[derive(ToSchema)]
+///     pub struct ErrorBody {
+///         message: String,
+///     }
+///
+///     #[derive(IntoResponses)]
+///     pub enum CommonErrors {
+///         /// Request is not authenticated.
+///         #[response(status = 401)]
+///         Unauthorized(ErrorBody),
+///
+///         /// Unexpected server error.
+///         #[response(status = 500)]
+///         Internal(ErrorBody),
+///     }
+/// }
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/pets",
+///     responses(
+///         (status = 200, description = "List pets"),
+///         shared::CommonErrors,
+///     )
+/// )]
+/// fn list_pets() {}
+/// ```
+///
+/// With _`auto_collect_schemas`_ feature the schemas referenced by the response bodies are
+/// collected by [`IntoResponses::schemas`] and registered to the [`OpenApi`] document along with
+/// the path.
+///
+/// [path]: attr.path.html
 pub trait IntoResponses {
     /// Returns an ordered map of response codes to responses.
     fn responses() -> BTreeMap<String, openapi::RefOr<openapi::response::Response>>;
+
+    /// Collect schemas referenced by the response bodies to `schemas` along with the schemas
+    /// they reference. See [`ToSchema::schemas`] for more details.
+    ///
+    /// With _`auto_collect_schemas`_ feature derive [`macro@IntoResponses`] implements this method
+    /// for response bodies referencing types implementing [`ToSchema`].
+    fn schemas(schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>) {
+        let _ = schemas;
+    }
 }
 
 #[cfg(feature = "auto_into_responses")]
@@ -896,6 +1927,11 @@ impl<T: IntoResponses, E: IntoResponses> IntoResponses for Result<T, E> {
 
         responses
     }
+
+    fn schemas(schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>) {
+        T::schemas(schemas);
+        E::schemas(schemas);
+    }
 }
 
 #[cfg(feature = "auto_into_responses")]
@@ -936,6 +1972,95 @@ pub trait ToResponse<'__r> {
     fn response() -> (&'__r str, openapi::RefOr<openapi::response::Response>);
 }
 
+/// This trait is implemented to document a type which represents a set of response headers which
+/// can be attached to multiple responses at once.
+///
+/// Type implementing [`ToHeader`] can be listed within _`headers(...)`_ of a response in
+/// [`#[utoipa::path(...)]`][path], [`#[derive(ToResponse)]`][to_response] and
+/// [`#[derive(IntoResponses)]`][into_responses] along with single header definitions.
+///
+/// _`ToHeader`_ trait can also be derived with [`#[derive(ToHeader)]`][derive].
+///
+/// # Examples
+///
+/// ```
+/// use utoipa::{
+///     openapi::{header::{Header, HeaderBuilder}, ObjectBuilder, RefOr, SchemaType},
+///     ToHeader,
+/// };
+///
+/// struct RateLimitHeaders;
+///
+/// impl ToHeader for RateLimitHeaders {
+///     fn headers() -> Vec<(String, RefOr<Header>)> {
+///         vec![(
+///             "x-rate-limit-limit".to_string(),
+///             HeaderBuilder::new()
+///                 .schema(ObjectBuilder::new().schema_type(SchemaType::Integer))
+///                 .description(Some("Allowed requests per hour"))
+///                 .into(),
+///         )]
+///     }
+/// }
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/pets",
+///     responses(
+///         (status = 200, description = "List pets", headers(RateLimitHeaders)),
+///     )
+/// )]
+/// fn list_pets() {}
+/// ```
+///
+/// [derive]: derive.ToHeader.html
+/// [path]: attr.path.html
+/// [to_response]: derive.ToResponse.html
+/// [into_responses]: derive.IntoResponses.html
+pub trait ToHeader {
+    /// Returns name and header pairs of the headers.
+    fn headers() -> Vec<(String, openapi::RefOr<openapi::header::Header>)>;
+}
+
+/// This trait is implemented for a type (like an enum) which can be used as response _`status`_
+/// in [`#[utoipa::path(...)]`][path] _`responses(...)`_ and [`#[derive(IntoResponses)]`][into_responses]
+/// instead of bare integer status codes.
+///
+/// # Examples
+///
+/// ```
+/// use utoipa::ToResponseStatus;
+///
+/// #[derive(Clone, Copy)]
+/// enum ApiStatus {
+///     NotFound = 404,
+///     Conflict = 409,
+/// }
+///
+/// impl ToResponseStatus for ApiStatus {
+///     fn to_response_status(&self) -> String {
+///         (*self as u16).to_string()
+///     }
+/// }
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/pets/{id}",
+///     responses(
+///         (status = ApiStatus::NotFound, description = "Pet not found"),
+///     )
+/// )]
+/// fn get_pet_by_id() {}
+/// ```
+///
+/// [path]: attr.path.html
+/// [into_responses]: derive.IntoResponses.html
+pub trait ToResponseStatus {
+    /// Returns the response status code e.g. _`"404"`_ or a status code range e.g. _`"4XX"`_
+    /// used as a key in [`openapi::response::Responses`].
+    fn to_response_status(&self) -> String;
+}
+
 #[cfg(test)]
 mod tests {
     use assert_json_diff::assert_json_eq;
@@ -943,7 +2068,44 @@ mod tests {
 
     use super::*;
 
-    #[cfg(not(feature = "non_strict_integers"))]
+    #[test]
+    fn test_openapi_cached_is_built_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static BUILT: AtomicUsize = AtomicUsize::new(0);
+
+        struct ApiDoc;
+
+        impl OpenApi for ApiDoc {
+            fn openapi() -> openapi::OpenApi {
+                BUILT.fetch_add(1, Ordering::SeqCst);
+                openapi::OpenApi::new(openapi::Info::new("cached", "1.0.0"), openapi::Paths::new())
+            }
+        }
+
+        struct OtherDoc;
+
+        impl OpenApi for OtherDoc {
+            fn openapi() -> openapi::OpenApi {
+                openapi::OpenApi::new(openapi::Info::new("other", "1.0.0"), openapi::Paths::new())
+            }
+        }
+
+        assert!(std::ptr::eq(
+            ApiDoc::openapi_cached(),
+            ApiDoc::openapi_cached()
+        ));
+        assert_eq!(ApiDoc::openapi_json(), ApiDoc::openapi().to_json().unwrap());
+        assert_eq!(ApiDoc::openapi_json(), ApiDoc::openapi_json());
+        assert_eq!(
+            BUILT.load(Ordering::SeqCst),
+            2,
+            "cached document should be built once besides the explicit openapi() call"
+        );
+        assert_eq!(OtherDoc::openapi_cached().info.title, "other");
+    }
+
+    #[cfg(not(any(feature = "non_strict_integers", feature = "large_numbers_as_string")))]
     #[test]
     fn test_partial_schema_strict_integers() {
         use assert_json_diff::{assert_json_matches, CompareMode, Config, NumericMode};
@@ -1003,6 +2165,34 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "large_numbers_as_string")]
+    #[test]
+    fn test_partial_schema_large_numbers_as_string() {
+        for (name, schema, value) in [
+            ("i64", i64::schema(), json!({"type": "string"})),
+            ("u64", u64::schema(), json!({"type": "string"})),
+            ("i128", i128::schema(), json!({"type": "string"})),
+            ("u128", u128::schema(), json!({"type": "string"})),
+            (
+                "Option<u64>",
+                Option::<u64>::schema(),
+                json!({"type": "string", "nullable": true}),
+            ),
+            (
+                "i32",
+                i32::schema(),
+                json!({"type": "integer", "format": "int32"}),
+            ),
+        ] {
+            println!(
+                "{name}: {json}",
+                json = serde_json::to_string(&schema).unwrap()
+            );
+            let schema = serde_json::to_value(schema).unwrap();
+            assert_json_eq!(schema, value);
+        }
+    }
+
     #[cfg(feature = "non_strict_integers")]
     #[test]
     fn test_partial_schema_non_strict_integers() {
@@ -1085,4 +2275,19 @@ mod tests {
             assert_json_eq!(schema, value);
         }
     }
+
+    #[test]
+    fn test_partial_schema_sets_are_unique() {
+        for (name, schema) in [
+            ("HashSet", HashSet::<TupleUnit>::schema()),
+            ("BTreeSet", BTreeSet::<TupleUnit>::schema()),
+        ] {
+            let schema = serde_json::to_value(schema).unwrap();
+            assert_eq!(schema["type"], "array", "{name} should be array");
+            assert_eq!(
+                schema["uniqueItems"], true,
+                "{name} should have unique items"
+            );
+        }
+    }
 }
@@ -1,19 +1,25 @@
 //! Rust implementation of Openapi Spec V3.
 
 use serde::{de::Error, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
-use std::fmt::Formatter;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Formatter,
+};
+
+use self::security::SecurityScheme;
 
 pub use self::{
     content::{Content, ContentBuilder},
+    diff::diff,
     external_docs::ExternalDocs,
     header::{Header, HeaderBuilder},
     info::{Contact, ContactBuilder, Info, InfoBuilder, License, LicenseBuilder},
     path::{PathItem, PathItemType, Paths, PathsBuilder},
     response::{Response, ResponseBuilder, Responses, ResponsesBuilder},
     schema::{
-        AllOf, AllOfBuilder, Array, ArrayBuilder, Components, ComponentsBuilder, Discriminator,
-        KnownFormat, Object, ObjectBuilder, OneOf, OneOfBuilder, Ref, Schema, SchemaFormat,
-        SchemaType, ToArray,
+        AllOf, AllOfBuilder, AnyOf, AnyOfBuilder, Array, ArrayBuilder, Components,
+        ComponentsBuilder, Discriminator, KnownFormat, Object, ObjectBuilder, OneOf, OneOfBuilder,
+        Ref, Schema, SchemaFormat, SchemaType, ToArray,
     },
     security::SecurityRequirement,
     server::{Server, ServerBuilder, ServerVariable, ServerVariableBuilder},
@@ -21,11 +27,13 @@ pub use self::{
 };
 
 pub mod content;
+pub mod diff;
 pub mod encoding;
 pub mod example;
 pub mod external_docs;
 pub mod header;
 pub mod info;
+pub mod link;
 pub mod path;
 pub mod request_body;
 pub mod response;
@@ -33,6 +41,7 @@ pub mod schema;
 pub mod security;
 pub mod server;
 pub mod tag;
+pub mod validation;
 pub mod xml;
 
 builder! {
@@ -83,7 +92,6 @@ builder! {
         /// Available paths and operations for the API.
         ///
         /// See more details at <https://spec.openapis.org/oas/latest.html#paths-object>.
-        #[serde(flatten)]
         pub paths: Paths,
 
         /// Holds various reusable schemas for the OpenAPI document.
@@ -113,6 +121,29 @@ builder! {
         /// See more details at <https://spec.openapis.org/oas/latest.html#external-documentation-object>.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
+
+        /// Default value for the `$schema` keyword within [`Schema`]s of the document. This is
+        /// only supported by OpenAPI 3.1.
+        ///
+        /// See more details at <https://spec.openapis.org/oas/latest.html#openapi-object>.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub json_schema_dialect: Option<String>,
+
+        /// Incoming webhooks that may be received as part of this API. Key of the map is unique
+        /// name of the webhook. This is only supported by OpenAPI 3.1.
+        ///
+        /// See more details at <https://spec.openapis.org/oas/latest.html#openapi-object>.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub webhooks: BTreeMap<String, PathItem>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "serialize_extensions",
+            deserialize_with = "deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
@@ -154,6 +185,190 @@ impl OpenApi {
         serde_yaml::to_string(self)
     }
 
+    /// Write this [`OpenApi`] to a file in given `path`.
+    ///
+    /// The file is written as YAML when `path` has _`yaml`_ or _`yml`_ extension and as pretty
+    /// JSON otherwise. Writing YAML requires _`yaml`_ feature. Missing parent directories are
+    /// created and the file is only written when its content has changed, so the modification
+    /// time of an up to date file is left untouched.
+    ///
+    /// See [`export!`][crate::export] for writing the document during `cargo test`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, Paths};
+    /// let openapi = OpenApi::new(Info::new("pet api", "1.0.0"), Paths::new());
+    /// let path = std::env::temp_dir().join("utoipa-write-to-file-example.json");
+    ///
+    /// openapi.write_to_file(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(&path).unwrap(), openapi.to_pretty_json().unwrap());
+    /// ```
+    pub fn write_to_file<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let is_yaml = path
+            .extension()
+            .is_some_and(|extension| extension == "yaml" || extension == "yml");
+
+        let content = if is_yaml {
+            #[cfg(feature = "yaml")]
+            {
+                self.to_yaml().map_err(std::io::Error::other)?
+            }
+            #[cfg(not(feature = "yaml"))]
+            {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("writing {} requires `yaml` feature", path.display()),
+                ));
+            }
+        } else {
+            self.to_pretty_json().map_err(std::io::Error::other)?
+        };
+
+        if std::fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, content)
+    }
+
+    /// Return _`Option`_ of reference to [`PathItem`] by given relative path _`P`_ or `None` if
+    /// not found. See [`Paths::get_path_item`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, Paths, OpenApi};
+    /// # let openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    /// let path_item = openapi.get_path_item("/pets");
+    /// ```
+    pub fn get_path_item<P: AsRef<str>>(&self, path: P) -> Option<&PathItem> {
+        self.paths.get_path_item(path)
+    }
+
+    /// Return _`Option`_ of mutable reference to [`PathItem`] by given relative path _`P`_ or
+    /// `None` if not found. See [`Paths::get_path_item_mut`].
+    pub fn get_path_item_mut<P: AsRef<str>>(&mut self, path: P) -> Option<&mut PathItem> {
+        self.paths.get_path_item_mut(path)
+    }
+
+    /// Return _`Option`_ of mutable reference to the schema registered with given `name` to the
+    /// [`Components`] or `None` if not found. See [`Components::schema_mut`].
+    pub fn schema_mut<N: AsRef<str>>(&mut self, name: N) -> Option<&mut RefOr<Schema>> {
+        self.components
+            .as_mut()
+            .and_then(|components| components.schema_mut(name))
+    }
+
+    /// Return the value at [JSON pointer][json_pointer] of the serialized [`OpenApi`] or `None`
+    /// if there is no value at the `pointer`.
+    ///
+    /// This allows reading any part of the document without navigating through the nested
+    /// structs e.g. in tests. The value is serialized from the document on every call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, Paths, OpenApi};
+    /// let openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    ///
+    /// assert_eq!(openapi.pointer("/info/title"), Some(serde_json::json!("pet api")));
+    /// assert_eq!(openapi.pointer("/paths/~1pets"), None);
+    /// ```
+    ///
+    /// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub fn pointer(&self, pointer: &str) -> Option<serde_json::Value> {
+        serde_json::to_value(self)
+            .ok()?
+            .pointer_mut(pointer)
+            .map(serde_json::Value::take)
+    }
+
+    /// Modify the value at [JSON pointer][json_pointer] of the serialized [`OpenApi`] with given
+    /// function `modify` and replace `self` with the modified document.
+    ///
+    /// This allows tweaking any part of the document e.g. in [`Modify`][modify] implementations
+    /// without pattern matching through the nested structs. Returns `Ok(false)` leaving `self`
+    /// untouched if there is no value at the `pointer` and an error if the modified document is
+    /// not a valid [`OpenApi`].
+    ///
+    /// # Examples
+    ///
+    /// _**Add an extension to every response of `GET /pets` operation.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, PathItem, PathItemType, Paths, ResponseBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// # let mut paths = Paths::new();
+    /// # paths.add_path(
+    /// #     "/pets",
+    /// #     PathItem::new(
+    /// #         PathItemType::Get,
+    /// #         OperationBuilder::new().response("200", ResponseBuilder::new().description("Pets")),
+    /// #     ),
+    /// # );
+    /// let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), paths);
+    ///
+    /// let modified = openapi
+    ///     .modify_pointer("/paths/~1pets/get/responses/200/description", |description| {
+    ///         *description = serde_json::json!("List of pets");
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert!(modified);
+    /// assert_eq!(
+    ///     openapi.pointer("/paths/~1pets/get/responses/200/description"),
+    ///     Some(serde_json::json!("List of pets"))
+    /// );
+    /// ```
+    ///
+    /// [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    /// [modify]: ../trait.Modify.html
+    pub fn modify_pointer<F: FnOnce(&mut serde_json::Value)>(
+        &mut self,
+        pointer: &str,
+        modify: F,
+    ) -> Result<bool, serde_json::Error> {
+        let mut document = serde_json::to_value(&*self)?;
+        let Some(value) = document.pointer_mut(pointer) else {
+            return Ok(false);
+        };
+        modify(value);
+        *self = serde_json::from_value(document)?;
+
+        Ok(true)
+    }
+
+    /// Add [`SecurityScheme`] to [`Components`] of this [`OpenApi`].
+    ///
+    /// [`Components`] will be created if they do not exist yet. Name of the [`SecurityScheme`]
+    /// is used when referencing it from [`SecurityRequirement`]s. Existing [`SecurityScheme`] with
+    /// the same name will be replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, Paths, OpenApi};
+    /// # use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+    /// let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    /// openapi.add_security_scheme(
+    ///     "api_key",
+    ///     SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key"))),
+    /// );
+    /// ```
+    pub fn add_security_scheme<N: Into<String>, S: Into<SecurityScheme>>(
+        &mut self,
+        name: N,
+        security_scheme: S,
+    ) {
+        self.components
+            .get_or_insert(Components::default())
+            .add_security_scheme(name, security_scheme);
+    }
+
     /// Merge `other` [`OpenApi`] moving `self` and returning combined [`OpenApi`].
     ///
     /// In functionality wise this is exactly same as calling [`OpenApi::merge`] but but provides
@@ -163,6 +378,16 @@ impl OpenApi {
         self
     }
 
+    /// Merge `other` [`OpenApi`] with given [`MergePolicy`] moving `self` and returning combined
+    /// [`OpenApi`].
+    ///
+    /// In functionality wise this is exactly same as calling [`OpenApi::merge_with`] but provides
+    /// leaner API for chaining method calls.
+    pub fn merge_from_with(mut self, other: OpenApi, policy: MergePolicy) -> OpenApi {
+        self.merge_with(other, policy);
+        self
+    }
+
     /// Merge `other` [`OpenApi`] consuming it and resuming it's content.
     ///
     /// Merge function will take all `self` nonexistent _`servers`, `paths`, `schemas`, `responses`,
@@ -171,47 +396,77 @@ impl OpenApi {
     /// This function performs a shallow comparison for `paths`, `schemas`, `responses` and
     /// `security schemes` which means that only _`name`_ and _`path`_ is used for comparison. When
     /// match occurs the whole item will be ignored from merged results. Only items not
-    /// found will be appended to `self`.
+    /// found will be appended to `self`. Operations of a path found from both are merged by
+    /// the http method in same manner. Path level _`summary`_ and _`description`_ of `other` are
+    /// only used when not defined in `self`, _`parameters`_ are compared by their _`name`_ and
+    /// location and _`servers`_ by the whole item.
+    ///
+    /// _`webhooks`_ and root level _`extensions`_ are compared by their name in same manner.
     ///
     /// For _`servers`_, _`tags`_ and _`security_requirements`_ the whole item will be used for
     /// comparison. Items not found from `self` will be appended to `self`.
     ///
+    /// This is same as calling [`OpenApi::merge_with`] with [`MergePolicy::KeepExisting`].
+    ///
     /// **Note!** `info`, `openapi` and `external_docs` will not be merged.
-    pub fn merge(&mut self, mut other: OpenApi) {
+    pub fn merge(&mut self, other: OpenApi) {
+        self.merge_with(other, MergePolicy::KeepExisting)
+    }
+
+    /// Merge `other` [`OpenApi`] consuming it and resolving conflicting items with given
+    /// [`MergePolicy`].
+    ///
+    /// Items are compared same way as in [`OpenApi::merge`]. With [`MergePolicy::KeepExisting`]
+    /// conflicting items of `other` are ignored and with [`MergePolicy::Overwrite`] conflicting
+    /// items of `other` will replace the items of `self`. With [`MergePolicy::Overwrite`] _`tags`_
+    /// are compared by their _`name`_ so that a tag of `other` replaces the tag of `self`
+    /// with the same name. Path level _`summary`_, _`description`_ and _`parameters`_ of a path
+    /// found from both are resolved with the policy as well.
+    ///
+    /// # Examples
+    ///
+    /// _**Merge hand written spec over the generated one.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Info, MergePolicy, OpenApi, Paths};
+    /// let generated = OpenApi::new(Info::new("generated api", "1.0.0"), Paths::new());
+    /// let legacy: OpenApi = serde_json::from_str(r#"{
+    ///     "openapi": "3.0.3",
+    ///     "info": { "title": "legacy api", "version": "0.1.0" },
+    ///     "paths": {
+    ///         "/legacy": { "get": { "responses": { "200": { "description": "ok" } } } }
+    ///     }
+    /// }"#).unwrap();
+    ///
+    /// let openapi = generated.merge_from_with(legacy, MergePolicy::Overwrite);
+    /// assert!(openapi.paths.get_path_item("/legacy").is_some());
+    /// ```
+    ///
+    /// **Note!** `info`, `openapi` and `external_docs` will not be merged.
+    pub fn merge_with(&mut self, mut other: OpenApi, policy: MergePolicy) {
         if let Some(other_servers) = &mut other.servers {
             let servers = self.servers.get_or_insert(Vec::new());
             other_servers.retain(|server| !servers.contains(server));
             servers.append(other_servers);
         }
 
-        if !other.paths.paths.is_empty() {
-            for (path, that) in &mut other.paths.paths {
-                if let Some(this) = self.paths.get_path_item(path) {
-                    that.operations.extend(this.operations.clone());
-                }
+        for (path, that) in other.paths.paths {
+            if let Some(this) = self.paths.paths.get_mut(&path) {
+                policy.merge_path_item(this, that);
+            } else {
+                self.paths.paths.insert(path, that);
             }
-            self.paths.paths.extend(other.paths.paths);
-        };
+        }
 
         if let Some(other_components) = &mut other.components {
             let components = self.components.get_or_insert(Components::default());
 
-            other_components
-                .schemas
-                .retain(|name, _| !components.schemas.contains_key(name));
-            components.schemas.append(&mut other_components.schemas);
-
-            other_components
-                .responses
-                .retain(|name, _| !components.responses.contains_key(name));
-            components.responses.append(&mut other_components.responses);
-
-            other_components
-                .security_schemes
-                .retain(|name, _| !components.security_schemes.contains_key(name));
-            components
-                .security_schemes
-                .append(&mut other_components.security_schemes);
+            policy.merge_map(&mut components.schemas, &mut other_components.schemas);
+            policy.merge_map(&mut components.responses, &mut other_components.responses);
+            policy.merge_map(
+                &mut components.security_schemes,
+                &mut other_components.security_schemes,
+            );
+            policy.merge_map(&mut components.headers, &mut other_components.headers);
         }
 
         if let Some(other_security) = &mut other.security {
@@ -222,13 +477,183 @@ impl OpenApi {
 
         if let Some(other_tags) = &mut other.tags {
             let tags = self.tags.get_or_insert(Vec::new());
-            other_tags.retain(|tag| !tags.contains(tag));
+            match policy {
+                MergePolicy::KeepExisting => other_tags.retain(|tag| !tags.contains(tag)),
+                MergePolicy::Overwrite => {
+                    tags.retain(|tag| !other_tags.iter().any(|other| other.name == tag.name))
+                }
+            }
             tags.append(other_tags);
         }
+
+        policy.merge_map(&mut self.webhooks, &mut other.webhooks);
+
+        if let Some(other_extensions) = &mut other.extensions {
+            let extensions = self.extensions.get_or_insert(HashMap::new());
+            for (name, value) in other_extensions.drain() {
+                if policy == MergePolicy::Overwrite || !extensions.contains_key(&name) {
+                    extensions.insert(name, value);
+                }
+            }
+        }
+    }
+
+    /// Nest `other` [`OpenApi`] to this [`OpenApi`].
+    ///
+    /// Nesting allows splitting the [`OpenApi`] to multiple modules and then composing them
+    /// together under a common path. Paths of `other` [`OpenApi`] will be prefixed with the given
+    /// `path` and then merged to `self` same way as in [`OpenApi::merge`].
+    ///
+    /// Paths are concatenated as is, thus the `path` should not end with a slash as paths of
+    /// `other` typically start with one e.g. `"/api/v1/pets"` + `"/{id}"`.
+    ///
+    /// # Examples
+    ///
+    /// _**Nest pet api under `/api/v1/pets`.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, OpenApiBuilder, Paths, PathsBuilder, PathItem, PathItemType};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let api = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+    /// let pet_api = OpenApiBuilder::new()
+    ///     .paths(PathsBuilder::new().path(
+    ///         "/{id}",
+    ///         PathItem::new(PathItemType::Get, OperationBuilder::new()),
+    ///     ))
+    ///     .build();
+    ///
+    /// let nested = api.nest("/api/v1/pets", pet_api);
+    /// assert!(nested.paths.get_path_item("/api/v1/pets/{id}").is_some());
+    /// ```
+    pub fn nest<P: Into<String>, O: Into<OpenApi>>(self, path: P, other: O) -> OpenApi {
+        self.nest_with_path_composer(path, other, |base, path| format!("{base}{path}"))
+    }
+
+    /// Nest `other` [`OpenApi`] to this [`OpenApi`] with custom path composer.
+    ///
+    /// This is same as [`OpenApi::nest`] but allows defining how the nested paths are composed
+    /// with the given `composer` function. The function is called with the nesting `path` and
+    /// the path of `other` [`OpenApi`] and it must return the new path.
+    ///
+    /// # Examples
+    ///
+    /// _**Nest paths without duplicate slashes.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, Paths};
+    /// # let api = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+    /// # let pet_api = OpenApi::new(Info::new("pet api", "1.0.0"), Paths::new());
+    /// let nested = api.nest_with_path_composer("/api/v1/pets/", pet_api, |base, path| {
+    ///     format!("{}/{}", base.trim_end_matches('/'), path.trim_start_matches('/'))
+    /// });
+    /// ```
+    pub fn nest_with_path_composer<
+        P: Into<String>,
+        O: Into<OpenApi>,
+        F: Fn(&str, &str) -> String,
+    >(
+        mut self,
+        path: P,
+        other: O,
+        composer: F,
+    ) -> OpenApi {
+        let path: String = path.into();
+        let mut other: OpenApi = other.into();
+
+        other.paths.paths = other
+            .paths
+            .paths
+            .into_iter()
+            .map(|(item_path, item)| (composer(&path, &item_path), item))
+            .collect();
+
+        self.merge(other);
+        self
+    }
+}
+
+/// Defines how conflicting items are resolved when merging [`OpenApi`] documents with
+/// [`OpenApi::merge_with`].
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum MergePolicy {
+    /// Keep the existing item and ignore the conflicting item being merged. This is the default.
+    #[default]
+    KeepExisting,
+    /// Replace the existing item with the conflicting item being merged.
+    Overwrite,
+}
+
+impl MergePolicy {
+    fn merge_map<V>(self, this: &mut BTreeMap<String, V>, other: &mut BTreeMap<String, V>) {
+        if self == MergePolicy::KeepExisting {
+            other.retain(|name, _| !this.contains_key(name));
+        }
+        this.append(other);
+    }
+
+    fn merge_option<T>(self, this: &mut Option<T>, other: Option<T>) {
+        if other.is_some() && (self == MergePolicy::Overwrite || this.is_none()) {
+            *this = other;
+        }
+    }
+
+    fn merge_path_item(self, this: &mut PathItem, that: PathItem) {
+        self.merge_option(&mut this.summary, that.summary);
+        self.merge_option(&mut this.description, that.description);
+
+        if let Some(mut other_servers) = that.servers {
+            let servers = this.servers.get_or_insert(Vec::new());
+            other_servers.retain(|server| !servers.contains(server));
+            servers.append(&mut other_servers);
+        }
+
+        if let Some(mut other_parameters) = that.parameters {
+            let parameters = this.parameters.get_or_insert(Vec::new());
+            let is_same = |a: &path::Parameter, b: &path::Parameter| {
+                a.name == b.name && a.parameter_in == b.parameter_in
+            };
+            match self {
+                MergePolicy::KeepExisting => other_parameters
+                    .retain(|other| !parameters.iter().any(|parameter| is_same(parameter, other))),
+                MergePolicy::Overwrite => parameters.retain(|parameter| {
+                    !other_parameters
+                        .iter()
+                        .any(|other| is_same(parameter, other))
+                }),
+            }
+            parameters.append(&mut other_parameters);
+        }
+
+        if let Some(other_extensions) = that.extensions {
+            let extensions = this.extensions.get_or_insert(HashMap::new());
+            for (name, value) in other_extensions {
+                match self {
+                    MergePolicy::KeepExisting => {
+                        extensions.entry(name).or_insert(value);
+                    }
+                    MergePolicy::Overwrite => {
+                        extensions.insert(name, value);
+                    }
+                }
+            }
+        }
+
+        match self {
+            MergePolicy::KeepExisting => {
+                for (path_item_type, operation) in that.operations {
+                    this.operations.entry(path_item_type).or_insert(operation);
+                }
+            }
+            MergePolicy::Overwrite => this.operations.extend(that.operations),
+        }
     }
 }
 
 impl OpenApiBuilder {
+    /// Add [`OpenApiVersion`] of the document.
+    pub fn openapi(mut self, openapi: OpenApiVersion) -> Self {
+        set_value!(self openapi openapi)
+    }
+
     /// Add [`Info`] metadata of the API.
     pub fn info<I: Into<Info>>(mut self, info: I) -> Self {
         set_value!(self info info.into())
@@ -266,6 +691,32 @@ impl OpenApiBuilder {
     pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
         set_value!(self external_docs external_docs)
     }
+
+    /// Add default JSON Schema dialect for [`Schema`]s of the document. This is only supported
+    /// by OpenAPI 3.1.
+    pub fn json_schema_dialect<S: Into<String>>(mut self, json_schema_dialect: Option<S>) -> Self {
+        set_value!(self json_schema_dialect json_schema_dialect.map(|dialect| dialect.into()))
+    }
+
+    /// Add iterator of _`(N, P)`_ where `N` is name of the webhook and `P` is [`PathItem`]
+    /// describing the request sent by the webhook. This is only supported by OpenAPI 3.1.
+    pub fn webhooks<I: IntoIterator<Item = (N, P)>, N: Into<String>, P: Into<PathItem>>(
+        mut self,
+        webhooks: I,
+    ) -> Self {
+        self.webhooks.extend(
+            webhooks
+                .into_iter()
+                .map(|(name, path_item)| (name.into(), path_item.into())),
+        );
+
+        self
+    }
+
+    /// Add root level extensions of the document.
+    pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 /// Represents available [OpenAPI versions][version].
@@ -277,11 +728,20 @@ pub enum OpenApiVersion {
     /// Will serialize to `3.0.3` the latest from 3.0 serde.
     #[serde(rename = "3.0.3")]
     Version3,
+    /// Will serialize to `3.1.0` the latest from 3.1 serde.
+    #[serde(rename = "3.1.0")]
+    Version31,
 }
 
 impl Default for OpenApiVersion {
+    /// Defaults to [`OpenApiVersion::Version3`] or to [`OpenApiVersion::Version31`] if
+    /// _`openapi_3_1`_ feature is enabled.
     fn default() -> Self {
-        Self::Version3
+        if cfg!(feature = "openapi_3_1") {
+            Self::Version31
+        } else {
+            Self::Version3
+        }
     }
 }
 
@@ -296,7 +756,7 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
             type Value = OpenApiVersion;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                formatter.write_str("a version string in 3, 3.0, or 3.0.x format")
+                formatter.write_str("a version string in 3, 3.0, 3.0.x, 3.1 or 3.1.x format")
             }
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -320,6 +780,7 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
 
                 Ok(match (parts[0], parts.get(1).copied().unwrap_or("0")) {
                     ("3", "0") => OpenApiVersion::Version3,
+                    ("3", "1") => OpenApiVersion::Version31,
                     _ => return Err(E::custom(format!("Unsupported version: {}", &v))),
                 })
             }
@@ -329,6 +790,35 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
     }
 }
 
+/// Deserialize flattened OpenAPI extensions. Only fields prefixed with _`x-`_ are considered
+/// extensions and empty extensions are deserialized as `None` to match the serialized value.
+pub(crate) fn deserialize_extensions<'de, D>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, serde_json::Value>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut extensions = HashMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    extensions.retain(|name, _| name.starts_with("x-"));
+
+    Ok(Some(extensions).filter(|extensions| !extensions.is_empty()))
+}
+
+/// Serialize flattened OpenAPI extensions sorted by name so that the serialized output is
+/// deterministic regardless of the iteration order of the underlying map.
+pub(crate) fn serialize_extensions<S>(
+    extensions: &Option<HashMap<String, serde_json::Value>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match extensions {
+        Some(extensions) => serializer.collect_map(extensions.iter().collect::<BTreeMap<_, _>>()),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Value used to indicate whether reusable schema, parameter or operation is deprecated.
 ///
 /// The value will serialize to boolean.
@@ -547,11 +1037,15 @@ pub(crate) use builder;
 
 #[cfg(test)]
 mod tests {
+    use assert_json_diff::assert_json_eq;
     use serde_json::json;
 
     use crate::openapi::{
         info::InfoBuilder,
         path::{OperationBuilder, PathsBuilder},
+        schema::{ObjectBuilder, SchemaType},
+        security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder},
+        tag::TagBuilder,
     };
 
     use super::{response::Response, *};
@@ -588,6 +1082,33 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn serialize_openapi_yaml_minimal_success() -> Result<(), serde_yaml::Error> {
+        let raw_yaml = include_str!("openapi/testdata/expected_openapi_minimal.yaml");
+        let openapi = OpenApi::new(
+            InfoBuilder::new()
+                .title("My api")
+                .version("1.0.0")
+                .description(Some("My api description"))
+                .license(Some(
+                    LicenseBuilder::new()
+                        .name("MIT")
+                        .url(Some("http://mit.licence"))
+                        .build(),
+                ))
+                .build(),
+            Paths::new(),
+        );
+        let serialized = openapi.to_yaml()?;
+
+        assert_eq!(
+            serialized, raw_yaml,
+            "expected serialized yaml to match raw: \nserialized: \n{serialized} \nraw: \n{raw_yaml}"
+        );
+        Ok(())
+    }
+
     #[test]
     fn serialize_openapi_json_with_paths_success() -> Result<(), serde_json::Error> {
         let openapi = OpenApi::new(
@@ -876,6 +1397,341 @@ mod tests {
         )
     }
 
+    #[test]
+    fn merge_with_overwrite_policy() {
+        let mut api_1 = OpenApiBuilder::new()
+            .info(Info::new("Api", "v1"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/api/v1/user",
+                        PathItem::new(
+                            PathItemType::Get,
+                            OperationBuilder::new().response("200", Response::new("Get user 1")),
+                        ),
+                    )
+                    .build(),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("User", ObjectBuilder::new().schema_type(SchemaType::String))
+                    .build(),
+            ))
+            .tags(Some([TagBuilder::new()
+                .name("user")
+                .description(Some("User v1"))
+                .build()]))
+            .build();
+
+        let api_2 = OpenApiBuilder::new()
+            .info(Info::new("Api", "v2"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/api/v1/user",
+                        PathItem::new(
+                            PathItemType::Get,
+                            OperationBuilder::new().response("200", Response::new("Get user 2")),
+                        ),
+                    )
+                    .path(
+                        "/api/v1/user",
+                        PathItem::new(
+                            PathItemType::Post,
+                            OperationBuilder::new().response("200", Response::new("Post user 2")),
+                        ),
+                    )
+                    .build(),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "User",
+                        ObjectBuilder::new().schema_type(SchemaType::Integer),
+                    )
+                    .build(),
+            ))
+            .tags(Some([TagBuilder::new()
+                .name("user")
+                .description(Some("User v2"))
+                .build()]))
+            .build();
+
+        api_1.merge_with(api_2, MergePolicy::Overwrite);
+        let value = serde_json::to_value(&api_1).unwrap();
+
+        assert_json_eq!(
+            value,
+            json!({
+                "openapi": "3.0.3",
+                "info": {
+                    "title": "Api",
+                    "version": "v1"
+                },
+                "paths": {
+                    "/api/v1/user": {
+                        "get": {
+                            "responses": {
+                                "200": {
+                                    "description": "Get user 2"
+                                }
+                            }
+                        },
+                        "post": {
+                            "responses": {
+                                "200": {
+                                    "description": "Post user 2"
+                                }
+                            }
+                        }
+                    }
+                },
+                "components": {
+                    "schemas": {
+                        "User": {
+                            "type": "integer"
+                        }
+                    }
+                },
+                "tags": [
+                    {
+                        "name": "user",
+                        "description": "User v2"
+                    }
+                ]
+            })
+        )
+    }
+
+    #[test]
+    fn merge_path_item_level_fields() {
+        use crate::openapi::path::{ParameterBuilder, ParameterIn};
+
+        let user_path_item =
+            |summary: &str, parameters: &[(&str, &str)], operation: PathItemType| {
+                let mut path_item = PathItem::new(operation, OperationBuilder::new());
+                path_item.summary = Some(summary.to_string());
+                path_item.parameters = Some(
+                    parameters
+                        .iter()
+                        .map(|(name, description)| {
+                            ParameterBuilder::new()
+                                .name(*name)
+                                .parameter_in(ParameterIn::Path)
+                                .description(Some(*description))
+                                .build()
+                        })
+                        .collect(),
+                );
+                path_item
+            };
+        let api = |path_item: PathItem| {
+            OpenApiBuilder::new()
+                .paths(PathsBuilder::new().path("/api/v1/user/{id}", path_item))
+                .build()
+        };
+
+        let mut api_1 = api(user_path_item(
+            "User 1",
+            &[("id", "Id 1")],
+            PathItemType::Get,
+        ));
+        let mut api_2 = api(user_path_item(
+            "User 2",
+            &[("id", "Id 2"), ("version", "Version 2")],
+            PathItemType::Post,
+        ));
+        api_2
+            .paths
+            .paths
+            .get_mut("/api/v1/user/{id}")
+            .unwrap()
+            .description = Some("Description 2".to_string());
+
+        api_1.merge(api_2.clone());
+        let path_item = api_1.paths.get_path_item("/api/v1/user/{id}").unwrap();
+        assert_json_eq!(
+            json!({
+                "summary": path_item.summary,
+                "description": path_item.description,
+                "parameters": path_item.parameters,
+                "operations": path_item.operations.keys().collect::<Vec<_>>(),
+            }),
+            json!({
+                "summary": "User 1",
+                "description": "Description 2",
+                "parameters": [
+                    { "name": "id", "in": "path", "description": "Id 1", "required": false },
+                    { "name": "version", "in": "path", "description": "Version 2", "required": false }
+                ],
+                "operations": ["get", "post"],
+            })
+        );
+
+        let mut api_1 = api(user_path_item(
+            "User 1",
+            &[("id", "Id 1")],
+            PathItemType::Get,
+        ));
+        api_1.merge_with(api_2, MergePolicy::Overwrite);
+        let path_item = api_1.paths.get_path_item("/api/v1/user/{id}").unwrap();
+        assert_json_eq!(
+            json!({
+                "summary": path_item.summary,
+                "parameters": path_item.parameters,
+            }),
+            json!({
+                "summary": "User 2",
+                "parameters": [
+                    { "name": "id", "in": "path", "description": "Id 2", "required": false },
+                    { "name": "version", "in": "path", "description": "Version 2", "required": false }
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn add_security_scheme_creates_components() {
+        let mut openapi = OpenApiBuilder::new().build();
+        openapi.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("session"))),
+        );
+        openapi.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+
+        assert_json_eq!(
+            openapi.components,
+            json!({
+                "securitySchemes": {
+                    "api_key": {
+                        "type": "apiKey",
+                        "in": "cookie",
+                        "name": "session"
+                    },
+                    "bearer": {
+                        "type": "http",
+                        "scheme": "bearer",
+                        "bearerFormat": "JWT"
+                    }
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn openapi_nest_prefixes_paths() {
+        let pet_api = OpenApiBuilder::new().paths(
+            PathsBuilder::new()
+                .path(
+                    "/",
+                    PathItem::new(
+                        PathItemType::Get,
+                        OperationBuilder::new().operation_id(Some("list_pets")),
+                    ),
+                )
+                .path(
+                    "/{id}",
+                    PathItem::new(
+                        PathItemType::Get,
+                        OperationBuilder::new().operation_id(Some("get_pet")),
+                    ),
+                ),
+        );
+        let api = OpenApiBuilder::new()
+            .paths(PathsBuilder::new().path(
+                "/health",
+                PathItem::new(
+                    PathItemType::Get,
+                    OperationBuilder::new().operation_id(Some("health")),
+                ),
+            ))
+            .build();
+
+        let api = api.nest("/api/v1/pets", pet_api);
+
+        assert_json_eq!(
+            api.paths.paths,
+            json!({
+                "/api/v1/pets/": {
+                    "get": {
+                        "operationId": "list_pets",
+                        "responses": {}
+                    }
+                },
+                "/api/v1/pets/{id}": {
+                    "get": {
+                        "operationId": "get_pet",
+                        "responses": {}
+                    }
+                },
+                "/health": {
+                    "get": {
+                        "operationId": "health",
+                        "responses": {}
+                    }
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn deserialize_openapi_3_1_versions() {
+        [r#""3.1.0""#, r#""3.1""#].iter().for_each(|v| {
+            assert!(matches!(
+                serde_json::from_str::<OpenApiVersion>(v).unwrap(),
+                OpenApiVersion::Version31,
+            ));
+        });
+    }
+
+    #[cfg(feature = "openapi_3_1")]
+    #[test]
+    fn openapi_3_1_serialize_document() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("My api", "1.0.0"))
+            .json_schema_dialect(Some("https://spec.openapis.org/oas/3.1/dialect/base"))
+            .webhooks([(
+                "newPet",
+                PathItem::new(
+                    PathItemType::Post,
+                    OperationBuilder::new().response("200", Response::new("Pet received")),
+                ),
+            )])
+            .build();
+
+        assert_json_eq!(
+            serde_json::to_value(openapi).unwrap(),
+            json!({
+                "openapi": "3.1.0",
+                "info": {
+                    "title": "My api",
+                    "version": "1.0.0"
+                },
+                "paths": {},
+                "jsonSchemaDialect": "https://spec.openapis.org/oas/3.1/dialect/base",
+                "webhooks": {
+                    "newPet": {
+                        "post": {
+                            "responses": {
+                                "200": {
+                                    "description": "Pet received"
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
     #[test]
     fn deserialize_other_versions() {
         [r#""3.0.3""#, r#""3.0.0""#, r#""3.0""#, r#""3""#]
@@ -887,4 +1743,173 @@ mod tests {
                 ));
             });
     }
+
+    #[test]
+    fn serialize_extensions_in_sorted_order() {
+        let extensions = ["x-c", "x-a", "x-d", "x-b", "x-f", "x-e"]
+            .into_iter()
+            .map(|name| (name.to_string(), serde_json::Value::from(name)))
+            .collect::<HashMap<_, _>>();
+        let info = InfoBuilder::new()
+            .title("title")
+            .version("1.0.0")
+            .extensions(Some(extensions))
+            .build();
+
+        let json = serde_json::to_string(&info).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"title":"title","version":"1.0.0","x-a":"x-a","x-b":"x-b","x-c":"x-c","x-d":"x-d","x-e":"x-e","x-f":"x-f"}"#
+        );
+    }
+
+    #[test]
+    fn serialize_deserialize_openapi_with_extensions() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(PathItemType::Get, OperationBuilder::new()),
+                    )
+                    .extensions(Some(HashMap::from([(
+                        "x-paths".to_string(),
+                        json!("paths"),
+                    )]))),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new().extensions(Some(HashMap::from([(
+                            "x-schema".to_string(),
+                            json!(true),
+                        )]))),
+                    )
+                    .build(),
+            ))
+            .extensions(Some(HashMap::from([(
+                "x-root".to_string(),
+                json!({ "internal": true }),
+            )])))
+            .build();
+
+        let value = serde_json::to_value(&openapi).unwrap();
+
+        assert_eq!(value["x-root"], json!({ "internal": true }));
+        assert_eq!(value["paths"]["x-paths"], json!("paths"));
+        assert!(value.get("x-paths").is_none());
+        assert_eq!(
+            value["components"]["schemas"]["Pet"]["x-schema"],
+            json!(true)
+        );
+
+        let deserialized: OpenApi = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized, openapi);
+    }
+
+    #[test]
+    fn write_openapi_to_json_file() {
+        let openapi = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+        let directory = std::env::temp_dir().join("utoipa-write-openapi-to-json-file");
+        let path = directory.join("nested").join("openapi.json");
+        let _ = std::fs::remove_dir_all(&directory);
+
+        openapi.write_to_file(&path).unwrap();
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+        openapi.write_to_file(&path).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            openapi.to_pretty_json().unwrap()
+        );
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            modified,
+            "unchanged file should not be written"
+        );
+    }
+
+    #[test]
+    fn write_openapi_to_yaml_file() {
+        let openapi = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+        let path = std::env::temp_dir().join("utoipa-write-openapi-to-yaml-file.yaml");
+
+        #[cfg(feature = "yaml")]
+        {
+            openapi.write_to_file(&path).unwrap();
+            assert_eq!(
+                std::fs::read_to_string(&path).unwrap(),
+                openapi.to_yaml().unwrap()
+            );
+        }
+        #[cfg(not(feature = "yaml"))]
+        assert_eq!(
+            openapi.write_to_file(&path).unwrap_err().kind(),
+            std::io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn navigate_and_modify_openapi() {
+        let mut openapi = OpenApiBuilder::new()
+            .paths(PathsBuilder::new().path(
+                "/pets",
+                PathItem::new(
+                    PathItemType::Get,
+                    OperationBuilder::new().response("200", Response::new("Pets")),
+                ),
+            ))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", ObjectBuilder::new().schema_type(SchemaType::Object))
+                    .build(),
+            ))
+            .build();
+
+        openapi.get_path_item_mut("/pets").unwrap().summary = Some(String::from("Pets"));
+        if let Some(RefOr::T(Schema::Object(pet))) = openapi.schema_mut("Pet") {
+            pet.description = Some(String::from("Pet of the store"));
+        }
+        assert!(openapi.get_path_item("/pets").is_some());
+        assert!(openapi.schema_mut("Owner").is_none());
+
+        assert!(openapi
+            .modify_pointer("/paths/~1pets/get/responses/200", |response| {
+                response["description"] = json!("List of pets");
+            })
+            .unwrap());
+        assert!(!openapi.modify_pointer("/paths/~1owners", |_| ()).unwrap());
+        assert!(openapi
+            .modify_pointer("/info", |info| *info = json!([]))
+            .is_err());
+
+        assert_eq!(
+            openapi.pointer("/paths/~1pets/summary"),
+            Some(json!("Pets"))
+        );
+        assert_eq!(
+            openapi.pointer("/paths/~1pets/get/responses/200/description"),
+            Some(json!("List of pets"))
+        );
+        assert_eq!(
+            openapi.pointer("/components/schemas/Pet/description"),
+            Some(json!("Pet of the store"))
+        );
+    }
+
+    struct ExportApiDoc;
+
+    impl crate::OpenApi for ExportApiDoc {
+        fn openapi() -> OpenApi {
+            OpenApi::new(Info::new("export", "1.0.0"), Paths::new())
+        }
+    }
+
+    crate::export!(
+        export_openapi_to_temp_dir: ExportApiDoc,
+        std::env::temp_dir().join("utoipa-export-openapi.json")
+    );
 }
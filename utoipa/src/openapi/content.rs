@@ -1,5 +1,5 @@
 //! Implements content object for request body and response.
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize};
 
@@ -42,10 +42,37 @@ builder! {
         /// multipart or `application/x-www-form-urlencoded`.
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub encoding: BTreeMap<String, Encoding>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
 impl Content {
+    /// Construct a new [`Content`] with given schema.
+    ///
+    /// # Examples
+    ///
+    /// _**Create plain string content e.g. for _`text/html`_ response.**_
+    /// ```rust
+    /// # use utoipa::openapi::{ContentBuilder, ObjectBuilder, ResponseBuilder, SchemaType};
+    /// let response = ResponseBuilder::new()
+    ///     .description("Rendered page")
+    ///     .content(
+    ///         "text/html",
+    ///         ContentBuilder::new()
+    ///             .schema(ObjectBuilder::new().schema_type(SchemaType::String))
+    ///             .example(Some("<html></html>".into()))
+    ///             .build(),
+    ///     )
+    ///     .build();
+    /// ```
     pub fn new<I: Into<RefOr<Schema>>>(schema: I) -> Self {
         Self {
             schema: schema.into(),
@@ -105,4 +132,9 @@ impl ContentBuilder {
         self.encoding.insert(property_name.into(), encoding.into());
         self
     }
+
+    /// Add openapi extensions (x-something) to the [`Content`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
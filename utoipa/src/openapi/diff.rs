@@ -0,0 +1,1069 @@
+//! Implements comparison of two [`OpenApi`] documents for detecting breaking changes.
+//!
+//! Comparison is done with [`diff`] which reports all found differences as [`Change`]s.
+//! Locations of the changes are reported as [JSON pointer][json_pointer] like paths within the
+//! serialized [`OpenApi`] document. Parameters are identified by their location and name
+//! instead of their index e.g. _`/paths/~1pets/get/parameters/query/limit`_.
+//!
+//! [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use serde_json::Value;
+
+use super::{
+    content::Content,
+    path::{Operation, Parameter, PathItem},
+    request_body::RequestBody,
+    response::Response,
+    schema::Schema,
+    validation::{escape, path_item_type_name},
+    OpenApi, RefOr, Required,
+};
+
+/// Difference between two [`OpenApi`] documents found by [`diff`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// JSON pointer like location of the changed item. For removed items this points to the old
+    /// document and for other changes to the new document.
+    pub location: String,
+    /// Kind of the change.
+    pub kind: ChangeKind,
+    /// Whether the change may break existing clients of the old document.
+    pub breaking: bool,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)?;
+        if self.breaking {
+            write!(f, " (breaking)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Kind of the [`Change`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Item was added. Parameters, request bodies and properties may be added as required.
+    Added {
+        /// Whether the added item is required.
+        required: bool,
+    },
+    /// Item was removed.
+    Removed,
+    /// Optional parameter, request body or property was changed to required.
+    MadeRequired,
+    /// Required parameter, request body or property was changed to optional.
+    MadeOptional,
+    /// Type of the schema was changed.
+    TypeChanged {
+        /// Type of the schema in the old document.
+        old: String,
+        /// Type of the schema in the new document.
+        new: String,
+    },
+    /// Allowed _`enum`_ values of the schema were changed.
+    EnumValuesChanged {
+        /// Values only allowed by the old document.
+        removed: Vec<String>,
+        /// Values only allowed by the new document.
+        added: Vec<String>,
+    },
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Added { required: true } => write!(f, "added as required"),
+            Self::Added { required: false } => write!(f, "added"),
+            Self::Removed => write!(f, "removed"),
+            Self::MadeRequired => write!(f, "changed to required"),
+            Self::MadeOptional => write!(f, "changed to optional"),
+            Self::TypeChanged { old, new } => write!(f, "type changed from `{old}` to `{new}`"),
+            Self::EnumValuesChanged { removed, added } => write!(
+                f,
+                "enum values changed, removed: [{}], added: [{}]",
+                removed.join(", "),
+                added.join(", ")
+            ),
+        }
+    }
+}
+
+/// Compare `old` and `new` [`OpenApi`] documents and return found differences as [`Change`]s
+/// classified as breaking or non-breaking.
+///
+/// Following differences are reported:
+/// * Added and removed paths, operations, responses, content types and component schemas.
+///   Removals are breaking.
+/// * Added, removed and changed parameters and request bodies. Adding a required or changing an
+///   optional one to required is breaking. Removals are not breaking.
+/// * Added, removed and changed properties of schemas. Removing a property is breaking.
+/// * Changed types of schemas. Changed _`$ref`_s are always breaking.
+/// * Changed _`enum`_ values of schemas.
+///
+/// Whether a changed schema is breaking depends on the direction of the data it describes.
+/// Schemas of parameters and request bodies are sent by the clients thus narrowing them is
+/// breaking: adding a required property, changing an optional property to required, narrowing
+/// the type or removing _`enum`_ values. Schemas of responses are read by the clients thus
+/// widening them is breaking instead: changing a required property to optional, widening the
+/// type e.g. _`integer`_ to _`number`_ or a type made nullable, or adding _`enum`_ values.
+/// Component schemas may be used in both directions and their changes are breaking if they
+/// are breaking in either direction.
+///
+/// Schemas are compared by their properties and array items. _`$ref`_s are not resolved, but
+/// the referenced component schemas are compared by their name. Composite schemas
+/// _`allOf`_, _`oneOf`_ and _`anyOf`_ are only compared by their type.
+///
+/// # Examples
+///
+/// _**Fail CI when the new document breaks the committed one.**_
+/// ```rust
+/// # use utoipa::openapi::{self, Info, OpenApi, Paths};
+/// let committed = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+/// let current = OpenApi::new(Info::new("api", "1.1.0"), Paths::new());
+///
+/// let breaking = openapi::diff(&committed, &current)
+///     .into_iter()
+///     .filter(|change| change.breaking)
+///     .map(|change| change.to_string())
+///     .collect::<Vec<_>>();
+/// assert!(breaking.is_empty(), "breaking changes:\n{}", breaking.join("\n"));
+/// ```
+pub fn diff(old: &OpenApi, new: &OpenApi) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    diff_entries(
+        "/paths",
+        &old.paths.paths,
+        &new.paths.paths,
+        &mut changes,
+        diff_path_items,
+    );
+    diff_entries(
+        "/webhooks",
+        &old.webhooks,
+        &new.webhooks,
+        &mut changes,
+        diff_path_items,
+    );
+
+    diff_entries(
+        "/components/schemas",
+        component_schemas(old),
+        component_schemas(new),
+        &mut changes,
+        diff_component_schema,
+    );
+
+    changes
+}
+
+/// Direction of the data described by the compared schemas.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// Schema of data sent by the clients, e.g. parameters and request bodies.
+    Request,
+    /// Schema of data read by the clients, e.g. response bodies.
+    Response,
+}
+
+/// Report removed and added entries of `old` and `new` maps found under `location` and compare
+/// entries found from both with `diff_entry`. Removals are breaking.
+fn diff_entries<'a, V: 'a>(
+    location: &str,
+    old: impl IntoIterator<Item = (&'a String, &'a V)>,
+    new: impl IntoIterator<Item = (&'a String, &'a V)>,
+    changes: &mut Vec<Change>,
+    mut diff_entry: impl FnMut(&str, &'a V, &'a V, &mut Vec<Change>),
+) {
+    let new = new.into_iter().collect::<Vec<_>>();
+    let new_entries = new.iter().copied().collect::<BTreeMap<_, _>>();
+    let mut old_keys = BTreeSet::new();
+    for (key, old) in old {
+        old_keys.insert(key);
+        let location = format!("{location}/{}", escape(key));
+        match new_entries.get(key) {
+            Some(new) => diff_entry(&location, old, new, changes),
+            None => changes.push(Change::removed(location, true)),
+        }
+    }
+
+    changes.extend(
+        new.into_iter()
+            .filter(|(key, _)| !old_keys.contains(key))
+            .map(|(key, _)| Change::added(format!("{location}/{}", escape(key)), false)),
+    );
+}
+
+fn component_schemas(openapi: &OpenApi) -> impl Iterator<Item = (&String, &RefOr<Schema>)> {
+    openapi
+        .components
+        .iter()
+        .flat_map(|components| &components.schemas)
+}
+
+/// Compare component schemas in both [`Direction`]s as they may be referenced from requests and
+/// responses alike. Change is breaking if it is breaking in either [`Direction`].
+fn diff_component_schema(
+    location: &str,
+    old: &RefOr<Schema>,
+    new: &RefOr<Schema>,
+    changes: &mut Vec<Change>,
+) {
+    let (old, new) = (to_value(old), to_value(new));
+    let [request_changes, response_changes] =
+        [Direction::Request, Direction::Response].map(|direction| {
+            let mut changes = Vec::new();
+            diff_schema(location, &old, &new, direction, &mut changes);
+            changes
+        });
+
+    changes.extend(request_changes.into_iter().zip(response_changes).map(
+        |(request_change, response_change)| Change {
+            breaking: request_change.breaking || response_change.breaking,
+            ..request_change
+        },
+    ));
+}
+
+fn diff_path_items(location: &str, old: &PathItem, new: &PathItem, changes: &mut Vec<Change>) {
+    let (old_operations, new_operations) = (operations(old), operations(new));
+
+    diff_entries(
+        location,
+        old_operations
+            .iter()
+            .map(|(name, operation)| (name, *operation)),
+        new_operations
+            .iter()
+            .map(|(name, operation)| (name, *operation)),
+        changes,
+        |location, old_operation, new_operation, changes| {
+            diff_parameters(
+                &format!("{location}/parameters"),
+                parameters(old, old_operation),
+                parameters(new, new_operation),
+                changes,
+            );
+            diff_request_bodies(
+                &format!("{location}/requestBody"),
+                old_operation.request_body.as_ref(),
+                new_operation.request_body.as_ref(),
+                changes,
+            );
+            diff_entries(
+                &format!("{location}/responses"),
+                &old_operation.responses.responses,
+                &new_operation.responses.responses,
+                changes,
+                diff_responses,
+            );
+        },
+    );
+}
+
+fn operations(path_item: &PathItem) -> Vec<(String, &Operation)> {
+    path_item
+        .operations
+        .iter()
+        .map(|(path_item_type, operation)| (path_item_type_name(path_item_type), operation))
+        .collect()
+}
+
+/// Parameters of the [`Operation`] along with the parameters of the [`PathItem`] keyed by their
+/// location and name. Parameters of the [`Operation`] override the ones of the [`PathItem`].
+fn parameters<'p>(
+    path_item: &'p PathItem,
+    operation: &'p Operation,
+) -> BTreeMap<String, &'p Parameter> {
+    path_item
+        .parameters
+        .iter()
+        .chain(operation.parameters.iter())
+        .flatten()
+        .map(|parameter| {
+            let parameter_in = serde_json::to_value(&parameter.parameter_in)
+                .ok()
+                .as_ref()
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            (
+                format!("{parameter_in}/{}", escape(&parameter.name)),
+                parameter,
+            )
+        })
+        .collect()
+}
+
+fn diff_parameters(
+    location: &str,
+    old: BTreeMap<String, &Parameter>,
+    new: BTreeMap<String, &Parameter>,
+    changes: &mut Vec<Change>,
+) {
+    for (key, old_parameter) in &old {
+        let location = format!("{location}/{key}");
+        let Some(new_parameter) = new.get(key) else {
+            changes.push(Change::removed(location, false));
+            continue;
+        };
+
+        diff_required(
+            &location,
+            old_parameter.required == Required::True,
+            new_parameter.required == Required::True,
+            Direction::Request,
+            changes,
+        );
+        if let (Some(old_schema), Some(new_schema)) = (&old_parameter.schema, &new_parameter.schema)
+        {
+            diff_schema(
+                &format!("{location}/schema"),
+                &to_value(old_schema),
+                &to_value(new_schema),
+                Direction::Request,
+                changes,
+            );
+        }
+    }
+
+    changes.extend(new.iter().filter(|(key, _)| !old.contains_key(*key)).map(
+        |(key, parameter)| {
+            Change::added(
+                format!("{location}/{key}"),
+                parameter.required == Required::True,
+            )
+        },
+    ));
+}
+
+fn diff_request_bodies(
+    location: &str,
+    old: Option<&RequestBody>,
+    new: Option<&RequestBody>,
+    changes: &mut Vec<Change>,
+) {
+    let is_required =
+        |request_body: &RequestBody| matches!(request_body.required, Some(Required::True));
+    match (old, new) {
+        (Some(old), Some(new)) => {
+            diff_required(
+                location,
+                is_required(old),
+                is_required(new),
+                Direction::Request,
+                changes,
+            );
+            diff_entries(
+                &format!("{location}/content"),
+                &old.content,
+                &new.content,
+                changes,
+                |location, old, new, changes| {
+                    diff_contents(location, old, new, Direction::Request, changes)
+                },
+            );
+        }
+        (Some(_), None) => changes.push(Change::removed(location.to_string(), false)),
+        (None, Some(new)) => changes.push(Change::added(location.to_string(), is_required(new))),
+        (None, None) => (),
+    }
+}
+
+fn diff_responses(
+    location: &str,
+    old: &RefOr<Response>,
+    new: &RefOr<Response>,
+    changes: &mut Vec<Change>,
+) {
+    match (old, new) {
+        (RefOr::T(old), RefOr::T(new)) => diff_entries(
+            &format!("{location}/content"),
+            &old.content,
+            &new.content,
+            changes,
+            |location, old, new, changes| {
+                diff_contents(location, old, new, Direction::Response, changes)
+            },
+        ),
+        (old, new) => {
+            let (old, new) = (to_value(old), to_value(new));
+            if old != new {
+                changes.push(Change::type_changed(
+                    location.to_string(),
+                    SchemaType::of(&old),
+                    SchemaType::of(&new),
+                    Direction::Response,
+                ));
+            }
+        }
+    }
+}
+
+fn diff_contents(
+    location: &str,
+    old: &Content,
+    new: &Content,
+    direction: Direction,
+    changes: &mut Vec<Change>,
+) {
+    diff_schema(
+        &format!("{location}/schema"),
+        &to_value(&old.schema),
+        &to_value(&new.schema),
+        direction,
+        changes,
+    );
+}
+
+/// Report requirement change of an item. Changing an optional item to required is breaking
+/// in [`Direction::Request`] while changing required item to optional is breaking in
+/// [`Direction::Response`].
+fn diff_required(
+    location: &str,
+    old: bool,
+    new: bool,
+    direction: Direction,
+    changes: &mut Vec<Change>,
+) {
+    match (old, new) {
+        (false, true) => changes.push(Change {
+            location: location.to_string(),
+            kind: ChangeKind::MadeRequired,
+            breaking: direction == Direction::Request,
+        }),
+        (true, false) => changes.push(Change {
+            location: location.to_string(),
+            kind: ChangeKind::MadeOptional,
+            breaking: direction == Direction::Response,
+        }),
+        _ => (),
+    }
+}
+
+/// Compare serialized schemas found under `location`. Properties and items of the schemas are
+/// compared only when the types of the schemas are same.
+fn diff_schema(
+    location: &str,
+    old: &Value,
+    new: &Value,
+    direction: Direction,
+    changes: &mut Vec<Change>,
+) {
+    let (old_type, new_type) = (SchemaType::of(old), SchemaType::of(new));
+    if old_type != new_type {
+        changes.push(Change::type_changed(
+            location.to_string(),
+            old_type,
+            new_type,
+            direction,
+        ));
+        return;
+    }
+
+    if let (Some(Value::Array(old_values)), Some(Value::Array(new_values))) =
+        (old.get("enum"), new.get("enum"))
+    {
+        let difference = |values: &[Value], other: &[Value]| {
+            values
+                .iter()
+                .filter(|value| !other.contains(value))
+                .map(Value::to_string)
+                .collect::<Vec<_>>()
+        };
+        let removed = difference(old_values, new_values);
+        let added = difference(new_values, old_values);
+        if !removed.is_empty() || !added.is_empty() {
+            changes.push(Change {
+                location: location.to_string(),
+                breaking: match direction {
+                    Direction::Request => !removed.is_empty(),
+                    Direction::Response => !added.is_empty(),
+                },
+                kind: ChangeKind::EnumValuesChanged { removed, added },
+            });
+        }
+    }
+
+    diff_properties(location, old, new, direction, changes);
+
+    if let (Some(old_items), Some(new_items)) = (old.get("items"), new.get("items")) {
+        diff_schema(
+            &format!("{location}/items"),
+            old_items,
+            new_items,
+            direction,
+            changes,
+        );
+    }
+}
+
+fn diff_properties(
+    location: &str,
+    old: &Value,
+    new: &Value,
+    direction: Direction,
+    changes: &mut Vec<Change>,
+) {
+    let required = |schema: &Value| {
+        schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| {
+                required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(ToString::to_string)
+                    .collect::<BTreeSet<_>>()
+            })
+            .unwrap_or_default()
+    };
+    let no_properties = serde_json::Map::new();
+    let properties = |schema: &'_ Value| {
+        schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .unwrap_or(&no_properties)
+            .iter()
+            .map(|(name, property)| (name.clone(), property.clone()))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let (old_required, new_required) = (required(old), required(new));
+    let (old_properties, new_properties) = (properties(old), properties(new));
+
+    for (name, old_property) in &old_properties {
+        let location = format!("{location}/properties/{}", escape(name));
+        let Some(new_property) = new_properties.get(name) else {
+            changes.push(Change::removed(location, true));
+            continue;
+        };
+
+        diff_required(
+            &location,
+            old_required.contains(name),
+            new_required.contains(name),
+            direction,
+            changes,
+        );
+        diff_schema(&location, old_property, new_property, direction, changes);
+    }
+
+    changes.extend(
+        new_properties
+            .keys()
+            .filter(|name| !old_properties.contains_key(*name))
+            .map(|name| {
+                let added = Change::added(
+                    format!("{location}/properties/{}", escape(name)),
+                    new_required.contains(name),
+                );
+                Change {
+                    breaking: added.breaking && direction == Direction::Request,
+                    ..added
+                }
+            }),
+    );
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or_default()
+}
+
+/// Type of serialized schema used for comparing schemas.
+#[derive(PartialEq, Eq)]
+enum SchemaType {
+    /// Schema is a _`$ref`_ to another schema.
+    Ref(String),
+    /// Schema has types, _`null`_ included for nullable schemas.
+    Types(BTreeSet<String>),
+    /// Schema is a composite _`allOf`_, _`oneOf`_ or _`anyOf`_ schema.
+    Composite(&'static str),
+    /// Schema does not restrict the type.
+    Any,
+}
+
+impl SchemaType {
+    fn of(schema: &Value) -> Self {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return Self::Ref(reference.to_string());
+        }
+
+        let mut types = match schema.get("type") {
+            Some(Value::String(schema_type)) => BTreeSet::from([schema_type.clone()]),
+            Some(Value::Array(schema_types)) => schema_types
+                .iter()
+                .filter_map(Value::as_str)
+                .map(ToString::to_string)
+                .collect(),
+            _ => {
+                return ["allOf", "oneOf", "anyOf"]
+                    .into_iter()
+                    .find(|composite| schema.get(composite).is_some())
+                    .map(Self::Composite)
+                    .unwrap_or(Self::Any)
+            }
+        };
+        if schema.get("nullable") == Some(&Value::Bool(true)) {
+            types.insert("null".to_string());
+        }
+
+        Self::Types(types)
+    }
+
+    /// Whether every value allowed by `self` is allowed by `other` as well.
+    fn is_widened_to(&self, other: &Self) -> bool {
+        match (self, other) {
+            (_, Self::Any) => true,
+            (Self::Types(types), Self::Types(other_types)) => types.iter().all(|schema_type| {
+                other_types.contains(schema_type)
+                    || (schema_type == "integer" && other_types.contains("number"))
+            }),
+            _ => false,
+        }
+    }
+}
+
+impl Display for SchemaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ref(reference) => write!(f, "{reference}"),
+            Self::Types(types) => write!(
+                f,
+                "{}",
+                types
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            Self::Composite(composite) => write!(f, "{composite}"),
+            Self::Any => write!(f, "any"),
+        }
+    }
+}
+
+impl Change {
+    fn added(location: String, required: bool) -> Self {
+        Self {
+            location,
+            kind: ChangeKind::Added { required },
+            breaking: required,
+        }
+    }
+
+    fn removed(location: String, breaking: bool) -> Self {
+        Self {
+            location,
+            kind: ChangeKind::Removed,
+            breaking,
+        }
+    }
+
+    fn type_changed(
+        location: String,
+        old: SchemaType,
+        new: SchemaType,
+        direction: Direction,
+    ) -> Self {
+        Self {
+            location,
+            breaking: match direction {
+                Direction::Request => !old.is_widened_to(&new),
+                Direction::Response => !new.is_widened_to(&old),
+            },
+            kind: ChangeKind::TypeChanged {
+                old: old.to_string(),
+                new: new.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn openapi(value: Value) -> OpenApi {
+        let mut document = json!({
+            "openapi": "3.0.3",
+            "info": { "title": "api", "version": "1.0.0" },
+            "paths": {}
+        });
+        document
+            .as_object_mut()
+            .unwrap()
+            .extend(value.as_object().unwrap().clone());
+
+        serde_json::from_value(document).unwrap()
+    }
+
+    fn change(location: &str, kind: ChangeKind, breaking: bool) -> Change {
+        Change {
+            location: location.to_string(),
+            kind,
+            breaking,
+        }
+    }
+
+    #[test]
+    fn diff_same_documents() {
+        let document = openapi(json!({
+            "paths": {
+                "/pets": {
+                    "get": { "responses": { "200": { "description": "ok" } } }
+                }
+            }
+        }));
+
+        assert_eq!(diff(&document, &document), Vec::new());
+    }
+
+    #[test]
+    fn diff_paths_and_operations() {
+        let old = openapi(json!({
+            "paths": {
+                "/pets": {
+                    "get": { "responses": { "200": { "description": "ok" } } },
+                    "delete": { "responses": { "200": { "description": "ok" } } }
+                },
+                "/owners": {
+                    "get": { "responses": { "200": { "description": "ok" } } }
+                }
+            }
+        }));
+        let new = openapi(json!({
+            "paths": {
+                "/pets": {
+                    "get": { "responses": { "404": { "description": "not found" } } },
+                    "post": { "responses": { "200": { "description": "ok" } } }
+                },
+                "/pets/{id}": {
+                    "get": { "responses": { "200": { "description": "ok" } } }
+                }
+            }
+        }));
+
+        let mut changes = diff(&old, &new);
+        changes.sort_by(|change, other| change.location.cmp(&other.location));
+
+        assert_eq!(
+            changes,
+            vec![
+                change("/paths/~1owners", ChangeKind::Removed, true),
+                change("/paths/~1pets/delete", ChangeKind::Removed, true),
+                change("/paths/~1pets/get/responses/200", ChangeKind::Removed, true),
+                change(
+                    "/paths/~1pets/get/responses/404",
+                    ChangeKind::Added { required: false },
+                    false
+                ),
+                change(
+                    "/paths/~1pets/post",
+                    ChangeKind::Added { required: false },
+                    false
+                ),
+                change(
+                    "/paths/~1pets~1{id}",
+                    ChangeKind::Added { required: false },
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_parameters_and_request_body() {
+        let old = openapi(json!({
+            "paths": {
+                "/pets": {
+                    "parameters": [
+                        { "name": "tenant", "in": "header", "required": false }
+                    ],
+                    "post": {
+                        "parameters": [
+                            { "name": "limit", "in": "query", "required": false, "schema": { "type": "integer" } },
+                            { "name": "offset", "in": "query", "required": false }
+                        ],
+                        "requestBody": {
+                            "content": { "application/json": { "schema": { "type": "object" } } }
+                        },
+                        "responses": {}
+                    }
+                }
+            }
+        }));
+        let new = openapi(json!({
+            "paths": {
+                "/pets": {
+                    "post": {
+                        "parameters": [
+                            { "name": "tenant", "in": "header", "required": true },
+                            { "name": "limit", "in": "query", "required": false, "schema": { "type": "string" } },
+                            { "name": "filter", "in": "query", "required": false },
+                            { "name": "version", "in": "query", "required": true }
+                        ],
+                        "requestBody": {
+                            "content": { "application/json": { "schema": { "type": "object" } } },
+                            "required": true
+                        },
+                        "responses": {}
+                    }
+                }
+            }
+        }));
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                change(
+                    "/paths/~1pets/post/parameters/header/tenant",
+                    ChangeKind::MadeRequired,
+                    true
+                ),
+                change(
+                    "/paths/~1pets/post/parameters/query/limit/schema",
+                    ChangeKind::TypeChanged {
+                        old: "integer".to_string(),
+                        new: "string".to_string()
+                    },
+                    true
+                ),
+                change(
+                    "/paths/~1pets/post/parameters/query/offset",
+                    ChangeKind::Removed,
+                    false
+                ),
+                change(
+                    "/paths/~1pets/post/parameters/query/filter",
+                    ChangeKind::Added { required: false },
+                    false
+                ),
+                change(
+                    "/paths/~1pets/post/parameters/query/version",
+                    ChangeKind::Added { required: true },
+                    true
+                ),
+                change(
+                    "/paths/~1pets/post/requestBody",
+                    ChangeKind::MadeRequired,
+                    true
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_component_schemas() {
+        let old = openapi(json!({
+            "components": {
+                "schemas": {
+                    "Owner": { "type": "object" },
+                    "Pet": {
+                        "type": "object",
+                        "required": ["id", "name"],
+                        "properties": {
+                            "id": { "type": "integer" },
+                            "name": { "type": "string" },
+                            "age": { "type": "integer" },
+                            "kind": { "type": "string", "enum": ["cat", "dog"] },
+                            "tags": { "type": "array", "items": { "type": "string" } }
+                        }
+                    }
+                }
+            }
+        }));
+        let new = openapi(json!({
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "required": ["id", "owner"],
+                        "properties": {
+                            "id": { "type": "number" },
+                            "name": { "type": "string", "nullable": true },
+                            "kind": { "type": "string", "enum": ["cat", "bird"] },
+                            "tags": { "type": "array", "items": { "type": "integer" } },
+                            "owner": { "$ref": "#/components/schemas/Owner" },
+                            "nickname": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        }));
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                change("/components/schemas/Owner", ChangeKind::Removed, true),
+                change(
+                    "/components/schemas/Pet/properties/age",
+                    ChangeKind::Removed,
+                    true
+                ),
+                change(
+                    "/components/schemas/Pet/properties/id",
+                    ChangeKind::TypeChanged {
+                        old: "integer".to_string(),
+                        new: "number".to_string()
+                    },
+                    true
+                ),
+                change(
+                    "/components/schemas/Pet/properties/kind",
+                    ChangeKind::EnumValuesChanged {
+                        removed: vec![r#""dog""#.to_string()],
+                        added: vec![r#""bird""#.to_string()]
+                    },
+                    true
+                ),
+                change(
+                    "/components/schemas/Pet/properties/name",
+                    ChangeKind::MadeOptional,
+                    true
+                ),
+                change(
+                    "/components/schemas/Pet/properties/name",
+                    ChangeKind::TypeChanged {
+                        old: "string".to_string(),
+                        new: "null | string".to_string()
+                    },
+                    true
+                ),
+                change(
+                    "/components/schemas/Pet/properties/tags/items",
+                    ChangeKind::TypeChanged {
+                        old: "string".to_string(),
+                        new: "integer".to_string()
+                    },
+                    true
+                ),
+                change(
+                    "/components/schemas/Pet/properties/nickname",
+                    ChangeKind::Added { required: false },
+                    false
+                ),
+                change(
+                    "/components/schemas/Pet/properties/owner",
+                    ChangeKind::Added { required: true },
+                    true
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_request_and_response_schemas_by_direction() {
+        let operation = |schema: Value| {
+            json!({
+                "requestBody": {
+                    "content": { "application/json": { "schema": schema } }
+                },
+                "responses": {
+                    "200": {
+                        "description": "ok",
+                        "content": { "application/json": { "schema": schema } }
+                    }
+                }
+            })
+        };
+        let old = openapi(json!({
+            "paths": {
+                "/pets": {
+                    "post": operation(json!({
+                        "type": "object",
+                        "required": ["name"],
+                        "properties": {
+                            "id": { "type": "integer" },
+                            "name": { "type": "string" },
+                            "kind": { "type": "string", "enum": ["cat", "dog"] }
+                        }
+                    }))
+                }
+            }
+        }));
+        let new = openapi(json!({
+            "paths": {
+                "/pets": {
+                    "post": operation(json!({
+                        "type": "object",
+                        "required": ["owner"],
+                        "properties": {
+                            "id": { "type": "number" },
+                            "name": { "type": "string" },
+                            "kind": { "type": "string", "enum": ["cat", "dog", "bird"] },
+                            "owner": { "type": "string" }
+                        }
+                    }))
+                }
+            }
+        }));
+
+        let breaking = |location: &str| {
+            diff(&old, &new)
+                .into_iter()
+                .filter(|change| change.location.starts_with(location))
+                .map(|change| {
+                    (
+                        change.location[location.len()..].to_string(),
+                        change.breaking,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            breaking("/paths/~1pets/post/requestBody/content/application~1json/schema"),
+            vec![
+                ("/properties/id".to_string(), false),
+                ("/properties/kind".to_string(), false),
+                ("/properties/name".to_string(), false),
+                ("/properties/owner".to_string(), true),
+            ]
+        );
+        assert_eq!(
+            breaking("/paths/~1pets/post/responses/200/content/application~1json/schema"),
+            vec![
+                ("/properties/id".to_string(), true),
+                ("/properties/kind".to_string(), true),
+                ("/properties/name".to_string(), true),
+                ("/properties/owner".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn display_change() {
+        assert_eq!(
+            change(
+                "/components/schemas/Pet/properties/id",
+                ChangeKind::TypeChanged {
+                    old: "integer".to_string(),
+                    new: "string".to_string()
+                },
+                true
+            )
+            .to_string(),
+            "/components/schemas/Pet/properties/id: type changed from `integer` to `string` (breaking)"
+        );
+        assert_eq!(
+            change(
+                "/paths/~1pets",
+                ChangeKind::Added { required: false },
+                false
+            )
+            .to_string(),
+            "/paths/~1pets: added"
+        );
+    }
+}
@@ -17,6 +17,7 @@ builder! {
         /// Target url for external documentation location.
         pub url: String,
         /// Additional description supporting markdown syntax of the external documentation.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
     }
 }
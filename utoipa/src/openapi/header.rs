@@ -3,8 +3,9 @@
 //! [header]: https://spec.openapis.org/oas/latest.html#header-object
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::{builder, set_value, Object, RefOr, Schema, SchemaType};
+use super::{builder, set_value, Deprecated, Object, Ref, RefOr, Required, Schema, SchemaType};
 
 builder! {
     HeaderBuilder;
@@ -22,6 +23,19 @@ builder! {
         /// Additional description of the header value.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+
+        /// Declares whether the header is mandatory.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub required: Option<Required>,
+
+        /// Declares the header deprecated status.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<Deprecated>,
+
+        /// Example of the header's potential value. This example will override example
+        /// within [`Header::schema`] if defined.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub example: Option<Value>,
     }
 }
 
@@ -56,6 +70,9 @@ impl Default for Header {
         Self {
             description: Default::default(),
             schema: Object::with_type(SchemaType::String).into(),
+            required: Default::default(),
+            deprecated: Default::default(),
+            example: Default::default(),
         }
     }
 }
@@ -70,4 +87,31 @@ impl HeaderBuilder {
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
     }
+
+    /// Add whether the header is mandatory.
+    pub fn required(mut self, required: Option<Required>) -> Self {
+        set_value!(self required required)
+    }
+
+    /// Add or change deprecated status of the header.
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add example of the header's potential value.
+    pub fn example(mut self, example: Option<Value>) -> Self {
+        set_value!(self example example)
+    }
+}
+
+impl From<HeaderBuilder> for RefOr<Header> {
+    fn from(builder: HeaderBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Header> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
 }
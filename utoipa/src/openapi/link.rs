@@ -0,0 +1,138 @@
+//! Implements [Open API Link Object][link_object] for responses.
+//!
+//! [link_object]: https://spec.openapis.org/oas/latest.html#link-object
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{builder, set_value, RefOr, Server};
+
+builder! {
+    /// # Examples
+    ///
+    /// _**Construct a new [`Link`] via builder**_
+    /// ```rust
+    /// # use utoipa::openapi::link::LinkBuilder;
+    /// let link = LinkBuilder::new()
+    ///     .operation_id("get_user")
+    ///     .parameter("id", "$response.body#/id")
+    ///     .description("Get the created user")
+    ///     .build();
+    /// ```
+    LinkBuilder;
+
+    /// Implements [Open API Link Object][link_object] for responses.
+    ///
+    /// The `Link` represents possible design time link for a response. It does not guarantee
+    /// callers ability to invoke it but rather provides known relationship between responses and
+    /// other operations.
+    ///
+    /// For computing links, and providing instructions to execute them,
+    /// a runtime [expression][expression] is used for accessing values in an operation
+    /// and using them as parameters while invoking the linked operation.
+    ///
+    /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+    /// [link_object]: https://spec.openapis.org/oas/latest.html#link-object
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Link {
+        /// A relative or absolute URI reference to an OAS operation. This field is
+        /// mutually exclusive of the _`operation_id`_ field, and **must** point to an [Operation
+        /// Object][operation].
+        ///
+        /// [operation]: ../path/struct.Operation.html
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub operation_ref: String,
+
+        /// The name of an existing, resolvable OAS operation, as defined with a unique
+        /// _`operation_id`_.
+        ///
+        /// This field is mutually exclusive of the _`operation_ref`_ field.
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub operation_id: String,
+
+        /// A map representing parameters to pass to an operation as specified with _`operation_id`_
+        /// or identified by _`operation_ref`_. The key is parameter name to be used and value can
+        /// be any value supported by JSON or an [expression][expression] e.g. `$path.id`
+        ///
+        /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub parameters: BTreeMap<String, serde_json::Value>,
+
+        /// A literal value or an [expression][expression] to be used as request body when operation is called.
+        ///
+        /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub request_body: Option<serde_json::Value>,
+
+        /// Description of the link. Value supports Markdown syntax.
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub description: String,
+
+        /// A [`Server`][server] object to be used by the target operation.
+        ///
+        /// [server]: ../server/struct.Server.html
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub server: Option<Server>,
+    }
+}
+
+impl LinkBuilder {
+    /// Set a relative or absolute URI reference to an OAS operation. This field is
+    /// mutually exclusive of the _`operation_id`_ field, and **must** point to an [Operation
+    /// Object][operation].
+    ///
+    /// [operation]: ../path/struct.Operation.html
+    pub fn operation_ref<S: Into<String>>(mut self, operation_ref: S) -> Self {
+        set_value!(self operation_ref operation_ref.into())
+    }
+
+    /// Set the name of an existing, resolvable OAS operation, as defined with a unique
+    /// _`operation_id`_.
+    ///
+    /// This field is mutually exclusive of the _`operation_ref`_ field.
+    pub fn operation_id<S: Into<String>>(mut self, operation_id: S) -> Self {
+        set_value!(self operation_id operation_id.into())
+    }
+
+    /// Add parameter to be passed to [Operation][operation] upon execution.
+    ///
+    /// [operation]: ../path/struct.Operation.html
+    pub fn parameter<N: Into<String>, V: Into<serde_json::Value>>(
+        mut self,
+        name: N,
+        value: V,
+    ) -> Self {
+        self.parameters.insert(name.into(), value.into());
+
+        self
+    }
+
+    /// Set a literal value or an [expression][expression] to be used as request body when
+    /// operation is called.
+    ///
+    /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+    pub fn request_body<B: Into<serde_json::Value>>(mut self, request_body: Option<B>) -> Self {
+        set_value!(self request_body request_body.map(|request_body| request_body.into()))
+    }
+
+    /// Set description of the link. Value supports Markdown syntax.
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        set_value!(self description description.into())
+    }
+
+    /// Set a [`Server`][server] object to be used by the target operation.
+    ///
+    /// [server]: ../server/struct.Server.html
+    pub fn server<S: Into<Server>>(mut self, server: Option<S>) -> Self {
+        set_value!(self server server.map(|server| server.into()))
+    }
+}
+
+impl From<LinkBuilder> for RefOr<Link> {
+    fn from(link_builder: LinkBuilder) -> Self {
+        Self::T(link_builder.build())
+    }
+}
@@ -1,7 +1,10 @@
 //! Implements [OpenAPI Path Object][paths] types.
 //!
 //! [paths]: https://spec.openapis.org/oas/latest.html#paths-object
-use std::{collections::HashMap, iter};
+use std::{
+    collections::{BTreeMap, HashMap},
+    iter,
+};
 
 use crate::Path;
 use serde::{Deserialize, Serialize};
@@ -9,6 +12,7 @@ use serde_json::Value;
 
 use super::{
     builder,
+    example::Example,
     request_body::RequestBody,
     response::{Response, Responses},
     security::SecurityRequirement,
@@ -30,19 +34,63 @@ builder! {
     ///
     /// [paths]: https://spec.openapis.org/oas/latest.html#paths-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+    #[derive(Serialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct Paths {
         /// Map of relative paths with [`PathItem`]s holding [`Operation`]s matching
         /// api endpoints.
+        #[serde(flatten)]
         pub paths: PathsMap<String, PathItem>,
 
         /// Optional extensions "x-something".
-        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
         pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
+impl<'de> Deserialize<'de> for Paths {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PathsVisitor;
+        impl<'de> serde::de::Visitor<'de> for PathsVisitor {
+            type Value = Paths;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of paths and extensions")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut paths = Paths::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    if key.starts_with("x-") {
+                        paths
+                            .extensions
+                            .get_or_insert_with(HashMap::new)
+                            .insert(key, map.next_value()?);
+                    } else {
+                        let path_item = map.next_value::<PathItem>()?;
+                        paths.paths.insert(key, path_item);
+                    }
+                }
+
+                Ok(paths)
+            }
+        }
+
+        deserializer.deserialize_map(PathsVisitor)
+    }
+}
+
 impl Paths {
     /// Construct a new [`Paths`] object.
     pub fn new() -> Self {
@@ -64,6 +112,24 @@ impl Paths {
         self.paths.get(path.as_ref())
     }
 
+    /// Return _`Option`_ of mutable reference to [`PathItem`] by given relative path _`P`_ if one
+    /// exists in [`Paths::paths`] map. Otherwise will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// _**Add summary to user path item.**_
+    /// ```rust
+    /// # use utoipa::openapi::path::{Paths, PathItem, PathItemType, OperationBuilder};
+    /// # let mut paths = Paths::new();
+    /// # paths.add_path("/api/v1/user", PathItem::new(PathItemType::Get, OperationBuilder::new()));
+    /// if let Some(path_item) = paths.get_path_item_mut("/api/v1/user") {
+    ///     path_item.summary = Some(String::from("User operations"));
+    /// }
+    /// ```
+    pub fn get_path_item_mut<P: AsRef<str>>(&mut self, path: P) -> Option<&mut PathItem> {
+        self.paths.get_mut(path.as_ref())
+    }
+
     /// Return _`Option`_ of reference to [`Operation`] from map of paths or `None` if not found.
     ///
     /// * First will try to find [`PathItem`] by given relative path _`P`_ e.g. `"/api/v1/user"`.
@@ -86,20 +152,93 @@ impl Paths {
             .get(path.as_ref())
             .and_then(|path| path.operations.get(&item_type))
     }
+
+    /// Return _`Option`_ of mutable reference to [`Operation`] from map of paths or `None` if not
+    /// found. See [`Paths::get_path_operation`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// _**Deprecate user operation.**_
+    /// ```rust
+    /// # use utoipa::openapi::path::{Paths, PathItem, PathItemType, OperationBuilder};
+    /// # use utoipa::openapi::Deprecated;
+    /// # let mut paths = Paths::new();
+    /// # paths.add_path("/api/v1/user", PathItem::new(PathItemType::Get, OperationBuilder::new()));
+    /// if let Some(operation) = paths.get_path_operation_mut("/api/v1/user", PathItemType::Get) {
+    ///     operation.deprecated = Some(Deprecated::True);
+    /// }
+    /// ```
+    pub fn get_path_operation_mut<P: AsRef<str>>(
+        &mut self,
+        path: P,
+        item_type: PathItemType,
+    ) -> Option<&mut Operation> {
+        self.paths
+            .get_mut(path.as_ref())
+            .and_then(|path| path.operations.get_mut(&item_type))
+    }
+
+    /// Add [`PathItem`] with path to map of paths at runtime. If path already exists it will merge
+    /// [`Operation`]s of [`PathItem`] with already found path item operations.
+    ///
+    /// This allows documenting routes which are constructed dynamically e.g. from configuration
+    /// and thus cannot be annotated with _`#[utoipa::path(...)]`_.
+    ///
+    /// # Examples
+    ///
+    /// _**Add dynamically configured route to existing paths.**_
+    /// ```rust
+    /// # use utoipa::openapi::path::{Paths, PathItem, PathItemType, OperationBuilder};
+    /// let mut paths = Paths::new();
+    /// for plugin in ["metrics", "status"] {
+    ///     paths.add_path(
+    ///         format!("/plugins/{plugin}/{{id}}"),
+    ///         PathItem::new(PathItemType::Get, OperationBuilder::new().tag(plugin)),
+    ///     );
+    /// }
+    ///
+    /// assert!(paths.get_path_operation("/plugins/status/{id}", PathItemType::Get).is_some());
+    /// ```
+    pub fn add_path<I: Into<String>>(&mut self, path: I, item: PathItem) {
+        add_path(&mut self.paths, path.into(), item);
+    }
+}
+
+fn add_path(paths: &mut PathsMap<String, PathItem>, path: String, item: PathItem) {
+    if let Some(existing_item) = paths.get_mut(&path) {
+        existing_item.operations.extend(item.operations);
+    } else {
+        paths.insert(path, item);
+    }
 }
 
 impl PathsBuilder {
     /// Append [`PathItem`] with path to map of paths. If path already exists it will merge [`Operation`]s of
     /// [`PathItem`] with already found path item operations.
+    ///
+    /// Produces the same [`Paths`] the _`#[utoipa::path(...)]`_ macro does thus it can be used
+    /// to document routes constructed at runtime. See also [`Paths::add_path`].
+    ///
+    /// # Examples
+    ///
+    /// _**Build paths of a dynamic route.**_
+    /// ```rust
+    /// # use utoipa::openapi::path::{PathsBuilder, PathItem, PathItemType, OperationBuilder};
+    /// # use utoipa::openapi::ResponseBuilder;
+    /// let paths = PathsBuilder::new()
+    ///     .path(
+    ///         "/dynamic/{id}",
+    ///         PathItem::new(
+    ///             PathItemType::Get,
+    ///             OperationBuilder::new()
+    ///                 .operation_id(Some("get_dynamic"))
+    ///                 .response("200", ResponseBuilder::new().description("Found")),
+    ///         ),
+    ///     )
+    ///     .build();
+    /// ```
     pub fn path<I: Into<String>>(mut self, path: I, item: PathItem) -> Self {
-        let path_string = path.into();
-        if let Some(existing_item) = self.paths.get_mut(&path_string) {
-            existing_item
-                .operations
-                .extend(&mut item.operations.into_iter());
-        } else {
-            self.paths.insert(path_string, item);
-        }
+        add_path(&mut self.paths, path.into(), item);
 
         self
     }
@@ -153,7 +292,12 @@ builder! {
         pub operations: PathsMap<PathItemType, Operation>,
 
         /// Optional extensions "x-something".
-        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
         pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
@@ -301,9 +445,10 @@ builder! {
         /// List of possible responses returned by the [`Operation`].
         pub responses: Responses,
 
-        // TODO
+        /// Map of out-of-band [`Callback`]s related to the [`Operation`] identified by
+        /// unique name of the callback.
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub callbacks: Option<String>,
+        pub callbacks: Option<BTreeMap<String, Callback>>,
 
         /// Define whether the operation is deprecated or not and thus should be avoided consuming.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -322,7 +467,12 @@ builder! {
         pub servers: Option<Vec<Server>>,
 
         /// Optional extensions "x-something".
-        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
         pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
@@ -368,6 +518,11 @@ impl OperationBuilder {
         set_value!(self operation_id operation_id.map(|operation_id| operation_id.into()))
     }
 
+    /// Add or change additional [`ExternalDocs`] of the [`Operation`].
+    pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
+        set_value!(self external_docs external_docs)
+    }
+
     /// Add or change parameters of the [`Operation`].
     pub fn parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
         mut self,
@@ -426,6 +581,26 @@ impl OperationBuilder {
         self
     }
 
+    /// Add or change map of [`Callback`]s of the [`Operation`].
+    pub fn callbacks<I: IntoIterator<Item = (S, Callback)>, S: Into<String>>(
+        mut self,
+        callbacks: Option<I>,
+    ) -> Self {
+        set_value!(self callbacks callbacks.map(|callbacks| callbacks
+            .into_iter()
+            .map(|(name, callback)| (name.into(), callback))
+            .collect()))
+    }
+
+    /// Append [`Callback`] with unique name to [`Operation`] callbacks.
+    pub fn callback<S: Into<String>, C: Into<Callback>>(mut self, name: S, callback: C) -> Self {
+        self.callbacks
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), callback.into());
+
+        self
+    }
+
     /// Add or change deprecated status of the [`Operation`].
     pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
         set_value!(self deprecated deprecated)
@@ -472,6 +647,60 @@ impl OperationBuilder {
     }
 }
 
+builder! {
+    CallbackBuilder;
+
+    /// Implements [OpenAPI Callback Object][callback] describing out-of-band requests the API
+    /// provider may initiate to the API consumer related to the parent [`Operation`].
+    ///
+    /// Callback is a map of [runtime expressions][expression] identifying the url of the request
+    /// to [`PathItem`]s describing the request and expected responses.
+    ///
+    /// # Examples
+    ///
+    /// _**Create payment notification callback.**_
+    /// ```rust
+    /// # use utoipa::openapi::{PathItem, PathItemType, Response};
+    /// # use utoipa::openapi::path::{Callback, OperationBuilder};
+    /// let callback = Callback::new(
+    ///     "{$request.body#/callbackUrl}",
+    ///     PathItem::new(
+    ///         PathItemType::Post,
+    ///         OperationBuilder::new().response("200", Response::new("Notification received")),
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+    /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Callback {
+        /// Map of runtime expressions to [`PathItem`]s.
+        #[serde(flatten)]
+        pub paths: BTreeMap<String, PathItem>,
+    }
+}
+
+impl Callback {
+    /// Construct a new [`Callback`] with single [`PathItem`] identified by runtime `expression`.
+    pub fn new<E: Into<String>>(expression: E, path_item: PathItem) -> Self {
+        Self {
+            paths: BTreeMap::from_iter(iter::once((expression.into(), path_item))),
+        }
+    }
+}
+
+impl CallbackBuilder {
+    /// Append a [`PathItem`] identified by runtime `expression` to the [`Callback`].
+    pub fn path<E: Into<String>>(mut self, expression: E, path_item: PathItem) -> Self {
+        self.paths.insert(expression.into(), path_item);
+
+        self
+    }
+}
+
 builder! {
     ParameterBuilder;
 
@@ -541,8 +770,19 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         example: Option<Value>,
 
+        /// Examples of [`Parameter`]'s potential value. [`Parameter::examples`] and
+        /// [`Parameter::example`] are mutually exclusive. If both are defined `examples` will
+        /// override value in `example`.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub examples: BTreeMap<String, RefOr<Example>>,
+
         /// Optional extensions "x-something".
-        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
         pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
@@ -616,6 +856,30 @@ impl ParameterBuilder {
         set_value!(self example example)
     }
 
+    /// Add iterator of _`(N, V)`_ where `N` is name of example and `V` is [`Example`][example] to
+    /// [`Parameter`].
+    ///
+    /// [`Parameter::examples`] and [`Parameter::example`] are mutually exclusive. If both are
+    /// defined `examples` will override value in `example`.
+    ///
+    /// [example]: ../example/Example.html
+    pub fn examples_from_iter<
+        E: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Into<RefOr<Example>>,
+    >(
+        mut self,
+        examples: E,
+    ) -> Self {
+        self.examples.extend(
+            examples
+                .into_iter()
+                .map(|(name, example)| (name.into(), example.into())),
+        );
+
+        self
+    }
+
     /// Add openapi extensions (x-something) to the [`Parameter`].
     pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
         set_value!(self extensions extensions)
@@ -676,9 +940,12 @@ pub enum ParameterStyle {
 
 #[cfg(test)]
 mod tests {
-    use super::{Operation, OperationBuilder};
+    use super::{
+        Callback, Operation, OperationBuilder, ParameterBuilder, ParameterIn, ParameterStyle,
+    };
     use crate::openapi::{
         security::SecurityRequirement, server::Server, PathItem, PathItemType, PathsBuilder,
+        Response,
     };
 
     #[test]
@@ -756,6 +1023,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn paths_add_path_merges_operations() {
+        let mut paths = PathsBuilder::new()
+            .path(
+                "/dynamic/{id}",
+                PathItem::new(PathItemType::Get, OperationBuilder::new()),
+            )
+            .build();
+
+        paths.add_path(
+            "/dynamic/{id}",
+            PathItem::new(PathItemType::Delete, OperationBuilder::new()),
+        );
+        paths.add_path(
+            "/dynamic",
+            PathItem::new(PathItemType::Post, OperationBuilder::new()),
+        );
+
+        let expected = PathsBuilder::new()
+            .path(
+                "/dynamic/{id}",
+                PathItem::new(PathItemType::Get, OperationBuilder::new()),
+            )
+            .path(
+                "/dynamic/{id}",
+                PathItem::new(PathItemType::Delete, OperationBuilder::new()),
+            )
+            .path(
+                "/dynamic",
+                PathItem::new(PathItemType::Post, OperationBuilder::new()),
+            )
+            .build();
+        assert_eq!(paths, expected);
+    }
+
     #[test]
     fn operation_new() {
         let operation = Operation::new();
@@ -787,6 +1089,27 @@ mod tests {
         assert!(operation.security.is_some());
     }
 
+    #[test]
+    fn parameter_deep_object_style() {
+        let parameter = ParameterBuilder::new()
+            .name("filter")
+            .parameter_in(ParameterIn::Query)
+            .style(Some(ParameterStyle::DeepObject))
+            .explode(Some(true))
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(parameter).unwrap(),
+            serde_json::json!({
+                "name": "filter",
+                "in": "query",
+                "required": false,
+                "style": "deepObject",
+                "explode": true
+            })
+        );
+    }
+
     #[test]
     fn operation_builder_server() {
         let server1 = Server::new("/api");
@@ -798,4 +1121,40 @@ mod tests {
 
         assert!(operation.servers.is_some());
     }
+
+    #[test]
+    fn operation_builder_callback() {
+        let operation = OperationBuilder::new()
+            .callback(
+                "orderStatus",
+                Callback::new(
+                    "{$request.body#/callbackUrl}",
+                    PathItem::new(
+                        PathItemType::Post,
+                        OperationBuilder::new().response("200", Response::new("Callback received")),
+                    ),
+                ),
+            )
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(operation).unwrap(),
+            serde_json::json!({
+                "callbacks": {
+                    "orderStatus": {
+                        "{$request.body#/callbackUrl}": {
+                            "post": {
+                                "responses": {
+                                    "200": {
+                                        "description": "Callback received"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                "responses": {}
+            })
+        );
+    }
 }
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::openapi::{Ref, RefOr};
 use crate::IntoResponses;
 
-use super::{builder, header::Header, set_value, Content};
+use super::{builder, header::Header, link::Link, set_value, Content};
 
 builder! {
     ResponsesBuilder;
@@ -110,7 +110,7 @@ builder! {
 
         /// Map of headers identified by their name. `Content-Type` header will be ignored.
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
-        pub headers: BTreeMap<String, Header>,
+        pub headers: BTreeMap<String, RefOr<Header>>,
 
         /// Map of response [`Content`] objects identified by response body content type e.g `application/json`.
         ///
@@ -119,8 +119,18 @@ builder! {
         #[serde(skip_serializing_if = "IndexMap::is_empty", default)]
         pub content: IndexMap<String, Content>,
 
+        /// A map of operations links that can be followed from the response. The key of the
+        /// map is a short name for the link.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub links: BTreeMap<String, RefOr<Link>>,
+
         /// Optional extensions "x-something".
-        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
         pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
@@ -150,9 +160,34 @@ impl ResponseBuilder {
         self
     }
 
-    /// Add response [`Header`].
-    pub fn header<S: Into<String>>(mut self, name: S, header: Header) -> Self {
-        self.headers.insert(name.into(), header);
+    /// Add response [`Header`] or reference to a reusable [`Header`] component.
+    pub fn header<S: Into<String>, H: Into<RefOr<Header>>>(mut self, name: S, header: H) -> Self {
+        self.headers.insert(name.into(), header.into());
+
+        self
+    }
+
+    /// Add multiple response headers from iterator of name and header pairs, e.g. from
+    /// [`ToHeader::headers`][crate::ToHeader::headers].
+    pub fn headers_from_iter<
+        I: IntoIterator<Item = (S, H)>,
+        S: Into<String>,
+        H: Into<RefOr<Header>>,
+    >(
+        mut self,
+        iter: I,
+    ) -> Self {
+        self.headers.extend(
+            iter.into_iter()
+                .map(|(name, header)| (name.into(), header.into())),
+        );
+
+        self
+    }
+
+    /// Add link that can be followed from the response.
+    pub fn link<S: Into<String>, L: Into<RefOr<Link>>>(mut self, name: S, link: L) -> Self {
+        self.links.insert(name.into(), link.into());
 
         self
     }
@@ -238,6 +273,7 @@ impl ResponseExt for ResponseBuilder {
 #[cfg(test)]
 mod tests {
     use super::{Content, ResponseBuilder, Responses};
+    use crate::openapi::link::LinkBuilder;
     use assert_json_diff::assert_json_eq;
     use serde_json::json;
 
@@ -274,6 +310,36 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn response_builder_with_links() {
+        let response = ResponseBuilder::new()
+            .description("A sample response")
+            .link(
+                "get_user",
+                LinkBuilder::new()
+                    .operation_id("get_user")
+                    .parameter("id", "$response.body#/id")
+                    .description("Get the user"),
+            )
+            .build();
+
+        assert_json_eq!(
+            response,
+            json!({
+              "description": "A sample response",
+              "links": {
+                "get_user": {
+                  "operationId": "get_user",
+                  "parameters": {
+                    "id": "$response.body#/id"
+                  },
+                  "description": "Get the user"
+                }
+              }
+            })
+        );
+    }
 }
 
 #[cfg(all(test, feature = "openapi_extensions"))]
@@ -2,13 +2,16 @@
 //! used to define field properties, enum values, array or object types.
 //!
 //! [schema]: https://spec.openapis.org/oas/latest.html#schema-object
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::RefOr;
-use super::{builder, security::SecurityScheme, set_value, xml::Xml, Deprecated, Response};
+use super::{
+    builder, external_docs::ExternalDocs, header::Header, security::SecurityScheme, set_value,
+    xml::Xml, Deprecated, Response,
+};
 use crate::{ToResponse, ToSchema};
 
 macro_rules! component_from_builder {
@@ -78,6 +81,14 @@ builder! {
         /// [security_scheme]: https://spec.openapis.org/oas/latest.html#security-scheme-object
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub security_schemes: BTreeMap<String, SecurityScheme>,
+
+        /// Map of reusable header name, to [OpenAPI Header Object][header]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Header Object][header]s.
+        ///
+        /// [header]: https://spec.openapis.org/oas/latest.html#header-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub headers: BTreeMap<String, RefOr<Header>>,
     }
 }
 
@@ -88,6 +99,32 @@ impl Components {
             ..Default::default()
         }
     }
+
+    /// Return _`Option`_ of reference to the schema registered with given `name` or `None` if
+    /// not found.
+    pub fn schema<N: AsRef<str>>(&self, name: N) -> Option<&RefOr<Schema>> {
+        self.schemas.get(name.as_ref())
+    }
+
+    /// Return _`Option`_ of mutable reference to the schema registered with given `name` or
+    /// `None` if not found.
+    ///
+    /// # Examples
+    ///
+    /// _**Add description to `Pet` schema.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Components, ComponentsBuilder, ObjectBuilder, RefOr, Schema};
+    /// let mut components = ComponentsBuilder::new()
+    ///     .schema("Pet", ObjectBuilder::new())
+    ///     .build();
+    ///
+    /// if let Some(RefOr::T(Schema::Object(pet))) = components.schema_mut("Pet") {
+    ///     pet.description = Some(String::from("Pet of the store"));
+    /// }
+    /// ```
+    pub fn schema_mut<N: AsRef<str>>(&mut self, name: N) -> Option<&mut RefOr<Schema>> {
+        self.schemas.get_mut(name.as_ref())
+    }
     /// Add [`SecurityScheme`] to [`Components`].
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -216,6 +253,47 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add reusable [`Header`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`Header`] and second is the header
+    /// itself. The header can be referenced from [`Response`]s with [`Ref::from_header_name`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, Header, Ref, ResponseBuilder};
+    /// let components = ComponentsBuilder::new()
+    ///     .header("X-Rate-Limit", Header::default())
+    ///     .build();
+    ///
+    /// let response = ResponseBuilder::new()
+    ///     .description("Success")
+    ///     .header("X-Rate-Limit", Ref::from_header_name("X-Rate-Limit"))
+    ///     .build();
+    /// ```
+    pub fn header<S: Into<String>, H: Into<RefOr<Header>>>(mut self, name: S, header: H) -> Self {
+        self.headers.insert(name.into(), header.into());
+
+        self
+    }
+
+    /// Add reusable [`Header`]s from iterator.
+    pub fn headers_from_iter<
+        I: IntoIterator<Item = (S, H)>,
+        S: Into<String>,
+        H: Into<RefOr<Header>>,
+    >(
+        mut self,
+        headers: I,
+    ) -> Self {
+        self.headers.extend(
+            headers
+                .into_iter()
+                .map(|(name, header)| (name.into(), header.into())),
+        );
+
+        self
+    }
+
     /// Add [`SecurityScheme`] to [`Components`].
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -238,10 +316,20 @@ impl ComponentsBuilder {
 /// referenced from path operations and other components using [`Ref`].
 ///
 /// [schemas]: https://spec.openapis.org/oas/latest.html#schema-object
+///
+/// With _`openapi_3_1`_ feature enabled nullable schemas are serialized in OpenAPI 3.1 format.
+/// Instead of _`nullable`_ keyword _`"null"`_ is added to the _`type`_ of [`Schema::Object`] and
+/// [`Schema::Array`] and _`{"type": "null"}`_ schema is added as an alternative for composite
+/// schemas and for [`Schema::Object`] without _`type`_. Both OpenAPI 3.0 and OpenAPI 3.1 nullable
+/// formats are then supported in deserialization.
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(
+    not(feature = "openapi_3_1"),
+    derive(Serialize),
+    serde(untagged, rename_all = "camelCase")
+)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[serde(untagged, rename_all = "camelCase")]
 pub enum Schema {
     /// Defines array schema from another schema. Typically used with
     /// [`Schema::Object`]. Slice and Vec types are translated to [`Schema::Array`] types.
@@ -273,23 +361,236 @@ impl Default for Schema {
     }
 }
 
-/// OpenAPI [Discriminator][discriminator] object which can be optionally used together with
-/// [`OneOf`] composite object.
+impl<'de> Deserialize<'de> for Schema {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut value = Value::deserialize(deserializer)?;
+        let Value::Object(schema) = &mut value else {
+            return Err(serde::de::Error::custom(format!(
+                "invalid type: {value}, expected schema object"
+            )));
+        };
+        #[cfg(feature = "openapi_3_1")]
+        from_nullable_3_1(schema);
+
+        let schema = if schema.contains_key("oneOf") {
+            serde_json::from_value(value).map(Self::OneOf)
+        } else if schema.contains_key("allOf") {
+            serde_json::from_value(value).map(Self::AllOf)
+        } else if schema.contains_key("anyOf") {
+            serde_json::from_value(value).map(Self::AnyOf)
+        } else if schema.get("type").and_then(Value::as_str) == Some("array") {
+            serde_json::from_value(value).map(Self::Array)
+        } else {
+            serde_json::from_value(value).map(Self::Object)
+        };
+
+        schema.map_err(serde::de::Error::custom)
+    }
+}
+
+/// Replace OpenAPI 3.1 _`"null"`_ type of deserialized schema with OpenAPI 3.0 _`nullable`_
+/// keyword.
+#[cfg(feature = "openapi_3_1")]
+fn from_nullable_3_1(schema: &mut serde_json::Map<String, Value>) {
+    fn remove_null(items: &mut Vec<Value>, is_null: fn(&Value) -> bool) -> bool {
+        let len = items.len();
+        items.retain(|item| !is_null(item));
+        items.len() != len
+    }
+
+    let mut nullable = false;
+    if let Some(Value::Array(types)) = schema.get_mut("type") {
+        nullable = remove_null(types, |schema_type| schema_type.as_str() == Some("null"));
+        if types.len() == 1 {
+            let schema_type = types.remove(0);
+            schema.insert("type".to_string(), schema_type);
+        }
+        if nullable {
+            if let Some(Value::Array(values)) = schema.get_mut("enum") {
+                remove_null(values, Value::is_null);
+            }
+        }
+    } else if !schema.contains_key("type") {
+        for key in ["oneOf", "anyOf"] {
+            if let Some(Value::Array(items)) = schema.get_mut(key) {
+                nullable |= remove_null(items, |item| {
+                    item.get("type").and_then(Value::as_str) == Some("null")
+                });
+            }
+        }
+    }
+
+    if nullable {
+        schema.insert("nullable".to_string(), Value::Bool(true));
+    }
+}
+
+#[cfg(feature = "openapi_3_1")]
+impl Serialize for Schema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+
+        let schema = match self {
+            Self::Array(array) if array.nullable => serde_json::to_value(array),
+            Self::Object(object) if object.nullable => serde_json::to_value(object),
+            Self::OneOf(one_of) if one_of.nullable => serde_json::to_value(one_of),
+            Self::AllOf(all_of) if all_of.nullable => serde_json::to_value(all_of),
+            Self::AnyOf(any_of) if any_of.nullable => serde_json::to_value(any_of),
+            Self::Array(array) => return array.serialize(serializer),
+            Self::Object(object) => return object.serialize(serializer),
+            Self::OneOf(one_of) => return one_of.serialize(serializer),
+            Self::AllOf(all_of) => return all_of.serialize(serializer),
+            Self::AnyOf(any_of) => return any_of.serialize(serializer),
+        };
+
+        match schema.map_err(S::Error::custom)? {
+            Value::Object(mut schema) => to_nullable_3_1(&mut schema).serialize(serializer),
+            schema => schema.serialize(serializer),
+        }
+    }
+}
+
+/// Replace OpenAPI 3.0 _`nullable`_ keyword of serialized schema with OpenAPI 3.1 _`"null"`_
+/// type. Schema without _`type`_ or _`enum`_ is wrapped in _`anyOf`_ with _`{"type": "null"}`_
+/// schema to keep the null explicit.
 ///
-/// [discriminator]: https://spec.openapis.org/oas/latest.html#discriminator-object
-#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-#[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Discriminator {
-    /// Defines a discriminator property name which must be found within all composite
-    /// objects.
-    pub property_name: String,
+/// Only nullable schemas are serialized through [`Value`]. Its keys keep their order only when
+/// _`preserve_order`_ feature is enabled.
+#[cfg(feature = "openapi_3_1")]
+fn to_nullable_3_1(schema: &mut serde_json::Map<String, Value>) -> &serde_json::Map<String, Value> {
+    fn remove(schema: &mut serde_json::Map<String, Value>, key: &str) -> Option<Value> {
+        #[cfg(not(feature = "preserve_order"))]
+        return schema.remove(key);
+        #[cfg(feature = "preserve_order")]
+        return schema.shift_remove(key);
+    }
+    let null = || serde_json::json!({ "type": "null" });
+
+    remove(schema, "nullable");
+    if let Some(schema_type) = schema.get_mut("type") {
+        *schema_type = Value::Array(vec![schema_type.take(), "null".into()]);
+        if let Some(Value::Array(values)) = schema.get_mut("enum") {
+            values.push(Value::Null);
+        }
+    } else if let Some(Value::Array(values)) = schema.get_mut("enum") {
+        values.push(Value::Null);
+    } else if let Some(Value::Array(items)) = schema.get_mut("oneOf") {
+        items.push(null());
+    } else if let Some(Value::Array(items)) = schema.get_mut("anyOf") {
+        items.push(null());
+    } else if let Some(Value::Array(mut items)) = remove(schema, "allOf") {
+        let all_of = match items.len() {
+            1 => items.remove(0),
+            _ => serde_json::json!({ "allOf": items }),
+        };
+        schema.insert("oneOf".to_string(), Value::Array(vec![null(), all_of]));
+    } else {
+        let any_value = Value::Object(std::mem::take(schema));
+        schema.insert("anyOf".to_string(), Value::Array(vec![any_value, null()]));
+    }
 
-    /// An object to hold mappings between payload values and schema names or references.
-    /// This field can only be populated manually. There is no macro support and no
-    /// validation.
-    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
-    pub mapping: BTreeMap<String, String>,
+    schema
+}
+
+/// Remove properties of object schema for which `is_excluded` returns `true` when called with
+/// _`read_only`_ and _`write_only`_ of the property. [`Object`], [`Array`] and [`AllOf`] properties
+/// are considered. Removed properties are removed from the `required` list as well.
+///
+/// Inline schemas are filtered recursively: properties of the remaining properties, array items,
+/// additional properties and _`allOf`_, _`oneOf`_ and _`anyOf`_ items. References are left as is
+/// since they point to the full schema in the components.
+pub(crate) fn exclude_properties(
+    schema: &mut RefOr<Schema>,
+    is_excluded: fn(read_only: Option<bool>, write_only: Option<bool>) -> bool,
+) {
+    match schema {
+        RefOr::T(Schema::Object(object)) => {
+            let excluded = object
+                .properties
+                .iter()
+                .filter(|(_, property)| match property {
+                    RefOr::T(Schema::Object(property)) => {
+                        is_excluded(property.read_only, property.write_only)
+                    }
+                    RefOr::T(Schema::Array(property)) => {
+                        is_excluded(property.read_only, property.write_only)
+                    }
+                    RefOr::T(Schema::AllOf(property)) => {
+                        is_excluded(property.read_only, property.write_only)
+                    }
+                    _ => false,
+                })
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+
+            for name in &excluded {
+                #[cfg(not(feature = "preserve_order"))]
+                object.properties.remove(name);
+                #[cfg(feature = "preserve_order")]
+                object.properties.shift_remove(name);
+            }
+            object.required.retain(|name| !excluded.contains(name));
+
+            object
+                .properties
+                .values_mut()
+                .for_each(|property| exclude_properties(property, is_excluded));
+            if let Some(AdditionalProperties::RefOr(additional_properties)) =
+                object.additional_properties.as_deref_mut()
+            {
+                exclude_properties(additional_properties, is_excluded);
+            }
+        }
+        RefOr::T(Schema::Array(array)) => {
+            exclude_properties(&mut array.items, is_excluded);
+            array
+                .prefix_items
+                .iter_mut()
+                .for_each(|item| exclude_properties(item, is_excluded));
+        }
+        RefOr::T(Schema::AllOf(all_of)) => all_of
+            .items
+            .iter_mut()
+            .for_each(|item| exclude_properties(item, is_excluded)),
+        RefOr::T(Schema::OneOf(one_of)) => one_of
+            .items
+            .iter_mut()
+            .for_each(|item| exclude_properties(item, is_excluded)),
+        RefOr::T(Schema::AnyOf(any_of)) => any_of
+            .items
+            .iter_mut()
+            .for_each(|item| exclude_properties(item, is_excluded)),
+        _ => (),
+    }
+}
+
+builder! {
+    DiscriminatorBuilder;
+
+    /// OpenAPI [Discriminator][discriminator] object which can be optionally used together with
+    /// [`OneOf`] composite object.
+    ///
+    /// [discriminator]: https://spec.openapis.org/oas/latest.html#discriminator-object
+    #[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Discriminator {
+        /// Defines a discriminator property name which must be found within all composite
+        /// objects.
+        pub property_name: String,
+
+        /// An object to hold mappings between payload values and schema names or references.
+        /// This field can only be populated manually. There is no macro support and no
+        /// validation.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub mapping: BTreeMap<String, String>,
+    }
 }
 
 impl Discriminator {
@@ -310,6 +611,31 @@ impl Discriminator {
     }
 }
 
+impl DiscriminatorBuilder {
+    /// Set property name of the [`Discriminator`].
+    pub fn property_name<I: Into<String>>(mut self, property_name: I) -> Self {
+        set_value!(self property_name property_name.into())
+    }
+
+    /// Add a new mapping between payload value and schema name or reference to the
+    /// [`Discriminator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::schema::DiscriminatorBuilder;
+    /// let discriminator = DiscriminatorBuilder::new()
+    ///     .property_name("pet_type")
+    ///     .mapping("dog", "#/components/schemas/Dog")
+    ///     .build();
+    /// ```
+    pub fn mapping<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.mapping.insert(key.into(), value.into());
+
+        self
+    }
+}
+
 builder! {
     OneOfBuilder;
 
@@ -321,6 +647,7 @@ builder! {
     /// [oneof]: https://spec.openapis.org/oas/latest.html#components-object
     #[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
     pub struct OneOf {
         /// Components of _OneOf_ component.
         #[serde(rename = "oneOf")]
@@ -350,6 +677,23 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Additional [`ExternalDocs`] for the [`OneOf`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub external_docs: Option<ExternalDocs>,
+
+        /// Additional [`Xml`] formatting of the [`OneOf`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub xml: Option<Xml>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
@@ -391,6 +735,18 @@ impl OneOfBuilder {
         self
     }
 
+    /// Adds all given [`Schema`]s to [`OneOf`] [Composite Object][composite].
+    ///
+    /// [composite]: https://spec.openapis.org/oas/latest.html#components-object
+    pub fn items<I: IntoIterator<Item = C>, C: Into<RefOr<Schema>>>(
+        mut self,
+        components: I,
+    ) -> Self {
+        self.items.extend(components.into_iter().map(Into::into));
+
+        self
+    }
+
     /// Add or change the title of the [`OneOf`].
     pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
         set_value!(self title title.map(|title| title.into()))
@@ -421,6 +777,21 @@ impl OneOfBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add or change additional [`ExternalDocs`] for the [`OneOf`].
+    pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
+        set_value!(self external_docs external_docs)
+    }
+
+    /// Set [`Xml`] formatting for [`OneOf`].
+    pub fn xml(mut self, xml: Option<Xml>) -> Self {
+        set_value!(self xml xml)
+    }
+
+    /// Add extensions "x-something" to the [`OneOf`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -436,6 +807,14 @@ impl From<OneOfBuilder> for RefOr<Schema> {
     }
 }
 
+impl From<OneOf> for RefOr<Schema> {
+    fn from(one_of: OneOf) -> Self {
+        Self::T(Schema::OneOf(one_of))
+    }
+}
+
+impl ToArray for OneOf {}
+
 component_from_builder!(OneOfBuilder);
 
 builder! {
@@ -449,6 +828,7 @@ builder! {
     /// [allof]: https://spec.openapis.org/oas/latest.html#components-object
     #[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
     pub struct AllOf {
         /// Components of _AllOf_ component.
         #[serde(rename = "allOf")]
@@ -478,6 +858,35 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Additional [`ExternalDocs`] for the [`AllOf`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub external_docs: Option<ExternalDocs>,
+
+        /// Changes the [`AllOf`] deprecated status.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<Deprecated>,
+
+        /// Write only property will be only sent in _write_ requests like _POST, PUT_.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub write_only: Option<bool>,
+
+        /// Read only property will be only sent in _read_ requests like _GET_.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub read_only: Option<bool>,
+
+        /// Additional [`Xml`] formatting of the [`AllOf`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub xml: Option<Xml>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
@@ -519,6 +928,18 @@ impl AllOfBuilder {
         self
     }
 
+    /// Adds all given [`Schema`]s to [`AllOf`] [Composite Object][composite].
+    ///
+    /// [composite]: https://spec.openapis.org/oas/latest.html#components-object
+    pub fn items<I: IntoIterator<Item = C>, C: Into<RefOr<Schema>>>(
+        mut self,
+        components: I,
+    ) -> Self {
+        self.items.extend(components.into_iter().map(Into::into));
+
+        self
+    }
+
     /// Add or change the title of the [`AllOf`].
     pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
         set_value!(self title title.map(|title| title.into()))
@@ -549,6 +970,36 @@ impl AllOfBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add or change additional [`ExternalDocs`] for the [`AllOf`].
+    pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
+        set_value!(self external_docs external_docs)
+    }
+
+    /// Add or change deprecated status for [`AllOf`].
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change write only flag for [`AllOf`].
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for [`AllOf`].
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
+    /// Set [`Xml`] formatting for [`AllOf`].
+    pub fn xml(mut self, xml: Option<Xml>) -> Self {
+        set_value!(self xml xml)
+    }
+
+    /// Add extensions "x-something" to the [`AllOf`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -564,6 +1015,14 @@ impl From<AllOfBuilder> for RefOr<Schema> {
     }
 }
 
+impl From<AllOf> for RefOr<Schema> {
+    fn from(one_of: AllOf) -> Self {
+        Self::T(Schema::AllOf(one_of))
+    }
+}
+
+impl ToArray for AllOf {}
+
 component_from_builder!(AllOfBuilder);
 
 builder! {
@@ -577,11 +1036,16 @@ builder! {
     /// [anyof]: https://spec.openapis.org/oas/latest.html#components-object
     #[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
     pub struct AnyOf {
         /// Components of _AnyOf component.
         #[serde(rename = "anyOf")]
         pub items: Vec<RefOr<Schema>>,
 
+        /// Changes the [`AnyOf`] title.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub title: Option<String>,
+
         /// Description of the [`AnyOf`]. Markdown syntax is supported.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
@@ -602,6 +1066,19 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Additional [`ExternalDocs`] for the [`AnyOf`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub external_docs: Option<ExternalDocs>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
@@ -643,6 +1120,23 @@ impl AnyOfBuilder {
         self
     }
 
+    /// Adds all given [`Schema`]s to [`AnyOf`] [Composite Object][composite].
+    ///
+    /// [composite]: https://spec.openapis.org/oas/latest.html#components-object
+    pub fn items<I: IntoIterator<Item = C>, C: Into<RefOr<Schema>>>(
+        mut self,
+        components: I,
+    ) -> Self {
+        self.items.extend(components.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Add or change the title of the [`AnyOf`].
+    pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
+        set_value!(self title title.map(|title| title.into()))
+    }
+
     /// Add or change optional description for `AnyOf` component.
     pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
         set_value!(self description description.map(|description| description.into()))
@@ -668,6 +1162,16 @@ impl AnyOfBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add or change additional [`ExternalDocs`] for the [`AnyOf`].
+    pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
+        set_value!(self external_docs external_docs)
+    }
+
+    /// Add extensions "x-something" to the [`AnyOf`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -683,6 +1187,14 @@ impl From<AnyOfBuilder> for RefOr<Schema> {
     }
 }
 
+impl From<AnyOf> for RefOr<Schema> {
+    fn from(any_of: AnyOf) -> Self {
+        Self::T(Schema::AnyOf(any_of))
+    }
+}
+
+impl ToArray for AnyOf {}
+
 component_from_builder!(AnyOfBuilder);
 
 #[cfg(not(feature = "preserve_order"))]
@@ -698,6 +1210,45 @@ builder! {
     ///
     /// This is a generic OpenAPI schema object which can used to present `object`, `field` or an `enum`.
     ///
+    /// Besides type and format the object carries description, numeric bounds, length bounds
+    /// and pattern of the value which allows manual [`ToSchema`][crate::ToSchema]
+    /// implementations to describe constrained fields. Item count bounds of `array` values are
+    /// defined with [`ArrayBuilder::min_items`] and [`ArrayBuilder::max_items`].
+    ///
+    /// # Examples
+    ///
+    /// _**Create constrained properties of an object.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, SchemaType};
+    /// let user = ObjectBuilder::new()
+    ///     .property(
+    ///         "username",
+    ///         ObjectBuilder::new()
+    ///             .schema_type(SchemaType::String)
+    ///             .description(Some("Unique name of the user"))
+    ///             .min_length(Some(3))
+    ///             .max_length(Some(32))
+    ///             .pattern(Some("^[a-z0-9_]+$")),
+    ///     )
+    ///     .property(
+    ///         "age",
+    ///         ObjectBuilder::new()
+    ///             .schema_type(SchemaType::Integer)
+    ///             .minimum(Some(0.0))
+    ///             .maximum(Some(150.0))
+    ///             .nullable(true),
+    ///     )
+    ///     .property(
+    ///         "roles",
+    ///         ArrayBuilder::new()
+    ///             .items(ObjectBuilder::new().schema_type(SchemaType::String))
+    ///             .min_items(Some(1))
+    ///             .max_items(Some(5)),
+    ///     )
+    ///     .required("username")
+    ///     .build();
+    /// ```
+    ///
     /// [schema]: https://spec.openapis.org/oas/latest.html#schema-object
     #[non_exhaustive]
     #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
@@ -706,7 +1257,7 @@ builder! {
     pub struct Object {
         /// Type of [`Object`] e.g. [`SchemaType::Object`] for `object` and [`SchemaType::String`] for
         /// `string` types.
-        #[serde(rename = "type", skip_serializing_if="SchemaType::is_value")]
+        #[serde(rename = "type", skip_serializing_if="SchemaType::is_value", default = "SchemaType::value")]
         pub schema_type: SchemaType,
 
         /// Changes the [`Object`] title.
@@ -767,6 +1318,10 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub xml: Option<Xml>,
 
+        /// Additional [`ExternalDocs`] for the [`Object`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub external_docs: Option<ExternalDocs>,
+
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
@@ -820,6 +1375,15 @@ builder! {
         /// `0` will have same effect as omitting the attribute.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub min_properties: Option<usize>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
@@ -947,6 +1511,11 @@ impl ObjectBuilder {
         set_value!(self xml xml)
     }
 
+    /// Add or change additional [`ExternalDocs`] for the [`Object`].
+    pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
+        set_value!(self external_docs external_docs)
+    }
+
     /// Add or change nullable flag for [`Object`].
     pub fn nullable(mut self, nullable: bool) -> Self {
         set_value!(self nullable nullable)
@@ -1002,6 +1571,11 @@ impl ObjectBuilder {
         set_value!(self min_properties min_properties)
     }
 
+    /// Add extensions "x-something" to the [`Object`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -1044,6 +1618,24 @@ impl From<ArrayBuilder> for AdditionalProperties<Schema> {
     }
 }
 
+impl From<OneOfBuilder> for AdditionalProperties<Schema> {
+    fn from(value: OneOfBuilder) -> Self {
+        Self::RefOr(RefOr::T(Schema::OneOf(value.build())))
+    }
+}
+
+impl From<AllOfBuilder> for AdditionalProperties<Schema> {
+    fn from(value: AllOfBuilder) -> Self {
+        Self::RefOr(RefOr::T(Schema::AllOf(value.build())))
+    }
+}
+
+impl From<AnyOfBuilder> for AdditionalProperties<Schema> {
+    fn from(value: AnyOfBuilder) -> Self {
+        Self::RefOr(RefOr::T(Schema::AnyOf(value.build())))
+    }
+}
+
 impl From<Ref> for AdditionalProperties<Schema> {
     fn from(value: Ref) -> Self {
         Self::RefOr(RefOr::Ref(value))
@@ -1090,6 +1682,12 @@ impl Ref {
         Self::new(format!("#/components/responses/{}", response_name.into()))
     }
 
+    /// Construct a new [`Ref`] from provided header name. This will create a [`Ref`] that
+    /// references the reusable header.
+    pub fn from_header_name<I: Into<String>>(header_name: I) -> Self {
+        Self::new(format!("#/components/headers/{}", header_name.into()))
+    }
+
     to_array_builder!();
 }
 
@@ -1162,6 +1760,14 @@ builder! {
         /// Schema representing the array items type.
         pub items: Box<RefOr<Schema>>,
 
+        /// Schemas of the items by their position e.g. for tuples. This is only supported by
+        /// OpenAPI 3.1 and is serialized only when _`openapi_3_1`_ feature is enabled. Otherwise
+        /// [`Array::items`] is expected to describe all of the items.
+        #[serde(default)]
+        #[cfg_attr(feature = "openapi_3_1", serde(skip_serializing_if = "Vec::is_empty"))]
+        #[cfg_attr(not(feature = "openapi_3_1"), serde(skip_serializing))]
+        pub prefix_items: Vec<RefOr<Schema>>,
+
         /// Description of the [`Array`]. Markdown syntax is supported.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
@@ -1198,6 +1804,23 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Write only property will be only sent in _write_ requests like _POST, PUT_.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub write_only: Option<bool>,
+
+        /// Read only property will be only sent in _read_ requests like _GET_.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub read_only: Option<bool>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
 
@@ -1208,6 +1831,7 @@ impl Default for Array {
             schema_type: SchemaType::Array,
             unique_items: bool::default(),
             items: Default::default(),
+            prefix_items: Default::default(),
             description: Default::default(),
             deprecated: Default::default(),
             example: Default::default(),
@@ -1216,6 +1840,9 @@ impl Default for Array {
             min_items: Default::default(),
             xml: Default::default(),
             nullable: Default::default(),
+            write_only: Default::default(),
+            read_only: Default::default(),
+            extensions: Default::default(),
         }
     }
 }
@@ -1244,6 +1871,14 @@ impl ArrayBuilder {
         set_value!(self items Box::new(component.into()))
     }
 
+    /// Add [`Schema`] of the next positional item of the [`Array`]. See [`Array::prefix_items`]
+    /// for more details.
+    pub fn prefix_item<I: Into<RefOr<Schema>>>(mut self, component: I) -> Self {
+        self.prefix_items.push(component.into());
+
+        self
+    }
+
     /// Add or change the title of the [`Array`].
     pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
         set_value!(self title title.map(|title| title.into()))
@@ -1294,6 +1929,21 @@ impl ArrayBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add or change write only flag for [`Array`].
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for [`Array`].
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
+    /// Add extensions "x-something" to the [`Array`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, serde_json::Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -1353,6 +2003,10 @@ impl SchemaType {
     fn is_value(type_: &SchemaType) -> bool {
         *type_ == SchemaType::Value
     }
+
+    fn value() -> SchemaType {
+        SchemaType::Value
+    }
 }
 
 impl Default for SchemaType {
@@ -1424,6 +2078,12 @@ pub enum KnownFormat {
     DateTime,
     /// Hint to UI to obscure input.
     Password,
+    /// Used with [`String`] values to indicate value is an IPv4 address in dotted-quad form
+    /// e.g. `127.0.0.1`.
+    Ipv4,
+    /// Used with [`String`] values to indicate value is an IPv6 address
+    /// [RFC4291](https://datatracker.ietf.org/doc/html/rfc4291#section-2.2) e.g. `::1`.
+    Ipv6,
     /// Used with [`String`] values to indicate value is in UUID format.
     ///
     /// **uuid** feature need to be enabled.
@@ -1452,6 +2112,295 @@ mod tests {
     use super::*;
     use crate::openapi::*;
 
+    #[cfg(feature = "openapi_3_1")]
+    #[test]
+    fn openapi_3_1_serialize_nullable_schemas() {
+        let schema = Schema::from(
+            ObjectBuilder::new()
+                .property(
+                    "name",
+                    ObjectBuilder::new()
+                        .schema_type(SchemaType::String)
+                        .nullable(true),
+                )
+                .property(
+                    "tags",
+                    ArrayBuilder::new()
+                        .items(ObjectBuilder::new().schema_type(SchemaType::String))
+                        .nullable(true),
+                )
+                .property(
+                    "pet",
+                    AllOfBuilder::new()
+                        .item(Ref::from_schema_name("Pet"))
+                        .nullable(true),
+                )
+                .property(
+                    "pets",
+                    AllOfBuilder::new()
+                        .item(Ref::from_schema_name("Pet"))
+                        .item(Ref::from_schema_name("Animal"))
+                        .nullable(true),
+                )
+                .property(
+                    "animal",
+                    OneOfBuilder::new()
+                        .item(Ref::from_schema_name("Cat"))
+                        .item(Ref::from_schema_name("Dog"))
+                        .nullable(true),
+                )
+                .property(
+                    "order",
+                    ObjectBuilder::new()
+                        .schema_type(SchemaType::String)
+                        .enum_values(Some(["asc", "desc"]))
+                        .nullable(true),
+                )
+                .property("id", ObjectBuilder::new().schema_type(SchemaType::Integer)),
+        );
+
+        assert_json_eq!(
+            serde_json::to_value(schema).unwrap(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": ["string", "null"]
+                    },
+                    "tags": {
+                        "type": ["array", "null"],
+                        "items": {
+                            "type": "string"
+                        }
+                    },
+                    "pet": {
+                        "oneOf": [
+                            {
+                                "type": "null"
+                            },
+                            {
+                                "$ref": "#/components/schemas/Pet"
+                            }
+                        ]
+                    },
+                    "pets": {
+                        "oneOf": [
+                            {
+                                "type": "null"
+                            },
+                            {
+                                "allOf": [
+                                    {
+                                        "$ref": "#/components/schemas/Pet"
+                                    },
+                                    {
+                                        "$ref": "#/components/schemas/Animal"
+                                    }
+                                ]
+                            }
+                        ]
+                    },
+                    "animal": {
+                        "oneOf": [
+                            {
+                                "$ref": "#/components/schemas/Cat"
+                            },
+                            {
+                                "$ref": "#/components/schemas/Dog"
+                            },
+                            {
+                                "type": "null"
+                            }
+                        ]
+                    },
+                    "order": {
+                        "type": ["string", "null"],
+                        "enum": ["asc", "desc", null]
+                    },
+                    "id": {
+                        "type": "integer"
+                    }
+                }
+            })
+        )
+    }
+
+    #[cfg(feature = "openapi_3_1")]
+    #[test]
+    fn openapi_3_1_serialize_nullable_schema_with_extensions() {
+        let schema = Schema::from(
+            ObjectBuilder::new()
+                .schema_type(SchemaType::String)
+                .enum_values(Some(["asc", "desc"]))
+                .nullable(true)
+                .extensions(Some(std::collections::HashMap::from([(
+                    "x-internal".to_string(),
+                    json!(true),
+                )]))),
+        );
+
+        assert_json_eq!(
+            serde_json::to_value(schema).unwrap(),
+            json!({
+                "type": ["string", "null"],
+                "enum": ["asc", "desc", null],
+                "x-internal": true
+            })
+        );
+    }
+
+    #[cfg(feature = "openapi_3_1")]
+    #[test]
+    fn openapi_3_1_serialize_nullable_schema_without_type() {
+        let schema = Schema::from(
+            ObjectBuilder::new()
+                .schema_type(SchemaType::Value)
+                .description(Some("Any value"))
+                .nullable(true),
+        );
+
+        assert_json_eq!(
+            serde_json::to_value(schema).unwrap(),
+            json!({
+                "anyOf": [
+                    {
+                        "description": "Any value"
+                    },
+                    {
+                        "type": "null"
+                    }
+                ]
+            })
+        );
+
+        let schema = Schema::from(
+            ObjectBuilder::new()
+                .schema_type(SchemaType::Value)
+                .enum_values(Some(["asc", "desc"]))
+                .nullable(true),
+        );
+
+        assert_json_eq!(
+            serde_json::to_value(schema).unwrap(),
+            json!({
+                "enum": ["asc", "desc", null]
+            })
+        );
+    }
+
+    #[cfg(all(feature = "openapi_3_1", feature = "preserve_order"))]
+    #[test]
+    fn openapi_3_1_serialize_nullable_schema_preserves_order() {
+        let schema = Schema::from(
+            ObjectBuilder::new()
+                .property("name", Object::with_type(SchemaType::String))
+                .property("id", Object::with_type(SchemaType::Integer))
+                .property(
+                    "address",
+                    ObjectBuilder::new()
+                        .property("street", Object::with_type(SchemaType::String))
+                        .property("city", Object::with_type(SchemaType::String))
+                        .nullable(true),
+                )
+                .nullable(true),
+        );
+
+        assert_eq!(
+            serde_json::to_string(&schema).unwrap(),
+            r#"{"type":["object","null"],"properties":{"name":{"type":"string"},"id":{"type":"integer"},"address":{"type":["object","null"],"properties":{"street":{"type":"string"},"city":{"type":"string"}}}}}"#
+        );
+    }
+
+    #[test]
+    fn serialize_constrained_object_properties() {
+        let object = ObjectBuilder::new()
+            .property(
+                "username",
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::String)
+                    .description(Some("Unique name of the user"))
+                    .min_length(Some(3))
+                    .max_length(Some(32))
+                    .pattern(Some("^[a-z0-9_]+$")),
+            )
+            .property(
+                "age",
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::Integer)
+                    .minimum(Some(0.0))
+                    .maximum(Some(150.0)),
+            )
+            .property(
+                "roles",
+                ArrayBuilder::new()
+                    .items(ObjectBuilder::new().schema_type(SchemaType::String))
+                    .min_items(Some(1))
+                    .max_items(Some(5)),
+            )
+            .build();
+
+        assert_json_eq!(
+            serde_json::to_value(object).unwrap(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "Unique name of the user",
+                        "minLength": 3,
+                        "maxLength": 32,
+                        "pattern": "^[a-z0-9_]+$"
+                    },
+                    "age": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "maximum": 150
+                    },
+                    "roles": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        },
+                        "minItems": 1,
+                        "maxItems": 5
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn openapi_3_1_serialize_array_prefix_items() {
+        let array = ArrayBuilder::new()
+            .items(
+                OneOfBuilder::new()
+                    .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                    .item(ObjectBuilder::new().schema_type(SchemaType::Integer)),
+            )
+            .prefix_item(ObjectBuilder::new().schema_type(SchemaType::String))
+            .prefix_item(ObjectBuilder::new().schema_type(SchemaType::Integer))
+            .min_items(Some(2))
+            .max_items(Some(2))
+            .build();
+
+        let mut expected = json!({
+            "type": "array",
+            "items": {
+                "oneOf": [
+                    { "type": "string" },
+                    { "type": "integer" }
+                ]
+            },
+            "minItems": 2,
+            "maxItems": 2
+        });
+        if cfg!(feature = "openapi_3_1") {
+            expected["prefixItems"] = json!([{ "type": "string" }, { "type": "integer" }]);
+        }
+
+        assert_json_eq!(serde_json::to_value(array).unwrap(), expected);
+    }
+
     #[test]
     fn create_schema_serializes_json() -> Result<(), serde_json::Error> {
         let openapi = OpenApiBuilder::new()
@@ -1528,38 +2477,42 @@ mod tests {
         assert_eq!(
             credential
                 .get("id")
-                .unwrap_or(&serde_json::value::Value::Null)
-                .to_string(),
-            r#"{"default":1,"description":"Id of credential","format":"int32","type":"integer"}"#,
+                .unwrap_or(&serde_json::value::Value::Null),
+            &serde_json::from_str::<Value>(
+                r#"{"default":1,"description":"Id of credential","format":"int32","type":"integer"}"#
+            )?,
             "components.schemas.Credential.properties.id did not match"
         );
         assert_eq!(
             credential
                 .get("name")
-                .unwrap_or(&serde_json::value::Value::Null)
-                .to_string(),
-            r#"{"description":"Name of credential","type":"string"}"#,
+                .unwrap_or(&serde_json::value::Value::Null),
+            &serde_json::from_str::<Value>(
+                r#"{"description":"Name of credential","type":"string"}"#
+            )?,
             "components.schemas.Credential.properties.name did not match"
         );
         assert_eq!(
             credential
                 .get("status")
-                .unwrap_or(&serde_json::value::Value::Null)
-                .to_string(),
-            r#"{"default":"Active","description":"Credential status","enum":["Active","NotActive","Locked","Expired"],"type":"string"}"#,
+                .unwrap_or(&serde_json::value::Value::Null),
+            &serde_json::from_str::<Value>(
+                r#"{"default":"Active","description":"Credential status","enum":["Active","NotActive","Locked","Expired"],"type":"string"}"#
+            )?,
             "components.schemas.Credential.properties.status did not match"
         );
         assert_eq!(
             credential
                 .get("history")
-                .unwrap_or(&serde_json::value::Value::Null)
-                .to_string(),
-            r###"{"items":{"$ref":"#/components/schemas/UpdateHistory"},"type":"array"}"###,
+                .unwrap_or(&serde_json::value::Value::Null),
+            &serde_json::from_str::<Value>(
+                r###"{"items":{"$ref":"#/components/schemas/UpdateHistory"},"type":"array"}"###
+            )?,
             "components.schemas.Credential.properties.history did not match"
         );
         assert_eq!(
-            person.to_string(),
-            r###"{"$ref":"#/components/PersonModel"}"###,
+            person,
+            &serde_json::from_str::<Value>(r###"{"$ref":"#/components/PersonModel"}"###)?,
             "components.schemas.Person.ref did not match"
         );
 
@@ -1755,6 +2708,85 @@ mod tests {
         )
     }
 
+    #[test]
+    fn deserialize_schema_variants() {
+        let schema: Schema = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(
+            schema,
+            Schema::Object(ObjectBuilder::new().schema_type(SchemaType::Value).build())
+        );
+
+        let schema: Schema = serde_json::from_value(json!({
+            "type": "array",
+            "items": {}
+        }))
+        .unwrap();
+        assert!(matches!(schema, Schema::Array(_)));
+
+        let schema: Schema = serde_json::from_value(json!({
+            "allOf": [{ "$ref": "#/components/schemas/Pet" }],
+            "nullable": true
+        }))
+        .unwrap();
+        assert_eq!(
+            schema,
+            Schema::AllOf(
+                AllOfBuilder::new()
+                    .item(Ref::from_schema_name("Pet"))
+                    .nullable(true)
+                    .build()
+            )
+        );
+    }
+
+    #[cfg(feature = "openapi_3_1")]
+    #[test]
+    fn deserialize_openapi_3_1_nullable_schema() {
+        let schema: Schema = serde_json::from_value(json!({
+            "type": ["string", "null"]
+        }))
+        .unwrap();
+        assert_eq!(
+            schema,
+            Schema::Object(
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::String)
+                    .nullable(true)
+                    .build()
+            )
+        );
+
+        let schema: Schema = serde_json::from_value(json!({
+            "oneOf": [{ "type": "null" }, { "$ref": "#/components/schemas/Pet" }]
+        }))
+        .unwrap();
+        assert_eq!(
+            schema,
+            Schema::OneOf(
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("Pet"))
+                    .nullable(true)
+                    .build()
+            )
+        );
+
+        let schema: Schema = serde_json::from_value(json!({
+            "type": ["string", "null"],
+            "enum": ["asc", "desc", null]
+        }))
+        .unwrap();
+        assert_eq!(
+            schema,
+            Schema::Object(
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::String)
+                    .enum_values(Some(["asc", "desc"]))
+                    .nullable(true)
+                    .build()
+            )
+        );
+    }
+
     #[test]
     fn reserialize_deserialized_object_component() {
         let prop = ObjectBuilder::new()
@@ -1945,6 +2977,76 @@ mod tests {
         assert_eq!(json_str, json_de_str);
     }
 
+    #[test]
+    fn compose_schemas_with_composite_builders() {
+        let pet = ObjectBuilder::new()
+            .property(
+                "kind",
+                AnyOfBuilder::new()
+                    .title(Some("Kind"))
+                    .items([Ref::from_schema_name("Cat"), Ref::from_schema_name("Dog")])
+                    .nullable(true),
+            )
+            .property(
+                "owners",
+                AllOfBuilder::new()
+                    .items([
+                        RefOr::from(Ref::from_schema_name("Person")),
+                        ObjectBuilder::new()
+                            .property("since", Object::with_type(SchemaType::String))
+                            .into(),
+                    ])
+                    .build()
+                    .to_array(),
+            )
+            .property(
+                "tag",
+                OneOf::from(
+                    OneOfBuilder::new()
+                        .items([Object::with_type(SchemaType::String)])
+                        .item(Object::with_type(SchemaType::Integer)),
+                ),
+            )
+            .build();
+
+        assert_json_eq!(
+            serde_json::to_value(&pet).unwrap(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "kind": {
+                        "anyOf": [
+                            { "$ref": "#/components/schemas/Cat" },
+                            { "$ref": "#/components/schemas/Dog" }
+                        ],
+                        "title": "Kind",
+                        "nullable": true
+                    },
+                    "owners": {
+                        "type": "array",
+                        "items": {
+                            "allOf": [
+                                { "$ref": "#/components/schemas/Person" },
+                                {
+                                    "type": "object",
+                                    "properties": {
+                                        "since": { "type": "string" }
+                                    }
+                                }
+                            ]
+                        }
+                    },
+                    "tag": {
+                        "oneOf": [
+                            { "type": "string" },
+                            { "type": "integer" }
+                        ]
+                    }
+                }
+            })
+        );
+    }
+
     #[test]
     fn serialize_deserialize_schema_array_ref_or_t() {
         let ref_or_schema = RefOr::T(Schema::Array(
@@ -2074,4 +3176,65 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn discriminator_builder_with_mapping() {
+        let discriminator = DiscriminatorBuilder::new()
+            .property_name("type")
+            .mapping("int", "#/components/schemas/MyInt")
+            .mapping("str", "#/components/schemas/MyStr")
+            .build();
+        let json_value = serde_json::to_value(discriminator).unwrap();
+
+        assert_json_eq!(
+            json_value,
+            json!({
+                "propertyName": "type",
+                "mapping": {
+                    "int": "#/components/schemas/MyInt",
+                    "str": "#/components/schemas/MyStr"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_composite_schemas_with_external_docs() {
+        let external_docs = || Some(ExternalDocs::new("https://docs.example.com/pet"));
+        let schemas = [
+            Schema::from(
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("Cat"))
+                    .external_docs(external_docs())
+                    .build(),
+            ),
+            Schema::from(
+                AllOfBuilder::new()
+                    .item(Ref::from_schema_name("Cat"))
+                    .external_docs(external_docs())
+                    .build(),
+            ),
+            Schema::from(
+                AnyOfBuilder::new()
+                    .item(Ref::from_schema_name("Cat"))
+                    .external_docs(external_docs())
+                    .build(),
+            ),
+        ];
+
+        for schema in schemas {
+            let json_value = serde_json::to_value(&schema).unwrap();
+
+            assert_json_eq!(
+                json_value["externalDocs"],
+                json!({
+                    "url": "https://docs.example.com/pet"
+                })
+            );
+            assert_eq!(
+                serde_json::from_value::<Schema>(json_value).unwrap(),
+                schema
+            );
+        }
+    }
 }
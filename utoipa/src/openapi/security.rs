@@ -5,10 +5,14 @@
 //! [security]: https://spec.openapis.org/oas/latest.html#security-scheme-object
 use std::{
     collections::{BTreeMap, HashMap},
+    fmt::Formatter,
     iter,
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{Error, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
 use super::builder;
 
@@ -417,6 +421,7 @@ impl OpenIdConnect {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct OAuth2 {
     /// Map of supported OAuth2 flows.
+    #[serde(deserialize_with = "deserialize_flows")]
     pub flows: BTreeMap<String, Flow>,
 
     /// Optional description for the [`OAuth2`] [`Flow`] [`SecurityScheme`].
@@ -424,7 +429,12 @@ pub struct OAuth2 {
     pub description: Option<String>,
 
     /// Optional extensions "x-something".
-    #[serde(skip_serializing_if = "Option::is_none", flatten)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        flatten,
+        serialize_with = "super::serialize_extensions",
+        deserialize_with = "super::deserialize_extensions"
+    )]
     pub extensions: Option<HashMap<String, serde_json::Value>>,
 }
 
@@ -534,6 +544,13 @@ pub enum Flow {
 }
 
 impl Flow {
+    const TYPES: [&'static str; 4] = [
+        "implicit",
+        "password",
+        "clientCredentials",
+        "authorizationCode",
+    ];
+
     fn get_type_as_str(&self) -> &str {
         match self {
             Self::Implicit(_) => "implicit",
@@ -544,6 +561,45 @@ impl Flow {
     }
 }
 
+/// Deserialize [`OAuth2`] flows resolving the [`Flow`] type from the map key. [`Flow`] is
+/// untagged and the flow objects overlap, e.g. _`clientCredentials`_ and _`password`_ are
+/// structurally identical, thus the type cannot be resolved from the flow object alone.
+fn deserialize_flows<'de, D>(deserializer: D) -> Result<BTreeMap<String, Flow>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct FlowsVisitor;
+
+    impl<'v> Visitor<'v> for FlowsVisitor {
+        type Value = BTreeMap<String, Flow>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of OAuth2 flows")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'v>,
+        {
+            let mut flows = BTreeMap::new();
+            while let Some(flow_type) = map.next_key::<String>()? {
+                let flow = match flow_type.as_str() {
+                    "implicit" => Flow::Implicit(map.next_value()?),
+                    "password" => Flow::Password(map.next_value()?),
+                    "clientCredentials" => Flow::ClientCredentials(map.next_value()?),
+                    "authorizationCode" => Flow::AuthorizationCode(map.next_value()?),
+                    _ => return Err(A::Error::unknown_variant(&flow_type, &Flow::TYPES)),
+                };
+                flows.insert(flow_type, flow);
+            }
+
+            Ok(flows)
+        }
+    }
+
+    deserializer.deserialize_map(FlowsVisitor)
+}
+
 /// Implicit [`Flow`] configuration for [`OAuth2`].
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -1264,6 +1320,50 @@ mod tests {
 }"###
     }
 
+    #[test]
+    fn deserialize_oauth2_flows_by_flow_type() {
+        let oauth2 = OAuth2::new([
+            Flow::Implicit(Implicit::new(
+                "https://localhost/authorization",
+                Scopes::one("read:items", "read my items"),
+            )),
+            Flow::Password(Password::new("https://localhost/token", Scopes::new())),
+            Flow::ClientCredentials(ClientCredentials::new(
+                "https://localhost/token",
+                Scopes::new(),
+            )),
+            Flow::AuthorizationCode(AuthorizationCode::with_refresh_url(
+                "https://localhost/authorization",
+                "https://localhost/token",
+                Scopes::one("edit:items", "edit my items"),
+                "https://localhost/refresh",
+            )),
+        ]);
+
+        let value = serde_json::to_value(&oauth2).unwrap();
+        let deserialized: OAuth2 = serde_json::from_value(value).unwrap();
+
+        assert_eq!(deserialized.flows, oauth2.flows);
+        assert!(matches!(
+            deserialized.flows.get("clientCredentials"),
+            Some(Flow::ClientCredentials(_))
+        ));
+        assert!(matches!(
+            deserialized.flows.get("authorizationCode"),
+            Some(Flow::AuthorizationCode(AuthorizationCode { token_url, .. }))
+                if token_url == "https://localhost/token"
+        ));
+    }
+
+    #[test]
+    fn deserialize_oauth2_unknown_flow_type_fails() {
+        let result = serde_json::from_str::<OAuth2>(
+            r#"{"flows": {"device": {"tokenUrl": "https://localhost/token", "scopes": {}}}}"#,
+        );
+
+        assert!(result.is_err());
+    }
+
     test_fn! {
         security_schema_correct_mutual_tls:
         SecurityScheme::MutualTls {
@@ -32,7 +32,12 @@ builder! {
         pub external_docs: Option<ExternalDocs>,
 
         /// Optional extensions "x-something".
-        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            serialize_with = "super::serialize_extensions",
+            deserialize_with = "super::deserialize_extensions"
+        )]
         pub extensions: Option<HashMap<String, serde_json::Value>>,
     }
 }
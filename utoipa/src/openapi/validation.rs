@@ -0,0 +1,587 @@
+//! Implements validation of [`OpenApi`] documents.
+//!
+//! Validation is done with [`OpenApi::validate`] which reports all found issues as
+//! [`ValidationError`]s. Locations of the issues are reported as [JSON pointers][json_pointer]
+//! within the serialized [`OpenApi`] document.
+//!
+//! [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde_json::Value;
+
+use super::{
+    path::{Operation, ParameterIn, PathItem, PathItemType},
+    response::Response,
+    OpenApi, RefOr,
+};
+
+/// Maps whose keys are user defined names and thus must never be interpreted as keywords of the
+/// specification when searching references.
+const NAMED_MAPS: [&str; 14] = [
+    "properties",
+    "schemas",
+    "responses",
+    "parameters",
+    "examples",
+    "requestBodies",
+    "headers",
+    "securitySchemes",
+    "links",
+    "callbacks",
+    "paths",
+    "content",
+    "encoding",
+    "webhooks",
+];
+
+/// Issue found from [`OpenApi`] document by [`OpenApi::validate`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Local `$ref` does not point to any existing item within the document.
+    MissingReference {
+        /// JSON pointer to the object containing the `$ref`.
+        location: String,
+        /// Value of the `$ref` that could not be resolved.
+        reference: String,
+    },
+    /// Path template contains a parameter which is not declared as a path parameter in
+    /// the [`PathItem`] or in the [`Operation`].
+    UndeclaredPathParameter {
+        /// JSON pointer to the [`Operation`].
+        location: String,
+        /// Name of the undeclared path parameter.
+        name: String,
+    },
+    /// Same _`operationId`_ is used by multiple [`Operation`]s.
+    DuplicateOperationId {
+        /// The duplicated _`operationId`_.
+        operation_id: String,
+        /// JSON pointers to the [`Operation`]s sharing the _`operationId`_.
+        locations: Vec<String>,
+    },
+    /// [`Response`] has an empty description.
+    MissingResponseDescription {
+        /// JSON pointer to the [`Response`].
+        location: String,
+    },
+    /// [`OpenApi`] document could not be serialized to JSON for validation.
+    Serialization {
+        /// Error message of the failed serialization.
+        message: String,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingReference {
+                location,
+                reference,
+            } => write!(f, "{location}: reference `{reference}` does not resolve"),
+            Self::UndeclaredPathParameter { location, name } => write!(
+                f,
+                "{location}: path parameter `{name}` is not declared in parameters"
+            ),
+            Self::DuplicateOperationId {
+                operation_id,
+                locations,
+            } => write!(
+                f,
+                "operation id `{operation_id}` is used by multiple operations: {}",
+                locations.join(", ")
+            ),
+            Self::MissingResponseDescription { location } => {
+                write!(f, "{location}: response has no description")
+            }
+            Self::Serialization { message } => {
+                write!(f, "document could not be serialized: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl OpenApi {
+    /// Validate this [`OpenApi`] document and return all found issues as [`ValidationError`]s.
+    ///
+    /// Following checks are performed:
+    /// * Local _`$ref`_s must point to an existing item within the document.
+    /// * Every parameter of a path template e.g. _`{id}`_ in _`/pets/{id}`_ must be declared as
+    ///   a path parameter of the [`PathItem`] or the [`Operation`].
+    /// * _`operationId`_s must be unique across paths, webhooks and callbacks.
+    /// * [`Response`]s must have a description.
+    ///
+    /// Paths, webhooks and callbacks are all searched for _`$ref`_s, _`operationId`_s and
+    /// [`Response`]s.
+    ///
+    /// # Examples
+    ///
+    /// _**Fail a test when the document is invalid.**_
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[derive(OpenApi)]
+    /// #[openapi()]
+    /// struct ApiDoc;
+    ///
+    /// if let Err(errors) = ApiDoc::openapi().validate() {
+    ///     panic!("{}", errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"));
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        let document = match serde_json::to_value(self) {
+            Ok(document) => document,
+            Err(error) => {
+                return Err(vec![ValidationError::Serialization {
+                    message: error.to_string(),
+                }])
+            }
+        };
+        let mut references = Vec::new();
+        collect_references(&document, None, &mut String::new(), &mut references);
+        errors.extend(
+            references
+                .into_iter()
+                .filter(|(_, reference)| {
+                    reference
+                        .strip_prefix('#')
+                        .map(|pointer| document.pointer(&percent_decode(pointer)).is_none())
+                        .unwrap_or(false)
+                })
+                .map(|(location, reference)| ValidationError::MissingReference {
+                    location,
+                    reference,
+                }),
+        );
+
+        let mut operation_ids = BTreeMap::<&str, Vec<String>>::new();
+        validate_path_items(
+            "/paths",
+            &self.paths.paths,
+            true,
+            &mut errors,
+            &mut operation_ids,
+        );
+        validate_path_items(
+            "/webhooks",
+            &self.webhooks,
+            false,
+            &mut errors,
+            &mut operation_ids,
+        );
+
+        if let Some(components) = &self.components {
+            errors.extend(
+                responses_without_description("/components/responses", &components.responses)
+                    .map(|location| ValidationError::MissingResponseDescription { location }),
+            );
+        }
+
+        errors.extend(
+            operation_ids
+                .into_iter()
+                .filter(|(_, locations)| locations.len() > 1)
+                .map(
+                    |(operation_id, locations)| ValidationError::DuplicateOperationId {
+                        operation_id: operation_id.to_string(),
+                        locations,
+                    },
+                ),
+        );
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Escape JSON pointer reference token according to RFC 6901.
+pub(crate) fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Decode percent encoded URI fragment e.g. _`%7Bid%7D`_ to _`{id}`_. Malformed escapes are
+/// left as is.
+fn percent_decode(fragment: &str) -> String {
+    let bytes = fragment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes
+            .get(index + 1..index + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Validate [`PathItem`]s of paths, webhooks or callbacks found under `location` and collect
+/// _`operationId`_s of their [`Operation`]s. Callbacks of the [`Operation`]s are validated
+/// recursively. Path parameters are only checked from `path_items` keyed by path templates.
+fn validate_path_items<'a>(
+    location: &str,
+    path_items: impl IntoIterator<Item = (&'a String, &'a PathItem)>,
+    is_path_template: bool,
+    errors: &mut Vec<ValidationError>,
+    operation_ids: &mut BTreeMap<&'a str, Vec<String>>,
+) {
+    for (path, path_item) in path_items {
+        let path_location = format!("{location}/{}", escape(path));
+        for (path_item_type, operation) in &path_item.operations {
+            let location = format!("{path_location}/{}", path_item_type_name(path_item_type));
+
+            if is_path_template {
+                errors.extend(
+                    undeclared_path_parameters(path, path_item, operation).map(|name| {
+                        ValidationError::UndeclaredPathParameter {
+                            location: location.clone(),
+                            name: name.to_string(),
+                        }
+                    }),
+                );
+            }
+            errors.extend(
+                responses_without_description(
+                    &format!("{location}/responses"),
+                    &operation.responses.responses,
+                )
+                .map(|location| ValidationError::MissingResponseDescription { location }),
+            );
+
+            for (name, callback) in operation.callbacks.iter().flatten() {
+                validate_path_items(
+                    &format!("{location}/callbacks/{}", escape(name)),
+                    &callback.paths,
+                    false,
+                    errors,
+                    operation_ids,
+                );
+            }
+
+            if let Some(operation_id) = &operation.operation_id {
+                operation_ids
+                    .entry(operation_id)
+                    .or_default()
+                    .push(location);
+            }
+        }
+    }
+}
+
+pub(super) fn path_item_type_name(path_item_type: &PathItemType) -> String {
+    serde_json::to_value(path_item_type)
+        .ok()
+        .as_ref()
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Recursively collect `$ref`s with their JSON pointer locations. Free form values such as
+/// examples, defaults and extensions are not searched.
+fn collect_references(
+    value: &Value,
+    parent: Option<&str>,
+    location: &mut String,
+    references: &mut Vec<(String, String)>,
+) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get("$ref") {
+                references.push((location.clone(), reference.clone()));
+            }
+
+            let is_named_map = parent
+                .map(|parent| NAMED_MAPS.contains(&parent))
+                .unwrap_or(false);
+            for (key, value) in object {
+                if !is_named_map
+                    && (matches!(key.as_str(), "example" | "default" | "enum" | "value")
+                        || key.starts_with("x-"))
+                {
+                    continue;
+                }
+
+                let len = location.len();
+                location.push('/');
+                location.push_str(&escape(key));
+                collect_references(value, Some(key), location, references);
+                location.truncate(len);
+            }
+        }
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                let len = location.len();
+                location.push_str(&format!("/{index}"));
+                collect_references(value, None, location, references);
+                location.truncate(len);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn undeclared_path_parameters<'p>(
+    path: &'p str,
+    path_item: &'p PathItem,
+    operation: &'p Operation,
+) -> impl Iterator<Item = &'p str> {
+    let is_declared = move |name: &str| {
+        path_item
+            .parameters
+            .iter()
+            .chain(operation.parameters.iter())
+            .flatten()
+            .any(|parameter| parameter.parameter_in == ParameterIn::Path && parameter.name == name)
+    };
+
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}').map(|(name, _)| name))
+        .filter(move |name| !is_declared(name))
+}
+
+fn responses_without_description<'r>(
+    location: &'r str,
+    responses: &'r BTreeMap<String, RefOr<Response>>,
+) -> impl Iterator<Item = String> + 'r {
+    responses
+        .iter()
+        .filter(|(_, response)| {
+            matches!(response, RefOr::T(response) if response.description.is_empty())
+        })
+        .map(move |(name, _)| format!("{location}/{}", escape(name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::{
+        path::{Callback, OperationBuilder, ParameterBuilder, PathItemBuilder},
+        ComponentsBuilder, ContentBuilder, OpenApiBuilder, PathItemType, PathsBuilder, Ref,
+        ResponseBuilder,
+    };
+
+    use super::*;
+
+    #[test]
+    fn validate_valid_openapi() {
+        let openapi = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets/{id}",
+                    PathItemBuilder::new()
+                        .parameters(Some([ParameterBuilder::new()
+                            .name("id")
+                            .parameter_in(ParameterIn::Path)
+                            .build()]))
+                        .operation(
+                            PathItemType::Get,
+                            OperationBuilder::new()
+                                .operation_id(Some("get_pet"))
+                                .response(
+                                    "200",
+                                    ResponseBuilder::new().description("Pet found").content(
+                                        "application/json",
+                                        ContentBuilder::new()
+                                            .schema(Ref::from_schema_name("Pet"))
+                                            .build(),
+                                    ),
+                                ),
+                        )
+                        .build(),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", crate::openapi::ObjectBuilder::new())
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(openapi.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_invalid_openapi() {
+        let get_pet = || {
+            OperationBuilder::new()
+                .operation_id(Some("get_pet"))
+                .response(
+                    "200",
+                    ResponseBuilder::new().content(
+                        "application/json",
+                        ContentBuilder::new()
+                            .schema(Ref::from_schema_name("Pet"))
+                            .build(),
+                    ),
+                )
+                .response("404", Ref::from_response_name("NotFound"))
+        };
+        let openapi = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pet/{id}",
+                        PathItemBuilder::new()
+                            .operation(PathItemType::Get, get_pet())
+                            .build(),
+                    )
+                    .path(
+                        "/pets",
+                        PathItemBuilder::new()
+                            .operation(PathItemType::Get, get_pet())
+                            .build(),
+                    ),
+            )
+            .build();
+
+        assert_eq!(
+            openapi.validate(),
+            Err(vec![
+                ValidationError::MissingReference {
+                    location:
+                        "/paths/~1pet~1{id}/get/responses/200/content/application~1json/schema"
+                            .to_string(),
+                    reference: "#/components/schemas/Pet".to_string()
+                },
+                ValidationError::MissingReference {
+                    location: "/paths/~1pet~1{id}/get/responses/404".to_string(),
+                    reference: "#/components/responses/NotFound".to_string()
+                },
+                ValidationError::MissingReference {
+                    location: "/paths/~1pets/get/responses/200/content/application~1json/schema"
+                        .to_string(),
+                    reference: "#/components/schemas/Pet".to_string()
+                },
+                ValidationError::MissingReference {
+                    location: "/paths/~1pets/get/responses/404".to_string(),
+                    reference: "#/components/responses/NotFound".to_string()
+                },
+                ValidationError::UndeclaredPathParameter {
+                    location: "/paths/~1pet~1{id}/get".to_string(),
+                    name: "id".to_string()
+                },
+                ValidationError::MissingResponseDescription {
+                    location: "/paths/~1pet~1{id}/get/responses/200".to_string()
+                },
+                ValidationError::MissingResponseDescription {
+                    location: "/paths/~1pets/get/responses/200".to_string()
+                },
+                ValidationError::DuplicateOperationId {
+                    operation_id: "get_pet".to_string(),
+                    locations: vec![
+                        "/paths/~1pet~1{id}/get".to_string(),
+                        "/paths/~1pets/get".to_string()
+                    ]
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_webhooks_and_callbacks() {
+        let notify = |operation_id: &str| {
+            PathItemBuilder::new()
+                .operation(
+                    PathItemType::Post,
+                    OperationBuilder::new()
+                        .operation_id(Some(operation_id))
+                        .response("200", Ref::from_response_name("Ok")),
+                )
+                .build()
+        };
+        let openapi = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/subscriptions",
+                    PathItemBuilder::new()
+                        .operation(
+                            PathItemType::Post,
+                            OperationBuilder::new()
+                                .operation_id(Some("subscribe"))
+                                .response("200", ResponseBuilder::new().description("Subscribed"))
+                                .callback(
+                                    "onEvent",
+                                    Callback::new("{$request.body#/url}", notify("notify")),
+                                ),
+                        )
+                        .build(),
+                ),
+            )
+            .webhooks([("newPet", notify("notify"))])
+            .build();
+
+        assert_eq!(
+            openapi.validate(),
+            Err(vec![
+                ValidationError::MissingReference {
+                    location: "/paths/~1subscriptions/post/callbacks/onEvent/{$request.body#~1url}/post/responses/200".to_string(),
+                    reference: "#/components/responses/Ok".to_string()
+                },
+                ValidationError::MissingReference {
+                    location: "/webhooks/newPet/post/responses/200".to_string(),
+                    reference: "#/components/responses/Ok".to_string()
+                },
+                ValidationError::DuplicateOperationId {
+                    operation_id: "notify".to_string(),
+                    locations: vec![
+                        "/paths/~1subscriptions/post/callbacks/onEvent/{$request.body#~1url}/post"
+                            .to_string(),
+                        "/webhooks/newPet/post".to_string()
+                    ]
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_encoded_references() {
+        let openapi = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("My Pet", crate::openapi::ObjectBuilder::new())
+                    .schema("pets/v1~Pet", crate::openapi::ObjectBuilder::new())
+                    .schema(
+                        "Pets",
+                        crate::openapi::ArrayBuilder::new()
+                            .items(Ref::new("#/components/schemas/My%20Pet")),
+                    )
+                    .schema(
+                        "VersionedPets",
+                        crate::openapi::ArrayBuilder::new()
+                            .items(Ref::new("#/components/schemas/pets~1v1~0Pet")),
+                    )
+                    .schema(
+                        "MissingPets",
+                        crate::openapi::ArrayBuilder::new()
+                            .items(Ref::new("#/components/schemas/pets/v1~0Pet")),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(
+            openapi.validate(),
+            Err(vec![ValidationError::MissingReference {
+                location: "/components/schemas/MissingPets/items".to_string(),
+                reference: "#/components/schemas/pets/v1~0Pet".to_string()
+            }])
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Implements helpers for asserting generated [`OpenApi`][openapi] documents in tests.
+//!
+//! The helpers are typically used through [`assert_openapi_snapshot!`][crate::assert_openapi_snapshot]
+//! and [`assert_json_path!`][crate::assert_json_path] macros. Locations within the documents are
+//! given as [JSON pointers][json_pointer] e.g. _`/paths/~1pets/get/responses/200`_.
+//!
+//! [openapi]: crate::openapi::OpenApi
+//! [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+use std::{fmt::Write, path::Path};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::openapi::validation::escape;
+
+/// Name of the environment variable which when set to a truthy value makes [`assert_snapshot`]
+/// write the snapshot files with the current document instead of comparing them. Values empty,
+/// _`0`_ and _`false`_ are not truthy.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UTOIPA_UPDATE_SNAPSHOTS";
+
+/// Maximum number of differences listed in the panic message of [`assert_snapshot`].
+const MAX_REPORTED_DIFFERENCES: usize = 20;
+
+/// Assert that serialized `document` equals the JSON snapshot stored at `path`.
+///
+/// Snapshot files are created or overwritten with the pretty printed JSON of the `document`
+/// instead of compared when [`UPDATE_SNAPSHOTS_ENV`] environment variable is set to a truthy
+/// value, e.g. _`1`_. Snapshots are compared as JSON values thus formatting of the snapshot file
+/// does not matter.
+///
+/// # Panics
+///
+/// Panics listing the JSON pointers of the differences if the `document` does not match the
+/// snapshot, if the snapshot is missing, or if the snapshot cannot be read or written.
+#[track_caller]
+pub fn assert_snapshot<T: Serialize + ?Sized, P: AsRef<Path>>(document: &T, path: P) {
+    let path = path.as_ref();
+    let actual = serde_json::to_value(document)
+        .unwrap_or_else(|error| panic!("failed to serialize document to JSON: {error}"));
+
+    if update_snapshots() {
+        write_snapshot(&actual, path);
+        return;
+    }
+    if !path.exists() {
+        panic!(
+            "snapshot {} does not exist, run the test with {UPDATE_SNAPSHOTS_ENV}=1 to create it",
+            path.display()
+        );
+    }
+
+    let snapshot = std::fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("failed to read snapshot {}: {error}", path.display()));
+    let expected = serde_json::from_str::<Value>(&snapshot)
+        .unwrap_or_else(|error| panic!("failed to parse snapshot {}: {error}", path.display()));
+
+    let differences = differences(&expected, &actual);
+    if !differences.is_empty() {
+        let mut message = format!(
+            "document does not match snapshot {}, found {} difference(s):\n",
+            path.display(),
+            differences.len()
+        );
+        for difference in differences.iter().take(MAX_REPORTED_DIFFERENCES) {
+            let _ = writeln!(message, "  {difference}");
+        }
+        if differences.len() > MAX_REPORTED_DIFFERENCES {
+            let _ = writeln!(
+                message,
+                "  ... and {} more",
+                differences.len() - MAX_REPORTED_DIFFERENCES
+            );
+        }
+        let _ = write!(
+            message,
+            "run the test with {UPDATE_SNAPSHOTS_ENV}=1 to update the snapshot"
+        );
+
+        panic!("{message}");
+    }
+}
+
+/// Whether [`UPDATE_SNAPSHOTS_ENV`] environment variable is set to a truthy value.
+fn update_snapshots() -> bool {
+    std::env::var(UPDATE_SNAPSHOTS_ENV)
+        .map(|value| {
+            !matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "" | "0" | "false"
+            )
+        })
+        .unwrap_or(false)
+}
+
+fn write_snapshot(value: &Value, path: &Path) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap_or_else(|error| {
+            panic!(
+                "failed to create snapshot directory {}: {error}",
+                parent.display()
+            )
+        });
+    }
+    let mut json = serde_json::to_string_pretty(value)
+        .unwrap_or_else(|error| panic!("failed to serialize document to JSON: {error}"));
+    json.push('\n');
+
+    std::fs::write(path, json)
+        .unwrap_or_else(|error| panic!("failed to write snapshot {}: {error}", path.display()));
+}
+
+/// Get the value at JSON `pointer` of the serialized `document`.
+///
+/// # Panics
+///
+/// Panics if the `document` does not have a value at the `pointer`. The panic message shows the
+/// deepest existing parent of the `pointer` and the keys available in it.
+#[track_caller]
+pub fn assert_json_path<T: Serialize + ?Sized>(document: &T, pointer: &str) -> Value {
+    let mut document = serde_json::to_value(document)
+        .unwrap_or_else(|error| panic!("failed to serialize document to JSON: {error}"));
+
+    if let Some(value) = document.pointer_mut(pointer) {
+        return value.take();
+    }
+
+    let mut parent = pointer;
+    let parent_value = loop {
+        parent = parent
+            .rsplit_once('/')
+            .map(|(parent, _)| parent)
+            .unwrap_or("");
+        if let Some(value) = document.pointer(parent) {
+            break value;
+        }
+    };
+    let available = match parent_value {
+        Value::Object(object) => object
+            .keys()
+            .map(|key| format!("`{key}`"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Array(array) => format!("indexes 0..{}", array.len()),
+        value => format!("none, value is {value}"),
+    };
+
+    panic!(
+        "no value at `{pointer}`, deepest existing parent is `{parent}` with available keys: {available}"
+    );
+}
+
+/// Assert that the value at JSON `pointer` of the serialized `document` equals the serialized
+/// `expected` value.
+///
+/// # Panics
+///
+/// Panics if the `document` does not have a value at the `pointer` or the value does not equal
+/// the `expected` value.
+#[track_caller]
+pub fn assert_json_path_eq<T: Serialize + ?Sized, E: Serialize + ?Sized>(
+    document: &T,
+    pointer: &str,
+    expected: &E,
+) {
+    let actual = assert_json_path(document, pointer);
+    let expected = serde_json::to_value(expected)
+        .unwrap_or_else(|error| panic!("failed to serialize expected value to JSON: {error}"));
+
+    let differences = differences(&expected, &actual);
+    if !differences.is_empty() {
+        panic!(
+            "value at `{pointer}` does not match expected value:\n  {}",
+            differences.join("\n  ")
+        );
+    }
+}
+
+/// Get the differences between `expected` and `actual` JSON values as human readable messages
+/// prefixed with the JSON pointer of the difference.
+fn differences(expected: &Value, actual: &Value) -> Vec<String> {
+    let mut differences = Vec::new();
+    collect_differences(expected, actual, &mut String::new(), &mut differences);
+
+    differences
+}
+
+fn collect_differences(
+    expected: &Value,
+    actual: &Value,
+    pointer: &mut String,
+    differences: &mut Vec<String>,
+) {
+    let location = |pointer: &str| {
+        if pointer.is_empty() {
+            String::from("/")
+        } else {
+            pointer.to_string()
+        }
+    };
+
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected) in expected {
+                let len = pointer.len();
+                pointer.push('/');
+                pointer.push_str(&escape(key));
+                match actual.get(key) {
+                    Some(actual) => collect_differences(expected, actual, pointer, differences),
+                    None => differences.push(format!("{}: removed", location(pointer))),
+                }
+                pointer.truncate(len);
+            }
+            for key in actual.keys().filter(|key| !expected.contains_key(*key)) {
+                differences.push(format!("{}/{}: added", pointer, escape(key)));
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                let len = pointer.len();
+                let _ = write!(pointer, "/{index}");
+                collect_differences(expected, actual, pointer, differences);
+                pointer.truncate(len);
+            }
+        }
+        (expected, actual) if expected != actual => differences.push(format!(
+            "{}: expected {expected}, found {actual}",
+            location(pointer)
+        )),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn differences_are_reported_with_json_pointers() {
+        let expected = json!({
+            "paths": {
+                "/pets": { "get": { "operationId": "list_pets", "tags": ["pets"] } },
+                "/tags": {}
+            }
+        });
+        let actual = json!({
+            "paths": {
+                "/pets": { "get": { "operationId": "get_pets", "tags": ["pets", "animals"] } },
+                "/owners": {}
+            }
+        });
+
+        assert_eq!(
+            differences(&expected, &actual),
+            [
+                r#"/paths/~1pets/get/operationId: expected "list_pets", found "get_pets""#,
+                r#"/paths/~1pets/get/tags: expected ["pets"], found ["pets","animals"]"#,
+                "/paths/~1tags: removed",
+                "/paths/~1owners: added",
+            ]
+        );
+        assert!(differences(&expected, &expected).is_empty());
+    }
+
+    #[test]
+    fn assert_snapshot_rejects_missing_and_compares_existing_snapshot() {
+        let path = std::env::temp_dir()
+            .join(format!("utoipa-snapshot-{}", std::process::id()))
+            .join("openapi.json");
+        let _ = std::fs::remove_file(&path);
+
+        let missing = std::panic::catch_unwind(|| {
+            assert_snapshot(&json!({ "openapi": "3.0.3" }), &path);
+        });
+        assert!(missing.is_err(), "missing snapshot should not pass");
+        assert!(!path.exists(), "missing snapshot should not be written");
+
+        write_snapshot(&json!({ "openapi": "3.0.3" }), &path);
+        assert_snapshot(&json!({ "openapi": "3.0.3" }), &path);
+
+        let result = std::panic::catch_unwind(|| {
+            assert_snapshot(&json!({ "openapi": "3.1.0" }), &path);
+        });
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        let error = result.expect_err("changed document should not match snapshot");
+        let message = error.downcast_ref::<String>().unwrap();
+        assert!(
+            message.contains(r#"/openapi: expected "3.0.3", found "3.1.0""#),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn assert_json_path_reports_available_keys() {
+        let document = json!({ "paths": { "/pets": { "get": {} } } });
+
+        assert_eq!(
+            assert_json_path(&document, "/paths/~1pets"),
+            json!({ "get": {} })
+        );
+
+        let error = std::panic::catch_unwind(|| {
+            assert_json_path(&document, "/paths/~1pets/post/responses");
+        })
+        .expect_err("missing path should panic");
+        assert_eq!(
+            error.downcast_ref::<String>().unwrap(),
+            "no value at `/paths/~1pets/post/responses`, deepest existing parent is `/paths/~1pets` with available keys: `get`"
+        );
+    }
+}